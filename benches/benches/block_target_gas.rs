@@ -278,6 +278,7 @@ fn service_with_many_contracts(
             cache_capacity: Some(16 * 1024 * 1024 * 1024),
             max_fds: -1,
             columns_policy: ColumnsPolicy::OnCreation,
+            allow_unknown_columns: false,
         },
     )
     .expect("Failed to create database");
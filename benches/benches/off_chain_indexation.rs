@@ -0,0 +1,176 @@
+use criterion::{
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use fuel_core::{
+    database::{
+        database_description::off_chain::OffChain,
+        Database,
+    },
+    fuel_core_graphql_api::worker_service::{
+        index_block_in_parallel,
+        index_block_serially,
+        DaCompressionConfig,
+        IndexationConfig,
+    },
+};
+use fuel_core_storage::transactional::WriteTransaction;
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        SealedBlock,
+    },
+    entities::coins::coin::Coin,
+    fuel_tx::{
+        Address,
+        AssetId,
+        Input,
+        Output,
+        TransactionBuilder,
+        UniqueIdentifier,
+        UtxoId,
+    },
+    fuel_types::{
+        BlockHeight,
+        ChainId,
+    },
+    services::{
+        block_importer::ImportResult,
+        executor::{
+            Event,
+            TransactionExecutionResult,
+            TransactionExecutionStatus,
+        },
+    },
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+use std::time::Duration;
+
+const TRANSFERS_PER_BLOCK: usize = 5_000;
+
+/// Creates a block of transfers. Every transfer spends one of the `unspent_coins`,
+/// when there is any, and creates two new coins. Returns the block with the
+/// coins created by it.
+fn transfers_block(
+    rng: &mut StdRng,
+    height: BlockHeight,
+    mut unspent_coins: Vec<Coin>,
+) -> (ImportResult, Vec<Coin>) {
+    let chain_id = ChainId::default();
+    let mut transactions = vec![];
+    let mut tx_status = vec![];
+    let mut events = vec![];
+    let mut created_coins = vec![];
+
+    for _ in 0..TRANSFERS_PER_BLOCK {
+        let mut builder = TransactionBuilder::script(vec![], vec![]);
+        if let Some(coin) = unspent_coins.pop() {
+            builder.add_input(Input::coin_signed(
+                coin.utxo_id,
+                coin.owner,
+                coin.amount,
+                coin.asset_id,
+                Default::default(),
+                Default::default(),
+            ));
+            builder.add_witness(Default::default());
+            events.push(Event::CoinConsumed(coin));
+        }
+        let recipient: Address = rng.gen();
+        let change: Address = rng.gen();
+        builder.add_output(Output::coin(recipient, 10, AssetId::default()));
+        builder.add_output(Output::change(change, 0, AssetId::default()));
+        let tx = builder.finalize_as_transaction();
+        let tx_id = tx.id(&chain_id);
+
+        for (output_index, owner) in [recipient, change].into_iter().enumerate() {
+            let coin = Coin {
+                utxo_id: UtxoId::new(tx_id, u16::try_from(output_index).unwrap()),
+                owner,
+                amount: rng.gen_range(1..1000),
+                asset_id: AssetId::default(),
+                tx_pointer: Default::default(),
+            };
+            events.push(Event::CoinCreated(coin));
+            created_coins.push(coin);
+        }
+        tx_status.push(TransactionExecutionStatus {
+            id: tx_id,
+            result: TransactionExecutionResult::Success {
+                result: None,
+                receipts: vec![],
+                total_gas: 0,
+                total_fee: 0,
+            },
+        });
+        transactions.push(tx);
+    }
+
+    let mut block = Block::default();
+    block.header_mut().set_block_height(height);
+    *block.transactions_mut() = transactions;
+
+    let import_result = ImportResult {
+        sealed_block: SealedBlock {
+            entity: block,
+            consensus: Default::default(),
+        },
+        tx_status,
+        events,
+        source: Default::default(),
+    };
+    (import_result, created_coins)
+}
+
+fn off_chain_indexation(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let config = IndexationConfig {
+        chain_id: ChainId::default(),
+        da_compression_config: DaCompressionConfig::Enabled(
+            fuel_core_compression::config::Config {
+                temporal_registry_retention: Duration::from_secs(3600),
+                registry_capacity: None,
+            },
+        ),
+        balances_indexation_enabled: true,
+        coins_to_spend_indexation_enabled: true,
+        asset_metadata_indexation_enabled: true,
+        contract_balances_indexation_enabled: true,
+        base_asset_id: AssetId::default(),
+        coin_owner_mapper: None,
+        relayed_transactions: None,
+        mark_spent_owned_coins: false,
+    };
+    let mut database = Database::<OffChain>::in_memory();
+
+    // The first block creates the coins spent by the benchmarked block.
+    let (funding_block, coins) = transfers_block(&mut rng, 1.into(), vec![]);
+    let mut transaction = database.write_transaction();
+    index_block_serially(&config, &funding_block, &mut transaction).unwrap();
+    transaction.commit().unwrap();
+    let (block, _) = transfers_block(&mut rng, 2.into(), coins);
+
+    let mut group = c.benchmark_group("off_chain_indexation");
+    group.sample_size(20);
+    group.bench_function(format!("serial/{TRANSFERS_PER_BLOCK}"), |b| {
+        b.iter(|| {
+            let mut transaction = database.write_transaction();
+            index_block_serially(&config, &block, &mut transaction).unwrap();
+        })
+    });
+    group.bench_function(format!("parallel/{TRANSFERS_PER_BLOCK}"), |b| {
+        b.iter(|| {
+            let mut transaction = database.write_transaction();
+            index_block_in_parallel(&config, &block, &mut transaction).unwrap();
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, off_chain_indexation);
+criterion_main!(benches);
@@ -0,0 +1,150 @@
+//! Compares the latency of the lookups of the transactions stored in the
+//! zstd compressed `OldTransactions` column of the off-chain database and
+//! in the LZ4 compressed `Transactions` column of the on-chain database.
+
+use criterion::{
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use fuel_core::{
+    database::{
+        database_description::{
+            off_chain::OffChain,
+            on_chain::OnChain,
+        },
+        Database,
+    },
+    fuel_core_graphql_api::storage::old::OldTransactions,
+    state::{
+        historical_rocksdb::StateRewindPolicy,
+        rocks_db::{
+            ColumnsPolicy,
+            DatabaseConfig,
+        },
+    },
+};
+use fuel_core_storage::{
+    tables::Transactions,
+    transactional::{
+        AtomicView,
+        WriteTransaction,
+    },
+    StorageAsMut,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        Address,
+        AssetId,
+        Input,
+        Output,
+        Transaction,
+        TransactionBuilder,
+        TxId,
+        UniqueIdentifier,
+        UtxoId,
+    },
+    fuel_types::ChainId,
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+const TRANSACTIONS: usize = 50_000;
+const LOOKUPS: usize = 1_000;
+
+/// Creates the transfers with the script data resembling the calls of a contract.
+fn transactions() -> Vec<(TxId, Transaction)> {
+    let mut rng = StdRng::seed_from_u64(0xC0FFEE);
+    let chain_id = ChainId::default();
+    (0..TRANSACTIONS)
+        .map(|_| {
+            let script_data = [rng.gen::<[u8; 32]>(), [0; 32], rng.gen()].concat();
+            let mut builder = TransactionBuilder::script(vec![0x24; 64], script_data);
+            builder.add_input(Input::coin_signed(
+                UtxoId::new(rng.gen(), 0),
+                rng.gen(),
+                rng.gen_range(1..1000),
+                AssetId::default(),
+                Default::default(),
+                Default::default(),
+            ));
+            builder.add_witness([rng.gen::<[u8; 32]>(), rng.gen()].concat().into());
+            builder.add_output(Output::coin(
+                rng.gen::<Address>(),
+                10,
+                AssetId::default(),
+            ));
+            builder.add_output(Output::change(rng.gen(), 0, AssetId::default()));
+            let tx = builder.finalize_as_transaction();
+            (tx.id(&chain_id), tx)
+        })
+        .collect()
+}
+
+fn config() -> DatabaseConfig {
+    DatabaseConfig {
+        cache_capacity: Some(16 * 1024 * 1024),
+        max_fds: -1,
+        columns_policy: ColumnsPolicy::OnCreation,
+        allow_unknown_columns: false,
+    }
+}
+
+fn old_transactions_compression(c: &mut Criterion) {
+    let transactions = transactions();
+    let mut rng = StdRng::seed_from_u64(0xBEEF);
+    let lookups = (0..LOOKUPS)
+        .map(|_| transactions[rng.gen_range(0..transactions.len())].0)
+        .collect::<Vec<_>>();
+
+    let mut off_chain =
+        Database::<OffChain>::rocksdb_temp(StateRewindPolicy::NoRewind, config())
+            .unwrap();
+    let mut transaction = off_chain.write_transaction();
+    for (tx_id, tx) in transactions.iter() {
+        transaction
+            .storage_as_mut::<OldTransactions>()
+            .insert(tx_id, tx)
+            .unwrap();
+    }
+    transaction.commit().unwrap();
+
+    let mut on_chain =
+        Database::<OnChain>::rocksdb_temp(StateRewindPolicy::NoRewind, config()).unwrap();
+    let mut transaction = on_chain.write_transaction();
+    for (tx_id, tx) in transactions.iter() {
+        transaction
+            .storage_as_mut::<Transactions>()
+            .insert(tx_id, tx)
+            .unwrap();
+    }
+    transaction.commit().unwrap();
+
+    let mut group = c.benchmark_group("old_transactions_compression");
+    group.bench_function(format!("zstd/{LOOKUPS}"), |b| {
+        b.iter(|| {
+            let view = off_chain.latest_view().unwrap();
+            for tx_id in lookups.iter() {
+                let tx = view.storage::<OldTransactions>().get(tx_id).unwrap();
+                assert!(tx.is_some());
+            }
+        })
+    });
+    group.bench_function(format!("lz4/{LOOKUPS}"), |b| {
+        b.iter(|| {
+            let view = on_chain.latest_view().unwrap();
+            for tx_id in lookups.iter() {
+                let tx = view.storage::<Transactions>().get(tx_id).unwrap();
+                assert!(tx.is_some());
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, old_transactions_compression);
+criterion_main!(benches);
@@ -0,0 +1,163 @@
+//! Compares the latency of the paged scans of the coins of a hot owner, like
+//! an exchange wallet, stored in the plain and in the sharded layouts of the
+//! `OwnedCoins` column. The coins are received and spent block by block, so
+//! the scans run over the column shaped by the compactions of the hot prefix.
+//! Besides the criterion measurements, the p50 and p99 latencies of the single
+//! page scans are printed for each layout.
+
+use criterion::{
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use fuel_core::{
+    database::{
+        database_description::off_chain::OffChain,
+        Database,
+    },
+    fuel_core_graphql_api::storage::coins::{
+        insert_owned_coin,
+        owner_coin_id_key,
+        take_owned_coin,
+        OwnedCoinState,
+        OwnedCoinsLayout,
+    },
+    state::{
+        historical_rocksdb::StateRewindPolicy,
+        rocks_db::{
+            ColumnsPolicy,
+            DatabaseConfig,
+        },
+    },
+};
+use fuel_core_storage::transactional::{
+    AtomicView,
+    ReadTransaction,
+};
+use fuel_core_types::fuel_tx::{
+    Address,
+    TxId,
+    UtxoId,
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+use std::time::{
+    Duration,
+    Instant,
+};
+
+const BLOCKS: u32 = 200;
+const COINS_PER_BLOCK: u32 = 1_000;
+/// The share of the received coins spent in the next block, in percents.
+const SPENT_PERCENT: u32 = 80;
+const PAGE_SIZE: usize = 100;
+const PAGES: usize = 1_000;
+const HOT_OWNER: Address = Address::new([7; 32]);
+
+fn utxo_id(rng: &mut StdRng) -> UtxoId {
+    UtxoId::new(TxId::from(rng.gen::<[u8; 32]>()), rng.gen_range(0..4))
+}
+
+/// Creates the database in the `layout` where the `HOT_OWNER` receives
+/// `COINS_PER_BLOCK` coins per block and spends most of the coins of the
+/// previous block. Returns it with the unspent coins.
+fn database(layout: OwnedCoinsLayout) -> (Database<OffChain>, Vec<UtxoId>) {
+    let database = Database::<OffChain>::rocksdb_temp(
+        StateRewindPolicy::NoRewind,
+        DatabaseConfig {
+            cache_capacity: Some(16 * 1024 * 1024),
+            max_fds: -1,
+            columns_policy: ColumnsPolicy::OnCreation,
+            allow_unknown_columns: false,
+        },
+    )
+    .unwrap();
+    database.init_owned_coins_layout(layout).unwrap();
+
+    let mut rng = StdRng::seed_from_u64(0xC0FFEE);
+    let mut unspent = Vec::new();
+    let mut received = Vec::new();
+    for _ in 0..BLOCKS {
+        let mut transaction = database.read_transaction();
+        for utxo_id in received.drain(..) {
+            if rng.gen_range(0..100) < SPENT_PERCENT {
+                let key = owner_coin_id_key(&HOT_OWNER, &utxo_id);
+                take_owned_coin(&mut transaction, layout, &key).unwrap();
+            } else {
+                unspent.push(utxo_id);
+            }
+        }
+        for _ in 0..COINS_PER_BLOCK {
+            let utxo_id = utxo_id(&mut rng);
+            let key = owner_coin_id_key(&HOT_OWNER, &utxo_id);
+            insert_owned_coin(&mut transaction, layout, &key, &OwnedCoinState::Unspent)
+                .unwrap();
+            received.push(utxo_id);
+        }
+        database
+            .commit_changes_without_height(transaction.into_changes())
+            .unwrap();
+    }
+    unspent.extend(received);
+    unspent.sort();
+    (database, unspent)
+}
+
+fn scan_page(database: &Database<OffChain>, cursor: UtxoId) -> Duration {
+    let start = Instant::now();
+    let view = database.latest_view().unwrap();
+    let page = view
+        .owned_coins_ids(&HOT_OWNER, Some(cursor), None, true)
+        .take(PAGE_SIZE)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let elapsed = start.elapsed();
+    assert!(!page.is_empty());
+    elapsed
+}
+
+fn percentile(latencies: &[Duration], percent: usize) -> Duration {
+    let index = (latencies.len() * percent / 100).min(latencies.len() - 1);
+    latencies[index]
+}
+
+fn owned_coins_layout(c: &mut Criterion) {
+    let mut group = c.benchmark_group("owned_coins_layout");
+    group.sample_size(20);
+    for layout in [OwnedCoinsLayout::Plain, OwnedCoinsLayout::Sharded] {
+        let (database, unspent) = database(layout);
+        let mut rng = StdRng::seed_from_u64(0xBEEF);
+        let cursors = (0..PAGES)
+            .map(|_| unspent[rng.gen_range(0..unspent.len())])
+            .collect::<Vec<_>>();
+
+        let mut latencies = cursors
+            .iter()
+            .map(|cursor| scan_page(&database, *cursor))
+            .collect::<Vec<_>>();
+        latencies.sort();
+        println!(
+            "owned_coins_layout/{layout}: p50 {:?}, p99 {:?}",
+            percentile(&latencies, 50),
+            percentile(&latencies, 99),
+        );
+
+        group.bench_function(format!("{layout}/page_{PAGE_SIZE}"), |b| {
+            b.iter_custom(|iters| {
+                cursors
+                    .iter()
+                    .cycle()
+                    .take(iters as usize)
+                    .map(|cursor| scan_page(&database, *cursor))
+                    .sum()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, owned_coins_layout);
+criterion_main!(benches);
@@ -0,0 +1,119 @@
+//! Compares the latency of the scan of the coins of an owner whose coins were
+//! mostly spent, when the spent coins are removed from the `OwnedCoins` index,
+//! leaving the tombstones of RocksDB behind, and when they are flagged as spent.
+
+use criterion::{
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use fuel_core::{
+    database::{
+        database_description::off_chain::OffChain,
+        Database,
+    },
+    fuel_core_graphql_api::storage::coins::{
+        owner_coin_id_key,
+        OwnedCoinState,
+        OwnedCoins,
+    },
+    state::{
+        historical_rocksdb::StateRewindPolicy,
+        rocks_db::{
+            ColumnsPolicy,
+            DatabaseConfig,
+        },
+    },
+};
+use fuel_core_storage::{
+    transactional::{
+        AtomicView,
+        WriteTransaction,
+    },
+    StorageAsMut,
+};
+use fuel_core_types::fuel_tx::{
+    Address,
+    TxId,
+    UtxoId,
+};
+
+const COINS: u32 = 100_000;
+/// Every `LIVE_COINS_STEP`th coin stays unspent.
+const LIVE_COINS_STEP: u32 = 100;
+const OWNER: Address = Address::new([7; 32]);
+
+fn utxo_id(index: u32) -> UtxoId {
+    let mut tx_id = [0u8; 32];
+    tx_id[..4].copy_from_slice(&index.to_be_bytes());
+    UtxoId::new(TxId::from(tx_id), 0)
+}
+
+/// Creates the database with the `COINS` coins of the `OWNER`, and then spends
+/// all of them except every `LIVE_COINS_STEP`th, either removing or flagging them.
+fn database(flag_spent: bool) -> Database<OffChain> {
+    let mut database = Database::<OffChain>::rocksdb_temp(
+        StateRewindPolicy::NoRewind,
+        DatabaseConfig {
+            cache_capacity: Some(16 * 1024 * 1024),
+            max_fds: -1,
+            columns_policy: ColumnsPolicy::OnCreation,
+            allow_unknown_columns: false,
+        },
+    )
+    .unwrap();
+
+    let mut transaction = database.write_transaction();
+    for index in 0..COINS {
+        transaction
+            .storage_as_mut::<OwnedCoins>()
+            .insert(
+                &owner_coin_id_key(&OWNER, &utxo_id(index)),
+                &OwnedCoinState::Unspent,
+            )
+            .unwrap();
+    }
+    transaction.commit().unwrap();
+
+    let mut transaction = database.write_transaction();
+    for index in (0..COINS).filter(|index| index % LIVE_COINS_STEP != 0) {
+        let key = owner_coin_id_key(&OWNER, &utxo_id(index));
+        let mut storage = transaction.storage_as_mut::<OwnedCoins>();
+        if flag_spent {
+            storage
+                .insert(
+                    &key,
+                    &OwnedCoinState::Spent {
+                        height: 1u32.into(),
+                    },
+                )
+                .unwrap();
+        } else {
+            storage.remove(&key).unwrap();
+        }
+    }
+    transaction.commit().unwrap();
+    database
+}
+
+fn owned_coins_scan(c: &mut Criterion) {
+    let mut group = c.benchmark_group("owned_coins_scan");
+    group.sample_size(20);
+    for (name, flag_spent) in [("removed", false), ("flagged", true)] {
+        let database = database(flag_spent);
+        group.bench_function(format!("{name}/{COINS}"), |b| {
+            b.iter(|| {
+                let view = database.latest_view().unwrap();
+                let coins = view
+                    .owned_coins_ids(&OWNER, None, None, true)
+                    .collect::<Result<Vec<_>, _>>()
+                    .unwrap();
+                assert_eq!(coins.len(), (COINS / LIVE_COINS_STEP) as usize);
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, owned_coins_scan);
+criterion_main!(benches);
@@ -86,6 +86,7 @@ fn insert_state_single_contract_database(c: &mut Criterion) {
                     cache_capacity: Some(16 * 1024 * 1024 * 1024),
                     max_fds: -1,
                     columns_policy: ColumnsPolicy::OnCreation,
+                    allow_unknown_columns: false,
                 },
             )
             .unwrap();
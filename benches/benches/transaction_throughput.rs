@@ -106,6 +106,7 @@ where
                 cache_capacity: Some(16 * 1024 * 1024 * 1024),
                 max_fds: -1,
                 columns_policy: ColumnsPolicy::OnCreation,
+                allow_unknown_columns: false,
             };
 
             // spin up node
@@ -0,0 +1,120 @@
+use criterion::{
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use fuel_core::{
+    database::{
+        database_description::off_chain::OffChain,
+        Database,
+    },
+    fuel_core_graphql_api::{
+        ports::OffChainDatabase,
+        storage::transactions::TransactionStatuses,
+        tx_status_loader::{
+            TransactionStatusLoader,
+            TxStatusSource,
+        },
+    },
+};
+use fuel_core_storage::{
+    transactional::{
+        AtomicView,
+        WriteTransaction,
+    },
+    Result as StorageResult,
+    StorageAsMut,
+};
+use fuel_core_types::{
+    fuel_tx::TxId,
+    services::txpool::TransactionExecutionStatus,
+    tai64::Tai64,
+};
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+use tokio::runtime::Runtime;
+
+const STATUSES: u8 = 100;
+
+/// Counts the requests to the off-chain database.
+struct CountingSource<V> {
+    view: V,
+    calls: AtomicUsize,
+}
+
+impl<V> TxStatusSource for CountingSource<V>
+where
+    V: OffChainDatabase,
+{
+    fn tx_statuses(
+        &self,
+        tx_ids: &[TxId],
+    ) -> Vec<StorageResult<Option<TransactionExecutionStatus>>> {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        self.view.tx_statuses(tx_ids)
+    }
+}
+
+fn tx_status_loader(c: &mut Criterion) {
+    let mut database = Database::<OffChain>::in_memory();
+    let tx_ids: Vec<TxId> = (0..STATUSES).map(|i| [i; 32].into()).collect();
+    let mut transaction = database.write_transaction();
+    for tx_id in tx_ids.iter() {
+        transaction
+            .storage_as_mut::<TransactionStatuses>()
+            .insert(
+                tx_id,
+                &TransactionExecutionStatus::Submitted {
+                    time: Tai64::UNIX_EPOCH,
+                },
+            )
+            .unwrap();
+    }
+    transaction.commit().unwrap();
+    let source = CountingSource {
+        view: database.latest_view().unwrap(),
+        calls: AtomicUsize::new(0),
+    };
+    let rt = Runtime::new().unwrap();
+
+    // The loader reads all statuses of the query with one request instead of one
+    // request per status.
+    let loader = TransactionStatusLoader::default();
+    rt.block_on(futures::future::join_all(
+        tx_ids.iter().map(|tx_id| loader.load(&source, *tx_id)),
+    ));
+    let batched_calls = source.calls.swap(0, Ordering::Relaxed);
+    for tx_id in tx_ids.iter() {
+        source.tx_statuses(&[*tx_id]);
+    }
+    let unbatched_calls = source.calls.swap(0, Ordering::Relaxed);
+    println!(
+        "Storage calls for {STATUSES} statuses: {batched_calls} with the loader, \
+        {unbatched_calls} without the loader"
+    );
+    assert!(batched_calls < unbatched_calls);
+
+    let mut group = c.benchmark_group("tx_status_loader");
+    group.bench_function(format!("unbatched/{STATUSES}"), |b| {
+        b.iter(|| {
+            for tx_id in tx_ids.iter() {
+                source.tx_statuses(&[*tx_id]);
+            }
+        })
+    });
+    group.bench_function(format!("batched/{STATUSES}"), |b| {
+        b.to_async(&rt).iter(|| async {
+            let loader = TransactionStatusLoader::default();
+            futures::future::join_all(
+                tx_ids.iter().map(|tx_id| loader.load(&source, *tx_id)),
+            )
+            .await
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, tx_status_loader);
+criterion_main!(benches);
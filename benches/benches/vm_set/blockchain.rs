@@ -88,6 +88,7 @@ impl BenchDb {
                 cache_capacity: None,
                 max_fds: -1,
                 columns_policy: ColumnsPolicy::OnCreation,
+                allow_unknown_columns: false,
             },
         )
         .unwrap();
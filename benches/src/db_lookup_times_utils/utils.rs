@@ -49,6 +49,7 @@ pub fn open_rocks_db<Description: DatabaseDescription>(
             cache_capacity: Some(16 * 1024 * 1024 * 1024),
             max_fds: -1,
             columns_policy: ColumnsPolicy::OnCreation,
+            allow_unknown_columns: false,
         },
     )?;
     Ok(db)
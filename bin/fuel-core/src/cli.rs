@@ -28,6 +28,9 @@ pub fn default_db_path() -> PathBuf {
 }
 
 pub mod fee_contract;
+pub mod index;
+pub mod migrate_owned_coins;
+pub mod reindex;
 #[cfg(feature = "rocksdb")]
 pub mod rollback;
 pub mod run;
@@ -58,6 +61,9 @@ pub enum Fuel {
     #[cfg(feature = "rocksdb")]
     Rollback(rollback::Command),
     GenerateFeeContract(fee_contract::Command),
+    Index(index::Command),
+    Reindex(reindex::Command),
+    MigrateOwnedCoins(migrate_owned_coins::Command),
 }
 
 pub const LOG_FILTER: &str = "RUST_LOG";
@@ -138,6 +144,9 @@ pub async fn run_cli() -> anyhow::Result<()> {
             #[cfg(feature = "rocksdb")]
             Fuel::Snapshot(command) => snapshot::exec(command).await,
             Fuel::GenerateFeeContract(command) => fee_contract::exec(command).await,
+            Fuel::Index(command) => index::exec(command).await,
+            Fuel::Reindex(command) => reindex::exec(command).await,
+            Fuel::MigrateOwnedCoins(command) => migrate_owned_coins::exec(command).await,
             Fuel::Rollback(command) => rollback::exec(command).await,
         },
         Err(e) => {
@@ -293,6 +302,7 @@ mod tests {
                         snapshot::SubCommands::Everything {
                             chain_config,
                             encoding_command,
+                            ..
                         },
                     output_dir,
                     ..
@@ -0,0 +1,65 @@
+use crate::cli::{
+    run,
+    ShutdownListener,
+};
+use anyhow::Context;
+use clap::Parser;
+use fuel_core::{
+    combined_database::CombinedDatabase,
+    service::{
+        genesis::NotifyCancel,
+        offline_indexer::{
+            read_blocks,
+            OfflineIndexer,
+        },
+    },
+};
+use std::path::PathBuf;
+use tracing::info;
+
+/// Builds the databases of the node from an export of sealed blocks without
+/// running the consensus, P2P, or the transaction pool.
+///
+/// The indexation resumes from the last indexed block, so the same export
+/// can be indexed again after an interruption.
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// The path to the file or the directory with the exported sealed blocks.
+    #[clap(long = "blocks", env)]
+    pub blocks: PathBuf,
+
+    #[clap(flatten)]
+    pub node: run::Command,
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    let config = command.node.get_config().await?;
+    let database = CombinedDatabase::from_config(&config.combined_db_config)?;
+    let shutdown_listener = ShutdownListener::spawn();
+
+    let mut indexer = OfflineIndexer::new(config, database).await?;
+    info!(height = %indexer.indexed_height(), "Resuming the indexation");
+
+    let blocks = read_blocks(&command.blocks).with_context(|| {
+        format!("Failed to read the blocks from {:?}", command.blocks)
+    })?;
+    let report = tokio::select! {
+        report = indexer.index(blocks) => Some(report?),
+        _ = shutdown_listener.wait_until_cancelled() => None,
+    };
+    let Some(report) = report else {
+        info!(height = %indexer.indexed_height(), "The indexation is interrupted");
+        return Ok(())
+    };
+
+    info!(
+        height = %indexer.indexed_height(),
+        indexed_blocks = report.indexed_blocks,
+        skipped_blocks = report.skipped_blocks,
+        elapsed = ?report.elapsed,
+        blocks_per_second = report.blocks_per_second(),
+        "The indexation is finished"
+    );
+
+    Ok(())
+}
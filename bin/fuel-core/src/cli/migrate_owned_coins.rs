@@ -0,0 +1,46 @@
+use crate::cli::run;
+use clap::Parser;
+use fuel_core::{
+    combined_database::CombinedDatabase,
+    fuel_core_graphql_api::storage::coins::OwnedCoinsLayout,
+    service::owned_coins_migration::migrate_owned_coins,
+};
+use std::num::NonZeroUsize;
+use tracing::info;
+
+/// Re-keys the owned coins of the off-chain database into the requested layout
+/// and records it, so the node can be started with this layout.
+///
+/// The migration is resumable, running it again after the interruption
+/// finishes it. The node must not run while the coins are migrated.
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// The layout of the keys of the owned coins to migrate to.
+    #[clap(long = "layout", env, value_parser)]
+    pub layout: OwnedCoinsLayout,
+
+    /// The number of the entries re-keyed per commit.
+    #[clap(long = "batch-size", default_value = "10000", env)]
+    pub batch_size: NonZeroUsize,
+
+    #[clap(flatten)]
+    pub node: run::Command,
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    let config = command.node.get_config().await?;
+    let database = CombinedDatabase::from_config(&config.combined_db_config)?;
+    database.check_version()?;
+
+    info!(layout = %command.layout, "Migrating the owned coins");
+    let report =
+        migrate_owned_coins(database.off_chain(), command.layout, command.batch_size)?;
+    info!(
+        from = %report.from,
+        migrated_entries = report.migrated_entries,
+        elapsed = ?report.elapsed,
+        "The migration of the owned coins is finished"
+    );
+
+    Ok(())
+}
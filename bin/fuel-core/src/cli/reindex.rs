@@ -0,0 +1,61 @@
+use crate::cli::run;
+use anyhow::Context;
+use clap::Parser;
+use fuel_core::{
+    combined_database::CombinedDatabase,
+    fuel_core_graphql_api::storage::Column,
+    service::reindex::{
+        reindex,
+        reindexable_column,
+    },
+};
+use tracing::info;
+
+/// Rebuilds the requested off-chain index columns from the on-chain state
+/// without rebuilding the rest of the off-chain database.
+///
+/// The columns derived together are rebuilt together, so requesting
+/// `CoinBalances` also rebuilds `MessageBalances`. The columns indexing
+/// the blocks, like `ContractsByBlock`, are replayed from the stored blocks.
+/// Rebuilding `OwnedCoinCountByAsset` backfills the coin counts of the databases
+/// created before they were maintained.
+/// The node must not run while the columns are rebuilt.
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// The names of the off-chain columns to rebuild,
+    /// e.g. `CoinsToSpend` or `CoinBalances`.
+    #[clap(long = "columns", env, value_delimiter = ',', required = true, value_parser = parse_column)]
+    pub columns: Vec<Column>,
+
+    #[clap(flatten)]
+    pub node: run::Command,
+}
+
+fn parse_column(name: &str) -> anyhow::Result<Column> {
+    reindexable_column(name)
+        .with_context(|| format!("The column {name} doesn't exist or can't be reindexed"))
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    let config = command.node.get_config().await?;
+    let database = CombinedDatabase::from_config(&config.combined_db_config)?;
+    database.check_version()?;
+    let base_asset_id = *config
+        .snapshot_reader
+        .chain_config()
+        .consensus_parameters
+        .base_asset_id();
+
+    info!(columns = ?command.columns, "Rebuilding the columns");
+    let report = reindex(&database, &base_asset_id, &command.columns)?;
+    info!(
+        rebuilt = ?report.rebuilt,
+        rebuilt_block_columns = ?report.rebuilt_block_columns,
+        removed_entries = report.removed_entries,
+        replayed_entries = report.replayed_entries,
+        elapsed = ?report.elapsed,
+        "The reindex is finished"
+    );
+
+    Ok(())
+}
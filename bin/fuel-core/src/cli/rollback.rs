@@ -62,6 +62,7 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
             cache_capacity: Some(64 * 1024 * 1024),
             max_fds: command.rocksdb_max_fds,
             columns_policy: ColumnsPolicy::Lazy,
+            allow_unknown_columns: false,
         },
     )
     .map_err(Into::<anyhow::Error>::into)
@@ -22,14 +22,21 @@ use fuel_core::{
         CombinedDatabaseConfig,
     },
     fuel_core_graphql_api::{
+        indexation::derived_ownership::{
+            CoinOwnerMapperRef,
+            PredicateDataOwnerMapper,
+        },
+        storage::da_compression::versioned_block_codec::DaBlockEncoding,
         worker_service::DaCompressionConfig,
         Costs,
+        MaxPageSizes,
         ServiceConfig as GraphQLConfig,
     },
     producer::Config as ProducerConfig,
     service::{
         config::Trigger,
         genesis::NotifyCancel,
+        integrity_check,
         Config,
         DbType,
         RelayerConsensusConfig,
@@ -80,9 +87,13 @@ use rlimit::{
 use std::{
     env,
     net,
-    num::NonZeroU64,
+    num::{
+        NonZeroU32,
+        NonZeroU64,
+    },
     path::PathBuf,
     str::FromStr,
+    sync::Arc,
     time::Duration,
 };
 use tracing::{
@@ -96,7 +107,6 @@ use fuel_core::state::historical_rocksdb::StateRewindPolicy;
 
 use crate::cli::run::gas_price::GasPriceArgs;
 use fuel_core::service::config::GasPriceConfig;
-#[cfg(feature = "parallel-executor")]
 use std::num::NonZeroUsize;
 
 #[cfg(feature = "p2p")]
@@ -155,6 +165,12 @@ pub struct Command {
     )]
     pub rocksdb_max_fds: i32,
 
+    #[cfg(feature = "rocksdb")]
+    /// Allows opening the database created by a newer version of the node.
+    /// Columns unknown to this version are ignored.
+    #[clap(long = "allow-unknown-columns", default_value = "false", env)]
+    pub allow_unknown_columns: bool,
+
     #[cfg(feature = "rocksdb")]
     /// Defines the state rewind policy for the database when RocksDB is enabled.
     ///
@@ -169,6 +185,15 @@ pub struct Command {
     #[clap(long = "state-rewind-duration", default_value = "7d", env)]
     pub state_rewind_duration: humantime::Duration,
 
+    #[cfg(feature = "rocksdb")]
+    /// The number of the last blocks for which the off-chain database keeps
+    /// the reverse diffs of its indexes, used to roll the indexes back.
+    ///
+    /// The diffs are never kept for more blocks than the `state-rewind-duration` allows.
+    /// By default, the diffs are kept for the whole `state-rewind-duration`.
+    #[clap(long = "index-diff-retention-blocks", env)]
+    pub index_diff_retention_blocks: Option<NonZeroU64>,
+
     /// Snapshot from which to do (re)genesis. Defaults to local testnet configuration.
     #[arg(name = "SNAPSHOT", long = "snapshot", env)]
     pub snapshot: Option<PathBuf>,
@@ -178,6 +203,24 @@ pub struct Command {
     #[arg(name = "DB_PRUNE", long = "db-prune", env, default_value = "false")]
     pub db_prune: bool,
 
+    /// Checks the integrity of the databases before the start by decoding the sampled
+    /// values of every column. The check always runs after an unclean shutdown.
+    #[arg(long = "verify-on-start", env)]
+    pub verify_on_start: bool,
+
+    /// The number of the values sampled per column by the integrity check.
+    #[arg(long = "verify-on-start-samples", default_value = "1000", env)]
+    pub verify_on_start_samples: usize,
+
+    /// The number of the values per column that may fail to decode
+    /// without blocking the start.
+    #[arg(long = "verify-on-start-max-failures", default_value = "0", env)]
+    pub verify_on_start_max_failures: u64,
+
+    /// Starts the node even if the integrity check found corrupted columns.
+    #[arg(long = "force")]
+    pub force: bool,
+
     /// The determines whether to continue the services on internal error or not.
     #[clap(long = "continue-services-on-error", default_value = "false", env)]
     pub continue_on_error: bool,
@@ -234,6 +277,60 @@ pub struct Command {
     #[arg(long = "da-compression", env)]
     pub da_compression: Option<humantime::Duration>,
 
+    /// The maximum number of entries in each keyspace of the temporal registry
+    /// of the DA compression. Once it is reached, the oldest registrations are
+    /// overwritten. The nodes must use the same capacity to produce the same
+    /// compressed blocks.
+    #[arg(
+        long = "da-compression-registry-capacity",
+        env,
+        requires = "da_compression"
+    )]
+    pub da_compression_registry_capacity: Option<NonZeroU32>,
+
+    /// If given, the da-compressed blocks are stored compressed with zstd
+    /// at the given level, using the dictionary trained on the stored blocks.
+    /// The blocks stored before are re-encoded in the background.
+    #[arg(
+        long = "da-compression-zstd-level",
+        env,
+        requires = "da_compression",
+        value_parser = clap::value_parser!(i32).range(1..=22)
+    )]
+    pub da_compression_zstd_level: Option<i32>,
+
+    /// If given, the historical data, like the statuses of relayed transactions
+    /// and the supply history of assets, is pruned after the given number of blocks.
+    #[arg(
+        long = "historical-data-retention",
+        alias = "relayed-tx-status-retention",
+        env
+    )]
+    pub historical_data_retention: Option<u32>,
+
+    /// The number of blocks indexed by the off-chain worker in one batch
+    /// while it catches up with the chain on startup. The blocks of the batch
+    /// are committed at once, so larger batches trade latency for throughput.
+    #[arg(long = "off-chain-worker-batch-blocks", default_value = "1", env)]
+    pub off_chain_worker_batch_blocks: NonZeroUsize,
+
+    /// The commits of the block indexes into the off-chain database longer than
+    /// the threshold are logged as slow with the height of the block.
+    #[arg(long = "off-chain-slow-commit-threshold", default_value = "1s", env)]
+    pub off_chain_slow_commit_threshold: humantime::Duration,
+
+    /// Recomputes the total number of transactions from the transaction statuses
+    /// on startup, and corrects it if the node crashed in the middle of the commit.
+    /// It scans all transaction statuses, so it slows down the startup.
+    #[arg(long = "reconcile-tx-count", env)]
+    pub reconcile_tx_count: bool,
+
+    /// Indexes the coins of the predicates that embed the public key of their owner
+    /// at the start of the predicate data under the address of this key,
+    /// so the `coins` query can return them with `includeDerivedOwnership`.
+    #[arg(long = "index-predicate-data-owners", env)]
+    pub index_predicate_data_owners: bool,
+
     /// A new block is produced instantly when transactions are available.
     #[clap(flatten)]
     pub poa_trigger: PoATriggerArgs,
@@ -316,8 +413,16 @@ impl Command {
             #[cfg(feature = "rocksdb")]
             rocksdb_max_fds,
             #[cfg(feature = "rocksdb")]
+            allow_unknown_columns,
+            #[cfg(feature = "rocksdb")]
             state_rewind_duration,
+            #[cfg(feature = "rocksdb")]
+            index_diff_retention_blocks,
             db_prune,
+            verify_on_start,
+            verify_on_start_samples,
+            verify_on_start_max_failures,
+            force,
             snapshot,
             continue_on_error,
             vm_backtrace: _,
@@ -332,6 +437,13 @@ impl Command {
             #[cfg(feature = "aws-kms")]
             consensus_aws_kms,
             da_compression,
+            da_compression_registry_capacity,
+            da_compression_zstd_level,
+            historical_data_retention,
+            off_chain_worker_batch_blocks,
+            off_chain_slow_commit_threshold,
+            reconcile_tx_count,
+            index_predicate_data_owners,
             poa_trigger,
             predefined_blocks_path,
             coinbase_recipient,
@@ -514,9 +626,12 @@ impl Command {
                 columns_policy: ColumnsPolicy::OnCreation,
                 #[cfg(not(feature = "production"))]
                 columns_policy: ColumnsPolicy::Lazy,
+                allow_unknown_columns,
             },
             #[cfg(feature = "rocksdb")]
             state_rewind_policy,
+            #[cfg(feature = "rocksdb")]
+            index_diff_retention_blocks,
         };
 
         let block_importer = fuel_core::service::config::fuel_core_importer::Config::new(
@@ -529,10 +644,18 @@ impl Command {
             Some(retention) => {
                 DaCompressionConfig::Enabled(fuel_core_compression::Config {
                     temporal_registry_retention: retention.into(),
+                    registry_capacity: da_compression_registry_capacity,
                 })
             }
             None => DaCompressionConfig::Disabled,
         };
+        let da_compression_encoding = match da_compression_zstd_level {
+            Some(level) => DaBlockEncoding::Zstd { level },
+            None => DaBlockEncoding::Plain,
+        };
+
+        let coin_owner_mapper = index_predicate_data_owners
+            .then(|| Arc::new(PredicateDataOwnerMapper) as CoinOwnerMapperRef);
 
         let TxPoolArgs {
             tx_pool_ttl,
@@ -632,7 +755,43 @@ impl Command {
                 assemble_tx_dry_run_limit: graphql.assemble_tx_dry_run_limit,
                 assemble_tx_estimate_predicates_limit: graphql
                     .assemble_tx_estimate_predicates_limit,
+                contracts_info_cache_capacity: graphql.contracts_info_cache_capacity,
+                message_proof_cache_capacity: graphql.message_proof_cache_capacity,
+                transaction_trees_cache_capacity: graphql
+                    .transaction_trees_cache_capacity,
+                max_scanned_entries: graphql.max_scanned_entries,
+                max_block_headers_per_request: graphql.max_block_headers_per_request,
+                consolidation_dust_threshold: graphql.consolidation_dust_threshold,
+                balance_check_owners_per_minute: graphql.balance_check_owners_per_minute,
+                balance_check_repair: graphql.balance_check_repair,
+                allow_owner_pruning: graphql.allow_owner_pruning,
+                mark_spent_owned_coins: graphql.mark_spent_owned_coins,
+                owned_coins_compaction_interval: graphql
+                    .owned_coins_compaction_interval
+                    .into(),
+                owned_coins_compaction_min_spent_age: graphql
+                    .owned_coins_compaction_min_spent_age,
+                owned_coins_compaction_entries_per_run: graphql
+                    .owned_coins_compaction_entries_per_run,
+                owned_coins_layout: graphql.owned_coins_layout,
+                snapshot_view_ttl: graphql.snapshot_view_ttl.into(),
+                max_snapshot_views: graphql.max_snapshot_views,
+                max_balance_subscriptions: graphql.max_balance_subscriptions,
+                max_transaction_tag_size: graphql.max_transaction_tag_size,
+                ready_when_offchain_synced: graphql.ready_when_offchain_synced,
+                health_degraded_lag: graphql.health_degraded_lag,
+                health_failing_lag: graphql.health_failing_lag,
                 query_log_threshold_time: graphql.query_log_threshold_time.into(),
+                max_page_sizes: MaxPageSizes {
+                    transactions_by_owner: graphql.max_page_sizes.transactions_by_owner,
+                    relayed_transactions_by_owner: graphql
+                        .max_page_sizes
+                        .relayed_transactions_by_owner,
+                    coins: graphql.max_page_sizes.coins,
+                    messages: graphql.max_page_sizes.messages,
+                    balances: graphql.max_page_sizes.balances,
+                    owner_activity: graphql.max_page_sizes.owner_activity,
+                },
                 costs: Costs {
                     balance_query: graphql.costs.balance_query,
                     coins_to_spend: graphql.costs.coins_to_spend,
@@ -695,6 +854,18 @@ impl Command {
             gas_price_config,
             block_importer,
             da_compression,
+            da_compression_encoding,
+            historical_data_retention,
+            off_chain_worker_batch_blocks,
+            off_chain_slow_commit_threshold: off_chain_slow_commit_threshold.into(),
+            reconcile_tx_count,
+            coin_owner_mapper,
+            integrity_check: integrity_check::Config {
+                verify_on_start,
+                samples_per_column: verify_on_start_samples,
+                max_failures_per_column: verify_on_start_max_failures,
+                force,
+            },
             #[cfg(feature = "relayer")]
             relayer: relayer_cfg,
             #[cfg(feature = "p2p")]
@@ -1,8 +1,15 @@
 //! Clap configuration related to GraphQL service.
 
-use std::net;
+use std::{
+    net,
+    num::NonZeroUsize,
+};
 
-use fuel_core::fuel_core_graphql_api::DEFAULT_QUERY_COSTS;
+use fuel_core::fuel_core_graphql_api::{
+    storage::coins::OwnedCoinsLayout,
+    DEFAULT_MAX_PAGE_SIZE,
+    DEFAULT_QUERY_COSTS,
+};
 
 #[derive(Debug, Clone, clap::Args)]
 pub struct GraphQLArgs {
@@ -22,6 +29,132 @@ pub struct GraphQLArgs {
     #[clap(long = "graphql-database-batch-size", default_value = "100", env)]
     pub database_batch_size: usize,
 
+    /// The maximum number of entries examined by one scan of the owner's index.
+    /// The query that exceeds it fails with a retriable error and the cursor to continue from.
+    #[clap(long = "graphql-max-scanned-entries", default_value = "10000", env)]
+    pub max_scanned_entries: usize,
+
+    /// The maximum number of block headers returned by one `blockHeaders` query.
+    #[clap(
+        long = "graphql-max-block-headers-per-request",
+        default_value = "1000",
+        env
+    )]
+    pub max_block_headers_per_request: usize,
+
+    /// The coins with the amount below it are suggested for the consolidation
+    /// by the `consolidationSuggestion` query, unless the query specifies its own threshold.
+    #[clap(
+        long = "graphql-consolidation-dust-threshold",
+        default_value = "1000",
+        env
+    )]
+    pub consolidation_dust_threshold: u64,
+
+    /// The number of owners per minute whose cached balances are recomputed from
+    /// their coins and messages in the background to detect the drift of the index.
+    /// The zero value disables the check.
+    #[clap(
+        long = "graphql-balance-check-owners-per-minute",
+        default_value = "0",
+        env
+    )]
+    pub balance_check_owners_per_minute: u32,
+
+    /// Overwrites the cached balances that differ from the recomputed ones.
+    #[clap(long = "graphql-balance-check-repair", env)]
+    pub balance_check_repair: bool,
+
+    /// Enables the `pruneOwner` mutation that removes the coins, messages, and
+    /// transactions indexes of an owner. Requires the `--debug` flag.
+    #[clap(long = "graphql-allow-owner-pruning", env)]
+    pub allow_owner_pruning: bool,
+
+    /// Flags the spent coins in the index of the owned coins instead of removing
+    /// them, to avoid the tombstones slowing down the scans of the active owners.
+    /// The flagged coins are removed later by the background compaction.
+    #[clap(long = "graphql-mark-spent-owned-coins", env)]
+    pub mark_spent_owned_coins: bool,
+
+    /// The period between the runs of the compaction of the flagged owned coins.
+    #[clap(
+        long = "graphql-owned-coins-compaction-interval",
+        default_value = "1s",
+        env
+    )]
+    pub owned_coins_compaction_interval: humantime::Duration,
+
+    /// The number of blocks since the spending after which the flagged coin is removed.
+    #[clap(
+        long = "graphql-owned-coins-compaction-min-spent-age",
+        default_value = "1000",
+        env
+    )]
+    pub owned_coins_compaction_min_spent_age: u32,
+
+    /// The maximum number of the owned coins examined by one run of the compaction.
+    #[clap(
+        long = "graphql-owned-coins-compaction-entries-per-run",
+        default_value = "10000",
+        env
+    )]
+    pub owned_coins_compaction_entries_per_run: NonZeroUsize,
+
+    /// The layout of the keys of the owned coins used by the new database. The `sharded`
+    /// layout spreads the coins of one owner across the sub-prefixes. The existing
+    /// database keeps its layout, use `fuel-core migrate-owned-coins` to change it.
+    #[clap(
+        long = "graphql-owned-coins-layout",
+        default_value = "plain",
+        value_parser,
+        env
+    )]
+    pub owned_coins_layout: OwnedCoinsLayout,
+
+    /// The time after which the view pinned by the `snapshotView` query is released.
+    /// The queries passing the token of the released view fail.
+    #[clap(long = "graphql-snapshot-view-ttl", default_value = "30s", env)]
+    pub snapshot_view_ttl: humantime::Duration,
+
+    /// The maximum number of the views pinned by the `snapshotView` query at the same
+    /// time. Each pinned view holds the snapshot of the database. The zero value
+    /// disables the query.
+    #[clap(long = "graphql-max-snapshot-views", default_value = "64", env)]
+    pub max_snapshot_views: usize,
+
+    /// The maximum number of the active `balanceUpdated` subscriptions. Every
+    /// subscription is served by its own connection, so the limit bounds the
+    /// number of connections watching the balances. The zero value disables
+    /// the subscription.
+    #[clap(
+        long = "graphql-max-balance-subscriptions",
+        default_value = "1024",
+        env
+    )]
+    pub max_balance_subscriptions: usize,
+
+    /// The maximum size in bytes of the tag attached to the transaction by
+    /// the `tagTransaction` mutation. The zero value disables the mutation,
+    /// which is also available only with the `debug` feature enabled.
+    #[clap(long = "graphql-max-transaction-tag-size", default_value = "0", env)]
+    pub max_transaction_tag_size: usize,
+
+    /// If given, the `/v1/ready` endpoint reports the node as ready only once
+    /// the off-chain indexes lag behind the chain tip by at most the given
+    /// number of blocks. Otherwise, the node is ready once the API is serving.
+    #[clap(long = "graphql-ready-when-offchain-synced", env)]
+    pub ready_when_offchain_synced: Option<u32>,
+
+    /// The number of blocks the off-chain indexes or the DA compression may lag
+    /// behind the chain tip before the health report classifies them as degraded.
+    #[clap(long = "graphql-health-degraded-lag", default_value = "10", env)]
+    pub health_degraded_lag: u32,
+
+    /// The number of blocks the off-chain indexes or the DA compression may lag
+    /// behind the chain tip before the health report classifies them as failing.
+    #[clap(long = "graphql-health-failing-lag", default_value = "100", env)]
+    pub health_failing_lag: u32,
+
     /// The max depth of GraphQL queries.
     #[clap(long = "graphql-max-depth", default_value = "16", env)]
     pub graphql_max_depth: usize,
@@ -80,6 +213,33 @@ pub struct GraphQLArgs {
     )]
     pub assemble_tx_estimate_predicates_limit: usize,
 
+    /// The number of contracts whose info is cached by the GraphQL service.
+    /// The zero value disables the cache.
+    #[clap(
+        long = "graphql-contracts-info-cache-capacity",
+        default_value = "1024",
+        env
+    )]
+    pub contracts_info_cache_capacity: usize,
+
+    /// The number of message proofs cached in the off-chain database.
+    /// The zero value disables the cache.
+    #[clap(
+        long = "graphql-message-proof-cache-capacity",
+        default_value = "0",
+        env
+    )]
+    pub message_proof_cache_capacity: usize,
+
+    /// The number of blocks whose transaction Merkle trees are cached for
+    /// the `transactionInclusionProof` query. The zero value disables the cache.
+    #[clap(
+        long = "graphql-transaction-trees-cache-capacity",
+        default_value = "64",
+        env
+    )]
+    pub transaction_trees_cache_capacity: usize,
+
     /// Maximum allowed block lag for GraphQL fuel block height requests.
     /// The client waits for the node to catch up if it's behind by no more blocks than
     /// this tolerance.
@@ -99,10 +259,67 @@ pub struct GraphQLArgs {
     )]
     pub required_fuel_block_height_timeout: humantime::Duration,
 
+    #[clap(flatten)]
+    pub max_page_sizes: MaxPageSizes,
+
     #[clap(flatten)]
     pub costs: QueryCosts,
 }
 
+/// The maximum number of the entries requested with `first` or `last` by one page
+/// of the graphql queries keyed by the owner. The query requesting more is rejected
+/// with the error containing the limit.
+#[derive(Debug, Clone, clap::Args)]
+pub struct MaxPageSizes {
+    /// The maximum page size of the `transactionsByOwner` query.
+    #[clap(
+        long = "graphql-max-page-size-transactions-by-owner",
+        default_value = DEFAULT_MAX_PAGE_SIZE.to_string(),
+        env
+    )]
+    pub transactions_by_owner: usize,
+
+    /// The maximum page size of the `relayedTransactionsByOwner` query.
+    #[clap(
+        long = "graphql-max-page-size-relayed-transactions-by-owner",
+        default_value = DEFAULT_MAX_PAGE_SIZE.to_string(),
+        env
+    )]
+    pub relayed_transactions_by_owner: usize,
+
+    /// The maximum page size of the `coins` query.
+    #[clap(
+        long = "graphql-max-page-size-coins",
+        default_value = DEFAULT_MAX_PAGE_SIZE.to_string(),
+        env
+    )]
+    pub coins: usize,
+
+    /// The maximum page size of the `messages` query.
+    #[clap(
+        long = "graphql-max-page-size-messages",
+        default_value = DEFAULT_MAX_PAGE_SIZE.to_string(),
+        env
+    )]
+    pub messages: usize,
+
+    /// The maximum page size of the `balances` query.
+    #[clap(
+        long = "graphql-max-page-size-balances",
+        default_value = DEFAULT_MAX_PAGE_SIZE.to_string(),
+        env
+    )]
+    pub balances: usize,
+
+    /// The maximum page size of the `ownerActivity` query.
+    #[clap(
+        long = "graphql-max-page-size-owner-activity",
+        default_value = DEFAULT_MAX_PAGE_SIZE.to_string(),
+        env
+    )]
+    pub owner_activity: usize,
+}
+
 /// Costs for individual graphql queries.
 #[derive(Debug, Clone, clap::Args)]
 pub struct QueryCosts {
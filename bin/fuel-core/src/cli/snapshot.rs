@@ -75,6 +75,15 @@ pub enum Encoding {
         /// The number of entries to write per parquet group.
         #[clap(name = "GROUP_SIZE", long = "group-size", default_value = "10000")]
         group_size: usize,
+        /// The maximum serialized size in bytes of the entries in a parquet group.
+        /// The group is closed before reaching the `GROUP_SIZE` if its entries
+        /// are large.
+        #[clap(
+            name = "GROUP_BYTES",
+            long = "group-bytes",
+            default_value_t = fuel_core::service::genesis::DEFAULT_GROUP_BYTES
+        )]
+        group_bytes: usize,
         /// Level of compression. Valid values are 0..=12.
         #[clap(
             name = "COMPRESSION_LEVEL",
@@ -93,6 +102,14 @@ impl Encoding {
             Encoding::Parquet { group_size, .. } => Some(group_size),
         }
     }
+
+    fn group_bytes(self) -> Option<usize> {
+        match self {
+            Encoding::Json => None,
+            #[cfg(feature = "parquet")]
+            Encoding::Parquet { group_bytes, .. } => Some(group_bytes),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Subcommand)]
@@ -120,10 +137,30 @@ pub enum SubCommands {
         /// is provided.
         #[clap(name = "CHAIN_CONFIG", long = "chain")]
         chain_config: Option<PathBuf>,
+        /// Include the off-chain indexes(owned coins and messages, balances,
+        /// coins to spend) into the snapshot, so the regenesis doesn't need
+        /// to recalculate them. Only the parquet encoding supports them.
+        #[clap(long = "include-off-chain-indexes")]
+        include_off_chain_indexes: bool,
         /// Encoding format for the chain state files.
         #[clap(subcommand)]
         encoding_command: Option<EncodingCommand>,
     },
+    /// Creates a snapshot of the off-chain tables together with the off-chain indexes.
+    /// The tables are exported concurrently from the same view of the database.
+    OffChain {
+        /// Specify a path to the chain config. Defaults used if no path
+        /// is provided.
+        #[clap(name = "CHAIN_CONFIG", long = "chain")]
+        chain_config: Option<PathBuf>,
+        /// The number of tables exported at the same time.
+        #[clap(name = "THREADS", long = "threads", default_value = "4")]
+        threads: usize,
+        /// Encoding format for the chain state files. Only the parquet encoding
+        /// supports the off-chain indexes.
+        #[clap(subcommand)]
+        encoding_command: Option<EncodingCommand>,
+    },
     /// Creates a config for the contract.
     #[command(arg_required_else_help = true)]
     Contract {
@@ -141,7 +178,10 @@ fn get_default_max_fds() -> i32 {
 
 #[cfg(feature = "rocksdb")]
 pub async fn exec(command: Command) -> anyhow::Result<()> {
-    use fuel_core::service::genesis::Exporter;
+    use fuel_core::service::genesis::{
+        Exporter,
+        DEFAULT_GROUP_BYTES,
+    };
     use fuel_core_chain_config::{
         SnapshotWriter,
         MAX_GROUP_SIZE,
@@ -161,30 +201,58 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
         SubCommands::Everything {
             chain_config,
             encoding_command,
-            ..
+            include_off_chain_indexes,
         } => {
             let encoding = encoding_command
                 .map(|f| f.encoding())
                 .unwrap_or_else(|| Encoding::Json);
+            if include_off_chain_indexes && matches!(encoding, Encoding::Json) {
+                anyhow::bail!(
+                    "The off-chain indexes can be included only into the parquet snapshot"
+                );
+            }
 
             let group_size = encoding.group_size().unwrap_or(MAX_GROUP_SIZE);
-            let writer = move || match encoding {
-                Encoding::Json => Ok(SnapshotWriter::json(output_dir.clone())),
-                #[cfg(feature = "parquet")]
-                Encoding::Parquet { compression, .. } => {
-                    SnapshotWriter::parquet(output_dir.clone(), compression.try_into()?)
-                }
-            };
+            let group_bytes = encoding.group_bytes().unwrap_or(DEFAULT_GROUP_BYTES);
             Exporter::new(
                 db,
                 load_chain_config_or_use_testnet(chain_config.as_deref())?,
-                writer,
+                snapshot_writer(encoding, output_dir),
                 group_size,
                 shutdown_listener,
             )
+            .with_group_bytes(group_bytes)
+            .with_off_chain_indexes(include_off_chain_indexes)
             .write_full_snapshot()
             .await
         }
+        SubCommands::OffChain {
+            chain_config,
+            threads,
+            encoding_command,
+        } => {
+            let encoding = encoding_command
+                .map(|f| f.encoding())
+                .unwrap_or_else(|| Encoding::Json);
+            if matches!(encoding, Encoding::Json) {
+                anyhow::bail!(
+                    "The off-chain indexes can be included only into the parquet snapshot"
+                );
+            }
+
+            let group_size = encoding.group_size().unwrap_or(MAX_GROUP_SIZE);
+            let group_bytes = encoding.group_bytes().unwrap_or(DEFAULT_GROUP_BYTES);
+            Exporter::new(
+                db,
+                load_chain_config_or_use_testnet(chain_config.as_deref())?,
+                snapshot_writer(encoding, output_dir),
+                group_size,
+                shutdown_listener,
+            )
+            .with_group_bytes(group_bytes)
+            .export_offchain_snapshot_parallel(threads)
+            .await
+        }
         SubCommands::Contract { contract_id } => {
             let writer = move || Ok(SnapshotWriter::json(output_dir.clone()));
             Exporter::new(
@@ -200,6 +268,22 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
     }
 }
 
+#[cfg(feature = "rocksdb")]
+fn snapshot_writer(
+    encoding: Encoding,
+    output_dir: PathBuf,
+) -> impl Fn() -> anyhow::Result<fuel_core_chain_config::SnapshotWriter> {
+    use fuel_core_chain_config::SnapshotWriter;
+
+    move || match encoding {
+        Encoding::Json => Ok(SnapshotWriter::json(output_dir.clone())),
+        #[cfg(feature = "parquet")]
+        Encoding::Parquet { compression, .. } => {
+            SnapshotWriter::parquet(output_dir.clone(), compression.try_into()?)
+        }
+    }
+}
+
 fn load_chain_config_or_use_testnet(path: Option<&Path>) -> anyhow::Result<ChainConfig> {
     if let Some(path) = path {
         ChainConfig::load(path)
@@ -220,6 +304,7 @@ fn open_db(
             cache_capacity: Some(capacity.unwrap_or(1024 * 1024 * 1024)),
             max_fds,
             columns_policy: ColumnsPolicy::OnCreation,
+            allow_unknown_columns: false,
         },
     )
     .map_err(Into::<anyhow::Error>::into)
@@ -238,6 +323,7 @@ mod tests {
             TransactionStatuses,
         },
         producer::ports::BlockProducerDatabase,
+        service::genesis::DEFAULT_GROUP_BYTES,
     };
     use fuel_core_chain_config::{
         AddTable,
@@ -693,7 +779,7 @@ mod tests {
         }
     }
 
-    #[cfg_attr(feature = "parquet", test_case(Encoding::Parquet { group_size: 2, compression: 1 }; "parquet"))]
+    #[cfg_attr(feature = "parquet", test_case(Encoding::Parquet { group_size: 2, group_bytes: DEFAULT_GROUP_BYTES, compression: 1 }; "parquet"))]
     #[test_case(Encoding::Json; "json")]
     fn everything_snapshot_correct_and_sorted(encoding: Encoding) -> anyhow::Result<()> {
         use pretty_assertions::assert_eq;
@@ -717,6 +803,7 @@ mod tests {
             output_dir: snapshot_dir.clone(),
             subcommand: SubCommands::Everything {
                 chain_config: None,
+                include_off_chain_indexes: false,
                 encoding_command: Some(EncodingCommand::Encoding { encoding }),
             },
             rocksdb_max_fds: 512,
@@ -772,9 +859,11 @@ mod tests {
             max_database_cache_size: DEFAULT_DATABASE_CACHE_SIZE,
             subcommand: SubCommands::Everything {
                 chain_config: None,
+                include_off_chain_indexes: false,
                 encoding_command: Some(EncodingCommand::Encoding {
                     encoding: Encoding::Parquet {
                         group_size,
+                        group_bytes: DEFAULT_GROUP_BYTES,
                         compression: 1,
                     },
                 }),
@@ -797,6 +886,57 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn everything_snapshot_closes_groups_by_serialized_size() -> anyhow::Result<()> {
+        use fuel_core_chain_config::SnapshotReader;
+
+        // given
+        let temp_dir = tempfile::tempdir()?;
+
+        let snapshot_dir = temp_dir.path().join("snapshot");
+        let db_path = temp_dir.path().join("db");
+        let mut db =
+            DbPopulator::new(open_db(&db_path, None, 512)?, StdRng::seed_from_u64(2));
+
+        let state = db.given_persisted_data();
+        db.flush();
+
+        // when
+        let fut = exec(Command {
+            database_path: db_path,
+            output_dir: snapshot_dir.clone(),
+            max_database_cache_size: DEFAULT_DATABASE_CACHE_SIZE,
+            subcommand: SubCommands::Everything {
+                chain_config: None,
+                include_off_chain_indexes: false,
+                encoding_command: Some(EncodingCommand::Encoding {
+                    encoding: Encoding::Parquet {
+                        group_size: 10_000,
+                        // Any entry exceeds the limit, so it closes the group.
+                        group_bytes: 1,
+                        compression: 1,
+                    },
+                }),
+            },
+            rocksdb_max_fds: 512,
+        });
+
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(fut)
+            .unwrap();
+
+        // then
+        let snapshot = SnapshotMetadata::read(&snapshot_dir)?;
+        let mut reader = SnapshotReader::open(snapshot)?;
+
+        let expected_state = state.sorted();
+        assert_groups_as_expected(1, expected_state.common.coins, &mut reader);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn contract_snapshot_isolates_contract_correctly() -> anyhow::Result<()> {
         // given
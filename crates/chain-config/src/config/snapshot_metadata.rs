@@ -61,10 +61,41 @@ impl TableEncoding {
     }
 }
 
+/// The version of the snapshot format.
+#[derive(
+    Default,
+    Debug,
+    Clone,
+    Copy,
+    serde::Serialize,
+    serde::Deserialize,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+)]
+pub enum SnapshotVersion {
+    /// The snapshot contains the on-chain state and the off-chain tables
+    /// that can't be derived from it. Snapshots created before the version
+    /// was introduced don't have the field and are treated as `V1`.
+    #[default]
+    V1,
+    /// The snapshot additionally contains the off-chain indexes derived
+    /// from the on-chain state(owned coins and messages, balances, coins to spend).
+    V2,
+}
+
+impl SnapshotVersion {
+    /// The latest version of the snapshot format.
+    pub const LATEST: Self = Self::V2;
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
 pub struct SnapshotMetadata {
     pub chain_config: PathBuf,
     pub table_encoding: TableEncoding,
+    #[serde(default)]
+    pub version: SnapshotVersion,
 }
 
 impl SnapshotMetadata {
@@ -119,6 +150,7 @@ mod tests {
                 table_encoding: TableEncoding::Json {
                     filepath: "some_state_file.json".into(),
                 },
+                version: SnapshotVersion::LATEST,
             };
             serde_json::to_writer(
                 std::fs::File::create(dir.join("metadata.json")).unwrap(),
@@ -136,7 +168,8 @@ mod tests {
                     chain_config: dir.join("some_chain_config.json"),
                     table_encoding: TableEncoding::Json {
                         filepath: temp_dir.path().join("some_state_file.json"),
-                    }
+                    },
+                    version: SnapshotVersion::LATEST,
                 }
             );
         }
@@ -151,6 +184,7 @@ mod tests {
                 table_encoding: TableEncoding::Json {
                     filepath: dir.join("some_state_file.json"),
                 },
+                version: SnapshotVersion::LATEST,
             };
 
             // when
@@ -167,12 +201,38 @@ mod tests {
                     chain_config: "some_chain_config.json".into(),
                     table_encoding: TableEncoding::Json {
                         filepath: "some_state_file.json".into(),
-                    }
+                    },
+                    version: SnapshotVersion::LATEST,
                 }
             );
         }
     }
 
+    #[test]
+    fn metadata_without_version_is_read_as_v1() {
+        // given
+        let temp_dir = tempfile::tempdir().unwrap();
+        let json = serde_json::json!({
+            "chain_config": "some_chain_config.json",
+            "table_encoding": {
+                "Json": {
+                    "filepath": "some_state_file.json"
+                }
+            }
+        });
+        serde_json::to_writer(
+            std::fs::File::create(temp_dir.path().join("metadata.json")).unwrap(),
+            &json,
+        )
+        .unwrap();
+
+        // when
+        let snapshot = SnapshotMetadata::read(temp_dir.path()).unwrap();
+
+        // then
+        assert_eq!(snapshot.version, SnapshotVersion::V1);
+    }
+
     #[cfg(feature = "parquet")]
     mod parquet {
         use super::*;
@@ -190,6 +250,7 @@ mod tests {
                     )]),
                     latest_block_config_path: "latest_block_config.parquet".into(),
                 },
+                version: SnapshotVersion::LATEST,
             };
             serde_json::to_writer(
                 std::fs::File::create(dir.join("metadata.json")).unwrap(),
@@ -213,7 +274,8 @@ mod tests {
                         latest_block_config_path: temp_dir
                             .path()
                             .join("latest_block_config.parquet"),
-                    }
+                    },
+                    version: SnapshotVersion::LATEST,
                 }
             );
         }
@@ -232,6 +294,7 @@ mod tests {
                     )]),
                     latest_block_config_path: dir.join("latest_block_config.parquet"),
                 },
+                version: SnapshotVersion::LATEST,
             };
 
             // when
@@ -252,7 +315,8 @@ mod tests {
                             "coins.parquet".into(),
                         )]),
                         latest_block_config_path: "latest_block_config.parquet".into(),
-                    }
+                    },
+                    version: SnapshotVersion::LATEST,
                 }
             );
         }
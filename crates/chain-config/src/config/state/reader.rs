@@ -11,6 +11,7 @@ use crate::{
     AsTable,
     ChainConfig,
     LastBlockConfig,
+    SnapshotVersion,
     StateConfig,
     MAX_GROUP_SIZE,
 };
@@ -119,6 +120,7 @@ enum DataSource {
 pub struct SnapshotReader {
     chain_config: ChainConfig,
     data_source: DataSource,
+    version: SnapshotVersion,
 }
 
 impl SnapshotReader {
@@ -129,6 +131,7 @@ impl SnapshotReader {
                 state,
                 group_size: MAX_GROUP_SIZE,
             },
+            version: SnapshotVersion::V1,
         }
     }
 
@@ -176,6 +179,7 @@ impl SnapshotReader {
         Ok(Self {
             data_source: DataSource::InMemory { state, group_size },
             chain_config,
+            version: SnapshotVersion::V1,
         })
     }
 
@@ -192,6 +196,7 @@ impl SnapshotReader {
                 latest_block_config,
             },
             chain_config,
+            version: SnapshotVersion::V1,
         })
     }
 
@@ -227,8 +232,9 @@ impl SnapshotReader {
     ) -> anyhow::Result<Self> {
         use crate::TableEncoding;
         let chain_config = ChainConfig::from_snapshot_metadata(&snapshot_metadata)?;
+        let version = snapshot_metadata.version;
 
-        match snapshot_metadata.table_encoding {
+        let reader = match snapshot_metadata.table_encoding {
             TableEncoding::Json { filepath } => {
                 Self::json(filepath, chain_config, json_group_size)
             }
@@ -238,7 +244,8 @@ impl SnapshotReader {
                 latest_block_config_path,
                 ..
             } => Self::parquet(tables, latest_block_config_path, chain_config),
-        }
+        }?;
+        Ok(Self { version, ..reader })
     }
 
     pub fn read<T>(&self) -> anyhow::Result<Groups<T>>
@@ -289,6 +296,11 @@ impl SnapshotReader {
         &self.chain_config
     }
 
+    /// The version of the snapshot format from its metadata.
+    pub fn version(&self) -> SnapshotVersion {
+        self.version
+    }
+
     pub fn last_block_config(&self) -> Option<&LastBlockConfig> {
         match &self.data_source {
             DataSource::InMemory { state, .. } => state.last_block.as_ref(),
@@ -4,6 +4,7 @@ use crate::{
     ChainConfig,
     LastBlockConfig,
     SnapshotMetadata,
+    SnapshotVersion,
     StateConfigBuilder,
     TableEncoding,
 };
@@ -27,6 +28,7 @@ enum EncoderType {
 pub struct SnapshotWriter {
     dir: PathBuf,
     encoder: EncoderType,
+    version: SnapshotVersion,
 }
 
 #[allow(dead_code)]
@@ -198,11 +200,13 @@ impl FragmentData {
 pub struct SnapshotFragment {
     dir: PathBuf,
     data: FragmentData,
+    version: SnapshotVersion,
 }
 
 impl SnapshotFragment {
     pub fn merge(mut self, fragment: Self) -> anyhow::Result<Self> {
         self.data = self.data.merge(fragment.data)?;
+        self.version = self.version.max(fragment.version);
         Ok(self)
     }
 
@@ -247,6 +251,7 @@ impl SnapshotFragment {
             &self.dir,
             chain_config,
             table_encoding,
+            self.version,
         )
     }
 }
@@ -259,6 +264,7 @@ impl SnapshotWriter {
                 builder: StateConfigBuilder::default(),
             },
             dir: dir.into(),
+            version: SnapshotVersion::V1,
         }
     }
 
@@ -275,9 +281,17 @@ impl SnapshotWriter {
                 compression: compression_level,
             },
             dir,
+            version: SnapshotVersion::V1,
         })
     }
 
+    /// Sets the version recorded in the metadata of the snapshot. The snapshots
+    /// containing the off-chain indexes are marked with [`SnapshotVersion::V2`].
+    pub fn with_version(mut self, version: SnapshotVersion) -> Self {
+        self.version = version;
+        self
+    }
+
     #[cfg(feature = "test-helpers")]
     pub fn write_state_config(
         mut self,
@@ -351,6 +365,7 @@ impl SnapshotWriter {
         dir: &std::path::Path,
         chain_config: &ChainConfig,
         table_encoding: TableEncoding,
+        version: SnapshotVersion,
     ) -> anyhow::Result<SnapshotMetadata> {
         let chain_config_path = dir.join(Self::CHAIN_CONFIG_FILENAME);
         chain_config.write(&chain_config_path)?;
@@ -358,6 +373,7 @@ impl SnapshotWriter {
         let metadata = SnapshotMetadata {
             chain_config: chain_config_path,
             table_encoding,
+            version,
         };
         metadata.clone().write(dir)?;
         Ok(metadata)
@@ -382,6 +398,7 @@ impl SnapshotWriter {
         let snapshot_fragment = SnapshotFragment {
             dir: self.dir,
             data,
+            version: self.version,
         };
         Ok(snapshot_fragment)
     }
@@ -632,6 +649,30 @@ mod tests {
         }
     }
 
+    #[test_case::test_case(SnapshotVersion::V1)]
+    #[test_case::test_case(SnapshotVersion::V2)]
+    fn merged_fragments__record_the_version_of_the_writer(version: SnapshotVersion) {
+        // given
+        let dir = tempfile::tempdir().unwrap();
+        let fragment = SnapshotWriter::json(dir.path())
+            .with_version(version)
+            .partial_close()
+            .unwrap();
+        let table_fragment = SnapshotWriter::json(dir.path()).partial_close().unwrap();
+
+        // when
+        let snapshot = fragment
+            .merge(table_fragment)
+            .unwrap()
+            .finalize(None, &ChainConfig::local_testnet())
+            .unwrap();
+
+        // then
+        assert_eq!(snapshot.version, version);
+        let metadata = SnapshotMetadata::read(dir.path()).unwrap();
+        assert_eq!(metadata.version, version);
+    }
+
     // It is enough just for the test to compile
     #[test]
     #[ignore]
@@ -3,6 +3,12 @@ use crate::client::types::StatusWithTransaction;
 use crate::{
     client::{
         schema::{
+            account::{
+                AccountSummaryArgs,
+                IsKnownPredicateArgs,
+                OwnerActivityArgs,
+                PruneOwnerArgs,
+            },
             block::BlockByHeightArgs,
             coins::{
                 ExcludeInput,
@@ -11,16 +17,30 @@ use crate::{
             contract::ContractBalanceQueryArgs,
             gas_price::EstimateGasPrice,
             message::MessageStatusArgs,
-            relayed_tx::RelayedTransactionStatusArgs,
+            relayed_tx::{
+                RelayedTransactionStatusArgs,
+                RelayedTransactionsByOwnerConnectionArgs,
+            },
             tx::{
                 DryRunArg,
+                DryRunAtHeightArg,
                 TxWithEstimatedPredicatesArg,
             },
             Tai64Timestamp,
             TransactionId,
         },
         types::{
-            asset::AssetDetail,
+            account::{
+                AccountSummary,
+                OwnerActivityItem,
+                PrunedOwnerIndexes,
+            },
+            asset::{
+                AssetDetail,
+                AssetFirstSeen,
+                AssetSupplyChange,
+                ChainAsset,
+            },
             gas_price::LatestGasPrice,
             message::MessageStatus,
             primitives::{
@@ -31,6 +51,7 @@ use crate::{
                 UtxoId,
             },
             upgrades::StateTransitionBytecode,
+            RelayedTransaction,
             RelayedTransactionStatus,
         },
     },
@@ -94,19 +115,31 @@ use pagination::{
     PaginationRequest,
 };
 use schema::{
-    assets::AssetInfoArg,
+    assets::{
+        AssetFirstSeenArgs,
+        AssetInfoArg,
+        AssetSupplyHistoryArgs,
+        AssetsArgs,
+    },
     balance::BalanceArgs,
     blob::BlobByIdArgs,
     block::BlockByIdArgs,
     coins::{
         CoinByIdArgs,
+        CoinSpenderArgs,
         CoinsConnectionArgs,
+        SpentCoinsAtHeightArgs,
     },
     contract::{
         ContractBalancesConnectionArgs,
         ContractByIdArgs,
     },
-    da_compressed::DaCompressedBlockByHeightArgs,
+    da_compressed::{
+        DaCompressedBlockByHeightArgs,
+        DaCompressedBlockRegistrationsArgs,
+        DaCompressionGapsArgs,
+        DaCompressionMetricsArgs,
+    },
     gas_price::BlockHorizonArgs,
     storage_read_replay::{
         StorageReadReplay,
@@ -114,6 +147,8 @@ use schema::{
     },
     tx::{
         AssembleTxArg,
+        OldTransactionArgs,
+        TransactionsByContractConnectionArgs,
         TransactionsByOwnerConnectionArgs,
         TxArg,
         TxIdArgs,
@@ -252,6 +287,8 @@ pub struct FuelClient {
     url: reqwest::Url,
     require_height: ConsistencyPolicy,
     chain_state_info: ChainStateInfo,
+    /// The token of the pinned view used by the queries.
+    snapshot_token: Option<String>,
 }
 
 impl FromStr for FuelClient {
@@ -283,6 +320,7 @@ impl FromStr for FuelClient {
                     height: Arc::new(Mutex::new(None)),
                 },
                 chain_state_info: Default::default(),
+                snapshot_token: None,
             })
         }
 
@@ -296,6 +334,7 @@ impl FromStr for FuelClient {
                     height: Arc::new(Mutex::new(None)),
                 },
                 chain_state_info: Default::default(),
+                snapshot_token: None,
             })
         }
     }
@@ -352,6 +391,13 @@ impl FuelClient {
         self
     }
 
+    /// Makes the queries read from the view pinned by the [`FuelClient::snapshot_view`]
+    /// under the `token`. The mutations and subscriptions always read the latest state.
+    pub fn with_snapshot_token(&mut self, token: Option<String>) -> &mut Self {
+        self.snapshot_token = token;
+        self
+    }
+
     pub fn required_block_height(&self) -> Option<BlockHeight> {
         match &self.require_height {
             ConsistencyPolicy::Auto { height } => height.lock().ok().and_then(|h| *h),
@@ -414,7 +460,8 @@ impl FuelClient {
         ResponseData: serde::de::DeserializeOwned + 'static,
     {
         let required_fuel_block_height = self.required_block_height();
-        let fuel_operation = FuelOperation::new(q, required_fuel_block_height);
+        let fuel_operation = FuelOperation::new(q, required_fuel_block_height)
+            .with_snapshot_token(self.snapshot_token.clone());
         let response = self
             .client
             .post(self.url.clone())
@@ -606,11 +653,64 @@ impl FuelClient {
         self.query(query).await.map(|r| r.health)
     }
 
+    /// Pins the view of the node's database at the latest block height. The queries
+    /// of the client configured with the [`FuelClient::with_snapshot_token`] read
+    /// from the pinned view until it expires.
+    pub async fn snapshot_view(&self) -> io::Result<types::SnapshotView> {
+        let query = schema::QuerySnapshotView::build(());
+        self.query(query).await.map(|r| r.snapshot_view.into())
+    }
+
     pub async fn node_info(&self) -> io::Result<types::NodeInfo> {
         let query = schema::node_info::QueryNodeInfo::build(());
         self.query(query).await.map(|r| r.node_info.into())
     }
 
+    /// Returns the health of the subsystems of the node, classified by
+    /// the lag behind the chain tip.
+    pub async fn node_health(&self) -> io::Result<schema::node_info::NodeHealth> {
+        let query = schema::node_info::QueryNodeHealth::build(());
+        self.query(query).await.map(|r| r.node_health)
+    }
+
+    /// Returns the name and the id of each off-chain database column
+    /// available in the node's build. Requires the `debug` feature.
+    pub async fn offchain_columns(&self) -> io::Result<Vec<(String, u32)>> {
+        let query = schema::node_info::QueryOffChainColumns::build(());
+        self.query(query).await.map(|r| {
+            r.offchain_columns
+                .into_iter()
+                .map(|column| (column.name, column.id.into()))
+                .collect()
+        })
+    }
+
+    /// Returns the value of the statistic with the `name` tracked by
+    /// the off-chain database. Requires the `debug` feature.
+    pub async fn statistic(&self, name: &str) -> io::Result<Option<u64>> {
+        let args = schema::node_info::StatisticArgs {
+            name: name.to_string(),
+        };
+        let query = schema::node_info::QueryStatistic::build(args);
+        self.query(query).await.map(|r| r.statistic.map(Into::into))
+    }
+
+    /// Returns the names of the statistics available via the [`Self::statistic`].
+    /// Requires the `debug` feature.
+    pub async fn statistics(&self) -> io::Result<Vec<String>> {
+        let query = schema::node_info::QueryStatistics::build(());
+        self.query(query).await.map(|r| r.statistics)
+    }
+
+    /// Returns the hash of all entries of the off-chain database, used to compare
+    /// the indexes of the nodes at the same height. Requires the `debug` feature.
+    pub async fn offchain_state_hash(&self) -> io::Result<Bytes32> {
+        let query = schema::node_info::QueryOffChainStateHash::build(());
+        self.query(query)
+            .await
+            .map(|r| r.offchain_state_hash.into())
+    }
+
     pub async fn latest_gas_price(&self) -> io::Result<LatestGasPrice> {
         let query = schema::gas_price::QueryLatestGasPrice::build(());
         self.query(query).await.map(|r| r.latest_gas_price.into())
@@ -662,6 +762,35 @@ impl FuelClient {
         Ok(result)
     }
 
+    /// Returns the consensus parameters of the `version` indexed by the node,
+    /// including the versions from before regenesis.
+    pub async fn consensus_parameters_by_version(
+        &self,
+        version: i32,
+    ) -> io::Result<Option<ConsensusParameters>> {
+        let args = schema::upgrades::ConsensusParametersByVersionArgs { version };
+        let query =
+            schema::upgrades::IndexedConsensusParametersByVersionQuery::build(args);
+
+        let result = self
+            .query(query)
+            .await?
+            .consensus_parameters_by_version
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        Ok(result)
+    }
+
+    /// Returns the versions of the consensus parameters indexed by the node
+    /// in ascending order.
+    pub async fn consensus_parameters_versions(&self) -> io::Result<Vec<i32>> {
+        let query = schema::upgrades::ConsensusParametersVersionsQuery::build(());
+        self.query(query)
+            .await
+            .map(|r| r.consensus_parameters_versions)
+    }
+
     pub async fn state_transition_byte_code_by_version(
         &self,
         version: i32,
@@ -733,6 +862,31 @@ impl FuelClient {
             .collect()
     }
 
+    /// Dry run on top of the state after the block at the `at_height`.
+    /// Requires the node to retain the historical state at the `at_height`.
+    pub async fn dry_run_at_height(
+        &self,
+        txs: &[Transaction],
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+        gas_price: Option<u64>,
+        at_height: BlockHeight,
+    ) -> io::Result<types::DryRunAtHeight> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.to_bytes())))
+            .collect::<Vec<HexString>>();
+        let query: Operation<schema::tx::DryRunAtHeight, DryRunAtHeightArg> =
+            schema::tx::DryRunAtHeight::build(DryRunAtHeightArg {
+                txs,
+                utxo_validation,
+                gas_price: gas_price.map(|gp| gp.into()),
+                at_height: Some(at_height.into()),
+            });
+        let tx_statuses = self.query(query).await.map(|r| r.dry_run)?;
+        types::DryRunAtHeight::try_from_schema(at_height, tx_statuses).map_err(Into::into)
+    }
+
     /// Get storage read replay for a block
     pub async fn storage_read_replay(
         &self,
@@ -825,6 +979,25 @@ impl FuelClient {
         Ok(())
     }
 
+    /// Estimate predicates for the transaction and return the result of each
+    /// predicate input. The gas of the succeeded predicates is filled in the
+    /// transaction even if some predicates have failed.
+    pub async fn estimate_predicates_breakdown(
+        &self,
+        tx: &mut Transaction,
+    ) -> io::Result<Vec<types::PredicateEstimation>> {
+        let serialized_tx = tx.to_bytes();
+        let query = schema::tx::EstimatePredicatesBreakdown::build(TxArg {
+            tx: HexString(Bytes(serialized_tx)),
+        });
+        let estimation = self
+            .query(query)
+            .await
+            .map(|r| r.estimate_predicates_breakdown)?;
+        *tx = estimation.transaction.try_into()?;
+        Ok(estimation.predicates.into_iter().map(Into::into).collect())
+    }
+
     pub async fn submit(
         &self,
         tx: &Transaction,
@@ -1180,7 +1353,49 @@ impl FuelClient {
         Ok(transaction.map(|tx| tx.try_into()).transpose()?)
     }
 
+    /// Returns the transaction retained from before the regenesis.
+    pub async fn old_transaction(
+        &self,
+        id: &TxId,
+    ) -> io::Result<Option<types::PreRegenesisTransaction>> {
+        let query = schema::tx::OldTransactionQuery::build(OldTransactionArgs {
+            tx_id: (*id).into(),
+        });
+
+        let transaction = self.query(query).await?.old_transaction;
+
+        Ok(transaction.map(Into::into))
+    }
+
     /// Get the status of a transaction
+    /// Returns the proof that the transaction is included in its block.
+    /// Fails if the transaction is not included in any block.
+    pub async fn transaction_inclusion_proof(
+        &self,
+        id: &TxId,
+    ) -> io::Result<types::TransactionInclusionProof> {
+        let query = schema::tx::TransactionInclusionProofQuery::build(
+            schema::tx::TransactionInclusionProofArgs {
+                tx_id: (*id).into(),
+            },
+        );
+        let proof = self
+            .query(query)
+            .await?
+            .transaction_inclusion_proof
+            .try_into()?;
+        Ok(proof)
+    }
+
+    /// Returns whether each of the transactions is included in a block,
+    /// in the order of the `ids`.
+    pub async fn transactions_included(&self, ids: &[TxId]) -> io::Result<Vec<bool>> {
+        let query = schema::tx::TransactionsIncludedQuery::build(schema::tx::TxIdsArgs {
+            tx_ids: ids.iter().map(|id| (*id).into()).collect(),
+        });
+        self.query(query).await.map(|r| r.transactions_included)
+    }
+
     pub async fn transaction_status(&self, id: &TxId) -> io::Result<TransactionStatus> {
         let query =
             schema::tx::TransactionStatusQuery::build(TxIdArgs { id: (*id).into() });
@@ -1204,6 +1419,32 @@ impl FuelClient {
         Ok(status)
     }
 
+    /// Returns the tag attached to the transaction by the [`Self::tag_transaction`].
+    pub async fn transaction_tag(&self, id: &TxId) -> io::Result<Option<Vec<u8>>> {
+        let query = schema::tx::TransactionTagQuery::build(TxIdArgs { id: (*id).into() });
+
+        let tag = self
+            .query(query)
+            .await?
+            .transaction
+            .and_then(|transaction| transaction.tag)
+            .map(Into::into);
+        Ok(tag)
+    }
+
+    /// Attaches the `tag` to the transaction with a known status,
+    /// replacing the previous tag.
+    pub async fn tag_transaction(&self, id: &TxId, tag: Vec<u8>) -> io::Result<()> {
+        let query =
+            schema::tx::TagTransactionMutation::build(schema::tx::TagTransactionArgs {
+                id: (*id).into(),
+                tag: HexString(Bytes(tag)),
+            });
+
+        self.query(query).await?;
+        Ok(())
+    }
+
     #[tracing::instrument(skip(self), level = "debug")]
     #[cfg(feature = "subscriptions")]
     /// Subscribe to the status of a transaction
@@ -1281,6 +1522,94 @@ impl FuelClient {
         Ok(transactions)
     }
 
+    /// Returns a paginated set of the owner's transactions with the `status_filter`
+    /// and in the `direction`. The skipped transactions count towards the scan limit
+    /// of the node, so the page can end before it has the requested number of results,
+    /// or fail with the cursor to continue from if no transaction matched within the limit.
+    pub async fn filtered_transactions_by_owner(
+        &self,
+        owner: &Address,
+        status_filter: Option<schema::tx::TransactionStatusFilter>,
+        direction: schema::tx::TransactionDirection,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let owner: schema::Address = (*owner).into();
+        let args = TransactionsByOwnerConnectionArgs {
+            status_filter,
+            direction,
+            ..TransactionsByOwnerConnectionArgs::from((owner, request))
+        };
+        let query = schema::tx::TransactionsByOwnerQuery::build(args);
+
+        let transactions = self.query(query).await?.transactions_by_owner.try_into()?;
+        Ok(transactions)
+    }
+
+    /// Returns the transactions that reference the contract in their inputs or outputs.
+    pub async fn transactions_by_contract(
+        &self,
+        contract_id: &ContractId,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let contract_id: schema::ContractId = (*contract_id).into();
+        let args = TransactionsByContractConnectionArgs::from((contract_id, request));
+        let query = schema::tx::TransactionsByContractQuery::build(args);
+
+        let transactions = self
+            .query(query)
+            .await?
+            .transactions_by_contract
+            .try_into()?;
+        Ok(transactions)
+    }
+
+    /// Returns the transactions in the TxPool in the `sort` order. The first page
+    /// takes the snapshot of the TxPool, and the next pages are served from it.
+    /// Only the forward pagination is supported.
+    pub async fn pending_transactions(
+        &self,
+        sort: schema::tx::PendingTransactionsSort,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::PendingTransaction, String>> {
+        if request.direction == PageDirection::Backward {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "The backward pagination of the pending transactions is not supported",
+            ));
+        }
+        let query = schema::tx::PendingTransactionsQuery::build(
+            schema::tx::PendingTransactionsArgs {
+                after: request.cursor,
+                first: Some(request.results),
+                sort,
+            },
+        );
+
+        let pending = self.query(query).await?.pending_transactions.into();
+        Ok(pending)
+    }
+
+    /// Returns up to `limit` most active addresses with their number of transactions.
+    pub async fn top_addresses_by_tx_count(
+        &self,
+        limit: u32,
+    ) -> io::Result<Vec<(Address, u64)>> {
+        let query = schema::tx::TopAddressesByTxCountQuery::build(
+            schema::tx::TopAddressesByTxCountArgs {
+                limit: limit.into(),
+            },
+        );
+
+        let top_addresses = self
+            .query(query)
+            .await?
+            .top_addresses_by_tx_count
+            .into_iter()
+            .map(|entry| (entry.address.into(), entry.tx_count.into()))
+            .collect();
+        Ok(top_addresses)
+    }
+
     pub async fn receipts(&self, id: &TxId) -> io::Result<Option<Vec<Receipt>>> {
         let query =
             schema::tx::TransactionStatusQuery::build(TxIdArgs { id: (*id).into() });
@@ -1375,6 +1704,93 @@ impl FuelClient {
         Ok(block)
     }
 
+    /// Returns the block with the lowest height retained from before the regenesis.
+    pub async fn oldest_retained_block(
+        &self,
+    ) -> io::Result<Option<types::PreRegenesisBlock>> {
+        let query = schema::block::OldestRetainedBlockQuery::build(());
+
+        let block = self
+            .query(query)
+            .await?
+            .oldest_retained_block
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        Ok(block)
+    }
+
+    /// Returns the ids of the transactions of the block at the `height`,
+    /// or `None` if there is no such block.
+    pub async fn block_transaction_ids(
+        &self,
+        height: BlockHeight,
+    ) -> io::Result<Option<Vec<TxId>>> {
+        let query = schema::block::BlockTransactionIdsQuery::build(
+            schema::block::BlockTransactionIdsArgs {
+                height: U32(height.into()),
+            },
+        );
+
+        let tx_ids = self
+            .query(query)
+            .await?
+            .block_transaction_ids
+            .map(|ids| ids.into_iter().map(Into::into).collect());
+
+        Ok(tx_ids)
+    }
+
+    /// Returns the fees collected by the block at the `height`,
+    /// or `None` if there is no such block.
+    pub async fn fees_at_height(
+        &self,
+        height: BlockHeight,
+    ) -> io::Result<Option<types::CollectedFees>> {
+        let query =
+            schema::block::FeesAtHeightQuery::build(schema::block::FeesAtHeightArgs {
+                height: U32(height.into()),
+            });
+
+        Ok(self.query(query).await?.fees_at_height.map(Into::into))
+    }
+
+    /// Returns the total fees collected by all blocks indexed by the node.
+    pub async fn total_fees_collected(&self) -> io::Result<types::CollectedFees> {
+        let query = schema::block::TotalFeesCollectedQuery::build(());
+        Ok(self.query(query).await?.total_fees_collected.into())
+    }
+
+    /// Returns the last rollback of the node, or `None` if the node never rolled back.
+    pub async fn last_rollback(&self) -> io::Result<Option<types::ChainRollback>> {
+        let query = schema::block::LastRollbackQuery::build(());
+        Ok(self.query(query).await?.last_rollback.map(Into::into))
+    }
+
+    /// Returns the headers and the consensus of the blocks from the `start`
+    /// to the `end`(inclusive) without the transactions of the blocks.
+    pub async fn block_headers(
+        &self,
+        start: BlockHeight,
+        end: BlockHeight,
+    ) -> io::Result<Vec<types::SignedHeader>> {
+        let query =
+            schema::block::BlockHeadersQuery::build(schema::block::BlockHeadersArgs {
+                start: U32(start.into()),
+                end: U32(end.into()),
+            });
+
+        let headers = self
+            .query(query)
+            .await?
+            .block_headers
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+
+        Ok(headers)
+    }
+
     pub async fn da_compressed_block(
         &self,
         height: BlockHeight,
@@ -1392,6 +1808,115 @@ impl FuelClient {
             .map(|b| b.bytes.into()))
     }
 
+    /// Returns the proof of the inclusion of the DA compressed block at the `height`
+    /// in the Merkle tree of all compressed blocks. Only the nodes with the
+    /// `fault-proving` feature serve the proofs.
+    pub async fn da_compressed_block_proof(
+        &self,
+        height: BlockHeight,
+    ) -> io::Result<Option<schema::da_compressed::DaCompressedBlockProof>> {
+        let query = schema::da_compressed::DaCompressedBlockProofQuery::build(
+            DaCompressedBlockByHeightArgs {
+                height: U32(height.into()),
+            },
+        );
+
+        Ok(self.query(query).await?.da_compressed_block_proof)
+    }
+
+    /// Returns the new registrations in the temporal registry made by the DA
+    /// compressed block at the `height`, or `None` if the block was compressed
+    /// without them.
+    pub async fn da_compressed_block_registrations(
+        &self,
+        height: BlockHeight,
+        request: PaginationRequest<String>,
+    ) -> io::Result<
+        Option<PaginatedResult<schema::da_compressed::DaBlockRegistration, String>>,
+    > {
+        if request.direction == PageDirection::Backward {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "The backward pagination of the registrations is not supported",
+            ));
+        }
+        let args =
+            DaCompressedBlockRegistrationsArgs::from((U32(height.into()), request));
+        let query =
+            schema::da_compressed::DaCompressedBlockRegistrationsQuery::build(args);
+
+        Ok(self
+            .query(query)
+            .await?
+            .da_compressed_block_registrations
+            .map(Into::into))
+    }
+
+    /// Returns the heights from the `start` to the `end`(inclusive)
+    /// without a DA compressed block.
+    pub async fn da_compression_gaps(
+        &self,
+        start: BlockHeight,
+        end: BlockHeight,
+    ) -> io::Result<Vec<BlockHeight>> {
+        let query =
+            schema::da_compressed::DaCompressionGapsQuery::build(DaCompressionGapsArgs {
+                start: U32(start.into()),
+                end: U32(end.into()),
+            });
+
+        Ok(self
+            .query(query)
+            .await?
+            .da_compression_gaps
+            .into_iter()
+            .map(|height| height.0.into())
+            .collect())
+    }
+
+    /// Returns the compression statistics of the `last` DA compressed blocks,
+    /// starting from the latest one.
+    pub async fn da_compression_metrics(
+        &self,
+        last: u32,
+    ) -> io::Result<Vec<schema::da_compressed::DaCompressionMetrics>> {
+        let query = schema::da_compressed::DaCompressionMetricsQuery::build(
+            DaCompressionMetricsArgs { last: U32(last) },
+        );
+
+        Ok(self.query(query).await?.da_compression_metrics)
+    }
+
+    /// Recomputes the checksum of the DA compressed block at the `height` and
+    /// compares it with the stored one. Requires the node to run in debug mode.
+    pub async fn verify_da_block(
+        &self,
+        height: BlockHeight,
+    ) -> io::Result<Option<schema::da_compressed::DaBlockVerification>> {
+        let query = schema::da_compressed::VerifyDaBlockQuery::build(
+            DaCompressedBlockByHeightArgs {
+                height: U32(height.into()),
+            },
+        );
+
+        Ok(self.query(query).await?.verify_da_block)
+    }
+
+    /// Returns the DA compressed block at the `height` decoded into
+    /// the human-readable form.
+    pub async fn decompressed_da_block(
+        &self,
+        height: BlockHeight,
+    ) -> io::Result<Option<schema::da_compressed::DecompressedDaBlock>> {
+        let query = schema::da_compressed::DecompressedDaBlockQuery::build(
+            DaCompressedBlockByHeightArgs {
+                height: U32(height.into()),
+            },
+        );
+
+        Ok(self.query(query).await?.decompressed_da_block)
+    }
+
     /// Retrieve a blob by its ID
     pub async fn blob(&self, id: BlobId) -> io::Result<Option<types::Blob>> {
         let query = schema::blob::BlobByIdQuery::build(BlobByIdArgs { id: id.into() });
@@ -1418,6 +1943,28 @@ impl FuelClient {
         Ok(blocks)
     }
 
+    /// Returns the headers of the blocks produced by the `producer`.
+    /// Only the forward pagination is supported.
+    pub async fn blocks_by_producer(
+        &self,
+        producer: &Address,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::block::Header, String>> {
+        if request.direction == PageDirection::Backward {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "The backward pagination of the blocks by producer is not supported",
+            ));
+        }
+        let producer: schema::Address = (*producer).into();
+        let args = schema::block::BlocksByProducerArgs::from((producer, request));
+        let query = schema::block::BlocksByProducerQuery::build(args);
+
+        let headers = self.query(query).await?.blocks_by_producer.try_into()?;
+
+        Ok(headers)
+    }
+
     pub async fn coin(&self, id: &UtxoId) -> io::Result<Option<types::Coin>> {
         let query = schema::coins::CoinByIdQuery::build(CoinByIdArgs {
             utxo_id: (*id).into(),
@@ -1426,6 +1973,38 @@ impl FuelClient {
         Ok(coin)
     }
 
+    /// Retrieve the transaction that spent the coin, if it was spent.
+    pub async fn coin_spender(
+        &self,
+        id: &UtxoId,
+    ) -> io::Result<Option<types::CoinSpender>> {
+        let query = schema::coins::CoinSpenderQuery::build(CoinSpenderArgs {
+            utxo_id: (*id).into(),
+        });
+        let spender = self.query(query).await?.coin_spender.map(Into::into);
+        Ok(spender)
+    }
+
+    /// Retrieve the ids of the coins spent by the block at the `height`,
+    /// in the order of the inputs of its transactions.
+    pub async fn spent_coins_at_height(
+        &self,
+        height: BlockHeight,
+    ) -> io::Result<Vec<UtxoId>> {
+        let query =
+            schema::coins::SpentCoinsAtHeightQuery::build(SpentCoinsAtHeightArgs {
+                height: U32(height.into()),
+            });
+        let utxo_ids = self
+            .query(query)
+            .await?
+            .spent_coins_at_height
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        Ok(utxo_ids)
+    }
+
     /// Retrieve a page of coins by their owner
     pub async fn coins(
         &self,
@@ -1445,6 +2024,154 @@ impl FuelClient {
         Ok(coins)
     }
 
+    /// Retrieve the number of unspent coins of the `owner` with the `asset_id`
+    pub async fn coin_count_by_owner_asset(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> io::Result<u64> {
+        let query = schema::coins::CoinCountByOwnerAssetQuery::build(
+            schema::coins::CoinCountByOwnerAssetArgs {
+                owner: (*owner).into(),
+                asset_id: (*asset_id).into(),
+            },
+        );
+
+        let count = self.query(query).await?.coin_count_by_owner_asset;
+        Ok(count.into())
+    }
+
+    /// Returns `true` if the `owner` has any spendable coins with the `asset_id`,
+    /// without selecting them like the [`Self::coins_to_spend`].
+    pub async fn has_spendable_coins(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> io::Result<bool> {
+        let query = schema::coins::HasSpendableCoinsQuery::build(
+            schema::coins::HasSpendableCoinsArgs {
+                owner: (*owner).into(),
+                asset_id: (*asset_id).into(),
+            },
+        );
+
+        let has_coins = self.query(query).await?.has_spendable_coins;
+        Ok(has_coins)
+    }
+
+    /// Streams the blocks indexed by the node after the subscription. If the stream
+    /// is read slower than the blocks are indexed, the dropped blocks are replaced
+    /// with the [`schema::block::BlockIndexedEvent::BlockIndexedGap`] marker.
+    #[cfg(feature = "subscriptions")]
+    pub async fn block_indexed_subscription(
+        &self,
+    ) -> io::Result<impl Stream<Item = io::Result<schema::block::BlockIndexedEvent>> + '_>
+    {
+        use cynic::SubscriptionBuilder;
+        let s = schema::block::BlockIndexedSubscription::build(());
+
+        let stream = self.subscribe(s).await?.map(
+            |result: io::Result<schema::block::BlockIndexedSubscription>| {
+                Result::<_, io::Error>::Ok(result?.block_indexed)
+            },
+        );
+
+        Ok(stream)
+    }
+
+    /// Streams the rollbacks of the node after the subscription. The rollbacks
+    /// missed by the stream can be detected with [`Self::last_rollback`].
+    #[cfg(feature = "subscriptions")]
+    pub async fn chain_rollback_subscription(
+        &self,
+    ) -> io::Result<impl Stream<Item = io::Result<types::ChainRollback>> + '_> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::block::ChainRollbackSubscription::build(());
+
+        let stream = self.subscribe(s).await?.map(
+            |result: io::Result<schema::block::ChainRollbackSubscription>| {
+                Result::<_, io::Error>::Ok(result?.chain_rollback.into())
+            },
+        );
+
+        Ok(stream)
+    }
+
+    /// Streams the blocks indexed by the node after the subscription together with
+    /// the data requested by `include`. If the stream is read slower than the blocks
+    /// are indexed, the node returns an error and closes the stream.
+    #[cfg(feature = "subscriptions")]
+    pub async fn blocks_with_data_subscription(
+        &self,
+        include: schema::block::BlockDataInclusion,
+    ) -> io::Result<impl Stream<Item = io::Result<schema::block::BlockWithData>> + '_>
+    {
+        use cynic::SubscriptionBuilder;
+        use schema::block::BlocksWithDataArgs;
+        let s = schema::block::BlocksWithDataSubscription::build(BlocksWithDataArgs {
+            include: Some(include),
+        });
+
+        let stream = self.subscribe(s).await?.map(
+            |result: io::Result<schema::block::BlocksWithDataSubscription>| {
+                Result::<_, io::Error>::Ok(result?.blocks_with_data)
+            },
+        );
+
+        Ok(stream)
+    }
+
+    /// Streams all unspent coins of the `owner` in batches of at most `batch_size` coins.
+    #[cfg(feature = "subscriptions")]
+    pub async fn stream_owned_coins<'a>(
+        &'a self,
+        owner: &Address,
+        batch_size: Option<u32>,
+    ) -> io::Result<impl Stream<Item = io::Result<Vec<types::Coin>>> + 'a> {
+        use cynic::SubscriptionBuilder;
+        use schema::coins::StreamOwnedCoinsArgs;
+        let s = schema::coins::StreamOwnedCoins::build(StreamOwnedCoinsArgs {
+            owner: (*owner).into(),
+            batch_size: batch_size.map(Into::into),
+        });
+
+        let stream = self.subscribe(s).await?.map(
+            |result: io::Result<schema::coins::StreamOwnedCoins>| {
+                let coins = result?
+                    .stream_owned_coins
+                    .into_iter()
+                    .map(Into::into)
+                    .collect();
+                Result::<_, io::Error>::Ok(coins)
+            },
+        );
+
+        Ok(stream)
+    }
+
+    /// Streams the balance of the `owner` in the `asset_id` after each block
+    /// that modifies it. The modifications within one block are reported as one update.
+    #[cfg(feature = "subscriptions")]
+    pub async fn balance_updated_subscription(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> io::Result<impl Stream<Item = io::Result<types::BalanceUpdate>> + '_> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::balance::BalanceUpdatedSubscription::build(BalanceArgs {
+            owner: (*owner).into(),
+            asset_id: (*asset_id).into(),
+        });
+
+        let stream = self.subscribe(s).await?.map(
+            |result: io::Result<schema::balance::BalanceUpdatedSubscription>| {
+                Result::<_, io::Error>::Ok(result?.balance_updated.into())
+            },
+        );
+
+        Ok(stream)
+    }
+
     /// Retrieve coins to spend in a transaction
     pub async fn coins_to_spend(
         &self,
@@ -1479,6 +2206,65 @@ impl FuelClient {
         Ok(coins_per_asset)
     }
 
+    /// Suggests the smallest coins of the `owner` with the `asset_id` below
+    /// the dust threshold to combine into one coin.
+    pub async fn consolidation_suggestion(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        dust_threshold: Option<u64>,
+        max_inputs: Option<u16>,
+    ) -> io::Result<types::ConsolidationSuggestion> {
+        let query = schema::coins::ConsolidationSuggestionQuery::build(
+            schema::coins::ConsolidationSuggestionArgs {
+                owner: (*owner).into(),
+                asset_id: (*asset_id).into(),
+                dust_threshold: dust_threshold.map(Into::into),
+                max_inputs: max_inputs.map(Into::into),
+            },
+        );
+
+        let suggestion = self.query(query).await?.consolidation_suggestion.into();
+        Ok(suggestion)
+    }
+
+    /// Returns at most `limit` coins of the `owner` from the owned coins index
+    /// that don't exist in the on-chain state. The node must run with the `debug` mode.
+    pub async fn find_orphaned_owned_coins(
+        &self,
+        owner: &Address,
+        limit: Option<u32>,
+    ) -> io::Result<Vec<UtxoId>> {
+        let query = schema::coins::FindOrphanedOwnedCoinsQuery::build(
+            schema::coins::FindOrphanedOwnedCoinsArgs {
+                owner: (*owner).into(),
+                limit: limit.map(Into::into),
+            },
+        );
+
+        let coins = self.query(query).await?.find_orphaned_owned_coins;
+        Ok(coins)
+    }
+
+    /// Removes at most `limit` orphaned coins of the `owner` from the owned coins
+    /// index and returns their ids, with whether more orphaned coins remain.
+    /// The node must run with the `debug` mode.
+    pub async fn repair_orphaned_owned_coins(
+        &self,
+        owner: &Address,
+        limit: Option<u32>,
+    ) -> io::Result<schema::coins::RepairedOrphanedOwnedCoins> {
+        let query = schema::coins::RepairOrphanedOwnedCoinsMutation::build(
+            schema::coins::RepairOrphanedOwnedCoinsArgs {
+                owner: (*owner).into(),
+                limit: limit.map(Into::into),
+            },
+        );
+
+        let coins = self.query(query).await?.repair_orphaned_owned_coins;
+        Ok(coins)
+    }
+
     pub async fn contract(&self, id: &ContractId) -> io::Result<Option<types::Contract>> {
         let query = schema::contract::ContractByIdQuery::build(ContractByIdArgs {
             id: (*id).into(),
@@ -1523,6 +2309,42 @@ impl FuelClient {
         Ok(balance.amount.try_into().unwrap_or(u64::MAX))
     }
 
+    /// Returns the total value of the coins of the `owner` in the `asset_id`,
+    /// without the messages.
+    pub async fn owned_coin_value(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> io::Result<u128> {
+        let query = schema::balance::OwnedCoinValueQuery::build(BalanceArgs {
+            owner: (*owner).into(),
+            asset_id: (*asset_id).into(),
+        });
+        self.query(query).await.map(|r| r.owned_coin_value.into())
+    }
+
+    /// Returns the net change of the balance of the `owner` from the block at
+    /// `from_height` to the block at `to_height`.
+    pub async fn balance_delta(
+        &self,
+        owner: &Address,
+        asset_id: Option<&AssetId>,
+        from_height: BlockHeight,
+        to_height: BlockHeight,
+    ) -> io::Result<i128> {
+        let args = schema::balance::BalanceDeltaArgs {
+            owner: (*owner).into(),
+            asset_id: match asset_id {
+                Some(asset_id) => (*asset_id).into(),
+                None => schema::AssetId::default(),
+            },
+            from_height: from_height.into(),
+            to_height: to_height.into(),
+        };
+        let query = schema::balance::BalanceDeltaQuery::build(args);
+        self.query(query).await.map(|r| r.balance_delta.into())
+    }
+
     // Retrieve a page of balances by their owner
     pub async fn balances(
         &self,
@@ -1551,6 +2373,28 @@ impl FuelClient {
         Ok(balances)
     }
 
+    /// Returns the contracts deployed within the blocks from `start_height`
+    /// to `end_height`, both inclusive. The unset bounds are not limited.
+    pub async fn contracts(
+        &self,
+        start_height: Option<BlockHeight>,
+        end_height: Option<BlockHeight>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::DeployedContract, String>> {
+        let start_height = start_height.map(|height| (*height).into());
+        let end_height = end_height.map(|height| (*height).into());
+        let args = schema::contract::ContractsConnectionArgs::from((
+            start_height,
+            end_height,
+            request,
+        ));
+        let query = schema::contract::ContractsQuery::build(args);
+
+        let contracts = self.query(query).await?.contracts.into();
+
+        Ok(contracts)
+    }
+
     // Retrieve a message by its nonce
     pub async fn message(&self, nonce: &Nonce) -> io::Result<Option<types::Message>> {
         let query = schema::message::MessageQuery::build(NonceArgs {
@@ -1574,6 +2418,47 @@ impl FuelClient {
         Ok(messages)
     }
 
+    /// Returns the messages of the `owner`, including the spent ones
+    /// with their `spent_status`.
+    pub async fn messages_including_spent(
+        &self,
+        owner: &Address,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::Message, String>> {
+        let owner: schema::Address = (*owner).into();
+        let mut args =
+            schema::message::OwnedMessagesConnectionArgs::from((Some(owner), request));
+        args.include_spent = Some(true);
+        let query = schema::message::OwnedMessageQuery::build(args);
+
+        let messages = self.query(query).await?.messages.into();
+
+        Ok(messages)
+    }
+
+    /// Returns the unspent messages sent by the `sender` on the L1.
+    /// Only the forward pagination is supported.
+    pub async fn messages_by_sender(
+        &self,
+        sender: &Address,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::Message, String>> {
+        if request.direction == PageDirection::Backward {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "The backward pagination of the messages by sender is not supported",
+            ));
+        }
+        let sender: schema::Address = (*sender).into();
+        let args =
+            schema::message::MessagesBySenderConnectionArgs::from((sender, request));
+        let query = schema::message::MessagesBySenderQuery::build(args);
+
+        let messages = self.query(query).await?.messages_by_sender.into();
+
+        Ok(messages)
+    }
+
     pub async fn contract_info(
         &self,
         contract: &ContractId,
@@ -1594,6 +2479,14 @@ impl FuelClient {
         Ok(status)
     }
 
+    /// Returns the total number of messages spent on the chain.
+    pub async fn spent_message_count(&self) -> io::Result<u64> {
+        let query = schema::message::SpentMessageCountQuery::build(());
+        let count = self.query(query).await?.spent_message_count.into();
+
+        Ok(count)
+    }
+
     /// Request a merkle proof of an output message.
     pub async fn message_proof(
         &self,
@@ -1635,6 +2528,46 @@ impl FuelClient {
         Ok(status)
     }
 
+    /// Returns the failed relayed transactions that spend inputs of the `owner`.
+    pub async fn relayed_transactions_by_owner(
+        &self,
+        owner: &Address,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<RelayedTransaction, String>> {
+        let owner: schema::Address = (*owner).into();
+        let args = RelayedTransactionsByOwnerConnectionArgs::from((owner, request));
+        let query = schema::relayed_tx::RelayedTransactionsByOwnerQuery::build(args);
+
+        let relayed_txs = self
+            .query(query)
+            .await?
+            .relayed_transactions_by_owner
+            .try_into()?;
+
+        Ok(relayed_txs)
+    }
+
+    /// Returns the assets minted on the chain in the order of asset ids.
+    /// With `include_base_asset`, the base asset without the details goes first.
+    /// Only the forward pagination is supported.
+    pub async fn assets(
+        &self,
+        request: PaginationRequest<String>,
+        include_base_asset: bool,
+    ) -> io::Result<PaginatedResult<ChainAsset, String>> {
+        if request.direction == PageDirection::Backward {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "The backward pagination of the assets is not supported",
+            ));
+        }
+        let args = AssetsArgs::from((request, include_base_asset));
+        let query = schema::assets::AssetsQuery::build(args);
+
+        let assets = self.query(query).await?.assets.into();
+        Ok(assets)
+    }
+
     pub async fn asset_info(&self, asset_id: &AssetId) -> io::Result<AssetDetail> {
         let query = schema::assets::AssetInfoQuery::build(AssetInfoArg {
             id: (*asset_id).into(),
@@ -1642,6 +2575,111 @@ impl FuelClient {
         let asset_info = self.query(query).await?.asset_details.into();
         Ok(asset_info)
     }
+
+    /// Returns the block where the asset was seen for the first time,
+    /// or `None` if the asset was never seen.
+    pub async fn asset_first_seen(
+        &self,
+        asset_id: &AssetId,
+    ) -> io::Result<Option<AssetFirstSeen>> {
+        let query = schema::assets::AssetFirstSeenQuery::build(AssetFirstSeenArgs {
+            asset_id: (*asset_id).into(),
+        });
+        let first_seen = self.query(query).await?.asset_first_seen.map(Into::into);
+        Ok(first_seen)
+    }
+
+    /// Returns the changes of the total supply of the asset per block.
+    /// Only the forward pagination is supported.
+    pub async fn asset_supply_history(
+        &self,
+        asset_id: &AssetId,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<AssetSupplyChange, String>> {
+        if request.direction == PageDirection::Backward {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "The backward pagination of the asset supply history is not supported",
+            ));
+        }
+        let asset_id: schema::AssetId = (*asset_id).into();
+        let args = AssetSupplyHistoryArgs::from((asset_id, request));
+        let query = schema::assets::AssetSupplyHistoryQuery::build(args);
+
+        let history = self.query(query).await?.asset_supply_history.into();
+        Ok(history)
+    }
+
+    /// Returns the summary of the activity of the `owner`,
+    /// or `None` if the `owner` was never active.
+    pub async fn account_summary(
+        &self,
+        owner: &Address,
+    ) -> io::Result<Option<AccountSummary>> {
+        let query = schema::account::AccountSummaryQuery::build(AccountSummaryArgs {
+            owner: (*owner).into(),
+        });
+        let summary = self.query(query).await?.account_summary.map(Into::into);
+        Ok(summary)
+    }
+
+    /// Removes the off-chain indexes of the `owner`. The node must run with
+    /// the `debug` mode and the owner pruning enabled. The on-chain state is
+    /// untouched, so re-indexing the chain restores the removed entries.
+    pub async fn prune_owner(&self, owner: &Address) -> io::Result<PrunedOwnerIndexes> {
+        let query = schema::account::PruneOwnerMutation::build(PruneOwnerArgs {
+            owner: (*owner).into(),
+            confirm: (*owner).into(),
+        });
+        let pruned = self.query(query).await?.prune_owner.into();
+        Ok(pruned)
+    }
+
+    /// Returns whether the `address` is known to be a predicate. The owner
+    /// is classified only after it spends for the first time.
+    pub async fn is_known_predicate(
+        &self,
+        address: &Address,
+    ) -> io::Result<schema::account::OwnerKind> {
+        let query = schema::account::IsKnownPredicateQuery::build(IsKnownPredicateArgs {
+            address: (*address).into(),
+        });
+        Ok(self.query(query).await?.is_known_predicate)
+    }
+
+    /// Returns the height of the last block where the `owner` was active,
+    /// or `None` if the `owner` was never active.
+    pub async fn owner_last_activity(
+        &self,
+        owner: &Address,
+    ) -> io::Result<Option<BlockHeight>> {
+        let query = schema::account::OwnerLastActivityQuery::build(AccountSummaryArgs {
+            owner: (*owner).into(),
+        });
+        let height = self.query(query).await?.owner_last_activity.map(Into::into);
+        Ok(height)
+    }
+
+    /// Returns the coins, messages, and transactions of the `owner` as one feed.
+    /// Only the forward pagination is supported.
+    pub async fn owner_activity(
+        &self,
+        owner: &Address,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<OwnerActivityItem, String>> {
+        if request.direction == PageDirection::Backward {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "The backward pagination of the owner activity is not supported",
+            ));
+        }
+        let owner: schema::Address = (*owner).into();
+        let args = OwnerActivityArgs::from((owner, request));
+        let query = schema::account::OwnerActivityQuery::build(args);
+
+        let feed = self.query(query).await?.owner_activity.try_into()?;
+        Ok(feed)
+    }
 }
 
 #[cfg(any(test, feature = "test-helpers"))]
@@ -26,6 +26,7 @@ use crate::client::pagination::{
 };
 pub use primitives::*;
 
+pub mod account;
 pub mod assets;
 pub mod balance;
 pub mod blob;
@@ -52,6 +53,20 @@ pub struct Health {
     pub health: bool,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct SnapshotView {
+    pub token: String,
+    pub height: U32,
+    pub expires_in_seconds: U64,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QuerySnapshotView {
+    pub snapshot_view: SnapshotView,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Mutation")]
 pub struct StartSession {
@@ -0,0 +1,156 @@
+use crate::client::{
+    schema::{
+        coins::Coin,
+        message::Message,
+        schema,
+        tx::OpaqueTransaction,
+        Address,
+        PageInfo,
+        U32,
+        U64,
+    },
+    PaginationRequest,
+};
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct AccountSummaryArgs {
+    pub owner: Address,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "AccountSummaryArgs"
+)]
+pub struct AccountSummaryQuery {
+    #[arguments(owner: $owner)]
+    pub account_summary: Option<AccountSummary>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AccountSummary {
+    pub owner: Address,
+    pub first_seen_block: U32,
+    pub last_active_block: U32,
+    pub total_transactions: U64,
+    pub coin_count: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct IsKnownPredicateArgs {
+    pub address: Address,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "IsKnownPredicateArgs"
+)]
+pub struct IsKnownPredicateQuery {
+    #[arguments(address: $address)]
+    pub is_known_predicate: OwnerKind,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "AccountSummaryArgs"
+)]
+pub struct OwnerLastActivityQuery {
+    #[arguments(owner: $owner)]
+    pub owner_last_activity: Option<U32>,
+}
+
+/// The kind of the owner, known only after it spends for the first time.
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum OwnerKind {
+    Unknown,
+    Predicate,
+    Account,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct OwnerActivityArgs {
+    pub owner: Address,
+    /// Skip until cursor
+    pub after: Option<String>,
+    /// Retrieve the first n entries of the feed
+    pub first: Option<i32>,
+}
+
+impl From<(Address, PaginationRequest<String>)> for OwnerActivityArgs {
+    fn from(r: (Address, PaginationRequest<String>)) -> Self {
+        OwnerActivityArgs {
+            owner: r.0,
+            after: r.1.cursor,
+            first: Some(r.1.results),
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "OwnerActivityArgs"
+)]
+pub struct OwnerActivityQuery {
+    #[arguments(owner: $owner, after: $after, first: $first)]
+    pub owner_activity: OwnerActivityItemConnection,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct OwnerActivityItemConnection {
+    pub edges: Vec<OwnerActivityItemEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct OwnerActivityItemEdge {
+    pub cursor: String,
+    pub node: OwnerActivityItem,
+}
+
+#[derive(cynic::InlineFragments, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum OwnerActivityItem {
+    Coin(Coin),
+    Message(Message),
+    Transaction(OpaqueTransaction),
+    #[cynic(fallback)]
+    Unknown,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct PruneOwnerArgs {
+    pub owner: Address,
+    pub confirm: Address,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "PruneOwnerArgs"
+)]
+pub struct PruneOwnerMutation {
+    #[arguments(owner: $owner, confirm: $confirm)]
+    pub prune_owner: PrunedOwnerIndexes,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PrunedOwnerIndexes {
+    pub owned_coins: U64,
+    pub owned_message_ids: U64,
+    pub owned_transactions: U64,
+    pub owned_coin_counts: U64,
+    pub owner_activities: U64,
+}
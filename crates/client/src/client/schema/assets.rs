@@ -1,11 +1,71 @@
-use crate::client::schema::{
-    schema,
-    AssetId,
-    ContractId,
-    SubId,
-    U128,
+use crate::client::{
+    schema::{
+        schema,
+        AssetId,
+        ContractId,
+        PageInfo,
+        SubId,
+        Tai64Timestamp,
+        I128,
+        U128,
+        U32,
+    },
+    PaginationRequest,
 };
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct AssetsArgs {
+    /// Skip until cursor
+    pub after: Option<String>,
+    /// Retrieve the first n assets
+    pub first: Option<i32>,
+    pub include_base_asset: Option<bool>,
+}
+
+impl From<(PaginationRequest<String>, bool)> for AssetsArgs {
+    fn from((request, include_base_asset): (PaginationRequest<String>, bool)) -> Self {
+        AssetsArgs {
+            after: request.cursor,
+            first: Some(request.results),
+            include_base_asset: Some(include_base_asset),
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "AssetsArgs"
+)]
+pub struct AssetsQuery {
+    #[arguments(after: $after, first: $first, includeBaseAsset: $include_base_asset)]
+    pub assets: ChainAssetConnection,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ChainAssetConnection {
+    pub edges: Vec<ChainAssetEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ChainAssetEdge {
+    pub cursor: String,
+    pub node: ChainAsset,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ChainAsset {
+    pub asset_id: AssetId,
+    pub contract_id: Option<ContractId>,
+    pub sub_id: Option<SubId>,
+    pub total_supply: Option<U128>,
+}
+
 #[derive(cynic::QueryVariables, Debug)]
 pub struct AssetInfoArg {
     pub id: AssetId,
@@ -29,3 +89,78 @@ pub struct AssetInfoDetails {
     pub contract_id: ContractId,
     pub total_supply: U128,
 }
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct AssetFirstSeenArgs {
+    pub asset_id: AssetId,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "AssetFirstSeenArgs"
+)]
+pub struct AssetFirstSeenQuery {
+    #[arguments(assetId: $asset_id)]
+    pub asset_first_seen: Option<AssetFirstSeen>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetFirstSeen {
+    pub block_height: U32,
+    pub time: Tai64Timestamp,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct AssetSupplyHistoryArgs {
+    pub asset_id: AssetId,
+    /// Skip until cursor
+    pub after: Option<String>,
+    /// Retrieve the first n changes
+    pub first: Option<i32>,
+}
+
+impl From<(AssetId, PaginationRequest<String>)> for AssetSupplyHistoryArgs {
+    fn from(r: (AssetId, PaginationRequest<String>)) -> Self {
+        AssetSupplyHistoryArgs {
+            asset_id: r.0,
+            after: r.1.cursor,
+            first: Some(r.1.results),
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "AssetSupplyHistoryArgs"
+)]
+pub struct AssetSupplyHistoryQuery {
+    #[arguments(assetId: $asset_id, after: $after, first: $first)]
+    pub asset_supply_history: AssetSupplyChangeConnection,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetSupplyChangeConnection {
+    pub edges: Vec<AssetSupplyChangeEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetSupplyChangeEdge {
+    pub cursor: String,
+    pub node: AssetSupplyChange,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetSupplyChange {
+    pub block_height: U32,
+    pub delta: I128,
+    pub total_supply: U128,
+}
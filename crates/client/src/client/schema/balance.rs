@@ -4,6 +4,9 @@ use crate::client::{
         Address,
         AssetId,
         PageInfo,
+        I128,
+        U128,
+        U32,
         U64,
     },
     PageDirection,
@@ -27,6 +30,56 @@ pub struct BalanceQuery {
     pub balance: Balance,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BalanceArgs"
+)]
+pub struct OwnedCoinValueQuery {
+    #[arguments(owner: $owner, assetId: $asset_id)]
+    pub owned_coin_value: U128,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BalanceDeltaArgs {
+    pub owner: Address,
+    pub asset_id: AssetId,
+    pub from_height: U32,
+    pub to_height: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BalanceDeltaArgs"
+)]
+pub struct BalanceDeltaQuery {
+    #[arguments(owner: $owner, assetId: $asset_id, fromHeight: $from_height, toHeight: $to_height)]
+    pub balance_delta: I128,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "BalanceArgs"
+)]
+pub struct BalanceUpdatedSubscription {
+    #[arguments(owner: $owner, assetId: $asset_id)]
+    pub balance_updated: BalanceUpdate,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BalanceUpdate {
+    pub owner: Address,
+    pub asset_id: AssetId,
+    pub amount: U128,
+    pub block_height: U32,
+}
+
 #[derive(cynic::InputObject, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct BalanceFilterInput {
@@ -131,4 +184,16 @@ mod tests {
         });
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn balance_delta_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = BalanceDeltaQuery::build(BalanceDeltaArgs {
+            owner: Address::default(),
+            asset_id: AssetId::default(),
+            from_height: 0u32.into(),
+            to_height: 0u32.into(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
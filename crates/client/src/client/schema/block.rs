@@ -1,15 +1,24 @@
 use super::Bytes32;
-use crate::client::schema::{
-    schema,
-    BlockId,
-    ConnectionArgsFields,
-    PageInfo,
-    Signature,
-    Tai64Timestamp,
-    TransactionId,
-    U16,
-    U32,
-    U64,
+use crate::client::{
+    pagination::PaginationRequest,
+    schema::{
+        schema,
+        tx::{
+            transparent_receipt::Receipt,
+            OpaqueTransaction,
+            TransactionStatus,
+        },
+        Address,
+        BlockId,
+        ConnectionArgsFields,
+        PageInfo,
+        Signature,
+        Tai64Timestamp,
+        TransactionId,
+        U16,
+        U32,
+        U64,
+    },
 };
 use fuel_core_types::{
     fuel_crypto,
@@ -48,6 +57,91 @@ pub struct BlockByHeightQuery {
     pub block: Option<Block>,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BlockTransactionIdsArgs {
+    pub height: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BlockTransactionIdsArgs"
+)]
+pub struct BlockTransactionIdsQuery {
+    #[arguments(height: $height)]
+    pub block_transaction_ids: Option<Vec<TransactionId>>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BlockHeadersArgs {
+    pub start: U32,
+    pub end: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BlockHeadersArgs"
+)]
+pub struct BlockHeadersQuery {
+    #[arguments(start: $start, end: $end)]
+    pub block_headers: Vec<SignedHeader>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct FeesAtHeightArgs {
+    pub height: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "FeesAtHeightArgs"
+)]
+pub struct FeesAtHeightQuery {
+    #[arguments(height: $height)]
+    pub fees_at_height: Option<CollectedFees>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct TotalFeesCollectedQuery {
+    pub total_fees_collected: CollectedFees,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CollectedFees {
+    pub base_fee: U64,
+    pub tip: U64,
+    pub total: U64,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct LastRollbackQuery {
+    pub last_rollback: Option<ChainRollback>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ChainRollback {
+    pub from_height: U32,
+    pub to_height: U32,
+    pub block_ids: Option<Vec<BlockId>>,
+}
+
+/// The header of the block with the consensus data that seals it
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct SignedHeader {
+    pub header: Header,
+    pub consensus: Consensus,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -73,6 +167,51 @@ pub struct BlockEdge {
     pub node: Block,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BlocksByProducerArgs {
+    /// The address of the block producer
+    pub producer: Address,
+    /// Skip until the cursor
+    pub after: Option<String>,
+    /// Retrieve the first n headers
+    pub first: Option<i32>,
+}
+
+impl From<(Address, PaginationRequest<String>)> for BlocksByProducerArgs {
+    fn from(r: (Address, PaginationRequest<String>)) -> Self {
+        BlocksByProducerArgs {
+            producer: r.0,
+            after: r.1.cursor,
+            first: Some(r.1.results),
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BlocksByProducerArgs"
+)]
+pub struct BlocksByProducerQuery {
+    #[arguments(producer: $producer, after: $after, first: $first)]
+    pub blocks_by_producer: HeaderConnection,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct HeaderConnection {
+    pub edges: Vec<HeaderEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct HeaderEdge {
+    pub cursor: String,
+    pub node: Header,
+}
+
 #[derive(cynic::Enum, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub enum BlockVersion {
@@ -121,6 +260,23 @@ pub struct BlockMutation {
     pub produce_blocks: U32,
 }
 
+/// A block of the chain from before the regenesis.
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PreRegenesisBlock {
+    pub id: BlockId,
+    pub header: Header,
+    pub consensus: Consensus,
+    pub transaction_ids: Vec<TransactionId>,
+    pub regenesis_height: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct OldestRetainedBlockQuery {
+    pub oldest_retained_block: Option<PreRegenesisBlock>,
+}
+
 #[derive(cynic::Enum, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub enum HeaderVersion {
@@ -198,6 +354,74 @@ impl From<BlockHeightFragment> for BlockHeight {
     }
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Subscription")]
+pub struct BlockIndexedSubscription {
+    pub block_indexed: BlockIndexedEvent,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Subscription")]
+pub struct ChainRollbackSubscription {
+    pub chain_rollback: ChainRollback,
+}
+
+#[derive(cynic::InlineFragments, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum BlockIndexedEvent {
+    BlockIndexed(BlockIndexed),
+    BlockIndexedGap(BlockIndexedGap),
+    #[cynic(fallback)]
+    Unknown,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockIndexed {
+    pub height: U32,
+    pub tx_count: U16,
+    pub timestamp: Tai64Timestamp,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockIndexedGap {
+    pub missed_blocks: U64,
+}
+
+#[derive(cynic::InputObject, Clone, Copy, Debug, Default)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockDataInclusion {
+    pub transactions: bool,
+    pub receipts: bool,
+    pub statuses: bool,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BlocksWithDataArgs {
+    pub include: Option<BlockDataInclusion>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "BlocksWithDataArgs"
+)]
+pub struct BlocksWithDataSubscription {
+    #[arguments(include: $include)]
+    pub blocks_with_data: BlockWithData,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockWithData {
+    pub block: Block,
+    pub transactions: Option<Vec<OpaqueTransaction>>,
+    pub receipts: Option<Vec<Vec<Receipt>>>,
+    pub statuses: Option<Vec<TransactionStatus>>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -234,6 +458,55 @@ mod tests {
         insta::assert_snapshot!(snapshot_name, operation.query)
     }
 
+    #[test]
+    fn block_headers_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = BlockHeadersQuery::build(BlockHeadersArgs {
+            start: U32(0),
+            end: U32(10),
+        });
+
+        let snapshot_name = if cfg!(feature = "fault-proving") {
+            "block_headers_query_gql_output_with_tx_id_commitment"
+        } else {
+            "block_headers_query_gql_output"
+        };
+
+        insta::assert_snapshot!(snapshot_name, operation.query)
+    }
+
+    #[test]
+    fn blocks_by_producer_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = BlocksByProducerQuery::build(BlocksByProducerArgs {
+            producer: Address::default(),
+            after: None,
+            first: None,
+        });
+
+        let snapshot_name = if cfg!(feature = "fault-proving") {
+            "blocks_by_producer_query_gql_output_with_tx_id_commitment"
+        } else {
+            "blocks_by_producer_query_gql_output"
+        };
+
+        insta::assert_snapshot!(snapshot_name, operation.query)
+    }
+
+    #[test]
+    fn oldest_retained_block_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = OldestRetainedBlockQuery::build(());
+
+        let snapshot_name = if cfg!(feature = "fault-proving") {
+            "oldest_retained_block_query_gql_output_with_tx_id_commitment"
+        } else {
+            "oldest_retained_block_query_gql_output"
+        };
+
+        insta::assert_snapshot!(snapshot_name, operation.query)
+    }
+
     #[test]
     fn block_mutation_query_gql_output() {
         use cynic::MutationBuilder;
@@ -263,4 +536,45 @@ mod tests {
 
         insta::assert_snapshot!(snapshot_name, operation.query)
     }
+
+    #[test]
+    fn block_indexed_subscription_gql_output() {
+        use cynic::SubscriptionBuilder;
+        let operation = BlockIndexedSubscription::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn last_rollback_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = LastRollbackQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn chain_rollback_subscription_gql_output() {
+        use cynic::SubscriptionBuilder;
+        let operation = ChainRollbackSubscription::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn blocks_with_data_subscription_gql_output() {
+        use cynic::SubscriptionBuilder;
+        let operation = BlocksWithDataSubscription::build(BlocksWithDataArgs {
+            include: Some(BlockDataInclusion {
+                transactions: true,
+                receipts: true,
+                statuses: true,
+            }),
+        });
+
+        let snapshot_name = if cfg!(feature = "fault-proving") {
+            "blocks_with_data_subscription_gql_output_with_tx_id_commitment"
+        } else {
+            "blocks_with_data_subscription_gql_output"
+        };
+
+        insta::assert_snapshot!(snapshot_name, operation.query)
+    }
 }
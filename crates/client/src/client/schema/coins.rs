@@ -3,8 +3,10 @@ use crate::client::{
         schema,
         Address,
         AssetId,
+        HexString,
         Nonce,
         PageInfo,
+        TransactionId,
         UtxoId,
         U128,
         U16,
@@ -35,6 +37,46 @@ pub struct CoinByIdQuery {
     pub coin: Option<Coin>,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinSpenderArgs {
+    pub utxo_id: UtxoId,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinSpenderArgs"
+)]
+pub struct CoinSpenderQuery {
+    #[arguments(utxoId: $ utxo_id)]
+    pub coin_spender: Option<CoinSpender>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct SpentCoinsAtHeightArgs {
+    pub height: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "SpentCoinsAtHeightArgs"
+)]
+pub struct SpentCoinsAtHeightQuery {
+    #[arguments(height: $ height)]
+    pub spent_coins_at_height: Vec<UtxoId>,
+}
+
+/// The transaction that spent the coin and the height of its block.
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinSpender {
+    pub transaction_id: TransactionId,
+    pub block_height: U32,
+}
+
 #[derive(cynic::InputObject, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct CoinFilterInput {
@@ -97,6 +139,40 @@ pub struct CoinsQuery {
     pub coins: CoinConnection,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinCountByOwnerAssetArgs {
+    pub owner: Address,
+    pub asset_id: AssetId,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinCountByOwnerAssetArgs"
+)]
+pub struct CoinCountByOwnerAssetQuery {
+    #[arguments(owner: $owner, assetId: $asset_id)]
+    pub coin_count_by_owner_asset: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct HasSpendableCoinsArgs {
+    pub owner: Address,
+    pub asset_id: AssetId,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "HasSpendableCoinsArgs"
+)]
+pub struct HasSpendableCoinsQuery {
+    #[arguments(owner: $owner, assetId: $asset_id)]
+    pub has_spendable_coins: bool,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct CoinConnection {
@@ -122,6 +198,25 @@ pub struct Coin {
     pub owner: Address,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct StreamOwnedCoinsArgs {
+    /// The owner of the coins.
+    pub owner: Address,
+    /// The maximum number of coins in one batch.
+    pub batch_size: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "StreamOwnedCoinsArgs"
+)]
+pub struct StreamOwnedCoins {
+    #[arguments(owner: $owner, batchSize: $batch_size)]
+    pub stream_owned_coins: Vec<Coin>,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Coin")]
 pub struct CoinIdFragment {
@@ -174,6 +269,7 @@ pub struct MessageCoin {
     pub recipient: Address,
     pub nonce: Nonce,
     pub da_height: U64,
+    pub data: HexString,
 }
 
 #[derive(cynic::InlineFragments, Debug, Clone)]
@@ -229,6 +325,81 @@ pub struct CoinsToSpendQuery {
     pub coins_to_spend: Vec<Vec<CoinType>>,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ConsolidationSuggestionArgs {
+    /// The `Address` of the coins owner.
+    pub owner: Address,
+    /// The `AssetId` of the coins.
+    pub asset_id: AssetId,
+    /// The coins with the amount below it are dust.
+    pub dust_threshold: Option<U64>,
+    /// The maximum number of coins in the suggestion.
+    pub max_inputs: Option<U16>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ConsolidationSuggestionArgs"
+)]
+pub struct ConsolidationSuggestionQuery {
+    #[arguments(owner: $owner, assetId: $asset_id, dustThreshold: $dust_threshold, maxInputs: $max_inputs)]
+    pub consolidation_suggestion: ConsolidationSuggestion,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ConsolidationSuggestion {
+    pub coins: Vec<CoinType>,
+    pub total: U128,
+    pub remaining_dust_coins: U64,
+    pub remaining_dust: U128,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct FindOrphanedOwnedCoinsArgs {
+    /// The `Address` of the coins owner.
+    pub owner: Address,
+    /// The maximum number of the returned coins.
+    pub limit: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "FindOrphanedOwnedCoinsArgs"
+)]
+pub struct FindOrphanedOwnedCoinsQuery {
+    #[arguments(owner: $owner, limit: $limit)]
+    pub find_orphaned_owned_coins: Vec<UtxoId>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct RepairOrphanedOwnedCoinsArgs {
+    pub owner: Address,
+    pub limit: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct RepairedOrphanedOwnedCoins {
+    pub removed: Vec<UtxoId>,
+    pub has_more: bool,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "RepairOrphanedOwnedCoinsArgs"
+)]
+pub struct RepairOrphanedOwnedCoinsMutation {
+    #[arguments(owner: $owner, limit: $limit)]
+    pub repair_orphaned_owned_coins: RepairedOrphanedOwnedCoins,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -242,6 +413,33 @@ mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn coin_spender_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = CoinSpenderQuery::build(CoinSpenderArgs {
+            utxo_id: UtxoId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn spent_coins_at_height_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation =
+            SpentCoinsAtHeightQuery::build(SpentCoinsAtHeightArgs { height: U32(1) });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn has_spendable_coins_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = HasSpendableCoinsQuery::build(HasSpendableCoinsArgs {
+            owner: Address::default(),
+            asset_id: AssetId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn coins_connection_query_gql_output() {
         use cynic::QueryBuilder;
@@ -257,4 +455,38 @@ mod tests {
         });
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn consolidation_suggestion_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation =
+            ConsolidationSuggestionQuery::build(ConsolidationSuggestionArgs {
+                owner: Address::default(),
+                asset_id: AssetId::default(),
+                dust_threshold: None,
+                max_inputs: None,
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn find_orphaned_owned_coins_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = FindOrphanedOwnedCoinsQuery::build(FindOrphanedOwnedCoinsArgs {
+            owner: Address::default(),
+            limit: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn repair_orphaned_owned_coins_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation =
+            RepairOrphanedOwnedCoinsMutation::build(RepairOrphanedOwnedCoinsArgs {
+                owner: Address::default(),
+                limit: None,
+            });
+        insta::assert_snapshot!(operation.query)
+    }
 }
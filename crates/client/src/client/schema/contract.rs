@@ -6,6 +6,9 @@ use crate::client::{
         HexString,
         PageInfo,
         Salt,
+        TransactionId,
+        TxPointer,
+        U32,
         U64,
     },
     PageDirection,
@@ -135,6 +138,83 @@ impl From<(ContractId, PaginationRequest<String>)> for ContractBalancesConnectio
     }
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractsConnectionArgs {
+    /// The height of the first block of the range, inclusive
+    pub start_height: Option<U32>,
+    /// The height of the last block of the range, inclusive
+    pub end_height: Option<U32>,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n contracts in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n contracts in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(Option<U32>, Option<U32>, PaginationRequest<String>)>
+    for ContractsConnectionArgs
+{
+    fn from(r: (Option<U32>, Option<U32>, PaginationRequest<String>)) -> Self {
+        let (start_height, end_height, request) = r;
+        match request.direction {
+            PageDirection::Forward => ContractsConnectionArgs {
+                start_height,
+                end_height,
+                after: request.cursor,
+                before: None,
+                first: Some(request.results),
+                last: None,
+            },
+            PageDirection::Backward => ContractsConnectionArgs {
+                start_height,
+                end_height,
+                after: None,
+                before: request.cursor,
+                first: None,
+                last: Some(request.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DeployedContract {
+    pub contract_id: ContractId,
+    pub tx_id: TransactionId,
+    pub tx_pointer: TxPointer,
+    pub salt: Salt,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DeployedContractEdge {
+    pub cursor: String,
+    pub node: DeployedContract,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DeployedContractConnection {
+    pub edges: Vec<DeployedContractEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractsConnectionArgs"
+)]
+pub struct ContractsQuery {
+    #[arguments(startHeight: $start_height, endHeight: $end_height, after: $after, before: $before, first: $first, last: $last)]
+    pub contracts: DeployedContractConnection,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -147,4 +227,18 @@ mod tests {
         });
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn contracts_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ContractsQuery::build(ContractsConnectionArgs {
+            start_height: Some(1.into()),
+            end_height: Some(10.into()),
+            after: None,
+            before: None,
+            first: Some(10),
+            last: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
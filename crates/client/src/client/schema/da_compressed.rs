@@ -1,9 +1,20 @@
-use crate::client::schema::{
-    schema,
-    U32,
+use crate::client::{
+    schema::{
+        schema,
+        Bytes32,
+        PageInfo,
+        Tai64Timestamp,
+        U32,
+        U64,
+    },
+    PaginatedResult,
+    PaginationRequest,
 };
 
-use super::HexString;
+use super::{
+    message::MerkleProof,
+    HexString,
+};
 
 #[derive(cynic::QueryVariables, Debug)]
 pub struct DaCompressedBlockByHeightArgs {
@@ -21,6 +32,50 @@ pub struct DaCompressedBlockByHeightQuery {
     pub da_compressed_block: Option<DaCompressedBlock>,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct DaCompressionGapsArgs {
+    pub start: U32,
+    pub end: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "DaCompressionGapsArgs"
+)]
+pub struct DaCompressionGapsQuery {
+    #[arguments(start: $start, end: $end)]
+    pub da_compression_gaps: Vec<U32>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct DaCompressionMetricsArgs {
+    pub last: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "DaCompressionMetricsArgs"
+)]
+pub struct DaCompressionMetricsQuery {
+    #[arguments(last: $last)]
+    pub da_compression_metrics: Vec<DaCompressionMetrics>,
+}
+
+/// The compression statistics of the DA compressed block
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DaCompressionMetrics {
+    pub height: U32,
+    pub uncompressed_size: U64,
+    pub compressed_size: U64,
+    pub ratio: f64,
+    pub registrations: U64,
+}
+
 /// Block with transaction ids
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
@@ -28,6 +83,159 @@ pub struct DaCompressedBlock {
     pub bytes: HexString,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "DaCompressedBlockByHeightArgs"
+)]
+pub struct VerifyDaBlockQuery {
+    #[arguments(height: $height)]
+    pub verify_da_block: Option<DaBlockVerification>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "DaCompressedBlockByHeightArgs"
+)]
+pub struct DecompressedDaBlockQuery {
+    #[arguments(height: $height)]
+    pub decompressed_da_block: Option<DecompressedDaBlock>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "DaCompressedBlockByHeightArgs"
+)]
+pub struct DaCompressedBlockProofQuery {
+    #[arguments(height: $height)]
+    pub da_compressed_block_proof: Option<DaCompressedBlockProof>,
+}
+
+/// The proof of the inclusion of the DA compressed block in the Merkle tree
+/// of all compressed blocks
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DaCompressedBlockProof {
+    pub root: Bytes32,
+    pub leaf_count: U64,
+    pub proof: MerkleProof,
+}
+
+/// The DA compressed block decoded into the human-readable form
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DecompressedDaBlock {
+    pub format_version: U32,
+    pub payload_version: U32,
+    pub height: U32,
+    pub prev_root: Bytes32,
+    pub time: Tai64Timestamp,
+    pub da_height: U64,
+    pub consensus_parameters_version: U32,
+    pub state_transition_bytecode_version: U32,
+    pub registrations: U64,
+    pub transaction_count: U32,
+    pub json: String,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum DaBlockVerificationStatus {
+    Verified,
+    Unverified,
+    Corrupted,
+}
+
+/// The result of the comparison of the DA compressed block with its checksum
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DaBlockVerification {
+    pub status: DaBlockVerificationStatus,
+    pub stored_checksum: Option<U32>,
+    pub computed_checksum: U32,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct DaCompressedBlockRegistrationsArgs {
+    pub height: U32,
+    /// Skip until cursor
+    pub after: Option<String>,
+    /// Retrieve the first n registrations
+    pub first: Option<i32>,
+}
+
+impl From<(U32, PaginationRequest<String>)> for DaCompressedBlockRegistrationsArgs {
+    fn from((height, request): (U32, PaginationRequest<String>)) -> Self {
+        DaCompressedBlockRegistrationsArgs {
+            height,
+            after: request.cursor,
+            first: Some(request.results),
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "DaCompressedBlockRegistrationsArgs"
+)]
+pub struct DaCompressedBlockRegistrationsQuery {
+    #[arguments(height: $height, after: $after, first: $first)]
+    pub da_compressed_block_registrations: Option<DaBlockRegistrationConnection>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DaBlockRegistrationConnection {
+    pub edges: Vec<DaBlockRegistrationEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DaBlockRegistrationEdge {
+    pub cursor: String,
+    pub node: DaBlockRegistration,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum DaRegistryKeyspace {
+    Address,
+    AssetId,
+    ContractId,
+    ScriptCode,
+    PredicateCode,
+}
+
+/// The new registration in the temporal registry made by the DA compressed block
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DaBlockRegistration {
+    pub keyspace: DaRegistryKeyspace,
+    pub key: U32,
+    pub value_hash: Bytes32,
+}
+
+impl From<DaBlockRegistrationConnection>
+    for PaginatedResult<DaBlockRegistration, String>
+{
+    fn from(conn: DaBlockRegistrationConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node).collect(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -41,4 +249,31 @@ mod tests {
             });
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn da_compression_gaps_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = DaCompressionGapsQuery::build(DaCompressionGapsArgs {
+            start: U32(0),
+            end: U32(10),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn da_compression_metrics_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation =
+            DaCompressionMetricsQuery::build(DaCompressionMetricsArgs { last: U32(10) });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn decompressed_da_block_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = DecompressedDaBlockQuery::build(DaCompressedBlockByHeightArgs {
+            height: U32(0),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
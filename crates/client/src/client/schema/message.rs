@@ -29,6 +29,14 @@ pub struct Message {
     pub nonce: Nonce,
     pub data: HexString,
     pub da_height: U64,
+    pub spent_status: Option<MessageSpentStatus>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct MessageSpentStatus {
+    pub block_height: U32,
+    pub transaction_id: Option<TransactionId>,
 }
 
 #[derive(cynic::QueryVariables, Debug)]
@@ -68,7 +76,7 @@ pub enum MessageState {
     variables = "OwnedMessagesConnectionArgs"
 )]
 pub struct OwnedMessageQuery {
-    #[arguments(owner: $owner, after: $after, before: $before, first: $first, last: $last)]
+    #[arguments(owner: $owner, after: $after, before: $before, first: $first, last: $last, includeSpent: $include_spent)]
     pub messages: MessageConnection,
 }
 
@@ -99,6 +107,39 @@ pub struct OwnedMessagesConnectionArgs {
     /// Retrieve the last n coins in order (backward pagination).
     /// Can't be used at the same time as `first`.
     pub last: Option<i32>,
+    /// Return the spent messages of the owner too
+    pub include_spent: Option<bool>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct MessagesBySenderConnectionArgs {
+    /// The sender of the messages on the L1
+    pub sender: Address,
+    /// Skip until the cursor
+    pub after: Option<String>,
+    /// Retrieve the first n messages
+    pub first: Option<i32>,
+}
+
+impl From<(Address, PaginationRequest<String>)> for MessagesBySenderConnectionArgs {
+    fn from(r: (Address, PaginationRequest<String>)) -> Self {
+        MessagesBySenderConnectionArgs {
+            sender: r.0,
+            after: r.1.cursor,
+            first: Some(r.1.results),
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "MessagesBySenderConnectionArgs"
+)]
+pub struct MessagesBySenderQuery {
+    #[arguments(sender: $sender, after: $after, first: $first)]
+    pub messages_by_sender: MessageConnection,
 }
 
 #[derive(cynic::QueryFragment, Clone, Debug)]
@@ -186,6 +227,12 @@ pub struct MessageStatusArgs {
     pub nonce: Nonce,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct SpentMessageCountQuery {
+    pub spent_message_count: U64,
+}
+
 impl From<(Option<Address>, PaginationRequest<String>)> for OwnedMessagesConnectionArgs {
     fn from(r: (Option<Address>, PaginationRequest<String>)) -> Self {
         match r.1.direction {
@@ -195,6 +242,7 @@ impl From<(Option<Address>, PaginationRequest<String>)> for OwnedMessagesConnect
                 before: None,
                 first: Some(r.1.results),
                 last: None,
+                include_spent: None,
             },
             PageDirection::Backward => OwnedMessagesConnectionArgs {
                 owner: r.0,
@@ -202,6 +250,7 @@ impl From<(Option<Address>, PaginationRequest<String>)> for OwnedMessagesConnect
                 before: r.1.cursor,
                 first: None,
                 last: Some(r.1.results),
+                include_spent: None,
             },
         }
     }
@@ -221,8 +270,31 @@ mod tests {
             before: None,
             first: None,
             last: None,
+            include_spent: Some(true),
+        });
+
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn messages_by_sender_query_gql_output() {
+        use cynic::QueryBuilder;
+
+        let operation = MessagesBySenderQuery::build(MessagesBySenderConnectionArgs {
+            sender: Address::default(),
+            after: None,
+            first: None,
         });
 
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn spent_message_count_query_gql_output() {
+        use cynic::QueryBuilder;
+
+        let operation = SpentMessageCountQuery::build(());
+
+        insta::assert_snapshot!(operation.query)
+    }
 }
@@ -1,5 +1,6 @@
 use crate::client::schema::{
     schema,
+    Bytes32,
     U32,
     U64,
 };
@@ -28,9 +29,19 @@ pub struct NodeInfo {
     pub max_depth: U64,
     pub node_version: String,
     pub indexation: IndexationFlags,
+    pub indexed_height: Option<U32>,
+    pub da_compression_height: Option<U32>,
+    pub off_chain_commit_duration_max: Option<OffChainCommitDuration>,
     pub tx_pool_stats: TxPoolStats,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct OffChainCommitDuration {
+    pub height: U32,
+    pub duration_micros: U64,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
 pub struct QueryNodeInfo {
@@ -81,6 +92,47 @@ impl From<PeerInfo> for fuel_core_types::services::p2p::PeerInfo {
     }
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct OffChainColumn {
+    pub name: String,
+    pub id: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryOffChainColumns {
+    pub offchain_columns: Vec<OffChainColumn>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct StatisticArgs {
+    pub name: String,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "StatisticArgs"
+)]
+pub struct QueryStatistic {
+    #[arguments(name: $name)]
+    pub statistic: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryStatistics {
+    pub statistics: Vec<String>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryOffChainStateHash {
+    pub offchain_state_hash: Bytes32,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug, PartialEq, Eq)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct TxPoolStats {
@@ -97,6 +149,63 @@ pub struct IndexationFlags {
     pub asset_metadata: bool,
 }
 
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum SubsystemStatus {
+    Ok,
+    Degraded,
+    Failing,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct HeightHealth {
+    pub status: SubsystemStatus,
+    pub height: Option<U32>,
+    pub lag: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TxPoolHealth {
+    pub status: SubsystemStatus,
+    pub tx_count: U64,
+    pub max_tx_count: U64,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct RelayerHealth {
+    pub status: SubsystemStatus,
+    pub da_height: U64,
+    pub synced: bool,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct IndexationHealth {
+    pub status: SubsystemStatus,
+    pub dead_letter_height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct NodeHealth {
+    pub status: SubsystemStatus,
+    pub on_chain: HeightHealth,
+    pub off_chain: HeightHealth,
+    pub da_compression: Option<HeightHealth>,
+    pub txpool: TxPoolHealth,
+    pub relayer: Option<RelayerHealth>,
+    pub indexation: IndexationHealth,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryNodeHealth {
+    pub node_health: NodeHealth,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -114,4 +223,25 @@ mod tests {
         let operation = QueryPeersInfo::build(());
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn offchain_columns_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QueryOffChainColumns::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn offchain_state_hash_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QueryOffChainStateHash::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn node_health_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QueryNodeHealth::build(());
+        insta::assert_snapshot!(operation.query)
+    }
 }
@@ -1,7 +1,15 @@
-use crate::client::schema::{
-    schema,
-    RelayedTransactionId,
-    U32,
+use crate::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    schema::{
+        schema,
+        Address,
+        PageInfo,
+        RelayedTransactionId,
+        U32,
+    },
 };
 
 #[derive(cynic::QueryFragment, Clone, Debug)]
@@ -21,6 +29,76 @@ pub struct RelayedTransactionStatusArgs {
     pub id: RelayedTransactionId,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "RelayedTransactionsByOwnerConnectionArgs"
+)]
+pub struct RelayedTransactionsByOwnerQuery {
+    #[arguments(owner: $owner, after: $after, before: $before, first: $first, last: $last)]
+    pub relayed_transactions_by_owner: RelayedTransactionConnection,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct RelayedTransactionConnection {
+    pub edges: Vec<RelayedTransactionEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct RelayedTransactionEdge {
+    pub cursor: String,
+    pub node: RelayedTransaction,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct RelayedTransaction {
+    pub id: RelayedTransactionId,
+    pub status: RelayedTransactionStatus,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct RelayedTransactionsByOwnerConnectionArgs {
+    /// The owner of the inputs spent by the relayed transactions
+    pub owner: Address,
+    /// Skip until relayed transaction id (forward pagination)
+    pub after: Option<String>,
+    /// Skip until relayed transaction id (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n relayed transactions in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n relayed transactions in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(Address, PaginationRequest<String>)>
+    for RelayedTransactionsByOwnerConnectionArgs
+{
+    fn from(r: (Address, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => RelayedTransactionsByOwnerConnectionArgs {
+                owner: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results),
+                last: None,
+            },
+            PageDirection::Backward => RelayedTransactionsByOwnerConnectionArgs {
+                owner: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results),
+            },
+        }
+    }
+}
+
 #[allow(clippy::enum_variant_names)]
 #[derive(cynic::InlineFragments, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
@@ -1,6 +1,8 @@
 use crate::client::{
     schema::{
+        block::Header,
         coins::ExcludeInput,
+        message::MerkleProof,
         schema,
         tx::{
             transparent_receipt::Receipt,
@@ -9,6 +11,7 @@ use crate::client::{
         Address,
         AssetId,
         ConnectionArgsFields,
+        ContractId,
         ConversionError,
         HexString,
         PageInfo,
@@ -73,6 +76,109 @@ pub struct TransactionStatusQuery {
     pub transaction: Option<OpaqueTransactionStatus>,
 }
 
+/// Retrieves the tag attached to the transaction
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct TransactionTagQuery {
+    #[arguments(id: $id)]
+    pub transaction: Option<TransactionTag>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct OldTransactionArgs {
+    pub tx_id: TransactionId,
+}
+
+/// Retrieves the transaction retained from before the regenesis
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "OldTransactionArgs"
+)]
+pub struct OldTransactionQuery {
+    #[arguments(txId: $tx_id)]
+    pub old_transaction: Option<PreRegenesisTransaction>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PreRegenesisTransaction {
+    pub id: TransactionId,
+    pub raw_payload: HexString,
+    pub regenesis_height: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Transaction")]
+pub struct TransactionTag {
+    pub tag: Option<HexString>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TagTransactionArgs {
+    pub id: TransactionId,
+    pub tag: HexString,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "TagTransactionArgs"
+)]
+pub struct TagTransactionMutation {
+    #[arguments(id: $id, tag: $tag)]
+    pub tag_transaction: bool,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TransactionInclusionProofArgs {
+    pub tx_id: TransactionId,
+}
+
+/// Retrieves the proof that the transaction is included in its block
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransactionInclusionProofArgs"
+)]
+pub struct TransactionInclusionProofQuery {
+    #[arguments(txId: $tx_id)]
+    pub transaction_inclusion_proof: TransactionInclusionProof,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionInclusionProof {
+    /// The proof against the `transactions_root` of the block header.
+    pub proof: MerkleProof,
+    /// The header of the block that includes the transaction.
+    pub block_header: Header,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TxIdsArgs {
+    pub tx_ids: Vec<crate::client::schema::Bytes32>,
+}
+
+/// Retrieves whether the transactions are included in the blocks
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdsArgs"
+)]
+pub struct TransactionsIncludedQuery {
+    #[arguments(txIds: $tx_ids)]
+    pub transactions_included: Vec<bool>,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -331,6 +437,20 @@ pub struct PreconfirmationFailureStatusWithTransaction {
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct SqueezedOutStatus {
     pub reason: String,
+    pub rejection_reason: RejectionReason,
+    pub rejection_code: Option<U16>,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum RejectionReason {
+    Unknown,
+    InsufficientFee,
+    DependentTxSqueezedOut,
+    InputSpent,
+    TtlExpired,
+    PoolFull,
+    Validity,
 }
 
 #[allow(clippy::enum_variant_names)]
@@ -430,6 +550,10 @@ pub struct TransactionsByOwnerConnectionArgs {
     /// Retrieve the last n transactions in order (backward pagination).
     /// Can't be used at the same time as `first`.
     pub last: Option<i32>,
+    /// Select only the transactions with the status
+    pub status_filter: Option<TransactionStatusFilter>,
+    /// Select only the transactions in the direction
+    pub direction: TransactionDirection,
 }
 
 impl From<(Address, PaginationRequest<String>)> for TransactionsByOwnerConnectionArgs {
@@ -441,6 +565,8 @@ impl From<(Address, PaginationRequest<String>)> for TransactionsByOwnerConnectio
                 before: None,
                 first: Some(r.1.results),
                 last: None,
+                status_filter: None,
+                direction: TransactionDirection::Any,
             },
             PageDirection::Backward => TransactionsByOwnerConnectionArgs {
                 owner: r.0,
@@ -448,11 +574,38 @@ impl From<(Address, PaginationRequest<String>)> for TransactionsByOwnerConnectio
                 before: r.1.cursor,
                 first: None,
                 last: Some(r.1.results),
+                status_filter: None,
+                direction: TransactionDirection::Any,
             },
         }
     }
 }
 
+/// The status of the transactions related to the owner.
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum TransactionStatusFilter {
+    /// The transaction was executed successfully.
+    Success,
+    /// The execution of the transaction failed.
+    Failure,
+    /// The transaction was squeezed out of the txpool.
+    SqueezedOut,
+}
+
+/// The direction of the transactions related to the owner.
+#[derive(cynic::Enum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum TransactionDirection {
+    /// The owner spends the inputs of the transaction.
+    Sent,
+    /// The owner receives the outputs of the transaction.
+    Received,
+    /// All transactions of the owner.
+    #[default]
+    Any,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -460,10 +613,140 @@ impl From<(Address, PaginationRequest<String>)> for TransactionsByOwnerConnectio
     variables = "TransactionsByOwnerConnectionArgs"
 )]
 pub struct TransactionsByOwnerQuery {
-    #[arguments(owner: $owner, after: $after, before: $before, first: $first, last: $last)]
+    #[arguments(owner: $owner, after: $after, before: $before, first: $first, last: $last, statusFilter: $status_filter, direction: $direction)]
     pub transactions_by_owner: TransactionConnection,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TransactionsByContractConnectionArgs {
+    /// Select transactions that reference the contract
+    pub contract_id: ContractId,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n transactions in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n transactions in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(ContractId, PaginationRequest<String>)>
+    for TransactionsByContractConnectionArgs
+{
+    fn from(r: (ContractId, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => TransactionsByContractConnectionArgs {
+                contract_id: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results),
+                last: None,
+            },
+            PageDirection::Backward => TransactionsByContractConnectionArgs {
+                contract_id: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransactionsByContractConnectionArgs"
+)]
+pub struct TransactionsByContractQuery {
+    #[arguments(contractId: $contract_id, after: $after, before: $before, first: $first, last: $last)]
+    pub transactions_by_contract: TransactionConnection,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TopAddressesByTxCountArgs {
+    /// The maximum number of addresses to return
+    pub limit: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TopAddressesByTxCountArgs"
+)]
+pub struct TopAddressesByTxCountQuery {
+    #[arguments(limit: $limit)]
+    pub top_addresses_by_tx_count: Vec<AddressTxCount>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AddressTxCount {
+    pub address: Address,
+    pub tx_count: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct PendingTransactionsArgs {
+    /// Skip until cursor
+    pub after: Option<String>,
+    /// Retrieve the first n pending transactions
+    pub first: Option<i32>,
+    /// The order of the pending transactions
+    pub sort: PendingTransactionsSort,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "PendingTransactionsArgs"
+)]
+pub struct PendingTransactionsQuery {
+    #[arguments(first: $first, after: $after, sort: $sort)]
+    pub pending_transactions: PendingTransactionConnection,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PendingTransactionConnection {
+    pub edges: Vec<PendingTransactionEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PendingTransactionEdge {
+    pub cursor: String,
+    pub node: PendingTransaction,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PendingTransaction {
+    pub id: TransactionId,
+    pub tip: U64,
+    pub max_gas: U64,
+    pub inserted_at: Tai64Timestamp,
+}
+
+/// The order of the pending transactions.
+#[derive(cynic::Enum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum PendingTransactionsSort {
+    /// By the tip/gas ratio, the way the block producer selects the transactions.
+    #[default]
+    Ratio,
+    /// By the tip, the highest first.
+    Tip,
+    /// By the insertion time, the oldest first.
+    Time,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -500,6 +783,33 @@ pub struct EstimatePredicates {
     pub estimate_predicates: OpaqueTransaction,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxArg"
+)]
+pub struct EstimatePredicatesBreakdown {
+    #[arguments(tx: $tx)]
+    pub estimate_predicates_breakdown: PredicatesEstimation,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PredicatesEstimation {
+    pub transaction: OpaqueTransaction,
+    pub predicates: Vec<PredicateEstimation>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PredicateEstimation {
+    pub input_index: U16,
+    pub success: bool,
+    pub gas_used: Option<U64>,
+    pub failure_reason: Option<String>,
+}
+
 #[derive(cynic::InputObject, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct Predicate {
@@ -598,6 +908,36 @@ pub struct DryRun {
     pub dry_run: Vec<DryRunTransactionExecutionStatus>,
 }
 
+#[derive(cynic::QueryVariables)]
+pub struct DryRunAtHeightArg {
+    pub txs: Vec<HexString>,
+    pub utxo_validation: Option<bool>,
+    pub gas_price: Option<U64>,
+    pub at_height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "DryRunTransactionExecutionStatus"
+)]
+pub struct DryRunAtHeightTransactionExecutionStatus {
+    pub id: TransactionId,
+    pub status: DryRunTransactionStatus,
+    pub state_height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "DryRunAtHeightArg"
+)]
+pub struct DryRunAtHeight {
+    #[arguments(txs: $txs, utxoValidation: $utxo_validation, gasPrice: $gas_price, atHeight: $at_height)]
+    pub dry_run: Vec<DryRunAtHeightTransactionExecutionStatus>,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -674,6 +1014,34 @@ pub mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn old_transaction_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = OldTransactionQuery::build(OldTransactionArgs {
+            tx_id: TransactionId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn transaction_tag_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TransactionTagQuery::build(TxIdArgs {
+            id: TransactionId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn tag_transaction_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = TagTransactionMutation::build(TagTransactionArgs {
+            id: TransactionId::default(),
+            tag: HexString(Bytes(vec![])),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[cfg(not(feature = "test-helpers"))]
     #[test]
     fn transactions_connection_query_gql_output() {
@@ -700,10 +1068,47 @@ pub mod tests {
                 before: None,
                 first: None,
                 last: None,
+                status_filter: None,
+                direction: TransactionDirection::Any,
             });
         insta::assert_snapshot!(operation.query)
     }
 
+    #[cfg(not(feature = "test-helpers"))]
+    #[test]
+    fn transactions_by_contract_gql_output() {
+        use cynic::QueryBuilder;
+        let operation =
+            TransactionsByContractQuery::build(TransactionsByContractConnectionArgs {
+                contract_id: Default::default(),
+                after: None,
+                before: None,
+                first: None,
+                last: None,
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn top_addresses_by_tx_count_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TopAddressesByTxCountQuery::build(TopAddressesByTxCountArgs {
+            limit: 10.into(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn pending_transactions_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = PendingTransactionsQuery::build(PendingTransactionsArgs {
+            after: None,
+            first: Some(10),
+            sort: PendingTransactionsSort::Tip,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn dry_run_tx_gql_output() {
         use cynic::MutationBuilder;
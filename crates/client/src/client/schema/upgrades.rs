@@ -26,6 +26,23 @@ pub struct ConsensusParametersByVersionQuery {
     pub consensus_parameters: Option<ConsensusParameters>,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ConsensusParametersByVersionArgs"
+)]
+pub struct IndexedConsensusParametersByVersionQuery {
+    #[arguments(version: $version)]
+    pub consensus_parameters_by_version: Option<ConsensusParameters>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct ConsensusParametersVersionsQuery {
+    pub consensus_parameters_versions: Vec<i32>,
+}
+
 #[derive(cynic::QueryVariables, Debug)]
 pub struct StateTransitionBytecodeByVersionArgs {
     pub version: i32,
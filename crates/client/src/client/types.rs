@@ -7,48 +7,81 @@ pub mod contract;
 pub mod gas_costs;
 pub mod upgrades;
 
+pub mod account;
 pub mod assemble_tx;
 pub mod asset;
+pub mod dry_run;
 pub mod gas_price;
 pub mod merkle_proof;
 pub mod message;
 pub mod node_info;
+pub mod predicate_estimation;
+pub mod snapshot_view;
 
-pub use balance::Balance;
+pub use balance::{
+    Balance,
+    BalanceUpdate,
+};
 pub use blob::Blob;
 pub use block::{
     Block,
+    ChainRollback,
+    CollectedFees,
     Consensus,
+    PreRegenesisBlock,
+    SignedHeader,
 };
 pub use chain_info::ChainInfo;
 pub use coins::{
     Coin,
+    CoinSpender,
     CoinType,
+    ConsolidationSuggestion,
     MessageCoin,
 };
 pub use contract::{
     Contract,
     ContractBalance,
+    DeployedContract,
 };
+pub use dry_run::DryRunAtHeight;
 pub use gas_costs::{
     DependentCost,
     GasCosts,
 };
-pub use merkle_proof::MerkleProof;
+pub use merkle_proof::{
+    MerkleProof,
+    TransactionInclusionProof,
+};
 pub use message::{
     Message,
     MessageProof,
+    MessageSpentStatus,
 };
 pub use node_info::NodeInfo;
+pub use predicate_estimation::PredicateEstimation;
+pub use snapshot_view::SnapshotView;
 
-use crate::client::schema::{
-    relayed_tx::RelayedTransactionStatus as SchemaRelayedTransactionStatus,
-    tx::{
-        OpaqueTransactionWithStatus,
-        StatusWithTransaction as SchemaStatusWithTx,
-        TransactionStatus as SchemaTxStatus,
+use crate::client::{
+    pagination::PaginatedResult,
+    schema::{
+        relayed_tx::{
+            RelayedTransaction as SchemaRelayedTransaction,
+            RelayedTransactionConnection as SchemaRelayedTransactionConnection,
+            RelayedTransactionStatus as SchemaRelayedTransactionStatus,
+        },
+        tx::{
+            OpaqueTransactionWithStatus,
+            PendingTransaction as SchemaPendingTransaction,
+            PendingTransactionConnection as SchemaPendingTransactionConnection,
+            PreRegenesisTransaction as SchemaPreRegenesisTransaction,
+            RejectionReason as SchemaRejectionReason,
+            SqueezedOutStatus as SchemaSqueezedOutStatus,
+            StatusWithTransaction as SchemaStatusWithTx,
+            TransactionStatus as SchemaTxStatus,
+        },
+        ConversionError,
     },
-    ConversionError,
 };
 use fuel_core_types::{
     fuel_tx::{
@@ -100,6 +133,16 @@ pub struct TransactionResponse {
     pub status: TransactionStatus,
 }
 
+/// A transaction of the chain from before the regenesis, retained by the node.
+/// The transaction is `Unknown` if it can't be decoded with the current types.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PreRegenesisTransaction {
+    pub id: TxId,
+    pub transaction: TransactionType,
+    /// The height of the genesis block of the current chain.
+    pub regenesis_height: BlockHeight,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct StatusWithTransactionResponse {
     pub status: StatusWithTransaction,
@@ -128,6 +171,7 @@ pub enum TransactionStatus {
     },
     SqueezedOut {
         reason: String,
+        rejection_reason: RejectionReason,
     },
     Failure {
         block_height: BlockHeight,
@@ -149,6 +193,36 @@ pub enum TransactionStatus {
     },
 }
 
+/// The structured reason why the transaction was squeezed out of the TxPool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum RejectionReason {
+    Unknown,
+    InsufficientFee,
+    DependentTxSqueezedOut,
+    InputSpent,
+    TtlExpired,
+    PoolFull,
+    Validity { code: Option<u16> },
+}
+
+impl From<&SchemaSqueezedOutStatus> for RejectionReason {
+    fn from(status: &SchemaSqueezedOutStatus) -> Self {
+        match status.rejection_reason {
+            SchemaRejectionReason::Unknown => RejectionReason::Unknown,
+            SchemaRejectionReason::InsufficientFee => RejectionReason::InsufficientFee,
+            SchemaRejectionReason::DependentTxSqueezedOut => {
+                RejectionReason::DependentTxSqueezedOut
+            }
+            SchemaRejectionReason::InputSpent => RejectionReason::InputSpent,
+            SchemaRejectionReason::TtlExpired => RejectionReason::TtlExpired,
+            SchemaRejectionReason::PoolFull => RejectionReason::PoolFull,
+            SchemaRejectionReason::Validity => RejectionReason::Validity {
+                code: status.rejection_code.as_ref().map(|code| code.0),
+            },
+        }
+    }
+}
+
 impl TransactionStatus {
     pub fn is_final(&self) -> bool {
         match self {
@@ -252,9 +326,10 @@ impl TryFrom<SchemaTxStatus> for TransactionStatus {
                     },
                 }
             }
-            SchemaTxStatus::SqueezedOutStatus(s) => {
-                TransactionStatus::SqueezedOut { reason: s.reason }
-            }
+            SchemaTxStatus::SqueezedOutStatus(s) => TransactionStatus::SqueezedOut {
+                rejection_reason: (&s).into(),
+                reason: s.reason,
+            },
             SchemaTxStatus::Unknown => {
                 return Err(Self::Error::UnknownVariant("SchemaTxStatus"))
             }
@@ -285,6 +360,7 @@ pub enum StatusWithTransaction {
     },
     SqueezedOut {
         reason: String,
+        rejection_reason: RejectionReason,
     },
     Failure {
         transaction: Transaction,
@@ -401,7 +477,10 @@ impl TryFrom<SchemaStatusWithTx> for StatusWithTransaction {
             }
 
             SchemaStatusWithTx::SqueezedOutStatus(s) => {
-                StatusWithTransaction::SqueezedOut { reason: s.reason }
+                StatusWithTransaction::SqueezedOut {
+                    rejection_reason: (&s).into(),
+                    reason: s.reason,
+                }
             }
             SchemaStatusWithTx::Unknown => {
                 return Err(Self::Error::UnknownVariant("SchemaTxStatus"))
@@ -430,6 +509,21 @@ impl TryFrom<OpaqueTransactionWithStatus> for TransactionResponse {
     }
 }
 
+impl From<SchemaPreRegenesisTransaction> for PreRegenesisTransaction {
+    fn from(value: SchemaPreRegenesisTransaction) -> Self {
+        let bytes = value.raw_payload.0 .0;
+        let transaction = Transaction::from_bytes(bytes.as_slice())
+            .map(Into::into)
+            .unwrap_or(TransactionType::Unknown);
+
+        Self {
+            id: value.id.into(),
+            transaction,
+            regenesis_height: value.regenesis_height.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RelayedTransactionStatus {
     Failed {
@@ -458,6 +552,75 @@ impl TryFrom<SchemaRelayedTransactionStatus> for RelayedTransactionStatus {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelayedTransaction {
+    pub id: primitives::Bytes32,
+    pub status: RelayedTransactionStatus,
+}
+
+impl TryFrom<SchemaRelayedTransaction> for RelayedTransaction {
+    type Error = ConversionError;
+
+    fn try_from(value: SchemaRelayedTransaction) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id.into(),
+            status: value.status.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<SchemaRelayedTransactionConnection>
+    for PaginatedResult<RelayedTransaction, String>
+{
+    type Error = ConversionError;
+
+    fn try_from(conn: SchemaRelayedTransactionConnection) -> Result<Self, Self::Error> {
+        Ok(PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn
+                .edges
+                .into_iter()
+                .map(|e| e.node.try_into())
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/// The summary of the transaction in the TxPool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PendingTransaction {
+    pub id: TxId,
+    pub tip: u64,
+    pub max_gas: u64,
+    pub inserted_at: Tai64,
+}
+
+impl From<SchemaPendingTransaction> for PendingTransaction {
+    fn from(value: SchemaPendingTransaction) -> Self {
+        Self {
+            id: value.id.into(),
+            tip: value.tip.into(),
+            max_gas: value.max_gas.into(),
+            inserted_at: value.inserted_at.0,
+        }
+    }
+}
+
+impl From<SchemaPendingTransactionConnection>
+    for PaginatedResult<PendingTransaction, String>
+{
+    fn from(conn: SchemaPendingTransactionConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 #[allow(clippy::large_enum_variant)]
 pub enum TransactionType {
@@ -0,0 +1,116 @@
+use crate::client::{
+    schema::{
+        self,
+        ConversionError,
+    },
+    types::{
+        Coin,
+        Message,
+    },
+    PaginatedResult,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        Address,
+        Transaction,
+    },
+    fuel_types::BlockHeight,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AccountSummary {
+    pub owner: Address,
+    pub first_seen_block: BlockHeight,
+    pub last_active_block: BlockHeight,
+    pub total_transactions: u64,
+    pub coin_count: u64,
+}
+
+/// The number of entries removed from each off-chain index of the owner.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PrunedOwnerIndexes {
+    pub owned_coins: u64,
+    pub owned_message_ids: u64,
+    pub owned_transactions: u64,
+    pub owned_coin_counts: u64,
+    pub owner_activities: u64,
+}
+
+/// The entry of the owner's activity feed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OwnerActivityItem {
+    Coin(Coin),
+    Message(Message),
+    Transaction(Transaction),
+}
+
+// GraphQL Translation
+
+impl From<schema::account::AccountSummary> for AccountSummary {
+    fn from(value: schema::account::AccountSummary) -> Self {
+        AccountSummary {
+            owner: value.owner.into(),
+            first_seen_block: value.first_seen_block.into(),
+            last_active_block: value.last_active_block.into(),
+            total_transactions: value.total_transactions.into(),
+            coin_count: value.coin_count.into(),
+        }
+    }
+}
+
+impl From<schema::account::PrunedOwnerIndexes> for PrunedOwnerIndexes {
+    fn from(value: schema::account::PrunedOwnerIndexes) -> Self {
+        PrunedOwnerIndexes {
+            owned_coins: value.owned_coins.into(),
+            owned_message_ids: value.owned_message_ids.into(),
+            owned_transactions: value.owned_transactions.into(),
+            owned_coin_counts: value.owned_coin_counts.into(),
+            owner_activities: value.owner_activities.into(),
+        }
+    }
+}
+
+impl TryFrom<schema::account::OwnerActivityItem> for OwnerActivityItem {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::account::OwnerActivityItem) -> Result<Self, Self::Error> {
+        let item = match value {
+            schema::account::OwnerActivityItem::Coin(coin) => {
+                OwnerActivityItem::Coin(coin.into())
+            }
+            schema::account::OwnerActivityItem::Message(message) => {
+                OwnerActivityItem::Message(message.into())
+            }
+            schema::account::OwnerActivityItem::Transaction(tx) => {
+                OwnerActivityItem::Transaction(tx.try_into()?)
+            }
+            schema::account::OwnerActivityItem::Unknown => {
+                return Err(ConversionError::UnknownVariant("OwnerActivityItem"))
+            }
+        };
+        Ok(item)
+    }
+}
+
+impl TryFrom<schema::account::OwnerActivityItemConnection>
+    for PaginatedResult<OwnerActivityItem, String>
+{
+    type Error = ConversionError;
+
+    fn try_from(
+        conn: schema::account::OwnerActivityItemConnection,
+    ) -> Result<Self, Self::Error> {
+        let results = conn
+            .edges
+            .into_iter()
+            .map(|e| e.node.try_into())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results,
+        })
+    }
+}
@@ -1,8 +1,16 @@
-use crate::client::schema;
+use crate::client::{
+    schema,
+    PaginatedResult,
+};
 use fuel_core_types::{
     fuel_tx::Bytes32,
-    fuel_types::ContractId,
+    fuel_types::{
+        AssetId,
+        BlockHeight,
+        ContractId,
+    },
 };
+use tai64::Tai64;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct AssetDetail {
@@ -11,6 +19,27 @@ pub struct AssetDetail {
     pub total_supply: u128,
 }
 
+/// The asset existing on the chain. The `detail` is `None` for the base asset.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChainAsset {
+    pub asset_id: AssetId,
+    pub detail: Option<AssetDetail>,
+}
+
+/// The block where the asset was seen for the first time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AssetFirstSeen {
+    pub block_height: BlockHeight,
+    pub time: Tai64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AssetSupplyChange {
+    pub block_height: BlockHeight,
+    pub delta: i128,
+    pub total_supply: u128,
+}
+
 // GraphQL Translation
 
 impl From<schema::assets::AssetInfoDetails> for AssetDetail {
@@ -22,3 +51,63 @@ impl From<schema::assets::AssetInfoDetails> for AssetDetail {
         }
     }
 }
+
+impl From<schema::assets::ChainAsset> for ChainAsset {
+    fn from(value: schema::assets::ChainAsset) -> Self {
+        let detail = match (value.contract_id, value.sub_id, value.total_supply) {
+            (Some(contract_id), Some(sub_id), Some(total_supply)) => Some(AssetDetail {
+                contract_id: contract_id.into(),
+                sub_id: sub_id.into(),
+                total_supply: total_supply.into(),
+            }),
+            _ => None,
+        };
+        ChainAsset {
+            asset_id: value.asset_id.into(),
+            detail,
+        }
+    }
+}
+
+impl From<schema::assets::ChainAssetConnection> for PaginatedResult<ChainAsset, String> {
+    fn from(conn: schema::assets::ChainAssetConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
+
+impl From<schema::assets::AssetFirstSeen> for AssetFirstSeen {
+    fn from(value: schema::assets::AssetFirstSeen) -> Self {
+        AssetFirstSeen {
+            block_height: value.block_height.into(),
+            time: value.time.0,
+        }
+    }
+}
+
+impl From<schema::assets::AssetSupplyChange> for AssetSupplyChange {
+    fn from(value: schema::assets::AssetSupplyChange) -> Self {
+        AssetSupplyChange {
+            block_height: value.block_height.into(),
+            delta: value.delta.into(),
+            total_supply: value.total_supply.into(),
+        }
+    }
+}
+
+impl From<schema::assets::AssetSupplyChangeConnection>
+    for PaginatedResult<AssetSupplyChange, String>
+{
+    fn from(conn: schema::assets::AssetSupplyChangeConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
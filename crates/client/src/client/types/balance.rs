@@ -6,6 +6,7 @@ use crate::client::{
     },
     PaginatedResult,
 };
+use fuel_core_types::fuel_types::BlockHeight;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Balance {
@@ -14,6 +15,15 @@ pub struct Balance {
     pub asset_id: AssetId,
 }
 
+/// The balance of the owner after the block that modified it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BalanceUpdate {
+    pub owner: Address,
+    pub asset_id: AssetId,
+    pub amount: u128,
+    pub block_height: BlockHeight,
+}
+
 // GraphQL Translation
 
 impl From<schema::balance::Balance> for Balance {
@@ -29,6 +39,17 @@ impl From<schema::balance::Balance> for Balance {
     }
 }
 
+impl From<schema::balance::BalanceUpdate> for BalanceUpdate {
+    fn from(value: schema::balance::BalanceUpdate) -> Self {
+        BalanceUpdate {
+            owner: value.owner.into(),
+            asset_id: value.asset_id.into(),
+            amount: value.amount.into(),
+            block_height: value.block_height.into(),
+        }
+    }
+}
+
 impl From<schema::balance::BalanceConnection> for PaginatedResult<Balance, String> {
     fn from(conn: schema::balance::BalanceConnection) -> Self {
         PaginatedResult {
@@ -33,6 +33,24 @@ impl Block {
     }
 }
 
+/// A block of the chain from before the regenesis, retained by the node.
+/// The block was produced under the rules of the old chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreRegenesisBlock {
+    pub id: BlockId,
+    pub header: Header,
+    pub consensus: Consensus,
+    pub transactions: Vec<TransactionId>,
+    /// The height of the genesis block of the current chain.
+    pub regenesis_height: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignedHeader {
+    pub header: Header,
+    pub consensus: Consensus,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Header {
     pub id: BlockId,
@@ -73,6 +91,27 @@ pub struct PoAConsensus {
     pub signature: Signature,
 }
 
+/// The fees in the base asset collected by the block producers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CollectedFees {
+    /// The fee paid for the gas, without the tips.
+    pub base_fee: u64,
+    /// The tips paid by the transactions on top of the base fee.
+    pub tip: u64,
+    pub total: u64,
+}
+
+/// The rollback of the node from the `from_height` to the `to_height`.
+/// The blocks above the `to_height` are removed from the node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChainRollback {
+    pub from_height: u32,
+    pub to_height: u32,
+    /// The ids of the removed blocks in the order of their heights. `None` if
+    /// too many blocks were removed, or if the ids of the blocks are unknown.
+    pub block_ids: Option<Vec<BlockId>>,
+}
+
 // GraphQL Translation
 
 impl TryFrom<schema::block::Header> for Header {
@@ -149,6 +188,28 @@ impl From<schema::block::Genesis> for Genesis {
     }
 }
 
+impl From<schema::block::CollectedFees> for CollectedFees {
+    fn from(value: schema::block::CollectedFees) -> Self {
+        Self {
+            base_fee: value.base_fee.into(),
+            tip: value.tip.into(),
+            total: value.total.into(),
+        }
+    }
+}
+
+impl From<schema::block::ChainRollback> for ChainRollback {
+    fn from(value: schema::block::ChainRollback) -> Self {
+        Self {
+            from_height: value.from_height.into(),
+            to_height: value.to_height.into(),
+            block_ids: value
+                .block_ids
+                .map(|block_ids| block_ids.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
 impl From<schema::block::PoAConsensus> for PoAConsensus {
     fn from(value: schema::block::PoAConsensus) -> Self {
         let bytes: [u8; 64] = value.signature.0 .0.into();
@@ -186,6 +247,31 @@ impl TryFrom<schema::block::Block> for Block {
     }
 }
 
+impl TryFrom<schema::block::PreRegenesisBlock> for PreRegenesisBlock {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::block::PreRegenesisBlock) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id.into(),
+            header: value.header.try_into()?,
+            consensus: value.consensus.into(),
+            transactions: value.transaction_ids.into_iter().map(Into::into).collect(),
+            regenesis_height: value.regenesis_height.into(),
+        })
+    }
+}
+
+impl TryFrom<schema::block::SignedHeader> for SignedHeader {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::block::SignedHeader) -> Result<Self, Self::Error> {
+        Ok(Self {
+            header: value.header.try_into()?,
+            consensus: value.consensus.into(),
+        })
+    }
+}
+
 impl TryFrom<schema::block::BlockConnection> for PaginatedResult<Block, String> {
     type Error = ConversionError;
 
@@ -202,3 +288,20 @@ impl TryFrom<schema::block::BlockConnection> for PaginatedResult<Block, String>
         })
     }
 }
+
+impl TryFrom<schema::block::HeaderConnection> for PaginatedResult<Header, String> {
+    type Error = ConversionError;
+
+    fn try_from(conn: schema::block::HeaderConnection) -> Result<Self, Self::Error> {
+        Ok(PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn
+                .edges
+                .into_iter()
+                .map(|e| e.node.try_into())
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
@@ -3,13 +3,15 @@ use crate::client::{
     types::primitives::{
         Address,
         AssetId,
+        Bytes,
         Nonce,
+        TransactionId,
         UtxoId,
     },
     PaginatedResult,
 };
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum CoinType {
     Coin(Coin),
     MessageCoin(MessageCoin),
@@ -36,13 +38,30 @@ pub struct Coin {
     pub owner: Address,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MessageCoin {
     pub amount: u64,
     pub sender: Address,
     pub recipient: Address,
     pub nonce: Nonce,
     pub da_height: u64,
+    pub data: Bytes,
+}
+
+/// The transaction that spent the coin and the height of its block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CoinSpender {
+    pub transaction_id: TransactionId,
+    pub block_height: u32,
+}
+
+/// The small coins of the owner worth combining into one coin.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsolidationSuggestion {
+    pub coins: Vec<CoinType>,
+    pub total: u128,
+    pub remaining_dust_coins: u64,
+    pub remaining_dust: u128,
 }
 
 // GraphQL Translation
@@ -72,6 +91,15 @@ impl From<schema::coins::Coin> for Coin {
     }
 }
 
+impl From<schema::coins::CoinSpender> for CoinSpender {
+    fn from(value: schema::coins::CoinSpender) -> Self {
+        Self {
+            transaction_id: value.transaction_id.into(),
+            block_height: value.block_height.into(),
+        }
+    }
+}
+
 impl From<schema::coins::MessageCoin> for MessageCoin {
     fn from(value: schema::coins::MessageCoin) -> Self {
         Self {
@@ -80,6 +108,18 @@ impl From<schema::coins::MessageCoin> for MessageCoin {
             recipient: value.recipient.into(),
             nonce: value.nonce.into(),
             da_height: value.da_height.into(),
+            data: value.data.into(),
+        }
+    }
+}
+
+impl From<schema::coins::ConsolidationSuggestion> for ConsolidationSuggestion {
+    fn from(value: schema::coins::ConsolidationSuggestion) -> Self {
+        Self {
+            coins: value.coins.into_iter().map(Into::into).collect(),
+            total: value.total.into(),
+            remaining_dust_coins: value.remaining_dust_coins.into(),
+            remaining_dust: value.remaining_dust.into(),
         }
     }
 }
@@ -5,9 +5,11 @@ use crate::client::{
         Bytes,
         ContractId,
         Salt,
+        TransactionId,
     },
     PaginatedResult,
 };
+use fuel_core_types::fuel_tx::TxPointer;
 
 pub struct Contract {
     pub id: ContractId,
@@ -22,6 +24,14 @@ pub struct ContractBalance {
     pub asset_id: AssetId,
 }
 
+#[derive(Copy, Clone, Debug)]
+pub struct DeployedContract {
+    pub contract_id: ContractId,
+    pub tx_id: TransactionId,
+    pub tx_pointer: TxPointer,
+    pub salt: Salt,
+}
+
 // GraphQL Translation
 
 impl From<schema::contract::Contract> for Contract {
@@ -56,3 +66,27 @@ impl From<schema::contract::ContractBalanceConnection>
         }
     }
 }
+
+impl From<schema::contract::DeployedContract> for DeployedContract {
+    fn from(value: schema::contract::DeployedContract) -> Self {
+        Self {
+            contract_id: value.contract_id.into(),
+            tx_id: value.tx_id.into(),
+            tx_pointer: value.tx_pointer.into(),
+            salt: value.salt.into(),
+        }
+    }
+}
+
+impl From<schema::contract::DeployedContractConnection>
+    for PaginatedResult<DeployedContract, String>
+{
+    fn from(conn: schema::contract::DeployedContractConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
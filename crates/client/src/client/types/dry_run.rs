@@ -0,0 +1,44 @@
+use crate::client::schema::{
+    self,
+    ConversionError,
+};
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::executor::TransactionExecutionStatus,
+};
+
+/// The result of the dry-run on top of the state after the block at the `state_height`.
+#[derive(Clone, Debug)]
+pub struct DryRunAtHeight {
+    pub state_height: BlockHeight,
+    pub statuses: Vec<TransactionExecutionStatus>,
+}
+
+// GraphQL Translation
+
+impl DryRunAtHeight {
+    pub(crate) fn try_from_schema(
+        requested_height: BlockHeight,
+        statuses: Vec<schema::tx::DryRunAtHeightTransactionExecutionStatus>,
+    ) -> Result<Self, ConversionError> {
+        let state_height = statuses
+            .first()
+            .and_then(|status| status.state_height)
+            .map(Into::into)
+            .unwrap_or(requested_height);
+        let statuses = statuses
+            .into_iter()
+            .map(|status| {
+                Ok(TransactionExecutionStatus {
+                    id: status.id.into(),
+                    result: status.status.try_into()?,
+                })
+            })
+            .collect::<Result<Vec<_>, ConversionError>>()?;
+
+        Ok(Self {
+            state_height,
+            statuses,
+        })
+    }
+}
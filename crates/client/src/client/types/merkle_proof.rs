@@ -1,6 +1,10 @@
 use crate::client::{
     schema,
-    types::primitives::MerkleRoot,
+    schema::ConversionError,
+    types::{
+        block::Header,
+        primitives::MerkleRoot,
+    },
 };
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -11,6 +15,15 @@ pub struct MerkleProof {
     pub proof_index: u64,
 }
 
+/// The proof that the transaction is included in the block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionInclusionProof {
+    /// The proof against the `transactions_root` of the `block_header`.
+    pub proof: MerkleProof,
+    /// The header of the block that includes the transaction.
+    pub block_header: Header,
+}
+
 // GraphQL Translation
 
 impl From<schema::message::MerkleProof> for MerkleProof {
@@ -28,3 +41,16 @@ impl From<schema::message::MerkleProof> for MerkleProof {
         }
     }
 }
+
+impl TryFrom<schema::tx::TransactionInclusionProof> for TransactionInclusionProof {
+    type Error = ConversionError;
+
+    fn try_from(
+        value: schema::tx::TransactionInclusionProof,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proof: value.proof.into(),
+            block_header: value.block_header.try_into()?,
+        })
+    }
+}
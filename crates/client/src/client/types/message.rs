@@ -7,6 +7,7 @@ use crate::client::{
             Address,
             Bytes,
             Nonce,
+            TransactionId,
         },
         MerkleProof,
     },
@@ -21,6 +22,16 @@ pub struct Message {
     pub nonce: Nonce,
     pub data: Bytes,
     pub da_height: u64,
+    /// Set for the spent messages returned by `messages_including_spent`.
+    pub spent_status: Option<MessageSpentStatus>,
+}
+
+/// The block and the transaction that consumed the message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MessageSpentStatus {
+    pub block_height: u32,
+    /// Unknown if the message wasn't consumed by an input.
+    pub transaction_id: Option<TransactionId>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -75,6 +86,16 @@ impl From<schema::message::Message> for Message {
             nonce: value.nonce.into(),
             data: value.data.into(),
             da_height: value.da_height.into(),
+            spent_status: value.spent_status.map(Into::into),
+        }
+    }
+}
+
+impl From<schema::message::MessageSpentStatus> for MessageSpentStatus {
+    fn from(value: schema::message::MessageSpentStatus) -> Self {
+        Self {
+            block_height: value.block_height.into(),
+            transaction_id: value.transaction_id.map(Into::into),
         }
     }
 }
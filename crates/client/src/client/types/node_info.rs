@@ -2,6 +2,7 @@ use crate::client::schema::{
     self,
     node_info::{
         IndexationFlags,
+        OffChainCommitDuration,
         TxPoolStats,
     },
 };
@@ -16,6 +17,9 @@ pub struct NodeInfo {
     pub max_depth: u64,
     pub node_version: String,
     pub indexation: IndexationFlags,
+    pub indexed_height: Option<u32>,
+    pub da_compression_height: Option<u32>,
+    pub off_chain_commit_duration_max: Option<OffChainCommitDuration>,
     pub tx_pool_stats: TxPoolStats,
 }
 
@@ -32,6 +36,9 @@ impl From<schema::node_info::NodeInfo> for NodeInfo {
             max_depth: value.max_depth.into(),
             node_version: value.node_version,
             indexation: value.indexation,
+            indexed_height: value.indexed_height.map(Into::into),
+            da_compression_height: value.da_compression_height.map(Into::into),
+            off_chain_commit_duration_max: value.off_chain_commit_duration_max,
             tx_pool_stats: value.tx_pool_stats,
         }
     }
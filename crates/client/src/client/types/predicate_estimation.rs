@@ -0,0 +1,26 @@
+use crate::client::schema;
+
+/// The result of the estimation of the predicate of the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PredicateEstimation {
+    /// The index of the predicate input in the transaction.
+    pub input_index: u16,
+    pub success: bool,
+    /// The gas used by the predicate, `None` if the predicate has failed.
+    pub gas_used: Option<u64>,
+    /// The reason of the failure, `None` if the predicate has succeeded.
+    pub failure_reason: Option<String>,
+}
+
+// GraphQL Translation
+
+impl From<schema::tx::PredicateEstimation> for PredicateEstimation {
+    fn from(value: schema::tx::PredicateEstimation) -> Self {
+        Self {
+            input_index: value.input_index.into(),
+            success: value.success,
+            gas_used: value.gas_used.map(Into::into),
+            failure_reason: value.failure_reason,
+        }
+    }
+}
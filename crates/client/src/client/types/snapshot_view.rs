@@ -0,0 +1,23 @@
+use crate::client::schema;
+use fuel_core_types::fuel_types::BlockHeight;
+
+/// The view of the node's database pinned at the block height.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SnapshotView {
+    /// The token to pass via the [`FuelClient::with_snapshot_token`](crate::client::FuelClient::with_snapshot_token).
+    pub token: String,
+    pub height: BlockHeight,
+    pub expires_in_seconds: u64,
+}
+
+// GraphQL Translation
+
+impl From<schema::SnapshotView> for SnapshotView {
+    fn from(value: schema::SnapshotView) -> Self {
+        Self {
+            token: value.token,
+            height: value.height.into(),
+            expires_in_seconds: value.expires_in_seconds.into(),
+        }
+    }
+}
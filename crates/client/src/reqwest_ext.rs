@@ -19,6 +19,9 @@ use std::{
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct ExtensionsRequest {
     pub required_fuel_block_height: Option<BlockHeight>,
+    /// The token of the view pinned by the `snapshotView` query.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot_token: Option<String>,
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -53,9 +56,16 @@ impl<Operation> FuelOperation<Operation> {
             operation,
             extensions: ExtensionsRequest {
                 required_fuel_block_height,
+                snapshot_token: None,
             },
         }
     }
+
+    /// Reads the state from the view pinned under the `snapshot_token`.
+    pub fn with_snapshot_token(mut self, snapshot_token: Option<String>) -> Self {
+        self.extensions.snapshot_token = snapshot_token;
+        self
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -136,7 +136,11 @@ macro_rules! compression {
                         $ident: CompressCtxKeyspace {
                             changes: Default::default(),
                             changes_lookup: Default::default(),
-                            cache_evictor: CacheEvictor::new_from_db(&mut self.db, self.accessed_keys.$ident.into())?,
+                            cache_evictor: CacheEvictor::new_from_db(
+                                &mut self.db,
+                                self.accessed_keys.$ident.into(),
+                                self.config.registry_capacity,
+                            )?,
                         },
                     )*
                     config: self.config,
@@ -153,9 +157,10 @@ macro_rules! compression {
                 let mut registrations = RegistrationsPerTable::default();
                 $(
                     self.$ident.cache_evictor.commit(&mut self.db)?;
-                    for (key, value) in self.$ident.changes.into_iter() {
-                        registrations.$ident.push((key, value));
-                    }
+                    let mut changes: Vec<_> = self.$ident.changes.into_iter().collect();
+                    // Ordered by the key, so every node produces the same block
+                    changes.sort_by_key(|(key, _)| key.as_u32());
+                    registrations.$ident.extend(changes);
                 )*
                 registrations.write_to_registry(&mut self.db, self.timestamp)?;
                 Ok(registrations)
@@ -209,7 +214,7 @@ macro_rules! compression {
                         }
                     }
 
-                    let key = ctx.$ident.cache_evictor.next_key();
+                    let key = ctx.$ident.cache_evictor.next_key(&ctx.db)?;
                     let old = ctx.$ident.changes.insert(key, self.clone());
                     let old_rev = ctx.$ident.changes_lookup.insert(self.clone(), key);
                     debug_assert!(old.is_none(), "Key collision in registry substitution");
@@ -1,4 +1,7 @@
-use core::time::Duration;
+use core::{
+    num::NonZeroU32,
+    time::Duration,
+};
 
 use fuel_core_types::tai64::{
     Tai64,
@@ -11,6 +14,11 @@ pub struct Config {
     /// After this time has passed, the entry is considered stale and must not be used.
     /// If the value is needed again, it must be re-registered.
     pub temporal_registry_retention: Duration,
+    /// The maximum number of entries in each keyspace of the temporal registry.
+    /// Once every key below the capacity holds a registration, the evictor
+    /// overwrites the oldest one, see [`EvictorCursor::assign`](crate::eviction_policy::EvictorCursor::assign).
+    /// `None` allows the keyspace to use all registry keys.
+    pub registry_capacity: Option<NonZeroU32>,
 }
 
 impl Config {
@@ -1,8 +1,127 @@
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    num::NonZeroU32,
+};
 
-use fuel_core_types::fuel_compression::RegistryKey;
+use fuel_core_types::{
+    fuel_compression::RegistryKey,
+    tai64::Tai64,
+};
 
-use crate::ports::EvictorDb;
+use crate::ports::{
+    EvictorDb,
+    TemporalRegistry,
+};
+
+/// The number of keys after the cursor examined to find the oldest registration
+/// once the keyspace is full. The window keeps the number of timestamp reads
+/// per registration bounded.
+pub const EVICTION_WINDOW: u32 = 8;
+
+/// The position of the evictor in a keyspace, shared by the evictor
+/// and the code predicting its next evictions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EvictorCursor {
+    /// The key assigned by the last registration, `None` if nothing is registered yet.
+    pub latest_assigned_key: Option<RegistryKey>,
+    /// The number of keys of the keyspace holding a registration.
+    pub entry_count: u32,
+}
+
+impl EvictorCursor {
+    /// Creates the cursor from the stored state. The count stored before
+    /// it was tracked is derived from the latest assigned key, as the keys
+    /// are assigned in order until the keyspace is full.
+    pub fn new(
+        latest_assigned_key: Option<RegistryKey>,
+        entry_count: Option<u32>,
+    ) -> Self {
+        let entry_count = entry_count.unwrap_or_else(|| {
+            latest_assigned_key
+                .map(|key| key.as_u32().saturating_add(1))
+                .unwrap_or_default()
+        });
+        Self {
+            latest_assigned_key,
+            entry_count,
+        }
+    }
+
+    /// Is every key allowed by the `capacity` holding a registration?
+    /// The keyspace without the capacity is never full.
+    pub fn is_full(&self, capacity: Option<NonZeroU32>) -> bool {
+        capacity.is_some_and(|capacity| self.entry_count >= capacity.get())
+    }
+
+    /// Assigns the key to the next registration and moves the cursor to it.
+    ///
+    /// Until the keyspace is full, the keys are assigned in order. Once it is full,
+    /// the entry with the oldest timestamp among the [`EVICTION_WINDOW`] keys after
+    /// the cursor is overwritten, the first of them in the case of a tie.
+    /// The keys for which `keep` returns `true` are never assigned.
+    pub fn assign<K, F>(
+        &mut self,
+        capacity: Option<NonZeroU32>,
+        keep: K,
+        mut timestamp: F,
+    ) -> anyhow::Result<RegistryKey>
+    where
+        K: Fn(&RegistryKey) -> bool,
+        F: FnMut(&RegistryKey) -> anyhow::Result<Tai64>,
+    {
+        let full = self.is_full(capacity);
+        let mut key = self.first_candidate(capacity);
+        let mut oldest: Option<(Tai64, RegistryKey)> = None;
+        let mut examined: u32 = 0;
+        let mut scanned: u32 = 0;
+
+        let assigned = loop {
+            if let Some(capacity) = capacity {
+                if scanned >= capacity.get() {
+                    break oldest.map(|(_, key)| key)
+                }
+            }
+
+            if !keep(&key) {
+                if !full {
+                    break Some(key)
+                }
+                let key_timestamp = timestamp(&key)?;
+                let is_older = match oldest {
+                    Some((oldest_timestamp, _)) => key_timestamp < oldest_timestamp,
+                    None => true,
+                };
+                if is_older {
+                    oldest = Some((key_timestamp, key));
+                }
+                examined = examined.saturating_add(1);
+                if examined >= EVICTION_WINDOW {
+                    break oldest.map(|(_, key)| key)
+                }
+            }
+
+            scanned = scanned.saturating_add(1);
+            key = following_key(key, capacity);
+        };
+
+        let Some(key) = assigned else {
+            anyhow::bail!(
+                "All {scanned} keys of the registry keyspace are used by the block"
+            );
+        };
+
+        self.latest_assigned_key = Some(key);
+        self.entry_count = self.entry_count.max(key.as_u32().saturating_add(1));
+        Ok(key)
+    }
+
+    /// The first key examined by the next registration.
+    fn first_candidate(&self, capacity: Option<NonZeroU32>) -> RegistryKey {
+        self.latest_assigned_key
+            .map(|key| following_key(key, capacity))
+            .unwrap_or(RegistryKey::ZERO)
+    }
+}
 
 /// Evictor for a single keyspace
 #[derive(Debug)]
@@ -10,8 +129,12 @@ use crate::ports::EvictorDb;
 pub(crate) struct CacheEvictor<T> {
     /// Set of keys that must not be evicted
     keep_keys: HashSet<RegistryKey>,
-    /// Next key to be used
-    next_key: RegistryKey,
+    /// The position of the evictor in the keyspace
+    cursor: EvictorCursor,
+    /// The count of the entries read from the database, `None` if it wasn't stored
+    stored_entry_count: Option<u32>,
+    /// The number of keys available in the keyspace, all keys if `None`
+    capacity: Option<NonZeroU32>,
     /// Marker for the keyspace type
     _keyspace_marker: std::marker::PhantomData<T>,
 }
@@ -21,36 +144,41 @@ impl<T> CacheEvictor<T> {
     pub fn new_from_db<D>(
         db: &mut D,
         keep_keys: HashSet<RegistryKey>,
+        capacity: Option<NonZeroU32>,
     ) -> anyhow::Result<Self>
     where
         D: EvictorDb<T>,
     {
-        let latest_key = db.get_latest_assigned_key()?;
-        let next_key = if let Some(latest_key) = latest_key {
-            latest_key.next()
-        } else {
-            RegistryKey::ZERO
-        };
+        let stored_entry_count = db.get_entry_count()?;
+        let cursor =
+            EvictorCursor::new(db.get_latest_assigned_key()?, stored_entry_count);
 
         Ok(Self {
             keep_keys,
-            next_key,
+            cursor,
+            stored_entry_count,
+            capacity,
             _keyspace_marker: std::marker::PhantomData,
         })
     }
 
-    pub fn next_key(&mut self) -> RegistryKey {
-        // Pick first key not in the set
-        // TODO: use a proper algo, maybe LRU?
-
+    /// Picks the key for the next registration, reading the timestamps
+    /// of the registrations from the `db` once the keyspace is full.
+    pub fn next_key<D>(&mut self, db: &D) -> anyhow::Result<RegistryKey>
+    where
+        D: TemporalRegistry<T>,
+    {
         debug_assert!(self.keep_keys.len() < 2usize.pow(24).saturating_sub(2));
 
-        while self.keep_keys.contains(&self.next_key) {
-            self.next_key = self.next_key.next();
-        }
+        let keep_keys = &self.keep_keys;
+        let key = self.cursor.assign(
+            self.capacity,
+            |key| keep_keys.contains(key),
+            |key| db.read_timestamp(key),
+        )?;
 
-        self.keep_keys.insert(self.next_key);
-        self.next_key
+        self.keep_keys.insert(key);
+        Ok(key)
     }
 
     /// Commit the current state of the evictor to the database
@@ -58,6 +186,123 @@ impl<T> CacheEvictor<T> {
     where
         D: EvictorDb<T>,
     {
-        db.set_latest_assigned_key(self.next_key)
+        if let Some(key) = self.cursor.latest_assigned_key {
+            db.set_latest_assigned_key(key)?;
+        }
+        if self.stored_entry_count != Some(self.cursor.entry_count) {
+            db.set_entry_count(self.cursor.entry_count)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the key after the `key`, wrapping to the first key
+/// once the `capacity` of the keyspace is reached.
+pub fn following_key(key: RegistryKey, capacity: Option<NonZeroU32>) -> RegistryKey {
+    let next = key.next();
+    match capacity {
+        Some(capacity) if next.as_u32() >= capacity.get() => RegistryKey::ZERO,
+        _ => next,
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+
+    fn key(key: u32) -> RegistryKey {
+        RegistryKey::try_from(key).unwrap()
+    }
+
+    fn capacity(capacity: u32) -> Option<NonZeroU32> {
+        NonZeroU32::new(capacity)
+    }
+
+    #[test]
+    fn assign__uses_the_keys_in_order_until_the_keyspace_is_full() {
+        // given
+        let mut cursor = EvictorCursor::new(None, None);
+
+        // when
+        let keys = (0..3)
+            .map(|_| {
+                cursor
+                    .assign(capacity(3), |_| false, |_| panic!("No timestamp is read"))
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        // then
+        assert_eq!(keys, vec![key(0), key(1), key(2)]);
+        assert_eq!(cursor.entry_count, 3);
+        assert!(cursor.is_full(capacity(3)));
+    }
+
+    #[test]
+    fn assign__overwrites_the_oldest_entry_once_the_keyspace_is_full() {
+        // given
+        let timestamps = [Tai64(30), Tai64(10), Tai64(20), Tai64(10)];
+        let mut cursor = EvictorCursor::new(Some(key(3)), Some(4));
+
+        // when
+        let assigned = cursor
+            .assign(
+                capacity(4),
+                |_| false,
+                |key| Ok(timestamps[key.as_u32() as usize]),
+            )
+            .unwrap();
+
+        // then
+        assert_eq!(assigned, key(1));
+        assert_eq!(cursor.latest_assigned_key, Some(key(1)));
+        assert_eq!(cursor.entry_count, 4);
+    }
+
+    #[test]
+    fn assign__skips_the_kept_keys_of_the_full_keyspace() {
+        // given
+        let timestamps = [Tai64(10), Tai64(20), Tai64(30)];
+        let mut cursor = EvictorCursor::new(Some(key(2)), Some(3));
+
+        // when
+        let assigned = cursor
+            .assign(
+                capacity(3),
+                |kept| *kept == key(0),
+                |key| Ok(timestamps[key.as_u32() as usize]),
+            )
+            .unwrap();
+
+        // then
+        assert_eq!(assigned, key(1));
+    }
+
+    #[test]
+    fn assign__fails_when_the_block_keeps_every_key() {
+        // given
+        let mut cursor = EvictorCursor::new(Some(key(1)), Some(2));
+
+        // when
+        let result = cursor.assign(capacity(2), |_| true, |_| Ok(Tai64(0)));
+
+        // then
+        assert!(result.is_err());
+        assert_eq!(cursor.latest_assigned_key, Some(key(1)));
+    }
+
+    #[test]
+    fn new__derives_the_missing_entry_count_from_the_latest_assigned_key() {
+        assert_eq!(EvictorCursor::new(None, None).entry_count, 0);
+        assert_eq!(EvictorCursor::new(Some(key(4)), None).entry_count, 5);
+        assert_eq!(EvictorCursor::new(Some(key(4)), Some(2)).entry_count, 2);
+    }
+
+    #[test]
+    fn following_key__wraps_at_the_capacity() {
+        assert_eq!(following_key(key(2), capacity(3)), key(0));
+        assert_eq!(following_key(key(1), capacity(3)), key(2));
+        assert_eq!(following_key(key(2), None), key(3));
     }
 }
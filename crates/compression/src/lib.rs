@@ -7,7 +7,7 @@ pub mod compress;
 mod compressed_block_payload;
 pub mod config;
 pub mod decompress;
-mod eviction_policy;
+pub mod eviction_policy;
 pub mod ports;
 mod registry;
 
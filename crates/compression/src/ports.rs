@@ -105,6 +105,18 @@ pub struct MessageInfo {
 pub trait EvictorDb<T> {
     fn get_latest_assigned_key(&self) -> anyhow::Result<Option<RegistryKey>>;
     fn set_latest_assigned_key(&mut self, key: RegistryKey) -> anyhow::Result<()>;
+
+    /// Returns the number of keys holding a registration, `None` if it isn't stored.
+    /// The evictor derives the count from the latest assigned key in that case.
+    fn get_entry_count(&self) -> anyhow::Result<Option<u32>> {
+        Ok(None)
+    }
+
+    /// Stores the number of keys holding a registration.
+    /// The storage without the count ignores it.
+    fn set_entry_count(&mut self, _count: u32) -> anyhow::Result<()> {
+        Ok(())
+    }
 }
 
 impl<D, T> EvictorDb<T> for &mut D
@@ -118,4 +130,12 @@ where
     fn set_latest_assigned_key(&mut self, key: RegistryKey) -> anyhow::Result<()> {
         <D as EvictorDb<T>>::set_latest_assigned_key(self, key)
     }
+
+    fn get_entry_count(&self) -> anyhow::Result<Option<u32>> {
+        <D as EvictorDb<T>>::get_entry_count(self)
+    }
+
+    fn set_entry_count(&mut self, count: u32) -> anyhow::Result<()> {
+        <D as EvictorDb<T>>::set_entry_count(self, count)
+    }
 }
@@ -90,6 +90,11 @@ macro_rules! tables {
 
                 Ok(())
             }
+
+            /// The total number of registrations across all tables.
+            pub fn count(&self) -> usize {
+                0usize $(.saturating_add(self.$ident.len()))*
+            }
         }
     }};
 }
@@ -68,6 +68,30 @@ pub enum Error {
     NoHistoryForRequestedHeight { requested_height: u64 },
     #[display(fmt = "Reached the end of the history")]
     ReachedEndOfHistory,
+    /// The reverse diffs required for the rollback are pruned.
+    #[display(
+        fmt = "The {database} database can't be rolled back to the height {requested_height:#x}, \
+        because the reverse diffs of the blocks above it are pruned"
+    )]
+    DiffUnavailable {
+        /// The name of the database.
+        database: String,
+        /// The height requested by the rollback.
+        requested_height: u64,
+    },
+    /// The database contains a column unknown to this version of fuel-core.
+    #[display(
+        fmt = "The {database} database was created by a newer version of fuel-core \
+        (unknown column id {id}: {name}). Use `--allow-unknown-columns` to open it anyway"
+    )]
+    UnknownColumn {
+        /// The name of the database.
+        database: String,
+        /// The id of the unknown column.
+        id: u32,
+        /// The name of the column family of the unknown column.
+        name: String,
+    },
 
     #[cfg(feature = "backup")]
     #[display(fmt = "BackupEngine initialization error: {}", _0)]
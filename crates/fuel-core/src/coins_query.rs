@@ -13,6 +13,7 @@ use crate::{
 use core::mem::swap;
 use fuel_core_services::yield_stream::StreamYieldExt;
 use fuel_core_storage::{
+    iter::BoxedIter,
     Error as StorageError,
     Result as StorageResult,
 };
@@ -323,6 +324,66 @@ pub async fn select_coins_to_spend(
     .collect())
 }
 
+/// The small coins worth combining into one coin.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DustConsolidation {
+    /// The smallest coins below the dust threshold in ascending order of the amount.
+    pub coins: Vec<CoinsToSpendIndexKey>,
+    /// The total amount of the `coins`.
+    pub total: u128,
+    /// The number of coins below the dust threshold left out of the `coins`.
+    pub remaining_dust_coins: u64,
+    /// The total amount of the coins below the dust threshold left out of the `coins`.
+    pub remaining_dust: u128,
+}
+
+/// Picks up to `max_inputs` of the smallest coins below the `dust_threshold`
+/// to combine into one coin. The `dust_coins_iter` must iterate the coins
+/// in ascending order of the amount.
+///
+/// Combining a single coin doesn't reduce the dust, so no coins are picked
+/// if less than two coins are below the threshold.
+pub async fn select_dust_to_consolidate(
+    dust_coins_iter: BoxedIter<'_, StorageResult<CoinsToSpendIndexKey>>,
+    dust_threshold: u64,
+    max_inputs: u16,
+    batch_size: usize,
+) -> Result<DustConsolidation, CoinsQueryError> {
+    let mut dust_coins_stream =
+        futures::stream::iter(dust_coins_iter).yield_each(batch_size);
+    let mut consolidation = DustConsolidation::default();
+
+    while let Some(coin) = dust_coins_stream.next().await {
+        let coin = coin?;
+        let amount = coin.amount();
+        if amount >= dust_threshold {
+            break;
+        }
+        if consolidation.coins.len() < max_inputs as usize {
+            consolidation.total = consolidation.total.saturating_add(amount as u128);
+            consolidation.coins.push(coin);
+        } else {
+            consolidation.remaining_dust_coins =
+                consolidation.remaining_dust_coins.saturating_add(1);
+            consolidation.remaining_dust =
+                consolidation.remaining_dust.saturating_add(amount as u128);
+        }
+    }
+
+    if consolidation.coins.len() < 2 {
+        consolidation.remaining_dust_coins = consolidation
+            .remaining_dust_coins
+            .saturating_add(consolidation.coins.len() as u64);
+        consolidation.remaining_dust = consolidation
+            .remaining_dust
+            .saturating_add(consolidation.total);
+        consolidation.coins.clear();
+        consolidation.total = 0;
+    }
+
+    Ok(consolidation)
+}
+
 async fn big_coins(
     big_coins_stream: impl Stream<Item = StorageResult<CoinsToSpendIndexKey>> + Unpin,
     total: u128,
@@ -435,11 +496,13 @@ mod tests {
             storage::{
                 coins::{
                     owner_coin_id_key,
+                    OwnedCoinState,
                     OwnedCoins,
                 },
                 messages::{
                     OwnedMessageIds,
                     OwnedMessageKey,
+                    OwnedMessageState,
                 },
             },
         },
@@ -1086,6 +1149,7 @@ mod tests {
             coins_query::{
                 select_coins_to_spend,
                 select_coins_until,
+                select_dust_to_consolidate,
                 CoinsQueryError,
                 CoinsToSpendIndexKey,
             },
@@ -1455,6 +1519,63 @@ mod tests {
             assert!(matches!(result, Err(actual_error)
                 if CoinsQueryError::InsufficientCoinsForTheMax { asset_id, collected_amount: EXPECTED_COLLECTED_AMOUNT, max: MAX } == actual_error));
         }
+
+        #[tokio::test]
+        async fn select_dust_to_consolidate_picks_smallest_coins_up_to_max_inputs() {
+            // Given
+            const DUST_THRESHOLD: u64 = 10;
+            const MAX_INPUTS: u16 = 3;
+
+            let dust_coins_iter = setup_test_coins([1, 2, 3, 4, 5, 10, 20])
+                .into_iter()
+                .map(|spec| spec.index_entry)
+                .into_boxed();
+
+            // When
+            let result = select_dust_to_consolidate(
+                dust_coins_iter,
+                DUST_THRESHOLD,
+                MAX_INPUTS,
+                BATCH_SIZE,
+            )
+            .await
+            .expect("should not error");
+
+            // Then
+            let amounts: Vec<_> = result.coins.iter().map(|key| key.amount()).collect();
+            assert_eq!(amounts, vec![1, 2, 3]);
+            assert_eq!(result.total, 1 + 2 + 3);
+            assert_eq!(result.remaining_dust_coins, 2);
+            assert_eq!(result.remaining_dust, 4 + 5);
+        }
+
+        #[tokio::test]
+        async fn select_dust_to_consolidate_picks_nothing_for_single_dust_coin() {
+            // Given
+            const DUST_THRESHOLD: u64 = 10;
+            const MAX_INPUTS: u16 = 3;
+
+            let dust_coins_iter = setup_test_coins([5, 10, 20])
+                .into_iter()
+                .map(|spec| spec.index_entry)
+                .into_boxed();
+
+            // When
+            let result = select_dust_to_consolidate(
+                dust_coins_iter,
+                DUST_THRESHOLD,
+                MAX_INPUTS,
+                BATCH_SIZE,
+            )
+            .await
+            .expect("should not error");
+
+            // Then
+            assert!(result.coins.is_empty());
+            assert_eq!(result.total, 0);
+            assert_eq!(result.remaining_dust_coins, 1);
+            assert_eq!(result.remaining_dust, 5);
+        }
     }
 
     #[derive(Clone, Debug)]
@@ -1651,7 +1772,12 @@ mod tests {
             StorageMutate::<Coins>::insert(db, &id, &coin).unwrap();
             let db = self.database.off_chain_mut();
             let coin_by_owner = owner_coin_id_key(&owner, &id);
-            StorageMutate::<OwnedCoins>::insert(db, &coin_by_owner, &()).unwrap();
+            StorageMutate::<OwnedCoins>::insert(
+                db,
+                &coin_by_owner,
+                &OwnedCoinState::Unspent,
+            )
+            .unwrap();
 
             coin.uncompress(id)
         }
@@ -1674,8 +1800,12 @@ mod tests {
             StorageMutate::<Messages>::insert(db, message.id(), &message).unwrap();
             let db = self.database.off_chain_mut();
             let owned_message_key = OwnedMessageKey::new(&owner, &nonce);
-            StorageMutate::<OwnedMessageIds>::insert(db, &owned_message_key, &())
-                .unwrap();
+            StorageMutate::<OwnedMessageIds>::insert(
+                db,
+                &owned_message_key,
+                &OwnedMessageState::Unspent,
+            )
+            .unwrap();
 
             message
         }
@@ -1684,7 +1814,7 @@ mod tests {
             let query = self.service_database();
             let query = query.test_view();
             query
-                .owned_coins(owner, None, IterDirection::Forward)
+                .owned_coins(owner, None, IterDirection::Forward, false)
                 .try_collect()
                 .await
                 .unwrap()
@@ -11,11 +11,20 @@ use crate::{
             off_chain::OffChain,
             on_chain::OnChain,
             relayer::Relayer,
+            DatabaseDescription,
         },
         Database,
+        Error as DatabaseError,
         GenesisDatabase,
         Result as DatabaseResult,
     },
+    graphql_api::{
+        block_height_subscription::ChainRollbackNotifier,
+        storage::rollbacks::{
+            ChainRollback,
+            MAX_ROLLBACK_BLOCK_IDS,
+        },
+    },
     service::DbType,
 };
 #[cfg(feature = "test-helpers")]
@@ -34,8 +43,19 @@ use fuel_core_storage::tables::{
     ContractsState,
     Messages,
 };
-use fuel_core_storage::Result as StorageResult;
-use fuel_core_types::fuel_types::BlockHeight;
+use fuel_core_storage::{
+    not_found,
+    tables::FuelBlocks,
+    transactional::HistoricalView,
+    Result as StorageResult,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    blockchain::primitives::BlockId,
+    fuel_types::BlockHeight,
+};
+#[cfg(feature = "rocksdb")]
+use std::num::NonZeroU64;
 use std::path::PathBuf;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -46,6 +66,11 @@ pub struct CombinedDatabaseConfig {
     pub database_config: DatabaseConfig,
     #[cfg(feature = "rocksdb")]
     pub state_rewind_policy: StateRewindPolicy,
+    /// The number of the last blocks for which the off-chain database keeps
+    /// the reverse diffs of its indexes. The diffs are never kept for more
+    /// blocks than the `state_rewind_policy` allows to roll back.
+    #[cfg(feature = "rocksdb")]
+    pub index_diff_retention_blocks: Option<NonZeroU64>,
 }
 
 /// A database that combines the on-chain, off-chain and relayer databases into one entity.
@@ -55,6 +80,7 @@ pub struct CombinedDatabase {
     off_chain: Database<OffChain>,
     relayer: Database<Relayer>,
     gas_price: Database<GasPriceDatabase>,
+    rollback_notifier: ChainRollbackNotifier,
 }
 
 impl CombinedDatabase {
@@ -69,6 +95,7 @@ impl CombinedDatabase {
             off_chain,
             relayer,
             gas_price,
+            rollback_notifier: ChainRollbackNotifier::default(),
         }
     }
 
@@ -174,6 +201,23 @@ impl CombinedDatabase {
         path: &std::path::Path,
         state_rewind_policy: StateRewindPolicy,
         database_config: DatabaseConfig,
+    ) -> crate::database::Result<Self> {
+        Self::open_with_index_diff_retention(
+            path,
+            state_rewind_policy,
+            None,
+            database_config,
+        )
+    }
+
+    /// Opens the databases like [`Self::open`], but the off-chain database keeps
+    /// the reverse diffs only for the last `index_diff_retention_blocks` blocks.
+    #[cfg(feature = "rocksdb")]
+    pub fn open_with_index_diff_retention(
+        path: &std::path::Path,
+        state_rewind_policy: StateRewindPolicy,
+        index_diff_retention_blocks: Option<NonZeroU64>,
+        database_config: DatabaseConfig,
     ) -> crate::database::Result<Self> {
         // Split the fds in equitable manner between the databases
 
@@ -191,9 +235,13 @@ impl CombinedDatabase {
                 ..database_config
             },
         )?;
+        let off_chain_rewind_policy = match index_diff_retention_blocks {
+            Some(blocks) => state_rewind_policy.limited_to(blocks),
+            None => state_rewind_policy,
+        };
         let off_chain = Database::open_rocksdb(
             path,
-            state_rewind_policy,
+            off_chain_rewind_policy,
             DatabaseConfig {
                 max_fds,
                 ..database_config
@@ -215,12 +263,7 @@ impl CombinedDatabase {
                 ..database_config
             },
         )?;
-        Ok(Self {
-            on_chain,
-            off_chain,
-            relayer,
-            gas_price,
-        })
+        Ok(Self::new(on_chain, off_chain, relayer, gas_price))
     }
 
     /// A test-only temporary rocksdb database with given rewind policy.
@@ -229,12 +272,12 @@ impl CombinedDatabase {
         state_rewind_policy: StateRewindPolicy,
         database_config: DatabaseConfig,
     ) -> DatabaseResult<Self> {
-        Ok(Self {
-            on_chain: Database::rocksdb_temp(state_rewind_policy, database_config)?,
-            off_chain: Database::rocksdb_temp(state_rewind_policy, database_config)?,
-            relayer: Default::default(),
-            gas_price: Default::default(),
-        })
+        Ok(Self::new(
+            Database::rocksdb_temp(state_rewind_policy, database_config)?,
+            Database::rocksdb_temp(state_rewind_policy, database_config)?,
+            Default::default(),
+            Default::default(),
+        ))
     }
 
     pub fn from_config(config: &CombinedDatabaseConfig) -> DatabaseResult<Self> {
@@ -257,9 +300,10 @@ impl CombinedDatabase {
                         config.database_config.cache_capacity,
                         config.state_rewind_policy,
                     );
-                    CombinedDatabase::open(
+                    CombinedDatabase::open_with_index_diff_retention(
                         &config.database_path,
                         config.state_rewind_policy,
+                        config.index_diff_retention_blocks,
                         config.database_config,
                     )?
                 }
@@ -289,6 +333,23 @@ impl CombinedDatabase {
         Ok(())
     }
 
+    /// Returns `true` if any of the databases wasn't closed cleanly.
+    pub fn is_dirty(&self) -> StorageResult<bool> {
+        Ok(self.on_chain.is_dirty()?
+            || self.off_chain.is_dirty()?
+            || self.relayer.is_dirty()?
+            || self.gas_price.is_dirty()?)
+    }
+
+    /// Sets or clears the dirty flag of all databases, see [`Database::set_dirty`].
+    pub fn set_dirty(&self, dirty: bool) -> StorageResult<()> {
+        self.on_chain.set_dirty(dirty)?;
+        self.off_chain.set_dirty(dirty)?;
+        self.relayer.set_dirty(dirty)?;
+        self.gas_price.set_dirty(dirty)?;
+        Ok(())
+    }
+
     pub fn on_chain(&self) -> &Database<OnChain> {
         &self.on_chain
     }
@@ -325,6 +386,11 @@ impl CombinedDatabase {
         &mut self.gas_price
     }
 
+    /// Returns the notifier of the rollbacks performed through this database.
+    pub fn rollback_notifier(&self) -> &ChainRollbackNotifier {
+        &self.rollback_notifier
+    }
+
     #[cfg(feature = "test-helpers")]
     pub fn read_state_config(&self) -> StorageResult<StateConfig> {
         use fuel_core_chain_config::AddTable;
@@ -378,6 +444,18 @@ impl CombinedDatabase {
     where
         S: ShutdownListener,
     {
+        // The rollback goes block by block, so the missing diffs are detected
+        // before the first block is rolled back to not leave the databases halfway.
+        ensure_diffs_available(self.on_chain(), target_block_height)?;
+        ensure_diffs_available(self.off_chain(), target_block_height)?;
+        ensure_diffs_available(self.gas_price(), target_block_height)?;
+
+        let from_height = self
+            .on_chain()
+            .latest_height_from_metadata()?
+            .max(self.off_chain().latest_height_from_metadata()?);
+        let block_ids = self.rolled_back_block_ids(target_block_height)?;
+
         while !shutdown_listener.is_cancelled() {
             let on_chain_height = self
                 .on_chain()
@@ -447,6 +525,16 @@ impl CombinedDatabase {
             ));
         }
 
+        if let Some(from_height) = from_height {
+            if from_height > target_block_height {
+                self.record_rollback(ChainRollback {
+                    from_height,
+                    to_height: target_block_height,
+                    block_ids,
+                })?;
+            }
+        }
+
         Ok(())
     }
 
@@ -460,6 +548,8 @@ impl CombinedDatabase {
     where
         S: ShutdownListener,
     {
+        let off_chain_height = self.off_chain().latest_height_from_metadata()?;
+
         while !shutdown_listener.is_cancelled() {
             let on_chain_height = match self.on_chain().latest_height_from_metadata()? {
                 Some(height) => height,
@@ -491,8 +581,88 @@ impl CombinedDatabase {
             }
         }
 
+        // The off-chain database was reverted to the height of the on-chain database.
+        // Its blocks above the on-chain database are unknown to the node.
+        let on_chain_height = self.on_chain().latest_height_from_metadata()?;
+        if let (Some(from_height), Some(to_height)) = (off_chain_height, on_chain_height)
+        {
+            if from_height > to_height && !shutdown_listener.is_cancelled() {
+                self.record_rollback(ChainRollback {
+                    from_height,
+                    to_height,
+                    block_ids: None,
+                })?;
+            }
+        }
+
         Ok(())
     }
+
+    /// Returns the ids of the on-chain blocks above the `target_block_height`,
+    /// or `None` if there are more than [`MAX_ROLLBACK_BLOCK_IDS`] of them.
+    fn rolled_back_block_ids(
+        &self,
+        target_block_height: BlockHeight,
+    ) -> StorageResult<Option<Vec<BlockId>>> {
+        let Some(on_chain_height) = self.on_chain().latest_height_from_metadata()? else {
+            return Ok(Some(vec![]));
+        };
+        let first_height = u32::from(target_block_height).saturating_add(1);
+        let last_height = u32::from(on_chain_height);
+        let count = last_height.saturating_sub(u32::from(target_block_height));
+        if usize::try_from(count).map_or(true, |count| count > MAX_ROLLBACK_BLOCK_IDS) {
+            return Ok(None);
+        }
+
+        let block_ids = (first_height..=last_height)
+            .map(|height| {
+                let block = self
+                    .on_chain()
+                    .storage::<FuelBlocks>()
+                    .get(&height.into())?
+                    .ok_or(not_found!(FuelBlocks))?;
+                Ok(block.header().id())
+            })
+            .collect::<StorageResult<Vec<_>>>()?;
+        Ok(Some(block_ids))
+    }
+
+    /// Records the `rollback` as the last one in the off-chain database
+    /// and notifies the subscribers about it.
+    fn record_rollback(&self, rollback: ChainRollback) -> StorageResult<()> {
+        tracing::info!(
+            "Rolled back the databases from the height {} to the height {}",
+            rollback.from_height,
+            rollback.to_height
+        );
+        self.off_chain().set_last_chain_rollback(&rollback)?;
+        self.rollback_notifier.notify(rollback);
+        Ok(())
+    }
+}
+
+/// Checks that the `database` keeps the reverse diffs of all blocks above
+/// the `target_block_height`, so it can be rolled back to it.
+fn ensure_diffs_available<Description>(
+    database: &Database<Description>,
+    target_block_height: BlockHeight,
+) -> anyhow::Result<()>
+where
+    Description: DatabaseDescription<Height = BlockHeight>,
+{
+    let Some(height) = HistoricalView::latest_height(database) else {
+        return Ok(())
+    };
+
+    if height > target_block_height && database.view_at(&target_block_height).is_err() {
+        return Err(DatabaseError::DiffUnavailable {
+            database: Description::name(),
+            requested_height: u64::from(*target_block_height),
+        }
+        .into())
+    }
+
+    Ok(())
 }
 
 /// A trait for listening to shutdown signals.
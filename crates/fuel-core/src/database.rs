@@ -11,7 +11,10 @@ use crate::{
         metadata::MetadataTable,
         Error as DatabaseError,
     },
-    graphql_api::storage::blocks::FuelBlockIdsToHeights,
+    graphql_api::storage::{
+        blocks::FuelBlockIdsToHeights,
+        merge_tx_count_delta,
+    },
     state::{
         data_source::{
             DataSource,
@@ -19,6 +22,7 @@ use crate::{
         },
         generic_database::GenericDatabase,
         in_memory::memory_store::MemoryStore,
+        merge_changes,
         ColumnType,
         IterableKeyValueView,
         KeyValueView,
@@ -315,6 +319,7 @@ where
                     cache_capacity: None,
                     max_fds: 512,
                     columns_policy: ColumnsPolicy::Lazy,
+                    allow_unknown_columns: false,
                 },
             )
             .expect("Failed to create a temporary database")
@@ -347,6 +352,35 @@ where
         Ok(())
     }
 
+    /// Commits the `changes` without linking them to any height.
+    ///
+    /// The changes are not tracked by the history of the database, so it should be
+    /// used only for entries that don't belong to the state at a specific height.
+    pub fn commit_changes_without_height(&self, changes: Changes) -> StorageResult<()> {
+        let _guard = self.inner_storage().stage.height.lock();
+        self.inner_storage()
+            .data
+            .commit_changes(None, changes.into())
+    }
+
+    /// Commits the changes without updating the height, but only if the height
+    /// of the database is still the `expected` one. Returns `false` without
+    /// committing if a block was committed in the meantime.
+    pub fn commit_changes_without_height_at(
+        &self,
+        expected: Option<Description::Height>,
+        changes: Changes,
+    ) -> StorageResult<bool> {
+        let guard = self.inner_storage().stage.height.lock();
+        if *guard != expected {
+            return Ok(false)
+        }
+        self.inner_storage()
+            .data
+            .commit_changes(None, changes.into())?;
+        Ok(true)
+    }
+
     fn latest_view_with_height(
         &self,
         height: Option<Description::Height>,
@@ -417,14 +451,40 @@ impl Modifiable for Database<OnChain> {
 
 impl Modifiable for Database<OffChain> {
     fn commit_changes(&mut self, changes: Changes) -> StorageResult<()> {
-        commit_changes_with_height_update(self, changes, |iter| {
-            iter.iter_all::<FuelBlockIdsToHeights>(Some(IterDirection::Reverse))
-                .map(|result| result.map(|(_, height)| height))
-                .try_collect()
-        })
+        commit_merged_changes_with_height_update(
+            self,
+            changes,
+            off_chain_heights,
+            merge_tx_count_delta,
+        )
+    }
+}
+
+impl Database<OffChain> {
+    /// Commits the `changes` of several consecutive blocks at once.
+    /// See [`commit_merged_changes_of_heights`].
+    pub fn commit_changes_of_blocks(
+        &mut self,
+        changes: Vec<Changes>,
+    ) -> StorageResult<()> {
+        commit_merged_changes_of_heights(
+            self,
+            changes,
+            off_chain_heights,
+            |storage, changes| merge_tx_count_delta(storage, changes),
+        )
     }
 }
 
+/// Returns the heights of the blocks indexed by the `changes` of the off-chain database.
+fn off_chain_heights(
+    iter: &ChangesIterator<ColumnType<OffChain>>,
+) -> StorageResult<Vec<BlockHeight>> {
+    iter.iter_all::<FuelBlockIdsToHeights>(Some(IterDirection::Reverse))
+        .map(|result| result.map(|(_, height)| height))
+        .try_collect()
+}
+
 impl Modifiable for Database<GasPriceDatabase> {
     fn commit_changes(&mut self, changes: Changes) -> StorageResult<()> {
         commit_changes_with_height_update(self, changes, |iter| {
@@ -478,6 +538,36 @@ pub fn commit_changes_with_height_update<Description, Changes>(
         &ChangesIterator<Description::Column>,
     ) -> StorageResult<Vec<Description::Height>>,
 ) -> StorageResult<()>
+where
+    Description: DatabaseDescription,
+    Description::Height: Debug + PartialOrd + DatabaseHeight,
+    for<'a> StorageTransaction<&'a &'a mut Database<Description>>:
+        StorageMutate<MetadataTable<Description>, Error = StorageError>,
+    Changes: Into<StorageChanges>,
+{
+    commit_merged_changes_with_height_update(
+        database,
+        changes,
+        heights_lookup,
+        |_, changes| Ok(changes),
+    )
+}
+
+/// The same as [`commit_changes_with_height_update`], but the `merge` rewrites
+/// the changes right before they are written. The `merge` is called under the
+/// commit lock with the state of the database, so it can accumulate the values
+/// of the changes into the stored ones without losing the concurrent commits.
+pub fn commit_merged_changes_with_height_update<Description, Changes>(
+    database: &mut Database<Description>,
+    changes: Changes,
+    heights_lookup: impl Fn(
+        &ChangesIterator<Description::Column>,
+    ) -> StorageResult<Vec<Description::Height>>,
+    merge: impl FnOnce(
+        &Database<Description>,
+        StorageChanges,
+    ) -> StorageResult<StorageChanges>,
+) -> StorageResult<()>
 where
     Description: DatabaseDescription,
     Description::Height: Debug + PartialOrd + DatabaseHeight,
@@ -568,6 +658,7 @@ where
 
     // Atomically commit the changes to the database, and to the mutex-protected field.
     let mut guard = database.stage.height.lock();
+    let changes = merge(database, changes)?;
     database.data.commit_changes(new_height, changes)?;
 
     // Update the block height
@@ -578,6 +669,127 @@ where
     Ok(())
 }
 
+/// The same as [`commit_merged_changes_with_height_update`], but commits the `changes`
+/// of several consecutive heights at once. Each of the `changes` must contain exactly
+/// one height, linked to the height before it. The `merge` is applied to the changes
+/// of each height on top of the changes of the previous heights. The database moves
+/// to the last height only after all changes are committed, so the failed commit
+/// leaves the database at the height before the batch.
+pub fn commit_merged_changes_of_heights<Description>(
+    database: &mut Database<Description>,
+    changes: Vec<Changes>,
+    heights_lookup: impl Fn(
+        &ChangesIterator<Description::Column>,
+    ) -> StorageResult<Vec<Description::Height>>,
+    merge: impl Fn(
+        &StorageTransaction<&Database<Description>>,
+        StorageChanges,
+    ) -> StorageResult<StorageChanges>,
+) -> StorageResult<()>
+where
+    Description: DatabaseDescription,
+    Description::Height: Debug + PartialOrd + DatabaseHeight,
+    for<'a> StorageTransaction<&'a &'a mut Database<Description>>:
+        StorageMutate<MetadataTable<Description>, Error = StorageError>,
+{
+    let mut metadata = {
+        let mut transaction = StorageTransaction::transaction(
+            &database,
+            ConflictPolicy::Overwrite,
+            Default::default(),
+        );
+        transaction
+            .storage_as_mut::<MetadataTable<Description>>()
+            .get(&())?
+            .map(Cow::into_owned)
+    };
+
+    // The merge and the commit are done under the lock, like for one height.
+    let mut guard = database.stage.height.lock();
+    let mut height = *guard;
+    let mut batch = StorageTransaction::transaction(
+        &*database,
+        ConflictPolicy::Overwrite,
+        Default::default(),
+    );
+    let mut changes_of_heights = Vec::with_capacity(changes.len());
+    for changes in changes {
+        let changes = StorageChanges::Changes(changes);
+        let iterator = ChangesIterator::<Description::Column>::new(&changes);
+        let new_height = match heights_lookup(&iterator)?.as_slice() {
+            [new_height] => *new_height,
+            [] => {
+                return Err(match height {
+                    Some(prev_height) => DatabaseError::NewHeightIsNotSet {
+                        prev_height: prev_height.as_u64(),
+                    }
+                    .into(),
+                    None => StorageError::Other(anyhow::anyhow!(
+                        "The changes of each height of the batch must set the height"
+                    )),
+                })
+            }
+            heights => {
+                return Err(DatabaseError::MultipleHeightsInCommit {
+                    heights: heights.iter().map(DatabaseHeight::as_u64).collect(),
+                }
+                .into())
+            }
+        };
+        if let Some(prev_height) = height {
+            let next_expected_height = prev_height
+                .advance_height()
+                .ok_or(DatabaseError::FailedToAdvanceHeight)?;
+            if next_expected_height != new_height {
+                return Err(DatabaseError::HeightsAreNotLinked {
+                    prev_height: prev_height.as_u64(),
+                    new_height: new_height.as_u64(),
+                }
+                .into());
+            }
+        }
+
+        // Each height updates the metadata, so the rollback to it restores the height.
+        let updated_metadata = update_metadata::<Description>(
+            metadata.as_ref().map(Cow::Borrowed),
+            new_height,
+        );
+        let mut transaction = StorageTransaction::transaction(
+            &database,
+            ConflictPolicy::Overwrite,
+            Default::default(),
+        );
+        transaction
+            .storage_as_mut::<MetadataTable<Description>>()
+            .insert(&(), &updated_metadata)?;
+        metadata = Some(updated_metadata);
+
+        let changes = match changes {
+            StorageChanges::Changes(changes) => changes,
+            StorageChanges::ChangesList(list) => merge_changes(list),
+        };
+        let changes =
+            StorageChanges::ChangesList(vec![changes, transaction.into_changes()]);
+        let changes = match merge(&batch, changes)? {
+            StorageChanges::Changes(changes) => changes,
+            StorageChanges::ChangesList(list) => merge_changes(list),
+        };
+        batch.commit_changes(changes.clone())?;
+        changes_of_heights.push((new_height, changes));
+        height = Some(new_height);
+    }
+
+    if changes_of_heights.is_empty() {
+        return Ok(())
+    }
+    database
+        .data
+        .commit_changes_of_heights(changes_of_heights)?;
+    *guard = height;
+
+    Ok(())
+}
+
 fn update_metadata<Description>(
     maybe_current_metadata: Option<
         Cow<DatabaseMetadata<<Description as DatabaseDescription>::Height>>,
@@ -601,6 +813,16 @@ where
                 height: new_height,
                 indexation_availability: indexation_availability.clone(),
             },
+            DatabaseMetadata::V3 {
+                indexation_availability,
+                stale_indexation,
+                ..
+            } => DatabaseMetadata::V3 {
+                version: Description::version(),
+                height: new_height,
+                indexation_availability: indexation_availability.clone(),
+                stale_indexation: stale_indexation.clone(),
+            },
         },
         None => DatabaseMetadata::V2 {
             version: Description::version(),
@@ -627,15 +849,18 @@ mod tests {
         Database,
     };
 
-    fn column_keys_not_exceed_count<Description>()
+    fn column_ids_are_unique<Description>()
     where
         Description: DatabaseDescription,
     {
         use enum_iterator::all;
         use fuel_core_storage::kv_store::StorageColumn;
-        use strum::EnumCount;
+        let mut ids = std::collections::HashSet::new();
         for column in all::<Description::Column>() {
-            assert!(column.as_usize() < Description::Column::COUNT);
+            assert!(
+                ids.insert(column.id()),
+                "The id of {column:?} is not unique"
+            );
         }
     }
 
@@ -656,8 +881,8 @@ mod tests {
         };
 
         #[test]
-        fn column_keys_not_exceed_count_test() {
-            column_keys_not_exceed_count::<OnChain>();
+        fn column_ids_are_unique_test() {
+            column_ids_are_unique::<OnChain>();
         }
 
         #[test]
@@ -816,8 +1041,8 @@ mod tests {
         use fuel_core_storage::transactional::WriteTransaction;
 
         #[test]
-        fn column_keys_not_exceed_count_test() {
-            column_keys_not_exceed_count::<OffChain>();
+        fn column_ids_are_unique_test() {
+            column_ids_are_unique::<OffChain>();
         }
 
         #[test]
@@ -846,7 +1071,7 @@ mod tests {
             // When
             database
                 .storage_as_mut::<OwnedMessageIds>()
-                .insert(&OwnedMessageKey::default(), &())
+                .insert(&OwnedMessageKey::default(), &Default::default())
                 .unwrap();
 
             // Then
@@ -916,7 +1141,7 @@ mod tests {
             // When
             let result = database
                 .storage_as_mut::<OwnedMessageIds>()
-                .insert(&OwnedMessageKey::default(), &());
+                .insert(&OwnedMessageKey::default(), &Default::default());
 
             // Then
             assert!(result.is_err());
@@ -976,8 +1201,8 @@ mod tests {
         use fuel_core_types::blockchain::primitives::DaBlockHeight;
 
         #[test]
-        fn column_keys_not_exceed_count_test() {
-            column_keys_not_exceed_count::<Relayer>();
+        fn column_ids_are_unique_test() {
+            column_ids_are_unique::<Relayer>();
         }
 
         #[test]
@@ -1271,7 +1496,7 @@ mod tests {
                     assert_eq!(version, current_metadata.version());
                     assert_eq!(height, HeightMock(2));
                 }
-                DatabaseMetadata::V2 { .. } => panic!("should be V1"),
+                _ => panic!("should be V1"),
             }
         }
 
@@ -1290,7 +1515,6 @@ mod tests {
             );
 
             match new_metadata {
-                DatabaseMetadata::V1 { .. } => panic!("should be V2"),
                 DatabaseMetadata::V2 {
                     version,
                     height,
@@ -1300,6 +1524,39 @@ mod tests {
                     assert_eq!(height, HeightMock(2));
                     assert_eq!(indexation_availability, available_indexation);
                 }
+                _ => panic!("should be V2"),
+            }
+        }
+
+        #[test]
+        fn update_metadata_preserves_v3() {
+            let available_indexation: HashSet<_> = IndexationKind::all().collect();
+            let stale_indexation = HashSet::from([IndexationKind::CoinsToSpend]);
+
+            let current_metadata: DatabaseMetadata<HeightMock> = DatabaseMetadata::V3 {
+                version: MOCK_VERSION,
+                height: HeightMock(1),
+                indexation_availability: available_indexation.clone(),
+                stale_indexation: stale_indexation.clone(),
+            };
+            let new_metadata = update_metadata::<DatabaseDescriptionMock>(
+                Some(Cow::Borrowed(&current_metadata)),
+                HeightMock(2),
+            );
+
+            match new_metadata {
+                DatabaseMetadata::V3 {
+                    version,
+                    height,
+                    indexation_availability,
+                    stale_indexation: new_stale_indexation,
+                } => {
+                    assert_eq!(version, current_metadata.version());
+                    assert_eq!(height, HeightMock(2));
+                    assert_eq!(indexation_availability, available_indexation);
+                    assert_eq!(new_stale_indexation, stale_indexation);
+                }
+                _ => panic!("should be V3"),
             }
         }
 
@@ -1309,7 +1566,6 @@ mod tests {
                 update_metadata::<DatabaseDescriptionMock>(None, HeightMock(2));
 
             match new_metadata {
-                DatabaseMetadata::V1 { .. } => panic!("should be V2"),
                 DatabaseMetadata::V2 {
                     version,
                     height,
@@ -1319,6 +1575,7 @@ mod tests {
                     assert_eq!(height, HeightMock(2));
                     assert_eq!(indexation_availability, IndexationKind::all().collect());
                 }
+                _ => panic!("should be V2"),
             }
         }
     }
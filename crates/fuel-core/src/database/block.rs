@@ -3,14 +3,25 @@ use crate::{
         OffChainIterableKeyValueView,
         OnChainIterableKeyValueView,
     },
-    fuel_core_graphql_api::storage::blocks::FuelBlockIdsToHeights,
+    fuel_core_graphql_api::storage::{
+        blocks::{
+            BlocksByProducer,
+            BlocksByProducerKey,
+            FuelBlockIdsToHeights,
+        },
+        old::OldFuelBlocks,
+    },
 };
 use fuel_core_storage::{
+    blueprint::BlueprintInspect,
+    codec::Encode,
     iter::{
         IterDirection,
         IteratorOverTable,
     },
+    kv_store::KeyValueInspect,
     not_found,
+    structured_storage::TableWithBlueprint,
     tables::{
         merkle::{
             DenseMetadataKey,
@@ -30,21 +41,82 @@ use fuel_core_types::{
             Block,
             CompressedBlock,
         },
+        header::BlockHeader,
         primitives::BlockId,
     },
     entities::relayer::message::MerkleProof,
     fuel_merkle::binary::MerkleTree,
-    fuel_types::BlockHeight,
+    fuel_types::{
+        Address,
+        BlockHeight,
+    },
 };
 use itertools::Itertools;
 use std::borrow::Cow;
 
+/// The beginning of the encoded [`CompressedBlock`] that holds the header.
+/// Decoding only it skips the ids of the transactions that follow the header.
+#[derive(serde::Deserialize)]
+enum EncodedBlockHeader {
+    V1(EncodedBlockHeaderV1),
+}
+
+#[derive(serde::Deserialize)]
+struct EncodedBlockHeaderV1 {
+    header: BlockHeader,
+}
+
+/// Decodes the header of the block encoded by the `Postcard` codec.
+fn decode_block_header(bytes: &[u8]) -> StorageResult<BlockHeader> {
+    let (encoded, _) = postcard::take_from_bytes::<EncodedBlockHeader>(bytes)
+        .map_err(|e| StorageError::Codec(anyhow::anyhow!(e)))?;
+    let EncodedBlockHeader::V1(EncodedBlockHeaderV1 { mut header }) = encoded;
+    header.recalculate_metadata();
+    Ok(header)
+}
+
 impl OffChainIterableKeyValueView {
     pub fn get_block_height(&self, id: &BlockId) -> StorageResult<Option<BlockHeight>> {
         self.storage::<FuelBlockIdsToHeights>()
             .get(id)
             .map(|v| v.map(|v| v.into_owned()))
     }
+
+    pub fn block_heights_by_producer(
+        &self,
+        producer: &Address,
+        start_height: Option<BlockHeight>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<BlockHeight>> + '_ {
+        let start_key = start_height.map(|height| BlocksByProducerKey {
+            producer: *producer,
+            height,
+        });
+        self.iter_all_filtered_keys::<BlocksByProducer, _>(
+            Some(*producer),
+            start_key.as_ref(),
+            direction,
+        )
+        .map(|res| res.map(|key| key.height))
+    }
+
+    /// Returns the header of the block from before regenesis
+    /// without decoding the ids of its transactions.
+    pub fn old_block_header(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<BlockHeader>> {
+        let column = <OldFuelBlocks as TableWithBlueprint>::column();
+        let key =
+            <<OldFuelBlocks as TableWithBlueprint>::Blueprint as BlueprintInspect<
+                OldFuelBlocks,
+                Self,
+            >>::KeyCodec::encode(height);
+
+        self.get(key.as_ref(), column)?
+            .map(|value| decode_block_header(&value))
+            .transpose()
+    }
 }
 
 impl OnChainIterableKeyValueView {
@@ -57,6 +129,22 @@ impl OnChainIterableKeyValueView {
         Ok(pair.map(|(_, compressed_block)| compressed_block))
     }
 
+    /// Returns the header of the block without decoding the ids of its transactions.
+    pub fn block_header(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<BlockHeader>> {
+        let column = <FuelBlocks as TableWithBlueprint>::column();
+        let key = <<FuelBlocks as TableWithBlueprint>::Blueprint as BlueprintInspect<
+            FuelBlocks,
+            Self,
+        >>::KeyCodec::encode(height);
+
+        self.get(key.as_ref(), column)?
+            .map(|value| decode_block_header(&value))
+            .transpose()
+    }
+
     /// Get the current block at the head of the chain.
     pub fn get_current_block(&self) -> StorageResult<Option<CompressedBlock>> {
         self.latest_compressed_block()
@@ -227,6 +315,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn block_header__decodes_header_without_transaction_ids() {
+        let mut database = Database::default();
+        let height = BlockHeight::from(7);
+
+        // given
+        let mut block = CompressedBlock::default();
+        block.header_mut().set_block_height(height);
+        *block.transactions_mut() = vec![[1; 32].into(), [2; 32].into()];
+        StorageMutate::<FuelBlocks>::insert(&mut database, &height, &block).unwrap();
+
+        // when
+        let header = database
+            .latest_view()
+            .unwrap()
+            .block_header(&height)
+            .unwrap();
+
+        // then
+        let header = header.expect("The block is stored");
+        assert_eq!(&header, block.header());
+        assert_eq!(header.id(), block.header().id());
+    }
+
     #[test]
     fn block_history_proof_error_if_message_higher_than_commit() {
         let mut database = Database::default();
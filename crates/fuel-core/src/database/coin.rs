@@ -4,12 +4,26 @@ use crate::{
         OnChainIterableKeyValueView,
     },
     fuel_core_graphql_api::storage::coins::{
+        owned_coins_layout,
         owner_coin_id_key,
+        DerivedCoinOwners,
+        OwnedCoinByAssetKey,
+        OwnedCoinKey,
+        OwnedCoinState,
         OwnedCoins,
+        OwnedCoinsByAsset,
+        OwnedCoinsLayout,
+        PredicateOwnedCoins,
+        ShardedOwnedCoinKey,
+        ShardedOwnedCoins,
+        OWNED_COINS_SHARDS,
     },
 };
+use core::iter::Peekable;
 use fuel_core_storage::{
     iter::{
+        BoxedIter,
+        IntoBoxedIter,
         IterDirection,
         IteratorOverTable,
     },
@@ -22,34 +36,164 @@ use fuel_core_types::{
     entities::coins::coin::CompressedCoin,
     fuel_tx::{
         Address,
-        TxId,
+        AssetId,
         UtxoId,
     },
 };
 
 impl OffChainIterableKeyValueView {
+    /// Returns the coins of the `owner`. The coins of which the `owner` is
+    /// the logical owner are included only if `include_derived` is `true`.
+    /// The coins flagged as spent are skipped.
     pub fn owned_coins_ids(
         &self,
         owner: &Address,
         start_coin: Option<UtxoId>,
         direction: Option<IterDirection>,
+        include_derived: bool,
+    ) -> impl Iterator<Item = StorageResult<UtxoId>> + '_ {
+        let entries = match owned_coins_layout(self) {
+            Ok(OwnedCoinsLayout::Plain) => {
+                let start_coin = start_coin.map(|b| owner_coin_id_key(owner, &b));
+                self.iter_all_filtered::<OwnedCoins, _>(
+                    Some(*owner),
+                    start_coin.as_ref(),
+                    direction,
+                )
+            }
+            Ok(OwnedCoinsLayout::Sharded) => {
+                self.sharded_owned_coins(owner, start_coin, direction)
+            }
+            Err(err) => core::iter::once(Err(err)).into_boxed(),
+        };
+        entries.filter_map(move |res| {
+            let key = match res {
+                Ok((_, state)) if state.is_spent() => return None,
+                Ok((key, _)) => key,
+                Err(err) => return Some(Err(err)),
+            };
+            if include_derived {
+                return Some(Ok(key.utxo_id))
+            }
+            match self.is_derived_owned_coin(&key) {
+                Ok(true) => None,
+                Ok(false) => Some(Ok(key.utxo_id)),
+                Err(err) => Some(Err(err)),
+            }
+        })
+    }
+
+    /// Returns the coins owned directly by the `owner` with the `asset_id`.
+    pub fn owned_coins_ids_by_asset(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        start_coin: Option<UtxoId>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<UtxoId>> + '_ {
+        let start_coin =
+            start_coin.map(|b| OwnedCoinByAssetKey::new(owner, asset_id, &b));
+        self.iter_all_filtered_keys::<OwnedCoinsByAsset, _>(
+            Some(OwnedCoinByAssetKey::prefix(owner, asset_id)),
+            start_coin.as_ref(),
+            direction,
+        )
+        .map(|res| res.map(|key| key.utxo_id))
+    }
+
+    /// Returns the coins of the `owner` flagged as owned by a predicate
+    /// when they were indexed.
+    pub fn predicate_owned_coins_ids(
+        &self,
+        owner: &Address,
+        start_coin: Option<UtxoId>,
+        direction: Option<IterDirection>,
     ) -> impl Iterator<Item = StorageResult<UtxoId>> + '_ {
         let start_coin = start_coin.map(|b| owner_coin_id_key(owner, &b));
-        self.iter_all_filtered_keys::<OwnedCoins, _>(
+        self.iter_all_filtered_keys::<PredicateOwnedCoins, _>(
             Some(*owner),
             start_coin.as_ref(),
             direction,
         )
-        .map(|res| {
-            res.map(|key| {
-                UtxoId::new(
-                    TxId::try_from(&key[32..64]).expect("The slice has size 32"),
-                    u16::from_be_bytes(
-                        key[64..].try_into().expect("The slice has size 2"),
-                    ),
+        .map(|res| res.map(|key| key.utxo_id))
+    }
+
+    /// Returns the entries of the `owner` in the sharded layout. The shards are
+    /// merged in the order of the coin ids, the same as the order of the plain layout.
+    fn sharded_owned_coins(
+        &self,
+        owner: &Address,
+        start_coin: Option<UtxoId>,
+        direction: Option<IterDirection>,
+    ) -> BoxedIter<'_, StorageResult<(OwnedCoinKey, OwnedCoinState)>> {
+        let direction = direction.unwrap_or_default();
+        let shards = (0..OWNED_COINS_SHARDS)
+            .map(|shard| {
+                let mut prefix = [0u8; Address::LEN + 1];
+                prefix[..Address::LEN].copy_from_slice(owner.as_ref());
+                prefix[Address::LEN] = shard;
+                let start = start_coin.map(|utxo_id| ShardedOwnedCoinKey {
+                    owner: *owner,
+                    shard,
+                    utxo_id,
+                });
+                self.iter_all_filtered::<ShardedOwnedCoins, _>(
+                    Some(prefix),
+                    start.as_ref(),
+                    Some(direction),
                 )
+                .peekable()
             })
-        })
+            .collect();
+        MergedShards { shards, direction }.into_boxed()
+    }
+
+    /// Checks if the entry of the `OwnedCoins` table belongs to the logical owner.
+    fn is_derived_owned_coin(&self, key: &OwnedCoinKey) -> StorageResult<bool> {
+        let derived = self
+            .storage_as_ref::<DerivedCoinOwners>()
+            .get(&key.utxo_id)?
+            .is_some_and(|owners| owners.contains(&key.owner));
+        Ok(derived)
+    }
+}
+
+/// Merges the ordered iterators over the shards of the owner into one iterator.
+struct MergedShards<I: Iterator> {
+    shards: Vec<Peekable<I>>,
+    direction: IterDirection,
+}
+
+impl<I> Iterator for MergedShards<I>
+where
+    I: Iterator<Item = StorageResult<(ShardedOwnedCoinKey, OwnedCoinState)>>,
+{
+    type Item = StorageResult<(OwnedCoinKey, OwnedCoinState)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut next: Option<(usize, UtxoId)> = None;
+        for (index, shard) in self.shards.iter_mut().enumerate() {
+            let utxo_id = match shard.peek() {
+                None => continue,
+                Some(Ok((key, _))) => key.utxo_id,
+                // The error is returned right away, the same as by the plain layout.
+                Some(Err(_)) => {
+                    return shard
+                        .next()
+                        .map(|entry| entry.map(|(key, state)| (key.into(), state)))
+                }
+            };
+            let closer = next.map_or(true, |(_, best)| match self.direction {
+                IterDirection::Forward => utxo_id < best,
+                IterDirection::Reverse => utxo_id > best,
+            });
+            if closer {
+                next = Some((index, utxo_id));
+            }
+        }
+        let (index, _) = next?;
+        let entry = self.shards.get_mut(index)?.next()?;
+        Some(entry.map(|(key, state)| (key.into(), state)))
     }
 }
 
@@ -64,3 +208,282 @@ impl OnChainIterableKeyValueView {
         Ok(coin)
     }
 }
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+#[allow(clippy::arithmetic_side_effects)]
+mod tests {
+    use super::*;
+    use crate::{
+        database::{
+            database_description::off_chain::OffChain,
+            Database,
+        },
+        fuel_core_graphql_api::storage::coins::insert_owned_coin,
+    };
+    use fuel_core_storage::{
+        iter::IntoScanBudgetIter,
+        transactional::{
+            AtomicView,
+            ReadTransaction,
+        },
+        Error as StorageError,
+        StorageAsMut,
+    };
+    use fuel_core_types::fuel_tx::TxId;
+    use std::str::FromStr;
+
+    const COINS: u16 = 1000;
+    const LIVE_COINS_STEP: u16 = 10;
+
+    fn utxo_id(index: u16) -> UtxoId {
+        UtxoId::new(TxId::from([1; 32]), index)
+    }
+
+    fn cursor(utxo_id: &UtxoId) -> String {
+        format!("{utxo_id:#x}")
+    }
+
+    /// Creates the database where only every `LIVE_COINS_STEP` coin of the `owner`
+    /// is unspent, while the spent ones leave tombstones in the RocksDB.
+    fn tombstone_heavy_database(owner: &Address) -> Database<OffChain> {
+        let mut database = Database::<OffChain>::default();
+        for index in 0..COINS {
+            database
+                .storage_as_mut::<OwnedCoins>()
+                .insert(
+                    &owner_coin_id_key(owner, &utxo_id(index)),
+                    &OwnedCoinState::Unspent,
+                )
+                .unwrap();
+        }
+        for index in (0..COINS).filter(|index| index % LIVE_COINS_STEP != 0) {
+            database
+                .storage_as_mut::<OwnedCoins>()
+                .remove(&owner_coin_id_key(owner, &utxo_id(index)))
+                .unwrap();
+        }
+        database
+    }
+
+    /// Creates the database where only every `LIVE_COINS_STEP` coin of the `owner`
+    /// is unspent, while the spent ones are flagged instead of being removed.
+    fn flagged_database(owner: &Address) -> Database<OffChain> {
+        let mut database = Database::<OffChain>::default();
+        for index in 0..COINS {
+            let state = if index % LIVE_COINS_STEP == 0 {
+                OwnedCoinState::Unspent
+            } else {
+                OwnedCoinState::Spent {
+                    height: u32::from(index).into(),
+                }
+            };
+            database
+                .storage_as_mut::<OwnedCoins>()
+                .insert(&owner_coin_id_key(owner, &utxo_id(index)), &state)
+                .unwrap();
+        }
+        database
+    }
+
+    #[test]
+    fn owned_coins_ids__skips_the_coins_flagged_as_spent() {
+        // given
+        let owner = Address::from([2; 32]);
+        let database = flagged_database(&owner);
+        let view = database.latest_view().unwrap();
+        let live = (0..COINS)
+            .step_by(LIVE_COINS_STEP as usize)
+            .map(utxo_id)
+            .collect::<Vec<_>>();
+
+        // when
+        let forward = view
+            .owned_coins_ids(&owner, None, Some(IterDirection::Forward), true)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let reverse = view
+            .owned_coins_ids(&owner, None, Some(IterDirection::Reverse), false)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let from_flagged = view
+            .owned_coins_ids(&owner, Some(utxo_id(1)), None, false)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        // then
+        assert_eq!(forward, live);
+        assert_eq!(reverse, live.iter().rev().copied().collect::<Vec<_>>());
+        assert_eq!(from_flagged, live[1..]);
+    }
+
+    #[test]
+    fn owned_coins_ids__fails_with_cursor_when_scan_budget_is_exceeded() {
+        // given
+        let owner = Address::from([2; 32]);
+        let database = tombstone_heavy_database(&owner);
+        let view = database.latest_view().unwrap();
+        let limit: u16 = 5;
+
+        // when
+        let result = view
+            .owned_coins_ids(&owner, None, None, false)
+            .with_scan_budget(usize::from(limit), cursor)
+            .collect::<Vec<_>>();
+
+        // then
+        let last_examined = utxo_id((limit - 1) * LIVE_COINS_STEP);
+        let limit = usize::from(limit);
+        assert_eq!(result.len(), limit + 1);
+        assert!(result[..limit].iter().all(Result::is_ok));
+        assert!(matches!(
+            &result[limit],
+            Err(StorageError::ScanBudgetExceeded {
+                limit: 5,
+                cursor: Some(cursor),
+            }) if cursor == &format!("{last_examined:#x}")
+        ));
+    }
+
+    #[test]
+    fn owned_coins_ids__continues_from_cursor_of_exceeded_scan_budget() {
+        // given
+        let owner = Address::from([2; 32]);
+        let database = tombstone_heavy_database(&owner);
+        let view = database.latest_view().unwrap();
+        let limit = 7;
+
+        // when
+        let mut coins = vec![];
+        let mut start = None;
+        loop {
+            let mut exceeded_cursor = None;
+            for result in view
+                .owned_coins_ids(&owner, start, None, false)
+                .with_scan_budget(limit, cursor)
+            {
+                match result {
+                    // The scan starts from the cursor inclusively.
+                    Ok(utxo_id) if Some(utxo_id) == start => {}
+                    Ok(utxo_id) => coins.push(utxo_id),
+                    Err(StorageError::ScanBudgetExceeded { cursor, .. }) => {
+                        exceeded_cursor = cursor;
+                    }
+                    Err(err) => panic!("Unexpected error: {err}"),
+                }
+            }
+
+            match exceeded_cursor {
+                Some(cursor) => start = Some(UtxoId::from_str(&cursor).unwrap()),
+                None => break,
+            }
+        }
+
+        // then
+        let expected = (0..COINS)
+            .step_by(LIVE_COINS_STEP as usize)
+            .map(utxo_id)
+            .collect::<Vec<_>>();
+        assert_eq!(coins, expected);
+    }
+
+    /// Creates the database storing the same coins of the `owner` in the `layout`.
+    /// The coins of the different transactions are spent and derived in a mix.
+    fn layout_database(owner: &Address, layout: OwnedCoinsLayout) -> Database<OffChain> {
+        let database = Database::<OffChain>::default();
+        database.set_owned_coins_layout(layout).unwrap();
+        let mut transaction = database.read_transaction();
+        for index in 0..COINS {
+            let utxo_id =
+                UtxoId::new(TxId::from([u8::try_from(index % 7).unwrap(); 32]), index);
+            let state = if index % 3 == 0 {
+                OwnedCoinState::Spent {
+                    height: u32::from(index).into(),
+                }
+            } else {
+                OwnedCoinState::Unspent
+            };
+            insert_owned_coin(
+                &mut transaction,
+                layout,
+                &owner_coin_id_key(owner, &utxo_id),
+                &state,
+            )
+            .unwrap();
+            if index % 5 == 0 {
+                transaction
+                    .storage_as_mut::<DerivedCoinOwners>()
+                    .insert(&utxo_id, &vec![*owner])
+                    .unwrap();
+            }
+        }
+        database
+            .commit_changes_without_height(transaction.into_changes())
+            .unwrap();
+        database
+    }
+
+    fn query(
+        database: &Database<OffChain>,
+        owner: &Address,
+        start: Option<UtxoId>,
+        direction: IterDirection,
+        include_derived: bool,
+    ) -> Vec<UtxoId> {
+        database
+            .latest_view()
+            .unwrap()
+            .owned_coins_ids(owner, start, Some(direction), include_derived)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn owned_coins_ids__returns_the_same_coins_in_both_layouts() {
+        // given
+        let owner = Address::from([2; 32]);
+        let plain = layout_database(&owner, OwnedCoinsLayout::Plain);
+        let sharded = layout_database(&owner, OwnedCoinsLayout::Sharded);
+        let start = UtxoId::new(TxId::from([3; 32]), 500);
+
+        for direction in [IterDirection::Forward, IterDirection::Reverse] {
+            for include_derived in [true, false] {
+                for start in [None, Some(start)] {
+                    // when
+                    let expected =
+                        query(&plain, &owner, start, direction, include_derived);
+                    let actual =
+                        query(&sharded, &owner, start, direction, include_derived);
+
+                    // then
+                    assert!(!expected.is_empty());
+                    assert_eq!(
+                        actual, expected,
+                        "{direction:?}, {include_derived}, {start:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn owned_coins_ids__fails_on_the_entries_of_the_other_layout() {
+        // given
+        let owner = Address::from([2; 32]);
+        let database = layout_database(&owner, OwnedCoinsLayout::Plain);
+        database
+            .set_owned_coins_layout(OwnedCoinsLayout::Sharded)
+            .unwrap();
+
+        // when
+        let result = database
+            .latest_view()
+            .unwrap()
+            .owned_coins_ids(&owner, None, None, true)
+            .collect::<Result<Vec<_>, _>>();
+
+        // then
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("mixes the layouts"), "{err}");
+    }
+}
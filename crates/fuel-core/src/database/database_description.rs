@@ -67,6 +67,25 @@ pub trait DatabaseDescription: 'static + Copy + Debug + Send + Sync {
 
     /// Returns the prefix for the column.
     fn prefix(column: &Self::Column) -> Option<usize>;
+
+    /// Returns the compression of the values of the column.
+    fn compression_policy(_column: &Self::Column) -> CompressionPolicy {
+        CompressionPolicy::default()
+    }
+}
+
+/// The compression of the values of a column applied by the storage backend.
+/// Changing the policy of the existing column only affects the newly written
+/// files, the files written before stay readable.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum CompressionPolicy {
+    /// The values are stored without compression.
+    None,
+    /// The fast compression suitable for most columns.
+    #[default]
+    Lz4,
+    /// The compression with the better ratio for the large values.
+    Zstd,
 }
 
 #[derive(
@@ -84,6 +103,8 @@ pub enum IndexationKind {
     Balances,
     CoinsToSpend,
     AssetMetadata,
+    ContractBalances,
+    OwnedCoinCounts,
 }
 
 impl IndexationKind {
@@ -104,6 +125,13 @@ pub enum DatabaseMetadata<Height> {
         height: Height,
         indexation_availability: HashSet<IndexationKind>,
     },
+    V3 {
+        version: u32,
+        height: Height,
+        indexation_availability: HashSet<IndexationKind>,
+        /// The indexation kinds whose indexes are cleared and not rebuilt yet.
+        stale_indexation: HashSet<IndexationKind>,
+    },
 }
 
 impl<Height> DatabaseMetadata<Height> {
@@ -112,6 +140,7 @@ impl<Height> DatabaseMetadata<Height> {
         match self {
             Self::V1 { version, .. } => *version,
             Self::V2 { version, .. } => *version,
+            Self::V3 { version, .. } => *version,
         }
     }
 
@@ -120,6 +149,7 @@ impl<Height> DatabaseMetadata<Height> {
         match self {
             Self::V1 { height, .. } => height,
             Self::V2 { height, .. } => height,
+            Self::V3 { height, .. } => height,
         }
     }
 
@@ -130,9 +160,23 @@ impl<Height> DatabaseMetadata<Height> {
             Self::V2 {
                 indexation_availability,
                 ..
+            }
+            | Self::V3 {
+                indexation_availability,
+                ..
             } => indexation_availability.contains(&kind),
         }
     }
+
+    /// Returns true if the index of the given indexation kind is being rebuilt.
+    pub fn indexation_stale(&self, kind: IndexationKind) -> bool {
+        match self {
+            Self::V1 { .. } | Self::V2 { .. } => false,
+            Self::V3 {
+                stale_indexation, ..
+            } => stale_indexation.contains(&kind),
+        }
+    }
 }
 
 /// Gets the indexation availability from the metadata.
@@ -147,6 +191,10 @@ where
         Some(DatabaseMetadata::V2 {
             indexation_availability,
             ..
+        })
+        | Some(DatabaseMetadata::V3 {
+            indexation_availability,
+            ..
         }) => indexation_availability.clone(),
         // If the metadata doesn't exist, it is a new database,
         // and we should set all indexation kinds to available.
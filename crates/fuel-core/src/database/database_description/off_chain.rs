@@ -1,5 +1,8 @@
 use crate::{
-    database::database_description::DatabaseDescription,
+    database::database_description::{
+        CompressionPolicy,
+        DatabaseDescription,
+    },
     fuel_core_graphql_api,
 };
 use fuel_core_types::fuel_types::BlockHeight;
@@ -26,12 +29,20 @@ impl DatabaseDescription for OffChain {
     fn prefix(column: &Self::Column) -> Option<usize> {
         match column {
             Self::Column::OwnedCoins
+            | Self::Column::OwnedCoinsByAsset
+            | Self::Column::PredicateOwnedCoins
             | Self::Column::TransactionsByOwnerBlockIdx
-            | Self::Column::OwnedMessageIds => {
+            | Self::Column::OwnedMessageIds
+            | Self::Column::MessagesBySender
+            | Self::Column::BlocksByProducer => {
                 // prefix is address length
                 Some(32)
             }
             _ => None,
         }
     }
+
+    fn compression_policy(column: &Self::Column) -> CompressionPolicy {
+        column.compression_policy()
+    }
 }
@@ -4,8 +4,11 @@ use crate::{
         OnChainIterableKeyValueView,
     },
     fuel_core_graphql_api::storage::messages::{
+        MessagesBySender,
         OwnedMessageIds,
         OwnedMessageKey,
+        OwnedMessageState,
+        SenderMessageKey,
         SpentMessages,
     },
 };
@@ -28,19 +31,51 @@ use fuel_core_types::{
 use itertools::Itertools;
 
 impl OffChainIterableKeyValueView {
+    /// Returns the ids of the unspent messages of the `owner`.
     pub fn owned_message_ids(
         &self,
         owner: &Address,
         start_message_id: Option<Nonce>,
         direction: Option<IterDirection>,
     ) -> impl Iterator<Item = StorageResult<Nonce>> + '_ {
+        self.owned_message_states(owner, start_message_id, direction)
+            .filter_map(|res| match res {
+                Ok((_, state)) if state.is_spent() => None,
+                res => Some(res.map(|(nonce, _)| nonce)),
+            })
+    }
+
+    /// Returns the ids of the messages of the `owner` with their states,
+    /// including the spent messages.
+    pub fn owned_message_states(
+        &self,
+        owner: &Address,
+        start_message_id: Option<Nonce>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<(Nonce, OwnedMessageState)>> + '_ {
         let start_message_id =
             start_message_id.map(|msg_id| OwnedMessageKey::new(owner, &msg_id));
-        self.iter_all_filtered_keys::<OwnedMessageIds, _>(
+        self.iter_all_filtered::<OwnedMessageIds, _>(
             Some(*owner),
             start_message_id.as_ref(),
             direction,
         )
+        .map(|res| res.map(|(key, state)| (*key.nonce(), state)))
+    }
+
+    pub fn message_ids_by_sender(
+        &self,
+        sender: &Address,
+        start_message_id: Option<Nonce>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<Nonce>> + '_ {
+        let start_message_id =
+            start_message_id.map(|msg_id| SenderMessageKey::new(sender, &msg_id));
+        self.iter_all_filtered_keys::<MessagesBySender, _>(
+            Some(*sender),
+            start_message_id.as_ref(),
+            direction,
+        )
         .map(|res| res.map(|key| *key.nonce()))
     }
 
@@ -2,24 +2,49 @@ use super::database_description::{
     indexation_availability,
     IndexationKind,
 };
-use crate::database::{
-    database_description::{
-        DatabaseDescription,
-        DatabaseMetadata,
+use crate::{
+    database::{
+        database_description::{
+            off_chain::OffChain,
+            DatabaseDescription,
+            DatabaseMetadata,
+        },
+        Database,
+        Error as DatabaseError,
+    },
+    graphql_api::storage::{
+        coins::{
+            OwnedCoinsLayout,
+            OwnedCoinsLayoutMetadata,
+            OWNED_COINS_LAYOUT_KEY,
+        },
+        rollbacks::{
+            ChainRollback,
+            LastChainRollback,
+            LAST_CHAIN_ROLLBACK_KEY,
+        },
     },
-    Database,
-    Error as DatabaseError,
 };
 use fuel_core_storage::{
     blueprint::plain::Plain,
-    codec::postcard::Postcard,
+    codec::{
+        postcard::Postcard,
+        raw::Raw,
+    },
     structured_storage::TableWithBlueprint,
+    transactional::{
+        ReadTransaction,
+        StorageTransaction,
+    },
     Error as StorageError,
     Mappable,
     Result as StorageResult,
+    StorageAsMut,
     StorageAsRef,
     StorageInspect,
+    StorageMutate,
 };
+use std::collections::HashSet;
 
 /// The table that stores all metadata about the database.
 pub struct MetadataTable<Description>(core::marker::PhantomData<Description>);
@@ -46,6 +71,66 @@ where
     }
 }
 
+/// The key of the [`DirtyFlag`]. The key of the [`MetadataTable`] is encoded
+/// as the empty bytes, so they share the metadata column without overlapping.
+pub const DIRTY_FLAG_KEY: [u8; 5] = *b"dirty";
+
+/// The flag set when the node opens the database and cleared at the clean shutdown.
+/// The flag found at the start means that the previous run didn't shut down cleanly.
+pub struct DirtyFlag<Description>(core::marker::PhantomData<Description>);
+
+impl<Description> Mappable for DirtyFlag<Description>
+where
+    Description: DatabaseDescription,
+{
+    type Key = [u8; 5];
+    type OwnedKey = Self::Key;
+    type Value = ();
+    type OwnedValue = Self::Value;
+}
+
+impl<Description> TableWithBlueprint for DirtyFlag<Description>
+where
+    Description: DatabaseDescription,
+{
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = Description::Column;
+
+    fn column() -> Self::Column {
+        Description::metadata_column()
+    }
+}
+
+impl<Description> Database<Description>
+where
+    Description: DatabaseDescription,
+    Self: StorageInspect<DirtyFlag<Description>, Error = StorageError>,
+    for<'a> StorageTransaction<&'a Self>:
+        StorageMutate<DirtyFlag<Description>, Error = StorageError>,
+{
+    /// Returns `true` if the database wasn't closed cleanly since it was marked as dirty.
+    pub fn is_dirty(&self) -> StorageResult<bool> {
+        self.storage::<DirtyFlag<Description>>()
+            .contains_key(&DIRTY_FLAG_KEY)
+    }
+
+    /// Sets the dirty flag when the node opens the database and
+    /// clears it when the node shuts down cleanly.
+    pub fn set_dirty(&self, dirty: bool) -> StorageResult<()> {
+        let mut transaction = self.read_transaction();
+        if dirty {
+            transaction
+                .storage_as_mut::<DirtyFlag<Description>>()
+                .insert(&DIRTY_FLAG_KEY, &())?;
+        } else {
+            transaction
+                .storage_as_mut::<DirtyFlag<Description>>()
+                .remove(&DIRTY_FLAG_KEY)?;
+        }
+        self.commit_changes_without_height(transaction.into_changes())
+    }
+}
+
 impl<Description, Stage> Database<Description, Stage>
 where
     Description: DatabaseDescription,
@@ -88,4 +173,144 @@ where
         let indexation_availability = indexation_availability::<Description>(metadata);
         Ok(indexation_availability.contains(&kind))
     }
+
+    pub fn indexation_stale(&self, kind: IndexationKind) -> StorageResult<bool> {
+        let stale = self
+            .storage::<MetadataTable<Description>>()
+            .get(&())?
+            .is_some_and(|metadata| metadata.indexation_stale(kind));
+        Ok(stale)
+    }
+}
+
+impl Database<OffChain> {
+    /// Marks the indexes of the `kinds` as stale while they are rebuilt,
+    /// or as ready once they are rebuilt.
+    pub fn set_indexation_stale(
+        &self,
+        kinds: &[IndexationKind],
+        stale: bool,
+    ) -> StorageResult<()> {
+        self.update_indexation(|_, stale_indexation| {
+            for kind in kinds {
+                if stale {
+                    stale_indexation.insert(*kind);
+                } else {
+                    stale_indexation.remove(kind);
+                }
+            }
+        })
+    }
+
+    /// Marks the indexes of the `kinds` as available once they are backfilled,
+    /// or as unavailable.
+    pub fn set_indexation_available(
+        &self,
+        kinds: &[IndexationKind],
+        available: bool,
+    ) -> StorageResult<()> {
+        self.update_indexation(|indexation_availability, _| {
+            for kind in kinds {
+                if available {
+                    indexation_availability.insert(*kind);
+                } else {
+                    indexation_availability.remove(kind);
+                }
+            }
+        })
+    }
+
+    /// Applies the `update` to the available and the stale indexation kinds.
+    fn update_indexation(
+        &self,
+        update: impl FnOnce(&mut HashSet<IndexationKind>, &mut HashSet<IndexationKind>),
+    ) -> StorageResult<()> {
+        let mut transaction = self.read_transaction();
+        let metadata = transaction
+            .storage_as_mut::<MetadataTable<OffChain>>()
+            .get(&())?
+            .ok_or_else(|| anyhow::anyhow!("The database is not initialized"))?
+            .into_owned();
+
+        let (version, height, mut indexation_availability, mut stale_indexation) =
+            match metadata {
+                DatabaseMetadata::V1 { .. } => {
+                    return Err(anyhow::anyhow!(
+                        "The database doesn't track the availability of the indexation"
+                    )
+                    .into())
+                }
+                DatabaseMetadata::V2 {
+                    version,
+                    height,
+                    indexation_availability,
+                } => (version, height, indexation_availability, HashSet::new()),
+                DatabaseMetadata::V3 {
+                    version,
+                    height,
+                    indexation_availability,
+                    stale_indexation,
+                } => (version, height, indexation_availability, stale_indexation),
+            };
+        update(&mut indexation_availability, &mut stale_indexation);
+
+        transaction
+            .storage_as_mut::<MetadataTable<OffChain>>()
+            .insert(
+                &(),
+                &DatabaseMetadata::V3 {
+                    version,
+                    height,
+                    indexation_availability,
+                    stale_indexation,
+                },
+            )?;
+        self.commit_changes_without_height(transaction.into_changes())
+    }
+
+    /// Records the `layout` of the `OwnedCoins` column in the new database,
+    /// or checks that the existing database uses it. The layouts can't be mixed,
+    /// so the layout of the existing database is changed only by the migration.
+    pub fn init_owned_coins_layout(&self, layout: OwnedCoinsLayout) -> StorageResult<()> {
+        let recorded = self
+            .storage::<OwnedCoinsLayoutMetadata>()
+            .get(&OWNED_COINS_LAYOUT_KEY)?
+            .map(|recorded| *recorded);
+        let recorded = match recorded {
+            Some(recorded) => recorded,
+            // The databases created before the layout was recorded use the plain layout.
+            None if self.latest_height_from_metadata()?.is_some() => {
+                OwnedCoinsLayout::Plain
+            }
+            None => layout,
+        };
+
+        if recorded != layout {
+            return Err(anyhow::anyhow!(
+                "The off-chain database uses the `{recorded}` layout of the owned coins, \
+                but the `{layout}` layout is configured. The layouts can't be mixed, \
+                migrate the database with `fuel-core migrate-owned-coins --layout {layout}`"
+            )
+            .into())
+        }
+        self.set_owned_coins_layout(layout)
+    }
+
+    /// Records the `layout` of the `OwnedCoins` column.
+    pub fn set_owned_coins_layout(&self, layout: OwnedCoinsLayout) -> StorageResult<()> {
+        let mut transaction = self.read_transaction();
+        transaction
+            .storage_as_mut::<OwnedCoinsLayoutMetadata>()
+            .insert(&OWNED_COINS_LAYOUT_KEY, &layout)?;
+        self.commit_changes_without_height(transaction.into_changes())
+    }
+
+    /// Records the `rollback` as the last rollback of the database.
+    pub fn set_last_chain_rollback(&self, rollback: &ChainRollback) -> StorageResult<()> {
+        let mut transaction = self.read_transaction();
+        transaction
+            .storage_as_mut::<LastChainRollback>()
+            .insert(&LAST_CHAIN_ROLLBACK_KEY, rollback)?;
+        self.commit_changes_without_height(transaction.into_changes())
+    }
 }
@@ -1,8 +1,11 @@
 use crate::{
     database::OffChainIterableKeyValueView,
     fuel_core_graphql_api::storage::transactions::{
+        ContractTransactionIndexKey,
+        ContractTransactions,
         OwnedTransactionIndexCursor,
         OwnedTransactionIndexKey,
+        OwnedTransactionValue,
         OwnedTransactions,
         TransactionStatuses,
     },
@@ -20,7 +23,10 @@ use fuel_core_types::{
         Bytes32,
         TxPointer,
     },
-    fuel_types::Address,
+    fuel_types::{
+        Address,
+        ContractId,
+    },
     services::txpool::TransactionExecutionStatus,
 };
 
@@ -39,8 +45,8 @@ impl crate::database::Database {
 }
 
 impl OffChainIterableKeyValueView {
-    /// Iterates over a KV mapping of `[address + block height + tx idx] => transaction id`. This
-    /// allows for efficient lookup of transaction ids associated with an address, sorted by
+    /// Iterates over a KV mapping of `[address + block height + tx idx] => transaction id + direction`.
+    /// This allows for efficient lookup of transaction ids associated with an address, sorted by
     /// block age and ordering within a block. The cursor tracks the `[block height + tx idx]` for
     /// pagination purposes.
     pub fn owned_transactions(
@@ -48,7 +54,8 @@ impl OffChainIterableKeyValueView {
         owner: Address,
         start: Option<OwnedTransactionIndexCursor>,
         direction: Option<IterDirection>,
-    ) -> impl Iterator<Item = StorageResult<(TxPointer, Bytes32)>> + '_ {
+    ) -> impl Iterator<Item = StorageResult<(TxPointer, OwnedTransactionValue)>> + '_
+    {
         let start = start.map(|cursor| {
             OwnedTransactionIndexKey::new(&owner, cursor.block_height, cursor.tx_idx)
         });
@@ -57,6 +64,31 @@ impl OffChainIterableKeyValueView {
             start.as_ref(),
             direction,
         )
+        .map(|res| {
+            res.map(|(key, value)| (TxPointer::new(key.block_height, key.tx_idx), value))
+        })
+    }
+
+    /// Iterates over a KV mapping of `[contract id + block height + tx idx] => transaction id`,
+    /// like [`Self::owned_transactions`] does for the owners.
+    pub fn contract_transactions(
+        &self,
+        contract_id: ContractId,
+        start: Option<OwnedTransactionIndexCursor>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<(TxPointer, Bytes32)>> + '_ {
+        let start = start.map(|cursor| {
+            ContractTransactionIndexKey::new(
+                &contract_id,
+                cursor.block_height,
+                cursor.tx_idx,
+            )
+        });
+        self.iter_all_filtered::<ContractTransactions, _>(
+            Some(contract_id),
+            start.as_ref(),
+            direction,
+        )
         .map(|res| {
             res.map(|(key, tx_id)| (TxPointer::new(key.block_height, key.tx_idx), tx_id))
         })
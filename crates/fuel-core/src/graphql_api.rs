@@ -1,3 +1,4 @@
+use crate::graphql_api::storage::coins::OwnedCoinsLayout;
 use async_graphql::Context;
 use fuel_core_storage::{
     Error as StorageError,
@@ -5,18 +6,31 @@ use fuel_core_storage::{
 };
 use std::{
     net::SocketAddr,
+    num::{
+        NonZeroU32,
+        NonZeroUsize,
+    },
     sync::OnceLock,
     time::Duration,
 };
 
 pub mod api_service;
 pub(crate) mod block_height_subscription;
+pub mod contracts_info_cache;
 pub mod da_compression;
+pub mod da_compression_service;
 pub mod database;
 pub(crate) mod extensions;
-pub(crate) mod indexation;
+pub mod indexation;
+pub mod message_proof_cache;
+pub mod node_health;
+pub mod pending_transactions;
 pub mod ports;
+pub(crate) mod resolver_metrics;
+pub mod snapshot_views;
 pub mod storage;
+pub mod transaction_trees_cache;
+pub mod tx_status_loader;
 pub mod worker_service;
 
 #[derive(Clone, Debug)]
@@ -30,6 +44,9 @@ pub struct Config {
     pub max_size: usize,
     pub max_txpool_dependency_chain_length: usize,
     pub chain_name: String,
+    /// The capacity of each keyspace of the temporal registry of the DA compression,
+    /// `None` if the compression is disabled or the keyspaces aren't bounded.
+    pub da_registry_capacity: Option<NonZeroU32>,
 }
 
 #[derive(Clone, Debug)]
@@ -55,10 +72,96 @@ pub struct ServiceConfig {
     pub api_request_timeout: Duration,
     pub assemble_tx_dry_run_limit: usize,
     pub assemble_tx_estimate_predicates_limit: usize,
+    /// The number of contracts whose info is cached by the GraphQL service.
+    /// The zero value disables the cache.
+    pub contracts_info_cache_capacity: usize,
+    /// The number of message proofs cached in the off-chain database.
+    /// The zero value disables the cache.
+    pub message_proof_cache_capacity: usize,
+    /// The number of blocks whose transaction trees are cached for the inclusion proofs.
+    /// The zero value disables the cache.
+    pub transaction_trees_cache_capacity: usize,
+    /// The maximum number of entries examined by one scan of the owner's index.
+    pub max_scanned_entries: usize,
+    /// The maximum number of block headers returned by one `blockHeaders` query.
+    pub max_block_headers_per_request: usize,
+    /// The coins with the amount below it are suggested for the consolidation by default.
+    pub consolidation_dust_threshold: u64,
+    /// The number of owners whose cached balances are compared with the recomputed
+    /// ones per minute. The zero value disables the check.
+    pub balance_check_owners_per_minute: u32,
+    /// Overwrites the mismatching cached balances found by the check.
+    pub balance_check_repair: bool,
+    /// Enables the `pruneOwner` mutation removing the off-chain indexes of an owner.
+    /// The mutation also requires the `debug` mode.
+    pub allow_owner_pruning: bool,
+    /// Flags the spent coins in the `OwnedCoins` index instead of removing them.
+    /// The flagged coins are removed by the background compaction.
+    pub mark_spent_owned_coins: bool,
+    /// The period between the runs of the compaction of the owned coins.
+    pub owned_coins_compaction_interval: Duration,
+    /// The number of blocks since the spending after which the flagged coin is removed.
+    pub owned_coins_compaction_min_spent_age: u32,
+    /// The maximum number of the owned coins examined by one run of the compaction.
+    pub owned_coins_compaction_entries_per_run: NonZeroUsize,
+    /// The layout of the keys of the `OwnedCoins` column. It is recorded when
+    /// the database is created and must match the layout of the existing database.
+    pub owned_coins_layout: OwnedCoinsLayout,
+    /// The time after which the view pinned by the `snapshotView` query is released.
+    pub snapshot_view_ttl: Duration,
+    /// The maximum number of the views pinned at the same time.
+    /// The zero value disables the `snapshotView` query.
+    pub max_snapshot_views: usize,
+    /// The maximum number of the active `balanceUpdated` subscriptions.
+    /// The zero value disables the subscription.
+    pub max_balance_subscriptions: usize,
+    /// The maximum size in bytes of the tag attached by the `tagTransaction` mutation.
+    /// The zero value disables the mutation, which also requires the `debug` feature.
+    pub max_transaction_tag_size: usize,
+    /// If set, the readiness endpoint reports the node as ready only once the off-chain
+    /// database lags behind the chain tip by at most this number of blocks.
+    pub ready_when_offchain_synced: Option<u32>,
+    /// The health report classifies the subsystem lagging behind the chain tip
+    /// by more blocks as degraded.
+    pub health_degraded_lag: u32,
+    /// The health report classifies the subsystem lagging behind the chain tip
+    /// by more blocks as failing.
+    pub health_failing_lag: u32,
+    /// The maximum number of the entries requested by one page of the resolvers
+    /// keyed by the owner.
+    pub max_page_sizes: MaxPageSizes,
     /// Configurable cost parameters to limit graphql queries complexity
     pub costs: Costs,
 }
 
+/// The default maximum number of the entries requested by one page.
+pub const DEFAULT_MAX_PAGE_SIZE: usize = 10_000;
+
+/// The maximum number of the entries requested with `first` or `last` by one page
+/// of each resolver keyed by the owner. The query requesting more entries is rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MaxPageSizes {
+    pub transactions_by_owner: usize,
+    pub relayed_transactions_by_owner: usize,
+    pub coins: usize,
+    pub messages: usize,
+    pub balances: usize,
+    pub owner_activity: usize,
+}
+
+impl Default for MaxPageSizes {
+    fn default() -> Self {
+        Self {
+            transactions_by_owner: DEFAULT_MAX_PAGE_SIZE,
+            relayed_transactions_by_owner: DEFAULT_MAX_PAGE_SIZE,
+            coins: DEFAULT_MAX_PAGE_SIZE,
+            messages: DEFAULT_MAX_PAGE_SIZE,
+            balances: DEFAULT_MAX_PAGE_SIZE,
+            owner_activity: DEFAULT_MAX_PAGE_SIZE,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct Costs {
     pub balance_query: usize,
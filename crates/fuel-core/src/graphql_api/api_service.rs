@@ -19,12 +19,15 @@ use crate::{
         self,
         extensions::{
             chain_state_info::ChainStateInfoExtension,
+            coins_to_spend_fallback::CoinsToSpendFallbackExtension,
+            message_proof_cache::MessageProofCacheExtension,
             metrics::MetricsExtension,
             required_fuel_block_height::RequiredFuelBlockHeightExtension,
             validation::ValidationExtension,
         },
     },
     schema::{
+        balance::BalanceSubscriptions,
         CoreSchema,
         CoreSchemaBuilder,
     },
@@ -50,6 +53,7 @@ use axum::{
             ACCESS_CONTROL_ALLOW_ORIGIN,
         },
         HeaderValue,
+        StatusCode,
     },
     response::{
         sse::Event,
@@ -98,10 +102,23 @@ pub type Service = fuel_core_services::ServiceRunner<GraphqlService>;
 pub use super::database::ReadDatabase;
 use super::{
     block_height_subscription,
+    contracts_info_cache::ContractsInfoCache,
+    message_proof_cache::MessageProofCache,
+    node_health::{
+        blocks_behind,
+        LagThresholds,
+        NodeHealthReporter,
+        NodeHealthSources,
+    },
+    pending_transactions::PendingTransactionsSnapshots,
     ports::{
         worker,
         OnChainDatabaseAt,
+        OwnerIndexesPruner,
+        TransactionTagger,
     },
+    snapshot_views::SnapshotViews,
+    transaction_trees_cache::TransactionTreesCache,
 };
 
 pub type BlockProducer = Box<dyn BlockProducerPort>;
@@ -116,6 +133,10 @@ pub type GasPriceProvider = Box<dyn GasPriceEstimate>;
 
 pub type ChainInfoProvider = Box<dyn ChainStateProviderTrait>;
 
+pub type OwnerIndexes = Box<dyn OwnerIndexesPruner>;
+
+pub type TransactionTags = Box<dyn TransactionTagger>;
+
 #[derive(Clone)]
 pub struct SharedState {
     pub bound_address: SocketAddr,
@@ -245,6 +266,12 @@ pub fn new_service<OnChain, OffChain>(
     chain_state_info_provider: ChainInfoProvider,
     memory_pool: SharedMemoryPool,
     block_height_subscriber: block_height_subscription::Subscriber,
+    rollback_notifier: block_height_subscription::ChainRollbackNotifier,
+    contracts_info_cache: ContractsInfoCache,
+    message_proof_cache: MessageProofCache,
+    owner_indexes: OwnerIndexes,
+    transaction_tags: TransactionTags,
+    health_sources: NodeHealthSources,
 ) -> anyhow::Result<Service>
 where
     OnChain: HistoricalView<Height = BlockHeight> + 'static,
@@ -265,11 +292,14 @@ where
     graphql_api::initialize_query_costs(cost_config, balances_indexation_enabled)?;
 
     let network_addr = config.config.addr;
+    let ready_when_offchain_synced = config.config.ready_when_offchain_synced;
     let combined_read_database = ReadDatabase::new(
         config.config.database_batch_size,
+        config.config.max_scanned_entries,
         genesis_block_height,
         on_database,
         off_database,
+        contracts_info_cache,
     )?;
     let request_timeout = config.config.api_request_timeout;
     let concurrency_limit = config.config.max_concurrent_queries;
@@ -281,6 +311,29 @@ where
         config.config.required_fuel_block_height_tolerance;
     let required_fuel_block_height_timeout =
         config.config.required_fuel_block_height_timeout;
+    let transaction_trees_cache =
+        TransactionTreesCache::new(config.config.transaction_trees_cache_capacity);
+    let snapshot_views: SnapshotViews = SnapshotViews::new(
+        config.config.snapshot_view_ttl,
+        config.config.max_snapshot_views,
+    );
+
+    let balance_subscriptions =
+        BalanceSubscriptions::new(config.config.max_balance_subscriptions);
+    let readiness = Readiness {
+        database: combined_read_database.clone(),
+        max_offchain_lag: ready_when_offchain_synced,
+    };
+    let health_reporter = NodeHealthReporter::new(
+        combined_read_database.clone(),
+        block_height_subscriber.clone(),
+        health_sources,
+        config.max_tx as u64,
+        LagThresholds {
+            degraded: config.config.health_degraded_lag,
+            failing: config.config.health_failing_lag,
+        },
+    );
 
     let schema = schema
         .limit_complexity(config.config.max_queries_complexity)
@@ -304,10 +357,21 @@ where
         .data(chain_state_info_provider)
         .data(memory_pool)
         .data(block_height_subscriber.clone())
+        .data(rollback_notifier)
+        .data(message_proof_cache)
+        .data(owner_indexes)
+        .data(transaction_tags)
+        .data(health_reporter.clone())
+        .data(PendingTransactionsSnapshots::default())
+        .data(snapshot_views)
+        .data(balance_subscriptions)
+        .data(transaction_trees_cache)
         .extension(ValidationExtension::new(
             max_queries_resolver_recursive_depth,
         ))
         .extension(async_graphql::extensions::Tracing)
+        .extension(MessageProofCacheExtension::new())
+        .extension(CoinsToSpendFallbackExtension::new())
         .extension(RequiredFuelBlockHeightExtension::new(
             required_fuel_block_height_tolerance,
             required_fuel_block_height_timeout,
@@ -336,7 +400,11 @@ where
         .route("/v1/metrics", get(metrics))
         .route("/v1/health", get(health))
         .route("/health", get(health))
+        .route("/v1/ready", get(ready))
+        .route("/ready", get(ready))
         .layer(Extension(schema))
+        .layer(Extension(readiness))
+        .layer(Extension(health_reporter))
         .layer(TraceLayer::new_for_http())
         .layer(TimeoutLayer::new(request_timeout))
         .layer(SetResponseHeaderLayer::<_>::overriding(
@@ -381,8 +449,48 @@ async fn render_graphql_playground(
     )
 }
 
-async fn health() -> Json<serde_json::Value> {
-    Json(json!({ "up": true }))
+/// Reports the node as up with the health of its subsystems. The endpoint
+/// responds with `200 OK` while the API is serving, regardless of the status.
+async fn health(
+    Extension(reporter): Extension<NodeHealthReporter>,
+) -> Json<serde_json::Value> {
+    match reporter.node_health() {
+        Ok(health) => Json(json!({ "up": true, "health": health })),
+        Err(e) => Json(json!({ "up": true, "error": e.to_string() })),
+    }
+}
+
+/// The state checked by the readiness endpoint.
+#[derive(Clone)]
+struct Readiness {
+    database: ReadDatabase,
+    /// The maximum number of blocks the off-chain database may lag behind
+    /// the on-chain database. If `None`, the off-chain lag is not checked.
+    max_offchain_lag: Option<u32>,
+}
+
+/// Responds with `503 Service Unavailable` while the off-chain database lags
+/// behind the chain tip by more than the configured number of blocks, so the
+/// load balancers don't route the queries to the node serving stale indexes.
+async fn ready(
+    Extension(readiness): Extension<Readiness>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    let on_chain_height = readiness.database.on_chain_height();
+    let off_chain_height = readiness.database.indexed_height();
+    let ready = readiness.max_offchain_lag.map_or(true, |max_lag| {
+        blocks_behind(on_chain_height, off_chain_height) <= max_lag
+    });
+    let status = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    let body = json!({
+        "ready": ready,
+        "on_chain_height": on_chain_height.map(u32::from),
+        "off_chain_height": off_chain_height.map(u32::from),
+    });
+    (status, Json(body))
 }
 
 async fn graphql_handler(
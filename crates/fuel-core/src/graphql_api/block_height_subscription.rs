@@ -1,31 +1,90 @@
 use std::{
     cmp::Reverse,
-    collections::BTreeMap,
+    collections::{
+        BTreeMap,
+        BTreeSet,
+    },
     sync::Arc,
 };
 
-use fuel_core_types::fuel_types::BlockHeight;
+use crate::graphql_api::storage::rollbacks::ChainRollback;
+use fuel_core_types::{
+    fuel_tx::{
+        Address,
+        AssetId,
+    },
+    fuel_types::BlockHeight,
+    tai64::Tai64,
+};
+use futures::{
+    Stream,
+    StreamExt,
+};
 use parking_lot::RwLock;
-use tokio::sync::oneshot;
+use tokio::sync::{
+    broadcast,
+    oneshot,
+};
+use tokio_stream::wrappers::{
+    errors::BroadcastStreamRecvError,
+    BroadcastStream,
+};
+
+/// The number of the indexed blocks buffered for the slowest subscriber.
+/// When the subscriber falls behind further, the oldest blocks are dropped for it.
+const BLOCK_INDEXED_CAPACITY: usize = 1024;
+
+/// The number of the rollbacks buffered for the slowest subscriber.
+const CHAIN_ROLLBACK_CAPACITY: usize = 16;
 
-#[derive(Default)]
 pub struct Handler {
     inner: Arc<RwLock<HandlersMapInner>>,
+    block_indexed: broadcast::Sender<BlockIndexed>,
+    balances_updated: broadcast::Sender<BalancesUpdated>,
+}
+
+impl Default for Handler {
+    fn default() -> Self {
+        Self::new(BlockHeight::default())
+    }
 }
 
 impl Handler {
     pub fn new(block_height: BlockHeight) -> Handler {
+        let (block_indexed, _) = broadcast::channel(BLOCK_INDEXED_CAPACITY);
+        let (balances_updated, _) = broadcast::channel(BLOCK_INDEXED_CAPACITY);
         Self {
             inner: Arc::new(RwLock::new(HandlersMapInner::new(block_height))),
+            block_indexed,
+            balances_updated,
         }
     }
 
     pub fn subscribe(&self) -> Subscriber {
         Subscriber {
             inner: self.inner.clone(),
+            block_indexed: self.block_indexed.clone(),
+            balances_updated: self.balances_updated.clone(),
         }
     }
 
+    /// Notifies the subscribers of the balances modified by the indexed block.
+    /// Like [`Self::notify_block_indexed`], it never waits for the subscribers.
+    pub fn notify_balances_updated(&self, balances: BalancesUpdated) {
+        if balances.balances.is_empty() {
+            return
+        }
+        // The error only means that there are no subscribers at the moment.
+        let _ = self.balances_updated.send(balances);
+    }
+
+    /// Notifies the subscribers of the indexed blocks. The sending never waits
+    /// for the subscribers, so a slow subscriber can't stall the indexation.
+    pub fn notify_block_indexed(&self, block: BlockIndexed) {
+        // The error only means that there are no subscribers at the moment.
+        let _ = self.block_indexed.send(block);
+    }
+
     pub fn notify_and_update(&self, block_height: BlockHeight) {
         let to_notify = {
             let mut inner_map = self.inner.write();
@@ -49,6 +108,8 @@ impl Handler {
 #[derive(Clone, Debug)]
 pub struct Subscriber {
     inner: Arc<RwLock<HandlersMapInner>>,
+    block_indexed: broadcast::Sender<BlockIndexed>,
+    balances_updated: broadcast::Sender<BalancesUpdated>,
 }
 
 impl Subscriber {
@@ -80,6 +141,93 @@ impl Subscriber {
     pub fn current_block_height(&self) -> BlockHeight {
         self.inner.read().current_block_height
     }
+
+    /// Returns the stream of the blocks indexed after the call.
+    /// If the stream falls behind, the dropped blocks are replaced
+    /// with the [`BlockIndexedEvent::Gap`] marker.
+    pub fn block_indexed_events(&self) -> impl Stream<Item = BlockIndexedEvent> {
+        BroadcastStream::new(self.block_indexed.subscribe()).map(|event| match event {
+            Ok(block) => BlockIndexedEvent::Indexed(block),
+            Err(BroadcastStreamRecvError::Lagged(missed_blocks)) => {
+                BlockIndexedEvent::Gap { missed_blocks }
+            }
+        })
+    }
+
+    /// Returns the stream of the balances modified by the blocks indexed after the call.
+    /// If the stream falls behind, the dropped updates are replaced
+    /// with the [`BalancesUpdatedEvent::Gap`] marker.
+    pub fn balances_updated_events(&self) -> impl Stream<Item = BalancesUpdatedEvent> {
+        BroadcastStream::new(self.balances_updated.subscribe()).map(|event| match event {
+            Ok(balances) => BalancesUpdatedEvent::Updated(balances),
+            Err(BroadcastStreamRecvError::Lagged(missed_blocks)) => {
+                BalancesUpdatedEvent::Gap { missed_blocks }
+            }
+        })
+    }
+}
+
+/// Notifies the subscribers of the rollbacks of the databases.
+/// The clones of the notifier share the subscribers.
+#[derive(Clone, Debug)]
+pub struct ChainRollbackNotifier {
+    sender: broadcast::Sender<ChainRollback>,
+}
+
+impl Default for ChainRollbackNotifier {
+    fn default() -> Self {
+        let (sender, _) = broadcast::channel(CHAIN_ROLLBACK_CAPACITY);
+        Self { sender }
+    }
+}
+
+impl ChainRollbackNotifier {
+    /// Notifies the subscribers of the `rollback`. It never waits for the subscribers.
+    pub fn notify(&self, rollback: ChainRollback) {
+        // The error only means that there are no subscribers at the moment.
+        let _ = self.sender.send(rollback);
+    }
+
+    /// Returns the stream of the rollbacks performed after the call. The rollbacks
+    /// dropped for a slow subscriber are skipped, the last one stays in the metadata.
+    pub fn rollback_events(&self) -> impl Stream<Item = ChainRollback> {
+        BroadcastStream::new(self.sender.subscribe())
+            .filter_map(|event| futures::future::ready(event.ok()))
+    }
+}
+
+/// The owner and asset pairs with the balances modified by the block
+/// committed into the off-chain database.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BalancesUpdated {
+    pub height: BlockHeight,
+    pub balances: Arc<BTreeSet<(Address, AssetId)>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BalancesUpdatedEvent {
+    Updated(BalancesUpdated),
+    /// The subscriber fell behind, and the updates of `missed_blocks` were dropped for it.
+    Gap {
+        missed_blocks: u64,
+    },
+}
+
+/// The block committed into the off-chain database.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlockIndexed {
+    pub height: BlockHeight,
+    pub tx_count: u16,
+    pub timestamp: Tai64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlockIndexedEvent {
+    Indexed(BlockIndexed),
+    /// The subscriber fell behind, and `missed_blocks` were dropped for it.
+    Gap {
+        missed_blocks: u64,
+    },
 }
 
 #[derive(Debug, Default)]
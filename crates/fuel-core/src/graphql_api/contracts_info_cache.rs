@@ -0,0 +1,137 @@
+use crate::graphql_api::storage::Column;
+use fuel_core_metrics::graphql_metrics::graphql_metrics;
+use fuel_core_storage::{
+    transactional::Changes,
+    Result as StorageResult,
+};
+use fuel_core_types::fuel_tx::{
+    ContractId,
+    Salt,
+};
+use quick_cache::sync::Cache;
+use std::sync::Arc;
+
+/// The read-through cache in front of the
+/// [`ContractsInfo`](crate::graphql_api::storage::contracts::ContractsInfo) table.
+///
+/// The cache is shared between the GraphQL service, which populates it on reads,
+/// and the off-chain worker, which evicts the contracts it writes.
+#[derive(Clone)]
+pub struct ContractsInfoCache {
+    salts: Option<Arc<Cache<ContractId, Salt>>>,
+}
+
+impl ContractsInfoCache {
+    /// Creates the cache holding up to `capacity` contracts.
+    /// The zero `capacity` disables the cache.
+    pub fn new(capacity: usize) -> Self {
+        let salts = (capacity > 0).then(|| Arc::new(Cache::new(capacity)));
+        Self { salts }
+    }
+
+    /// Returns the cached salt of the contract or fetches it on a miss.
+    pub fn salt<F>(&self, contract_id: &ContractId, fetch: F) -> StorageResult<Salt>
+    where
+        F: FnOnce() -> StorageResult<Salt>,
+    {
+        let Some(salts) = &self.salts else {
+            return fetch();
+        };
+
+        if let Some(salt) = salts.get(contract_id) {
+            graphql_metrics().record_contracts_info_cache_hit();
+            return Ok(salt);
+        }
+
+        graphql_metrics().record_contracts_info_cache_miss();
+        let salt = fetch()?;
+        salts.insert(*contract_id, salt);
+        Ok(salt)
+    }
+
+    /// Evicts the contracts from the cache.
+    pub fn invalidate(&self, contract_ids: &[ContractId]) {
+        let Some(salts) = &self.salts else {
+            return;
+        };
+
+        for contract_id in contract_ids {
+            salts.remove(contract_id);
+        }
+    }
+}
+
+/// Returns the contracts with the info modified by the `changes`.
+pub fn modified_contracts(changes: &Changes) -> Vec<ContractId> {
+    changes
+        .get(&Column::ContractsInfo.as_u32())
+        .into_iter()
+        .flat_map(|contracts| contracts.keys())
+        .filter_map(|key| ContractId::try_from(key.as_slice()).ok())
+        .collect()
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::{
+        database::{
+            database_description::off_chain::OffChain,
+            Database,
+        },
+        graphql_api::storage::contracts::ContractsInfo,
+    };
+    use fuel_core_storage::{
+        transactional::{
+            IntoTransaction,
+            StorageTransaction,
+        },
+        StorageAsMut,
+    };
+    use fuel_core_types::entities::contract::ContractsInfoType;
+
+    #[test]
+    fn salt__fetches_only_on_miss() {
+        let cache = ContractsInfoCache::new(10);
+        let contract_id = ContractId::from([1; 32]);
+        let salt = Salt::from([2; 32]);
+
+        // given
+        cache.salt(&contract_id, || Ok(salt)).unwrap();
+
+        // when
+        let cached = cache
+            .salt(&contract_id, || panic!("The salt should be cached"))
+            .unwrap();
+
+        // then
+        assert_eq!(cached, salt);
+    }
+
+    #[test]
+    fn invalidate__evicts_modified_contracts() {
+        let cache = ContractsInfoCache::new(10);
+        let contract_id = ContractId::from([1; 32]);
+        let new_salt = Salt::from([3; 32]);
+        cache
+            .salt(&contract_id, || Ok(Salt::from([2; 32])))
+            .unwrap();
+
+        // given
+        let mut transaction: StorageTransaction<Database<OffChain>> =
+            Database::<OffChain>::in_memory().into_transaction();
+        let info: ContractsInfoType = new_salt.into();
+        transaction
+            .storage_as_mut::<ContractsInfo>()
+            .insert(&contract_id, &info)
+            .unwrap();
+
+        // when
+        cache.invalidate(&modified_contracts(transaction.changes()));
+
+        // then
+        let salt = cache.salt(&contract_id, || Ok(new_salt)).unwrap();
+        assert_eq!(salt, new_salt);
+    }
+}
@@ -6,32 +6,48 @@ use crate::fuel_core_graphql_api::{
             TimestampKey,
             TimestampKeyspace,
         },
+        versioned_block_codec::{
+            da_block_payload,
+            split_da_format,
+        },
         *,
     },
 };
 use fuel_core_compression::{
     compress::compress,
     config::Config,
+    eviction_policy::EvictorCursor,
     ports::{
         EvictorDb,
         HistoryLookup,
         TemporalRegistry,
         UtxoIdToPointer,
     },
+    VersionedBlockPayload,
+    VersionedCompressedBlock,
 };
+use fuel_core_metrics::graphql_metrics::graphql_metrics;
 use fuel_core_storage::{
+    codec::{
+        postcard::Postcard,
+        Decode,
+        Encode,
+    },
     not_found,
     tables::{
         Coins,
         FuelBlocks,
         Messages,
     },
+    Result as StorageResult,
     StorageAsMut,
     StorageAsRef,
     StorageInspect,
 };
 use fuel_core_types::{
     blockchain::block::Block,
+    fuel_compression::RegistryKey,
+    fuel_crypto::Hasher,
     fuel_tx::{
         input::PredicateCode,
         Address,
@@ -39,12 +55,27 @@ use fuel_core_types::{
         ContractId,
         ScriptCode,
     },
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+    },
     services::executor::Event,
     tai64::Tai64,
 };
 use futures::FutureExt;
+use std::{
+    num::NonZeroU32,
+    ops::Deref,
+};
+
+/// The maximum number of heights checked by one search of the DA compression gaps.
+pub const DA_COMPRESSION_GAPS_MAX_RANGE: u32 = 10_000;
+
+/// The maximum number of blocks returned by one request of the DA compression metrics.
+pub const DA_COMPRESSION_METRICS_MAX_BLOCKS: usize = 1_000;
 
-/// Performs DA compression for a block and stores it in the database.
+/// Performs DA compression for a block and stores it in the database
+/// together with the height of the last compressed block.
 pub fn da_compress_block<T>(
     config: Config,
     block: &Block,
@@ -65,13 +96,330 @@ where
     .now_or_never()
     .expect("The current implementation resolved all futures instantly")?;
 
+    let height = block.header().consensus().height;
+    let encoded = Postcard::encode(&compressed);
+    let checksum = da_compressed_block_checksum(&encoded);
+    let stats = DaCompressionStats {
+        uncompressed_size: encoded_len(&Postcard::encode(block)),
+        compressed_size: encoded_len(&encoded),
+        registrations: u64::try_from(compressed.registrations().count())
+            .unwrap_or(u64::MAX),
+    };
     db_tx
         .storage_as_mut::<DaCompressedBlocks>()
-        .insert(&block.header().consensus().height, &compressed)?;
+        .insert(&height, &compressed)?;
+    db_tx
+        .storage_as_mut::<DaCompressedBlockChecksums>()
+        .insert(&height, &checksum)?;
+    db_tx
+        .storage_as_mut::<DaCompressedBlockStats>()
+        .insert(&height, &stats)?;
+    db_tx
+        .storage_as_mut::<DaCompressedBlockRegistrations>()
+        .insert(&height, &registration_manifest(&compressed))?;
+    db_tx.set_da_compression_height(height)?;
+
+    graphql_metrics().record_da_compression(
+        stats.uncompressed_size,
+        stats.compressed_size,
+        stats.ratio(),
+        stats.registrations,
+    );
 
     Ok(())
 }
 
+/// Returns the new registrations in the temporal registry made by the block,
+/// with the hash of each registered value.
+pub fn registration_manifest(block: &VersionedCompressedBlock) -> Vec<DaRegistration> {
+    let registrations = block.registrations();
+    let entry = |keyspace, key: &RegistryKey, value: &[u8]| DaRegistration {
+        keyspace,
+        key: *key,
+        value_hash: Hasher::hash(value),
+    };
+
+    let addresses = registrations
+        .address
+        .iter()
+        .map(|(key, value)| entry(MetadataKey::Address, key, value.as_ref()));
+    let asset_ids = registrations
+        .asset_id
+        .iter()
+        .map(|(key, value)| entry(MetadataKey::AssetId, key, value.as_ref()));
+    let contract_ids = registrations
+        .contract_id
+        .iter()
+        .map(|(key, value)| entry(MetadataKey::ContractId, key, value.as_ref()));
+    let script_codes = registrations
+        .script_code
+        .iter()
+        .map(|(key, value)| entry(MetadataKey::ScriptCode, key, value.deref()));
+    let predicate_codes = registrations
+        .predicate_code
+        .iter()
+        .map(|(key, value)| entry(MetadataKey::PredicateCode, key, value.deref()));
+
+    addresses
+        .chain(asset_ids)
+        .chain(contract_ids)
+        .chain(script_codes)
+        .chain(predicate_codes)
+        .collect()
+}
+
+fn encoded_len(bytes: &[u8]) -> u64 {
+    u64::try_from(bytes.len()).unwrap_or(u64::MAX)
+}
+
+/// Computes the checksum of the encoded DA compressed block.
+pub fn da_compressed_block_checksum(bytes: &[u8]) -> u32 {
+    crc32fast::hash(bytes)
+}
+
+/// The result of the comparison of the encoded DA compressed block
+/// with the checksum stored for it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DaBlockVerification {
+    /// The checksum of the block matches the stored checksum.
+    Verified { checksum: u32 },
+    /// The block was stored without a checksum.
+    Unverified { checksum: u32 },
+    /// The checksum of the block doesn't match the stored checksum.
+    Corrupted { expected: u32, actual: u32 },
+}
+
+impl DaBlockVerification {
+    /// Compares the `bytes` of the block with the `stored` checksum.
+    pub fn new(bytes: &[u8], stored: Option<u32>) -> Self {
+        let actual = da_compressed_block_checksum(bytes);
+        match stored {
+            Some(expected) if expected == actual => Self::Verified { checksum: actual },
+            Some(expected) => Self::Corrupted { expected, actual },
+            None => Self::Unverified { checksum: actual },
+        }
+    }
+}
+
+/// The DA compressed block doesn't match the checksum stored for it.
+#[derive(Debug, derive_more::Display)]
+#[display(
+    fmt = "DA compressed block at height {} is corrupted: expected checksum {}, actual {}",
+    height,
+    expected,
+    actual
+)]
+pub struct DaBlockCorrupted {
+    pub height: BlockHeight,
+    pub expected: u32,
+    pub actual: u32,
+}
+
+impl std::error::Error for DaBlockCorrupted {}
+
+/// The proof of the inclusion of the DA compressed block in the Merkle tree
+/// of all compressed blocks. The leaf is the postcard encoded block.
+#[cfg(feature = "fault-proving")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DaCompressedBlockProof {
+    /// The root of the tree at the last compressed block.
+    pub root: Bytes32,
+    /// The number of the compressed blocks in the tree.
+    pub leaf_count: u64,
+    pub proof: fuel_core_types::entities::relayer::message::MerkleProof,
+}
+
+/// The block is above the last block compressed for the DA.
+/// The compression lags behind the indexation, so the block can be requested
+/// again after the compression catches up.
+#[derive(Debug, derive_more::Display)]
+#[display(
+    fmt = "The block at height {} is not yet compressed, the current DA compression height is {}",
+    height,
+    compression_height
+)]
+pub struct DaBlockNotYetCompressed {
+    pub height: BlockHeight,
+    pub compression_height: BlockHeight,
+}
+
+impl std::error::Error for DaBlockNotYetCompressed {}
+
+/// The maximum number of code bytes returned from the temporal registry for debugging.
+pub const REGISTRY_CODE_MAX_BYTES: usize = 64 * 1024;
+
+/// Returns the heights from the `start` to the `end`(inclusive) without
+/// a DA compressed block. The `heights` are the heights of the compressed
+/// blocks in ascending order, starting from the `start`, so the search
+/// visits only the stored blocks instead of probing every height.
+pub fn da_compression_gaps<I>(
+    heights: I,
+    start: BlockHeight,
+    end: BlockHeight,
+) -> StorageResult<Vec<BlockHeight>>
+where
+    I: IntoIterator<Item = StorageResult<BlockHeight>>,
+{
+    let end = u32::from(end);
+    let mut gaps = Vec::new();
+    // `None` when the previous compressed block is at `u32::MAX`.
+    let mut next = Some(u32::from(start));
+
+    for height in heights {
+        let height = u32::from(height?);
+        if height > end {
+            break
+        }
+        if let Some(next) = next {
+            gaps.extend((next..height).map(BlockHeight::from));
+        }
+        next = height.checked_add(1);
+    }
+
+    if let Some(next) = next {
+        gaps.extend((next..=end).map(BlockHeight::from));
+    }
+
+    Ok(gaps)
+}
+
+/// The code registered in the temporal registry of the DA compression.
+/// The code above the size cap is truncated, while the hash and
+/// the size always describe the whole code. The hash is the same as
+/// the one used by the registry index, so it can be compared with
+/// the hash of the code on the chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegistryCode {
+    pub code_hash: Bytes32,
+    pub size: usize,
+    pub bytes: Vec<u8>,
+    pub truncated: bool,
+}
+
+impl RegistryCode {
+    pub fn new(code: &[u8], max_bytes: usize) -> Self {
+        let truncated = code.len() > max_bytes;
+        let bytes = code.iter().take(max_bytes).copied().collect();
+        Self {
+            code_hash: Hasher::hash(code),
+            size: code.len(),
+            bytes,
+            truncated,
+        }
+    }
+}
+
+/// The maximum number of the next eviction candidates returned per keyspace.
+pub const DA_REGISTRY_EVICTION_CANDIDATES_MAX: usize = 256;
+
+/// The keyspaces of the temporal registry of the DA compression.
+pub const DA_REGISTRY_KEYSPACES: [MetadataKey; 5] = [
+    MetadataKey::Address,
+    MetadataKey::AssetId,
+    MetadataKey::ContractId,
+    MetadataKey::ScriptCode,
+    MetadataKey::PredicateCode,
+];
+
+/// The entry of the temporal registry that is overwritten by one of the next registrations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EvictionCandidate {
+    pub key: RegistryKey,
+    /// The time of the last update of the entry, `None` if the key is not used yet.
+    pub last_updated: Option<Tai64>,
+}
+
+/// The state of the evictor of one keyspace of the temporal registry.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegistryEvictorState {
+    pub keyspace: MetadataKey,
+    /// The cursor of the evictor, the key assigned by the last registration.
+    pub latest_assigned_key: Option<RegistryKey>,
+    /// The number of keys holding a registration.
+    pub entry_count: u32,
+    /// The entries in the order they are evicted by the next registrations.
+    pub next_evictions: Vec<EvictionCandidate>,
+}
+
+/// Returns the `count` keys assigned by the next registrations of the keyspace
+/// at the `cursor`, in the same order as the evictor assigns them, see
+/// [`EvictorCursor::assign`]. The `timestamp` reads the time of the registration
+/// of the key, it is used once every key below the `capacity` is registered.
+///
+/// The evictor skips the keys referenced by the block being compressed,
+/// so the actual order may differ for the keys used by that block.
+/// No more keys than the `capacity` are returned.
+pub fn next_eviction_keys<F>(
+    mut cursor: EvictorCursor,
+    capacity: Option<NonZeroU32>,
+    count: usize,
+    mut timestamp: F,
+) -> anyhow::Result<Vec<RegistryKey>>
+where
+    F: FnMut(&RegistryKey) -> anyhow::Result<Tai64>,
+{
+    let count = capacity.map_or(count, |capacity| {
+        count.min(usize::try_from(capacity.get()).unwrap_or(usize::MAX))
+    });
+    let mut keys = Vec::with_capacity(count);
+    while keys.len() < count {
+        let key = cursor.assign(capacity, |key| keys.contains(key), &mut timestamp)?;
+        keys.push(key);
+    }
+    Ok(keys)
+}
+
+/// Returns the keyspace of the timestamps table for the keyspace of the evictor.
+pub fn timestamp_keyspace(keyspace: MetadataKey) -> TimestampKeyspace {
+    match keyspace {
+        MetadataKey::Address => TimestampKeyspace::Address,
+        MetadataKey::AssetId => TimestampKeyspace::AssetId,
+        MetadataKey::ContractId => TimestampKeyspace::ContractId,
+        MetadataKey::ScriptCode => TimestampKeyspace::ScriptCode,
+        MetadataKey::PredicateCode => TimestampKeyspace::PredicateCode,
+    }
+}
+
+/// The DA compressed block decoded from its stored bytes.
+///
+/// The transactions stay in the compressed form, with the registry keys instead
+/// of the addresses, contract ids, and codes. Restoring them requires the temporal
+/// registry at the height of the block, which is overwritten by the later blocks.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecompressedBlock {
+    /// The version of the format of the stored block.
+    pub format_version: u8,
+    pub block: VersionedCompressedBlock,
+}
+
+impl DecompressedBlock {
+    /// The version of the payload of the block.
+    pub fn payload_version(&self) -> u8 {
+        match self.block {
+            VersionedCompressedBlock::V0(_) => 0,
+            #[cfg(feature = "fault-proving")]
+            VersionedCompressedBlock::V1(_) => 1,
+        }
+    }
+
+    /// Returns the whole block as pretty printed JSON.
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        Ok(serde_json::to_string_pretty(&self.block)?)
+    }
+}
+
+/// Decodes the DA compressed block from the `bytes` stored in the `DaCompressedBlocks`.
+/// The bytes without the format byte are read as the blocks stored before the versioning.
+pub fn decompress_da_block(bytes: &[u8]) -> anyhow::Result<DecompressedBlock> {
+    let (format_version, _) = split_da_format(bytes);
+    let payload = da_block_payload(bytes)?;
+    let block = Postcard::decode(&payload)?;
+    Ok(DecompressedBlock {
+        format_version,
+        block,
+    })
+}
+
 pub struct DbTx<'a, Tx> {
     pub db_tx: &'a mut Tx,
 }
@@ -266,6 +614,22 @@ mod v1_impl_temporal_registry {
                         .map(|v| v.into_owned())
                     )
                 }
+
+                fn get_entry_count(&self) -> anyhow::Result<Option<u32>> {
+                    Ok(self
+                        .db_tx.db_tx
+                        .storage_as_ref::<DaCompressionTemporalRegistryEntryCounts>()
+                        .get(&MetadataKey::$type)?
+                        .map(|v| v.into_owned())
+                    )
+                }
+
+                fn set_entry_count(&mut self, count: u32) -> anyhow::Result<()> {
+                    self.db_tx.db_tx
+                        .storage_as_mut::<DaCompressionTemporalRegistryEntryCounts>()
+                        .insert(&MetadataKey::$type, &count)?;
+                    Ok(())
+                }
             }
 
         }};
@@ -286,6 +650,7 @@ mod v2_impl_temporal_registry {
         address::DaCompressionTemporalRegistryAddressV2,
         asset_id::DaCompressionTemporalRegistryAssetIdV2,
         contract_id::DaCompressionTemporalRegistryContractIdV2,
+        entry_counts::DaCompressionTemporalRegistryEntryCountsV2,
         evictor_cache::DaCompressionTemporalRegistryEvictorCacheV2,
         predicate_code::DaCompressionTemporalRegistryPredicateCodeV2,
         registry_index::DaCompressionTemporalRegistryIndexV2,
@@ -469,6 +834,22 @@ mod v2_impl_temporal_registry {
                         .map(|v| v.into_owned())
                     )
                 }
+
+                fn get_entry_count(&self) -> anyhow::Result<Option<u32>> {
+                    Ok(self
+                        .db_tx.db_tx
+                        .storage_as_ref::<DaCompressionTemporalRegistryEntryCountsV2>()
+                        .get(&MetadataKey::$type)?
+                        .map(|v| v.into_owned())
+                    )
+                }
+
+                fn set_entry_count(&mut self, count: u32) -> anyhow::Result<()> {
+                    self.db_tx.db_tx
+                        .storage_as_mut::<DaCompressionTemporalRegistryEntryCountsV2>()
+                        .insert(&MetadataKey::$type, &count)?;
+                    Ok(())
+                }
             }
         }};
     }
@@ -575,3 +956,537 @@ where
         })
     }
 }
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::{
+        database::{
+            database_description::off_chain::OffChain,
+            Database,
+        },
+        fuel_core_graphql_api::{
+            ports::OffChainDatabase,
+            storage::da_compression::versioned_block_codec::VersionedBlockCodec,
+        },
+    };
+    use fuel_core_storage::transactional::{
+        AtomicView,
+        WriteTransaction,
+    };
+    use fuel_core_types::fuel_tx::{
+        Output,
+        TransactionBuilder,
+    };
+    use std::time::Duration;
+
+    fn block_with_outputs_to(recipients: impl Iterator<Item = Address>) -> Block {
+        let mut builder = TransactionBuilder::script(vec![], vec![]);
+        for recipient in recipients {
+            builder.add_output(Output::coin(recipient, 1, AssetId::default()));
+        }
+        let mut block = Block::default();
+        block.header_mut().set_block_height(1u32.into());
+        *block.transactions_mut() = vec![builder.finalize_as_transaction()];
+        block
+    }
+
+    fn compress_and_read_stats(block: &Block) -> DaCompressionStats {
+        let mut database = Database::<OffChain>::in_memory();
+        let config = Config {
+            temporal_registry_retention: Duration::from_secs(3600),
+            registry_capacity: None,
+        };
+        let mut transaction = database.write_transaction();
+        da_compress_block(config, block, &[], &mut transaction).unwrap();
+        transaction.commit().unwrap();
+
+        let stats = database
+            .latest_view()
+            .unwrap()
+            .da_compression_stats()
+            .collect::<StorageResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(stats.len(), 1);
+        let (height, stats) = stats[0];
+        assert_eq!(height, 1u32.into());
+        stats
+    }
+
+    #[test]
+    fn da_compress_block__repeated_addresses_compress_better_than_unique_ones() {
+        // given
+        const OUTPUTS: u8 = 50;
+        let repeated = block_with_outputs_to((0..OUTPUTS).map(|_| Address::new([1; 32])));
+        let unique = block_with_outputs_to((0..OUTPUTS).map(|i| Address::new([i; 32])));
+
+        // when
+        let repeated_stats = compress_and_read_stats(&repeated);
+        let unique_stats = compress_and_read_stats(&unique);
+
+        // then
+        assert!(repeated_stats.compressed_size < repeated_stats.uncompressed_size);
+        assert!(repeated_stats.registrations < unique_stats.registrations);
+        assert!(repeated_stats.ratio() < unique_stats.ratio());
+    }
+
+    fn block_at(height: u32, recipients: &[Address]) -> Block {
+        let mut block = block_with_outputs_to(recipients.iter().copied());
+        block.header_mut().set_block_height(height.into());
+        block.header_mut().set_time(Tai64(u64::from(height)));
+        block
+    }
+
+    fn compress_blocks_with_capacity(
+        capacity: u32,
+        blocks: &[Block],
+    ) -> (Database<OffChain>, Vec<Vec<u8>>) {
+        let mut database = Database::<OffChain>::in_memory();
+        let config = Config {
+            temporal_registry_retention: Duration::from_secs(3600),
+            registry_capacity: std::num::NonZeroU32::new(capacity),
+        };
+        for block in blocks {
+            let mut transaction = database.write_transaction();
+            da_compress_block(config, block, &[], &mut transaction).unwrap();
+            transaction.commit().unwrap();
+        }
+
+        let view = database.latest_view().unwrap();
+        let payloads = blocks
+            .iter()
+            .map(|block| view.da_compressed_block(block.header().height()).unwrap())
+            .collect();
+        (database, payloads)
+    }
+
+    fn address_registrations(payload: &[u8]) -> Vec<(RegistryKey, Address)> {
+        let compressed: VersionedCompressedBlock = Postcard::decode(payload).unwrap();
+        compressed.registrations().address.clone()
+    }
+
+    #[test]
+    fn da_compress_block__registry_capacity_bounds_keys_and_keeps_blocks_deterministic() {
+        // given
+        const CAPACITY: u32 = 4;
+        let blocks: Vec<Block> = (0u8..10)
+            .map(|i| {
+                let recipients: Vec<_> = (1u8..=3)
+                    .map(|j| Address::new([i.saturating_mul(3).saturating_add(j); 32]))
+                    .collect();
+                block_at(u32::from(i).saturating_add(1), &recipients)
+            })
+            .collect();
+
+        // when
+        let (first_database, first_node) =
+            compress_blocks_with_capacity(CAPACITY, &blocks);
+        let (_, second_node) = compress_blocks_with_capacity(CAPACITY, &blocks);
+
+        // then
+        assert_eq!(first_node, second_node);
+        for payload in &first_node {
+            let registrations = address_registrations(payload);
+            assert_eq!(registrations.len(), 3);
+            assert!(registrations.iter().all(|(key, _)| key.as_u32() < CAPACITY));
+        }
+        let entry_count = first_database
+            .latest_view()
+            .unwrap()
+            .da_registry_entry_count(MetadataKey::Address)
+            .unwrap();
+        assert_eq!(entry_count, Some(CAPACITY));
+    }
+
+    #[test]
+    fn da_compress_block__heavy_eviction_produces_the_same_blocks_on_two_nodes() {
+        // given
+        const CAPACITY: u32 = 8;
+        let address = |i: u32| {
+            let mut bytes = [0u8; 32];
+            bytes[..4].copy_from_slice(&i.to_be_bytes());
+            Address::new(bytes)
+        };
+        // Every block registers new addresses and references some of the recent
+        // ones, so the evictor both overwrites and skips the entries.
+        let blocks: Vec<Block> = (1u32..=50)
+            .map(|height| {
+                let mut recipients: Vec<_> = (0..5)
+                    .map(|j| address(height.saturating_mul(5).saturating_add(j)))
+                    .collect();
+                recipients.push(address(height.saturating_sub(1).saturating_mul(5)));
+                block_at(height, &recipients)
+            })
+            .collect();
+
+        // when
+        let (_, first_node) = compress_blocks_with_capacity(CAPACITY, &blocks);
+        let (_, second_node) = compress_blocks_with_capacity(CAPACITY, &blocks);
+
+        // then
+        assert_eq!(first_node, second_node);
+        let registrations: usize = first_node
+            .iter()
+            .map(|payload| {
+                let registrations = address_registrations(payload);
+                assert!(registrations.iter().all(|(key, _)| key.as_u32() < CAPACITY));
+                registrations.len()
+            })
+            .sum();
+        assert!(registrations > usize::try_from(CAPACITY).unwrap().saturating_mul(10));
+    }
+
+    #[test]
+    fn da_compress_block__overwrites_the_oldest_registration_of_the_full_keyspace() {
+        // given
+        const CAPACITY: u32 = 3;
+        let [a, b, c, d, e, f] = [1u8, 2, 3, 4, 5, 6].map(|i| Address::new([i; 32]));
+        let blocks = vec![
+            // Registers `a`, `b`, `c` under the keys 0, 1, 2.
+            block_at(1, &[a, b, c]),
+            // Keeps `a` and overwrites `b` under the key 1.
+            block_at(2, &[a, d]),
+            // Overwrites `c` under the key 2.
+            block_at(3, &[e]),
+            // Keeps `a` and overwrites `d` under the key 1.
+            block_at(4, &[a, f]),
+            // The key 2 follows the cursor, but `a` under the key 0 is older.
+            block_at(5, &[Address::new([7; 32])]),
+        ];
+
+        // when
+        let (_, payloads) = compress_blocks_with_capacity(CAPACITY, &blocks);
+
+        // then
+        let keys = |payload: &Vec<u8>| {
+            address_registrations(payload)
+                .into_iter()
+                .map(|(key, _)| key.as_u32())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(keys(&payloads[1]), vec![1]);
+        assert_eq!(keys(&payloads[2]), vec![2]);
+        assert_eq!(keys(&payloads[3]), vec![1]);
+        assert_eq!(keys(&payloads[4]), vec![0]);
+    }
+
+    #[test]
+    fn da_compress_block__stores_the_new_registrations_of_the_block() {
+        // given
+        let recipients: Vec<_> = (1u8..=3).map(|i| Address::new([i; 32])).collect();
+        let block = block_with_outputs_to(recipients.clone().into_iter());
+        let mut database = Database::<OffChain>::in_memory();
+        let config = Config {
+            temporal_registry_retention: Duration::from_secs(3600),
+            registry_capacity: None,
+        };
+
+        // when
+        let mut transaction = database.write_transaction();
+        da_compress_block(config, &block, &[], &mut transaction).unwrap();
+        transaction.commit().unwrap();
+
+        // then
+        let registrations = database
+            .latest_view()
+            .unwrap()
+            .da_compressed_block_registrations(&1u32.into())
+            .unwrap()
+            .expect("The registrations are stored with the block");
+        let address_hashes: Vec<_> = registrations
+            .iter()
+            .filter(|registration| registration.keyspace == MetadataKey::Address)
+            .map(|registration| registration.value_hash)
+            .collect();
+        assert_eq!(address_hashes.len(), recipients.len());
+        for recipient in &recipients {
+            assert!(address_hashes.contains(&Hasher::hash(recipient.as_ref())));
+        }
+        let unknown = database
+            .latest_view()
+            .unwrap()
+            .da_compressed_block_registrations(&2u32.into())
+            .unwrap();
+        assert!(unknown.is_none());
+    }
+
+    #[cfg(feature = "fault-proving")]
+    #[test]
+    fn da_compressed_block_proof__proves_the_block_against_the_root_of_all_blocks() {
+        // given
+        let mut database = Database::<OffChain>::in_memory();
+        let config = Config {
+            temporal_registry_retention: Duration::from_secs(3600),
+            registry_capacity: None,
+        };
+        for i in 1u8..=5 {
+            let mut block = block_with_outputs_to(std::iter::once(Address::new([i; 32])));
+            block.header_mut().set_block_height(u32::from(i).into());
+            let mut transaction = database.write_transaction();
+            da_compress_block(config, &block, &[], &mut transaction).unwrap();
+            transaction.commit().unwrap();
+        }
+        let view = database.latest_view().unwrap();
+
+        // when
+        let proof = view
+            .da_compressed_block_proof(&3u32.into())
+            .unwrap()
+            .expect("The block is in the tree");
+
+        // then
+        let leaf = view.da_compressed_block(&3u32.into()).unwrap();
+        let other_leaf = view.da_compressed_block(&2u32.into()).unwrap();
+        assert_eq!(proof.leaf_count, 5);
+        assert_eq!(proof.proof.proof_index, 2);
+        assert!(fuel_core_types::fuel_merkle::binary::verify(
+            &proof.root.into(),
+            &leaf,
+            &proof.proof.proof_set,
+            proof.proof.proof_index,
+            proof.leaf_count,
+        ));
+        assert!(!fuel_core_types::fuel_merkle::binary::verify(
+            &proof.root.into(),
+            &other_leaf,
+            &proof.proof.proof_set,
+            proof.proof.proof_index,
+            proof.leaf_count,
+        ));
+        let unknown = view.da_compressed_block_proof(&6u32.into()).unwrap();
+        assert!(unknown.is_none());
+    }
+
+    #[test]
+    fn decompress_da_block__decodes_stored_block_with_and_without_format_byte() {
+        // given
+        let block = block_with_outputs_to((0..3).map(|i| Address::new([i; 32])));
+        let mut database = Database::<OffChain>::in_memory();
+        let config = Config {
+            temporal_registry_retention: Duration::from_secs(3600),
+            registry_capacity: None,
+        };
+        let mut transaction = database.write_transaction();
+        da_compress_block(config, &block, &[], &mut transaction).unwrap();
+        transaction.commit().unwrap();
+        let payload = database
+            .latest_view()
+            .unwrap()
+            .da_compressed_block(&1u32.into())
+            .unwrap();
+        let compressed: VersionedCompressedBlock = Postcard::decode(&payload).unwrap();
+        let stored = VersionedBlockCodec::encode(&compressed).into_owned();
+
+        // when
+        let from_payload = decompress_da_block(&payload).unwrap();
+        let from_stored = decompress_da_block(&stored).unwrap();
+
+        // then
+        assert_eq!(from_payload, from_stored);
+        assert_eq!(from_stored.format_version, 0);
+        assert_eq!(*from_stored.block.height(), 1u32.into());
+        assert_eq!(from_stored.block.transactions().len(), 1);
+        assert!(from_stored.to_json().unwrap().contains("registrations"));
+    }
+
+    #[test]
+    fn da_block_verification__detects_corrupted_block() {
+        // given
+        let block = b"compressed block".to_vec();
+        let checksum = da_compressed_block_checksum(&block);
+        let mut corrupted = block.clone();
+        corrupted[0] ^= 1;
+
+        // when
+        let verified = DaBlockVerification::new(&block, Some(checksum));
+        let unverified = DaBlockVerification::new(&block, None);
+        let corrupted = DaBlockVerification::new(&corrupted, Some(checksum));
+
+        // then
+        assert_eq!(verified, DaBlockVerification::Verified { checksum });
+        assert_eq!(unverified, DaBlockVerification::Unverified { checksum });
+        assert!(matches!(
+            corrupted,
+            DaBlockVerification::Corrupted { expected, actual }
+                if expected == checksum && actual != checksum
+        ));
+    }
+
+    #[test]
+    fn da_compression_gaps__returns_heights_without_compressed_block() {
+        // given
+        let heights = [3u32, 4, 7, 12]
+            .into_iter()
+            .map(|height| Ok(BlockHeight::from(height)));
+
+        // when
+        let gaps = da_compression_gaps(heights, 2u32.into(), 9u32.into()).unwrap();
+
+        // then
+        let expected: Vec<BlockHeight> =
+            [2u32, 5, 6, 8, 9].into_iter().map(Into::into).collect();
+        assert_eq!(gaps, expected);
+    }
+
+    #[test]
+    fn da_compression_gaps__handles_block_at_the_last_height() {
+        // given
+        let heights = [u32::MAX].into_iter().map(|height| Ok(height.into()));
+
+        // when
+        let gaps =
+            da_compression_gaps(heights, (u32::MAX - 1).into(), u32::MAX.into()).unwrap();
+
+        // then
+        assert_eq!(gaps, vec![BlockHeight::from(u32::MAX - 1)]);
+    }
+
+    #[test]
+    fn da_registry_script_code__returns_registered_code_with_its_hash() {
+        // given
+        let mut database = Database::<OffChain>::in_memory();
+        let key = RegistryKey::try_from(7u32).unwrap();
+        let code: ScriptCode = vec![42u8; 100].into();
+        let mut transaction = database.write_transaction();
+        DbTx {
+            db_tx: &mut transaction,
+        }
+        .write_registry(&key, &code, Tai64::UNIX_EPOCH)
+        .unwrap();
+        transaction.commit().unwrap();
+
+        // when
+        let stored = database
+            .latest_view()
+            .unwrap()
+            .da_registry_script_code(&key)
+            .unwrap()
+            .expect("The code is registered");
+        let registry_code = RegistryCode::new(&stored, 64);
+
+        // then
+        assert_eq!(stored, code);
+        assert_eq!(registry_code.code_hash, Hasher::hash(&code[..]));
+        assert_eq!(registry_code.size, 100);
+        assert_eq!(registry_code.bytes, vec![42u8; 64]);
+        assert!(registry_code.truncated);
+    }
+
+    #[test]
+    fn da_registry_predicate_code__returns_registered_code_with_its_hash() {
+        // given
+        let mut database = Database::<OffChain>::in_memory();
+        let key = RegistryKey::try_from(7u32).unwrap();
+        let code: PredicateCode = vec![42u8; 10].into();
+        let mut transaction = database.write_transaction();
+        DbTx {
+            db_tx: &mut transaction,
+        }
+        .write_registry(&key, &code, Tai64::UNIX_EPOCH)
+        .unwrap();
+        transaction.commit().unwrap();
+
+        // when
+        let stored = database
+            .latest_view()
+            .unwrap()
+            .da_registry_predicate_code(&key)
+            .unwrap()
+            .expect("The code is registered");
+        let registry_code = RegistryCode::new(&stored, REGISTRY_CODE_MAX_BYTES);
+
+        // then
+        assert_eq!(stored, code);
+        assert_eq!(registry_code.code_hash, Hasher::hash(&code[..]));
+        assert_eq!(registry_code.size, 10);
+        assert_eq!(registry_code.bytes, vec![42u8; 10]);
+        assert!(!registry_code.truncated);
+    }
+
+    fn keys(keys: &[u32]) -> Vec<RegistryKey> {
+        keys.iter()
+            .map(|key| RegistryKey::try_from(*key).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn next_eviction_keys__starts_after_the_latest_assigned_key() {
+        // given
+        let latest_assigned_key = RegistryKey::try_from(5u32).unwrap();
+        let no_timestamp = |_: &RegistryKey| -> anyhow::Result<Tai64> {
+            panic!("The keyspace without the capacity is never full")
+        };
+
+        // when
+        let after_latest = next_eviction_keys(
+            EvictorCursor::new(Some(latest_assigned_key), None),
+            None,
+            3,
+            no_timestamp,
+        )
+        .unwrap();
+        let without_latest =
+            next_eviction_keys(EvictorCursor::new(None, None), None, 2, no_timestamp)
+                .unwrap();
+
+        // then
+        assert_eq!(after_latest, keys(&[6, 7, 8]));
+        assert_eq!(without_latest, keys(&[0, 1]));
+    }
+
+    #[test]
+    fn next_eviction_keys__follows_the_evictor_once_the_capacity_is_reached() {
+        // given
+        let capacity = NonZeroU32::new(4);
+        let cursor = EvictorCursor::new(RegistryKey::try_from(2u32).ok(), Some(3));
+        let timestamps = [Tai64(20), Tai64(10), Tai64(30)];
+
+        // when
+        let next = next_eviction_keys(cursor, capacity, 10, |key| {
+            Ok(timestamps[usize::try_from(key.as_u32()).unwrap()])
+        })
+        .unwrap();
+
+        // then
+        assert_eq!(next, keys(&[3, 1, 0, 2]));
+    }
+
+    #[test]
+    fn da_registry_latest_assigned_key__returns_the_cursor_of_the_evictor() {
+        // given
+        let mut database = Database::<OffChain>::in_memory();
+        let key = RegistryKey::try_from(3u32).unwrap();
+        let address = Address::from([1; 32]);
+        let mut transaction = database.write_transaction();
+        let mut db_tx = CompressDbTx {
+            db_tx: DbTx {
+                db_tx: &mut transaction,
+            },
+            block_events: &[],
+        };
+        db_tx.write_registry(&key, &address, Tai64(10)).unwrap();
+        EvictorDb::<Address>::set_latest_assigned_key(&mut db_tx, key).unwrap();
+        transaction.commit().unwrap();
+
+        // when
+        let view = database.latest_view().unwrap();
+        let latest_assigned_key = view
+            .da_registry_latest_assigned_key(MetadataKey::Address)
+            .unwrap();
+        let timestamp = view
+            .da_registry_timestamp(&TimestampKey {
+                keyspace: timestamp_keyspace(MetadataKey::Address),
+                key,
+            })
+            .unwrap();
+        let other_keyspace = view
+            .da_registry_latest_assigned_key(MetadataKey::AssetId)
+            .unwrap();
+
+        // then
+        assert_eq!(latest_assigned_key, Some(key));
+        assert_eq!(timestamp, Some(Tai64(10)));
+        assert_eq!(other_keyspace, None);
+    }
+}
@@ -0,0 +1,541 @@
+//! The DA compression service compresses the imported blocks separately from
+//! the off-chain worker. It tracks its own progress, so the indexation keeps
+//! advancing while the compression retries a failed block or lags behind.
+
+use super::{
+    da_compression::da_compress_block,
+    node_health::DaCompressionHeight,
+    ports::worker::{
+        self,
+        BlockAt,
+        OffChainDatabaseTransaction,
+    },
+    storage::da_compression::{
+        dictionaries::SharedDaDictionary,
+        versioned_block_codec::DaBlockEncoding,
+    },
+    worker_service::{
+        derive_in_overlay,
+        IndexationRetry,
+    },
+};
+use fuel_core_services::{
+    stream::BoxStream,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+    TaskNextAction,
+};
+use fuel_core_types::{
+    blockchain::consensus::Consensus,
+    fuel_types::BlockHeight,
+    services::block_importer::{
+        ImportResult,
+        SharedImportResult,
+    },
+};
+use futures::StreamExt;
+
+/// The initialization task restores the progress of the DA compression on startup.
+pub struct InitializeTask<BlockImporter, OnChain, OffChain> {
+    config: fuel_core_compression::config::Config,
+    encoding: DaBlockEncoding,
+    dictionary: SharedDaDictionary,
+    blocks_events: BoxStream<SharedImportResult>,
+    block_importer: BlockImporter,
+    on_chain_database: OnChain,
+    off_chain_database: OffChain,
+    published_height: DaCompressionHeight,
+}
+
+/// The DA compression task compresses the imported blocks one after another.
+/// The failed block is retried until it is compressed, and the blocks
+/// imported in the meantime are compressed after it.
+pub struct Task<BlockImporter, D> {
+    config: fuel_core_compression::config::Config,
+    /// The encoding of the compressed blocks at rest.
+    encoding: DaBlockEncoding,
+    /// The dictionary of the [`DaBlockEncoding::Zstd`], once it is trained.
+    dictionary: SharedDaDictionary,
+    blocks_events: BoxStream<SharedImportResult>,
+    block_importer: BlockImporter,
+    database: D,
+    /// The height of the last compressed block.
+    compression_height: Option<BlockHeight>,
+    /// The copy of the `compression_height` read by the health report.
+    published_height: DaCompressionHeight,
+    /// The height of the last imported block known to the task.
+    target_height: Option<BlockHeight>,
+    /// Only the backoff of the policy is used, because the compression
+    /// retries the failed block until it succeeds.
+    retry: IndexationRetry,
+    /// The number of failed attempts to compress the next block.
+    failed_attempts: u32,
+}
+
+impl<BlockImporter, D> Task<BlockImporter, D>
+where
+    BlockImporter: worker::BlockImporter,
+    D: worker::OffChainDatabase,
+{
+    /// Returns true if the `block` follows the last compressed block.
+    fn is_next(&self, block: &ImportResult) -> bool {
+        match self.compression_height {
+            Some(height) => {
+                height.succ() == Some(*block.sealed_block.entity.header().height())
+            }
+            None => matches!(block.sealed_block.consensus, Consensus::Genesis(_)),
+        }
+    }
+
+    fn next_block_at(&self) -> anyhow::Result<BlockAt> {
+        match self.compression_height {
+            Some(height) => {
+                let next = height
+                    .succ()
+                    .ok_or_else(|| anyhow::anyhow!("The block height overflowed"))?;
+                Ok(BlockAt::Specific(next))
+            }
+            None => Ok(BlockAt::Genesis),
+        }
+    }
+
+    /// Compresses the block that follows the last compressed block.
+    /// The `block` is used if it is the next one, otherwise the next block
+    /// is requested from the block importer.
+    fn compress_next_block(
+        &mut self,
+        block: Option<SharedImportResult>,
+    ) -> TaskNextAction {
+        let result = match block {
+            Some(block) if self.is_next(&block) => self.compress(&block),
+            _ => self
+                .next_block_at()
+                .and_then(|at| self.block_importer.block_event_at_height(at))
+                .and_then(|block| self.compress(&block)),
+        };
+
+        match result {
+            Ok(height) => {
+                self.compression_height = Some(height);
+                self.published_height.set(self.compression_height);
+                self.failed_attempts = 0;
+                TaskNextAction::Continue
+            }
+            Err(e) => {
+                self.failed_attempts = self.failed_attempts.saturating_add(1);
+                tracing::warn!(
+                    compression_height = ?self.compression_height,
+                    attempts = self.failed_attempts,
+                    "Failed to compress the next block: {e:?}"
+                );
+                TaskNextAction::ErrorContinue(e)
+            }
+        }
+    }
+
+    /// Compresses the `block` and commits it together with the new compression height.
+    fn compress(&mut self, block: &ImportResult) -> anyhow::Result<BlockHeight> {
+        let height = *block.sealed_block.entity.header().height();
+        let changes = {
+            let storage = self.database.transaction();
+            let ((), changes) = derive_in_overlay(&storage, |tx| {
+                da_compress_block(
+                    self.config,
+                    &block.sealed_block.entity,
+                    &block.events,
+                    tx,
+                )?;
+                if self.encoding != DaBlockEncoding::Plain {
+                    tx.encode_da_compressed_block(
+                        &height,
+                        self.encoding,
+                        self.dictionary.read().as_ref(),
+                    )?;
+                }
+                Ok(())
+            })?;
+            changes
+        };
+        self.database.commit_da_compression(changes)?;
+        Ok(height)
+    }
+
+    fn update_target_height(&mut self, block: &ImportResult) {
+        let height = *block.sealed_block.entity.header().height();
+        if self.target_height < Some(height) {
+            self.target_height = Some(height);
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<BlockImporter, OnChain, OffChain> RunnableService
+    for InitializeTask<BlockImporter, OnChain, OffChain>
+where
+    BlockImporter: worker::BlockImporter,
+    OnChain: worker::OnChainDatabase,
+    OffChain: worker::OffChainDatabase,
+{
+    const NAME: &'static str = "DaCompression";
+    type SharedData = DaCompressionHeight;
+    type Task = Task<BlockImporter, OffChain>;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        self.published_height.clone()
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let InitializeTask {
+            config,
+            encoding,
+            dictionary,
+            blocks_events,
+            block_importer,
+            on_chain_database,
+            off_chain_database,
+            published_height,
+        } = self;
+
+        let compression_height = off_chain_database.da_compression_height()?;
+        published_height.set(compression_height);
+        let target_height = on_chain_database.latest_height()?;
+        tracing::info!(
+            ?compression_height,
+            ?target_height,
+            "Starting the DA compression"
+        );
+
+        Ok(Task {
+            config,
+            encoding,
+            dictionary,
+            blocks_events,
+            block_importer,
+            database: off_chain_database,
+            compression_height,
+            published_height,
+            target_height,
+            retry: IndexationRetry::default(),
+            failed_attempts: 0,
+        })
+    }
+}
+
+impl<BlockImporter, D> RunnableTask for Task<BlockImporter, D>
+where
+    BlockImporter: worker::BlockImporter,
+    D: worker::OffChainDatabase,
+{
+    async fn run(&mut self, watcher: &mut StateWatcher) -> TaskNextAction {
+        // The compression catches up with the imported blocks one block
+        // per iteration, so the service stays responsive to the stop signal.
+        if self.compression_height < self.target_height {
+            if self.failed_attempts > 0 {
+                let backoff = self.retry.backoff(self.failed_attempts);
+                tokio::select! {
+                    biased;
+
+                    _ = watcher.while_started() => {
+                        return TaskNextAction::Stop
+                    }
+
+                    _ = tokio::time::sleep(backoff) => {}
+                }
+            }
+            return self.compress_next_block(None)
+        }
+
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                TaskNextAction::Stop
+            }
+
+            result = self.blocks_events.next() => {
+                let Some(block) = result else {
+                    return TaskNextAction::Stop
+                };
+                self.update_target_height(&block);
+                if self.compression_height < self.target_height {
+                    self.compress_next_block(Some(block))
+                } else {
+                    // The block was compressed during the catch-up.
+                    TaskNextAction::Continue
+                }
+            }
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // The blocks that are not compressed yet are compressed after the restart.
+        Ok(())
+    }
+}
+
+pub(crate) fn new_service<BlockImporter, OnChain, OffChain>(
+    config: fuel_core_compression::config::Config,
+    encoding: DaBlockEncoding,
+    dictionary: SharedDaDictionary,
+    block_importer: BlockImporter,
+    on_chain_database: OnChain,
+    off_chain_database: OffChain,
+) -> ServiceRunner<InitializeTask<BlockImporter, OnChain, OffChain>>
+where
+    BlockImporter: worker::BlockImporter,
+    OnChain: worker::OnChainDatabase,
+    OffChain: worker::OffChainDatabase,
+{
+    ServiceRunner::new(InitializeTask {
+        config,
+        encoding,
+        dictionary,
+        blocks_events: block_importer.block_events(),
+        block_importer,
+        on_chain_database,
+        off_chain_database,
+        published_height: DaCompressionHeight::default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use crate::{
+        database::{
+            database_description::off_chain::OffChain,
+            Database,
+        },
+        graphql_api::{
+            ports::worker::OffChainDatabase,
+            storage::{
+                balances::{
+                    CoinBalances,
+                    CoinBalancesKey,
+                },
+                da_compression::DaCompressedBlocks,
+            },
+            worker_service::{
+                commit_block_indexes,
+                DaCompressionConfig,
+                IndexationConfig,
+            },
+        },
+    };
+    use fuel_core_services::stream::IntoBoxStream;
+    use fuel_core_storage::StorageAsRef;
+    use fuel_core_types::{
+        blockchain::{
+            block::Block,
+            SealedBlock,
+        },
+        entities::coins::coin::Coin,
+        fuel_tx::{
+            Address,
+            AssetId,
+            Input,
+            Output,
+            TransactionBuilder,
+            UniqueIdentifier,
+            UtxoId,
+        },
+        services::executor::Event,
+    };
+    use std::{
+        sync::Arc,
+        time::Duration,
+    };
+
+    /// Returns the blocks from the `imported` results by their heights.
+    struct MockBlockImporter {
+        imported: Vec<SharedImportResult>,
+    }
+
+    impl worker::BlockImporter for MockBlockImporter {
+        fn block_events(&self) -> BoxStream<SharedImportResult> {
+            tokio_stream::pending().into_boxed()
+        }
+
+        fn block_event_at_height(
+            &self,
+            height: BlockAt,
+        ) -> anyhow::Result<SharedImportResult> {
+            let height = match height {
+                BlockAt::Genesis => BlockHeight::from(0),
+                BlockAt::Specific(height) => height,
+            };
+            self.imported
+                .iter()
+                .find(|block| *block.sealed_block.entity.header().height() == height)
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("No block at {height}"))
+        }
+    }
+
+    fn import_result(
+        height: u32,
+        consensus: Consensus,
+        transactions: Vec<fuel_core_types::fuel_tx::Transaction>,
+        events: Vec<Event>,
+    ) -> SharedImportResult {
+        let mut block = Block::default();
+        block.header_mut().set_block_height(height.into());
+        *block.transactions_mut() = transactions;
+        Arc::new(ImportResult {
+            sealed_block: SealedBlock {
+                entity: block,
+                consensus,
+            },
+            tx_status: vec![],
+            events,
+            source: Default::default(),
+        })
+    }
+
+    /// Creates the genesis block with the `coin`, the block spending the `coin`
+    /// to the `recipient`, and an empty block.
+    fn transfer_blocks(coin: Coin, recipient: Address) -> Vec<SharedImportResult> {
+        let mut builder = TransactionBuilder::script(vec![], vec![]);
+        builder.add_input(Input::coin_signed(
+            coin.utxo_id,
+            coin.owner,
+            coin.amount,
+            coin.asset_id,
+            Default::default(),
+            Default::default(),
+        ));
+        builder.add_witness(Default::default());
+        builder.add_output(Output::coin(recipient, coin.amount, coin.asset_id));
+        let tx = builder.finalize_as_transaction();
+        let received = Coin {
+            utxo_id: UtxoId::new(tx.id(&Default::default()), 0),
+            owner: recipient,
+            ..coin
+        };
+
+        vec![
+            import_result(
+                0,
+                Consensus::Genesis(Default::default()),
+                vec![],
+                vec![Event::CoinCreated(coin)],
+            ),
+            import_result(
+                1,
+                Default::default(),
+                vec![tx],
+                vec![Event::CoinConsumed(coin), Event::CoinCreated(received)],
+            ),
+            import_result(2, Default::default(), vec![], vec![]),
+        ]
+    }
+
+    fn indexation_config() -> IndexationConfig {
+        IndexationConfig {
+            chain_id: Default::default(),
+            da_compression_config: DaCompressionConfig::Disabled,
+            balances_indexation_enabled: true,
+            coins_to_spend_indexation_enabled: true,
+            asset_metadata_indexation_enabled: true,
+            contract_balances_indexation_enabled: true,
+            base_asset_id: Default::default(),
+            coin_owner_mapper: None,
+            relayed_transactions: None,
+            mark_spent_owned_coins: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn run__balances_are_indexed_ahead_while_compression_retries_failed_block() {
+        let mut database = Database::<OffChain>::in_memory();
+        let coin = Coin {
+            utxo_id: UtxoId::new([1; 32].into(), 0),
+            owner: [2; 32].into(),
+            amount: 100,
+            asset_id: AssetId::default(),
+            tx_pointer: Default::default(),
+        };
+        let recipient: Address = [3; 32].into();
+        let blocks = transfer_blocks(coin, recipient);
+        // The events of the spending block miss the spent coin,
+        // so the compression of the block fails.
+        let faulty_block = import_result(
+            1,
+            Default::default(),
+            blocks[1].sealed_block.entity.transactions().to_vec(),
+            blocks[1]
+                .events
+                .iter()
+                .filter(|event| !matches!(event, Event::CoinConsumed(_)))
+                .cloned()
+                .collect(),
+        );
+        let events = vec![blocks[0].clone(), faulty_block, blocks[2].clone()];
+        let mut task = Task {
+            config: fuel_core_compression::config::Config {
+                temporal_registry_retention: Duration::from_secs(3600),
+                registry_capacity: None,
+            },
+            encoding: DaBlockEncoding::Plain,
+            dictionary: Default::default(),
+            blocks_events: tokio_stream::iter(events).into_boxed(),
+            block_importer: MockBlockImporter {
+                imported: blocks.clone(),
+            },
+            database: database.clone(),
+            compression_height: None,
+            published_height: Default::default(),
+            target_height: None,
+            retry: IndexationRetry {
+                attempts: 1,
+                initial_backoff: Duration::from_millis(1),
+                max_backoff: Duration::from_millis(1),
+            },
+            failed_attempts: 0,
+        };
+        let mut state_watcher = StateWatcher::started();
+
+        // given
+        for block in blocks.iter() {
+            commit_block_indexes(&indexation_config(), None, block, &mut database)
+                .unwrap();
+        }
+        let _ = task.run(&mut state_watcher).await;
+        let result = task.run(&mut state_watcher).await;
+        assert!(matches!(result, TaskNextAction::ErrorContinue(_)));
+        assert_eq!(database.da_compression_height().unwrap(), Some(0.into()));
+        assert_eq!(task.published_height.get(), Some(0.into()));
+        assert_eq!(
+            OffChainDatabase::latest_height(&database).unwrap(),
+            Some(2.into())
+        );
+        let balance = database
+            .storage_as_ref::<CoinBalances>()
+            .get(&CoinBalancesKey::new(&recipient, &coin.asset_id))
+            .unwrap()
+            .unwrap()
+            .into_owned();
+        assert_eq!(balance, u128::from(coin.amount));
+
+        // when
+        for _ in 0..2 {
+            let _ = task.run(&mut state_watcher).await;
+        }
+
+        // then
+        assert_eq!(database.da_compression_height().unwrap(), Some(2.into()));
+        assert_eq!(task.published_height.get(), Some(2.into()));
+        let compressed_blocks = database.storage_as_ref::<DaCompressedBlocks>();
+        assert!(compressed_blocks.get(&1.into()).unwrap().is_some());
+        assert!(compressed_blocks.get(&2.into()).unwrap().is_some());
+    }
+}
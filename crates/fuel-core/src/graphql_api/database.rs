@@ -1,20 +1,67 @@
+#[cfg(feature = "fault-proving")]
+use crate::fuel_core_graphql_api::da_compression::DaCompressedBlockProof;
 use crate::{
     database::database_description::IndexationKind,
     fuel_core_graphql_api::{
+        contracts_info_cache::ContractsInfoCache,
+        da_compression::{
+            da_compression_gaps,
+            next_eviction_keys,
+            timestamp_keyspace,
+            DaBlockCorrupted,
+            DaBlockNotYetCompressed,
+            DaBlockVerification,
+            EvictionCandidate,
+            RegistryCode,
+            RegistryEvictorState,
+            DA_COMPRESSION_GAPS_MAX_RANGE,
+            DA_COMPRESSION_METRICS_MAX_BLOCKS,
+            DA_REGISTRY_EVICTION_CANDIDATES_MAX,
+            DA_REGISTRY_KEYSPACES,
+            REGISTRY_CODE_MAX_BYTES,
+        },
         database::arc_wrapper::ArcWrapper,
         ports::{
+            CoinsToSpendIndexIter,
             OffChainDatabase,
             OffChainDatabaseAt,
             OnChainDatabase,
             OnChainDatabaseAt,
         },
+        storage::{
+            blocks::CollectedFees,
+            coins::SpentCoin,
+            contracts::{
+                ContractDeployment,
+                ContractsByBlockKey,
+            },
+            da_compression::{
+                timestamps::TimestampKey,
+                DaCompressionStats,
+                DaRegistration,
+            },
+            dead_letter::IndexationDeadLetter,
+            known_owners::KnownOwner,
+            messages::OwnedMessageState,
+            owner_activity::OwnerActivity,
+            rollbacks::ChainRollback,
+            statistic::{
+                AddressTxCount,
+                CommitDurationMax,
+            },
+        },
     },
+    query::OwnedTransactionsFilter,
+    schema::scalars,
 };
+use async_graphql::connection::CursorType;
+use fuel_core_compression::eviction_policy::EvictorCursor;
 use fuel_core_services::yield_stream::StreamYieldExt;
 use fuel_core_storage::{
     iter::{
         BoxedIter,
         IntoBoxedIter,
+        IntoScanBudgetIter,
         IterDirection,
     },
     not_found,
@@ -33,6 +80,10 @@ use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
         consensus::Consensus,
+        header::{
+            BlockHeader,
+            ConsensusParametersVersion,
+        },
         primitives::{
             BlockId,
             DaBlockHeight,
@@ -45,10 +96,12 @@ use fuel_core_types::{
         },
         transaction::RelayedTransactionStatus,
     },
+    fuel_compression::RegistryKey,
     fuel_tx::{
         Address,
         AssetId,
         Bytes32,
+        ConsensusParameters,
         ContractId,
         Salt,
         Transaction,
@@ -66,10 +119,14 @@ use fuel_core_types::{
         graphql_api::ContractBalance,
         txpool::TransactionExecutionStatus,
     },
+    tai64::Tai64,
 };
 use futures::Stream;
 use std::{
     borrow::Cow,
+    collections::HashMap,
+    num::NonZeroU32,
+    ops::RangeInclusive,
     sync::Arc,
 };
 use strum::IntoEnumIterator;
@@ -90,13 +147,16 @@ pub type OffChainViewAt = Arc<dyn OffChainDatabaseAt>;
 
 /// The container of the on-chain and off-chain database view provides.
 /// It is used only by `ViewExtension` to create a [`ReadView`].
+#[derive(Clone)]
 pub struct ReadDatabase {
     /// The size of the batch during fetching from the database.
     batch_size: usize,
+    /// The maximum number of entries examined by one scan of the owner's index.
+    max_scanned_entries: usize,
     /// The height of the genesis block.
     genesis_height: BlockHeight,
     /// The on-chain database view provider.
-    on_chain: Box<
+    on_chain: Arc<
         dyn HistoricalView<
             LatestView = OnChainView,
             Height = BlockHeight,
@@ -104,7 +164,7 @@ pub struct ReadDatabase {
         >,
     >,
     /// The off-chain database view provider.
-    off_chain: Box<
+    off_chain: Arc<
         dyn HistoricalView<
             LatestView = OffChainView,
             Height = BlockHeight,
@@ -113,6 +173,10 @@ pub struct ReadDatabase {
     >,
     /// The flag indicating which indexation is enabled.
     indexation_flags: IndexationFlags,
+    /// The flag indicating which indexation is being rebuilt.
+    stale_indexation_flags: IndexationFlags,
+    /// The cache of the contracts info shared by all views.
+    contracts_info_cache: ContractsInfoCache,
 }
 
 #[derive(Clone)]
@@ -142,9 +206,11 @@ impl ReadDatabase {
     /// Creates a new [`ReadDatabase`] with the given on-chain and off-chain database view providers.
     pub fn new<OnChain, OffChain>(
         batch_size: usize,
+        max_scanned_entries: usize,
         genesis_height: BlockHeight,
         on_chain: OnChain,
         off_chain: OffChain,
+        contracts_info_cache: ContractsInfoCache,
     ) -> Result<Self, StorageError>
     where
         OnChain: HistoricalView<Height = BlockHeight> + 'static,
@@ -173,17 +239,41 @@ impl ReadDatabase {
                         indexation_flags.insert(kind);
                     }
                 }
+                IndexationKind::ContractBalances => {
+                    if off_chain.contract_balances_indexation_enabled()? {
+                        indexation_flags.insert(kind);
+                    }
+                }
+                IndexationKind::OwnedCoinCounts => {
+                    if off_chain.owned_coin_counts_indexation_enabled()? {
+                        indexation_flags.insert(kind);
+                    }
+                }
+            }
+        }
+        let mut stale_indexation_flags = IndexationFlags::new();
+        for kind in IndexationKind::iter() {
+            if off_chain.indexation_stale(kind)? {
+                stale_indexation_flags.insert(kind);
             }
         }
         Ok(Self {
             batch_size,
+            max_scanned_entries,
             genesis_height,
-            on_chain: Box::new(ArcWrapper::new(on_chain)),
-            off_chain: Box::new(ArcWrapper::new(off_chain)),
+            on_chain: Arc::new(ArcWrapper::new(on_chain)),
+            off_chain: Arc::new(ArcWrapper::new(off_chain)),
             indexation_flags,
+            stale_indexation_flags,
+            contracts_info_cache,
         })
     }
 
+    /// Returns the height of the last block indexed in the off-chain database.
+    pub fn indexed_height(&self) -> Option<BlockHeight> {
+        self.off_chain.latest_height()
+    }
+
     /// Creates a consistent view of the database.
     pub fn view(&self) -> StorageResult<ReadView> {
         // TODO: Use the same height for both views to guarantee consistency.
@@ -191,13 +281,63 @@ impl ReadDatabase {
         //  https://github.com/FuelLabs/fuel-core/issues/1582
         Ok(ReadView {
             batch_size: self.batch_size,
+            max_scanned_entries: self.max_scanned_entries,
             genesis_height: self.genesis_height,
             on_chain: self.on_chain.latest_view()?,
             off_chain: self.off_chain.latest_view()?,
             indexation_flags: self.indexation_flags.clone(),
+            stale_indexation_flags: self.stale_indexation_flags.clone(),
+            contracts_info_cache: self.contracts_info_cache.clone(),
         })
     }
 
+    /// Creates the view whose on-chain and off-chain parts are at the same height,
+    /// and returns it with the height. Returns `None` if the off-chain database
+    /// hasn't indexed the latest block yet, or indexed a block during the creation.
+    pub fn consistent_view(&self) -> StorageResult<Option<(ReadView, BlockHeight)>> {
+        let indexed_before = self.off_chain.latest_height();
+        let view = self.view()?;
+        let indexed_after = self.off_chain.latest_height();
+        let height = view.latest_height()?;
+
+        if indexed_before != indexed_after || indexed_after != Some(height) {
+            return Ok(None)
+        }
+        Ok(Some((view, height)))
+    }
+
+    /// Returns the height of the latest block in the on-chain database.
+    pub fn on_chain_height(&self) -> Option<BlockHeight> {
+        self.on_chain.latest_height()
+    }
+
+    /// Returns `true` if the on-chain database retains the state after the block
+    /// at the `height`.
+    pub fn on_chain_state_available_at(&self, height: BlockHeight) -> bool {
+        self.on_chain.view_at(&height).is_ok()
+    }
+
+    /// Returns the lowest height, the state after which is retained by the on-chain
+    /// database. The history is pruned from the oldest blocks, so the availability
+    /// is monotonic, and the height is found by the binary search in the range
+    /// `[genesis_height..latest_height]`.
+    pub fn earliest_on_chain_state_height(&self) -> Option<BlockHeight> {
+        let latest_height = self.on_chain.latest_height()?;
+        let mut low = u32::from(self.genesis_height);
+        let mut high = u32::from(latest_height);
+
+        while low < high {
+            let middle = low.saturating_add(high.saturating_sub(low) / 2);
+            if self.on_chain_state_available_at(middle.into()) {
+                high = middle;
+            } else {
+                low = middle.saturating_add(1);
+            }
+        }
+
+        Some(low.into())
+    }
+
     /// Creates a consistent view of the database at specific block height.
     pub fn view_at(&self, block_height: BlockHeight) -> StorageResult<ReadViewAt> {
         Ok(ReadViewAt {
@@ -219,13 +359,34 @@ impl ReadDatabase {
 #[derive(Clone)]
 pub struct ReadView {
     pub(crate) batch_size: usize,
+    pub(crate) max_scanned_entries: usize,
     pub(crate) genesis_height: BlockHeight,
     pub(crate) on_chain: OnChainView,
     pub(crate) off_chain: OffChainView,
     pub(crate) indexation_flags: IndexationFlags,
+    pub(crate) stale_indexation_flags: IndexationFlags,
+    pub(crate) contracts_info_cache: ContractsInfoCache,
+}
+
+impl core::fmt::Debug for ReadView {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ReadView").finish_non_exhaustive()
+    }
 }
 
 impl ReadView {
+    /// Fails if the index of the `kind` is being rebuilt, so the queries
+    /// don't serve the results of the partially rebuilt index.
+    pub fn ensure_indexation_ready(&self, kind: IndexationKind) -> StorageResult<()> {
+        if self.stale_indexation_flags.contains(&kind) {
+            return Err(anyhow::anyhow!(
+                "The {kind:?} index is rebuilding, try again once it is rebuilt"
+            )
+            .into())
+        }
+        Ok(())
+    }
+
     pub fn transaction(&self, tx_id: &TxId) -> StorageResult<Transaction> {
         let result = self.on_chain.transaction(tx_id);
         if result.is_not_found() {
@@ -262,6 +423,14 @@ impl ReadView {
         }
     }
 
+    pub fn block_header(&self, height: &BlockHeight) -> StorageResult<BlockHeader> {
+        if *height >= self.genesis_height {
+            self.on_chain.block_header(height)
+        } else {
+            self.off_chain.old_block_header(height)
+        }
+    }
+
     pub fn blocks(
         &self,
         height: Option<BlockHeight>,
@@ -316,6 +485,19 @@ impl ReadView {
             self.off_chain.old_block_consensus(id)
         }
     }
+
+    /// Returns the block with the lowest height retained from before regenesis.
+    pub fn oldest_retained_block(&self) -> StorageResult<Option<CompressedBlock>> {
+        self.off_chain
+            .old_blocks(None, IterDirection::Forward)
+            .next()
+            .transpose()
+    }
+
+    /// Returns the transaction retained from before regenesis.
+    pub fn old_transaction(&self, tx_id: &TxId) -> StorageResult<Option<Transaction>> {
+        self.off_chain.old_transaction(tx_id)
+    }
 }
 
 impl StorageInspect<BlobData> for ReadView {
@@ -381,18 +563,48 @@ impl ReadView {
         self.off_chain.relayed_tx_status(id)
     }
 
+    pub fn consensus_parameters_by_version(
+        &self,
+        version: ConsensusParametersVersion,
+    ) -> StorageResult<Option<ConsensusParameters>> {
+        self.off_chain.consensus_parameters_by_version(version)
+    }
+
+    pub fn consensus_parameters_versions(
+        &self,
+    ) -> StorageResult<Vec<ConsensusParametersVersion>> {
+        self.off_chain.consensus_parameters_versions().collect()
+    }
+
+    pub fn owned_relayed_transaction_ids(
+        &self,
+        owner: &Address,
+        start: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<Bytes32>> + '_ {
+        futures::stream::iter(
+            self.off_chain
+                .owned_relayed_transaction_ids(owner, start, direction),
+        )
+    }
+
     pub fn contract_balances(
         &self,
         contract: ContractId,
         start_asset: Option<AssetId>,
         direction: IterDirection,
     ) -> impl Stream<Item = StorageResult<ContractBalance>> + '_ {
-        futures::stream::iter(self.on_chain.contract_balances(
-            contract,
-            start_asset,
-            direction,
-        ))
-        .yield_each(self.batch_size)
+        let balances = if self
+            .indexation_flags
+            .contains(&IndexationKind::ContractBalances)
+        {
+            self.off_chain
+                .contract_balances(contract, start_asset, direction)
+        } else {
+            self.on_chain
+                .contract_balances(contract, start_asset, direction)
+        };
+        futures::stream::iter(balances).yield_each(self.batch_size)
     }
 
     pub fn contract_storage_slots(
@@ -430,52 +642,553 @@ impl ReadView {
         self.off_chain.block_height(block_id)
     }
 
+    pub fn block_heights_by_producer(
+        &self,
+        producer: &Address,
+        start_height: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<BlockHeight>> + '_ {
+        let iter = self
+            .off_chain
+            .block_heights_by_producer(producer, start_height, direction)
+            .with_scan_budget(self.max_scanned_entries, |height| {
+                scalars::U32::from(*height).encode_cursor()
+            });
+
+        futures::stream::iter(iter)
+    }
+
+    /// Returns the ids of the transactions of the block at the `height`.
+    /// The blocks indexed before the ids were tracked fall back to the compressed block.
+    pub fn block_transaction_ids(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Vec<TxId>> {
+        if let Some(ids) = self.off_chain.block_transaction_ids(height)? {
+            return Ok(ids)
+        }
+
+        let block = self.block(height)?;
+        Ok(block.transactions().to_vec())
+    }
+
+    /// Returns the DA compressed block and verifies it against its checksum.
+    /// Fails if the block is above the last compressed block.
     pub fn da_compressed_block(&self, height: &BlockHeight) -> StorageResult<Vec<u8>> {
-        self.off_chain.da_compressed_block(height)
+        let bytes = match self.off_chain.da_compressed_block(height) {
+            Err(err) if err.is_not_found() => {
+                let compression_height = self.off_chain.da_compression_height()?;
+                return match compression_height {
+                    Some(compression_height) if compression_height < *height => {
+                        Err(anyhow::Error::new(DaBlockNotYetCompressed {
+                            height: *height,
+                            compression_height,
+                        })
+                        .into())
+                    }
+                    _ => Err(err),
+                }
+            }
+            result => result?,
+        };
+        let stored = self.off_chain.da_compressed_block_checksum(height)?;
+
+        match DaBlockVerification::new(&bytes, stored) {
+            DaBlockVerification::Corrupted { expected, actual } => {
+                Err(anyhow::Error::new(DaBlockCorrupted {
+                    height: *height,
+                    expected,
+                    actual,
+                })
+                .into())
+            }
+            DaBlockVerification::Verified { .. }
+            | DaBlockVerification::Unverified { .. } => Ok(bytes),
+        }
+    }
+
+    /// Returns the height of the last block compressed for the DA, if any.
+    pub fn da_compression_height(&self) -> StorageResult<Option<BlockHeight>> {
+        self.off_chain.da_compression_height()
+    }
+
+    /// Returns the longest commit of the off-chain worker within the recent blocks, if any.
+    pub fn commit_duration_max(&self) -> StorageResult<Option<CommitDurationMax>> {
+        self.off_chain.commit_duration_max()
+    }
+
+    /// Returns the compression statistics of the `last` DA compressed blocks,
+    /// starting from the latest one. The `last` can't be above
+    /// the [`DA_COMPRESSION_METRICS_MAX_BLOCKS`].
+    pub fn da_compression_stats(
+        &self,
+        last: usize,
+    ) -> StorageResult<Vec<(BlockHeight, DaCompressionStats)>> {
+        if last > DA_COMPRESSION_METRICS_MAX_BLOCKS {
+            return Err(anyhow::anyhow!(
+                "The number of blocks can't be above {DA_COMPRESSION_METRICS_MAX_BLOCKS}"
+            )
+            .into())
+        }
+
+        self.off_chain.da_compression_stats().take(last).collect()
+    }
+
+    /// Returns the registrations in the temporal registry made by the DA
+    /// compressed block, `None` if the block was compressed without them.
+    pub fn da_compressed_block_registrations(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<Vec<DaRegistration>>> {
+        self.off_chain.da_compressed_block_registrations(height)
+    }
+
+    /// Returns the proof of the inclusion of the DA compressed block against
+    /// the root of all compressed blocks, `None` if the block is not in the tree.
+    #[cfg(feature = "fault-proving")]
+    pub fn da_compressed_block_proof(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<DaCompressedBlockProof>> {
+        self.off_chain.da_compressed_block_proof(height)
+    }
+
+    /// Returns the heights from the `start` to the `end`(inclusive) without
+    /// a DA compressed block. The range can't be longer than
+    /// the [`DA_COMPRESSION_GAPS_MAX_RANGE`].
+    pub fn da_compression_gaps(
+        &self,
+        start: BlockHeight,
+        end: BlockHeight,
+    ) -> StorageResult<Vec<BlockHeight>> {
+        if start > end {
+            return Err(anyhow::anyhow!(
+                "The start height {start} is above the end height {end}"
+            )
+            .into())
+        }
+        let range = u32::from(end).saturating_sub(u32::from(start));
+        if range >= DA_COMPRESSION_GAPS_MAX_RANGE {
+            return Err(anyhow::anyhow!(
+                "The range of heights can't be longer than {DA_COMPRESSION_GAPS_MAX_RANGE}"
+            )
+            .into())
+        }
+
+        da_compression_gaps(
+            self.off_chain.da_compressed_block_heights(start),
+            start,
+            end,
+        )
+    }
+
+    /// Returns the script code registered under the `key` in the temporal registry
+    /// of the DA compression, truncated to the [`REGISTRY_CODE_MAX_BYTES`].
+    pub fn da_registry_script_code(
+        &self,
+        key: &RegistryKey,
+    ) -> StorageResult<Option<RegistryCode>> {
+        let code = self.off_chain.da_registry_script_code(key)?;
+        Ok(code.map(|code| RegistryCode::new(&code, REGISTRY_CODE_MAX_BYTES)))
+    }
+
+    /// Returns the predicate code registered under the `key` in the temporal registry
+    /// of the DA compression, truncated to the [`REGISTRY_CODE_MAX_BYTES`].
+    pub fn da_registry_predicate_code(
+        &self,
+        key: &RegistryKey,
+    ) -> StorageResult<Option<RegistryCode>> {
+        let code = self.off_chain.da_registry_predicate_code(key)?;
+        Ok(code.map(|code| RegistryCode::new(&code, REGISTRY_CODE_MAX_BYTES)))
+    }
+
+    /// Returns the state of the evictor of each keyspace of the temporal registry
+    /// with the `next` entries to be evicted by the evictor with the `capacity`.
+    /// The `next` can't be above the [`DA_REGISTRY_EVICTION_CANDIDATES_MAX`].
+    pub fn da_registry_evictor_state(
+        &self,
+        next: usize,
+        capacity: Option<NonZeroU32>,
+    ) -> StorageResult<Vec<RegistryEvictorState>> {
+        if next > DA_REGISTRY_EVICTION_CANDIDATES_MAX {
+            return Err(anyhow::anyhow!(
+                "The number of entries can't be above {DA_REGISTRY_EVICTION_CANDIDATES_MAX}"
+            )
+            .into())
+        }
+
+        DA_REGISTRY_KEYSPACES
+            .into_iter()
+            .map(|keyspace| {
+                let latest_assigned_key =
+                    self.off_chain.da_registry_latest_assigned_key(keyspace)?;
+                let cursor = EvictorCursor::new(
+                    latest_assigned_key,
+                    self.off_chain.da_registry_entry_count(keyspace)?,
+                );
+
+                // The evictor of the full keyspace reads the same timestamps
+                // for several registrations, so they are read once.
+                let mut timestamps = HashMap::new();
+                let mut timestamp = |key: &RegistryKey| -> StorageResult<Option<Tai64>> {
+                    if let Some(timestamp) = timestamps.get(key) {
+                        return Ok(*timestamp)
+                    }
+                    let timestamp =
+                        self.off_chain.da_registry_timestamp(&TimestampKey {
+                            keyspace: timestamp_keyspace(keyspace),
+                            key: *key,
+                        })?;
+                    timestamps.insert(*key, timestamp);
+                    Ok(timestamp)
+                };
+
+                let keys = next_eviction_keys(cursor, capacity, next, |key| {
+                    timestamp(key)?.ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "The key {key:?} of the full keyspace has no timestamp"
+                        )
+                    })
+                })?;
+                let next_evictions = keys
+                    .into_iter()
+                    .map(|key| {
+                        Ok(EvictionCandidate {
+                            key,
+                            last_updated: timestamp(&key)?,
+                        })
+                    })
+                    .collect::<StorageResult<Vec<_>>>()?;
+
+                Ok(RegistryEvictorState {
+                    keyspace,
+                    latest_assigned_key,
+                    entry_count: cursor.entry_count,
+                    next_evictions,
+                })
+            })
+            .collect()
+    }
+
+    /// Recomputes the checksum of the DA compressed block and compares it
+    /// with the stored one.
+    pub fn verify_da_compressed_block(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<DaBlockVerification> {
+        let bytes = self.off_chain.da_compressed_block(height)?;
+        let stored = self.off_chain.da_compressed_block_checksum(height)?;
+        Ok(DaBlockVerification::new(&bytes, stored))
     }
 
     pub fn tx_status(&self, tx_id: &TxId) -> StorageResult<TransactionExecutionStatus> {
         self.off_chain.tx_status(tx_id)
     }
 
+    pub fn transaction_tag(&self, tx_id: &TxId) -> StorageResult<Option<Vec<u8>>> {
+        self.off_chain.transaction_tag(tx_id)
+    }
+
+    pub fn indexation_dead_letter(&self) -> StorageResult<Option<IndexationDeadLetter>> {
+        self.off_chain.indexation_dead_letter()
+    }
+
+    pub fn last_chain_rollback(&self) -> StorageResult<Option<ChainRollback>> {
+        self.off_chain.last_chain_rollback()
+    }
+
+    /// Returns the statuses of the transactions in the order of the `tx_ids`
+    /// with one request to the off-chain database.
+    pub fn tx_statuses(
+        &self,
+        tx_ids: &[TxId],
+    ) -> Vec<StorageResult<Option<TransactionExecutionStatus>>> {
+        self.off_chain.tx_statuses(tx_ids)
+    }
+
+    /// Returns whether the transactions in the order of the `tx_ids` are included
+    /// in the blocks, checking only the existence of their execution statuses.
+    pub fn txs_included(&self, tx_ids: &[TxId]) -> StorageResult<Vec<bool>> {
+        self.off_chain.txs_included(tx_ids).into_iter().collect()
+    }
+
     pub fn owned_coins_ids(
         &self,
         owner: &Address,
         start_coin: Option<UtxoId>,
         direction: IterDirection,
+        include_derived: bool,
     ) -> impl Stream<Item = StorageResult<UtxoId>> + '_ {
-        let iter = self.off_chain.owned_coins_ids(owner, start_coin, direction);
+        let iter = self
+            .off_chain
+            .owned_coins_ids(owner, start_coin, direction, include_derived)
+            .with_scan_budget(self.max_scanned_entries, |utxo_id| {
+                scalars::UtxoId::from(*utxo_id).encode_cursor()
+            });
 
         futures::stream::iter(iter)
     }
 
+    pub fn owned_coins_ids_by_asset(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        start_coin: Option<UtxoId>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<UtxoId>> + '_ {
+        let iter = self
+            .off_chain
+            .owned_coins_ids_by_asset(owner, asset_id, start_coin, direction)
+            .with_scan_budget(self.max_scanned_entries, |utxo_id| {
+                scalars::UtxoId::from(*utxo_id).encode_cursor()
+            });
+
+        futures::stream::iter(iter)
+    }
+
+    pub fn predicate_owned_coins_ids(
+        &self,
+        owner: &Address,
+        start_coin: Option<UtxoId>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<UtxoId>> + '_ {
+        let iter = self
+            .off_chain
+            .predicate_owned_coins_ids(owner, start_coin, direction)
+            .with_scan_budget(self.max_scanned_entries, |utxo_id| {
+                scalars::UtxoId::from(*utxo_id).encode_cursor()
+            });
+
+        futures::stream::iter(iter)
+    }
+
+    pub fn is_predicate_owned_coin(
+        &self,
+        owner: &Address,
+        utxo_id: &UtxoId,
+    ) -> StorageResult<bool> {
+        self.off_chain.is_predicate_owned_coin(owner, utxo_id)
+    }
+
+    pub fn spent_coin(&self, utxo_id: &UtxoId) -> StorageResult<Option<SpentCoin>> {
+        self.off_chain.spent_coin(utxo_id)
+    }
+
+    pub fn spent_coins_at_height(
+        &self,
+        height: BlockHeight,
+    ) -> StorageResult<Vec<UtxoId>> {
+        self.off_chain.spent_coins_at_height(height).collect()
+    }
+
+    /// Returns the number of coins of the `owner` with the `asset_id`. The databases
+    /// created before the counts were maintained don't count their older coins,
+    /// so the counts are served only once they are backfilled by the reindex.
+    pub fn owned_coin_count_by_asset(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> StorageResult<u64> {
+        self.ensure_indexation_ready(IndexationKind::OwnedCoinCounts)?;
+        if !self
+            .indexation_flags
+            .contains(&IndexationKind::OwnedCoinCounts)
+        {
+            return Err(anyhow::anyhow!(
+                "The owned coin counts are not indexed, reindex the `OwnedCoinCountByAsset` \
+                column to backfill them"
+            )
+            .into())
+        }
+        self.off_chain.owned_coin_count_by_asset(owner, asset_id)
+    }
+
     pub fn owned_message_ids(
         &self,
         owner: &Address,
         start_message_id: Option<Nonce>,
         direction: IterDirection,
     ) -> impl Stream<Item = StorageResult<Nonce>> + '_ {
-        futures::stream::iter(self.off_chain.owned_message_ids(
-            owner,
-            start_message_id,
-            direction,
-        ))
+        let iter = self
+            .off_chain
+            .owned_message_ids(owner, start_message_id, direction)
+            .with_scan_budget(self.max_scanned_entries, |nonce| {
+                scalars::Nonce::from(*nonce).encode_cursor()
+            });
+
+        futures::stream::iter(iter)
+    }
+
+    pub fn owned_message_states(
+        &self,
+        owner: &Address,
+        start_message_id: Option<Nonce>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<(Nonce, OwnedMessageState)>> + '_ {
+        let iter = self
+            .off_chain
+            .owned_message_states(owner, start_message_id, direction)
+            .with_scan_budget(self.max_scanned_entries, |(nonce, _)| {
+                scalars::Nonce::from(*nonce).encode_cursor()
+            });
+
+        futures::stream::iter(iter)
+    }
+
+    pub fn message_ids_by_sender(
+        &self,
+        sender: &Address,
+        start_message_id: Option<Nonce>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<Nonce>> + '_ {
+        let iter = self
+            .off_chain
+            .message_ids_by_sender(sender, start_message_id, direction)
+            .with_scan_budget(self.max_scanned_entries, |nonce| {
+                scalars::Nonce::from(*nonce).encode_cursor()
+            });
+
+        futures::stream::iter(iter)
     }
 
+    /// Returns the ids of the owner's transactions that pass the `filter`.
+    /// The skipped transactions count towards the scan budget, and the status
+    /// filter reads the status of each scanned transaction.
     pub fn owned_transactions_ids(
         &self,
         owner: Address,
         start: Option<TxPointer>,
         direction: IterDirection,
+        filter: OwnedTransactionsFilter,
     ) -> impl Stream<Item = StorageResult<(TxPointer, TxId)>> + '_ {
-        futures::stream::iter(
-            self.off_chain
-                .owned_transactions_ids(owner, start, direction),
-        )
+        let iter = self
+            .off_chain
+            .owned_transactions_ids(owner, start, direction)
+            .with_scan_budget(self.max_scanned_entries, |(tx_pointer, _)| {
+                scalars::TxPointer::from(*tx_pointer).encode_cursor()
+            })
+            .filter_map(move |result| {
+                let (tx_pointer, value) = match result {
+                    Ok(entry) => entry,
+                    Err(err) => return Some(Err(err)),
+                };
+                if !value.direction.matches(filter.direction) {
+                    return None
+                }
+                if let Some(status_filter) = filter.status {
+                    match self.off_chain.tx_status(&value.tx_id) {
+                        Ok(status) if status_filter.matches(&status) => {}
+                        Ok(_) | Err(StorageError::NotFound(_, _)) => return None,
+                        Err(err) => return Some(Err(err)),
+                    }
+                }
+                Some(Ok((tx_pointer, value.tx_id)))
+            });
+
+        futures::stream::iter(iter)
+    }
+
+    pub fn contract_transactions_ids(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<(TxPointer, TxId)>> + '_ {
+        let iter = self
+            .off_chain
+            .contract_transactions_ids(contract_id, start, direction)
+            .with_scan_budget(self.max_scanned_entries, |(tx_pointer, _)| {
+                scalars::TxPointer::from(*tx_pointer).encode_cursor()
+            });
+
+        futures::stream::iter(iter)
+    }
+
+    /// Returns the contracts deployed within the `heights`, starting from the `start`.
+    pub fn contracts_by_block(
+        &self,
+        start: Option<TxPointer>,
+        heights: RangeInclusive<BlockHeight>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<(TxPointer, ContractDeployment)>> + '_ {
+        let (start_height, end_height) = heights.into_inner();
+        let start = match direction {
+            IterDirection::Forward => {
+                let lower = ContractsByBlockKey {
+                    block_height: start_height,
+                    tx_idx: 0,
+                };
+                start.map(ContractsByBlockKey::from).max(Some(lower))
+            }
+            IterDirection::Reverse => {
+                let upper = ContractsByBlockKey {
+                    block_height: end_height,
+                    tx_idx: u16::MAX,
+                };
+                Some(start.map_or(upper, |start| upper.min(start.into())))
+            }
+        };
+        let iter = self
+            .off_chain
+            .contracts_by_block(start.map(Into::into), direction)
+            .take_while(move |result| match result {
+                Ok((tx_pointer, _)) => {
+                    let height = tx_pointer.block_height();
+                    start_height <= height && height <= end_height
+                }
+                Err(_) => true,
+            })
+            .with_scan_budget(self.max_scanned_entries, |(tx_pointer, _)| {
+                scalars::TxPointer::from(*tx_pointer).encode_cursor()
+            });
+
+        futures::stream::iter(iter)
+    }
+
+    pub fn coins_to_spend_index(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> CoinsToSpendIndexIter {
+        let CoinsToSpendIndexIter {
+            big_coins_iter,
+            dust_coins_iter,
+        } = self.off_chain.coins_to_spend_index(owner, asset_id);
+
+        CoinsToSpendIndexIter {
+            big_coins_iter: big_coins_iter
+                .with_scan_budget(self.max_scanned_entries, ToString::to_string)
+                .into_boxed(),
+            dust_coins_iter: dust_coins_iter
+                .with_scan_budget(self.max_scanned_entries, ToString::to_string)
+                .into_boxed(),
+        }
+    }
+
+    /// Returns `true` if the `owner` has any spendable coins or messages
+    /// with the `asset_id`. Only the first entry of the coins to spend index
+    /// is read, so the check doesn't depend on the number of the coins.
+    pub fn has_spendable_coins(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> StorageResult<bool> {
+        self.ensure_indexation_ready(IndexationKind::CoinsToSpend)?;
+        if !self
+            .indexation_flags
+            .contains(&IndexationKind::CoinsToSpend)
+        {
+            return Err(anyhow::anyhow!(
+                "The check of the spendable coins requires the coins to spend indexation"
+            )
+            .into())
+        }
+        self.off_chain.has_coins_to_spend(owner, asset_id)
     }
 
     pub fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt> {
-        self.off_chain.contract_salt(contract_id)
+        self.contracts_info_cache
+            .salt(contract_id, || self.off_chain.contract_salt(contract_id))
     }
 
     pub fn relayed_tx_status(
@@ -485,9 +1198,54 @@ impl ReadView {
         self.off_chain.relayed_tx_status(id)
     }
 
+    pub fn u64_statistic(&self, name: &str) -> StorageResult<Option<u64>> {
+        self.off_chain.u64_statistic(name)
+    }
+
+    pub fn offchain_state_hash(&self) -> StorageResult<Bytes32> {
+        self.off_chain.offchain_state_hash()
+    }
+
     pub fn message_is_spent(&self, nonce: &Nonce) -> StorageResult<bool> {
         self.off_chain.message_is_spent(nonce)
     }
+
+    pub fn spent_message_count(&self) -> StorageResult<u64> {
+        self.off_chain.spent_message_count()
+    }
+
+    pub fn block_fees(&self, height: &BlockHeight) -> StorageResult<CollectedFees> {
+        self.off_chain.block_fees(height)
+    }
+
+    pub fn total_fees(&self) -> StorageResult<CollectedFees> {
+        self.off_chain.total_fees()
+    }
+
+    pub fn top_addresses_by_tx_count(
+        &self,
+        limit: usize,
+    ) -> StorageResult<Vec<AddressTxCount>> {
+        self.off_chain.top_addresses_by_tx_count(limit)
+    }
+
+    pub fn owner_activity(
+        &self,
+        owner: &Address,
+    ) -> StorageResult<Option<OwnerActivity>> {
+        self.off_chain.owner_activity(owner)
+    }
+
+    pub fn owner_last_activity(
+        &self,
+        owner: &Address,
+    ) -> StorageResult<Option<BlockHeight>> {
+        self.off_chain.owner_last_activity(owner)
+    }
+
+    pub fn known_owner(&self, owner: &Address) -> StorageResult<Option<KnownOwner>> {
+        self.off_chain.known_owner(owner)
+    }
 }
 
 #[derive(Clone)]
@@ -545,4 +1303,8 @@ fn test_indexation_flags() {
     assert!(indexation.contains(&IndexationKind::Balances));
     assert!(indexation.contains(&IndexationKind::CoinsToSpend));
     assert!(indexation.contains(&IndexationKind::AssetMetadata));
+    assert!(!indexation.contains(&IndexationKind::ContractBalances));
+
+    indexation.insert(IndexationKind::ContractBalances);
+    assert!(indexation.contains(&IndexationKind::ContractBalances));
 }
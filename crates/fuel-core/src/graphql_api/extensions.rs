@@ -9,6 +9,8 @@ use crate::fuel_core_graphql_api::extensions::{
 use async_graphql::Response;
 
 pub(crate) mod chain_state_info;
+pub(crate) mod coins_to_spend_fallback;
+pub(crate) mod message_proof_cache;
 pub(crate) mod metrics;
 pub(crate) mod required_fuel_block_height;
 pub(crate) mod validation;
@@ -0,0 +1,85 @@
+use std::sync::{
+    atomic::{
+        AtomicBool,
+        Ordering,
+    },
+    Arc,
+};
+
+use async_graphql::{
+    extensions::{
+        Extension,
+        ExtensionContext,
+        ExtensionFactory,
+        NextExecute,
+        NextPrepareRequest,
+    },
+    Request,
+    Response,
+    ServerResult,
+    Value,
+};
+
+pub(crate) const USED_FALLBACK: &str = "usedFallback";
+
+/// Records whether the coins to spend of the request were selected
+/// without the coins to spend index.
+#[derive(Clone, Default)]
+pub(crate) struct CoinsToSpendFallbackStatus(Arc<AtomicBool>);
+
+impl CoinsToSpendFallbackStatus {
+    pub fn record(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    fn used(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// The extension adds the `usedFallback` field to the response of the requests
+/// that selected the coins to spend while the coins to spend index was rebuilt.
+#[derive(Default)]
+pub(crate) struct CoinsToSpendFallbackExtension {
+    status: CoinsToSpendFallbackStatus,
+}
+
+impl CoinsToSpendFallbackExtension {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ExtensionFactory for CoinsToSpendFallbackExtension {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(CoinsToSpendFallbackExtension::new())
+    }
+}
+
+#[async_trait::async_trait]
+impl Extension for CoinsToSpendFallbackExtension {
+    async fn prepare_request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        request: Request,
+        next: NextPrepareRequest<'_>,
+    ) -> ServerResult<Request> {
+        let request = request.data(self.status.clone());
+        next.run(ctx, request).await
+    }
+
+    async fn execute(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        operation_name: Option<&str>,
+        next: NextExecute<'_>,
+    ) -> Response {
+        let mut response = next.run(ctx, operation_name).await;
+        if self.status.used() {
+            response
+                .extensions
+                .insert(USED_FALLBACK.to_string(), Value::Boolean(true));
+        }
+        response
+    }
+}
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use async_graphql::{
+    extensions::{
+        Extension,
+        ExtensionContext,
+        ExtensionFactory,
+        NextExecute,
+        NextPrepareRequest,
+    },
+    Request,
+    Response,
+    ServerResult,
+    Value,
+};
+use parking_lot::Mutex;
+
+pub(crate) const MESSAGE_PROOF_CACHED: &str = "message_proof_cached";
+
+/// Records whether the message proofs of the request were served from the cache.
+#[derive(Clone, Default)]
+pub(crate) struct MessageProofCacheStatus(Arc<Mutex<Option<bool>>>);
+
+impl MessageProofCacheStatus {
+    /// Records the outcome of one proof. The request is reported as cached
+    /// only if all of its proofs came from the cache.
+    pub fn record(&self, cached: bool) {
+        let mut status = self.0.lock();
+        *status = Some(status.unwrap_or(true) && cached);
+    }
+
+    fn cached(&self) -> Option<bool> {
+        *self.0.lock()
+    }
+}
+
+/// The extension adds the `message_proof_cached` field to the response
+/// of the requests that resolved message proofs.
+#[derive(Default)]
+pub(crate) struct MessageProofCacheExtension {
+    status: MessageProofCacheStatus,
+}
+
+impl MessageProofCacheExtension {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ExtensionFactory for MessageProofCacheExtension {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(MessageProofCacheExtension::new())
+    }
+}
+
+#[async_trait::async_trait]
+impl Extension for MessageProofCacheExtension {
+    async fn prepare_request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        request: Request,
+        next: NextPrepareRequest<'_>,
+    ) -> ServerResult<Request> {
+        let request = request.data(self.status.clone());
+        next.run(ctx, request).await
+    }
+
+    async fn execute(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        operation_name: Option<&str>,
+        next: NextExecute<'_>,
+    ) -> Response {
+        let mut response = next.run(ctx, operation_name).await;
+        if let Some(cached) = self.status.cached() {
+            response
+                .extensions
+                .insert(MESSAGE_PROOF_CACHED.to_string(), Value::Boolean(cached));
+        }
+        response
+    }
+}
@@ -1,6 +1,8 @@
 use crate::fuel_core_graphql_api::{
     api_service::ReadDatabase,
     block_height_subscription,
+    snapshot_views::SnapshotViews,
+    tx_status_loader::TransactionStatusLoader,
 };
 use async_graphql::{
     extensions::{
@@ -21,6 +23,9 @@ use std::sync::Arc;
 use tokio::time::Duration;
 
 const REQUIRED_FUEL_BLOCK_HEIGHT: &str = "required_fuel_block_height";
+/// The token of the view pinned by the `snapshotView` query. The request passing
+/// it reads from the pinned view instead of the latest one.
+const SNAPSHOT_TOKEN: &str = "snapshot_token";
 pub(crate) const FUEL_BLOCK_HEIGHT_PRECONDITION_FAILED: &str =
     "fuel_block_height_precondition_failed";
 
@@ -126,6 +131,13 @@ fn get_required_height(request: &Request) -> Option<BlockHeight> {
     None
 }
 
+fn get_snapshot_token(request: &Request) -> Option<String> {
+    if let Some(ConstValue::String(token)) = request.extensions.get(SNAPSHOT_TOKEN) {
+        return Some(token.clone())
+    }
+    None
+}
+
 #[async_trait::async_trait]
 impl Extension for RequiredFuelBlockHeightInner {
     async fn prepare_request(
@@ -135,6 +147,7 @@ impl Extension for RequiredFuelBlockHeightInner {
         next: NextPrepareRequest<'_>,
     ) -> ServerResult<Request> {
         let required_height = get_required_height(&request);
+        let snapshot_token = get_snapshot_token(&request);
 
         let request = next.run(ctx, request).await?;
 
@@ -183,6 +196,18 @@ impl Extension for RequiredFuelBlockHeightInner {
             }
         };
 
+        if let Some(token) = snapshot_token {
+            let snapshot_views: &SnapshotViews = ctx.data_unchecked();
+            let Some((view, _)) = snapshot_views.get(&token) else {
+                return Err(ServerError::new(
+                    format!("The snapshot view `{token}` is unknown or expired"),
+                    None,
+                ));
+            };
+            let request = request.data(view).data(TransactionStatusLoader::default());
+            return Ok(request)
+        }
+
         let database: &ReadDatabase = ctx.data_unchecked();
         let view = match database.view() {
             Ok(view) => view,
@@ -199,7 +224,9 @@ impl Extension for RequiredFuelBlockHeightInner {
             }
         };
 
-        let request = request.data(view);
+        // The loader is created per request, so its batches are read
+        // from the view of the request.
+        let request = request.data(view).data(TransactionStatusLoader::default());
 
         Ok(request)
     }
@@ -1,6 +1,9 @@
 pub(crate) mod asset_metadata;
 pub(crate) mod balances;
 pub(crate) mod coins_to_spend;
+pub(crate) mod contract_balances;
+pub mod derived_ownership;
 pub(crate) mod error;
+pub(crate) mod owner_activity;
 #[cfg(test)]
 pub(crate) mod test_utils;
@@ -1,14 +1,30 @@
-use fuel_core_types::fuel_tx::{
-    ContractIdExt,
-    Receipt,
+use fuel_core_types::{
+    fuel_tx::{
+        AssetId,
+        ContractIdExt,
+        Receipt,
+    },
+    fuel_types::BlockHeight,
+    services::executor::{
+        Event,
+        TransactionExecutionResult,
+        TransactionExecutionStatus,
+    },
+    tai64::Tai64,
 };
 
 use fuel_core_storage::StorageAsMut;
+use std::collections::{
+    BTreeMap,
+    BTreeSet,
+};
 
 use crate::graphql_api::{
     ports::worker::OffChainDatabaseTransaction,
     storage::assets::{
         AssetDetails,
+        AssetFirstSeen,
+        AssetFirstSeenInfo,
         AssetsInfo,
     },
 };
@@ -67,6 +83,156 @@ where
     Ok(())
 }
 
+/// Records the net change of the supply of every asset minted or burned
+/// by the successful transactions of the block at the `block_height`.
+/// Must be called before the receipts of the block are applied to the [`AssetsInfo`].
+pub(crate) fn update_supply_history<'a, I, T>(
+    statuses: I,
+    block_height: BlockHeight,
+    block_st_transaction: &mut T,
+    enabled: bool,
+) -> Result<(), IndexationError>
+where
+    I: Iterator<Item = &'a TransactionExecutionStatus>,
+    T: OffChainDatabaseTransaction,
+{
+    if !enabled {
+        return Ok(());
+    }
+
+    let mut deltas = BTreeMap::<AssetId, i128>::new();
+    for status in statuses {
+        let TransactionExecutionResult::Success { receipts, .. } = &status.result else {
+            continue
+        };
+        for receipt in receipts.iter() {
+            let (asset_id, delta) = match receipt {
+                Receipt::Mint {
+                    sub_id,
+                    contract_id,
+                    val,
+                    ..
+                } => (contract_id.asset_id(sub_id), i128::from(*val)),
+                Receipt::Burn {
+                    sub_id,
+                    contract_id,
+                    val,
+                    ..
+                } => (
+                    contract_id.asset_id(sub_id),
+                    i128::from(*val).saturating_neg(),
+                ),
+                Receipt::Call { .. }
+                | Receipt::Return { .. }
+                | Receipt::ReturnData { .. }
+                | Receipt::Panic { .. }
+                | Receipt::Revert { .. }
+                | Receipt::Log { .. }
+                | Receipt::LogData { .. }
+                | Receipt::Transfer { .. }
+                | Receipt::TransferOut { .. }
+                | Receipt::ScriptResult { .. }
+                | Receipt::MessageOut { .. } => continue,
+            };
+            let total = deltas.entry(asset_id).or_default();
+            *total = total.saturating_add(delta);
+        }
+    }
+
+    for (asset_id, delta) in deltas {
+        let supply_before = block_st_transaction
+            .storage::<AssetsInfo>()
+            .get(&asset_id)?
+            .map(|info| info.total_supply)
+            .unwrap_or_default();
+        block_st_transaction.record_asset_supply_change(
+            &asset_id,
+            block_height,
+            delta,
+            supply_before,
+        )?;
+    }
+    Ok(())
+}
+
+/// Records the block for every asset minted by the successful transactions
+/// of the block or received as a coin in it, unless the asset was seen before.
+/// The entry is never overwritten, so indexing the block again keeps the original one.
+pub(crate) fn update_first_seen<'a, I, E, T>(
+    statuses: I,
+    events: E,
+    block_height: BlockHeight,
+    block_time: Tai64,
+    block_st_transaction: &mut T,
+    enabled: bool,
+) -> Result<(), IndexationError>
+where
+    I: Iterator<Item = &'a TransactionExecutionStatus>,
+    E: Iterator<Item = &'a Event>,
+    T: OffChainDatabaseTransaction,
+{
+    if !enabled {
+        return Ok(());
+    }
+
+    let mut assets = BTreeSet::new();
+    for status in statuses {
+        let TransactionExecutionResult::Success { receipts, .. } = &status.result else {
+            continue
+        };
+        for receipt in receipts.iter() {
+            match receipt {
+                Receipt::Mint {
+                    sub_id,
+                    contract_id,
+                    ..
+                } => {
+                    assets.insert(contract_id.asset_id(sub_id));
+                }
+                Receipt::Call { .. }
+                | Receipt::Return { .. }
+                | Receipt::ReturnData { .. }
+                | Receipt::Panic { .. }
+                | Receipt::Revert { .. }
+                | Receipt::Log { .. }
+                | Receipt::LogData { .. }
+                | Receipt::Transfer { .. }
+                | Receipt::TransferOut { .. }
+                | Receipt::ScriptResult { .. }
+                | Receipt::MessageOut { .. }
+                | Receipt::Burn { .. } => {}
+            }
+        }
+    }
+    for event in events {
+        match event {
+            Event::CoinCreated(coin) => {
+                assets.insert(coin.asset_id);
+            }
+            Event::CoinConsumed(_)
+            | Event::MessageImported(_)
+            | Event::MessageConsumed(_)
+            | Event::ForcedTransactionFailed { .. } => {}
+        }
+    }
+
+    let info = AssetFirstSeenInfo {
+        block_height,
+        block_time,
+    };
+    for asset_id in assets {
+        let seen = block_st_transaction
+            .storage::<AssetFirstSeen>()
+            .contains_key(&asset_id)?;
+        if !seen {
+            block_st_transaction
+                .storage::<AssetFirstSeen>()
+                .insert(&asset_id, &info)?;
+        }
+    }
+    Ok(())
+}
+
 fn current_supply<T>(
     block_st_transaction: &mut T,
     receipt: &Receipt,
@@ -104,16 +270,28 @@ where
 }
 
 #[cfg(test)]
+#[allow(non_snake_case)]
 mod tests {
     use fuel_core_storage::{
         transactional::WriteTransaction,
         StorageAsMut,
     };
-    use fuel_core_types::fuel_tx::{
-        Bytes32,
-        ContractId,
-        ContractIdExt,
-        Receipt,
+    use fuel_core_types::{
+        entities::coins::coin::Coin,
+        fuel_tx::{
+            AssetId,
+            Bytes32,
+            ContractId,
+            ContractIdExt,
+            Receipt,
+        },
+        fuel_types::BlockHeight,
+        services::executor::{
+            Event,
+            TransactionExecutionResult,
+            TransactionExecutionStatus,
+        },
+        tai64::Tai64,
     };
 
     use crate::{
@@ -122,10 +300,21 @@ mod tests {
             Database,
         },
         graphql_api::{
-            indexation::asset_metadata::update,
+            indexation::asset_metadata::{
+                update,
+                update_first_seen,
+                update_supply_history,
+            },
+            ports::worker::OffChainDatabaseTransaction,
             storage::assets::{
                 AssetDetails,
+                AssetFirstSeen,
+                AssetFirstSeenInfo,
+                AssetSupplyCheckpoints,
+                AssetSupplyHistory,
+                AssetSupplyHistoryKey,
                 AssetsInfo,
+                ASSET_SUPPLY_CHECKPOINT_INTERVAL,
             },
         },
         state::rocks_db::DatabaseConfig,
@@ -210,4 +399,267 @@ mod tests {
 
         assert!(metadata.is_none());
     }
+
+    const SUB_ID: Bytes32 = Bytes32::zeroed();
+    const CONTRACT_ID: ContractId = ContractId::zeroed();
+
+    fn success(receipts: Vec<Receipt>) -> TransactionExecutionStatus {
+        TransactionExecutionStatus {
+            id: Default::default(),
+            result: TransactionExecutionResult::Success {
+                result: None,
+                receipts,
+                total_gas: 0,
+                total_fee: 0,
+            },
+        }
+    }
+
+    /// Indexes the block with the transactions that produced the `receipts`.
+    fn index_block<T>(tx: &mut T, height: u32, receipts: Vec<Vec<Receipt>>)
+    where
+        T: OffChainDatabaseTransaction,
+    {
+        let statuses = receipts.into_iter().map(success).collect::<Vec<_>>();
+        update_supply_history(statuses.iter(), height.into(), tx, true)
+            .expect("should record the supply history");
+        for status in statuses.iter() {
+            update(status.result.receipts(), tx, true).expect("should process receipts");
+        }
+    }
+
+    fn history<T>(tx: &mut T, asset_id: &AssetId, heights: &[u32]) -> Vec<Option<i128>>
+    where
+        T: OffChainDatabaseTransaction,
+    {
+        heights
+            .iter()
+            .map(|height| {
+                tx.storage::<AssetSupplyHistory>()
+                    .get(&AssetSupplyHistoryKey::new(asset_id, (*height).into()))
+                    .unwrap()
+                    .map(|delta| *delta)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn update_supply_history__coalesces_mints_and_burns_of_the_block() {
+        let mut db = Database::<OffChain>::in_memory();
+        let mut tx = db.write_transaction();
+        let asset_id = CONTRACT_ID.asset_id(&SUB_ID);
+
+        // given
+        let receipts = vec![
+            vec![
+                Receipt::mint(SUB_ID, CONTRACT_ID, 3, 0, 0),
+                Receipt::burn(SUB_ID, CONTRACT_ID, 1, 0, 0),
+            ],
+            vec![Receipt::mint(SUB_ID, CONTRACT_ID, 2, 0, 0)],
+        ];
+
+        // when
+        index_block(&mut tx, 1, receipts);
+
+        // then
+        assert_eq!(history(&mut tx, &asset_id, &[1]), vec![Some(4)]);
+        let checkpoint = tx
+            .storage::<AssetSupplyCheckpoints>()
+            .get(&AssetSupplyHistoryKey::new(&asset_id, BlockHeight::from(0)))
+            .unwrap()
+            .map(|supply| *supply);
+        assert_eq!(checkpoint, Some(0));
+    }
+
+    #[test]
+    fn update_supply_history__records_mint_then_burn_to_zero() {
+        let mut db = Database::<OffChain>::in_memory();
+        let mut tx = db.write_transaction();
+        let asset_id = CONTRACT_ID.asset_id(&SUB_ID);
+
+        // given
+        index_block(
+            &mut tx,
+            1,
+            vec![vec![Receipt::mint(SUB_ID, CONTRACT_ID, 5, 0, 0)]],
+        );
+
+        // when
+        index_block(
+            &mut tx,
+            2,
+            vec![vec![Receipt::burn(SUB_ID, CONTRACT_ID, 5, 0, 0)]],
+        );
+        index_block(
+            &mut tx,
+            3,
+            vec![vec![
+                Receipt::mint(SUB_ID, CONTRACT_ID, 7, 0, 0),
+                Receipt::burn(SUB_ID, CONTRACT_ID, 7, 0, 0),
+            ]],
+        );
+
+        // then
+        assert_eq!(
+            history(&mut tx, &asset_id, &[1, 2, 3]),
+            vec![Some(5), Some(-5), Some(0)]
+        );
+        let supply = tx
+            .storage::<AssetsInfo>()
+            .get(&asset_id)
+            .unwrap()
+            .unwrap()
+            .total_supply;
+        assert_eq!(supply, 0);
+    }
+
+    #[test]
+    fn update_supply_history__skips_failed_transactions() {
+        let mut db = Database::<OffChain>::in_memory();
+        let mut tx = db.write_transaction();
+        let asset_id = CONTRACT_ID.asset_id(&SUB_ID);
+
+        // given
+        let failed = TransactionExecutionStatus {
+            id: Default::default(),
+            result: TransactionExecutionResult::Failed {
+                result: None,
+                receipts: vec![Receipt::mint(SUB_ID, CONTRACT_ID, 5, 0, 0)],
+                total_gas: 0,
+                total_fee: 0,
+            },
+        };
+
+        // when
+        update_supply_history([failed].iter(), 1.into(), &mut tx, true)
+            .expect("should record the supply history");
+
+        // then
+        assert_eq!(history(&mut tx, &asset_id, &[1]), vec![None]);
+    }
+
+    #[test]
+    fn prune_asset_supply_history_before__keeps_the_interval_of_the_height() {
+        let mut db = Database::<OffChain>::in_memory();
+        let mut tx = db.write_transaction();
+        let asset_id = CONTRACT_ID.asset_id(&SUB_ID);
+        let interval = ASSET_SUPPLY_CHECKPOINT_INTERVAL;
+        let next_interval = interval.saturating_add(1);
+
+        // given
+        for height in [1, 2, next_interval] {
+            index_block(
+                &mut tx,
+                height,
+                vec![vec![Receipt::mint(SUB_ID, CONTRACT_ID, 1, 0, 0)]],
+            );
+        }
+
+        // when
+        let pruned = tx
+            .prune_asset_supply_history_before(next_interval.saturating_add(1).into())
+            .unwrap();
+
+        // then
+        assert_eq!(pruned, 2);
+        assert_eq!(
+            history(&mut tx, &asset_id, &[1, 2, next_interval]),
+            vec![None, None, Some(1)]
+        );
+        let checkpoints = [0, interval].map(|height| {
+            tx.storage::<AssetSupplyCheckpoints>()
+                .get(&AssetSupplyHistoryKey::new(&asset_id, height.into()))
+                .unwrap()
+                .map(|supply| *supply)
+        });
+        assert_eq!(checkpoints, [None, Some(2)]);
+    }
+
+    fn first_seen<T>(tx: &mut T, asset_id: &AssetId) -> Option<AssetFirstSeenInfo>
+    where
+        T: OffChainDatabaseTransaction,
+    {
+        tx.storage::<AssetFirstSeen>()
+            .get(asset_id)
+            .unwrap()
+            .map(|info| *info)
+    }
+
+    #[test]
+    fn update_first_seen__records_minted_and_received_assets() {
+        let mut db = Database::<OffChain>::in_memory();
+        let mut tx = db.write_transaction();
+        let minted_asset_id = CONTRACT_ID.asset_id(&SUB_ID);
+        let received_asset_id = AssetId::from([3u8; 32]);
+
+        // given
+        let statuses = [success(vec![Receipt::mint(SUB_ID, CONTRACT_ID, 5, 0, 0)])];
+        let events = [Event::CoinCreated(Coin {
+            utxo_id: Default::default(),
+            owner: Default::default(),
+            amount: 1,
+            asset_id: received_asset_id,
+            tx_pointer: Default::default(),
+        })];
+
+        // when
+        update_first_seen(
+            statuses.iter(),
+            events.iter(),
+            1.into(),
+            Tai64(10),
+            &mut tx,
+            true,
+        )
+        .expect("should record the first seen assets");
+
+        // then
+        let expected = Some(AssetFirstSeenInfo {
+            block_height: 1.into(),
+            block_time: Tai64(10),
+        });
+        assert_eq!(first_seen(&mut tx, &minted_asset_id), expected);
+        assert_eq!(first_seen(&mut tx, &received_asset_id), expected);
+    }
+
+    #[test]
+    fn update_first_seen__does_not_overwrite_the_original_block() {
+        let mut db = Database::<OffChain>::in_memory();
+        let mut tx = db.write_transaction();
+        let asset_id = CONTRACT_ID.asset_id(&SUB_ID);
+        let statuses = [success(vec![Receipt::mint(SUB_ID, CONTRACT_ID, 5, 0, 0)])];
+
+        // given
+        update_first_seen(
+            statuses.iter(),
+            [].iter(),
+            2.into(),
+            Tai64(20),
+            &mut tx,
+            true,
+        )
+        .expect("should record the first seen assets");
+
+        // when
+        for (height, time) in [(3, 30), (1, 10)] {
+            update_first_seen(
+                statuses.iter(),
+                [].iter(),
+                height.into(),
+                Tai64(time),
+                &mut tx,
+                true,
+            )
+            .expect("should record the first seen assets");
+        }
+
+        // then
+        assert_eq!(
+            first_seen(&mut tx, &asset_id),
+            Some(AssetFirstSeenInfo {
+                block_height: 2.into(),
+                block_time: Tai64(20),
+            })
+        );
+    }
 }
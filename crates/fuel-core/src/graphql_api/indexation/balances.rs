@@ -1,19 +1,31 @@
-use fuel_core_storage::StorageAsMut;
+use std::collections::BTreeSet;
+
+use fuel_core_storage::{
+    transactional::Changes,
+    StorageAsMut,
+};
 use fuel_core_types::{
     entities::{
         coins::coin::Coin,
         Message,
     },
+    fuel_tx::{
+        Address,
+        AssetId,
+    },
     services::executor::Event,
 };
 
 use crate::graphql_api::{
     ports::worker::OffChainDatabaseTransaction,
-    storage::balances::{
-        CoinBalances,
-        CoinBalancesKey,
-        MessageBalance,
-        MessageBalances,
+    storage::{
+        balances::{
+            CoinBalances,
+            CoinBalancesKey,
+            MessageBalance,
+            MessageBalances,
+        },
+        Column,
     },
 };
 
@@ -163,6 +175,31 @@ where
     }
 }
 
+/// Returns the owner and asset pairs with the balances modified by the `changes`.
+/// The messages only hold the base asset, so the modified message balances are
+/// reported for the `base_asset_id`. Each pair is reported once, no matter how
+/// many times its balance was modified.
+pub(crate) fn updated_balances(
+    changes: &Changes,
+    base_asset_id: &AssetId,
+) -> BTreeSet<(Address, AssetId)> {
+    let coin_balances = changes
+        .get(&Column::CoinBalances.as_u32())
+        .into_iter()
+        .flat_map(|balances| balances.keys())
+        .filter_map(|key| <[u8; CoinBalancesKey::LEN]>::try_from(key.as_slice()).ok())
+        .map(CoinBalancesKey::from_array)
+        .map(|key| (*key.address(), *key.asset_id()));
+    let message_balances = changes
+        .get(&Column::MessageBalances.as_u32())
+        .into_iter()
+        .flat_map(|balances| balances.keys())
+        .filter_map(|key| Address::try_from(key.as_slice()).ok())
+        .map(|owner| (owner, *base_asset_id));
+
+    coin_balances.chain(message_balances).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use fuel_core_storage::{
@@ -184,7 +221,10 @@ mod tests {
         },
         graphql_api::{
             indexation::{
-                balances::update,
+                balances::{
+                    update,
+                    updated_balances,
+                },
                 error::IndexationError,
                 test_utils::{
                     make_coin,
@@ -237,6 +277,37 @@ mod tests {
         assert_eq!(*balance, expected_balance);
     }
 
+    #[test]
+    #[allow(non_snake_case)]
+    fn updated_balances__reports_each_modified_pair_once() {
+        let mut db = Database::<OffChain>::in_memory();
+        let mut tx = db.write_transaction();
+        let base_asset_id = AssetId::from([0; 32]);
+        let owner_1 = Address::from([1; 32]);
+        let owner_2 = Address::from([2; 32]);
+        let asset_id = AssetId::from([11; 32]);
+
+        // given
+        let events = [
+            Event::CoinCreated(make_coin(&owner_1, &asset_id, 100)),
+            Event::CoinCreated(make_coin(&owner_1, &asset_id, 200)),
+            Event::CoinConsumed(make_coin(&owner_1, &asset_id, 100)),
+            Event::MessageImported(make_nonretryable_message(&owner_2, 300)),
+        ];
+        for event in events.iter() {
+            update(event, &mut tx, true).expect("should process balance");
+        }
+
+        // when
+        let updated = updated_balances(tx.changes(), &base_asset_id);
+
+        // then
+        let expected = [(owner_1, asset_id), (owner_2, base_asset_id)]
+            .into_iter()
+            .collect();
+        assert_eq!(updated, expected);
+    }
+
     #[test]
     fn balances_indexation_enabled_flag_is_respected() {
         use tempfile::TempDir;
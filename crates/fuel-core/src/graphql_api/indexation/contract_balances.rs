@@ -0,0 +1,360 @@
+use fuel_core_storage::{
+    ContractsAssetKey,
+    StorageAsMut,
+};
+use fuel_core_types::{
+    blockchain::block::Block,
+    fuel_tx::{
+        AssetId,
+        ContractId,
+        ContractIdExt,
+        Receipt,
+        Transaction,
+    },
+    services::executor::{
+        TransactionExecutionResult,
+        TransactionExecutionStatus,
+    },
+};
+use std::collections::BTreeMap;
+
+use crate::graphql_api::{
+    ports::worker::OffChainDatabaseTransaction,
+    storage::contracts::ContractBalances,
+};
+
+use super::error::IndexationError;
+
+/// Applies the changes of the balances of contracts made by the block.
+///
+/// The executor doesn't emit events for the balances of contracts, so the changes
+/// are derived from the state transitions of the block: the coinbase of the `Mint`
+/// transaction and the receipts of the successful transactions. The reverted
+/// transactions don't change the balances of contracts.
+pub(crate) fn update<'a, I, T>(
+    block: &Block,
+    statuses: I,
+    base_asset_id: &AssetId,
+    block_st_transaction: &mut T,
+    enabled: bool,
+) -> Result<(), IndexationError>
+where
+    I: Iterator<Item = &'a TransactionExecutionStatus>,
+    T: OffChainDatabaseTransaction,
+{
+    if !enabled {
+        return Ok(());
+    }
+
+    let mut deltas = BalanceDeltas::default();
+    for tx in block.transactions() {
+        if let Transaction::Mint(mint) = tx {
+            deltas.increase(
+                mint.input_contract().contract_id,
+                *mint.mint_asset_id(),
+                *mint.mint_amount(),
+            );
+        }
+    }
+    for status in statuses {
+        let TransactionExecutionResult::Success { receipts, .. } = &status.result else {
+            continue
+        };
+        deltas.apply_receipts(receipts, base_asset_id);
+    }
+
+    // All new balances are computed before writing any of them,
+    // so a failure doesn't leave the index partially updated.
+    let mut balances = Vec::with_capacity(deltas.0.len());
+    for ((contract_id, asset_id), delta) in deltas.0 {
+        let key = ContractsAssetKey::new(&contract_id, &asset_id);
+        let current_amount = block_st_transaction
+            .storage::<ContractBalances>()
+            .get(&key)?
+            .map(|amount| amount.into_owned())
+            .unwrap_or_default();
+        let new_amount = i128::from(current_amount)
+            .checked_add(delta)
+            .and_then(|amount| u64::try_from(amount).ok())
+            .ok_or(IndexationError::ContractBalanceOutOfRange {
+                contract_id,
+                asset_id,
+                current_amount,
+                delta,
+            })?;
+        balances.push((key, new_amount));
+    }
+
+    for (key, amount) in balances {
+        block_st_transaction
+            .storage::<ContractBalances>()
+            .insert(&key, &amount)?;
+    }
+    Ok(())
+}
+
+/// The net changes of the balances of contracts per asset.
+#[derive(Default)]
+struct BalanceDeltas(BTreeMap<(ContractId, AssetId), i128>);
+
+impl BalanceDeltas {
+    fn increase(&mut self, contract_id: ContractId, asset_id: AssetId, amount: u64) {
+        self.add(contract_id, asset_id, i128::from(amount));
+    }
+
+    fn decrease(&mut self, contract_id: ContractId, asset_id: AssetId, amount: u64) {
+        self.add(contract_id, asset_id, i128::from(amount).saturating_neg());
+    }
+
+    fn add(&mut self, contract_id: ContractId, asset_id: AssetId, delta: i128) {
+        if delta == 0 {
+            return;
+        }
+        let total = self.0.entry((contract_id, asset_id)).or_default();
+        *total = total.saturating_add(delta);
+    }
+
+    /// Applies the receipts of one successful transaction.
+    ///
+    /// The `MessageOut` receipt doesn't say whether the message was sent by
+    /// a contract, so the receipts are replayed with the stack of the called
+    /// contracts to know the context of each receipt.
+    fn apply_receipts(&mut self, receipts: &[Receipt], base_asset_id: &AssetId) {
+        let mut frames = Vec::<ContractId>::new();
+        for receipt in receipts {
+            let context = frames.last().copied();
+            match receipt {
+                Receipt::Call {
+                    to,
+                    amount,
+                    asset_id,
+                    ..
+                } => {
+                    if let Some(caller) = context {
+                        self.decrease(caller, *asset_id, *amount);
+                    }
+                    self.increase(*to, *asset_id, *amount);
+                    frames.push(*to);
+                }
+                Receipt::Return { .. } | Receipt::ReturnData { .. } => {
+                    frames.pop();
+                }
+                Receipt::Transfer {
+                    to,
+                    amount,
+                    asset_id,
+                    ..
+                } => {
+                    if let Some(sender) = context {
+                        self.decrease(sender, *asset_id, *amount);
+                    }
+                    self.increase(*to, *asset_id, *amount);
+                }
+                Receipt::TransferOut {
+                    amount, asset_id, ..
+                } => {
+                    if let Some(sender) = context {
+                        self.decrease(sender, *asset_id, *amount);
+                    }
+                }
+                Receipt::MessageOut { amount, .. } => {
+                    if let Some(sender) = context {
+                        self.decrease(sender, *base_asset_id, *amount);
+                    }
+                }
+                Receipt::Mint {
+                    sub_id,
+                    contract_id,
+                    val,
+                    ..
+                } => {
+                    self.increase(*contract_id, contract_id.asset_id(sub_id), *val);
+                }
+                Receipt::Burn {
+                    sub_id,
+                    contract_id,
+                    val,
+                    ..
+                } => {
+                    self.decrease(*contract_id, contract_id.asset_id(sub_id), *val);
+                }
+                Receipt::Panic { .. }
+                | Receipt::Revert { .. }
+                | Receipt::Log { .. }
+                | Receipt::LogData { .. }
+                | Receipt::ScriptResult { .. } => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::database::{
+        database_description::off_chain::OffChain,
+        Database,
+    };
+    use fuel_core_storage::transactional::WriteTransaction;
+    use fuel_core_types::{
+        fuel_tx::{
+            Bytes32,
+            TxId,
+        },
+        fuel_types::Address,
+    };
+
+    const CALLER: ContractId = ContractId::new([1; 32]);
+    const CALLEE: ContractId = ContractId::new([2; 32]);
+    const ASSET: AssetId = AssetId::new([3; 32]);
+    const BASE_ASSET: AssetId = AssetId::new([4; 32]);
+
+    fn success(receipts: Vec<Receipt>) -> TransactionExecutionStatus {
+        TransactionExecutionStatus {
+            id: TxId::default(),
+            result: TransactionExecutionResult::Success {
+                result: None,
+                receipts,
+                total_gas: 0,
+                total_fee: 0,
+            },
+        }
+    }
+
+    fn call(id: ContractId, to: ContractId, amount: u64) -> Receipt {
+        Receipt::call(id, to, amount, ASSET, 0, 0, 0, 0, 0)
+    }
+
+    fn ret(id: ContractId) -> Receipt {
+        Receipt::ret(id, 0, 0, 0)
+    }
+
+    fn balance<T>(tx: &mut T, contract_id: &ContractId, asset_id: &AssetId) -> Option<u64>
+    where
+        T: OffChainDatabaseTransaction,
+    {
+        tx.storage::<ContractBalances>()
+            .get(&ContractsAssetKey::new(contract_id, asset_id))
+            .unwrap()
+            .map(|amount| amount.into_owned())
+    }
+
+    #[test]
+    fn update__moves_the_forwarded_coins_between_contracts() {
+        let mut db = Database::<OffChain>::default();
+        let mut tx = db.write_transaction();
+        let block = Block::default();
+        let statuses = [success(vec![
+            // The script forwards the coins to the caller.
+            call(ContractId::zeroed(), CALLER, 10),
+            // The caller forwards a part of them to the callee.
+            call(CALLER, CALLEE, 4),
+            ret(CALLEE),
+            Receipt::transfer(CALLER, CALLEE, 1, ASSET, 0, 0),
+            Receipt::transfer_out(CALLER, Address::default(), 2, ASSET, 0, 0),
+            ret(CALLER),
+        ])];
+
+        // when
+        update(&block, statuses.iter(), &BASE_ASSET, &mut tx, true).unwrap();
+
+        // then
+        assert_eq!(balance(&mut tx, &CALLER, &ASSET), Some(3));
+        assert_eq!(balance(&mut tx, &CALLEE, &ASSET), Some(5));
+    }
+
+    #[test]
+    fn update__message_from_the_script_does_not_change_contract_balances() {
+        let mut db = Database::<OffChain>::default();
+        let mut tx = db.write_transaction();
+        let block = Block::default();
+        let statuses = [success(vec![
+            call(ContractId::zeroed(), CALLER, 10),
+            ret(CALLER),
+            Receipt::message_out(
+                &TxId::default(),
+                0,
+                Address::default(),
+                Address::default(),
+                3,
+                vec![],
+            ),
+        ])];
+
+        // when
+        update(&block, statuses.iter(), &BASE_ASSET, &mut tx, true).unwrap();
+
+        // then
+        assert_eq!(balance(&mut tx, &CALLER, &ASSET), Some(10));
+        assert_eq!(balance(&mut tx, &CALLER, &BASE_ASSET), None);
+    }
+
+    #[test]
+    fn update__mint_and_burn_change_the_balance_of_the_contract() {
+        let mut db = Database::<OffChain>::default();
+        let mut tx = db.write_transaction();
+        let block = Block::default();
+        let sub_id = Bytes32::new([5; 32]);
+        let statuses = [success(vec![
+            Receipt::mint(sub_id, CALLER, 10, 0, 0),
+            Receipt::burn(sub_id, CALLER, 4, 0, 0),
+        ])];
+
+        // when
+        update(&block, statuses.iter(), &BASE_ASSET, &mut tx, true).unwrap();
+
+        // then
+        assert_eq!(
+            balance(&mut tx, &CALLER, &CALLER.asset_id(&sub_id)),
+            Some(6)
+        );
+    }
+
+    #[test]
+    fn update__failed_transactions_do_not_change_balances() {
+        let mut db = Database::<OffChain>::default();
+        let mut tx = db.write_transaction();
+        let block = Block::default();
+        let statuses = [TransactionExecutionStatus {
+            id: TxId::default(),
+            result: TransactionExecutionResult::Failed {
+                result: None,
+                receipts: vec![call(ContractId::zeroed(), CALLER, 10)],
+                total_gas: 0,
+                total_fee: 0,
+            },
+        }];
+
+        // when
+        update(&block, statuses.iter(), &BASE_ASSET, &mut tx, true).unwrap();
+
+        // then
+        assert_eq!(balance(&mut tx, &CALLER, &ASSET), None);
+    }
+
+    #[test]
+    fn update__underflow_leaves_the_index_untouched() {
+        let mut db = Database::<OffChain>::default();
+        let mut tx = db.write_transaction();
+        let block = Block::default();
+        let statuses = [success(vec![
+            call(ContractId::zeroed(), CALLEE, 10),
+            ret(CALLEE),
+            // The caller has nothing to transfer.
+            call(ContractId::zeroed(), CALLER, 0),
+            Receipt::transfer_out(CALLER, Address::default(), 2, ASSET, 0, 0),
+            ret(CALLER),
+        ])];
+
+        // when
+        let result = update(&block, statuses.iter(), &BASE_ASSET, &mut tx, true);
+
+        // then
+        assert!(matches!(
+            result,
+            Err(IndexationError::ContractBalanceOutOfRange { .. })
+        ));
+        assert_eq!(balance(&mut tx, &CALLEE, &ASSET), None);
+    }
+}
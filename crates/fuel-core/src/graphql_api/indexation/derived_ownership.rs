@@ -0,0 +1,241 @@
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::Arc,
+};
+
+use fuel_core_storage::{
+    Result as StorageResult,
+    StorageAsMut,
+};
+use fuel_core_types::{
+    entities::coins::coin::Coin,
+    fuel_crypto::Hasher,
+    fuel_tx::{
+        field::Inputs,
+        Address,
+        Input,
+        Transaction,
+        TxId,
+    },
+    fuel_types::BlockHeight,
+    services::executor::Event,
+};
+
+use crate::graphql_api::{
+    ports::worker::OffChainDatabaseTransaction,
+    storage::coins::{
+        insert_owned_coin,
+        owner_coin_id_key,
+        spend_owned_coin,
+        DerivedCoinOwners,
+        OwnedCoinState,
+        OwnedCoinsLayout,
+    },
+};
+
+/// Maps the coins to their logical owners, like the key controlling
+/// the predicate that owns the coin.
+///
+/// The coin is additionally indexed in the `OwnedCoins` table under each of
+/// its logical owners, and these entries are recorded in the `DerivedCoinOwners`
+/// table, so the queries can tell them apart from the coins owned directly.
+pub trait CoinOwnerMapper: Debug + Send + Sync {
+    /// Returns the logical owners of the `coin` created by the `tx`.
+    /// The owner of the coin itself is always indexed and is ignored
+    /// if it is returned.
+    fn logical_owners(&self, coin: &Coin, tx: &Transaction) -> Vec<Address>;
+}
+
+pub type CoinOwnerMapperRef = Arc<dyn CoinOwnerMapper>;
+
+/// The mapper for the predicates that embed the public key of their owner
+/// into the predicate data.
+///
+/// The predicate data of such predicates starts with the 64 bytes of
+/// the uncompressed secp256k1 public key. The coin created for the predicate
+/// root by the transaction spending the same predicate is mapped to the address
+/// of the public key, the same as `Input::owner` of the key.
+///
+/// The coins sent to the predicate root by other transactions are not mapped,
+/// because the predicate data is known only when the predicate is spent.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PredicateDataOwnerMapper;
+
+impl PredicateDataOwnerMapper {
+    /// The length of the public key at the start of the predicate data.
+    pub const PUBLIC_KEY_LEN: usize = 64;
+}
+
+impl CoinOwnerMapper for PredicateDataOwnerMapper {
+    fn logical_owners(&self, coin: &Coin, tx: &Transaction) -> Vec<Address> {
+        let mut owners: Vec<Address> = transaction_inputs(tx)
+            .iter()
+            .filter(|input| input.input_owner() == Some(&coin.owner))
+            .filter_map(|input| input.predicate_data())
+            .filter_map(|data| data.get(..Self::PUBLIC_KEY_LEN))
+            .map(|public_key| Address::from(*Hasher::hash(public_key)))
+            .collect();
+        owners.sort();
+        owners.dedup();
+        owners
+    }
+}
+
+/// Returns the inputs of the transaction, or nothing for the `Mint`.
+pub(crate) fn transaction_inputs(tx: &Transaction) -> &[Input] {
+    match tx {
+        Transaction::Script(tx) => tx.inputs(),
+        Transaction::Create(tx) => tx.inputs(),
+        Transaction::Upgrade(tx) => tx.inputs(),
+        Transaction::Upload(tx) => tx.inputs(),
+        Transaction::Blob(tx) => tx.inputs(),
+        Transaction::Mint(_) => &[],
+    }
+}
+
+/// Indexes the created coins under their logical owners, and removes
+/// the derived entries of the consumed coins.
+///
+/// The derived entries are removed even if the `mapper` is not configured,
+/// so the coins indexed before it was disabled don't outlive their spending.
+/// They are flagged as spent at the `block_height` instead if `mark_spent` is set.
+/// The entries are keyed with the `layout` of the `OwnedCoins` column.
+pub(crate) fn update<T>(
+    event: &Event,
+    block_height: BlockHeight,
+    transactions: &HashMap<TxId, &Transaction>,
+    mapper: Option<&dyn CoinOwnerMapper>,
+    mark_spent: bool,
+    layout: OwnedCoinsLayout,
+    block_st_transaction: &mut T,
+) -> StorageResult<()>
+where
+    T: OffChainDatabaseTransaction,
+{
+    match event {
+        Event::CoinCreated(coin) => {
+            let Some(mapper) = mapper else { return Ok(()) };
+            let Some(tx) = transactions.get(coin.utxo_id.tx_id()) else {
+                return Ok(())
+            };
+            let mut owners = mapper.logical_owners(coin, tx);
+            owners.retain(|owner| owner != &coin.owner);
+            if owners.is_empty() {
+                return Ok(())
+            }
+
+            for owner in &owners {
+                insert_owned_coin(
+                    block_st_transaction,
+                    layout,
+                    &owner_coin_id_key(owner, &coin.utxo_id),
+                    &OwnedCoinState::Unspent,
+                )?;
+            }
+            block_st_transaction
+                .storage_as_mut::<DerivedCoinOwners>()
+                .insert(&coin.utxo_id, &owners)?;
+        }
+        Event::CoinConsumed(coin) => {
+            let owners = block_st_transaction
+                .storage_as_mut::<DerivedCoinOwners>()
+                .take(&coin.utxo_id)?
+                .unwrap_or_default();
+            for owner in &owners {
+                spend_owned_coin(
+                    block_st_transaction,
+                    layout,
+                    &owner_coin_id_key(owner, &coin.utxo_id),
+                    block_height,
+                    mark_spent,
+                )?;
+            }
+        }
+        Event::MessageImported(_)
+        | Event::MessageConsumed(_)
+        | Event::ForcedTransactionFailed { .. } => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::{
+        fuel_asm::{
+            op,
+            RegId,
+        },
+        fuel_crypto::SecretKey,
+        fuel_tx::{
+            AssetId,
+            TransactionBuilder,
+            UtxoId,
+        },
+    };
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+
+    fn predicate_tx(owner: Address, predicate: Vec<u8>, data: Vec<u8>) -> Transaction {
+        TransactionBuilder::script(vec![], vec![])
+            .add_input(Input::coin_predicate(
+                UtxoId::default(),
+                owner,
+                100,
+                AssetId::BASE,
+                Default::default(),
+                Default::default(),
+                predicate,
+                data,
+            ))
+            .finalize_as_transaction()
+    }
+
+    fn coin_of(owner: Address) -> Coin {
+        Coin {
+            utxo_id: UtxoId::default(),
+            owner,
+            amount: 100,
+            asset_id: AssetId::BASE,
+            tx_pointer: Default::default(),
+        }
+    }
+
+    #[test]
+    fn logical_owners__maps_the_predicate_root_to_the_address_of_the_embedded_key() {
+        // given
+        let mut rng = StdRng::seed_from_u64(1234);
+        let public_key = SecretKey::random(&mut rng).public_key();
+        let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+        let root = Input::predicate_owner(&predicate);
+        let tx = predicate_tx(root, predicate, public_key.as_ref().to_vec());
+
+        // when
+        let owners = PredicateDataOwnerMapper.logical_owners(&coin_of(root), &tx);
+
+        // then
+        assert_eq!(owners, vec![Input::owner(&public_key)]);
+    }
+
+    #[test]
+    fn logical_owners__ignores_the_coins_of_other_owners_and_short_predicate_data() {
+        // given
+        let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+        let root = Input::predicate_owner(&predicate);
+        let short_data = vec![1; PredicateDataOwnerMapper::PUBLIC_KEY_LEN - 1];
+        let tx = predicate_tx(root, predicate.clone(), short_data);
+        let other_tx = predicate_tx(root, predicate, vec![1; 64]);
+
+        // when
+        let short_owners = PredicateDataOwnerMapper.logical_owners(&coin_of(root), &tx);
+        let other_owners = PredicateDataOwnerMapper
+            .logical_owners(&coin_of(Address::zeroed()), &other_tx);
+
+        // then
+        assert!(short_owners.is_empty());
+        assert!(other_owners.is_empty());
+    }
+}
@@ -4,6 +4,7 @@ use fuel_core_types::{
     fuel_tx::{
         Address,
         AssetId,
+        ContractId,
         UtxoId,
     },
     fuel_types::Nonce,
@@ -112,6 +113,19 @@ pub enum IndexationError {
         receipt
     )]
     UnexpectedReceipt { receipt: String },
+    #[display(
+        fmt = "Contract balance would be out of range for contract_id: {}, asset_id: {}, current_amount: {}, delta: {}",
+        contract_id,
+        asset_id,
+        current_amount,
+        delta
+    )]
+    ContractBalanceOutOfRange {
+        contract_id: ContractId,
+        asset_id: AssetId,
+        current_amount: u64,
+        delta: i128,
+    },
     #[from]
     StorageError(StorageError),
 }
@@ -0,0 +1,168 @@
+use std::collections::BTreeMap;
+
+use fuel_core_storage::{
+    Result as StorageResult,
+    StorageAsMut,
+};
+use fuel_core_types::{
+    fuel_tx::Address,
+    fuel_types::BlockHeight,
+    services::executor::Event,
+};
+
+use crate::graphql_api::{
+    ports::worker::OffChainDatabaseTransaction,
+    storage::owner_activity::{
+        OwnerActivities,
+        OwnerActivity,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct OwnerActivityUpdate {
+    first_seen_block: BlockHeight,
+    last_active_block: BlockHeight,
+    transactions: u64,
+    coins_created: u64,
+    coins_consumed: u64,
+}
+
+impl OwnerActivityUpdate {
+    fn merge(self, current: Option<OwnerActivity>) -> OwnerActivity {
+        match current {
+            Some(activity) => OwnerActivity {
+                first_seen_block: activity.first_seen_block.min(self.first_seen_block),
+                last_active_block: activity.last_active_block.max(self.last_active_block),
+                total_transactions: activity
+                    .total_transactions
+                    .saturating_add(self.transactions),
+                coin_count: activity
+                    .coin_count
+                    .saturating_add(self.coins_created)
+                    .saturating_sub(self.coins_consumed),
+            },
+            None => OwnerActivity {
+                first_seen_block: self.first_seen_block,
+                last_active_block: self.last_active_block,
+                total_transactions: self.transactions,
+                coin_count: self.coins_created.saturating_sub(self.coins_consumed),
+            },
+        }
+    }
+}
+
+/// Accumulates the activity of owners, so the activity record of each owner
+/// is updated once per [`OwnerActivityUpdates::apply`], regardless of how many
+/// transactions and coins touched it.
+#[derive(Debug, Default)]
+pub(crate) struct OwnerActivityUpdates {
+    updates: BTreeMap<Address, OwnerActivityUpdate>,
+}
+
+impl OwnerActivityUpdates {
+    pub(crate) fn record_transaction(
+        &mut self,
+        owner: &Address,
+        block_height: BlockHeight,
+    ) {
+        let update = self.touch(owner, block_height);
+        update.transactions = update.transactions.saturating_add(1);
+    }
+
+    pub(crate) fn record_coin_created(
+        &mut self,
+        owner: &Address,
+        block_height: BlockHeight,
+    ) {
+        let update = self.touch(owner, block_height);
+        update.coins_created = update.coins_created.saturating_add(1);
+    }
+
+    pub(crate) fn record_coin_consumed(
+        &mut self,
+        owner: &Address,
+        block_height: BlockHeight,
+    ) {
+        let update = self.touch(owner, block_height);
+        update.coins_consumed = update.coins_consumed.saturating_add(1);
+    }
+
+    /// Records the changes of the owned coins caused by the executor event.
+    pub(crate) fn record_event(&mut self, event: &Event, block_height: BlockHeight) {
+        match event {
+            Event::CoinCreated(coin) => {
+                self.record_coin_created(&coin.owner, block_height)
+            }
+            Event::CoinConsumed(coin) => {
+                self.record_coin_consumed(&coin.owner, block_height)
+            }
+            Event::MessageImported(_)
+            | Event::MessageConsumed(_)
+            | Event::ForcedTransactionFailed { .. } => {}
+        }
+    }
+
+    /// Returns the number of owners with the accumulated activity.
+    pub(crate) fn len(&self) -> usize {
+        self.updates.len()
+    }
+
+    fn touch(
+        &mut self,
+        owner: &Address,
+        block_height: BlockHeight,
+    ) -> &mut OwnerActivityUpdate {
+        let update = self
+            .updates
+            .entry(*owner)
+            .or_insert_with(|| OwnerActivityUpdate {
+                first_seen_block: block_height,
+                last_active_block: block_height,
+                transactions: 0,
+                coins_created: 0,
+                coins_consumed: 0,
+            });
+        update.first_seen_block = update.first_seen_block.min(block_height);
+        update.last_active_block = update.last_active_block.max(block_height);
+        update
+    }
+
+    /// Merges the accumulated activity into the stored records, and the numbers
+    /// of transactions into the top addresses.
+    pub(crate) fn apply<T>(self, block_st_transaction: &mut T) -> StorageResult<()>
+    where
+        T: OffChainDatabaseTransaction,
+    {
+        let tx_counts = self
+            .updates
+            .iter()
+            .filter(|(_, update)| update.transactions > 0)
+            .map(|(owner, update)| (*owner, update.transactions))
+            .collect::<Vec<_>>();
+        block_st_transaction.update_top_addresses(&tx_counts)?;
+
+        for (owner, update) in self.updates {
+            let current = block_st_transaction
+                .storage::<OwnerActivities>()
+                .get(&owner)?
+                .map(|activity| activity.into_owned());
+            block_st_transaction
+                .storage::<OwnerActivities>()
+                .insert(&owner, &update.merge(current))?;
+        }
+        Ok(())
+    }
+
+    /// Replaces the stored records with the accumulated activity.
+    pub(crate) fn overwrite<T>(self, block_st_transaction: &mut T) -> StorageResult<()>
+    where
+        T: OffChainDatabaseTransaction,
+    {
+        for (owner, update) in self.updates {
+            block_st_transaction
+                .storage::<OwnerActivities>()
+                .insert(&owner, &update.merge(None))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+use crate::graphql_api::{
+    ports::MessageProofCacheStorage,
+    storage::messages::MessageProofCacheKey,
+};
+use fuel_core_storage::Result as StorageResult;
+use fuel_core_types::entities::relayer::message::MessageProof;
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+/// The cache of the message proofs generated by the GraphQL service.
+///
+/// The proofs are stored in the off-chain database, so they survive restarts
+/// of the node. The least recently used proofs are evicted above the capacity.
+#[derive(Clone)]
+pub struct MessageProofCache {
+    inner: Option<Arc<Inner>>,
+}
+
+struct Inner {
+    storage: Box<dyn MessageProofCacheStorage>,
+    capacity: usize,
+    /// Serializes the updates of the order of use of the cached proofs.
+    lock: Mutex<()>,
+}
+
+impl MessageProofCache {
+    /// Creates the cache holding up to `capacity` proofs in the `storage`.
+    /// The zero `capacity` disables the cache.
+    pub fn new<S>(storage: S, capacity: usize) -> Self
+    where
+        S: MessageProofCacheStorage + 'static,
+    {
+        let inner = (capacity > 0).then(|| {
+            Arc::new(Inner {
+                storage: Box::new(storage),
+                capacity,
+                lock: Mutex::new(()),
+            })
+        });
+        Self { inner }
+    }
+
+    /// Returns the cached proof if `is_valid` accepts it, or generates the proof
+    /// and caches it. The returned flag is `true` if the proof came from the cache.
+    pub fn proof<V, G>(
+        &self,
+        key: &MessageProofCacheKey,
+        is_valid: V,
+        generate: G,
+    ) -> StorageResult<(MessageProof, bool)>
+    where
+        V: FnOnce(&MessageProof) -> StorageResult<bool>,
+        G: FnOnce() -> StorageResult<MessageProof>,
+    {
+        let Some(inner) = &self.inner else {
+            return Ok((generate()?, false));
+        };
+
+        if let Some(proof) = inner.storage.cached_message_proof(key)? {
+            if is_valid(&proof)? {
+                let _guard = inner.lock.lock();
+                let mut entries = inner.storage.message_proof_cache_entries()?;
+                entries.touch(key);
+                inner
+                    .storage
+                    .update_message_proof_cache(&entries, None, &[])?;
+                return Ok((proof, true));
+            }
+        }
+
+        let proof = generate()?;
+
+        let _guard = inner.lock.lock();
+        let mut entries = inner.storage.message_proof_cache_entries()?;
+        entries.touch(key);
+        let evicted = entries.evict(inner.capacity);
+        inner.storage.update_message_proof_cache(
+            &entries,
+            Some((key, &proof)),
+            &evicted,
+        )?;
+        Ok((proof, false))
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::database::{
+        database_description::off_chain::OffChain,
+        Database,
+    };
+    use fuel_core_types::{
+        blockchain::header::BlockHeader,
+        entities::relayer::message::MerkleProof,
+        fuel_tx::TxId,
+        fuel_types::Nonce,
+    };
+
+    fn proof_committed_at(height: u32) -> MessageProof {
+        let mut commit_block_header = BlockHeader::default();
+        commit_block_header.set_block_height(height.into());
+        MessageProof {
+            message_proof: MerkleProof::default(),
+            block_proof: MerkleProof::default(),
+            message_block_header: BlockHeader::default(),
+            commit_block_header,
+            sender: Default::default(),
+            recipient: Default::default(),
+            nonce: Default::default(),
+            amount: 0,
+            data: vec![],
+        }
+    }
+
+    fn key(nonce: u8, height: u32) -> MessageProofCacheKey {
+        MessageProofCacheKey::new(
+            TxId::from([1; 32]),
+            Nonce::from([nonce; 32]),
+            height.into(),
+        )
+    }
+
+    #[test]
+    fn proof__second_request_hits_the_cache() {
+        let cache = MessageProofCache::new(Database::<OffChain>::default(), 10);
+        let key = key(1, 10);
+
+        // given
+        let (_, cached) = cache
+            .proof(&key, |_| Ok(true), || Ok(proof_committed_at(10)))
+            .unwrap();
+        assert!(!cached);
+
+        // when
+        let (proof, cached) = cache
+            .proof(&key, |_| Ok(true), || panic!("The proof should be cached"))
+            .unwrap();
+
+        // then
+        assert!(cached);
+        assert_eq!(proof, proof_committed_at(10));
+    }
+
+    #[test]
+    fn proof__different_commit_height_regenerates_the_proof() {
+        let cache = MessageProofCache::new(Database::<OffChain>::default(), 10);
+        cache
+            .proof(&key(1, 10), |_| Ok(true), || Ok(proof_committed_at(10)))
+            .unwrap();
+
+        // when
+        let (proof, cached) = cache
+            .proof(&key(1, 11), |_| Ok(true), || Ok(proof_committed_at(11)))
+            .unwrap();
+
+        // then
+        assert!(!cached);
+        assert_eq!(proof, proof_committed_at(11));
+    }
+
+    #[test]
+    fn proof__changed_commit_block_regenerates_the_proof() {
+        let cache = MessageProofCache::new(Database::<OffChain>::default(), 10);
+        let key = key(1, 10);
+        cache
+            .proof(&key, |_| Ok(true), || Ok(proof_committed_at(10)))
+            .unwrap();
+        let mut new_proof = proof_committed_at(10);
+        new_proof.amount = 1;
+
+        // when
+        let (proof, cached) = cache
+            .proof(&key, |_| Ok(false), || Ok(new_proof.clone()))
+            .unwrap();
+
+        // then
+        assert!(!cached);
+        assert_eq!(proof, new_proof);
+        let (proof, cached) = cache
+            .proof(&key, |_| Ok(true), || panic!("The proof should be cached"))
+            .unwrap();
+        assert!(cached);
+        assert_eq!(proof, new_proof);
+    }
+
+    #[test]
+    fn proof__evicts_the_least_recently_used_proof() {
+        let database = Database::<OffChain>::default();
+        let cache = MessageProofCache::new(database.clone(), 2);
+        cache
+            .proof(&key(1, 10), |_| Ok(true), || Ok(proof_committed_at(10)))
+            .unwrap();
+        cache
+            .proof(&key(2, 10), |_| Ok(true), || Ok(proof_committed_at(10)))
+            .unwrap();
+        cache
+            .proof(
+                &key(1, 10),
+                |_| Ok(true),
+                || panic!("The proof should be cached"),
+            )
+            .unwrap();
+
+        // when
+        cache
+            .proof(&key(3, 10), |_| Ok(true), || Ok(proof_committed_at(10)))
+            .unwrap();
+
+        // then
+        assert!(database
+            .cached_message_proof(&key(1, 10))
+            .unwrap()
+            .is_some());
+        assert!(database
+            .cached_message_proof(&key(2, 10))
+            .unwrap()
+            .is_none());
+        assert!(database
+            .cached_message_proof(&key(3, 10))
+            .unwrap()
+            .is_some());
+        assert_eq!(database.message_proof_cache_entries().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,406 @@
+//! The health of the subsystems of the node, reported by the `/v1/health`
+//! endpoint and the `nodeHealth` query.
+//!
+//! The services publish their progress into the shared handles read by the API,
+//! and the lag of each subsystem behind the chain tip is classified with the
+//! configured thresholds.
+
+use super::{
+    api_service::ReadDatabase,
+    block_height_subscription,
+    ports::{
+        RelayerSyncPort,
+        RelayerSyncState,
+        TxPoolPort,
+    },
+};
+use fuel_core_storage::Result as StorageResult;
+use fuel_core_types::fuel_types::BlockHeight;
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// The classification of the state of the subsystem.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubsystemStatus {
+    Ok,
+    /// The subsystem works, but lags behind or is close to its limits.
+    Degraded,
+    /// The subsystem doesn't make progress.
+    Failing,
+}
+
+/// The number of blocks the subsystem may lag behind the chain tip.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LagThresholds {
+    /// The subsystem lagging by more blocks is degraded.
+    pub degraded: u32,
+    /// The subsystem lagging by more blocks is failing.
+    pub failing: u32,
+}
+
+impl LagThresholds {
+    pub fn classify(&self, lag: u32) -> SubsystemStatus {
+        if lag > self.failing {
+            SubsystemStatus::Failing
+        } else if lag > self.degraded {
+            SubsystemStatus::Degraded
+        } else {
+            SubsystemStatus::Ok
+        }
+    }
+}
+
+/// The height of the last block compressed by the DA compression service.
+#[derive(Clone, Debug, Default)]
+pub struct DaCompressionHeight(Arc<RwLock<Option<BlockHeight>>>);
+
+impl DaCompressionHeight {
+    pub fn get(&self) -> Option<BlockHeight> {
+        *self.0.read()
+    }
+
+    pub fn set(&self, height: Option<BlockHeight>) {
+        *self.0.write() = height;
+    }
+}
+
+/// Returns the number of blocks the `height` lags behind the `tip`.
+pub(crate) fn blocks_behind(
+    tip: Option<BlockHeight>,
+    height: Option<BlockHeight>,
+) -> u32 {
+    match (tip, height) {
+        (None, _) => 0,
+        (Some(tip), None) => u32::from(tip).saturating_add(1),
+        (Some(tip), Some(height)) => u32::from(tip).saturating_sub(u32::from(height)),
+    }
+}
+
+/// The progress of the subsystem following the chain tip.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct HeightHealth {
+    pub status: SubsystemStatus,
+    pub height: Option<u32>,
+    /// The number of blocks the subsystem lags behind the chain tip.
+    pub lag: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct TxPoolHealth {
+    /// The pool is degraded once it is full.
+    pub status: SubsystemStatus,
+    pub tx_count: u64,
+    pub max_tx_count: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct RelayerHealth {
+    /// The relayer is degraded until it is synced with the DA layer.
+    pub status: SubsystemStatus,
+    pub da_height: u64,
+    pub synced: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct IndexationHealth {
+    /// The indexation is failing while a block is in the dead letter.
+    pub status: SubsystemStatus,
+    /// The height of the block that failed to be indexed, if any.
+    pub dead_letter_height: Option<u32>,
+}
+
+/// The health of the node. The disabled subsystems are `None`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct NodeHealth {
+    /// The worst status of the subsystems.
+    pub status: SubsystemStatus,
+    pub on_chain: HeightHealth,
+    pub off_chain: HeightHealth,
+    pub da_compression: Option<HeightHealth>,
+    pub txpool: TxPoolHealth,
+    pub relayer: Option<RelayerHealth>,
+    pub indexation: IndexationHealth,
+}
+
+/// The state of the subsystems the [`NodeHealth`] is derived from.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SubsystemsState {
+    pub on_chain_height: Option<BlockHeight>,
+    pub off_chain_height: Option<BlockHeight>,
+    /// The height of the last compressed block, if the compression is enabled.
+    pub da_compression_height: Option<Option<BlockHeight>>,
+    pub txpool_tx_count: u64,
+    pub max_txpool_tx_count: u64,
+    pub relayer: Option<RelayerSyncState>,
+    pub dead_letter_height: Option<BlockHeight>,
+}
+
+impl SubsystemsState {
+    pub fn classify(&self, thresholds: &LagThresholds) -> NodeHealth {
+        let tip = self.on_chain_height;
+        let following_tip = |height: Option<BlockHeight>| {
+            let lag = blocks_behind(tip, height);
+            HeightHealth {
+                status: thresholds.classify(lag),
+                height: height.map(u32::from),
+                lag,
+            }
+        };
+
+        let on_chain = HeightHealth {
+            status: if tip.is_some() {
+                SubsystemStatus::Ok
+            } else {
+                SubsystemStatus::Failing
+            },
+            height: tip.map(u32::from),
+            lag: 0,
+        };
+        let off_chain = following_tip(self.off_chain_height);
+        let da_compression = self.da_compression_height.map(following_tip);
+        let txpool = TxPoolHealth {
+            status: if self.txpool_tx_count >= self.max_txpool_tx_count {
+                SubsystemStatus::Degraded
+            } else {
+                SubsystemStatus::Ok
+            },
+            tx_count: self.txpool_tx_count,
+            max_tx_count: self.max_txpool_tx_count,
+        };
+        let relayer = self.relayer.map(|relayer| RelayerHealth {
+            status: if relayer.synced {
+                SubsystemStatus::Ok
+            } else {
+                SubsystemStatus::Degraded
+            },
+            da_height: relayer.da_height.0,
+            synced: relayer.synced,
+        });
+        let indexation = IndexationHealth {
+            status: if self.dead_letter_height.is_some() {
+                SubsystemStatus::Failing
+            } else {
+                SubsystemStatus::Ok
+            },
+            dead_letter_height: self.dead_letter_height.map(u32::from),
+        };
+
+        let status = [
+            Some(on_chain.status),
+            Some(off_chain.status),
+            da_compression.map(|health| health.status),
+            Some(txpool.status),
+            relayer.map(|health| health.status),
+            Some(indexation.status),
+        ]
+        .into_iter()
+        .flatten()
+        .max()
+        .unwrap_or(SubsystemStatus::Ok);
+
+        NodeHealth {
+            status,
+            on_chain,
+            off_chain,
+            da_compression,
+            txpool,
+            relayer,
+            indexation,
+        }
+    }
+}
+
+/// The sources of the state of the subsystems outside of the databases.
+pub struct NodeHealthSources {
+    pub txpool: Box<dyn TxPoolPort>,
+    pub relayer: Box<dyn RelayerSyncPort>,
+    /// The progress of the DA compression, if it is enabled.
+    pub da_compression_height: Option<DaCompressionHeight>,
+}
+
+/// Collects the state of the subsystems on each request.
+#[derive(Clone)]
+pub struct NodeHealthReporter {
+    database: ReadDatabase,
+    /// The height of the last block indexed by the off-chain worker.
+    indexed_height: block_height_subscription::Subscriber,
+    sources: Arc<NodeHealthSources>,
+    max_txpool_tx_count: u64,
+    thresholds: LagThresholds,
+}
+
+impl NodeHealthReporter {
+    pub(crate) fn new(
+        database: ReadDatabase,
+        indexed_height: block_height_subscription::Subscriber,
+        sources: NodeHealthSources,
+        max_txpool_tx_count: u64,
+        thresholds: LagThresholds,
+    ) -> Self {
+        Self {
+            database,
+            indexed_height,
+            sources: Arc::new(sources),
+            max_txpool_tx_count,
+            thresholds,
+        }
+    }
+
+    pub fn subsystems_state(&self) -> StorageResult<SubsystemsState> {
+        let dead_letter = self.database.view()?.indexation_dead_letter()?;
+        Ok(SubsystemsState {
+            on_chain_height: self.database.on_chain_height(),
+            off_chain_height: Some(self.indexed_height.current_block_height()),
+            da_compression_height: self
+                .sources
+                .da_compression_height
+                .as_ref()
+                .map(DaCompressionHeight::get),
+            txpool_tx_count: self.sources.txpool.latest_pool_stats().tx_count,
+            max_txpool_tx_count: self.max_txpool_tx_count,
+            relayer: self.sources.relayer.relayer_sync_state(),
+            dead_letter_height: dead_letter.map(|dead_letter| dead_letter.height),
+        })
+    }
+
+    pub fn node_health(&self) -> StorageResult<NodeHealth> {
+        Ok(self.subsystems_state()?.classify(&self.thresholds))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use fuel_core_types::blockchain::primitives::DaBlockHeight;
+
+    const THRESHOLDS: LagThresholds = LagThresholds {
+        degraded: 10,
+        failing: 100,
+    };
+
+    fn healthy_state(tip: u32) -> SubsystemsState {
+        SubsystemsState {
+            on_chain_height: Some(tip.into()),
+            off_chain_height: Some(tip.into()),
+            da_compression_height: Some(Some(tip.into())),
+            txpool_tx_count: 3,
+            max_txpool_tx_count: 100,
+            relayer: Some(RelayerSyncState {
+                da_height: DaBlockHeight(7),
+                synced: true,
+            }),
+            dead_letter_height: None,
+        }
+    }
+
+    #[test]
+    fn blocks_behind__counts_the_blocks_missing_behind_the_tip() {
+        // given
+        let cases = [
+            (None, None, 0),
+            (Some(0), None, 1),
+            (Some(10), None, 11),
+            (Some(10), Some(7), 3),
+            (Some(10), Some(10), 0),
+            // The subsystem ahead of the tip doesn't lag.
+            (Some(7), Some(10), 0),
+        ];
+
+        for (tip, height, expected) in cases {
+            // when
+            let lag =
+                blocks_behind(tip.map(BlockHeight::new), height.map(BlockHeight::new));
+
+            // then
+            assert_eq!(lag, expected, "tip {tip:?}, height {height:?}");
+        }
+    }
+
+    #[test]
+    fn classify__reports_all_subsystems_ok_when_they_follow_the_tip() {
+        // given
+        let state = healthy_state(50);
+
+        // when
+        let health = state.classify(&THRESHOLDS);
+
+        // then
+        assert_eq!(health.status, SubsystemStatus::Ok);
+        assert_eq!(health.off_chain.lag, 0);
+        assert_eq!(health.relayer.unwrap().da_height, 7);
+    }
+
+    #[test]
+    fn classify__reports_the_stalled_worker_as_degraded_while_others_stay_ok() {
+        // given
+        // The worker stopped at the height 10 while the chain advanced to 50.
+        let mut state = healthy_state(50);
+        state.off_chain_height = Some(10.into());
+
+        // when
+        let health = state.classify(&THRESHOLDS);
+
+        // then
+        assert_eq!(health.off_chain.status, SubsystemStatus::Degraded);
+        assert_eq!(health.off_chain.lag, 40);
+        assert_eq!(health.status, SubsystemStatus::Degraded);
+        assert_eq!(health.on_chain.status, SubsystemStatus::Ok);
+        assert_eq!(health.da_compression.unwrap().status, SubsystemStatus::Ok);
+        assert_eq!(health.txpool.status, SubsystemStatus::Ok);
+        assert_eq!(health.relayer.unwrap().status, SubsystemStatus::Ok);
+        assert_eq!(health.indexation.status, SubsystemStatus::Ok);
+    }
+
+    #[test]
+    fn classify__reports_the_worker_stalled_beyond_the_failing_threshold_as_failing() {
+        // given
+        let mut state = healthy_state(500);
+        state.off_chain_height = Some(10.into());
+
+        // when
+        let health = state.classify(&THRESHOLDS);
+
+        // then
+        assert_eq!(health.off_chain.status, SubsystemStatus::Failing);
+        assert_eq!(health.status, SubsystemStatus::Failing);
+    }
+
+    #[test]
+    fn classify__reports_the_dead_letter_and_the_full_pool() {
+        // given
+        let mut state = healthy_state(50);
+        state.dead_letter_height = Some(51.into());
+        state.txpool_tx_count = 100;
+
+        // when
+        let health = state.classify(&THRESHOLDS);
+
+        // then
+        assert_eq!(health.indexation.status, SubsystemStatus::Failing);
+        assert_eq!(health.indexation.dead_letter_height, Some(51));
+        assert_eq!(health.txpool.status, SubsystemStatus::Degraded);
+        assert_eq!(health.status, SubsystemStatus::Failing);
+    }
+
+    #[test]
+    fn classify__skips_the_disabled_compression_and_degrades_the_unsynced_relayer() {
+        // given
+        let mut state = healthy_state(50);
+        state.da_compression_height = None;
+        state.relayer = Some(RelayerSyncState {
+            da_height: DaBlockHeight(7),
+            synced: false,
+        });
+
+        // when
+        let health = state.classify(&THRESHOLDS);
+
+        // then
+        assert_eq!(health.da_compression, None);
+        assert_eq!(health.relayer.unwrap().status, SubsystemStatus::Degraded);
+        assert_eq!(health.status, SubsystemStatus::Degraded);
+    }
+}
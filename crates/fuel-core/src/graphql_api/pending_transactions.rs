@@ -0,0 +1,94 @@
+use fuel_core_txpool::{
+    PendingTransactionsOrder,
+    TxInfo,
+};
+use fuel_core_types::{
+    fuel_tx::TxId,
+    services::txpool::PoolTransaction,
+};
+use quick_cache::sync::Cache;
+use std::{
+    sync::{
+        atomic::{
+            AtomicU64,
+            Ordering,
+        },
+        Arc,
+    },
+    time::SystemTime,
+};
+
+/// The number of snapshots kept for the pagination. The cursors of the evicted
+/// snapshots become invalid, and the pagination has to start over.
+const SNAPSHOTS_CAPACITY: usize = 64;
+
+/// The summary of the transaction in the pool.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PendingTransaction {
+    pub id: TxId,
+    pub tip: u64,
+    pub max_gas: u64,
+    pub inserted_at: SystemTime,
+}
+
+impl From<&TxInfo> for PendingTransaction {
+    fn from(info: &TxInfo) -> Self {
+        let tx: &PoolTransaction = info.tx();
+        Self {
+            id: tx.id(),
+            tip: tx.tip(),
+            max_gas: tx.max_gas(),
+            inserted_at: *info.creation_instant(),
+        }
+    }
+}
+
+/// The transactions of the pool at the moment of the snapshot, in the `order`.
+#[derive(Debug)]
+pub struct PendingTransactionsSnapshot {
+    pub order: PendingTransactionsOrder,
+    pub transactions: Vec<PendingTransaction>,
+}
+
+/// The snapshots of the pool taken by the `pendingTransactions` query.
+///
+/// The first page of the query takes the snapshot, and the next pages are served
+/// from it, so the pages don't shift while the pool changes. The identifiers of
+/// the snapshots start from a random number, so the cursors issued before
+/// the restart of the node don't point to the new snapshots.
+#[derive(Clone)]
+pub struct PendingTransactionsSnapshots {
+    next_id: Arc<AtomicU64>,
+    snapshots: Arc<Cache<u64, Arc<PendingTransactionsSnapshot>>>,
+}
+
+impl Default for PendingTransactionsSnapshots {
+    fn default() -> Self {
+        Self {
+            next_id: Arc::new(AtomicU64::new(rand::random())),
+            snapshots: Arc::new(Cache::new(SNAPSHOTS_CAPACITY)),
+        }
+    }
+}
+
+impl PendingTransactionsSnapshots {
+    /// Stores the snapshot and returns it with its identifier.
+    pub fn insert(
+        &self,
+        order: PendingTransactionsOrder,
+        transactions: &[TxInfo],
+    ) -> (u64, Arc<PendingTransactionsSnapshot>) {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let snapshot = Arc::new(PendingTransactionsSnapshot {
+            order,
+            transactions: transactions.iter().map(Into::into).collect(),
+        });
+        self.snapshots.insert(id, snapshot.clone());
+        (id, snapshot)
+    }
+
+    /// Returns the snapshot, or `None` if it was evicted.
+    pub fn get(&self, id: u64) -> Option<Arc<PendingTransactionsSnapshot>> {
+        self.snapshots.get(&id)
+    }
+}
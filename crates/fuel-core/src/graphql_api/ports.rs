@@ -1,8 +1,39 @@
 use super::storage::{
-    assets::AssetDetails,
+    assets::{
+        AssetDetails,
+        AssetFirstSeenInfo,
+    },
     balances::TotalBalanceAmount,
+    blocks::CollectedFees,
+    coins::SpentCoin,
+    contracts::ContractDeployment,
+    da_compression::{
+        evictor_cache::MetadataKey,
+        timestamps::TimestampKey,
+        DaCompressionStats,
+        DaRegistration,
+    },
+    dead_letter::IndexationDeadLetter,
+    known_owners::KnownOwner,
+    messages::{
+        MessageProofCacheEntries,
+        MessageProofCacheKey,
+        OwnedMessageState,
+    },
+    owner_activity::OwnerActivity,
+    rollbacks::ChainRollback,
+    statistic::{
+        AddressTxCount,
+        CommitDurationMax,
+    },
+    transactions::OwnedTransactionValue,
+};
+#[cfg(feature = "fault-proving")]
+use crate::fuel_core_graphql_api::da_compression::DaCompressedBlockProof;
+use crate::{
+    database::database_description::IndexationKind,
+    fuel_core_graphql_api::storage::coins::CoinsToSpendIndexKey,
 };
-use crate::fuel_core_graphql_api::storage::coins::CoinsToSpendIndexKey;
 use async_trait::async_trait;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::{
@@ -25,12 +56,17 @@ use fuel_core_storage::{
     StorageRead,
 };
 use fuel_core_tx_status_manager::TxStatusMessage;
-use fuel_core_txpool::TxPoolStats;
+use fuel_core_txpool::{
+    PendingTransactionsOrder,
+    TxInfo,
+    TxPoolStats,
+};
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
         consensus::Consensus,
         header::{
+            BlockHeader,
             ConsensusParametersVersion,
             StateTransitionBytecodeVersion,
         },
@@ -43,13 +79,17 @@ use fuel_core_types::{
         message::{
             MerkleProof,
             Message,
+            MessageProof,
         },
         transaction::RelayedTransactionStatus,
     },
+    fuel_compression::RegistryKey,
     fuel_tx::{
+        input::PredicateCode,
         Bytes32,
         ConsensusParameters,
         Salt,
+        ScriptCode,
         Transaction,
         TxId,
         TxPointer,
@@ -87,13 +127,116 @@ pub struct CoinsToSpendIndexIter<'a> {
 pub trait OffChainDatabase: Send + Sync {
     fn block_height(&self, block_id: &BlockId) -> StorageResult<BlockHeight>;
 
+    /// Returns the heights of the blocks produced by the `producer`.
+    fn block_heights_by_producer(
+        &self,
+        producer: &Address,
+        start_height: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<BlockHeight>>;
+
+    /// Returns the ids of the transactions of the block at the `height`,
+    /// if the block was indexed with them.
+    fn block_transaction_ids(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<Vec<TxId>>>;
+
+    /// Returns the postcard encoded DA compressed block without the format byte,
+    /// decompressed if it is compressed at rest. Fails with the [`UnsupportedDaFormat`](super::storage::da_compression::versioned_block_codec::UnsupportedDaFormat)
+    /// if the format of the stored block is unknown.
     fn da_compressed_block(&self, height: &BlockHeight) -> StorageResult<Vec<u8>>;
 
+    /// Returns the checksum of the DA compressed block, if it was stored with one.
+    fn da_compressed_block_checksum(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<u32>>;
+
+    /// Returns the heights of the DA compressed blocks in ascending order,
+    /// starting from the `start`.
+    fn da_compressed_block_heights(
+        &self,
+        start: BlockHeight,
+    ) -> BoxedIter<'_, StorageResult<BlockHeight>>;
+
+    /// Returns the height of the last block compressed for the DA, if any.
+    fn da_compression_height(&self) -> StorageResult<Option<BlockHeight>>;
+
+    /// Returns the longest commit of the off-chain worker within the recent blocks, if any.
+    fn commit_duration_max(&self) -> StorageResult<Option<CommitDurationMax>>;
+
+    /// Returns the statistics of the DA compressed blocks in descending order of heights.
+    fn da_compression_stats(
+        &self,
+    ) -> BoxedIter<'_, StorageResult<(BlockHeight, DaCompressionStats)>>;
+
+    /// Returns the registrations in the temporal registry made by the DA
+    /// compressed block, if it was stored with them.
+    fn da_compressed_block_registrations(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<Vec<DaRegistration>>>;
+
+    /// Returns the proof of the inclusion of the DA compressed block against
+    /// the root of all compressed blocks, if the block is in the tree.
+    #[cfg(feature = "fault-proving")]
+    fn da_compressed_block_proof(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<DaCompressedBlockProof>>;
+
+    /// Returns the script code registered under the `key`
+    /// in the temporal registry of the DA compression.
+    fn da_registry_script_code(
+        &self,
+        key: &RegistryKey,
+    ) -> StorageResult<Option<ScriptCode>>;
+
+    /// Returns the predicate code registered under the `key`
+    /// in the temporal registry of the DA compression.
+    fn da_registry_predicate_code(
+        &self,
+        key: &RegistryKey,
+    ) -> StorageResult<Option<PredicateCode>>;
+
+    /// Returns the latest key assigned by the evictor of the `keyspace`
+    /// in the temporal registry of the DA compression.
+    fn da_registry_latest_assigned_key(
+        &self,
+        keyspace: MetadataKey,
+    ) -> StorageResult<Option<RegistryKey>>;
+
+    /// Returns the number of keys holding a registration in the `keyspace`
+    /// of the temporal registry of the DA compression, `None` if it isn't stored.
+    fn da_registry_entry_count(
+        &self,
+        keyspace: MetadataKey,
+    ) -> StorageResult<Option<u32>>;
+
+    /// Returns the time of the last update of the `key`
+    /// in the temporal registry of the DA compression.
+    fn da_registry_timestamp(&self, key: &TimestampKey) -> StorageResult<Option<Tai64>>;
+
     fn tx_status(
         &self,
         tx_id: &TxId,
     ) -> StorageResult<txpool::TransactionExecutionStatus>;
 
+    /// Returns the statuses of the transactions in the order of the `tx_ids`,
+    /// or `None` for the transactions without a status.
+    fn tx_statuses(
+        &self,
+        tx_ids: &[TxId],
+    ) -> Vec<StorageResult<Option<txpool::TransactionExecutionStatus>>>;
+
+    /// Returns whether the transactions in the order of the `tx_ids` have
+    /// an execution status, without decoding the statuses.
+    fn txs_included(&self, tx_ids: &[TxId]) -> Vec<StorageResult<bool>>;
+
+    /// Returns the tag attached to the transaction by the `tagTransaction` mutation.
+    fn transaction_tag(&self, tx_id: &TxId) -> StorageResult<Option<Vec<u8>>>;
+
     fn balance(
         &self,
         owner: &Address,
@@ -101,6 +244,14 @@ pub trait OffChainDatabase: Send + Sync {
         base_asset_id: &AssetId,
     ) -> StorageResult<TotalBalanceAmount>;
 
+    /// Returns the total value of the coins of the `owner` in the `asset_id`
+    /// from the [`CoinBalances`](super::storage::balances::CoinBalances), without messages.
+    fn coin_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> StorageResult<TotalBalanceAmount>;
+
     fn balances<'a>(
         &'a self,
         owner: &Address,
@@ -109,13 +260,61 @@ pub trait OffChainDatabase: Send + Sync {
         direction: IterDirection,
     ) -> BoxedIter<'a, StorageResult<(AssetId, TotalBalanceAmount)>>;
 
+    /// Returns the coins of the `owner`, including the coins of which the `owner`
+    /// is the logical owner if `include_derived` is `true`.
     fn owned_coins_ids(
         &self,
         owner: &Address,
         start_coin: Option<UtxoId>,
         direction: IterDirection,
+        include_derived: bool,
     ) -> BoxedIter<'_, StorageResult<UtxoId>>;
 
+    /// Returns the coins owned directly by the `owner` with the `asset_id`.
+    fn owned_coins_ids_by_asset(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        start_coin: Option<UtxoId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<UtxoId>>;
+
+    /// Returns the coins of the `owner` flagged as owned by a predicate
+    /// when they were indexed.
+    fn predicate_owned_coins_ids(
+        &self,
+        owner: &Address,
+        start_coin: Option<UtxoId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<UtxoId>>;
+
+    /// Returns `true` if the coin of the `owner` was flagged as owned by a predicate
+    /// when it was indexed.
+    fn is_predicate_owned_coin(
+        &self,
+        owner: &Address,
+        utxo_id: &UtxoId,
+    ) -> StorageResult<bool>;
+
+    /// Returns the spent coin with the transaction that spent it, or `None`
+    /// if the coin wasn't spent or was pruned.
+    fn spent_coin(&self, utxo_id: &UtxoId) -> StorageResult<Option<SpentCoin>>;
+
+    /// Returns the ids of the coins spent by the block at the `height`,
+    /// in the order of the inputs of its transactions.
+    fn spent_coins_at_height(
+        &self,
+        height: BlockHeight,
+    ) -> BoxedIter<'_, StorageResult<UtxoId>>;
+
+    /// Returns the number of coins of the `owner` with the `asset_id`.
+    fn owned_coin_count_by_asset(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> StorageResult<u64>;
+
+    /// Returns the ids of the unspent messages of the `owner`.
     fn owned_message_ids(
         &self,
         owner: &Address,
@@ -123,11 +322,38 @@ pub trait OffChainDatabase: Send + Sync {
         direction: IterDirection,
     ) -> BoxedIter<'_, StorageResult<Nonce>>;
 
+    /// Returns the ids of the messages of the `owner` with their states,
+    /// including the spent messages.
+    fn owned_message_states(
+        &self,
+        owner: &Address,
+        start_message_id: Option<Nonce>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(Nonce, OwnedMessageState)>>;
+
+    /// Returns the ids of the unspent messages sent by the `sender` on the L1.
+    /// Only the messages imported after the index was introduced are returned.
+    fn message_ids_by_sender(
+        &self,
+        sender: &Address,
+        start_message_id: Option<Nonce>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<Nonce>>;
+
+    /// Returns the ids of the owner's transactions tagged with the direction.
     fn owned_transactions_ids(
         &self,
         owner: Address,
         start: Option<TxPointer>,
         direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, OwnedTransactionValue)>>;
+
+    /// Returns the ids of the transactions that reference the contract.
+    fn contract_transactions_ids(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
 
     fn coins_to_spend_index(
@@ -136,10 +362,39 @@ pub trait OffChainDatabase: Send + Sync {
         asset_id: &AssetId,
     ) -> CoinsToSpendIndexIter;
 
+    /// Returns `true` if the coins to spend index has any entry
+    /// of the `owner` with the `asset_id`.
+    fn has_coins_to_spend(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> StorageResult<bool>;
+
+    /// Checks if the index of the `kind` is marked as being rebuilt in the metadata.
+    fn indexation_stale(&self, kind: IndexationKind) -> StorageResult<bool>;
+
+    /// Returns the block that failed to be indexed, if any.
+    fn indexation_dead_letter(&self) -> StorageResult<Option<IndexationDeadLetter>>;
+
+    /// Returns the last rollback of the database recorded in the metadata, if any.
+    fn last_chain_rollback(&self) -> StorageResult<Option<ChainRollback>>;
+
     fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt>;
 
+    /// Returns the contracts deployed by the transactions,
+    /// starting from the transaction at the `start`.
+    fn contracts_by_block(
+        &self,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, ContractDeployment)>>;
+
     fn old_block(&self, height: &BlockHeight) -> StorageResult<CompressedBlock>;
 
+    /// Returns the header of the block from before regenesis
+    /// without the ids of the transactions of the block.
+    fn old_block_header(&self, height: &BlockHeight) -> StorageResult<BlockHeader>;
+
     fn old_blocks(
         &self,
         height: Option<BlockHeight>,
@@ -150,14 +405,104 @@ pub trait OffChainDatabase: Send + Sync {
 
     fn old_transaction(&self, id: &TxId) -> StorageResult<Option<Transaction>>;
 
+    /// Returns the consensus parameters of the `version`,
+    /// including the versions from before regenesis.
+    fn consensus_parameters_by_version(
+        &self,
+        version: ConsensusParametersVersion,
+    ) -> StorageResult<Option<ConsensusParameters>>;
+
+    /// Returns the versions of the consensus parameters seen by the node in ascending order.
+    fn consensus_parameters_versions(
+        &self,
+    ) -> BoxedIter<'_, StorageResult<ConsensusParametersVersion>>;
+
     fn relayed_tx_status(
         &self,
         id: Bytes32,
     ) -> StorageResult<Option<RelayedTransactionStatus>>;
 
+    /// Returns the ids of the failed relayed transactions that spend inputs of the `owner`.
+    fn owned_relayed_transaction_ids(
+        &self,
+        owner: &Address,
+        start: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<Bytes32>>;
+
     fn message_is_spent(&self, nonce: &Nonce) -> StorageResult<bool>;
 
+    /// Returns the total number of messages spent on the chain.
+    fn spent_message_count(&self) -> StorageResult<u64>;
+
+    /// Returns the value of the `u64` statistic with the `name`, if it was ever recorded.
+    /// The caller is responsible for requesting only the statistics with the `u64` values.
+    fn u64_statistic(&self, name: &str) -> StorageResult<Option<u64>>;
+
+    /// Returns the fees collected by the block at the `height`.
+    fn block_fees(&self, height: &BlockHeight) -> StorageResult<CollectedFees>;
+
+    /// Returns the total fees collected by all blocks.
+    fn total_fees(&self) -> StorageResult<CollectedFees>;
+
     fn asset_info(&self, asset_id: &AssetId) -> StorageResult<Option<AssetDetails>>;
+
+    /// Returns the minted assets with their details in the order of asset ids.
+    fn assets_info(
+        &self,
+        start: Option<AssetId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(AssetId, AssetDetails)>>;
+
+    /// Returns the block where the `asset_id` was seen for the first time.
+    fn asset_first_seen(
+        &self,
+        asset_id: &AssetId,
+    ) -> StorageResult<Option<AssetFirstSeenInfo>>;
+
+    /// Returns the net changes of the supply of the `asset_id` per block.
+    fn asset_supply_changes(
+        &self,
+        asset_id: &AssetId,
+        start: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(BlockHeight, i128)>>;
+
+    /// Returns the supply of the `asset_id` at the start of the checkpoint interval
+    /// at the `height`, if the interval has changes of the supply.
+    fn asset_supply_checkpoint(
+        &self,
+        asset_id: &AssetId,
+        height: BlockHeight,
+    ) -> StorageResult<Option<u128>>;
+
+    /// Returns up to `limit` most active addresses by the number of transactions.
+    fn top_addresses_by_tx_count(
+        &self,
+        limit: usize,
+    ) -> StorageResult<Vec<AddressTxCount>>;
+
+    /// Returns the summary of the activity of the `owner`, if it was ever active.
+    fn owner_activity(&self, owner: &Address) -> StorageResult<Option<OwnerActivity>>;
+
+    /// Returns the height of the last block where the `owner` was active.
+    fn owner_last_activity(&self, owner: &Address) -> StorageResult<Option<BlockHeight>>;
+
+    /// Returns how the `owner` spent its coins or messages, if it ever spent them.
+    fn known_owner(&self, owner: &Address) -> StorageResult<Option<KnownOwner>>;
+
+    /// Returns the indexed balances of the `contract` ordered by the asset id.
+    fn contract_balances(
+        &self,
+        contract: ContractId,
+        start_asset: Option<AssetId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<ContractBalance>>;
+
+    /// Returns the hash folded over the key-value pairs of all columns in the canonical
+    /// order. The nodes with the same indexes at the same height have the same hash.
+    /// It reads the whole database.
+    fn offchain_state_hash(&self) -> StorageResult<Bytes32>;
 }
 
 /// The on chain database port expected by GraphQL API service.
@@ -184,6 +529,10 @@ pub trait DatabaseBlocks {
     /// Get a block by its height.
     fn block(&self, height: &BlockHeight) -> StorageResult<CompressedBlock>;
 
+    /// Get the header of a block by its height
+    /// without the ids of the transactions of the block.
+    fn block_header(&self, height: &BlockHeight) -> StorageResult<BlockHeader>;
+
     fn blocks(
         &self,
         height: Option<BlockHeight>,
@@ -257,6 +606,13 @@ pub trait TxPoolPort: Send + Sync {
     async fn insert(&self, txs: Transaction) -> anyhow::Result<()>;
 
     fn latest_pool_stats(&self) -> TxPoolStats;
+
+    /// Returns all transactions in the pool sorted in the `order`. The snapshot
+    /// is taken at once, the pool isn't blocked while the caller uses it.
+    async fn pending_transactions(
+        &self,
+        order: PendingTransactionsOrder,
+    ) -> anyhow::Result<Vec<TxInfo>>;
 }
 
 #[async_trait]
@@ -306,6 +662,73 @@ pub trait DatabaseMessageProof: Send + Sync {
     ) -> StorageResult<MerkleProof>;
 }
 
+/// The storage of the message proofs cached by the GraphQL service.
+/// The cache doesn't change the height of the database.
+pub trait MessageProofCacheStorage: Send + Sync {
+    /// Returns the cached proof of the message.
+    fn cached_message_proof(
+        &self,
+        key: &MessageProofCacheKey,
+    ) -> StorageResult<Option<MessageProof>>;
+
+    /// Returns the keys of the cached proofs in the order of their use.
+    fn message_proof_cache_entries(&self) -> StorageResult<MessageProofCacheEntries>;
+
+    /// Stores the `proof`, if any, removes the `evicted` proofs
+    /// and updates the order of use of the cached proofs.
+    fn update_message_proof_cache(
+        &self,
+        entries: &MessageProofCacheEntries,
+        proof: Option<(&MessageProofCacheKey, &MessageProof)>,
+        evicted: &[MessageProofCacheKey],
+    ) -> StorageResult<()>;
+}
+
+/// The number of entries removed from each off-chain index of the owner.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PrunedOwnerIndexes {
+    pub owned_coins: u64,
+    pub owned_message_ids: u64,
+    pub owned_transactions: u64,
+    pub owned_coin_counts: u64,
+    pub owner_activities: u64,
+}
+
+/// Removes the off-chain indexes of the owner. The on-chain state is untouched,
+/// so re-indexing the chain restores the removed entries.
+pub trait OwnerIndexesPruner: Send + Sync {
+    /// Removes all entries of the `owner` from the owner indexes in one transaction.
+    fn prune_owner(&self, owner: &Address) -> StorageResult<PrunedOwnerIndexes>;
+
+    /// Removes the `utxo_ids` of the `owner` from the owned coins index, if no block
+    /// was indexed after the `height`. Returns the number of removed entries.
+    fn remove_owned_coins(
+        &self,
+        owner: &Address,
+        utxo_ids: &[UtxoId],
+        height: BlockHeight,
+    ) -> StorageResult<u64>;
+}
+
+/// Attaches the off-chain tags to the transactions.
+pub trait TransactionTagger: Send + Sync {
+    /// Replaces the tag of the transaction with the `tag`.
+    fn tag_transaction(&self, tx_id: &TxId, tag: &[u8]) -> StorageResult<()>;
+}
+
+/// The state of the synchronization of the relayer with the DA layer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RelayerSyncState {
+    /// The DA block height the relayer synced to.
+    pub da_height: DaBlockHeight,
+    pub synced: bool,
+}
+
+pub trait RelayerSyncPort: Send + Sync {
+    /// Returns the state of the relayer, or `None` if the relayer is disabled.
+    fn relayer_sync_state(&self) -> Option<RelayerSyncState>;
+}
+
 #[async_trait::async_trait]
 pub trait P2pPort: Send + Sync {
     async fn all_peer_info(&self) -> anyhow::Result<Vec<PeerInfo>>;
@@ -327,45 +750,110 @@ pub trait MemoryPool {
 }
 
 pub mod worker {
-    use super::super::storage::blocks::FuelBlockIdsToHeights;
+    use super::super::storage::blocks::{
+        BlockFees,
+        BlockTransactionIds,
+        CollectedFees,
+        FuelBlockIdsToHeights,
+    };
     use crate::{
+        database::database_description::IndexationKind,
         fuel_core_graphql_api::storage::{
-            coins::OwnedCoins,
-            contracts::ContractsInfo,
+            blocks::BlocksByProducer,
+            coins::{
+                DerivedCoinOwners,
+                OwnedCoinCountByAsset,
+                OwnedCoins,
+                OwnedCoinsByAsset,
+                OwnedCoinsLayoutMetadata,
+                PredicateOwnedCoins,
+                ShardedOwnedCoins,
+                SpentCoin,
+                SpentCoins,
+                SpentCoinsByBlock,
+                SpentCoinsByHeight,
+            },
+            contracts::{
+                ContractBalances,
+                ContractsByBlock,
+                ContractsInfo,
+            },
             messages::{
+                MessagesBySender,
                 OwnedMessageIds,
                 SpentMessages,
             },
         },
         graphql_api::storage::{
-            assets::AssetsInfo,
+            assets::{
+                AssetFirstSeen,
+                AssetSupplyCheckpoints,
+                AssetSupplyHistory,
+                AssetsInfo,
+            },
             balances::{
                 CoinBalances,
                 MessageBalances,
             },
             coins::CoinsToSpendIndex,
-            da_compression::*,
+            da_compression::{
+                dictionaries::DaDictionary,
+                versioned_block_codec::DaBlockEncoding,
+                *,
+            },
+            dead_letter::IndexationDeadLetter,
+            known_owners::KnownPredicateOwners,
             old::{
                 OldFuelBlockConsensus,
                 OldFuelBlocks,
                 OldTransactions,
             },
-            relayed_transactions::RelayedTransactionStatuses,
+            owner_activity::{
+                OwnerActivities,
+                OwnerLastActivity,
+            },
+            relayed_transactions::{
+                OwnedRelayedTransactionIds,
+                RelayedTransactionStatusHeightKey,
+                RelayedTransactionStatusHeights,
+                RelayedTransactionStatuses,
+            },
+            statistic::CommitDurationMax,
+            transactions::{
+                ContractTransactions,
+                OwnedTransactionDirection,
+            },
+            upgrades::ConsensusParametersHistory,
+            Column,
         },
     };
     use derive_more::Display;
     use fuel_core_services::stream::BoxStream;
     use fuel_core_storage::{
+        kv_store::KeyValueInspect,
+        transactional::{
+            Changes,
+            Modifiable,
+        },
         Error as StorageError,
         Result as StorageResult,
+        StorageInspect,
         StorageMutate,
     };
     use fuel_core_types::{
+        entities::relayer::transaction::{
+            RelayedTransaction,
+            RelayedTransactionStatus,
+        },
         fuel_tx::{
             Address,
+            AssetId,
             Bytes32,
         },
-        fuel_types::BlockHeight,
+        fuel_types::{
+            BlockHeight,
+            Nonce,
+        },
         services::{
             block_importer::SharedImportResult,
             txpool::{
@@ -374,6 +862,10 @@ pub mod worker {
             },
         },
     };
+    use std::{
+        fmt::Debug,
+        sync::Arc,
+    };
 
     pub trait OnChainDatabase: Send + Sync {
         /// Returns the latest block height.
@@ -381,16 +873,36 @@ pub mod worker {
     }
 
     pub trait OffChainDatabase: Send + Sync {
+        /// The transaction is shared between the threads that derive the indexes
+        /// of the block, and receives their changes afterward.
         type Transaction<'a>: OffChainDatabaseTransaction
+            + KeyValueInspect<Column = Column>
+            + Modifiable
+            + Sync
         where
             Self: 'a;
 
-        /// Returns the latest block height.
+        /// The snapshot of the database. All reads from it observe the same
+        /// committed indexes, even if blocks are committed in the meantime.
+        type View: super::OffChainDatabase;
+
+        /// Returns the height of the last fully indexed block. It must be
+        /// committed together with the indexes of the block.
         fn latest_height(&self) -> StorageResult<Option<BlockHeight>>;
 
+        /// Returns the snapshot of the latest committed state. The reads spanning
+        /// several columns should be done from one view, so they can't interleave
+        /// with the commits of the blocks.
+        fn latest_view(&self) -> StorageResult<Self::View>;
+
         /// Creates a write database transaction.
         fn transaction(&mut self) -> Self::Transaction<'_>;
 
+        /// Commits the `changes` of several consecutive blocks at once. Each of them
+        /// is built on top of the previous ones. The height is advanced to the last
+        /// block only if the changes of all blocks are committed.
+        fn commit_blocks(&mut self, changes: Vec<Changes>) -> StorageResult<()>;
+
         /// Checks if Balances indexation functionality is available.
         fn balances_indexation_enabled(&self) -> StorageResult<bool>;
 
@@ -399,6 +911,59 @@ pub mod worker {
 
         /// Checks if AssetMetadata indexation functionality is available.
         fn asset_metadata_indexation_enabled(&self) -> StorageResult<bool>;
+
+        /// Checks if ContractBalances indexation functionality is available.
+        fn contract_balances_indexation_enabled(&self) -> StorageResult<bool>;
+
+        /// Checks if the number of owned coins per asset is available.
+        fn owned_coin_counts_indexation_enabled(&self) -> StorageResult<bool>;
+
+        /// Checks if the index of the `kind` is cleared and not rebuilt yet.
+        /// The stale indexation is not available until it is rebuilt.
+        fn indexation_stale(&self, kind: IndexationKind) -> StorageResult<bool>;
+
+        /// Returns the block that failed to be indexed, if any.
+        fn indexation_dead_letter(&self) -> StorageResult<Option<IndexationDeadLetter>>;
+
+        /// Records the block that failed to be indexed, or clears the record if
+        /// `dead_letter` is `None`. The record doesn't change the height of the database.
+        fn set_indexation_dead_letter(
+            &mut self,
+            dead_letter: Option<&IndexationDeadLetter>,
+        ) -> StorageResult<()>;
+
+        /// Returns up to `limit` statuses of the relayed transactions processed
+        /// in blocks below the `height`, in the order of the heights.
+        fn relayed_tx_statuses_before(
+            &self,
+            height: BlockHeight,
+            limit: usize,
+        ) -> StorageResult<Vec<RelayedTransactionStatusHeightKey>>;
+
+        /// Counts the transactions with the statuses of the included transactions.
+        /// It scans all statuses, so it is expensive.
+        fn included_tx_count(&self) -> StorageResult<u64>;
+
+        /// Overwrites the total number of transactions on the chain.
+        /// The write doesn't change the height of the database.
+        fn set_tx_count(&mut self, tx_count: u64) -> StorageResult<()>;
+
+        /// Returns the longest commit of the block indexes within the recent blocks, if any.
+        fn commit_duration_max(&self) -> StorageResult<Option<CommitDurationMax>>;
+
+        /// Records the longest commit of the block indexes within the recent blocks.
+        /// The record doesn't change the height of the database.
+        fn set_commit_duration_max(
+            &mut self,
+            max: &CommitDurationMax,
+        ) -> StorageResult<()>;
+
+        /// Returns the height of the last block compressed for the DA, if any.
+        fn da_compression_height(&self) -> StorageResult<Option<BlockHeight>>;
+
+        /// Commits the changes of the DA compression. The changes don't change
+        /// the height of the database, so the compression can lag behind the indexation.
+        fn commit_da_compression(&mut self, changes: Changes) -> StorageResult<()>;
     }
 
     /// Represents either the Genesis Block or a block at a specific height
@@ -412,18 +977,38 @@ pub mod worker {
 
     pub trait OffChainDatabaseTransaction:
         StorageMutate<OwnedMessageIds, Error = StorageError>
+        + StorageMutate<MessagesBySender, Error = StorageError>
         + StorageMutate<OwnedCoins, Error = StorageError>
+        + StorageMutate<ShardedOwnedCoins, Error = StorageError>
+        + StorageInspect<OwnedCoinsLayoutMetadata, Error = StorageError>
+        + StorageMutate<DerivedCoinOwners, Error = StorageError>
+        + StorageMutate<OwnedCoinCountByAsset, Error = StorageError>
+        + StorageMutate<OwnedCoinsByAsset, Error = StorageError>
+        + StorageMutate<PredicateOwnedCoins, Error = StorageError>
         + StorageMutate<FuelBlockIdsToHeights, Error = StorageError>
+        + StorageMutate<BlockTransactionIds, Error = StorageError>
         + StorageMutate<ContractsInfo, Error = StorageError>
+        + StorageMutate<ContractsByBlock, Error = StorageError>
+        + StorageMutate<BlocksByProducer, Error = StorageError>
+        + StorageMutate<BlockFees, Error = StorageError>
+        + StorageMutate<ContractBalances, Error = StorageError>
         + StorageMutate<OldFuelBlocks, Error = StorageError>
         + StorageMutate<OldFuelBlockConsensus, Error = StorageError>
         + StorageMutate<OldTransactions, Error = StorageError>
         + StorageMutate<SpentMessages, Error = StorageError>
+        + StorageMutate<SpentCoins, Error = StorageError>
+        + StorageMutate<SpentCoinsByHeight, Error = StorageError>
+        + StorageMutate<SpentCoinsByBlock, Error = StorageError>
         + StorageMutate<RelayedTransactionStatuses, Error = StorageError>
+        + StorageMutate<RelayedTransactionStatusHeights, Error = StorageError>
+        + StorageMutate<OwnedRelayedTransactionIds, Error = StorageError>
         + StorageMutate<CoinBalances, Error = StorageError>
         + StorageMutate<MessageBalances, Error = StorageError>
         + StorageMutate<CoinsToSpendIndex, Error = StorageError>
         + StorageMutate<DaCompressedBlocks, Error = StorageError>
+        + StorageMutate<DaCompressedBlockChecksums, Error = StorageError>
+        + StorageMutate<DaCompressedBlockStats, Error = StorageError>
+        + StorageMutate<DaCompressedBlockRegistrations, Error = StorageError>
         + StorageMutate<DaCompressionTemporalRegistryAddress, Error = StorageError>
         + StorageMutate<DaCompressionTemporalRegistryAssetId, Error = StorageError>
         + StorageMutate<DaCompressionTemporalRegistryContractId, Error = StorageError>
@@ -432,7 +1017,16 @@ pub mod worker {
         + StorageMutate<DaCompressionTemporalRegistryIndex, Error = StorageError>
         + StorageMutate<DaCompressionTemporalRegistryTimestamps, Error = StorageError>
         + StorageMutate<DaCompressionTemporalRegistryEvictorCache, Error = StorageError>
+        + StorageMutate<DaCompressionTemporalRegistryEntryCounts, Error = StorageError>
         + StorageMutate<AssetsInfo, Error = StorageError>
+        + StorageMutate<AssetSupplyHistory, Error = StorageError>
+        + StorageMutate<AssetSupplyCheckpoints, Error = StorageError>
+        + StorageMutate<AssetFirstSeen, Error = StorageError>
+        + StorageMutate<OwnerActivities, Error = StorageError>
+        + StorageMutate<OwnerLastActivity, Error = StorageError>
+        + StorageMutate<ContractTransactions, Error = StorageError>
+        + StorageMutate<KnownPredicateOwners, Error = StorageError>
+        + StorageMutate<ConsensusParametersHistory, Error = StorageError>
         + MaybeTemporalRegistryV2Bounds
     {
         fn record_tx_id_owner(
@@ -441,6 +1035,24 @@ pub mod worker {
             block_height: BlockHeight,
             tx_idx: u16,
             tx_id: &Bytes32,
+            direction: OwnedTransactionDirection,
+        ) -> StorageResult<()>;
+
+        /// Adds the numbers of transactions of the block to the top addresses
+        /// by the number of transactions. It is called once per block, so the list
+        /// is read and written once regardless of the number of transactions.
+        fn update_top_addresses(
+            &mut self,
+            tx_counts: &[(Address, u64)],
+        ) -> StorageResult<()>;
+
+        /// Records that the `owner` was active in the block at the `block_height`.
+        /// The height only moves forward, so the blocks indexed out of order
+        /// don't roll it back.
+        fn update_owner_last_activity(
+            &mut self,
+            owner: &Address,
+            block_height: BlockHeight,
         ) -> StorageResult<()>;
 
         fn update_tx_status(
@@ -449,13 +1061,94 @@ pub mod worker {
             status: txpool::TransactionExecutionStatus,
         ) -> StorageResult<Option<txpool::TransactionExecutionStatus>>;
 
+        /// Updates the statuses of the transactions at once, like the block does
+        /// when it is finalized. Returns the prior statuses in the order of the `updates`.
+        fn update_tx_statuses(
+            &mut self,
+            updates: &[(Bytes32, txpool::TransactionExecutionStatus)],
+        ) -> StorageResult<Vec<Option<txpool::TransactionExecutionStatus>>>;
+
         /// Update metadata about the total number of transactions on the chain.
         /// Returns the total count after the update.
         fn increase_tx_count(&mut self, new_txs_count: u64) -> StorageResult<u64>;
 
+        /// Marks the message with the `nonce` as spent. The number of spent messages
+        /// is incremented only if the message wasn't marked as spent before.
+        fn mark_message_spent(&mut self, nonce: &Nonce) -> StorageResult<()>;
+
+        /// Records the coins spent by the block at the `height` and tracks them
+        /// for the pruning.
+        fn record_spent_coins(
+            &mut self,
+            height: BlockHeight,
+            spent_coins: &[SpentCoin],
+        ) -> StorageResult<()>;
+
+        /// Removes the coins spent in blocks below the `height`. Returns the number
+        /// of removed coins.
+        fn prune_spent_coins_before(&mut self, height: BlockHeight)
+            -> StorageResult<u64>;
+
+        /// Inserts the status of the relayed transaction and tracks the height
+        /// of the block that processed it for the pruning.
+        fn insert_relayed_tx_status(
+            &mut self,
+            id: &Bytes32,
+            status: &RelayedTransactionStatus,
+        ) -> StorageResult<()>;
+
+        /// Removes the `expired` statuses of the relayed transactions.
+        /// Returns the number of removed statuses.
+        fn prune_relayed_statuses(
+            &mut self,
+            expired: &[RelayedTransactionStatusHeightKey],
+        ) -> StorageResult<u64>;
+
+        /// Records the net change of the supply of the asset in the block at the `height`.
+        /// The `supply_before` is the total supply of the asset before the block,
+        /// and becomes the checkpoint of the interval if it is the first change in it.
+        fn record_asset_supply_change(
+            &mut self,
+            asset_id: &AssetId,
+            height: BlockHeight,
+            delta: i128,
+            supply_before: u128,
+        ) -> StorageResult<()>;
+
+        /// Removes the changes of the supply history made in blocks below the `height`,
+        /// together with their checkpoints. The changes are removed by whole checkpoint
+        /// intervals, so the interval of the `height` is kept.
+        /// Returns the number of removed changes.
+        fn prune_asset_supply_history_before(
+            &mut self,
+            height: BlockHeight,
+        ) -> StorageResult<u64>;
+
         /// Gets the total number of transactions on the chain from metadata.
         fn get_tx_count(&self) -> StorageResult<u64>;
 
+        /// Records the `height` of the last block compressed for the DA.
+        fn set_da_compression_height(&mut self, height: BlockHeight)
+            -> StorageResult<()>;
+
+        /// Re-encodes the stored DA compressed block at the `height` with the `encoding`.
+        /// The Merkle tree of the blocks is built from the decoded blocks, so it is untouched.
+        /// Returns `false` if there is no block at the `height`.
+        fn encode_da_compressed_block(
+            &mut self,
+            height: &BlockHeight,
+            encoding: DaBlockEncoding,
+            dictionary: Option<&DaDictionary>,
+        ) -> StorageResult<bool>;
+
+        /// Stores the fees collected by the block at the `height` and adds them
+        /// to the total fees collected by all blocks. Returns the new total.
+        fn record_block_fees(
+            &mut self,
+            height: BlockHeight,
+            fees: &CollectedFees,
+        ) -> StorageResult<CollectedFees>;
+
         /// Commits the underlying changes into the database.
         fn commit(self) -> StorageResult<()>;
     }
@@ -467,6 +1160,7 @@ pub mod worker {
             address::DaCompressionTemporalRegistryAddressV2,
             asset_id::DaCompressionTemporalRegistryAssetIdV2,
             contract_id::DaCompressionTemporalRegistryContractIdV2,
+            entry_counts::DaCompressionTemporalRegistryEntryCountsV2,
             evictor_cache::DaCompressionTemporalRegistryEvictorCacheV2,
             predicate_code::DaCompressionTemporalRegistryPredicateCodeV2,
             registry_index::DaCompressionTemporalRegistryIndexV2,
@@ -486,6 +1180,9 @@ pub mod worker {
             + StorageMutate<
                 DaCompressionTemporalRegistryEvictorCacheV2,
                 Error = StorageError,
+            > + StorageMutate<
+                DaCompressionTemporalRegistryEntryCountsV2,
+                Error = StorageError,
             >
         {
         }
@@ -513,6 +1210,9 @@ pub mod worker {
                 > + StorageMutate<
                     DaCompressionTemporalRegistryEvictorCacheV2,
                     Error = StorageError,
+                > + StorageMutate<
+                    DaCompressionTemporalRegistryEntryCountsV2,
+                    Error = StorageError,
                 >
         {
         }
@@ -549,6 +1249,18 @@ pub mod worker {
             status: TransactionStatus,
         );
     }
+
+    /// Provides the relayed transactions processed by the blocks.
+    pub trait RelayedTransactionsProvider: Debug + Send + Sync {
+        /// Returns the transactions relayed after the DA height of the previous block
+        /// up to the DA height of the block at the `height`.
+        fn relayed_transactions(
+            &self,
+            height: &BlockHeight,
+        ) -> StorageResult<Vec<RelayedTransaction>>;
+    }
+
+    pub type RelayedTransactionsProviderRef = Arc<dyn RelayedTransactionsProvider>;
 }
 
 #[cfg_attr(feature = "test-helpers", mockall::automock)]
@@ -583,4 +1295,12 @@ pub trait OnChainDatabaseAt: Send + Sync {
     ) -> BoxedIter<StorageResult<ContractBalance>>;
 }
 
-pub trait OffChainDatabaseAt: Send + Sync {}
+pub trait OffChainDatabaseAt: Send + Sync {
+    /// Returns the balance of the owner from the balances index at the height of the view.
+    fn balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        base_asset_id: &AssetId,
+    ) -> StorageResult<TotalBalanceAmount>;
+}
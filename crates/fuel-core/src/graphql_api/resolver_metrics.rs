@@ -0,0 +1,67 @@
+//! The metrics of the GraphQL resolvers reading the storage.
+//!
+//! The resolver opts in by wrapping the future reading the storage into
+//! [`instrument`] with the name of the resolver. The calls, the failed calls,
+//! and the duration of the future are recorded per resolver, to find
+//! the queries dominating the load of the database.
+
+use fuel_core_metrics::graphql_metrics::graphql_metrics;
+use std::future::Future;
+use tokio::time::Instant;
+
+/// Awaits the `resolver` and records its duration and result under the `name`.
+pub(crate) async fn instrument<F, T, E>(name: &'static str, resolver: F) -> Result<T, E>
+where
+    F: Future<Output = Result<T, E>>,
+{
+    let start_time = Instant::now();
+    let result = resolver.await;
+    graphql_metrics().observe_storage_resolver(
+        name,
+        start_time.elapsed().as_secs_f64(),
+        result.is_err(),
+    );
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    fn metric_line(metrics: &str, name: &str) -> Option<String> {
+        metrics
+            .lines()
+            .find(|line| {
+                line.contains(name) && line.contains("resolver=\"test_resolver\"")
+            })
+            .map(str::to_string)
+    }
+
+    #[tokio::test]
+    async fn instrument__records_the_calls_and_the_errors_of_the_resolver() {
+        // given
+        let succeeded = async { Ok::<_, ()>(1) };
+        let failed = async { Err::<u8, _>(()) };
+
+        // when
+        let succeeded = instrument("test_resolver", succeeded).await;
+        let failed = instrument("test_resolver", failed).await;
+
+        // then
+        assert_eq!(succeeded, Ok(1));
+        assert_eq!(failed, Err(()));
+        let metrics = fuel_core_metrics::encode_metrics().unwrap();
+        let calls =
+            metric_line(&metrics, "graphql_storage_resolver_calls_total").unwrap();
+        let errors =
+            metric_line(&metrics, "graphql_storage_resolver_errors_total").unwrap();
+        assert!(calls.ends_with(" 2"), "{calls}");
+        assert!(errors.ends_with(" 1"), "{errors}");
+        assert!(
+            metric_line(&metrics, "graphql_storage_resolver_duration_seconds_count")
+                .is_some()
+        );
+    }
+}
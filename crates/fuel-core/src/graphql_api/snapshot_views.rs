@@ -0,0 +1,167 @@
+//! The views of the database pinned by the `snapshotView` query.
+//!
+//! The clients that read the state with several queries, like the balances and
+//! then the coins of the owner, may see the results of different blocks if
+//! a block is indexed between the queries. The pinned view is a snapshot of
+//! the on-chain and off-chain databases at the same block height, and all
+//! queries passing its token read from it. The view is released after the TTL,
+//! so the snapshots of the database don't outlive the clients.
+
+use crate::fuel_core_graphql_api::database::ReadView;
+use fuel_core_types::fuel_types::BlockHeight;
+use parking_lot::Mutex;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The token of the pinned view.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SnapshotToken {
+    pub token: String,
+    pub height: BlockHeight,
+    pub expires_at: Instant,
+}
+
+struct PinnedView<V> {
+    view: V,
+    height: BlockHeight,
+    expires_at: Instant,
+}
+
+/// The registry of the pinned views.
+#[derive(Clone)]
+pub struct SnapshotViews<V = ReadView> {
+    ttl: Duration,
+    capacity: usize,
+    views: Arc<Mutex<HashMap<String, PinnedView<V>>>>,
+}
+
+impl<V: Clone> SnapshotViews<V> {
+    pub fn new(ttl: Duration, capacity: usize) -> Self {
+        Self {
+            ttl,
+            capacity,
+            views: Default::default(),
+        }
+    }
+
+    /// Pins the `view` at the `height` until the TTL expires.
+    pub fn pin(&self, view: V, height: BlockHeight) -> anyhow::Result<SnapshotToken> {
+        self.pin_at(view, height, Instant::now())
+    }
+
+    fn pin_at(
+        &self,
+        view: V,
+        height: BlockHeight,
+        now: Instant,
+    ) -> anyhow::Result<SnapshotToken> {
+        if self.capacity == 0 {
+            return Err(anyhow::anyhow!("The snapshot views are disabled"))
+        }
+
+        let mut views = self.views.lock();
+        views.retain(|_, pinned| pinned.expires_at > now);
+        if views.len() >= self.capacity {
+            return Err(anyhow::anyhow!(
+                "Too many snapshot views are pinned, try again once some of them expire"
+            ))
+        }
+
+        let token = uuid::Uuid::new_v4().to_string();
+        let expires_at = now.checked_add(self.ttl).unwrap_or(now);
+        views.insert(
+            token.clone(),
+            PinnedView {
+                view,
+                height,
+                expires_at,
+            },
+        );
+        Ok(SnapshotToken {
+            token,
+            height,
+            expires_at,
+        })
+    }
+
+    /// Returns the view pinned under the `token`, or `None` if it is unknown or expired.
+    pub fn get(&self, token: &str) -> Option<(V, BlockHeight)> {
+        self.get_at(token, Instant::now())
+    }
+
+    fn get_at(&self, token: &str, now: Instant) -> Option<(V, BlockHeight)> {
+        let mut views = self.views.lock();
+        let pinned = views.get(token)?;
+        if pinned.expires_at <= now {
+            views.remove(token);
+            return None
+        }
+        Some((pinned.view.clone(), pinned.height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    const TTL: Duration = Duration::from_secs(30);
+
+    #[test]
+    fn get__returns_the_pinned_view_until_the_ttl_expires() {
+        // given
+        let views = SnapshotViews::<u32>::new(TTL, 10);
+        let now = Instant::now();
+        let expiry = now.checked_add(TTL).unwrap();
+        let token = views.pin_at(7, 100u32.into(), now).unwrap();
+
+        // when
+        let before_expiry = views.get_at(
+            &token.token,
+            expiry.checked_sub(Duration::from_secs(1)).unwrap(),
+        );
+        let after_expiry = views.get_at(&token.token, expiry);
+
+        // then
+        assert_eq!(before_expiry, Some((7, 100u32.into())));
+        assert_eq!(after_expiry, None);
+        assert_eq!(views.get_at(&token.token, now), None);
+    }
+
+    #[test]
+    fn pin__fails_when_the_capacity_is_reached_by_the_unexpired_views() {
+        // given
+        let views = SnapshotViews::<u32>::new(TTL, 2);
+        let now = Instant::now();
+        views.pin_at(1, 1u32.into(), now).unwrap();
+        views.pin_at(2, 2u32.into(), now).unwrap();
+
+        // when
+        let result = views.pin_at(3, 3u32.into(), now);
+        let result_after_expiry =
+            views.pin_at(3, 3u32.into(), now.checked_add(TTL).unwrap());
+
+        // then
+        assert!(result.is_err());
+        assert!(result_after_expiry.is_ok());
+    }
+
+    #[test]
+    fn get__returns_none_for_the_unknown_token() {
+        // given
+        let views = SnapshotViews::<u32>::new(TTL, 2);
+
+        // when
+        let result = views.get("unknown");
+
+        // then
+        assert_eq!(result, None);
+    }
+}
@@ -1,10 +1,47 @@
 use crate::{
+    database::database_description::CompressionPolicy,
     fuel_core_graphql_api::storage::{
-        blocks::FuelBlockIdsToHeights,
-        coins::OwnedCoins,
-        messages::OwnedMessageIds,
+        assets::{
+            asset_supply_checkpoint_height,
+            AssetSupplyCheckpoints,
+            AssetSupplyHistory,
+            AssetSupplyHistoryHeights,
+            AssetSupplyHistoryKey,
+        },
+        blocks::{
+            BlockFees,
+            CollectedFees,
+            FuelBlockIdsToHeights,
+        },
+        coins::{
+            OwnedCoins,
+            SpentCoin,
+            SpentCoins,
+            SpentCoinsByHeight,
+        },
+        da_compression::{
+            dictionaries::DaDictionary,
+            versioned_block_codec::{
+                da_block_payload,
+                encode_da_block,
+                DaBlockEncoding,
+            },
+            DaCompressedBlocks,
+        },
+        messages::{
+            OwnedMessageIds,
+            SpentMessages,
+        },
+        owner_activity::OwnerLastActivity,
+        relayed_transactions::{
+            RelayedTransactionStatusHeightKey,
+            RelayedTransactionStatusHeights,
+            RelayedTransactionStatuses,
+        },
         transactions::{
+            OwnedTransactionDirection,
             OwnedTransactionIndexKey,
+            OwnedTransactionValue,
             OwnedTransactions,
             TransactionStatuses,
         },
@@ -12,12 +49,23 @@ use crate::{
     graphql_api::ports::worker::OffChainDatabaseTransaction,
 };
 use fuel_core_storage::{
+    codec::{
+        postcard::Postcard,
+        primitive::Primitive,
+        Decode,
+        Encode,
+    },
     kv_store::{
         KeyValueInspect,
+        KeyValueMutate,
         StorageColumn,
+        WriteOperation,
     },
+    structured_storage::TableWithBlueprint,
     transactional::{
+        Changes,
         Modifiable,
+        StorageChanges,
         StorageTransaction,
     },
     Error as StorageError,
@@ -27,14 +75,22 @@ use fuel_core_storage::{
     StorageMutate,
 };
 use fuel_core_types::{
+    entities::relayer::transaction::RelayedTransactionStatus,
     fuel_tx::{
         Address,
+        AssetId,
         Bytes32,
     },
-    fuel_types::BlockHeight,
+    fuel_types::{
+        BlockHeight,
+        Nonce,
+    },
     services::txpool::TransactionExecutionStatus,
 };
-use statistic::StatisticTable;
+use statistic::{
+    StatisticTable,
+    TopAddressesByTxCount,
+};
 
 pub mod assets;
 pub mod balances;
@@ -42,15 +98,59 @@ pub mod blocks;
 pub mod coins;
 pub mod contracts;
 pub mod da_compression;
+pub mod dead_letter;
+pub mod known_owners;
 pub mod messages;
 pub mod old;
+pub mod owner_activity;
 pub mod statistic;
 pub mod transactions;
+pub mod upgrades;
 
 pub mod relayed_transactions;
+pub mod rollbacks;
+
 /// Tracks the total number of transactions written to the chain
 /// It's useful for analyzing TPS or other metrics.
-const TX_COUNT: &str = "total_tx_count";
+pub(crate) const TX_COUNT: &str = "total_tx_count";
+
+/// Accumulates the number of transactions added by the storage transaction.
+/// The delta is folded into the [`TX_COUNT`] during the commit, see [`merge_tx_count_delta`].
+pub(crate) const TX_COUNT_DELTA: &str = "total_tx_count_delta";
+
+/// Tracks the total number of messages spent on the chain.
+pub(crate) const SPENT_MESSAGE_COUNT: &str = "spent_message_count";
+
+/// The statistics with the `u64` values, exposed by the name via the GraphQL API.
+pub(crate) const U64_STATISTICS: [&str; 2] = [TX_COUNT, SPENT_MESSAGE_COUNT];
+
+/// Tracks the total fees in the base asset collected by all blocks.
+pub(crate) const TOTAL_FEES: &str = "total_fees";
+
+/// Tracks the most active addresses by the number of transactions.
+pub(crate) const TOP_ADDRESSES_BY_TX_COUNT: &str = "top_addresses_by_tx_count";
+
+/// Tracks the heights of the changes of the asset supply history to prune them.
+const ASSET_SUPPLY_HISTORY_HEIGHTS: &str = "asset_supply_history_heights";
+
+/// Tracks the lowest height whose spent coins aren't pruned yet.
+const SPENT_COINS_OLDEST_HEIGHT: &str = "spent_coins_oldest_height";
+
+/// The maximum number of heights whose spent coins are pruned with one block,
+/// so enabling the retention on a long chain doesn't stall the indexation.
+const MAX_PRUNED_SPENT_COINS_HEIGHTS: u32 = 1000;
+
+/// Tracks the height of the last block compressed for the DA.
+pub(crate) const DA_COMPRESSION_HEIGHT: &str = "da_compression_height";
+
+/// Tracks the block that the off-chain worker failed to index.
+pub(crate) const INDEXATION_DEAD_LETTER: &str = "indexation_dead_letter";
+
+/// Tracks the longest commit of the off-chain worker within the recent blocks.
+pub(crate) const OFF_CHAIN_COMMIT_DURATION_MAX: &str = "off_chain_commit_duration_max";
+
+/// Tracks the order of use of the cached message proofs to evict them.
+pub(crate) const MESSAGE_PROOF_CACHE_ENTRIES: &str = "message_proof_cache_entries";
 
 /// GraphQL database tables column ids to the corresponding [`fuel_core_storage::Mappable`] table.
 #[repr(u32)]
@@ -176,6 +276,79 @@ pub enum Column {
     DaCompressionTemporalRegistryEvictorCacheMerkleData = 49,
     #[cfg(feature = "fault-proving")]
     DaCompressionTemporalRegistryEvictorCacheMerkleMetadata = 50,
+    /// See [`OwnerActivities`](owner_activity::OwnerActivities)
+    OwnerActivity = 51,
+    /// See [`DaCompressedBlockChecksums`](da_compression::DaCompressedBlockChecksums)
+    DaCompressedBlockChecksums = 52,
+    /// See [`OwnedCoinCountByAsset`](coins::OwnedCoinCountByAsset)
+    OwnedCoinCountByAsset = 53,
+    /// See [`OwnedRelayedTransactionIds`](relayed_transactions::OwnedRelayedTransactionIds)
+    OwnedRelayedTransactionIds = 54,
+    /// See [`BlockTransactionIds`](blocks::BlockTransactionIds)
+    BlockTransactionIds = 55,
+    /// See [`AssetSupplyHistory`](assets::AssetSupplyHistory)
+    AssetSupplyHistory = 56,
+    /// See [`AssetSupplyCheckpoints`](assets::AssetSupplyCheckpoints)
+    AssetSupplyCheckpoints = 57,
+    /// See [`AssetFirstSeen`](assets::AssetFirstSeen)
+    AssetFirstSeen = 58,
+    /// See [`MessageProofCache`](messages::MessageProofCache)
+    MessageProofCache = 59,
+    /// See [`ContractBalances`](contracts::ContractBalances)
+    ContractBalances = 60,
+    /// See [`ContractTransactions`](transactions::ContractTransactions)
+    TransactionsByContract = 61,
+    /// See [`KnownPredicateOwners`](known_owners::KnownPredicateOwners)
+    KnownPredicateOwners = 62,
+    /// See [`DaCompressedBlockStats`](da_compression::DaCompressedBlockStats)
+    DaCompressedBlockStats = 63,
+    /// See [`DerivedCoinOwners`](coins::DerivedCoinOwners)
+    DerivedCoinOwners = 64,
+    /// See [`OwnerLastActivity`](owner_activity::OwnerLastActivity)
+    OwnerLastActivity = 65,
+    /// See [`ContractsByBlock`](contracts::ContractsByBlock)
+    ContractsByBlock = 66,
+    /// See [`OwnedCoinsByAsset`](coins::OwnedCoinsByAsset)
+    OwnedCoinsByAsset = 67,
+    /// See [`BlockFees`](blocks::BlockFees)
+    BlockFees = 68,
+    /// See [`DaCompressedBlockRegistrations`](da_compression::DaCompressedBlockRegistrations)
+    DaCompressedBlockRegistrations = 69,
+    /// See [`DaCompressedBlocksMerkleData`](da_compression::v2::compressed_blocks::DaCompressedBlocksMerkleData)
+    #[cfg(feature = "fault-proving")]
+    DaCompressedBlocksMerkleData = 70,
+    /// See [`DaCompressedBlocksMerkleMetadata`](da_compression::v2::compressed_blocks::DaCompressedBlocksMerkleMetadata)
+    #[cfg(feature = "fault-proving")]
+    DaCompressedBlocksMerkleMetadata = 71,
+    /// See [`ConsensusParametersHistory`](upgrades::ConsensusParametersHistory)
+    ConsensusParametersHistory = 72,
+    /// See [`DaCompressionDictionaries`](da_compression::dictionaries::DaCompressionDictionaries)
+    DaCompressionDictionaries = 73,
+    /// See [`MessagesBySender`](messages::MessagesBySender)
+    MessagesBySender = 74,
+    /// See [`BlocksByProducer`](blocks::BlocksByProducer)
+    BlocksByProducer = 75,
+    /// See [`SpentCoins`](coins::SpentCoins)
+    SpentCoins = 76,
+    /// See [`SpentCoinsByHeight`](coins::SpentCoinsByHeight)
+    SpentCoinsByHeight = 77,
+    /// See [`TransactionTags`](transactions::TransactionTags)
+    TransactionTags = 78,
+    /// See [`SpentCoinsByBlock`](coins::SpentCoinsByBlock)
+    SpentCoinsByBlock = 79,
+    /// See [`RelayedTransactionStatusHeights`](relayed_transactions::RelayedTransactionStatusHeights)
+    RelayedTransactionStatusHeights = 80,
+    /// See [`PredicateOwnedCoins`](coins::PredicateOwnedCoins)
+    PredicateOwnedCoins = 81,
+    /// See [`DaCompressionTemporalRegistryEntryCounts`](da_compression::DaCompressionTemporalRegistryEntryCounts)
+    DaCompressionTemporalRegistryEntryCounts = 82,
+    /// See [`DaCompressionTemporalRegistryEntryCountsV2`](da_compression::v2::entry_counts::DaCompressionTemporalRegistryEntryCountsV2)
+    #[cfg(feature = "fault-proving")]
+    DaCompressionTemporalRegistryEntryCountsV2 = 83,
+    #[cfg(feature = "fault-proving")]
+    DaCompressionTemporalRegistryEntryCountsMerkleData = 84,
+    #[cfg(feature = "fault-proving")]
+    DaCompressionTemporalRegistryEntryCountsMerkleMetadata = 85,
 }
 
 impl Column {
@@ -186,6 +359,35 @@ impl Column {
     pub fn as_u32(&self) -> u32 {
         *self as u32
     }
+
+    /// Returns the compression of the values of the column applied by the storage
+    /// backend. The bulk columns with large values that are rarely read are compressed
+    /// with zstd, while the hot indexes with small keys and values aren't compressed.
+    pub fn compression_policy(&self) -> CompressionPolicy {
+        match self {
+            Self::OldFuelBlocks | Self::OldTransactions | Self::DaCompressedBlocks => {
+                CompressionPolicy::Zstd
+            }
+            Self::OwnedCoins
+            | Self::OwnedCoinsByAsset
+            | Self::PredicateOwnedCoins
+            | Self::TransactionsByOwnerBlockIdx
+            | Self::OwnedMessageIds
+            | Self::FuelBlockIdsToHeights
+            | Self::SpentMessages
+            | Self::CoinBalances
+            | Self::MessageBalances
+            | Self::CoinsToSpend
+            | Self::OwnedCoinCountByAsset
+            | Self::DerivedCoinOwners
+            | Self::OwnerLastActivity
+            | Self::KnownPredicateOwners
+            | Self::MessagesBySender
+            | Self::BlocksByProducer
+            | Self::SpentCoinsByBlock => CompressionPolicy::None,
+            _ => CompressionPolicy::default(),
+        }
+    }
 }
 
 impl StorageColumn for Column {
@@ -199,6 +401,76 @@ impl StorageColumn for Column {
     }
 }
 
+/// Folds the [`TX_COUNT_DELTA`] written by the `changes` into the [`TX_COUNT`].
+/// It is called under the commit lock of the off-chain database with its state
+/// as the `storage`, so the deltas of the transactions built on the same state
+/// are summed instead of overwriting each other.
+///
+/// The total written by the `changes`, e.g. by the reconciliation, replaces
+/// the stored one. The delta is removed from the storage, so the delta left by
+/// the commits without the merge is folded by the next one.
+pub(crate) fn merge_tx_count_delta<S>(
+    storage: &S,
+    changes: StorageChanges,
+) -> StorageResult<StorageChanges>
+where
+    S: KeyValueInspect<Column = Column>,
+{
+    let mut changes = match changes {
+        StorageChanges::Changes(changes) => vec![changes],
+        StorageChanges::ChangesList(list) => list,
+    };
+    let column = Column::Statistic.id();
+    let total_key = Postcard::encode(TX_COUNT);
+    let delta_key = Postcard::encode(TX_COUNT_DELTA);
+
+    let mut delta = None;
+    let mut total = None;
+    for batch in changes.iter_mut() {
+        let Some(statistic) = batch.get_mut(&column) else {
+            continue
+        };
+        if let Some(operation) = statistic.remove(delta_key.as_ref()) {
+            delta = Some(decode_tx_count(operation)?);
+        }
+        if let Some(operation) = statistic.get(total_key.as_ref()) {
+            total = Some(decode_tx_count(operation.clone())?);
+        }
+    }
+
+    let Some(delta) = delta else {
+        return Ok(StorageChanges::ChangesList(changes))
+    };
+    let total = match total {
+        Some(total) => total,
+        None => storage
+            .get(total_key.as_ref(), Column::Statistic)?
+            .map(|value| Postcard::decode_from_value(value).map_err(StorageError::Codec))
+            .transpose()?
+            .unwrap_or_default(),
+    };
+
+    let mut merged = Changes::default();
+    let statistic = merged.entry(column).or_default();
+    statistic.insert(
+        total_key.to_vec().into(),
+        WriteOperation::Insert(Postcard::encode_as_value(&total.saturating_add(delta))),
+    );
+    statistic.insert(delta_key.to_vec().into(), WriteOperation::Remove);
+    changes.push(merged);
+
+    Ok(StorageChanges::ChangesList(changes))
+}
+
+fn decode_tx_count(operation: WriteOperation) -> StorageResult<u64> {
+    match operation {
+        WriteOperation::Insert(value) => {
+            Postcard::decode_from_value(value).map_err(StorageError::Codec)
+        }
+        WriteOperation::Remove => Ok(0),
+    }
+}
+
 impl<S> OffChainDatabaseTransaction for StorageTransaction<S>
 where
     S: KeyValueInspect<Column = Column> + Modifiable,
@@ -212,11 +484,48 @@ where
         block_height: BlockHeight,
         tx_idx: u16,
         tx_id: &Bytes32,
+        direction: OwnedTransactionDirection,
     ) -> StorageResult<()> {
         self.storage::<OwnedTransactions>().insert(
             &OwnedTransactionIndexKey::new(owner, block_height, tx_idx),
-            tx_id,
-        )
+            &OwnedTransactionValue {
+                tx_id: *tx_id,
+                direction,
+            },
+        )?;
+        self.update_owner_last_activity(owner, block_height)
+    }
+
+    fn update_top_addresses(
+        &mut self,
+        tx_counts: &[(Address, u64)],
+    ) -> StorageResult<()> {
+        if tx_counts.is_empty() {
+            return Ok(())
+        }
+        let mut top_addresses = self
+            .storage::<StatisticTable<TopAddressesByTxCount>>()
+            .get(TOP_ADDRESSES_BY_TX_COUNT)?
+            .unwrap_or_default()
+            .into_owned();
+        for (address, tx_count) in tx_counts {
+            top_addresses.record(address, *tx_count);
+        }
+        self.storage::<StatisticTable<TopAddressesByTxCount>>()
+            .insert(TOP_ADDRESSES_BY_TX_COUNT, &top_addresses)
+    }
+
+    fn update_owner_last_activity(
+        &mut self,
+        owner: &Address,
+        block_height: BlockHeight,
+    ) -> StorageResult<()> {
+        let last_activity = self.storage::<OwnerLastActivity>().get(owner)?;
+        if last_activity.is_some_and(|height| *height >= block_height) {
+            return Ok(());
+        }
+        self.storage::<OwnerLastActivity>()
+            .insert(owner, &block_height)
     }
 
     fn update_tx_status(
@@ -227,13 +536,214 @@ where
         self.storage::<TransactionStatuses>().replace(id, &status)
     }
 
+    fn update_tx_statuses(
+        &mut self,
+        updates: &[(Bytes32, TransactionExecutionStatus)],
+    ) -> StorageResult<Vec<Option<TransactionExecutionStatus>>> {
+        let mut statuses = self.storage::<TransactionStatuses>();
+        updates
+            .iter()
+            .map(|(id, status)| statuses.replace(id, status))
+            .collect()
+    }
+
     fn increase_tx_count(&mut self, new_txs_count: u64) -> StorageResult<u64> {
         // TODO: how should tx count be initialized after regenesis?
-        let current_tx_count: u64 = self.get_tx_count()?;
+        // Only the delta of this transaction is modified. The total is updated during
+        // the commit, so the transactions built on the same state don't overwrite it.
+        let delta = self
+            .storage::<StatisticTable<u64>>()
+            .get(TX_COUNT_DELTA)?
+            .unwrap_or_default()
+            .into_owned();
         // Using saturating_add because this value doesn't significantly impact the correctness of execution.
-        let new_tx_count = current_tx_count.saturating_add(new_txs_count);
-        <_ as StorageMutate<StatisticTable<u64>>>::insert(self, TX_COUNT, &new_tx_count)?;
-        Ok(new_tx_count)
+        <_ as StorageMutate<StatisticTable<u64>>>::insert(
+            self,
+            TX_COUNT_DELTA,
+            &delta.saturating_add(new_txs_count),
+        )?;
+        self.get_tx_count()
+    }
+
+    fn mark_message_spent(&mut self, nonce: &Nonce) -> StorageResult<()> {
+        let already_spent = self
+            .storage::<SpentMessages>()
+            .replace(nonce, &())?
+            .is_some();
+        if already_spent {
+            return Ok(());
+        }
+
+        let spent_message_count = self
+            .storage::<StatisticTable<u64>>()
+            .get(SPENT_MESSAGE_COUNT)?
+            .unwrap_or_default()
+            .into_owned();
+        <_ as StorageMutate<StatisticTable<u64>>>::insert(
+            self,
+            SPENT_MESSAGE_COUNT,
+            &spent_message_count.saturating_add(1),
+        )
+    }
+
+    fn record_spent_coins(
+        &mut self,
+        height: BlockHeight,
+        spent_coins: &[SpentCoin],
+    ) -> StorageResult<()> {
+        if spent_coins.is_empty() {
+            return Ok(());
+        }
+        for spent_coin in spent_coins {
+            self.storage::<SpentCoins>()
+                .insert(&spent_coin.coin.utxo_id, spent_coin)?;
+        }
+        let utxo_ids = spent_coins
+            .iter()
+            .map(|spent_coin| spent_coin.coin.utxo_id)
+            .collect::<Vec<_>>();
+        self.storage::<SpentCoinsByHeight>()
+            .insert(&height, &utxo_ids)?;
+
+        let tracked = self
+            .storage::<StatisticTable<BlockHeight>>()
+            .contains_key(SPENT_COINS_OLDEST_HEIGHT)?;
+        if !tracked {
+            self.storage::<StatisticTable<BlockHeight>>()
+                .insert(SPENT_COINS_OLDEST_HEIGHT, &height)?;
+        }
+        Ok(())
+    }
+
+    fn prune_spent_coins_before(&mut self, height: BlockHeight) -> StorageResult<u64> {
+        let Some(oldest) = self
+            .storage::<StatisticTable<BlockHeight>>()
+            .get(SPENT_COINS_OLDEST_HEIGHT)?
+        else {
+            return Ok(0);
+        };
+        let oldest = u32::from(*oldest);
+        let end =
+            u32::from(height).min(oldest.saturating_add(MAX_PRUNED_SPENT_COINS_HEIGHTS));
+        if oldest >= end {
+            return Ok(0);
+        }
+
+        let mut pruned = 0u64;
+        for pruned_height in oldest..end {
+            let Some(utxo_ids) = self
+                .storage::<SpentCoinsByHeight>()
+                .take(&pruned_height.into())?
+            else {
+                continue;
+            };
+            for utxo_id in utxo_ids.iter() {
+                self.storage::<SpentCoins>().remove(utxo_id)?;
+            }
+            pruned = pruned.saturating_add(utxo_ids.len() as u64);
+        }
+        self.storage::<StatisticTable<BlockHeight>>()
+            .insert(SPENT_COINS_OLDEST_HEIGHT, &end.into())?;
+        Ok(pruned)
+    }
+
+    fn insert_relayed_tx_status(
+        &mut self,
+        id: &Bytes32,
+        status: &RelayedTransactionStatus,
+    ) -> StorageResult<()> {
+        self.storage::<RelayedTransactionStatuses>()
+            .insert(id, status)?;
+        self.storage::<RelayedTransactionStatusHeights>().insert(
+            &RelayedTransactionStatusHeightKey {
+                block_height: status.block_height(),
+                id: *id,
+            },
+            &(),
+        )
+    }
+
+    fn prune_relayed_statuses(
+        &mut self,
+        expired: &[RelayedTransactionStatusHeightKey],
+    ) -> StorageResult<u64> {
+        let mut pruned = 0u64;
+        for key in expired {
+            // The key may be already pruned by the previous block of the batch.
+            if self
+                .storage::<RelayedTransactionStatusHeights>()
+                .take(key)?
+                .is_some()
+            {
+                self.storage::<RelayedTransactionStatuses>()
+                    .remove(&key.id)?;
+                pruned = pruned.saturating_add(1);
+            }
+        }
+        Ok(pruned)
+    }
+
+    fn record_asset_supply_change(
+        &mut self,
+        asset_id: &AssetId,
+        height: BlockHeight,
+        delta: i128,
+        supply_before: u128,
+    ) -> StorageResult<()> {
+        self.storage::<AssetSupplyHistory>()
+            .insert(&AssetSupplyHistoryKey::new(asset_id, height), &delta)?;
+
+        let checkpoint =
+            AssetSupplyHistoryKey::new(asset_id, asset_supply_checkpoint_height(height));
+        if !self
+            .storage::<AssetSupplyCheckpoints>()
+            .contains_key(&checkpoint)?
+        {
+            self.storage::<AssetSupplyCheckpoints>()
+                .insert(&checkpoint, &supply_before)?;
+        }
+
+        let mut heights = self
+            .storage::<StatisticTable<AssetSupplyHistoryHeights>>()
+            .get(ASSET_SUPPLY_HISTORY_HEIGHTS)?
+            .unwrap_or_default()
+            .into_owned();
+        heights.insert(asset_id, height);
+        self.storage::<StatisticTable<AssetSupplyHistoryHeights>>()
+            .insert(ASSET_SUPPLY_HISTORY_HEIGHTS, &heights)
+    }
+
+    fn prune_asset_supply_history_before(
+        &mut self,
+        height: BlockHeight,
+    ) -> StorageResult<u64> {
+        let Some(heights) = self
+            .storage::<StatisticTable<AssetSupplyHistoryHeights>>()
+            .get(ASSET_SUPPLY_HISTORY_HEIGHTS)?
+        else {
+            return Ok(0);
+        };
+        let mut heights = heights.into_owned();
+
+        // The supply at a change is derived from the checkpoint and all the changes
+        // of its interval, so the interval is either kept or pruned entirely.
+        let pruned = heights.take_before(asset_supply_checkpoint_height(height));
+        if pruned.is_empty() {
+            return Ok(0);
+        }
+
+        for key in pruned.iter() {
+            self.storage::<AssetSupplyHistory>().remove(key)?;
+            let checkpoint = AssetSupplyHistoryKey::new(
+                &key.asset_id,
+                asset_supply_checkpoint_height(key.block_height),
+            );
+            self.storage::<AssetSupplyCheckpoints>()
+                .remove(&checkpoint)?;
+        }
+        self.storage::<StatisticTable<AssetSupplyHistoryHeights>>()
+            .insert(ASSET_SUPPLY_HISTORY_HEIGHTS, &heights)?;
+        Ok(pruned.len() as u64)
     }
 
     fn get_tx_count(&self) -> StorageResult<u64> {
@@ -242,7 +752,52 @@ where
             .get(TX_COUNT)?
             .unwrap_or_default()
             .into_owned();
-        Ok(tx_count)
+        let delta = self
+            .storage::<StatisticTable<u64>>()
+            .get(TX_COUNT_DELTA)?
+            .unwrap_or_default()
+            .into_owned();
+        Ok(tx_count.saturating_add(delta))
+    }
+
+    fn set_da_compression_height(&mut self, height: BlockHeight) -> StorageResult<()> {
+        self.storage::<StatisticTable<BlockHeight>>()
+            .insert(DA_COMPRESSION_HEIGHT, &height)
+    }
+
+    fn encode_da_compressed_block(
+        &mut self,
+        height: &BlockHeight,
+        encoding: DaBlockEncoding,
+        dictionary: Option<&DaDictionary>,
+    ) -> StorageResult<bool> {
+        let column = DaCompressedBlocks::column();
+        let key = <Primitive<4> as Encode<BlockHeight>>::encode(height);
+        let Some(stored) = KeyValueInspect::get(self, key.as_ref(), column)? else {
+            return Ok(false)
+        };
+        let payload = da_block_payload(&stored)?;
+        let encoded = encode_da_block(&payload, encoding, dictionary)?;
+        KeyValueMutate::put(self, key.as_ref(), column, encoded.into())?;
+        Ok(true)
+    }
+
+    fn record_block_fees(
+        &mut self,
+        height: BlockHeight,
+        fees: &CollectedFees,
+    ) -> StorageResult<CollectedFees> {
+        self.storage::<BlockFees>().insert(&height, fees)?;
+
+        let mut total_fees = self
+            .storage::<StatisticTable<CollectedFees>>()
+            .get(TOTAL_FEES)?
+            .unwrap_or_default()
+            .into_owned();
+        total_fees.add(fees);
+        self.storage::<StatisticTable<CollectedFees>>()
+            .insert(TOTAL_FEES, &total_fees)?;
+        Ok(total_fees)
     }
 
     fn commit(self) -> StorageResult<()> {
@@ -250,3 +805,133 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::database::{
+        database_description::off_chain::OffChain,
+        Database,
+    };
+    use fuel_core_storage::transactional::{
+        IntoTransaction,
+        WriteTransaction,
+    };
+    use fuel_core_types::tai64::Tai64;
+
+    fn tx_count(database: &Database<OffChain>) -> u64 {
+        database.clone().into_transaction().get_tx_count().unwrap()
+    }
+
+    #[test]
+    fn increase_tx_count__interleaved_transactions_do_not_lose_increments() {
+        // given
+        let mut database = Database::<OffChain>::in_memory();
+        let transactions: Vec<Changes> = (1..=100u64)
+            .map(|count| {
+                let mut transaction = database.clone().into_transaction();
+                transaction.increase_tx_count(count).unwrap();
+                transaction.into_changes()
+            })
+            .collect();
+
+        // when
+        for changes in transactions {
+            database.commit_changes(changes).unwrap();
+        }
+
+        // then
+        assert_eq!(tx_count(&database), 5050);
+    }
+
+    #[test]
+    fn increase_tx_count__concurrent_commits_do_not_lose_increments() {
+        // given
+        const THREADS: u64 = 8;
+        const COMMITS: u64 = 100;
+        let database = Database::<OffChain>::in_memory();
+
+        // when
+        std::thread::scope(|scope| {
+            for _ in 0..THREADS {
+                let mut database = database.clone();
+                scope.spawn(move || {
+                    for _ in 0..COMMITS {
+                        let mut transaction = database.write_transaction();
+                        transaction.increase_tx_count(1).unwrap();
+                        transaction.commit().unwrap();
+                    }
+                });
+            }
+        });
+
+        // then
+        assert_eq!(tx_count(&database), THREADS.saturating_mul(COMMITS));
+    }
+
+    #[test]
+    fn increase_tx_count__continues_from_the_single_key_total() {
+        // given
+        let mut database = Database::<OffChain>::in_memory();
+        database
+            .storage_as_mut::<StatisticTable<u64>>()
+            .insert(TX_COUNT, &10)
+            .unwrap();
+
+        // when
+        let mut transaction = database.write_transaction();
+        let tx_count_in_transaction = transaction.increase_tx_count(5).unwrap();
+        transaction.commit().unwrap();
+
+        // then
+        assert_eq!(tx_count_in_transaction, 15);
+        let statistic = database.storage::<StatisticTable<u64>>();
+        let total = statistic
+            .get(TX_COUNT)
+            .unwrap()
+            .map(|total| total.into_owned());
+        assert_eq!(total, Some(15));
+        assert!(!statistic.contains_key(TX_COUNT_DELTA).unwrap());
+    }
+
+    #[test]
+    fn update_tx_statuses__applies_all_updates_and_returns_prior_statuses() {
+        // given
+        let mut database = Database::<OffChain>::in_memory();
+        let submitted = Bytes32::from([1; 32]);
+        let unknown = Bytes32::from([2; 32]);
+        let prior = TransactionExecutionStatus::Submitted {
+            time: Tai64::UNIX_EPOCH,
+        };
+        database
+            .storage_as_mut::<TransactionStatuses>()
+            .insert(&submitted, &prior)
+            .unwrap();
+        let status = |block_height: u32| TransactionExecutionStatus::Success {
+            block_height: block_height.into(),
+            time: Tai64::UNIX_EPOCH,
+            result: None,
+            receipts: vec![],
+            total_gas: 0,
+            total_fee: 0,
+        };
+        let updates = vec![(submitted, status(1)), (unknown, status(2))];
+
+        // when
+        let mut transaction = database.write_transaction();
+        let prior_statuses = transaction.update_tx_statuses(&updates).unwrap();
+        transaction.commit().unwrap();
+
+        // then
+        assert_eq!(prior_statuses, vec![Some(prior), None]);
+        for (id, status) in updates {
+            let stored = database
+                .storage::<TransactionStatuses>()
+                .get(&id)
+                .unwrap()
+                .map(|status| status.into_owned());
+            assert_eq!(stored, Some(status));
+        }
+    }
+}
@@ -1,16 +1,27 @@
 use fuel_core_storage::{
     blueprint::plain::Plain,
     codec::{
+        manual::Manual,
         postcard::Postcard,
         raw::Raw,
+        Decode,
+        Encode,
     },
     structured_storage::TableWithBlueprint,
     Mappable,
 };
-use fuel_core_types::fuel_tx::{
-    AssetId,
-    Bytes32,
-    ContractId,
+use fuel_core_types::{
+    fuel_tx::{
+        AssetId,
+        Bytes32,
+        ContractId,
+    },
+    fuel_types::BlockHeight,
+    tai64::Tai64,
+};
+use std::{
+    collections::VecDeque,
+    mem::size_of,
 };
 
 /// Asset info table to store information about the asset like total minted amounts,
@@ -40,7 +51,167 @@ impl TableWithBlueprint for AssetsInfo {
     }
 }
 
+/// The table of the blocks where assets were seen for the first time, either minted
+/// or received as a coin. The entry of the asset is written once and never updated.
+pub struct AssetFirstSeen;
+
+/// The block where the asset was seen for the first time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AssetFirstSeenInfo {
+    pub block_height: BlockHeight,
+    pub block_time: Tai64,
+}
+
+impl Mappable for AssetFirstSeen {
+    type Key = AssetId;
+    type OwnedKey = Self::Key;
+    type Value = Self::OwnedValue;
+    type OwnedValue = AssetFirstSeenInfo;
+}
+
+impl TableWithBlueprint for AssetFirstSeen {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::AssetFirstSeen
+    }
+}
+
+/// The number of blocks covered by one checkpoint of the supply history.
+pub const ASSET_SUPPLY_CHECKPOINT_INTERVAL: u32 = 256;
+
+/// Returns the height of the checkpoint that covers the `height`.
+pub fn asset_supply_checkpoint_height(height: BlockHeight) -> BlockHeight {
+    let height = u32::from(height);
+    height
+        .saturating_sub(height % ASSET_SUPPLY_CHECKPOINT_INTERVAL)
+        .into()
+}
+
+/// The table of the changes of the total supply of assets. Mints and burns of the asset
+/// within one block are coalesced into one change.
+pub struct AssetSupplyHistory;
+
+impl Mappable for AssetSupplyHistory {
+    type Key = AssetSupplyHistoryKey;
+    type OwnedKey = Self::Key;
+    /// The net change of the total supply in the block.
+    type Value = i128;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for AssetSupplyHistory {
+    type Blueprint = Plain<Manual<AssetSupplyHistoryKey>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::AssetSupplyHistory
+    }
+}
+
+/// The table of the total supply of assets at the start of each checkpoint interval
+/// with changes. The total supply at any change is the checkpoint of its interval
+/// plus the changes of the interval up to it, so it is computed without reading
+/// the whole history of the asset.
+pub struct AssetSupplyCheckpoints;
+
+impl Mappable for AssetSupplyCheckpoints {
+    /// The key with the height of the checkpoint.
+    type Key = AssetSupplyHistoryKey;
+    type OwnedKey = Self::Key;
+    type Value = u128;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for AssetSupplyCheckpoints {
+    type Blueprint = Plain<Manual<AssetSupplyHistoryKey>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::AssetSupplyCheckpoints
+    }
+}
+
+const ASSET_SUPPLY_HISTORY_KEY_SIZE: usize = AssetId::LEN + size_of::<BlockHeight>();
+
+/// The key of the supply history, ordered by the asset and then by the height.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AssetSupplyHistoryKey {
+    pub asset_id: AssetId,
+    pub block_height: BlockHeight,
+}
+
+impl AssetSupplyHistoryKey {
+    pub fn new(asset_id: &AssetId, block_height: BlockHeight) -> Self {
+        Self {
+            asset_id: *asset_id,
+            block_height,
+        }
+    }
+}
+
+impl Encode<AssetSupplyHistoryKey> for Manual<AssetSupplyHistoryKey> {
+    type Encoder<'a> = [u8; ASSET_SUPPLY_HISTORY_KEY_SIZE];
+
+    fn encode(t: &AssetSupplyHistoryKey) -> Self::Encoder<'_> {
+        let mut bytes = [0u8; ASSET_SUPPLY_HISTORY_KEY_SIZE];
+        bytes[..AssetId::LEN].copy_from_slice(t.asset_id.as_ref());
+        bytes[AssetId::LEN..].copy_from_slice(&t.block_height.to_bytes());
+        bytes
+    }
+}
+
+impl Decode<AssetSupplyHistoryKey> for Manual<AssetSupplyHistoryKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<AssetSupplyHistoryKey> {
+        let bytes: [u8; ASSET_SUPPLY_HISTORY_KEY_SIZE] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))?;
+        let (asset_id, block_height) = bytes.split_at(AssetId::LEN);
+        let asset_id: [u8; AssetId::LEN] =
+            asset_id.try_into().expect("It's an array of 32 bytes");
+        let block_height: [u8; 4] =
+            block_height.try_into().expect("It's an array of 4 bytes");
+
+        Ok(AssetSupplyHistoryKey {
+            asset_id: asset_id.into(),
+            block_height: u32::from_be_bytes(block_height).into(),
+        })
+    }
+}
+
+/// The changes of the supply history ordered by the height of the block. The history
+/// is keyed by the asset, so the order is used to find the changes older than
+/// the retention window without iterating over the table.
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub struct AssetSupplyHistoryHeights {
+    entries: VecDeque<AssetSupplyHistoryKey>,
+}
+
+impl AssetSupplyHistoryHeights {
+    /// Tracks the change of the supply of the `asset_id` at the `block_height`.
+    pub fn insert(&mut self, asset_id: &AssetId, block_height: BlockHeight) {
+        let position = self
+            .entries
+            .partition_point(|key| key.block_height <= block_height);
+        self.entries
+            .insert(position, AssetSupplyHistoryKey::new(asset_id, block_height));
+    }
+
+    /// Stops tracking the changes made before the `block_height` and returns their keys.
+    pub fn take_before(
+        &mut self,
+        block_height: BlockHeight,
+    ) -> Vec<AssetSupplyHistoryKey> {
+        let count = self
+            .entries
+            .partition_point(|key| key.block_height < block_height);
+        self.entries.drain(..count).collect()
+    }
+}
+
 #[cfg(test)]
+#[allow(non_snake_case)]
 mod test {
     use super::*;
 
@@ -49,4 +220,43 @@ mod test {
         <AssetsInfo as Mappable>::Key::default(),
         <AssetsInfo as Mappable>::Value::default()
     );
+
+    fuel_core_storage::basic_storage_tests!(
+        AssetFirstSeen,
+        <AssetFirstSeen as Mappable>::Key::default(),
+        AssetFirstSeenInfo {
+            block_height: Default::default(),
+            block_time: Tai64::UNIX_EPOCH,
+        }
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        AssetSupplyHistory,
+        <AssetSupplyHistory as Mappable>::Key::default(),
+        <AssetSupplyHistory as Mappable>::Value::default()
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        AssetSupplyCheckpoints,
+        <AssetSupplyCheckpoints as Mappable>::Key::default(),
+        <AssetSupplyCheckpoints as Mappable>::Value::default()
+    );
+
+    #[test]
+    fn asset_supply_checkpoint_height__rounds_down_to_the_interval() {
+        assert_eq!(asset_supply_checkpoint_height(0.into()), 0.into());
+        assert_eq!(
+            asset_supply_checkpoint_height(ASSET_SUPPLY_CHECKPOINT_INTERVAL.into()),
+            ASSET_SUPPLY_CHECKPOINT_INTERVAL.into()
+        );
+        assert_eq!(
+            asset_supply_checkpoint_height(
+                ASSET_SUPPLY_CHECKPOINT_INTERVAL
+                    .saturating_mul(2)
+                    .saturating_sub(1)
+                    .into()
+            ),
+            ASSET_SUPPLY_CHECKPOINT_INTERVAL.into()
+        );
+    }
 }
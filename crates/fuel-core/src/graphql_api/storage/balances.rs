@@ -1,3 +1,10 @@
+use fuel_core_chain_config::{
+    AddTable,
+    AsTable,
+    StateConfig,
+    StateConfigBuilder,
+    TableEntry,
+};
 use fuel_core_storage::{
     blueprint::plain::Plain,
     codec::{
@@ -39,6 +46,8 @@ impl core::fmt::Display for CoinBalancesKey {
 }
 
 /// This table stores the balances of coins per owner and asset id.
+/// It is the only source of the total value of the coins owned by the owner,
+/// so the `balance` and `ownedCoinValue` queries never disagree.
 pub struct CoinBalances;
 
 impl Mappable for CoinBalances {
@@ -57,7 +66,19 @@ impl TableWithBlueprint for CoinBalances {
     }
 }
 
-#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, PartialEq)]
+impl AsTable<CoinBalances> for StateConfig {
+    fn as_table(&self) -> Vec<TableEntry<CoinBalances>> {
+        Vec::new() // Do not include these for now
+    }
+}
+
+impl AddTable<CoinBalances> for StateConfigBuilder {
+    fn add(&mut self, _entries: Vec<TableEntry<CoinBalances>>) {
+        // Do not include these for now
+    }
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
 pub struct MessageBalance {
     pub retryable: TotalBalanceAmount,
     pub non_retryable: TotalBalanceAmount,
@@ -82,6 +103,18 @@ impl TableWithBlueprint for MessageBalances {
     }
 }
 
+impl AsTable<MessageBalances> for StateConfig {
+    fn as_table(&self) -> Vec<TableEntry<MessageBalances>> {
+        Vec::new() // Do not include these for now
+    }
+}
+
+impl AddTable<MessageBalances> for StateConfigBuilder {
+    fn add(&mut self, _entries: Vec<TableEntry<MessageBalances>>) {
+        // Do not include these for now
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
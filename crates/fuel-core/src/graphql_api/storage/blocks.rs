@@ -1,14 +1,22 @@
 use fuel_core_storage::{
     blueprint::plain::Plain,
     codec::{
+        manual::Manual,
+        postcard::Postcard,
         primitive::Primitive,
         raw::Raw,
+        Decode,
+        Encode,
     },
     structured_storage::TableWithBlueprint,
     Mappable,
 };
 use fuel_core_types::{
     blockchain::primitives::BlockId,
+    fuel_tx::{
+        Address,
+        Bytes32,
+    },
     fuel_types::BlockHeight,
 };
 
@@ -40,3 +48,210 @@ fuel_core_storage::basic_storage_tests!(
     <FuelBlockIdsToHeights as Mappable>::Key::default(),
     <FuelBlockIdsToHeights as Mappable>::Value::default()
 );
+
+/// The table of the transaction ids of the block at the `BlockHeight`.
+/// It allows to list the ids without decoding the transactions of the block.
+pub struct BlockTransactionIds;
+
+impl Mappable for BlockTransactionIds {
+    type Key = BlockHeight;
+    type OwnedKey = Self::Key;
+    /// The ids of the transactions in the order of the block.
+    type Value = Vec<Bytes32>;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for BlockTransactionIds {
+    type Blueprint = Plain<Primitive<4>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::BlockTransactionIds
+    }
+}
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    BlockTransactionIds,
+    <BlockTransactionIds as Mappable>::Key::default(),
+    vec![Bytes32::default()]
+);
+
+/// The fees in the base asset collected by the block producers.
+/// The tip is tracked separately from the base fee paid for the used gas.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+pub struct CollectedFees {
+    /// The fee paid for the gas, without the tips.
+    pub base_fee: u64,
+    /// The tips paid by the transactions on top of the base fee.
+    pub tip: u64,
+}
+
+impl CollectedFees {
+    /// Records the fees of the transaction, `total_fee` includes the `tip`.
+    pub fn record(&mut self, total_fee: u64, tip: u64) {
+        self.base_fee = self.base_fee.saturating_add(total_fee.saturating_sub(tip));
+        self.tip = self.tip.saturating_add(tip.min(total_fee));
+    }
+
+    /// Adds the fees collected by the other block.
+    pub fn add(&mut self, other: &Self) {
+        self.base_fee = self.base_fee.saturating_add(other.base_fee);
+        self.tip = self.tip.saturating_add(other.tip);
+    }
+
+    /// The sum of the base fee and the tips.
+    pub fn total(&self) -> u64 {
+        self.base_fee.saturating_add(self.tip)
+    }
+}
+
+/// The table of the fees collected by the block at the `BlockHeight`.
+pub struct BlockFees;
+
+impl Mappable for BlockFees {
+    type Key = BlockHeight;
+    type OwnedKey = Self::Key;
+    /// The fees collected by the block.
+    type Value = CollectedFees;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for BlockFees {
+    type Blueprint = Plain<Primitive<4>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::BlockFees
+    }
+}
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    BlockFees,
+    <BlockFees as Mappable>::Key::default(),
+    CollectedFees::default()
+);
+
+/// The heights of the blocks by the address of their producer, recovered from
+/// the consensus signature. The genesis block and the blocks with the producer
+/// that can't be recovered are indexed under the [`UNKNOWN_BLOCK_PRODUCER`].
+pub struct BlocksByProducer;
+
+/// The producer under which the blocks without the recoverable producer are indexed.
+pub const UNKNOWN_BLOCK_PRODUCER: Address = Address::zeroed();
+
+impl Mappable for BlocksByProducer {
+    type Key = Self::OwnedKey;
+    type OwnedKey = BlocksByProducerKey;
+    type Value = Self::OwnedValue;
+    type OwnedValue = ();
+}
+
+impl TableWithBlueprint for BlocksByProducer {
+    type Blueprint = Plain<Manual<BlocksByProducerKey>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::BlocksByProducer
+    }
+}
+
+/// The producer of the block and the height of the block.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BlocksByProducerKey {
+    pub producer: Address,
+    pub height: BlockHeight,
+}
+
+impl BlocksByProducerKey {
+    const SIZE: usize = Address::LEN + 4;
+}
+
+impl Encode<BlocksByProducerKey> for Manual<BlocksByProducerKey> {
+    type Encoder<'a> = [u8; BlocksByProducerKey::SIZE];
+
+    fn encode(key: &BlocksByProducerKey) -> Self::Encoder<'_> {
+        let mut bytes = [0u8; BlocksByProducerKey::SIZE];
+        bytes[..Address::LEN].copy_from_slice(key.producer.as_ref());
+        bytes[Address::LEN..].copy_from_slice(&key.height.to_bytes());
+        bytes
+    }
+}
+
+impl Decode<BlocksByProducerKey> for Manual<BlocksByProducerKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<BlocksByProducerKey> {
+        if bytes.len() != BlocksByProducerKey::SIZE {
+            return Err(anyhow::anyhow!("Unable to decode bytes"))
+        }
+        let (producer, height) = bytes.split_at(Address::LEN);
+        let producer = Address::try_from(producer)
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))?;
+        let height: [u8; 4] = height
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))?;
+        Ok(BlocksByProducerKey {
+            producer,
+            height: u32::from_be_bytes(height).into(),
+        })
+    }
+}
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    BlocksByProducer,
+    <BlocksByProducer as Mappable>::Key::default(),
+    <BlocksByProducer as Mappable>::Value::default()
+);
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record__splits_the_total_fee_into_the_base_fee_and_the_tip() {
+        // given
+        let mut fees = CollectedFees::default();
+
+        // when
+        fees.record(100, 30);
+        fees.record(50, 0);
+
+        // then
+        assert_eq!(
+            fees,
+            CollectedFees {
+                base_fee: 120,
+                tip: 30,
+            }
+        );
+        assert_eq!(fees.total(), 150);
+    }
+
+    #[test]
+    fn blocks_by_producer_key__is_ordered_by_the_producer_and_the_height() {
+        // given
+        let key = |producer: u8, height: u32| BlocksByProducerKey {
+            producer: Address::from([producer; 32]),
+            height: height.into(),
+        };
+        let keys = [key(1, 256), key(1, 1), key(2, 0)];
+
+        // when
+        let mut encoded = keys
+            .iter()
+            .map(|key| Manual::<BlocksByProducerKey>::encode(key))
+            .collect::<Vec<_>>();
+        encoded.sort();
+
+        // then
+        let decoded = encoded
+            .iter()
+            .map(|bytes| Manual::<BlocksByProducerKey>::decode(bytes).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(decoded, vec![key(1, 1), key(1, 256), key(2, 0)]);
+    }
+}
@@ -1,43 +1,57 @@
 mod codecs;
 
-use crate::fuel_core_graphql_api::{
-    indexation::coins_to_spend::{
-        NON_RETRYABLE_BYTE,
-        RETRYABLE_BYTE,
-    },
-    storage::coins::codecs::UTXO_ID_SIZE,
+use super::balances::CoinBalancesKey;
+use crate::fuel_core_graphql_api::indexation::coins_to_spend::{
+    NON_RETRYABLE_BYTE,
+    RETRYABLE_BYTE,
+};
+use fuel_core_chain_config::{
+    AddTable,
+    AsTable,
+    StateConfig,
+    StateConfigBuilder,
+    TableEntry,
 };
 use fuel_core_storage::{
     blueprint::plain::Plain,
     codec::{
         manual::Manual,
         postcard::Postcard,
-        primitive::utxo_id_to_bytes,
+        primitive::{
+            utxo_id_to_bytes,
+            Primitive,
+        },
         raw::Raw,
     },
     structured_storage::TableWithBlueprint,
+    Error as StorageError,
     Mappable,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+    StorageInspect,
+    StorageMutate,
 };
 use fuel_core_types::{
     entities::{
         coins::coin::Coin,
         Message,
     },
+    fuel_crypto::Hasher,
     fuel_tx::{
         Address,
         AssetId,
         TxId,
         UtxoId,
     },
-    fuel_types::Nonce,
+    fuel_types::{
+        BlockHeight,
+        Nonce,
+    },
 };
 
 pub fn owner_coin_id_key(owner: &Address, coin_id: &UtxoId) -> OwnedCoinKey {
-    let mut default = [0u8; Address::LEN + UTXO_ID_SIZE];
-    default[0..Address::LEN].copy_from_slice(owner.as_ref());
-    let utxo_id_bytes: [u8; UTXO_ID_SIZE] = utxo_id_to_bytes(coin_id);
-    default[Address::LEN..].copy_from_slice(utxo_id_bytes.as_ref());
-    default
+    OwnedCoinKey::new(owner, coin_id)
 }
 
 /// The storage table for the index of coins to spend.
@@ -59,7 +73,29 @@ impl TableWithBlueprint for CoinsToSpendIndex {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+impl AsTable<CoinsToSpendIndex> for StateConfig {
+    fn as_table(&self) -> Vec<TableEntry<CoinsToSpendIndex>> {
+        Vec::new() // Do not include these for now
+    }
+}
+
+impl AddTable<CoinsToSpendIndex> for StateConfigBuilder {
+    fn add(&mut self, _entries: Vec<TableEntry<CoinsToSpendIndex>>) {
+        // Do not include these for now
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+)]
 pub enum CoinsToSpendIndexKey {
     Coin {
         owner: Address,
@@ -145,18 +181,179 @@ impl CoinsToSpendIndexKey {
 
 /// The storage table of owned coin ids. Maps addresses to owned coins.
 pub struct OwnedCoins;
+
 /// The storage key for owned coins: `Address ++ UtxoId`
-pub type OwnedCoinKey = [u8; Address::LEN + TxId::LEN + 2];
+#[derive(
+    Default,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct OwnedCoinKey {
+    pub owner: Address,
+    pub utxo_id: UtxoId,
+}
+
+impl OwnedCoinKey {
+    pub fn new(owner: &Address, utxo_id: &UtxoId) -> Self {
+        Self {
+            owner: *owner,
+            utxo_id: *utxo_id,
+        }
+    }
+}
+
+/// The state of the coin in the [`OwnedCoins`] table.
+///
+/// The spent coins are removed from the table right away by default. If
+/// the spent coins are flagged instead, the removal is deferred to the
+/// compaction of the table, so the hot prefixes of the owners don't collect
+/// the tombstones of RocksDB. The flagged coins are never returned by queries.
+#[derive(
+    Default, Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+pub enum OwnedCoinState {
+    #[default]
+    Unspent,
+    /// The coin was spent in the block at the `height`.
+    Spent { height: BlockHeight },
+}
+
+impl OwnedCoinState {
+    pub fn is_spent(&self) -> bool {
+        matches!(self, OwnedCoinState::Spent { .. })
+    }
+}
 
 impl Mappable for OwnedCoins {
     type Key = Self::OwnedKey;
     type OwnedKey = OwnedCoinKey;
     type Value = Self::OwnedValue;
-    type OwnedValue = ();
+    type OwnedValue = OwnedCoinState;
 }
 
 impl TableWithBlueprint for OwnedCoins {
-    type Blueprint = Plain<Raw, Postcard>;
+    type Blueprint = Plain<Manual<OwnedCoinKey>, Manual<OwnedCoinState>>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::OwnedCoins
+    }
+}
+
+impl AsTable<OwnedCoins> for StateConfig {
+    fn as_table(&self) -> Vec<TableEntry<OwnedCoins>> {
+        Vec::new() // Do not include these for now
+    }
+}
+
+impl AddTable<OwnedCoins> for StateConfigBuilder {
+    fn add(&mut self, _entries: Vec<TableEntry<OwnedCoins>>) {
+        // Do not include these for now
+    }
+}
+
+/// The layout of the keys of the [`OwnedCoins`] column, chosen at the creation
+/// of the database and recorded in the [`OwnedCoinsLayoutMetadata`].
+#[derive(
+    Default,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum_macros::Display,
+    strum_macros::EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum OwnedCoinsLayout {
+    /// The keys are `Address ++ UtxoId`, see [`OwnedCoins`].
+    #[default]
+    Plain,
+    /// The keys are `Address ++ shard ++ UtxoId`, see [`ShardedOwnedCoins`].
+    Sharded,
+}
+
+impl OwnedCoinsLayout {
+    /// Returns the size of the keys of the [`OwnedCoins`] column in the layout.
+    pub fn key_size(&self) -> usize {
+        match self {
+            OwnedCoinsLayout::Plain => codecs::OWNED_COIN_KEY_SIZE,
+            OwnedCoinsLayout::Sharded => codecs::SHARDED_OWNED_COIN_KEY_SIZE,
+        }
+    }
+}
+
+/// The number of the sub-prefixes of the owner in the sharded layout.
+pub const OWNED_COINS_SHARDS: u8 = 16;
+
+/// Returns the shard of the coin in the sharded layout of the [`OwnedCoins`] column.
+pub fn owned_coin_shard(utxo_id: &UtxoId) -> u8 {
+    Hasher::hash(utxo_id_to_bytes(utxo_id))[0].wrapping_rem(OWNED_COINS_SHARDS)
+}
+
+/// The storage table of owned coin ids in the sharded layout. It shares the
+/// [`OwnedCoins`] column, but the owner prefix is followed by the shard of the coin,
+/// so the writes of the owner with many coins are spread across the sub-prefixes.
+pub struct ShardedOwnedCoins;
+
+/// The storage key for owned coins in the sharded layout: `Address ++ shard ++ UtxoId`
+#[derive(
+    Default,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct ShardedOwnedCoinKey {
+    pub owner: Address,
+    pub shard: u8,
+    pub utxo_id: UtxoId,
+}
+
+impl From<&OwnedCoinKey> for ShardedOwnedCoinKey {
+    fn from(key: &OwnedCoinKey) -> Self {
+        Self {
+            owner: key.owner,
+            shard: owned_coin_shard(&key.utxo_id),
+            utxo_id: key.utxo_id,
+        }
+    }
+}
+
+impl From<ShardedOwnedCoinKey> for OwnedCoinKey {
+    fn from(key: ShardedOwnedCoinKey) -> Self {
+        Self {
+            owner: key.owner,
+            utxo_id: key.utxo_id,
+        }
+    }
+}
+
+impl Mappable for ShardedOwnedCoins {
+    type Key = Self::OwnedKey;
+    type OwnedKey = ShardedOwnedCoinKey;
+    type Value = Self::OwnedValue;
+    type OwnedValue = OwnedCoinState;
+}
+
+impl TableWithBlueprint for ShardedOwnedCoins {
+    type Blueprint = Plain<Manual<ShardedOwnedCoinKey>, Manual<OwnedCoinState>>;
     type Column = super::Column;
 
     fn column() -> Self::Column {
@@ -164,6 +361,325 @@ impl TableWithBlueprint for OwnedCoins {
     }
 }
 
+/// The key of the [`OwnedCoinsLayoutMetadata`] in the metadata column. The key
+/// of the database metadata is encoded as the empty bytes, so they don't overlap.
+pub const OWNED_COINS_LAYOUT_KEY: [u8; 18] = *b"owned_coins_layout";
+
+/// The layout of the [`OwnedCoins`] column recorded at the creation of the database.
+/// The databases created before the layout was recorded use the plain layout.
+pub struct OwnedCoinsLayoutMetadata;
+
+impl Mappable for OwnedCoinsLayoutMetadata {
+    type Key = [u8; 18];
+    type OwnedKey = Self::Key;
+    type Value = OwnedCoinsLayout;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for OwnedCoinsLayoutMetadata {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::Metadata
+    }
+}
+
+/// Returns the layout of the [`OwnedCoins`] column of the `storage`.
+pub fn owned_coins_layout<T>(storage: &T) -> StorageResult<OwnedCoinsLayout>
+where
+    T: StorageInspect<OwnedCoinsLayoutMetadata, Error = StorageError>,
+{
+    let layout = storage
+        .storage::<OwnedCoinsLayoutMetadata>()
+        .get(&OWNED_COINS_LAYOUT_KEY)?
+        .map(|layout| *layout)
+        .unwrap_or_default();
+    Ok(layout)
+}
+
+/// Inserts the owned coin into the [`OwnedCoins`] column with the `layout`.
+pub fn insert_owned_coin<T>(
+    storage: &mut T,
+    layout: OwnedCoinsLayout,
+    key: &OwnedCoinKey,
+    state: &OwnedCoinState,
+) -> StorageResult<()>
+where
+    T: StorageMutate<OwnedCoins, Error = StorageError>
+        + StorageMutate<ShardedOwnedCoins, Error = StorageError>,
+{
+    match layout {
+        OwnedCoinsLayout::Plain => {
+            storage.storage_as_mut::<OwnedCoins>().insert(key, state)
+        }
+        OwnedCoinsLayout::Sharded => storage
+            .storage_as_mut::<ShardedOwnedCoins>()
+            .insert(&key.into(), state),
+    }
+}
+
+/// Removes the owned coin from the [`OwnedCoins`] column with the `layout`
+/// and returns its state.
+pub fn take_owned_coin<T>(
+    storage: &mut T,
+    layout: OwnedCoinsLayout,
+    key: &OwnedCoinKey,
+) -> StorageResult<Option<OwnedCoinState>>
+where
+    T: StorageMutate<OwnedCoins, Error = StorageError>
+        + StorageMutate<ShardedOwnedCoins, Error = StorageError>,
+{
+    match layout {
+        OwnedCoinsLayout::Plain => storage.storage_as_mut::<OwnedCoins>().take(key),
+        OwnedCoinsLayout::Sharded => storage
+            .storage_as_mut::<ShardedOwnedCoins>()
+            .take(&key.into()),
+    }
+}
+
+/// Removes the spent coin from the [`OwnedCoins`], or flags it as spent
+/// at the `height` if `mark_spent` is set.
+pub fn spend_owned_coin<T>(
+    storage: &mut T,
+    layout: OwnedCoinsLayout,
+    key: &OwnedCoinKey,
+    height: BlockHeight,
+    mark_spent: bool,
+) -> StorageResult<()>
+where
+    T: StorageMutate<OwnedCoins, Error = StorageError>
+        + StorageMutate<ShardedOwnedCoins, Error = StorageError>,
+{
+    if mark_spent {
+        insert_owned_coin(storage, layout, key, &OwnedCoinState::Spent { height })
+    } else {
+        take_owned_coin(storage, layout, key).map(|_| ())
+    }
+}
+
+/// The storage table of owned coin ids grouped by the asset id. It is maintained
+/// together with [`OwnedCoins`] for the coins owned directly, so the coins of the
+/// owner with the asset id are found without loading the other coins of the owner.
+pub struct OwnedCoinsByAsset;
+
+/// The storage key for owned coins by asset: `Address ++ AssetId ++ UtxoId`
+#[derive(
+    Default,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct OwnedCoinByAssetKey {
+    pub owner: Address,
+    pub asset_id: AssetId,
+    pub utxo_id: UtxoId,
+}
+
+impl OwnedCoinByAssetKey {
+    pub fn new(owner: &Address, asset_id: &AssetId, utxo_id: &UtxoId) -> Self {
+        Self {
+            owner: *owner,
+            asset_id: *asset_id,
+            utxo_id: *utxo_id,
+        }
+    }
+
+    /// The prefix of the keys of the coins of the `owner` with the `asset_id`.
+    pub fn prefix(
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> [u8; Address::LEN + AssetId::LEN] {
+        let mut prefix = [0u8; Address::LEN + AssetId::LEN];
+        prefix[..Address::LEN].copy_from_slice(owner.as_ref());
+        prefix[Address::LEN..].copy_from_slice(asset_id.as_ref());
+        prefix
+    }
+}
+
+impl Mappable for OwnedCoinsByAsset {
+    type Key = Self::OwnedKey;
+    type OwnedKey = OwnedCoinByAssetKey;
+    type Value = Self::OwnedValue;
+    type OwnedValue = ();
+}
+
+impl TableWithBlueprint for OwnedCoinsByAsset {
+    type Blueprint = Plain<Manual<OwnedCoinByAssetKey>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::OwnedCoinsByAsset
+    }
+}
+
+/// The storage table of the coins owned by the predicates. The worker flags the coin
+/// when it is created if its owner is known to be a predicate from the inputs of this
+/// or an earlier transaction, see [`KnownPredicateOwners`](super::known_owners::KnownPredicateOwners).
+/// The coins received before the first spend of the owner with the predicate aren't flagged.
+/// The flag is removed together with the coin once it is spent.
+pub struct PredicateOwnedCoins;
+
+impl Mappable for PredicateOwnedCoins {
+    type Key = Self::OwnedKey;
+    type OwnedKey = OwnedCoinKey;
+    type Value = Self::OwnedValue;
+    type OwnedValue = ();
+}
+
+impl TableWithBlueprint for PredicateOwnedCoins {
+    type Blueprint = Plain<Manual<OwnedCoinKey>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::PredicateOwnedCoins
+    }
+}
+
+/// The storage table of the logical owners of the coins. The coin is indexed
+/// in the [`OwnedCoins`] under each of its logical owners, and the table marks
+/// these entries as derived, so they are told apart from the coins owned directly.
+pub struct DerivedCoinOwners;
+
+impl Mappable for DerivedCoinOwners {
+    type Key = Self::OwnedKey;
+    type OwnedKey = UtxoId;
+    type Value = Self::OwnedValue;
+    type OwnedValue = Vec<Address>;
+}
+
+impl TableWithBlueprint for DerivedCoinOwners {
+    type Blueprint = Plain<Primitive<34>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::DerivedCoinOwners
+    }
+}
+
+impl AsTable<DerivedCoinOwners> for StateConfig {
+    fn as_table(&self) -> Vec<TableEntry<DerivedCoinOwners>> {
+        Vec::new() // Do not include these for now
+    }
+}
+
+impl AddTable<DerivedCoinOwners> for StateConfigBuilder {
+    fn add(&mut self, _entries: Vec<TableEntry<DerivedCoinOwners>>) {
+        // Do not include these for now
+    }
+}
+
+/// The storage table of the number of coins per owner and asset id.
+/// It is maintained together with [`OwnedCoins`]. The coins indexed before
+/// the table existed are not counted.
+pub struct OwnedCoinCountByAsset;
+
+impl Mappable for OwnedCoinCountByAsset {
+    type Key = Self::OwnedKey;
+    type OwnedKey = CoinBalancesKey;
+    type Value = Self::OwnedValue;
+    type OwnedValue = u64;
+}
+
+impl TableWithBlueprint for OwnedCoinCountByAsset {
+    type Blueprint = Plain<Raw, Primitive<8>>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::OwnedCoinCountByAsset
+    }
+}
+
+/// The storage table of the coins spent by the indexed blocks, with the transaction
+/// that spent them. The coins are pruned together with the other historical data.
+pub struct SpentCoins;
+
+impl Mappable for SpentCoins {
+    type Key = Self::OwnedKey;
+    type OwnedKey = UtxoId;
+    type Value = Self::OwnedValue;
+    type OwnedValue = SpentCoin;
+}
+
+impl TableWithBlueprint for SpentCoins {
+    type Blueprint = Plain<Primitive<34>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::SpentCoins
+    }
+}
+
+/// The spent coin and the transaction that spent it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SpentCoin {
+    pub coin: Coin,
+    /// The id of the transaction that spent the coin.
+    pub tx_id: TxId,
+    /// The height of the block that spent the coin.
+    pub block_height: BlockHeight,
+}
+
+/// The storage table of the coins spent by the block at the height. The spent
+/// coins are keyed by the id, so the table is used to find the coins spent
+/// before the retention window without iterating over the [`SpentCoins`].
+pub struct SpentCoinsByHeight;
+
+impl Mappable for SpentCoinsByHeight {
+    type Key = Self::OwnedKey;
+    type OwnedKey = BlockHeight;
+    type Value = Self::OwnedValue;
+    type OwnedValue = Vec<UtxoId>;
+}
+
+impl TableWithBlueprint for SpentCoinsByHeight {
+    type Blueprint = Plain<Primitive<4>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::SpentCoinsByHeight
+    }
+}
+
+/// The storage table of the coins spent by the blocks, in the order of the inputs
+/// of the transactions of the block. Only the coin inputs are indexed, while
+/// the spent messages are tracked by the `SpentMessages`.
+pub struct SpentCoinsByBlock;
+
+impl Mappable for SpentCoinsByBlock {
+    type Key = Self::OwnedKey;
+    type OwnedKey = SpentCoinsByBlockKey;
+    type Value = Self::OwnedValue;
+    type OwnedValue = UtxoId;
+}
+
+impl TableWithBlueprint for SpentCoinsByBlock {
+    type Blueprint = Plain<Manual<SpentCoinsByBlockKey>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::SpentCoinsByBlock
+    }
+}
+
+/// The height of the block and the index of the coin among the coins spent by it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SpentCoinsByBlockKey {
+    pub block_height: BlockHeight,
+    pub index: u32,
+}
+
+impl SpentCoinsByBlockKey {
+    pub const SIZE: usize = 8;
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
@@ -171,6 +687,7 @@ mod test {
             AMOUNT_SIZE,
             COIN_TYPE_SIZE,
             RETRYABLE_FLAG_SIZE,
+            UTXO_ID_SIZE,
         },
         graphql_api::storage::coins::codecs::CoinType,
     };
@@ -180,6 +697,7 @@ mod test {
     };
     use fuel_core_types::{
         entities::relayer::message::MessageV1,
+        fuel_tx::TxId,
         fuel_types::Nonce,
     };
 
@@ -221,19 +739,100 @@ mod test {
         COIN_TO_SPEND_BASE_KEY_LEN + Nonce::LEN + COIN_TYPE_SIZE;
 
     fn generate_key(rng: &mut impl rand::Rng) -> <OwnedCoins as Mappable>::Key {
-        let mut bytes = [0u8; 66];
-        rng.fill(bytes.as_mut());
-        bytes
+        OwnedCoinKey::new(&rng.gen(), &rng.gen())
     }
 
     fuel_core_storage::basic_storage_tests!(
         OwnedCoins,
-        [0u8; 66],
+        OwnedCoinKey::default(),
         <OwnedCoins as Mappable>::Value::default(),
         <OwnedCoins as Mappable>::Value::default(),
         generate_key
     );
 
+    #[test]
+    fn unspent_owned_coin_state_is_encoded_as_the_legacy_unit_value() {
+        use fuel_core_storage::codec::Decode;
+
+        // given
+        let legacy = <Postcard as Encode<()>>::encode(&());
+        let spent = OwnedCoinState::Spent {
+            height: 42u32.into(),
+        };
+
+        // when
+        let unspent_bytes = Manual::<OwnedCoinState>::encode(&OwnedCoinState::Unspent);
+        let decoded_legacy = Manual::<OwnedCoinState>::decode(&legacy.as_bytes());
+        let decoded_spent = Manual::<OwnedCoinState>::decode(
+            &Manual::<OwnedCoinState>::encode(&spent).as_bytes(),
+        );
+
+        // then
+        assert_eq!(unspent_bytes.as_bytes(), legacy.as_bytes());
+        assert_eq!(decoded_legacy.unwrap(), OwnedCoinState::Unspent);
+        assert_eq!(decoded_spent.unwrap(), spent);
+    }
+
+    fn generate_by_asset_key(
+        rng: &mut impl rand::Rng,
+    ) -> <OwnedCoinsByAsset as Mappable>::Key {
+        OwnedCoinByAssetKey::new(&rng.gen(), &rng.gen(), &rng.gen())
+    }
+
+    fuel_core_storage::basic_storage_tests!(
+        OwnedCoinsByAsset,
+        OwnedCoinByAssetKey::default(),
+        <OwnedCoinsByAsset as Mappable>::Value::default(),
+        <OwnedCoinsByAsset as Mappable>::Value::default(),
+        generate_by_asset_key
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        PredicateOwnedCoins,
+        OwnedCoinKey::default(),
+        <PredicateOwnedCoins as Mappable>::Value::default()
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        DerivedCoinOwners,
+        <DerivedCoinOwners as Mappable>::Key::default(),
+        vec![Address::default()]
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        SpentCoins,
+        <SpentCoins as Mappable>::Key::default(),
+        SpentCoin {
+            coin: Coin {
+                utxo_id: Default::default(),
+                owner: Default::default(),
+                amount: 0,
+                asset_id: Default::default(),
+                tx_pointer: Default::default(),
+            },
+            tx_id: Default::default(),
+            block_height: Default::default(),
+        }
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        SpentCoinsByHeight,
+        <SpentCoinsByHeight as Mappable>::Key::default(),
+        vec![UtxoId::default()]
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        SpentCoinsByBlock,
+        <SpentCoinsByBlock as Mappable>::Key::default(),
+        UtxoId::default()
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        OwnedCoinCountByAsset,
+        <OwnedCoinCountByAsset as Mappable>::Key::default(),
+        <OwnedCoinCountByAsset as Mappable>::Value::default()
+    );
+
     fuel_core_storage::basic_storage_tests!(
         CoinsToSpendIndex,
         CoinsToSpendIndexKey::Coin {
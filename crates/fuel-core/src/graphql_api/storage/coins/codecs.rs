@@ -1,6 +1,13 @@
 use crate::fuel_core_graphql_api::{
     indexation::coins_to_spend::NON_RETRYABLE_BYTE,
-    storage::coins::CoinsToSpendIndexKey,
+    storage::coins::{
+        CoinsToSpendIndexKey,
+        OwnedCoinByAssetKey,
+        OwnedCoinKey,
+        OwnedCoinState,
+        ShardedOwnedCoinKey,
+        SpentCoinsByBlockKey,
+    },
 };
 use fuel_core_storage::codec::{
     manual::Manual,
@@ -16,7 +23,10 @@ use fuel_core_types::{
         TxId,
         UtxoId,
     },
-    fuel_types::Nonce,
+    fuel_types::{
+        BlockHeight,
+        Nonce,
+    },
 };
 use std::borrow::Cow;
 
@@ -48,6 +58,177 @@ pub const MESSAGE_VARIANT_SIZE: usize = RETRYABLE_FLAG_SIZE
     + Nonce::LEN
     + COIN_TYPE_SIZE;
 
+pub const OWNED_COIN_KEY_SIZE: usize = Address::LEN + UTXO_ID_SIZE;
+
+pub const SHARD_SIZE: usize = size_of::<u8>();
+
+const SHARDED_UTXO_ID_OFFSET: usize = Address::LEN + SHARD_SIZE;
+
+pub const SHARDED_OWNED_COIN_KEY_SIZE: usize = SHARDED_UTXO_ID_OFFSET + UTXO_ID_SIZE;
+
+impl Encode<OwnedCoinKey> for Manual<OwnedCoinKey> {
+    type Encoder<'a> = [u8; OWNED_COIN_KEY_SIZE];
+
+    fn encode(t: &OwnedCoinKey) -> Self::Encoder<'_> {
+        // address | utxo_id
+        let mut serialized_key = [0u8; OWNED_COIN_KEY_SIZE];
+        serialized_key[..Address::LEN].copy_from_slice(t.owner.as_ref());
+        serialized_key[Address::LEN..].copy_from_slice(&utxo_id_to_bytes(&t.utxo_id));
+        serialized_key
+    }
+}
+
+// The keys of the sharded layout are decoded too, so the tools iterating over
+// the whole column, like the integrity check and the snapshot export, work with
+// both layouts.
+impl Decode<OwnedCoinKey> for Manual<OwnedCoinKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<OwnedCoinKey> {
+        if bytes.len() == SHARDED_OWNED_COIN_KEY_SIZE {
+            return Manual::<ShardedOwnedCoinKey>::decode(bytes).map(Into::into)
+        }
+        let bytes: [u8; OWNED_COIN_KEY_SIZE] = bytes.try_into().map_err(|_| {
+            anyhow::anyhow!(
+                "The owned coin key of {} bytes matches neither of the layouts",
+                bytes.len()
+            )
+        })?;
+        let (owner_bytes, utxo_id_bytes) = bytes.split_at(Address::LEN);
+        let owner = Address::try_from(owner_bytes)?;
+        let utxo_id = decode_utxo_id(utxo_id_bytes)?;
+
+        Ok(OwnedCoinKey { owner, utxo_id })
+    }
+}
+
+impl Encode<ShardedOwnedCoinKey> for Manual<ShardedOwnedCoinKey> {
+    type Encoder<'a> = [u8; SHARDED_OWNED_COIN_KEY_SIZE];
+
+    fn encode(t: &ShardedOwnedCoinKey) -> Self::Encoder<'_> {
+        // address | shard | utxo_id
+        let mut serialized_key = [0u8; SHARDED_OWNED_COIN_KEY_SIZE];
+        serialized_key[..Address::LEN].copy_from_slice(t.owner.as_ref());
+        serialized_key[Address::LEN] = t.shard;
+        serialized_key[SHARDED_UTXO_ID_OFFSET..]
+            .copy_from_slice(&utxo_id_to_bytes(&t.utxo_id));
+        serialized_key
+    }
+}
+
+impl Decode<ShardedOwnedCoinKey> for Manual<ShardedOwnedCoinKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<ShardedOwnedCoinKey> {
+        let bytes: [u8; SHARDED_OWNED_COIN_KEY_SIZE] =
+            bytes.try_into().map_err(|_| {
+                anyhow::anyhow!(
+                    "The owned coin key of {} bytes doesn't match the sharded layout, \
+                    the `OwnedCoins` column mixes the layouts",
+                    bytes.len()
+                )
+            })?;
+        let (owner_bytes, rest) = bytes.split_at(Address::LEN);
+        let owner = Address::try_from(owner_bytes)?;
+        let (shard_bytes, utxo_id_bytes) = rest.split_at(SHARD_SIZE);
+        let utxo_id = decode_utxo_id(utxo_id_bytes)?;
+
+        Ok(ShardedOwnedCoinKey {
+            owner,
+            shard: shard_bytes[0],
+            utxo_id,
+        })
+    }
+}
+
+fn decode_utxo_id(bytes: &[u8]) -> anyhow::Result<UtxoId> {
+    let (tx_id_bytes, output_index_bytes) = bytes.split_at(TxId::LEN);
+    let tx_id = TxId::try_from(tx_id_bytes)?;
+    let output_index = u16::from_be_bytes(output_index_bytes.try_into()?);
+    Ok(UtxoId::new(tx_id, output_index))
+}
+
+// The unspent coin is encoded as the empty value, the same as the unit value
+// stored before the spent coins were flagged, so the existing entries stay valid.
+impl Encode<OwnedCoinState> for Manual<OwnedCoinState> {
+    type Encoder<'a> = Cow<'a, [u8]>;
+
+    fn encode(t: &OwnedCoinState) -> Self::Encoder<'_> {
+        match t {
+            OwnedCoinState::Unspent => Cow::Borrowed(&[]),
+            OwnedCoinState::Spent { height } => Cow::Owned(height.to_bytes().to_vec()),
+        }
+    }
+}
+
+impl Decode<OwnedCoinState> for Manual<OwnedCoinState> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<OwnedCoinState> {
+        if bytes.is_empty() {
+            return Ok(OwnedCoinState::Unspent)
+        }
+        let height: [u8; 4] = bytes.try_into()?;
+        Ok(OwnedCoinState::Spent {
+            height: BlockHeight::from(u32::from_be_bytes(height)),
+        })
+    }
+}
+
+impl Encode<SpentCoinsByBlockKey> for Manual<SpentCoinsByBlockKey> {
+    type Encoder<'a> = [u8; SpentCoinsByBlockKey::SIZE];
+
+    fn encode(t: &SpentCoinsByBlockKey) -> Self::Encoder<'_> {
+        // block_height | index
+        let mut serialized_key = [0u8; SpentCoinsByBlockKey::SIZE];
+        serialized_key[..4].copy_from_slice(&t.block_height.to_bytes());
+        serialized_key[4..].copy_from_slice(&t.index.to_be_bytes());
+        serialized_key
+    }
+}
+
+impl Decode<SpentCoinsByBlockKey> for Manual<SpentCoinsByBlockKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<SpentCoinsByBlockKey> {
+        let bytes: [u8; SpentCoinsByBlockKey::SIZE] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))?;
+        let [h0, h1, h2, h3, i0, i1, i2, i3] = bytes;
+        Ok(SpentCoinsByBlockKey {
+            block_height: u32::from_be_bytes([h0, h1, h2, h3]).into(),
+            index: u32::from_be_bytes([i0, i1, i2, i3]),
+        })
+    }
+}
+
+pub const OWNED_COIN_BY_ASSET_KEY_SIZE: usize =
+    Address::LEN + AssetId::LEN + UTXO_ID_SIZE;
+
+impl Encode<OwnedCoinByAssetKey> for Manual<OwnedCoinByAssetKey> {
+    type Encoder<'a> = [u8; OWNED_COIN_BY_ASSET_KEY_SIZE];
+
+    fn encode(t: &OwnedCoinByAssetKey) -> Self::Encoder<'_> {
+        // address | asset_id | utxo_id
+        let mut serialized_key = [0u8; OWNED_COIN_BY_ASSET_KEY_SIZE];
+        let prefix = OwnedCoinByAssetKey::prefix(&t.owner, &t.asset_id);
+        serialized_key[..prefix.len()].copy_from_slice(&prefix);
+        serialized_key[prefix.len()..].copy_from_slice(&utxo_id_to_bytes(&t.utxo_id));
+        serialized_key
+    }
+}
+
+impl Decode<OwnedCoinByAssetKey> for Manual<OwnedCoinByAssetKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<OwnedCoinByAssetKey> {
+        let bytes: [u8; OWNED_COIN_BY_ASSET_KEY_SIZE] = bytes.try_into()?;
+        let (owner_bytes, rest) = bytes.split_at(Address::LEN);
+        let owner = Address::try_from(owner_bytes)?;
+        let (asset_id_bytes, utxo_id_bytes) = rest.split_at(AssetId::LEN);
+        let asset_id = AssetId::try_from(asset_id_bytes)?;
+        let (tx_id_bytes, output_index_bytes) = utxo_id_bytes.split_at(TxId::LEN);
+        let tx_id = TxId::try_from(tx_id_bytes)?;
+        let output_index = u16::from_be_bytes(output_index_bytes.try_into()?);
+
+        Ok(OwnedCoinByAssetKey {
+            owner,
+            asset_id,
+            utxo_id: UtxoId::new(tx_id, output_index),
+        })
+    }
+}
+
 pub enum SerializedCoinsToSpendIndexKey {
     Coin([u8; COIN_VARIANT_SIZE]),
     Message([u8; MESSAGE_VARIANT_SIZE]),
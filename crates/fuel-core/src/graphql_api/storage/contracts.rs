@@ -6,10 +6,14 @@ use fuel_core_chain_config::{
 use fuel_core_storage::{
     blueprint::plain::Plain,
     codec::{
+        manual::Manual,
         postcard::Postcard,
         raw::Raw,
+        Decode,
+        Encode,
     },
     structured_storage::TableWithBlueprint,
+    ContractsAssetKey,
     Mappable,
 };
 use fuel_core_types::{
@@ -17,7 +21,10 @@ use fuel_core_types::{
     fuel_tx::{
         ContractId,
         Salt,
+        TxId,
+        TxPointer,
     },
+    fuel_types::BlockHeight,
 };
 
 /// Contract info
@@ -51,6 +58,106 @@ impl AsTable<ContractsInfo> for StateConfig {
     }
 }
 
+/// The balances of contracts indexed by the contract and the asset. Unlike the on-chain
+/// `ContractsAssets` table, the keys are stored as is, so the balances of a contract are
+/// iterated in the order of the asset ids.
+pub struct ContractBalances;
+
+impl Mappable for ContractBalances {
+    type Key = Self::OwnedKey;
+    type OwnedKey = ContractsAssetKey;
+    type Value = Self::OwnedValue;
+    type OwnedValue = u64;
+}
+
+impl TableWithBlueprint for ContractBalances {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::ContractBalances
+    }
+}
+
+/// The contracts deployed by the `Create` transactions, ordered by the height
+/// of the block and the index of the transaction within the block.
+pub struct ContractsByBlock;
+
+impl Mappable for ContractsByBlock {
+    type Key = Self::OwnedKey;
+    type OwnedKey = ContractsByBlockKey;
+    type Value = Self::OwnedValue;
+    type OwnedValue = ContractDeployment;
+}
+
+impl TableWithBlueprint for ContractsByBlock {
+    type Blueprint = Plain<Manual<ContractsByBlockKey>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::ContractsByBlock
+    }
+}
+
+/// The height of the block and the index of the transaction that deployed the contract.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ContractsByBlockKey {
+    pub block_height: BlockHeight,
+    pub tx_idx: u16,
+}
+
+impl ContractsByBlockKey {
+    const SIZE: usize = 6;
+}
+
+impl From<TxPointer> for ContractsByBlockKey {
+    fn from(tx_pointer: TxPointer) -> Self {
+        Self {
+            block_height: tx_pointer.block_height(),
+            tx_idx: tx_pointer.tx_index(),
+        }
+    }
+}
+
+impl From<ContractsByBlockKey> for TxPointer {
+    fn from(key: ContractsByBlockKey) -> Self {
+        TxPointer::new(key.block_height, key.tx_idx)
+    }
+}
+
+impl Encode<ContractsByBlockKey> for Manual<ContractsByBlockKey> {
+    type Encoder<'a> = [u8; ContractsByBlockKey::SIZE];
+
+    fn encode(key: &ContractsByBlockKey) -> Self::Encoder<'_> {
+        let mut bytes = [0u8; ContractsByBlockKey::SIZE];
+        bytes[..4].copy_from_slice(&key.block_height.to_bytes());
+        bytes[4..].copy_from_slice(&key.tx_idx.to_be_bytes());
+        bytes
+    }
+}
+
+impl Decode<ContractsByBlockKey> for Manual<ContractsByBlockKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<ContractsByBlockKey> {
+        let bytes: [u8; ContractsByBlockKey::SIZE] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))?;
+        let [h0, h1, h2, h3, i0, i1] = bytes;
+        Ok(ContractsByBlockKey {
+            block_height: u32::from_be_bytes([h0, h1, h2, h3]).into(),
+            tx_idx: u16::from_be_bytes([i0, i1]),
+        })
+    }
+}
+
+/// The contract deployed by the transaction.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+pub struct ContractDeployment {
+    pub contract_id: ContractId,
+    pub tx_id: TxId,
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -61,4 +168,16 @@ mod test {
         <ContractsInfo as Mappable>::Key::from([1u8; 32]),
         ContractsInfoType::V1(Salt::new([2u8; 32]).into())
     );
+
+    fuel_core_storage::basic_storage_tests!(
+        ContractBalances,
+        <ContractBalances as Mappable>::Key::default(),
+        <ContractBalances as Mappable>::Value::default()
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        ContractsByBlock,
+        <ContractsByBlock as Mappable>::Key::default(),
+        <ContractsByBlock as Mappable>::Value::default()
+    );
 }
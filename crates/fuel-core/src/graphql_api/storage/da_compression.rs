@@ -4,6 +4,7 @@ use self::{
     reverse_key::ReverseKey,
     script_code_codec::ScriptCodeCodec,
     timestamps::TimestampKey,
+    versioned_block_codec::VersionedBlockCodec,
 };
 use fuel_core_compression::VersionedCompressedBlock;
 use fuel_core_storage::{
@@ -25,20 +26,26 @@ use fuel_core_types::{
         ContractId,
         ScriptCode,
     },
-    fuel_types::BlockHeight,
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+    },
     tai64::Tai64,
 };
 
+pub mod dictionaries;
 pub mod evictor_cache;
 pub mod predicate_code_codec;
 pub mod reverse_key;
 pub mod script_code_codec;
 pub mod timestamps;
+pub mod versioned_block_codec;
 
 #[cfg(feature = "fault-proving")]
 pub mod v2;
 
 /// The table for the compressed blocks sent to DA.
+/// The blocks are prefixed with the version of their format, see [`VersionedBlockCodec`].
 pub struct DaCompressedBlocks;
 
 impl Mappable for DaCompressedBlocks {
@@ -49,7 +56,18 @@ impl Mappable for DaCompressedBlocks {
 }
 
 impl TableWithBlueprint for DaCompressedBlocks {
-    type Blueprint = Plain<Primitive<4>, Postcard>;
+    #[cfg(not(feature = "fault-proving"))]
+    type Blueprint = Plain<Primitive<4>, VersionedBlockCodec>;
+    /// The blocks are merklized, so the inclusion of the block can be proven
+    /// against the root of all compressed blocks.
+    #[cfg(feature = "fault-proving")]
+    type Blueprint = fuel_core_storage::blueprint::merklized::Merklized<
+        Primitive<4>,
+        VersionedBlockCodec,
+        v2::compressed_blocks::DaCompressedBlocksMerkleMetadata,
+        v2::compressed_blocks::DaCompressedBlocksMerkleData,
+        Postcard,
+    >;
     type Column = super::Column;
 
     fn column() -> Self::Column {
@@ -57,6 +75,101 @@ impl TableWithBlueprint for DaCompressedBlocks {
     }
 }
 
+/// The table for the checksums of the compressed blocks from [`DaCompressedBlocks`].
+/// The checksum is computed over the encoded block, so corruption of the stored
+/// bytes is detected before decoding. Blocks compressed before the table existed
+/// don't have a checksum.
+pub struct DaCompressedBlockChecksums;
+
+impl Mappable for DaCompressedBlockChecksums {
+    type Key = Self::OwnedKey;
+    type OwnedKey = BlockHeight;
+    type Value = Self::OwnedValue;
+    type OwnedValue = u32;
+}
+
+impl TableWithBlueprint for DaCompressedBlockChecksums {
+    type Blueprint = Plain<Primitive<4>, Primitive<4>>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::DaCompressedBlockChecksums
+    }
+}
+
+/// The statistics of the compression of the block from [`DaCompressedBlocks`].
+#[derive(
+    Default, Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+pub struct DaCompressionStats {
+    /// The size of the encoded uncompressed block in bytes.
+    pub uncompressed_size: u64,
+    /// The size of the encoded compressed block in bytes.
+    pub compressed_size: u64,
+    /// The number of new registrations in the temporal registry made by the block.
+    pub registrations: u64,
+}
+
+impl DaCompressionStats {
+    /// The ratio of the compressed size to the uncompressed size.
+    /// The lower the ratio, the better the compression.
+    pub fn ratio(&self) -> f64 {
+        if self.uncompressed_size == 0 {
+            return 0.0;
+        }
+        self.compressed_size as f64 / self.uncompressed_size as f64
+    }
+}
+
+/// The table for the statistics of the compressed blocks.
+/// The statistics are overwritten when the block is compressed again.
+pub struct DaCompressedBlockStats;
+
+impl Mappable for DaCompressedBlockStats {
+    type Key = Self::OwnedKey;
+    type OwnedKey = BlockHeight;
+    type Value = Self::OwnedValue;
+    type OwnedValue = DaCompressionStats;
+}
+
+impl TableWithBlueprint for DaCompressedBlockStats {
+    type Blueprint = Plain<Primitive<4>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::DaCompressedBlockStats
+    }
+}
+
+/// The new registration in the temporal registry made by the compressed block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DaRegistration {
+    pub keyspace: MetadataKey,
+    pub key: RegistryKey,
+    /// The hash of the registered value.
+    pub value_hash: Bytes32,
+}
+
+/// The table for the registrations made by the compressed blocks from [`DaCompressedBlocks`].
+/// Blocks compressed before the table existed don't have the registrations.
+pub struct DaCompressedBlockRegistrations;
+
+impl Mappable for DaCompressedBlockRegistrations {
+    type Key = Self::OwnedKey;
+    type OwnedKey = BlockHeight;
+    type Value = Self::OwnedValue;
+    type OwnedValue = Vec<DaRegistration>;
+}
+
+impl TableWithBlueprint for DaCompressedBlockRegistrations {
+    type Blueprint = Plain<Primitive<4>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::DaCompressedBlockRegistrations
+    }
+}
+
 /// Mapping from the type to the registry key in the temporal registry.
 pub struct DaCompressionTemporalRegistryIndex;
 
@@ -120,6 +233,26 @@ impl TableWithBlueprint for DaCompressionTemporalRegistryEvictorCache {
     }
 }
 
+/// This table holds the number of keys holding a registration in each keyspace,
+/// so the evictor knows when the `registry_capacity` is reached.
+pub struct DaCompressionTemporalRegistryEntryCounts;
+
+impl Mappable for DaCompressionTemporalRegistryEntryCounts {
+    type Key = Self::OwnedKey;
+    type OwnedKey = MetadataKey;
+    type Value = Self::OwnedValue;
+    type OwnedValue = u32;
+}
+
+impl TableWithBlueprint for DaCompressionTemporalRegistryEntryCounts {
+    type Blueprint = Plain<Postcard, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::DaCompressionTemporalRegistryEntryCounts
+    }
+}
+
 macro_rules! temporal_registry {
     ($type:ty, $code:ty) => {
         paste::paste! {
@@ -189,12 +322,38 @@ mod tests {
         RegistryKey::ZERO
     );
 
+    #[cfg(test)]
+    fuel_core_storage::basic_storage_tests!(
+        DaCompressionTemporalRegistryEntryCounts,
+        MetadataKey::Address,
+        0u32
+    );
+
+    #[cfg(not(feature = "fault-proving"))]
     fuel_core_storage::basic_storage_tests!(
         DaCompressedBlocks,
         <DaCompressedBlocks as Mappable>::Key::default(),
         <DaCompressedBlocks as Mappable>::Value::default()
     );
 
+    fuel_core_storage::basic_storage_tests!(
+        DaCompressedBlockChecksums,
+        <DaCompressedBlockChecksums as Mappable>::Key::default(),
+        <DaCompressedBlockChecksums as Mappable>::Value::default()
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        DaCompressedBlockStats,
+        <DaCompressedBlockStats as Mappable>::Key::default(),
+        <DaCompressedBlockStats as Mappable>::Value::default()
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        DaCompressedBlockRegistrations,
+        <DaCompressedBlockRegistrations as Mappable>::Key::default(),
+        <DaCompressedBlockRegistrations as Mappable>::Value::default()
+    );
+
     #[allow(clippy::arithmetic_side_effects)] // Test code, and also safe
     pub fn generate_key(rng: &mut impl rand::Rng) -> RegistryKey {
         let raw_key: u32 = rng.gen_range(0..2u32.pow(24) - 2);
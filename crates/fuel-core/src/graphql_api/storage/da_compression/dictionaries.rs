@@ -0,0 +1,140 @@
+//! The zstd dictionaries of the DA compressed blocks stored at rest.
+//!
+//! The dictionary is trained on the recently compressed blocks and is kept forever,
+//! because the blocks compressed with it reference it by the id. The id is derived
+//! from the content of the dictionary, so the ids of the dictionaries trained
+//! by the different nodes never point to the wrong dictionary.
+//!
+//! The [`DaCompressedBlocks`](super::DaCompressedBlocks) are decoded without
+//! the access to the database, so the dictionaries are loaded into the process-wide
+//! registry with [`register_da_dictionary`] before the blocks compressed with them are read.
+
+use crate::graphql_api::storage::Column;
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        primitive::Primitive,
+        raw::Raw,
+    },
+    iter::{
+        IterDirection,
+        IterableTable,
+        IteratorOverTable,
+    },
+    structured_storage::TableWithBlueprint,
+    Mappable,
+    Result as StorageResult,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+use parking_lot::RwLock;
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc,
+        OnceLock,
+    },
+};
+
+/// The maximum size of the trained dictionary in bytes.
+pub const DA_DICTIONARY_MAX_SIZE: usize = 64 * 1024;
+
+/// The table of the zstd dictionaries by the height of the newest block
+/// among the blocks the dictionary was trained on.
+pub struct DaCompressionDictionaries;
+
+impl Mappable for DaCompressionDictionaries {
+    type Key = Self::OwnedKey;
+    type OwnedKey = BlockHeight;
+    type Value = Self::OwnedValue;
+    type OwnedValue = Vec<u8>;
+}
+
+impl TableWithBlueprint for DaCompressionDictionaries {
+    type Blueprint = Plain<Primitive<4>, Raw>;
+    type Column = Column;
+
+    fn column() -> Column {
+        Column::DaCompressionDictionaries
+    }
+}
+
+/// The zstd dictionary of the DA compressed blocks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DaDictionary {
+    id: u32,
+    bytes: Arc<Vec<u8>>,
+}
+
+impl DaDictionary {
+    pub fn new(bytes: Vec<u8>) -> Self {
+        // The `0` id marks the blocks compressed without a dictionary.
+        let id = crc32fast::hash(&bytes).max(1);
+        Self {
+            id,
+            bytes: Arc::new(bytes),
+        }
+    }
+
+    /// Trains the dictionary on the postcard encoded blocks.
+    pub fn train<S: AsRef<[u8]>>(samples: &[S]) -> anyhow::Result<Self> {
+        let bytes = zstd::dict::from_samples(samples, DA_DICTIONARY_MAX_SIZE)?;
+        Ok(Self::new(bytes))
+    }
+
+    /// The id of the dictionary stored with the blocks compressed with it.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+/// The dictionary used to compress the newly stored blocks, shared between
+/// the DA compression and the re-encoding of the existing blocks.
+pub type SharedDaDictionary = Arc<RwLock<Option<DaDictionary>>>;
+
+fn registry() -> &'static RwLock<HashMap<u32, DaDictionary>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<u32, DaDictionary>>> = OnceLock::new();
+    REGISTRY.get_or_init(Default::default)
+}
+
+/// Makes the `dictionary` available to the decoding of the blocks compressed with it.
+pub fn register_da_dictionary(dictionary: &DaDictionary) {
+    registry().write().insert(dictionary.id, dictionary.clone());
+}
+
+/// Returns the registered dictionary with the `id`.
+pub fn da_dictionary(id: u32) -> Option<DaDictionary> {
+    registry().read().get(&id).cloned()
+}
+
+/// Registers all stored dictionaries and returns the shared handle
+/// holding the newest one.
+pub fn load_da_dictionaries<S>(storage: &S) -> StorageResult<SharedDaDictionary>
+where
+    S: IterableTable<DaCompressionDictionaries>,
+{
+    let mut newest = None;
+    for entry in
+        storage.iter_all::<DaCompressionDictionaries>(Some(IterDirection::Forward))
+    {
+        let (_, bytes) = entry?;
+        let dictionary = DaDictionary::new(bytes);
+        register_da_dictionary(&dictionary);
+        newest = Some(dictionary);
+    }
+    Ok(Arc::new(RwLock::new(newest)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fuel_core_storage::basic_storage_tests!(
+        DaCompressionDictionaries,
+        <DaCompressionDictionaries as Mappable>::Key::default(),
+        vec![1u8; 32]
+    );
+}
@@ -1,6 +1,8 @@
 pub mod address;
 pub mod asset_id;
+pub mod compressed_blocks;
 pub mod contract_id;
+pub mod entry_counts;
 pub mod evictor_cache;
 pub mod predicate_code;
 pub mod registry_index;
@@ -0,0 +1,76 @@
+use crate::graphql_api::storage::Column;
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        primitive::Primitive,
+    },
+    structured_storage::TableWithBlueprint,
+    tables::merkle::{
+        DenseMerkleMetadata,
+        DenseMetadataKey,
+    },
+    Mappable,
+};
+use fuel_core_types::{
+    fuel_merkle::binary,
+    fuel_types::BlockHeight,
+};
+
+/// The Merkle tree of the [`DaCompressedBlocks`](super::super::DaCompressedBlocks).
+/// The leaves are the postcard encoded compressed blocks in the order of compression.
+pub struct DaCompressedBlocksMerkleData;
+
+impl Mappable for DaCompressedBlocksMerkleData {
+    type Key = u64;
+    type OwnedKey = Self::Key;
+    type Value = binary::Primitive;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for DaCompressedBlocksMerkleData {
+    type Blueprint = Plain<Primitive<8>, Postcard>;
+    type Column = Column;
+
+    fn column() -> Column {
+        Column::DaCompressedBlocksMerkleData
+    }
+}
+
+/// The metadata table for [`DaCompressedBlocksMerkleData`] table.
+pub struct DaCompressedBlocksMerkleMetadata;
+
+impl Mappable for DaCompressedBlocksMerkleMetadata {
+    type Key = DenseMetadataKey<BlockHeight>;
+    type OwnedKey = Self::Key;
+    type Value = DenseMerkleMetadata;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for DaCompressedBlocksMerkleMetadata {
+    type Blueprint = Plain<Postcard, Postcard>;
+    type Column = Column;
+
+    fn column() -> Column {
+        Column::DaCompressedBlocksMerkleMetadata
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphql_api::storage::da_compression::DaCompressedBlocks;
+
+    fn generate_height(rng: &mut impl rand::Rng) -> BlockHeight {
+        rng.gen::<u32>().into()
+    }
+
+    #[cfg(test)]
+    fuel_core_storage::basic_merklelized_storage_tests!(
+        DaCompressedBlocks,
+        <DaCompressedBlocks as Mappable>::Key::default(),
+        <DaCompressedBlocks as Mappable>::Value::default(),
+        <DaCompressedBlocks as Mappable>::Value::default(),
+        generate_height
+    );
+}
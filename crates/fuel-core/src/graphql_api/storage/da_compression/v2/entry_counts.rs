@@ -0,0 +1,95 @@
+use crate::graphql_api::storage::{
+    da_compression::MetadataKey,
+    Column,
+};
+use fuel_core_storage::{
+    blueprint::{
+        merklized::Merklized,
+        plain::Plain,
+    },
+    codec::{
+        postcard::Postcard,
+        primitive::Primitive,
+    },
+    structured_storage::TableWithBlueprint,
+    tables::merkle::{
+        DenseMerkleMetadata,
+        DenseMetadataKey,
+    },
+    Mappable,
+};
+use fuel_core_types::fuel_merkle::binary;
+
+pub struct TemporalRegistryEntryCountsMerkleData;
+
+impl Mappable for TemporalRegistryEntryCountsMerkleData {
+    type Key = u64;
+    type OwnedKey = Self::Key;
+    type Value = binary::Primitive;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for TemporalRegistryEntryCountsMerkleData {
+    type Blueprint = Plain<Primitive<8>, Postcard>;
+    type Column = Column;
+
+    fn column() -> Self::Column {
+        Self::Column::DaCompressionTemporalRegistryEntryCountsMerkleData
+    }
+}
+
+/// The metadata table for [`TemporalRegistryEntryCountsMerkleData`] table.
+pub struct TemporalRegistryEntryCountsMerkleMetadata;
+
+impl Mappable for TemporalRegistryEntryCountsMerkleMetadata {
+    type Key = DenseMetadataKey<MetadataKey>;
+    type OwnedKey = Self::Key;
+    type Value = DenseMerkleMetadata;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for TemporalRegistryEntryCountsMerkleMetadata {
+    type Blueprint = Plain<Postcard, Postcard>;
+    type Column = Column;
+
+    fn column() -> Self::Column {
+        Self::Column::DaCompressionTemporalRegistryEntryCountsMerkleMetadata
+    }
+}
+
+impl Mappable for DaCompressionTemporalRegistryEntryCountsV2 {
+    type Key = Self::OwnedKey;
+    type OwnedKey = MetadataKey;
+    type Value = Self::OwnedValue;
+    type OwnedValue = u32;
+}
+
+/// Encoder for the V2 version of the DaCompressionTemporalRegistry for EntryCounts.
+pub struct DaCompressionTemporalEntryCountsV2Encoder;
+
+impl fuel_core_storage::codec::Encode<u32> for DaCompressionTemporalEntryCountsV2Encoder {
+    type Encoder<'a> = [u8; 4];
+
+    fn encode(value: &u32) -> Self::Encoder<'_> {
+        value.to_be_bytes()
+    }
+}
+
+/// This table holds the number of keys holding a registration in each keyspace,
+/// so the evictor knows when the `registry_capacity` is reached.
+pub struct DaCompressionTemporalRegistryEntryCountsV2;
+
+impl TableWithBlueprint for DaCompressionTemporalRegistryEntryCountsV2 {
+    type Blueprint = Merklized<
+        Postcard,
+        Postcard,
+        TemporalRegistryEntryCountsMerkleMetadata,
+        TemporalRegistryEntryCountsMerkleData,
+        DaCompressionTemporalEntryCountsV2Encoder,
+    >;
+    type Column = Column;
+
+    fn column() -> Self::Column {
+        Self::Column::DaCompressionTemporalRegistryEntryCountsV2
+    }
+}
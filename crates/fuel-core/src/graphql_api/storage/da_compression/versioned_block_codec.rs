@@ -0,0 +1,300 @@
+//! The codec of the DA compressed blocks prefixed with the version of their format.
+//!
+//! The stored value is the format byte followed by the payload. The format byte
+//! has the [`DA_FORMAT_FLAG`] bit set, and the rest of the bits hold the version.
+//! The blocks stored before the versioning start with the postcard discriminant of
+//! the [`VersionedCompressedBlock`], which never has the flag bit set, so they are
+//! read as the [`DA_FORMAT_V0`] without the rewrite of the existing entries.
+//!
+//! The format byte also tags the encoding of the block at rest, so the plain
+//! and the [`DA_FORMAT_ZSTD`] blocks coexist in the table and are decoded transparently.
+
+use super::dictionaries::{
+    da_dictionary,
+    DaDictionary,
+};
+use fuel_core_compression::VersionedCompressedBlock;
+use fuel_core_storage::codec::{
+    postcard::Postcard,
+    Decode,
+    Encode,
+};
+use std::{
+    borrow::Cow,
+    io::Read,
+};
+
+/// The bit marking the first byte of the stored block as the format byte.
+pub const DA_FORMAT_FLAG: u8 = 0x80;
+
+/// The format of the postcard encoded [`VersionedCompressedBlock`].
+pub const DA_FORMAT_V0: u8 = 0;
+
+/// The format of the zstd compressed postcard encoded [`VersionedCompressedBlock`].
+/// The payload is the big-endian id of the [`DaDictionary`] used by the compression,
+/// `0` for the compression without a dictionary, followed by the zstd frame.
+pub const DA_FORMAT_ZSTD: u8 = 1;
+
+/// The format used for the newly compressed blocks before they are encoded
+/// with the [`DaBlockEncoding`].
+pub const DA_FORMAT_CURRENT: u8 = DA_FORMAT_V0;
+
+const DICTIONARY_ID_SIZE: usize = 4;
+
+/// The stored DA compressed block has the format unknown to this node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display)]
+#[display(fmt = "Unsupported DA compressed block format version {}", version)]
+pub struct UnsupportedDaFormat {
+    pub version: u8,
+}
+
+impl std::error::Error for UnsupportedDaFormat {}
+
+/// The encoding of the DA compressed blocks at rest.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DaBlockEncoding {
+    /// The blocks are stored in the [`DA_FORMAT_CURRENT`].
+    #[default]
+    Plain,
+    /// The blocks are stored in the [`DA_FORMAT_ZSTD`] with the compression `level`.
+    Zstd { level: i32 },
+}
+
+/// Splits the stored DA compressed block into the version of its format and the payload.
+/// The blocks without the format byte are of the [`DA_FORMAT_V0`].
+pub fn split_da_format(bytes: &[u8]) -> (u8, &[u8]) {
+    match bytes.split_first() {
+        Some((first, payload)) if first & DA_FORMAT_FLAG != 0 => {
+            (first & !DA_FORMAT_FLAG, payload)
+        }
+        _ => (DA_FORMAT_V0, bytes),
+    }
+}
+
+/// Returns the postcard encoded [`VersionedCompressedBlock`] from the stored block,
+/// decompressing the [`DA_FORMAT_ZSTD`] blocks. Fails with the [`UnsupportedDaFormat`]
+/// if the format of the block is unknown.
+pub fn da_block_payload(bytes: &[u8]) -> anyhow::Result<Cow<'_, [u8]>> {
+    match split_da_format(bytes) {
+        (DA_FORMAT_V0, payload) => Ok(Cow::Borrowed(payload)),
+        (DA_FORMAT_ZSTD, payload) => decompress_zstd_payload(payload).map(Cow::Owned),
+        (version, _) => Err(UnsupportedDaFormat { version }.into()),
+    }
+}
+
+fn decompress_zstd_payload(payload: &[u8]) -> anyhow::Result<Vec<u8>> {
+    if payload.len() < DICTIONARY_ID_SIZE {
+        anyhow::bail!("The zstd compressed DA block is truncated");
+    }
+    let (id, frame) = payload.split_at(DICTIONARY_ID_SIZE);
+    let id = u32::from_be_bytes(id.try_into()?);
+
+    let mut decompressed = vec![];
+    if id == 0 {
+        zstd::stream::read::Decoder::new(frame)?.read_to_end(&mut decompressed)?;
+    } else {
+        let dictionary = da_dictionary(id).ok_or_else(|| {
+            anyhow::anyhow!(
+                "The dictionary {id} of the DA compressed block is not loaded"
+            )
+        })?;
+        zstd::stream::read::Decoder::with_dictionary(frame, dictionary.bytes())?
+            .read_to_end(&mut decompressed)?;
+    }
+    Ok(decompressed)
+}
+
+/// Encodes the postcard encoded [`VersionedCompressedBlock`] for the storage with the `encoding`.
+/// The `dictionary` is used only by the [`DaBlockEncoding::Zstd`].
+pub fn encode_da_block(
+    payload: &[u8],
+    encoding: DaBlockEncoding,
+    dictionary: Option<&DaDictionary>,
+) -> anyhow::Result<Vec<u8>> {
+    match encoding {
+        DaBlockEncoding::Plain => {
+            let mut bytes = Vec::with_capacity(payload.len().saturating_add(1));
+            bytes.push(DA_FORMAT_FLAG | DA_FORMAT_CURRENT);
+            bytes.extend_from_slice(payload);
+            Ok(bytes)
+        }
+        DaBlockEncoding::Zstd { level } => {
+            let (id, frame) = match dictionary {
+                Some(dictionary) => (
+                    dictionary.id(),
+                    zstd::bulk::Compressor::with_dictionary(level, dictionary.bytes())?
+                        .compress(payload)?,
+                ),
+                None => (0, zstd::bulk::compress(payload, level)?),
+            };
+            let mut bytes = Vec::with_capacity(
+                frame
+                    .len()
+                    .saturating_add(DICTIONARY_ID_SIZE)
+                    .saturating_add(1),
+            );
+            bytes.push(DA_FORMAT_FLAG | DA_FORMAT_ZSTD);
+            bytes.extend_from_slice(&id.to_be_bytes());
+            bytes.extend_from_slice(&frame);
+            Ok(bytes)
+        }
+    }
+}
+
+pub struct VersionedBlockCodec;
+
+impl Encode<VersionedCompressedBlock> for VersionedBlockCodec {
+    type Encoder<'a> = Cow<'a, [u8]>;
+
+    fn encode(t: &VersionedCompressedBlock) -> Self::Encoder<'_> {
+        let payload = Postcard::encode(t);
+        let mut bytes = Vec::with_capacity(payload.len().saturating_add(1));
+        bytes.push(DA_FORMAT_FLAG | DA_FORMAT_CURRENT);
+        bytes.extend_from_slice(&payload);
+        Cow::Owned(bytes)
+    }
+}
+
+impl Decode<VersionedCompressedBlock> for VersionedBlockCodec {
+    fn decode(bytes: &[u8]) -> anyhow::Result<VersionedCompressedBlock> {
+        let payload = da_block_payload(bytes)?;
+        Postcard::decode(&payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphql_api::storage::da_compression::dictionaries::register_da_dictionary;
+
+    #[test]
+    fn decode__reads_block_without_format_byte_as_v0() {
+        // given
+        let block = VersionedCompressedBlock::default();
+        let legacy = Postcard::encode(&block).into_owned();
+
+        // when
+        let decoded = VersionedBlockCodec::decode(&legacy).unwrap();
+
+        // then
+        assert_eq!(&*da_block_payload(&legacy).unwrap(), legacy.as_slice());
+        assert_eq!(decoded, block);
+    }
+
+    #[test]
+    fn encode__prefixes_block_with_current_format_byte() {
+        // given
+        let block = VersionedCompressedBlock::default();
+
+        // when
+        let encoded = VersionedBlockCodec::encode(&block).into_owned();
+
+        // then
+        assert_eq!(encoded[0], DA_FORMAT_FLAG | DA_FORMAT_CURRENT);
+        assert_eq!(
+            &*da_block_payload(&encoded).unwrap(),
+            &*Postcard::encode(&block)
+        );
+    }
+
+    #[test]
+    fn decode__fails_for_unknown_format_version() {
+        // given
+        let block = VersionedCompressedBlock::default();
+        let mut encoded = VersionedBlockCodec::encode(&block).into_owned();
+        encoded[0] = DA_FORMAT_FLAG | 5;
+
+        // when
+        let result = VersionedBlockCodec::decode(&encoded);
+
+        // then
+        let err = result.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<UnsupportedDaFormat>(),
+            Some(&UnsupportedDaFormat { version: 5 })
+        );
+    }
+
+    /// The bytes standing in for the postcard encoded block, which repeat
+    /// within the block and between the blocks like the real ones do.
+    fn payload(height: u32) -> Vec<u8> {
+        let mut payload = b"header".to_vec();
+        payload.extend_from_slice(&height.to_be_bytes());
+        for _ in 0..16 {
+            payload.extend_from_slice(b"transaction with the same script");
+        }
+        payload
+    }
+
+    #[test]
+    fn encode_da_block__zstd_round_trips_without_dictionary() {
+        // given
+        let payload = payload(1);
+
+        // when
+        let encoded =
+            encode_da_block(&payload, DaBlockEncoding::Zstd { level: 3 }, None).unwrap();
+
+        // then
+        assert_eq!(split_da_format(&encoded).0, DA_FORMAT_ZSTD);
+        assert_eq!(&*da_block_payload(&encoded).unwrap(), payload.as_slice());
+    }
+
+    #[test]
+    fn encode_da_block__zstd_round_trips_with_registered_dictionary() {
+        // given
+        // Any bytes are accepted by zstd as the raw content dictionary.
+        let dictionary = DaDictionary::new(payload(0));
+        register_da_dictionary(&dictionary);
+        let payload = payload(1);
+
+        // when
+        let encoded = encode_da_block(
+            &payload,
+            DaBlockEncoding::Zstd { level: 3 },
+            Some(&dictionary),
+        )
+        .unwrap();
+
+        // then
+        assert_eq!(&encoded[1..5], &dictionary.id().to_be_bytes());
+        assert_eq!(&*da_block_payload(&encoded).unwrap(), payload.as_slice());
+    }
+
+    #[test]
+    fn decode__reads_plain_and_zstd_blocks_stored_side_by_side() {
+        // given
+        let block = VersionedCompressedBlock::default();
+        let payload = Postcard::encode(&block).into_owned();
+        let stored = [
+            payload.clone(),
+            encode_da_block(&payload, DaBlockEncoding::Plain, None).unwrap(),
+            encode_da_block(&payload, DaBlockEncoding::Zstd { level: 1 }, None).unwrap(),
+        ];
+
+        // when
+        let decoded = stored
+            .iter()
+            .map(|bytes| VersionedBlockCodec::decode(bytes).unwrap())
+            .collect::<Vec<_>>();
+
+        // then
+        assert_eq!(decoded, vec![block.clone(), block.clone(), block]);
+    }
+
+    #[test]
+    fn da_block_payload__fails_for_unknown_dictionary() {
+        // given
+        let payload = payload(1);
+        let dictionary = DaDictionary::new(b"never registered dictionary".to_vec());
+        let mut encoded =
+            encode_da_block(&payload, DaBlockEncoding::Zstd { level: 1 }, None).unwrap();
+        encoded[1..5].copy_from_slice(&dictionary.id().to_be_bytes());
+
+        // when
+        let result = da_block_payload(&encoded);
+
+        // then
+        assert!(result.is_err());
+    }
+}
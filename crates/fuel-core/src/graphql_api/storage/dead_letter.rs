@@ -0,0 +1,11 @@
+use fuel_core_types::fuel_types::BlockHeight;
+
+/// The failure to index the block at the `height` after all retries were exhausted.
+/// The worker keeps retrying the block, and removes the entry once the block is indexed.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub struct IndexationDeadLetter {
+    /// The height of the block that failed to be indexed.
+    pub height: BlockHeight,
+    /// The error of the last attempt.
+    pub error: String,
+}
@@ -0,0 +1,55 @@
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        raw::Raw,
+    },
+    structured_storage::TableWithBlueprint,
+    Mappable,
+};
+use fuel_core_types::fuel_tx::{
+    Address,
+    Bytes32,
+};
+
+/// The table classifies the owners by the way they spent their coins or messages.
+/// The owner is known only after its first spend, because the address alone
+/// doesn't tell whether it is the root of a predicate.
+pub struct KnownPredicateOwners;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum KnownOwner {
+    /// The owner spent with a predicate, the code of which has the `code_hash`.
+    Predicate { code_hash: Bytes32 },
+    /// The owner spent with a signature.
+    Account,
+}
+
+impl Mappable for KnownPredicateOwners {
+    type Key = Address;
+    type OwnedKey = Self::Key;
+    type Value = Self::OwnedValue;
+    type OwnedValue = KnownOwner;
+}
+
+impl TableWithBlueprint for KnownPredicateOwners {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::KnownPredicateOwners
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fuel_core_storage::basic_storage_tests!(
+        KnownPredicateOwners,
+        <KnownPredicateOwners as Mappable>::Key::default(),
+        KnownOwner::Predicate {
+            code_hash: Bytes32::default()
+        }
+    );
+}
@@ -8,15 +8,26 @@ use fuel_core_chain_config::{
 use fuel_core_storage::{
     blueprint::plain::Plain,
     codec::{
+        manual::Manual,
         postcard::Postcard,
         raw::Raw,
+        Decode,
+        Encode,
     },
     structured_storage::TableWithBlueprint,
     Mappable,
 };
-use fuel_core_types::fuel_types::{
-    Address,
-    Nonce,
+use fuel_core_types::{
+    entities::relayer::message::{
+        Message,
+        MessageProof,
+    },
+    fuel_tx::TxId,
+    fuel_types::{
+        Address,
+        BlockHeight,
+        Nonce,
+    },
 };
 use rand::{
     distributions::{
@@ -25,6 +36,11 @@ use rand::{
     },
     Rng,
 };
+use std::{
+    borrow::Cow,
+    collections::VecDeque,
+    mem::size_of,
+};
 
 fuel_core_types::fuel_vm::double_key!(OwnedMessageKey, Address, address, Nonce, nonce);
 
@@ -38,18 +54,47 @@ impl Distribution<OwnedMessageKey> for Standard {
     }
 }
 
-/// The table that stores all messages per owner.
+/// The table that stores all messages per owner. The consumed messages stay
+/// in the table with the [`OwnedMessageState::Spent`] marker.
 pub struct OwnedMessageIds;
 
+/// The state of the message in the [`OwnedMessageIds`] table.
+///
+/// The entries written before the spent messages were kept have the unit value,
+/// that is decoded as [`OwnedMessageState::Unspent`].
+#[derive(Default, Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum OwnedMessageState {
+    #[default]
+    Unspent,
+    /// The message is removed from the on-chain state once it is consumed,
+    /// so the marker keeps it to return the history of the owner.
+    Spent(SpentOwnedMessage),
+}
+
+/// The message consumed in the block at the `height` by the transaction `tx_id`.
+/// The `tx_id` is unknown if the message wasn't consumed by an input.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SpentOwnedMessage {
+    pub height: BlockHeight,
+    pub tx_id: Option<TxId>,
+    pub message: Message,
+}
+
+impl OwnedMessageState {
+    pub fn is_spent(&self) -> bool {
+        matches!(self, OwnedMessageState::Spent(_))
+    }
+}
+
 impl Mappable for OwnedMessageIds {
     type Key = OwnedMessageKey;
     type OwnedKey = Self::Key;
-    type Value = ();
-    type OwnedValue = Self::Value;
+    type Value = Self::OwnedValue;
+    type OwnedValue = OwnedMessageState;
 }
 
 impl TableWithBlueprint for OwnedMessageIds {
-    type Blueprint = Plain<Raw, Postcard>;
+    type Blueprint = Plain<Raw, Manual<OwnedMessageState>>;
     type Column = super::Column;
 
     fn column() -> Self::Column {
@@ -57,6 +102,40 @@ impl TableWithBlueprint for OwnedMessageIds {
     }
 }
 
+impl AsTable<OwnedMessageIds> for StateConfig {
+    fn as_table(&self) -> Vec<TableEntry<OwnedMessageIds>> {
+        Vec::new() // Do not include these for now
+    }
+}
+
+impl AddTable<OwnedMessageIds> for StateConfigBuilder {
+    fn add(&mut self, _entries: Vec<TableEntry<OwnedMessageIds>>) {
+        // Do not include these for now
+    }
+}
+
+// The unspent state is empty like the postcard encoding of `()`,
+// the spent state is the postcard encoding of the marker.
+impl Encode<OwnedMessageState> for Manual<OwnedMessageState> {
+    type Encoder<'a> = Cow<'a, [u8]>;
+
+    fn encode(t: &OwnedMessageState) -> Self::Encoder<'_> {
+        match t {
+            OwnedMessageState::Unspent => Cow::Borrowed(&[]),
+            OwnedMessageState::Spent(spent) => Postcard::encode(spent),
+        }
+    }
+}
+
+impl Decode<OwnedMessageState> for Manual<OwnedMessageState> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<OwnedMessageState> {
+        if bytes.is_empty() {
+            return Ok(OwnedMessageState::Unspent)
+        }
+        Ok(OwnedMessageState::Spent(Postcard::decode(bytes)?))
+    }
+}
+
 #[cfg(test)]
 fuel_core_storage::basic_storage_tests!(
     OwnedMessageIds,
@@ -64,6 +143,47 @@ fuel_core_storage::basic_storage_tests!(
     <OwnedMessageIds as Mappable>::Value::default()
 );
 
+fuel_core_types::fuel_vm::double_key!(SenderMessageKey, Address, sender, Nonce, nonce);
+
+/// The table of the unspent messages per sender on the L1. The messages imported
+/// before the table was introduced are not indexed.
+pub struct MessagesBySender;
+
+impl Mappable for MessagesBySender {
+    type Key = SenderMessageKey;
+    type OwnedKey = Self::Key;
+    type Value = ();
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for MessagesBySender {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::MessagesBySender
+    }
+}
+
+impl AsTable<MessagesBySender> for StateConfig {
+    fn as_table(&self) -> Vec<TableEntry<MessagesBySender>> {
+        Vec::new() // Do not include these for now
+    }
+}
+
+impl AddTable<MessagesBySender> for StateConfigBuilder {
+    fn add(&mut self, _entries: Vec<TableEntry<MessagesBySender>>) {
+        // Do not include these for now
+    }
+}
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    MessagesBySender,
+    <MessagesBySender as Mappable>::Key::default(),
+    <MessagesBySender as Mappable>::Value::default()
+);
+
 /// The storage table that indicates if the message is spent or not.
 pub struct SpentMessages;
 
@@ -101,3 +221,164 @@ fuel_core_storage::basic_storage_tests!(
     <SpentMessages as Mappable>::Key::default(),
     <SpentMessages as Mappable>::Value::default()
 );
+
+/// The table of the message proofs generated by the GraphQL service. The proof
+/// stays valid while the commit block at the height of the key is the same as
+/// the commit block of the proof.
+pub struct MessageProofCache;
+
+impl Mappable for MessageProofCache {
+    type Key = MessageProofCacheKey;
+    type OwnedKey = Self::Key;
+    type Value = MessageProof;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for MessageProofCache {
+    type Blueprint = Plain<Manual<MessageProofCacheKey>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::MessageProofCache
+    }
+}
+
+const MESSAGE_PROOF_CACHE_KEY_SIZE: usize =
+    TxId::LEN + Nonce::LEN + size_of::<BlockHeight>();
+
+/// The key of the cached message proof.
+#[derive(
+    Default, Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub struct MessageProofCacheKey {
+    pub transaction_id: TxId,
+    pub nonce: Nonce,
+    pub commit_block_height: BlockHeight,
+}
+
+impl MessageProofCacheKey {
+    pub fn new(
+        transaction_id: TxId,
+        nonce: Nonce,
+        commit_block_height: BlockHeight,
+    ) -> Self {
+        Self {
+            transaction_id,
+            nonce,
+            commit_block_height,
+        }
+    }
+}
+
+impl Encode<MessageProofCacheKey> for Manual<MessageProofCacheKey> {
+    type Encoder<'a> = [u8; MESSAGE_PROOF_CACHE_KEY_SIZE];
+
+    fn encode(t: &MessageProofCacheKey) -> Self::Encoder<'_> {
+        let mut bytes = [0u8; MESSAGE_PROOF_CACHE_KEY_SIZE];
+        let (transaction_id, rest) = bytes.split_at_mut(TxId::LEN);
+        let (nonce, commit_block_height) = rest.split_at_mut(Nonce::LEN);
+        transaction_id.copy_from_slice(t.transaction_id.as_ref());
+        nonce.copy_from_slice(t.nonce.as_ref());
+        commit_block_height.copy_from_slice(&t.commit_block_height.to_bytes());
+        bytes
+    }
+}
+
+impl Decode<MessageProofCacheKey> for Manual<MessageProofCacheKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<MessageProofCacheKey> {
+        let bytes: [u8; MESSAGE_PROOF_CACHE_KEY_SIZE] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))?;
+        let (transaction_id, rest) = bytes.split_at(TxId::LEN);
+        let (nonce, commit_block_height) = rest.split_at(Nonce::LEN);
+        let transaction_id: [u8; TxId::LEN] = transaction_id
+            .try_into()
+            .expect("It's an array of 32 bytes");
+        let nonce: [u8; Nonce::LEN] =
+            nonce.try_into().expect("It's an array of 32 bytes");
+        let commit_block_height: [u8; 4] = commit_block_height
+            .try_into()
+            .expect("It's an array of 4 bytes");
+
+        Ok(MessageProofCacheKey {
+            transaction_id: transaction_id.into(),
+            nonce: nonce.into(),
+            commit_block_height: u32::from_be_bytes(commit_block_height).into(),
+        })
+    }
+}
+
+/// The keys of the cached message proofs from the least to the most recently used.
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub struct MessageProofCacheEntries {
+    entries: VecDeque<MessageProofCacheKey>,
+}
+
+impl MessageProofCacheEntries {
+    /// Marks the `key` as the most recently used one.
+    pub fn touch(&mut self, key: &MessageProofCacheKey) {
+        self.entries.retain(|entry| entry != key);
+        self.entries.push_back(*key);
+    }
+
+    /// Stops tracking the least recently used keys above the `capacity`
+    /// and returns them.
+    pub fn evict(&mut self, capacity: usize) -> Vec<MessageProofCacheKey> {
+        let count = self.entries.len().saturating_sub(capacity);
+        self.entries.drain(..count).collect()
+    }
+
+    /// Returns the number of the tracked keys.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no keys are tracked.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::codec::Encoder;
+
+    #[test]
+    fn owned_message_state__decodes_the_unit_value_as_unspent() {
+        // given
+        let unit_value = postcard::to_allocvec(&()).unwrap();
+
+        // when
+        let state = Manual::<OwnedMessageState>::decode(&unit_value).unwrap();
+
+        // then
+        assert_eq!(state, OwnedMessageState::Unspent);
+    }
+
+    #[test]
+    fn owned_message_state__round_trips_the_spent_marker() {
+        for state in [
+            OwnedMessageState::Unspent,
+            OwnedMessageState::Spent(SpentOwnedMessage {
+                height: 7.into(),
+                tx_id: None,
+                message: Message::default(),
+            }),
+            OwnedMessageState::Spent(SpentOwnedMessage {
+                height: 7.into(),
+                tx_id: Some(TxId::from([3; 32])),
+                message: Message::default(),
+            }),
+        ] {
+            // when
+            let encoded = Manual::<OwnedMessageState>::encode(&state);
+            let decoded =
+                Manual::<OwnedMessageState>::decode(&encoded.as_bytes()).unwrap();
+
+            // then
+            assert_eq!(decoded, state);
+        }
+    }
+}
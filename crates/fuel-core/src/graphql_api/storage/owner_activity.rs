@@ -0,0 +1,84 @@
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        primitive::Primitive,
+        raw::Raw,
+    },
+    structured_storage::TableWithBlueprint,
+    Mappable,
+};
+use fuel_core_types::{
+    fuel_tx::Address,
+    fuel_types::BlockHeight,
+};
+
+/// The table stores the summary of the owner's activity on the chain.
+pub struct OwnerActivities;
+
+#[derive(
+    Default, Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+pub struct OwnerActivity {
+    /// The first block where the owner appeared in a transaction or received a coin.
+    pub first_seen_block: BlockHeight,
+    /// The last block where the owner appeared in a transaction or its coins changed.
+    pub last_active_block: BlockHeight,
+    /// The number of transactions that have the owner in inputs or outputs.
+    pub total_transactions: u64,
+    /// The number of unspent coins owned by the owner.
+    pub coin_count: u64,
+}
+
+impl Mappable for OwnerActivities {
+    type Key = Address;
+    type OwnedKey = Self::Key;
+    type Value = Self::OwnedValue;
+    type OwnedValue = OwnerActivity;
+}
+
+impl TableWithBlueprint for OwnerActivities {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::OwnerActivity
+    }
+}
+
+/// The table stores the height of the last block where the owner appeared
+/// in a transaction or received a coin or a message.
+pub struct OwnerLastActivity;
+
+impl Mappable for OwnerLastActivity {
+    type Key = Address;
+    type OwnedKey = Self::Key;
+    type Value = Self::OwnedValue;
+    type OwnedValue = BlockHeight;
+}
+
+impl TableWithBlueprint for OwnerLastActivity {
+    type Blueprint = Plain<Raw, Primitive<4>>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::OwnerLastActivity
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fuel_core_storage::basic_storage_tests!(
+        OwnerActivities,
+        <OwnerActivities as Mappable>::Key::default(),
+        <OwnerActivities as Mappable>::Value::default()
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        OwnerLastActivity,
+        <OwnerLastActivity as Mappable>::Key::default(),
+        <OwnerLastActivity as Mappable>::Value::default()
+    );
+}
@@ -8,8 +8,11 @@ use fuel_core_chain_config::{
 use fuel_core_storage::{
     blueprint::plain::Plain,
     codec::{
+        manual::Manual,
         postcard::Postcard,
         raw::Raw,
+        Decode,
+        Encode,
     },
     structured_storage::TableWithBlueprint,
     Mappable,
@@ -17,6 +20,10 @@ use fuel_core_storage::{
 use fuel_core_types::{
     entities::relayer::transaction::RelayedTransactionStatus,
     fuel_tx::Bytes32,
+    fuel_types::{
+        Address,
+        BlockHeight,
+    },
 };
 
 /// Tracks the status of transactions from the L1. These are tracked separately from tx-pool
@@ -53,7 +60,121 @@ impl AddTable<RelayedTransactionStatuses> for StateConfigBuilder {
     }
 }
 
+fuel_core_types::fuel_vm::double_key!(
+    OwnedRelayedTransactionKey,
+    Address,
+    owner,
+    Bytes32,
+    relayed_tx_id
+);
+
+/// The table of the failed relayed transactions per owner of their inputs.
+/// The entries outlive the pruned statuses in [`RelayedTransactionStatuses`].
+pub struct OwnedRelayedTransactionIds;
+
+impl Mappable for OwnedRelayedTransactionIds {
+    type Key = OwnedRelayedTransactionKey;
+    type OwnedKey = Self::Key;
+    type Value = ();
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for OwnedRelayedTransactionIds {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::OwnedRelayedTransactionIds
+    }
+}
+
+impl AsTable<OwnedRelayedTransactionIds> for StateConfig {
+    fn as_table(&self) -> Vec<TableEntry<OwnedRelayedTransactionIds>> {
+        Vec::new() // Do not include these for now
+    }
+}
+
+impl AddTable<OwnedRelayedTransactionIds> for StateConfigBuilder {
+    fn add(&mut self, _entries: Vec<TableEntry<OwnedRelayedTransactionIds>>) {
+        // Do not include these for now
+    }
+}
+
+/// The ids of the relayed transactions with a status, keyed by the height of the block
+/// that processed them. The table of statuses is keyed by the id, so the table is used
+/// to find the statuses older than the retention window without iterating over the statuses.
+pub struct RelayedTransactionStatusHeights;
+
+impl Mappable for RelayedTransactionStatusHeights {
+    type Key = Self::OwnedKey;
+    type OwnedKey = RelayedTransactionStatusHeightKey;
+    type Value = Self::OwnedValue;
+    type OwnedValue = ();
+}
+
+impl TableWithBlueprint for RelayedTransactionStatusHeights {
+    type Blueprint = Plain<Manual<RelayedTransactionStatusHeightKey>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::RelayedTransactionStatusHeights
+    }
+}
+
+impl AsTable<RelayedTransactionStatusHeights> for StateConfig {
+    fn as_table(&self) -> Vec<TableEntry<RelayedTransactionStatusHeights>> {
+        Vec::new() // Do not include these for now
+    }
+}
+
+impl AddTable<RelayedTransactionStatusHeights> for StateConfigBuilder {
+    fn add(&mut self, _entries: Vec<TableEntry<RelayedTransactionStatusHeights>>) {
+        // Do not include these for now
+    }
+}
+
+/// The height of the block that processed the relayed transaction and its id.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RelayedTransactionStatusHeightKey {
+    pub block_height: BlockHeight,
+    pub id: Bytes32,
+}
+
+impl RelayedTransactionStatusHeightKey {
+    pub const SIZE: usize = 4 + Bytes32::LEN;
+}
+
+impl Encode<RelayedTransactionStatusHeightKey>
+    for Manual<RelayedTransactionStatusHeightKey>
+{
+    type Encoder<'a> = [u8; RelayedTransactionStatusHeightKey::SIZE];
+
+    fn encode(t: &RelayedTransactionStatusHeightKey) -> Self::Encoder<'_> {
+        // block_height | id
+        let mut serialized_key = [0u8; RelayedTransactionStatusHeightKey::SIZE];
+        serialized_key[..4].copy_from_slice(&t.block_height.to_bytes());
+        serialized_key[4..].copy_from_slice(t.id.as_ref());
+        serialized_key
+    }
+}
+
+impl Decode<RelayedTransactionStatusHeightKey>
+    for Manual<RelayedTransactionStatusHeightKey>
+{
+    fn decode(bytes: &[u8]) -> anyhow::Result<RelayedTransactionStatusHeightKey> {
+        let bytes: [u8; RelayedTransactionStatusHeightKey::SIZE] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))?;
+        let (height, id) = bytes.split_at(4);
+        Ok(RelayedTransactionStatusHeightKey {
+            block_height: u32::from_be_bytes(height.try_into()?).into(),
+            id: Bytes32::try_from(id)?,
+        })
+    }
+}
+
 #[cfg(test)]
+#[allow(non_snake_case)]
 mod tests {
     use super::*;
     use fuel_core_types::fuel_tx::Bytes32;
@@ -66,4 +187,40 @@ mod tests {
             failure: "Some reason".to_string(),
         }
     );
+
+    fuel_core_storage::basic_storage_tests!(
+        OwnedRelayedTransactionIds,
+        <OwnedRelayedTransactionIds as Mappable>::Key::default(),
+        <OwnedRelayedTransactionIds as Mappable>::Value::default()
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        RelayedTransactionStatusHeights,
+        <RelayedTransactionStatusHeights as Mappable>::Key::default(),
+        <RelayedTransactionStatusHeights as Mappable>::Value::default()
+    );
+
+    #[test]
+    fn relayed_transaction_status_height_key__is_ordered_by_height() {
+        // given
+        let lower = RelayedTransactionStatusHeightKey {
+            block_height: 1.into(),
+            id: [u8::MAX; 32].into(),
+        };
+        let higher = RelayedTransactionStatusHeightKey {
+            block_height: 256.into(),
+            id: [0; 32].into(),
+        };
+
+        // when
+        let lower_bytes = Manual::<RelayedTransactionStatusHeightKey>::encode(&lower);
+        let higher_bytes = Manual::<RelayedTransactionStatusHeightKey>::encode(&higher);
+
+        // then
+        assert!(lower_bytes < higher_bytes);
+        assert_eq!(
+            Manual::<RelayedTransactionStatusHeightKey>::decode(&higher_bytes).unwrap(),
+            higher
+        );
+    }
 }
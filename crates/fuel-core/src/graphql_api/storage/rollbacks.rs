@@ -0,0 +1,53 @@
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        raw::Raw,
+    },
+    structured_storage::TableWithBlueprint,
+    Mappable,
+};
+use fuel_core_types::{
+    blockchain::primitives::BlockId,
+    fuel_types::BlockHeight,
+};
+
+/// The maximum number of the ids of the rolled back blocks kept in the [`ChainRollback`].
+pub const MAX_ROLLBACK_BLOCK_IDS: usize = 64;
+
+/// The rollback of the databases from the `from_height` to the `to_height`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ChainRollback {
+    /// The height of the databases before the rollback.
+    pub from_height: BlockHeight,
+    /// The height of the databases after the rollback.
+    pub to_height: BlockHeight,
+    /// The ids of the rolled back blocks in the order of their heights. `None` if
+    /// more than [`MAX_ROLLBACK_BLOCK_IDS`] blocks were rolled back, or if only
+    /// the off-chain database was reverted and the ids of its blocks are unknown.
+    pub block_ids: Option<Vec<BlockId>>,
+}
+
+/// The key of the [`LastChainRollback`] in the metadata column. The key
+/// of the database metadata is encoded as the empty bytes, so they don't overlap.
+pub const LAST_CHAIN_ROLLBACK_KEY: [u8; 13] = *b"last_rollback";
+
+/// The last rollback of the off-chain database. The record isn't a part of the changes
+/// of any block, so it survives the following rollbacks until it is replaced.
+pub struct LastChainRollback;
+
+impl Mappable for LastChainRollback {
+    type Key = [u8; 13];
+    type OwnedKey = Self::Key;
+    type Value = ChainRollback;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for LastChainRollback {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::Metadata
+    }
+}
@@ -1,9 +1,14 @@
+use core::time::Duration;
 use fuel_core_storage::{
     blueprint::plain::Plain,
     codec::postcard::Postcard,
     structured_storage::TableWithBlueprint,
     Mappable,
 };
+use fuel_core_types::fuel_types::{
+    Address,
+    BlockHeight,
+};
 
 /// The table that stores all statistic about blockchain. Each key is a string, while the value
 /// depends on the context.
@@ -30,3 +35,204 @@ where
         Self::Column::Statistic
     }
 }
+
+/// The maximum number of addresses tracked by the [`TopAddressesByTxCount`].
+pub const TOP_ADDRESSES_BY_TX_COUNT_LIMIT: usize = 100;
+
+/// The number of transactions that involve the `address`.
+#[derive(Clone, Copy, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub struct AddressTxCount {
+    pub address: Address,
+    pub tx_count: u64,
+}
+
+/// The bounded set of the most active addresses by the number of transactions.
+///
+/// Sorting the counters of all addresses is too expensive, so only
+/// [`TOP_ADDRESSES_BY_TX_COUNT_LIMIT`] addresses are tracked. When the set is full,
+/// a new address replaces the tracked address with the lowest count and inherits
+/// that count. Because of it, the count of an address that entered the set
+/// after the eviction is an upper bound, and addresses below the tracked
+/// threshold are not represented at all.
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub struct TopAddressesByTxCount {
+    entries: Vec<AddressTxCount>,
+}
+
+impl TopAddressesByTxCount {
+    /// Records `tx_count` more transactions for the `address`.
+    pub fn record(&mut self, address: &Address, tx_count: u64) {
+        if let Some(entry) = self.entries.iter_mut().find(|e| &e.address == address) {
+            entry.tx_count = entry.tx_count.saturating_add(tx_count);
+        } else if self.entries.len() < TOP_ADDRESSES_BY_TX_COUNT_LIMIT {
+            self.entries.push(AddressTxCount {
+                address: *address,
+                tx_count,
+            });
+        } else if let Some(entry) = self.entries.iter_mut().min_by_key(|e| e.tx_count) {
+            entry.address = *address;
+            entry.tx_count = entry.tx_count.saturating_add(tx_count);
+        }
+    }
+
+    /// Returns up to `limit` addresses sorted by the number of transactions
+    /// in descending order.
+    pub fn top(&self, limit: usize) -> Vec<AddressTxCount> {
+        let mut entries = self.entries.clone();
+        entries.sort_by(|a, b| {
+            b.tx_count
+                .cmp(&a.tx_count)
+                .then_with(|| a.address.cmp(&b.address))
+        });
+        entries.truncate(limit);
+        entries
+    }
+}
+
+/// The number of blocks after which the [`CommitDurationMax`] expires.
+pub const COMMIT_DURATION_MAX_WINDOW: u32 = 10_000;
+
+/// The longest commit of the block indexes into the off-chain database
+/// within the last [`COMMIT_DURATION_MAX_WINDOW`] blocks.
+#[derive(Clone, Copy, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub struct CommitDurationMax {
+    /// The height of the block with the longest commit.
+    pub height: BlockHeight,
+    /// The duration of the commit.
+    pub duration: Duration,
+}
+
+impl CommitDurationMax {
+    /// Records the commit of the block at the `height`. The commit replaces
+    /// the maximum if it is longer, or if the maximum left the window.
+    /// Returns `true` if the maximum changed.
+    pub fn record(
+        max: &mut Option<Self>,
+        height: BlockHeight,
+        duration: Duration,
+    ) -> bool {
+        let replace = match max {
+            Some(max) => {
+                let expires_at =
+                    u32::from(max.height).saturating_add(COMMIT_DURATION_MAX_WINDOW);
+                duration > max.duration || u32::from(height) >= expires_at
+            }
+            None => true,
+        };
+        if replace {
+            *max = Some(Self { height, duration });
+        }
+        replace
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn top__sorted_by_tx_count() {
+        // given
+        let mut top = TopAddressesByTxCount::default();
+        let first = Address::new([1; 32]);
+        let second = Address::new([2; 32]);
+        top.record(&first, 1);
+        top.record(&second, 1);
+        top.record(&second, 1);
+
+        // when
+        let result = top.top(10);
+
+        // then
+        assert_eq!(
+            result,
+            vec![
+                AddressTxCount {
+                    address: second,
+                    tx_count: 2
+                },
+                AddressTxCount {
+                    address: first,
+                    tx_count: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn record__replaces_least_active_address_when_full() {
+        // given
+        let mut top = TopAddressesByTxCount::default();
+        for i in 0..TOP_ADDRESSES_BY_TX_COUNT_LIMIT {
+            let address = Address::new([u8::try_from(i).unwrap(); 32]);
+            top.record(&address, 1);
+            top.record(&address, 1);
+        }
+        let least_active = Address::new([0; 32]);
+        let new_address = Address::new([u8::MAX; 32]);
+
+        // when
+        top.record(&new_address, 1);
+
+        // then
+        let result = top.top(TOP_ADDRESSES_BY_TX_COUNT_LIMIT);
+        assert_eq!(result.len(), TOP_ADDRESSES_BY_TX_COUNT_LIMIT);
+        assert_eq!(
+            result[0],
+            AddressTxCount {
+                address: new_address,
+                tx_count: 3
+            }
+        );
+        assert!(!result.iter().any(|e| e.address == least_active));
+    }
+
+    #[test]
+    fn record__adds_all_transactions_of_the_block_at_once() {
+        // given
+        let mut top = TopAddressesByTxCount::default();
+        let address = Address::new([1; 32]);
+        top.record(&address, 1);
+
+        // when
+        top.record(&address, 3);
+
+        // then
+        assert_eq!(
+            top.top(10),
+            vec![AddressTxCount {
+                address,
+                tx_count: 4
+            }]
+        );
+    }
+
+    #[test]
+    fn record__keeps_the_longest_commit_until_it_leaves_the_window() {
+        // given
+        let mut max = None;
+        let long = Duration::from_millis(100);
+        let short = Duration::from_millis(10);
+        CommitDurationMax::record(&mut max, 1.into(), long);
+
+        // when
+        let shorter_replaced = CommitDurationMax::record(&mut max, 2.into(), short);
+        let expired_replaced = CommitDurationMax::record(
+            &mut max,
+            (1 + COMMIT_DURATION_MAX_WINDOW).into(),
+            short,
+        );
+
+        // then
+        assert!(!shorter_replaced);
+        assert!(expired_replaced);
+        assert_eq!(
+            max,
+            Some(CommitDurationMax {
+                height: (1 + COMMIT_DURATION_MAX_WINDOW).into(),
+                duration: short,
+            })
+        );
+    }
+}
@@ -21,6 +21,7 @@ use fuel_core_types::{
     fuel_tx::{
         Address,
         Bytes32,
+        ContractId,
     },
     fuel_types::BlockHeight,
     services::txpool::TransactionExecutionStatus,
@@ -31,17 +32,19 @@ use std::{
 };
 
 /// These tables allow iteration over all transactions owned by an address.
+/// The value is the id of the transaction tagged with the [`OwnedTransactionDirection`].
 pub struct OwnedTransactions;
 
 impl Mappable for OwnedTransactions {
     type Key = OwnedTransactionIndexKey;
     type OwnedKey = Self::Key;
-    type Value = Bytes32;
+    type Value = OwnedTransactionValue;
     type OwnedValue = Self::Value;
 }
 
 impl TableWithBlueprint for OwnedTransactions {
-    type Blueprint = Plain<Manual<OwnedTransactionIndexKey>, Raw>;
+    type Blueprint =
+        Plain<Manual<OwnedTransactionIndexKey>, Manual<OwnedTransactionValue>>;
     type Column = super::Column;
 
     fn column() -> Self::Column {
@@ -61,6 +64,38 @@ impl AddTable<OwnedTransactions> for StateConfigBuilder {
     }
 }
 
+/// These tables allow iteration over all transactions that reference a contract
+/// in their inputs or outputs.
+pub struct ContractTransactions;
+
+impl Mappable for ContractTransactions {
+    type Key = ContractTransactionIndexKey;
+    type OwnedKey = Self::Key;
+    type Value = Bytes32;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for ContractTransactions {
+    type Blueprint = Plain<Manual<ContractTransactionIndexKey>, Raw>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::TransactionsByContract
+    }
+}
+
+impl AsTable<ContractTransactions> for StateConfig {
+    fn as_table(&self) -> Vec<TableEntry<ContractTransactions>> {
+        Vec::new() // Do not include these for now
+    }
+}
+
+impl AddTable<ContractTransactions> for StateConfigBuilder {
+    fn add(&mut self, _entries: Vec<TableEntry<ContractTransactions>>) {
+        // Do not include these for now
+    }
+}
+
 /// The table stores the status of each transaction.
 pub struct TransactionStatuses;
 
@@ -92,12 +127,34 @@ impl AddTable<TransactionStatuses> for StateConfigBuilder {
     }
 }
 
+/// The table stores the tags attached to the transactions by the integrators,
+/// e.g. the ids of their internal requests. The tags are the off-chain annotations,
+/// kept apart from the [`TransactionStatuses`], so the updates of the status
+/// don't affect them.
+pub struct TransactionTags;
+
+impl Mappable for TransactionTags {
+    type Key = Bytes32;
+    type OwnedKey = Self::Key;
+    type Value = Self::OwnedValue;
+    type OwnedValue = Vec<u8>;
+}
+
+impl TableWithBlueprint for TransactionTags {
+    type Blueprint = Plain<Raw, Raw>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::TransactionTags
+    }
+}
+
 const TX_INDEX_SIZE: usize = size_of::<TransactionIndex>();
 const BLOCK_HEIGHT: usize = size_of::<BlockHeight>();
 const INDEX_SIZE: usize = Address::LEN + BLOCK_HEIGHT + TX_INDEX_SIZE;
 
 fn owned_tx_index_key(
-    owner: &Address,
+    owner: &[u8; Address::LEN],
     height: BlockHeight,
     tx_idx: TransactionIndex,
 ) -> [u8; INDEX_SIZE] {
@@ -179,6 +236,153 @@ impl Decode<OwnedTransactionIndexKey> for Manual<OwnedTransactionIndexKey> {
     }
 }
 
+/// The key of the [`ContractTransactions`] table. The keys share the layout of the
+/// [`OwnedTransactionIndexKey`] with the contract id in place of the owner.
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+pub struct ContractTransactionIndexKey {
+    pub contract_id: ContractId,
+    pub block_height: BlockHeight,
+    pub tx_idx: TransactionIndex,
+}
+
+impl ContractTransactionIndexKey {
+    pub fn new(
+        contract_id: &ContractId,
+        block_height: BlockHeight,
+        tx_idx: TransactionIndex,
+    ) -> Self {
+        Self {
+            contract_id: *contract_id,
+            block_height,
+            tx_idx,
+        }
+    }
+}
+
+impl From<[u8; INDEX_SIZE]> for ContractTransactionIndexKey {
+    fn from(bytes: [u8; INDEX_SIZE]) -> Self {
+        let OwnedTransactionIndexKey {
+            owner,
+            block_height,
+            tx_idx,
+        } = bytes.into();
+        Self {
+            contract_id: ContractId::new(*owner),
+            block_height,
+            tx_idx,
+        }
+    }
+}
+
+impl Encode<ContractTransactionIndexKey> for Manual<ContractTransactionIndexKey> {
+    type Encoder<'a> = [u8; INDEX_SIZE];
+
+    fn encode(t: &ContractTransactionIndexKey) -> Self::Encoder<'_> {
+        owned_tx_index_key(&t.contract_id, t.block_height, t.tx_idx)
+    }
+}
+
+impl Decode<ContractTransactionIndexKey> for Manual<ContractTransactionIndexKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<ContractTransactionIndexKey> {
+        let bytes: [u8; INDEX_SIZE] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))?;
+        Ok(bytes.into())
+    }
+}
+
+/// The role of the owner in the transaction.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub enum OwnedTransactionDirection {
+    /// The owner appears only in the inputs.
+    Sent,
+    /// The owner appears only in the outputs.
+    Received,
+    /// The owner appears in the inputs and the outputs, or the entry
+    /// was recorded before the direction was tracked.
+    #[default]
+    Any,
+}
+
+impl OwnedTransactionDirection {
+    /// Returns the direction of the owner appearing in the inputs and/or the outputs.
+    pub fn new(in_inputs: bool, in_outputs: bool) -> Self {
+        match (in_inputs, in_outputs) {
+            (true, false) => Self::Sent,
+            (false, true) => Self::Received,
+            _ => Self::Any,
+        }
+    }
+
+    /// Returns `true` if the transaction with `self` direction passes the `filter`.
+    /// The [`OwnedTransactionDirection::Any`] entries pass any filter.
+    pub fn matches(&self, filter: Self) -> bool {
+        filter == Self::Any || *self == Self::Any || *self == filter
+    }
+
+    fn tag(&self) -> u8 {
+        match self {
+            Self::Any => 0,
+            Self::Sent => 1,
+            Self::Received => 2,
+        }
+    }
+
+    fn from_tag(tag: u8) -> anyhow::Result<Self> {
+        match tag {
+            0 => Ok(Self::Any),
+            1 => Ok(Self::Sent),
+            2 => Ok(Self::Received),
+            _ => Err(anyhow::anyhow!("Unknown transaction direction {tag}")),
+        }
+    }
+}
+
+/// The value of the [`OwnedTransactions`] table.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct OwnedTransactionValue {
+    pub tx_id: Bytes32,
+    pub direction: OwnedTransactionDirection,
+}
+
+const OWNED_TX_VALUE_SIZE: usize = Bytes32::LEN + 1;
+
+impl Encode<OwnedTransactionValue> for Manual<OwnedTransactionValue> {
+    type Encoder<'a> = [u8; OWNED_TX_VALUE_SIZE];
+
+    fn encode(t: &OwnedTransactionValue) -> Self::Encoder<'_> {
+        let mut bytes = [0u8; OWNED_TX_VALUE_SIZE];
+        bytes[..Bytes32::LEN].copy_from_slice(t.tx_id.as_ref());
+        bytes[Bytes32::LEN] = t.direction.tag();
+        bytes
+    }
+}
+
+impl Decode<OwnedTransactionValue> for Manual<OwnedTransactionValue> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<OwnedTransactionValue> {
+        // The entries recorded before the direction was tracked contain only the id.
+        let (tx_id, direction) = match bytes.len() {
+            Bytes32::LEN => (bytes, OwnedTransactionDirection::Any),
+            OWNED_TX_VALUE_SIZE => (
+                &bytes[..Bytes32::LEN],
+                OwnedTransactionDirection::from_tag(bytes[Bytes32::LEN])?,
+            ),
+            _ => return Err(anyhow::anyhow!("Unable to decode bytes")),
+        };
+        let tx_id: [u8; Bytes32::LEN] = tx_id
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))?;
+        Ok(OwnedTransactionValue {
+            tx_id: tx_id.into(),
+            direction,
+        })
+    }
+}
+
 #[derive(Clone, Debug, PartialOrd, Eq, PartialEq)]
 pub struct OwnedTransactionIndexCursor {
     pub block_height: BlockHeight,
@@ -235,6 +439,53 @@ mod test {
         generate_key
     );
 
+    fuel_core_storage::basic_storage_tests!(
+        ContractTransactions,
+        [1u8; INDEX_SIZE].into(),
+        <ContractTransactions as Mappable>::Value::default(),
+        <ContractTransactions as Mappable>::Value::default(),
+        generate_contract_key
+    );
+
+    #[test]
+    fn owned_transaction_value__legacy_id_decodes_as_any_direction() {
+        // Given
+        let tx_id = Bytes32::from([3u8; 32]);
+
+        // When
+        let value: OwnedTransactionValue =
+            <Manual<OwnedTransactionValue> as Decode<_>>::decode(tx_id.as_ref()).unwrap();
+
+        // Then
+        assert_eq!(value.tx_id, tx_id);
+        assert_eq!(value.direction, OwnedTransactionDirection::Any);
+    }
+
+    #[test]
+    fn owned_transaction_value__round_trips_the_direction() {
+        // Given
+        let value = OwnedTransactionValue {
+            tx_id: Bytes32::from([3u8; 32]),
+            direction: OwnedTransactionDirection::Received,
+        };
+
+        // When
+        let encoded = <Manual<OwnedTransactionValue> as Encode<_>>::encode(&value);
+        let decoded: OwnedTransactionValue =
+            <Manual<OwnedTransactionValue> as Decode<_>>::decode(&encoded).unwrap();
+
+        // Then
+        assert_eq!(decoded, value);
+    }
+
+    fn generate_contract_key(
+        rng: &mut impl rand::Rng,
+    ) -> <ContractTransactions as Mappable>::Key {
+        let mut bytes = [0u8; INDEX_SIZE];
+        rng.fill(bytes.as_mut());
+        bytes.into()
+    }
+
     fuel_core_storage::basic_storage_tests!(
         TransactionStatuses,
         <TransactionStatuses as Mappable>::Key::default(),
@@ -242,4 +493,64 @@ mod test {
             time: fuel_core_types::tai64::Tai64::UNIX_EPOCH,
         }
     );
+
+    fuel_core_storage::basic_storage_tests!(
+        TransactionTags,
+        <TransactionTags as Mappable>::Key::default(),
+        vec![1u8, 2, 3]
+    );
+
+    #[test]
+    fn transaction_statuses__legacy_squeezed_out_decodes_with_unknown_reason() {
+        use fuel_core_types::services::txpool::{
+            RejectionReason,
+            TransactionStatus,
+        };
+
+        // Given
+        // The variant index of `SqueezedOut` followed by the length-prefixed reason,
+        // as it was stored before the rejection reasons were tracked.
+        let mut legacy = vec![2u8, 4];
+        legacy.extend_from_slice(b"full");
+
+        // When
+        let status: TransactionExecutionStatus =
+            <Postcard as Decode<_>>::decode(&legacy).unwrap();
+
+        // Then
+        assert_eq!(
+            status,
+            TransactionExecutionStatus::SqueezedOut {
+                reason: "full".to_string()
+            }
+        );
+        assert_eq!(status.rejection_reason(), Some(RejectionReason::Unknown));
+        let TransactionStatus::SqueezedOut(status) = status.into() else {
+            panic!("Expected the squeezed out status")
+        };
+        assert_eq!(status.rejection_reason, RejectionReason::Unknown);
+    }
+
+    #[test]
+    fn transaction_statuses__rejected_round_trips_the_reason() {
+        use fuel_core_types::services::txpool::RejectionReason;
+
+        // Given
+        let status = TransactionExecutionStatus::Rejected {
+            reason: "expired".to_string(),
+            rejection_reason: RejectionReason::TtlExpired,
+        };
+
+        // When
+        let encoded = <Postcard as Encode<_>>::encode(&status);
+        let decoded: TransactionExecutionStatus =
+            <Postcard as Decode<_>>::decode(&encoded).unwrap();
+
+        // Then
+        assert_eq!(decoded, status);
+        assert_eq!(
+            decoded.rejection_reason(),
+            Some(RejectionReason::TtlExpired)
+        );
+    }
 }
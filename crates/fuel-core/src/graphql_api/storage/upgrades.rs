@@ -0,0 +1,60 @@
+use fuel_core_chain_config::{
+    AddTable,
+    AsTable,
+    StateConfig,
+    StateConfigBuilder,
+    TableEntry,
+};
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        primitive::Primitive,
+    },
+    structured_storage::TableWithBlueprint,
+    Mappable,
+};
+use fuel_core_types::{
+    blockchain::header::ConsensusParametersVersion,
+    fuel_tx::ConsensusParameters,
+};
+
+/// Every version of the consensus parameters seen by the node, including the versions
+/// from before regenesis. Has same form as
+/// [`ConsensusParametersVersions`](fuel_core_storage::tables::ConsensusParametersVersions).
+pub struct ConsensusParametersHistory;
+
+impl Mappable for ConsensusParametersHistory {
+    type Key = Self::OwnedKey;
+    type OwnedKey = ConsensusParametersVersion;
+    type Value = Self::OwnedValue;
+    type OwnedValue = ConsensusParameters;
+}
+
+impl TableWithBlueprint for ConsensusParametersHistory {
+    type Blueprint = Plain<Primitive<4>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::ConsensusParametersHistory
+    }
+}
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    ConsensusParametersHistory,
+    <ConsensusParametersHistory as Mappable>::Key::default(),
+    <ConsensusParametersHistory as Mappable>::Value::default()
+);
+
+impl AsTable<ConsensusParametersHistory> for StateConfig {
+    fn as_table(&self) -> Vec<TableEntry<ConsensusParametersHistory>> {
+        Vec::new() // Do not include these for now
+    }
+}
+
+impl AddTable<ConsensusParametersHistory> for StateConfigBuilder {
+    fn add(&mut self, _entries: Vec<TableEntry<ConsensusParametersHistory>>) {
+        // Do not include these for now
+    }
+}
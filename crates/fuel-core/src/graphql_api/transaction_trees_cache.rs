@@ -0,0 +1,138 @@
+use fuel_core_types::{
+    blockchain::primitives::BlockId,
+    fuel_merkle::{
+        binary::in_memory::MerkleTree,
+        common::ProofSet,
+    },
+    fuel_tx::Transaction,
+    fuel_types::{
+        canonical::Serialize,
+        Bytes32,
+    },
+};
+use quick_cache::sync::Cache;
+use std::sync::Arc;
+
+/// The Merkle tree of the transactions of the block, the same as the one
+/// used to calculate the `transactions_root` of the block header.
+pub struct TransactionTree {
+    tree: MerkleTree,
+    leaves: u64,
+}
+
+impl TransactionTree {
+    pub fn new(transactions: &[Transaction]) -> Self {
+        let mut tree = MerkleTree::new();
+        for tx in transactions {
+            tree.push(tx.to_bytes().as_ref());
+        }
+        Self {
+            tree,
+            leaves: transactions.len() as u64,
+        }
+    }
+
+    pub fn root(&self) -> Bytes32 {
+        self.tree.root().into()
+    }
+
+    /// The number of the transactions in the tree.
+    pub fn leaves(&self) -> u64 {
+        self.leaves
+    }
+
+    /// Returns the proof set of the transaction at the `index`.
+    pub fn prove(&self, index: u64) -> Option<ProofSet> {
+        let (_, proof_set) = self.tree.prove(index)?;
+        Some(proof_set)
+    }
+}
+
+/// The cache of the transaction trees of the blocks.
+///
+/// The explorers request the inclusion proofs for many transactions of
+/// the same block, so the tree is built once per block instead of fetching
+/// all transactions of the block for each proof. The trees are keyed by
+/// the block id, so the trees of the blocks removed by the rollback are
+/// never served.
+#[derive(Clone)]
+pub struct TransactionTreesCache {
+    trees: Option<Arc<Cache<BlockId, Arc<TransactionTree>>>>,
+}
+
+impl TransactionTreesCache {
+    /// Creates the cache holding up to `capacity` trees.
+    /// The zero `capacity` disables the cache.
+    pub fn new(capacity: usize) -> Self {
+        let trees = (capacity > 0).then(|| Arc::new(Cache::new(capacity)));
+        Self { trees }
+    }
+
+    /// Returns the cached tree of the block.
+    pub fn get(&self, block_id: &BlockId) -> Option<Arc<TransactionTree>> {
+        self.trees.as_ref()?.get(block_id)
+    }
+
+    /// Caches the tree of the block.
+    pub fn insert(&self, block_id: BlockId, tree: Arc<TransactionTree>) {
+        if let Some(trees) = &self.trees {
+            trees.insert(block_id, tree);
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_merkle::binary::verify;
+
+    fn transactions(count: u8) -> Vec<Transaction> {
+        (0..count)
+            .map(|i| {
+                fuel_core_types::fuel_tx::TransactionBuilder::script(vec![i], vec![])
+                    .finalize_as_transaction()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn prove__returns_the_proof_verifiable_against_the_root() {
+        // given
+        let transactions = transactions(5);
+        let tree = TransactionTree::new(&transactions);
+
+        // when
+        let proof_set = tree.prove(3).unwrap();
+
+        // then
+        assert!(verify(
+            &tree.root().into(),
+            &transactions[3].to_bytes(),
+            &proof_set,
+            3,
+            tree.leaves(),
+        ));
+        assert!(!verify(
+            &tree.root().into(),
+            &transactions[2].to_bytes(),
+            &proof_set,
+            3,
+            tree.leaves(),
+        ));
+    }
+
+    #[test]
+    fn get__returns_nothing_when_the_cache_is_disabled() {
+        // given
+        let cache = TransactionTreesCache::new(0);
+        let block_id = BlockId::from([1; 32]);
+        cache.insert(block_id, Arc::new(TransactionTree::new(&transactions(1))));
+
+        // when
+        let tree = cache.get(&block_id);
+
+        // then
+        assert!(tree.is_none());
+    }
+}
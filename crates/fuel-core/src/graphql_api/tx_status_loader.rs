@@ -0,0 +1,196 @@
+use crate::fuel_core_graphql_api::database::ReadView;
+use fuel_core_storage::{
+    Error as StorageError,
+    Result as StorageResult,
+};
+use fuel_core_types::{
+    fuel_tx::TxId,
+    services::txpool::TransactionExecutionStatus,
+};
+use parking_lot::Mutex;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+};
+use tokio::sync::watch;
+
+type LoadedStatuses = HashMap<TxId, Result<Option<TransactionExecutionStatus>, String>>;
+
+/// The source of the transaction statuses for the [`TransactionStatusLoader`].
+pub trait TxStatusSource {
+    /// Returns the statuses of the transactions in the order of the `tx_ids`.
+    fn tx_statuses(
+        &self,
+        tx_ids: &[TxId],
+    ) -> Vec<StorageResult<Option<TransactionExecutionStatus>>>;
+}
+
+impl TxStatusSource for ReadView {
+    fn tx_statuses(
+        &self,
+        tx_ids: &[TxId],
+    ) -> Vec<StorageResult<Option<TransactionExecutionStatus>>> {
+        ReadView::tx_statuses(self, tx_ids)
+    }
+}
+
+struct Batch {
+    tx_ids: Vec<TxId>,
+    loaded: watch::Receiver<Option<Arc<LoadedStatuses>>>,
+}
+
+/// Coalesces the reads of the transaction statuses requested by the resolvers
+/// within one tick of the executor into one request to the storage.
+///
+/// The first load of the batch yields to let the concurrent resolvers join it,
+/// and then reads the whole batch from its `source`. The loader is created
+/// per GraphQL request, so all loads of the batch share the same view.
+#[derive(Default, Clone)]
+pub struct TransactionStatusLoader {
+    pending: Arc<Mutex<Option<Batch>>>,
+}
+
+impl TransactionStatusLoader {
+    /// Returns the status of the transaction, or `None` if it has no status.
+    pub async fn load<S>(
+        &self,
+        source: &S,
+        tx_id: TxId,
+    ) -> StorageResult<Option<TransactionExecutionStatus>>
+    where
+        S: TxStatusSource + ?Sized,
+    {
+        let joined = {
+            let mut pending = self.pending.lock();
+            match pending.as_mut() {
+                // The batch is abandoned if the load reading it was dropped.
+                Some(batch) if batch.loaded.has_changed().is_ok() => {
+                    batch.tx_ids.push(tx_id);
+                    Some(batch.loaded.clone())
+                }
+                _ => None,
+            }
+        };
+
+        let loaded = match joined {
+            Some(mut loaded) => loaded
+                .wait_for(Option::is_some)
+                .await
+                .map_err(|_| {
+                    StorageError::Other(anyhow::anyhow!(
+                        "The batch of the transaction statuses was dropped"
+                    ))
+                })?
+                .clone()
+                .expect("The `wait_for` returns only the loaded statuses; qed"),
+            None => self.load_batch(source, tx_id).await,
+        };
+
+        match loaded.get(&tx_id) {
+            Some(Ok(status)) => Ok(status.clone()),
+            Some(Err(err)) => Err(StorageError::Other(anyhow::anyhow!("{err}"))),
+            None => Err(StorageError::Other(anyhow::anyhow!(
+                "The transaction {tx_id} is missing in the loaded batch"
+            ))),
+        }
+    }
+
+    async fn load_batch<S>(&self, source: &S, tx_id: TxId) -> Arc<LoadedStatuses>
+    where
+        S: TxStatusSource + ?Sized,
+    {
+        let (sender, loaded) = watch::channel(None);
+        *self.pending.lock() = Some(Batch {
+            tx_ids: vec![tx_id],
+            loaded,
+        });
+
+        // Give a chance to other resolvers to join the batch.
+        tokio::task::yield_now().await;
+
+        let tx_ids = self
+            .pending
+            .lock()
+            .take()
+            .map(|batch| batch.tx_ids)
+            .unwrap_or_else(|| vec![tx_id]);
+        let statuses = source.tx_statuses(&tx_ids);
+        let loaded: LoadedStatuses = tx_ids
+            .into_iter()
+            .zip(statuses)
+            .map(|(tx_id, status)| (tx_id, status.map_err(|err| err.to_string())))
+            .collect();
+        let loaded = Arc::new(loaded);
+        sender.send_replace(Some(loaded.clone()));
+        loaded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::tai64::Tai64;
+    use std::sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    };
+
+    #[derive(Default)]
+    struct CountingSource {
+        calls: AtomicUsize,
+    }
+
+    impl TxStatusSource for CountingSource {
+        fn tx_statuses(
+            &self,
+            tx_ids: &[TxId],
+        ) -> Vec<StorageResult<Option<TransactionExecutionStatus>>> {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            tx_ids
+                .iter()
+                .map(|tx_id| {
+                    Ok((tx_id[0] % 2 == 0).then(|| {
+                        TransactionExecutionStatus::Submitted {
+                            time: Tai64(u64::from(tx_id[0])),
+                        }
+                    }))
+                })
+                .collect()
+        }
+    }
+
+    #[tokio::test]
+    async fn load__coalesces_concurrent_loads_into_one_storage_call() {
+        // given
+        let loader = TransactionStatusLoader::default();
+        let source = CountingSource::default();
+        let tx_ids: Vec<TxId> = (0..100u8).map(|i| [i; 32].into()).collect();
+
+        // when
+        let statuses = futures::future::join_all(
+            tx_ids.iter().map(|tx_id| loader.load(&source, *tx_id)),
+        )
+        .await;
+
+        // then
+        assert_eq!(source.calls.load(Ordering::Relaxed), 1);
+        for (tx_id, status) in tx_ids.iter().zip(statuses) {
+            let status = status.unwrap();
+            assert_eq!(status.is_some(), tx_id[0] % 2 == 0);
+        }
+    }
+
+    #[tokio::test]
+    async fn load__sequential_loads_use_separate_storage_calls() {
+        // given
+        let loader = TransactionStatusLoader::default();
+        let source = CountingSource::default();
+
+        // when
+        loader.load(&source, [0; 32].into()).await.unwrap();
+        loader.load(&source, [1; 32].into()).await.unwrap();
+
+        // then
+        assert_eq!(source.calls.load(Ordering::Relaxed), 2);
+    }
+}
@@ -1,4 +1,11 @@
-use self::indexation::error::IndexationError;
+use self::indexation::{
+    derived_ownership::{
+        self,
+        CoinOwnerMapperRef,
+    },
+    error::IndexationError,
+    owner_activity::OwnerActivityUpdates,
+};
 
 use super::{
     block_height_subscription,
@@ -17,25 +24,77 @@ use crate::{
             worker::{
                 BlockAt,
                 OffChainDatabaseTransaction,
+                RelayedTransactionsProviderRef,
             },
         },
         storage::{
-            blocks::FuelBlockIdsToHeights,
+            balances::CoinBalancesKey,
+            blocks::{
+                BlockTransactionIds,
+                BlocksByProducer,
+                BlocksByProducerKey,
+                CollectedFees,
+                FuelBlockIdsToHeights,
+                UNKNOWN_BLOCK_PRODUCER,
+            },
             coins::{
+                insert_owned_coin,
+                owned_coins_layout,
                 owner_coin_id_key,
-                OwnedCoins,
+                spend_owned_coin,
+                OwnedCoinByAssetKey,
+                OwnedCoinCountByAsset,
+                OwnedCoinState,
+                OwnedCoinsByAsset,
+                OwnedCoinsLayout,
+                PredicateOwnedCoins,
+                SpentCoin,
+                SpentCoinsByBlock,
+                SpentCoinsByBlockKey,
+            },
+            contracts::{
+                ContractDeployment,
+                ContractsByBlock,
+                ContractsByBlockKey,
+                ContractsInfo,
+            },
+            dead_letter::IndexationDeadLetter,
+            known_owners::{
+                KnownOwner,
+                KnownPredicateOwners,
             },
-            contracts::ContractsInfo,
             messages::{
+                MessagesBySender,
                 OwnedMessageIds,
                 OwnedMessageKey,
-                SpentMessages,
+                OwnedMessageState,
+                SenderMessageKey,
+                SpentOwnedMessage,
+            },
+            relayed_transactions::{
+                OwnedRelayedTransactionIds,
+                OwnedRelayedTransactionKey,
+                RelayedTransactionStatusHeightKey,
             },
+            statistic::CommitDurationMax,
+            transactions::{
+                ContractTransactionIndexKey,
+                ContractTransactions,
+                OwnedTransactionDirection,
+            },
+            upgrades::ConsensusParametersHistory,
         },
     },
     graphql_api::{
+        contracts_info_cache::{
+            modified_contracts,
+            ContractsInfoCache,
+        },
         query_costs,
-        storage::relayed_transactions::RelayedTransactionStatuses,
+        storage::{
+            merge_tx_count_delta,
+            Column,
+        },
     },
 };
 use fuel_core_metrics::graphql_metrics::graphql_metrics;
@@ -48,9 +107,19 @@ use fuel_core_services::{
     TaskNextAction,
 };
 use fuel_core_storage::{
+    kv_store::KeyValueInspect,
+    transactional::{
+        Changes,
+        Modifiable,
+        ReadTransaction,
+        StorageChanges,
+        StorageTransaction,
+        WriteTransaction,
+    },
     Error as StorageError,
     Result as StorageResult,
     StorageAsMut,
+    StorageAsRef,
 };
 use fuel_core_tx_status_manager::from_executor_to_status;
 use fuel_core_types::{
@@ -60,33 +129,44 @@ use fuel_core_types::{
             CompressedBlock,
         },
         consensus::Consensus,
+        primitives::BlockId,
     },
     entities::relayer::transaction::RelayedTransactionStatus,
+    fuel_crypto::Hasher,
     fuel_tx::{
         field::{
             Inputs,
             Outputs,
             Salt,
             StorageSlots,
+            Tip,
+            UpgradePurpose as UpgradePurposeField,
+            Witnesses,
         },
         input::coin::{
             CoinPredicate,
             CoinSigned,
         },
+        Address,
         AssetId,
         ConsensusParameters,
         Contract,
+        Create,
         Input,
         Output,
         Receipt,
         Transaction,
         TxId,
         UniqueIdentifier,
+        UpgradePurpose,
     },
     fuel_types::{
+        canonical::Deserialize,
         BlockHeight,
         Bytes32,
         ChainId,
+        ContractId,
+        Nonce,
     },
     services::{
         block_importer::{
@@ -106,7 +186,18 @@ use futures::{
 };
 use std::{
     borrow::Cow,
-    ops::Deref,
+    collections::{
+        BTreeMap,
+        BTreeSet,
+        HashMap,
+    },
+    num::NonZeroUsize,
+    sync::Arc,
+    thread::ScopedJoinHandle,
+    time::{
+        Duration,
+        Instant,
+    },
 };
 #[cfg(test)]
 mod tests;
@@ -116,9 +207,16 @@ pub(crate) struct Context<'a, TxStatusManager, BlockImporter, OnChain, OffChain>
     pub(crate) block_importer: BlockImporter,
     pub(crate) on_chain_database: OnChain,
     pub(crate) off_chain_database: OffChain,
-    pub(crate) da_compression_config: DaCompressionConfig,
     pub(crate) continue_on_error: bool,
+    pub(crate) historical_data_retention: Option<u32>,
+    pub(crate) batch_blocks: NonZeroUsize,
     pub(crate) consensus_parameters: &'a ConsensusParameters,
+    pub(crate) contracts_info_cache: ContractsInfoCache,
+    pub(crate) coin_owner_mapper: Option<CoinOwnerMapperRef>,
+    pub(crate) relayed_transactions: Option<RelayedTransactionsProviderRef>,
+    pub(crate) mark_spent_owned_coins: bool,
+    pub(crate) slow_commit_threshold: Duration,
+    pub(crate) reconcile_tx_count: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -130,7 +228,6 @@ pub enum DaCompressionConfig {
 /// The initialization task recovers the state of the GraphQL service database on startup.
 pub struct InitializeTask<TxStatusManager, BlockImporter, OnChain, OffChain> {
     chain_id: ChainId,
-    da_compression_config: DaCompressionConfig,
     continue_on_error: bool,
     tx_status_manager: TxStatusManager,
     blocks_events: BoxStream<SharedImportResult>,
@@ -138,7 +235,16 @@ pub struct InitializeTask<TxStatusManager, BlockImporter, OnChain, OffChain> {
     on_chain_database: OnChain,
     off_chain_database: OffChain,
     base_asset_id: AssetId,
+    historical_data_retention: Option<u32>,
+    batch_blocks: NonZeroUsize,
     block_height_subscription_handler: block_height_subscription::Handler,
+    contracts_info_cache: ContractsInfoCache,
+    coin_owner_mapper: Option<CoinOwnerMapperRef>,
+    relayed_transactions: Option<RelayedTransactionsProviderRef>,
+    mark_spent_owned_coins: bool,
+    slow_commit_threshold: Duration,
+    /// Recomputes the total number of transactions on startup.
+    reconcile_tx_count: bool,
 }
 
 /// The off-chain GraphQL API worker task processes the imported blocks
@@ -148,13 +254,73 @@ pub struct Task<TxStatusManager, D> {
     block_importer: BoxStream<SharedImportResult>,
     database: D,
     chain_id: ChainId,
-    da_compression_config: DaCompressionConfig,
     continue_on_error: bool,
     balances_indexation_enabled: bool,
     coins_to_spend_indexation_enabled: bool,
     asset_metadata_indexation_enabled: bool,
+    contract_balances_indexation_enabled: bool,
     base_asset_id: AssetId,
+    /// The number of blocks for which the historical data, like the statuses of relayed
+    /// transactions and the supply history of assets, is kept.
+    /// The data is kept forever if it is `None`.
+    historical_data_retention: Option<u32>,
+    /// The number of blocks indexed in one batch during the catch-up sync
+    /// with the on-chain database.
+    batch_blocks: NonZeroUsize,
     block_height_subscription_handler: block_height_subscription::Handler,
+    /// The cache of the contracts info evicted on writes to the `ContractsInfo` table.
+    contracts_info_cache: ContractsInfoCache,
+    /// The mapper of the coins to their logical owners, if the derived ownership is indexed.
+    coin_owner_mapper: Option<CoinOwnerMapperRef>,
+    /// The provider of the relayed transactions, used to index the failed ones by their owners.
+    relayed_transactions: Option<RelayedTransactionsProviderRef>,
+    /// Flags the spent coins in the `OwnedCoins` for the compaction instead of removing them.
+    mark_spent_owned_coins: bool,
+    /// The commits of the block indexes longer than the threshold are logged as slow.
+    slow_commit_threshold: Duration,
+    /// The longest commit within the recent blocks, mirrored into the database.
+    commit_duration_max: Option<CommitDurationMax>,
+    indexation_retry: IndexationRetry,
+    /// The block that failed to be indexed and waits for the next attempt.
+    failed_block: Option<FailedBlock>,
+    /// The height of the recorded dead letter, if any.
+    dead_letter_height: Option<BlockHeight>,
+}
+
+/// The retry policy of the block indexation.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct IndexationRetry {
+    /// The number of attempts to index a block before it is recorded as the dead letter.
+    pub(crate) attempts: u32,
+    /// The delay before the first retry. It doubles with each next retry.
+    pub(crate) initial_backoff: Duration,
+    /// The upper bound of the delay between retries.
+    pub(crate) max_backoff: Duration,
+}
+
+impl Default for IndexationRetry {
+    fn default() -> Self {
+        Self {
+            attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+}
+
+impl IndexationRetry {
+    /// Returns the delay before the next attempt after `attempts` failed attempts.
+    pub(crate) fn backoff(&self, attempts: u32) -> Duration {
+        let exponent = attempts.saturating_sub(1).min(16);
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(exponent))
+            .min(self.max_backoff)
+    }
+}
+
+struct FailedBlock {
+    block: SharedImportResult,
+    attempts: u32,
 }
 
 impl<TxStatusManager, D> Task<TxStatusManager, D>
@@ -162,48 +328,117 @@ where
     TxStatusManager: ports::worker::TxStatusCompletion,
     D: ports::worker::OffChainDatabase,
 {
+    fn indexation_config(&self) -> IndexationConfig {
+        IndexationConfig {
+            chain_id: self.chain_id,
+            // The DA compression runs in its own service, so its failures
+            // don't stall the indexation.
+            da_compression_config: DaCompressionConfig::Disabled,
+            balances_indexation_enabled: self.balances_indexation_enabled,
+            coins_to_spend_indexation_enabled: self.coins_to_spend_indexation_enabled,
+            asset_metadata_indexation_enabled: self.asset_metadata_indexation_enabled,
+            contract_balances_indexation_enabled: self
+                .contract_balances_indexation_enabled,
+            base_asset_id: self.base_asset_id,
+            coin_owner_mapper: self.coin_owner_mapper.clone(),
+            relayed_transactions: self.relayed_transactions.clone(),
+            mark_spent_owned_coins: self.mark_spent_owned_coins,
+        }
+    }
+
+    /// Returns the height of the last fully indexed block. The height is committed
+    /// together with the indexes of the block, so the block at or below it was
+    /// indexed, and the block above it wasn't indexed at all.
+    fn indexed_height(&self) -> StorageResult<Option<BlockHeight>> {
+        self.database.latest_height()
+    }
+
+    /// Processes the block unless it is already indexed. The importer may deliver
+    /// the block again, e.g. after a crash between the on-chain and the off-chain
+    /// commits, and indexing it twice would double the tx count and the balances.
     fn process_block(&mut self, result: SharedImportResult) -> anyhow::Result<()> {
-        let block = &result.sealed_block.entity;
-        let mut transaction = self.database.transaction();
-        // save the status for every transaction using the finalized block id
-        persist_transaction_status(
+        if is_indexed(self.indexed_height()?, &result) {
+            return Ok(())
+        }
+        let config = self.indexation_config();
+        let indexed_block = commit_block_indexes(
+            &config,
+            self.historical_data_retention,
             &result,
-            self.asset_metadata_indexation_enabled,
-            &mut transaction,
+            &mut self.database,
         )?;
+        self.complete_block(&result, indexed_block);
+        Ok(())
+    }
 
-        // save the associated owner for each transaction in the block
-        index_tx_owners_for_block(block, &mut transaction, &self.chain_id)?;
-
-        // save the transaction related information
-        process_transactions(block.transactions().iter(), &mut transaction)?;
-
-        let height = block.header().height();
-        let block_id = block.id();
-        transaction
-            .storage_as_mut::<FuelBlockIdsToHeights>()
-            .insert(&block_id, height)?;
-
-        let total_tx_count = transaction
-            .increase_tx_count(block.transactions().len() as u64)
-            .unwrap_or_default();
+    /// Indexes the `blocks` on top of each other in one transaction and commits
+    /// the indexes of all of them at once. The height is advanced to the last block
+    /// only by this commit, so the failure, or the crash, before it leaves the whole
+    /// batch to be processed again. The subscribers are notified after the commit.
+    fn process_blocks(&mut self, blocks: Vec<SharedImportResult>) -> anyhow::Result<()> {
+        let indexed_height = self.indexed_height()?;
+        let blocks = blocks
+            .into_iter()
+            .filter(|block| !is_indexed(indexed_height, block))
+            .collect::<Vec<_>>();
+        let config = self.indexation_config();
+        // The expired statuses are looked up in the committed database, so the statuses
+        // recorded by the batch itself are pruned by the blocks after it.
+        let expired_relayed_statuses = blocks
+            .iter()
+            .map(|block| {
+                expired_relayed_tx_statuses(
+                    self.historical_data_retention,
+                    *block.sealed_block.entity.header().height(),
+                    &self.database,
+                )
+            })
+            .collect::<StorageResult<Vec<_>>>()?;
+
+        let mut indexed_blocks = Vec::with_capacity(blocks.len());
+        let mut changes_of_blocks = Vec::with_capacity(blocks.len());
+        let mut batch = self.database.transaction();
+        for (block, expired) in blocks.into_iter().zip(expired_relayed_statuses) {
+            let (indexed_block, changes) = derive_block_indexes(
+                &config,
+                self.historical_data_retention,
+                &expired,
+                &block,
+                &mut batch,
+            )?;
+            stack_block_changes(&mut batch, changes.clone())?;
+            changes_of_blocks.push(changes);
+            indexed_blocks.push((block, indexed_block));
+        }
+        // The batch is only used to index the blocks on top of each other.
+        drop(batch);
 
-        process_executor_events(
-            result.events.iter().map(Cow::Borrowed),
-            &mut transaction,
-            self.balances_indexation_enabled,
-            self.coins_to_spend_indexation_enabled,
-            &self.base_asset_id,
-        )?;
+        let start = Instant::now();
+        self.database.commit_blocks(changes_of_blocks)?;
+        let commit_duration = start.elapsed();
 
-        match self.da_compression_config {
-            DaCompressionConfig::Disabled => {}
-            DaCompressionConfig::Enabled(config) => {
-                da_compress_block(config, block, &result.events, &mut transaction)?;
-            }
+        for (block, mut indexed_block) in indexed_blocks {
+            indexed_block.commit_duration = commit_duration;
+            self.complete_block(&block, indexed_block);
         }
+        Ok(())
+    }
 
-        transaction.commit()?;
+    /// Notifies the subscribers about the committed block.
+    fn complete_block(&mut self, result: &ImportResult, indexed_block: IndexedBlock) {
+        let block = &result.sealed_block.entity;
+        let height = block.header().height();
+        let IndexedBlock {
+            total_tx_count,
+            pruned_relayed_tx_statuses,
+            modified_contracts,
+            updated_balances,
+            processing_duration,
+            phases,
+            commit_duration,
+        } = indexed_block;
+        // Evict after the commit, so the cache can't be repopulated with the old info.
+        self.contracts_info_cache.invalidate(&modified_contracts);
 
         for status in result.tx_status.iter() {
             let tx_id = status.id;
@@ -215,19 +450,797 @@ where
         // Notify subscribers and update last seen block height
         self.block_height_subscription_handler
             .notify_and_update(*height);
+        self.block_height_subscription_handler.notify_block_indexed(
+            block_height_subscription::BlockIndexed {
+                height: *height,
+                tx_count: block.header().transactions_count(),
+                timestamp: block.header().time(),
+            },
+        );
+        self.block_height_subscription_handler
+            .notify_balances_updated(block_height_subscription::BalancesUpdated {
+                height: *height,
+                balances: Arc::new(updated_balances),
+            });
         // Get all the subscribers that need to be notified that the block height
         // has been reached.
 
         // update the importer metrics after the block is successfully committed
         graphql_metrics().total_txs_count.set(total_tx_count as i64);
+        if let Some(pruned) = pruned_relayed_tx_statuses {
+            graphql_metrics().observe_pruned_relayed_tx_statuses(pruned);
+        }
+        observe_block_index(processing_duration, commit_duration, &phases);
+        self.observe_commit(
+            *height,
+            u64::from(block.header().transactions_count()),
+            processing_duration,
+            commit_duration,
+        );
+    }
 
+    /// Records the durations of the block indexation, warns about the slow commit,
+    /// and persists the longest commit within the recent blocks.
+    /// Returns `true` if the commit was slow.
+    fn observe_commit(
+        &mut self,
+        height: BlockHeight,
+        tx_count: u64,
+        processing_duration: Duration,
+        commit_duration: Duration,
+    ) -> bool {
+        graphql_metrics().observe_off_chain_block_processing(
+            tx_count,
+            processing_duration.as_secs_f64(),
+        );
+        graphql_metrics()
+            .observe_off_chain_commit(tx_count, commit_duration.as_secs_f64());
+
+        let slow = commit_duration > self.slow_commit_threshold;
+        if slow {
+            graphql_metrics().record_off_chain_slow_commit();
+            tracing::warn!(
+                %height,
+                tx_count,
+                ?commit_duration,
+                threshold = ?self.slow_commit_threshold,
+                "The commit of the block indexes is slow"
+            );
+        }
+
+        // The maximum is written after the commit it measures, so it doesn't change
+        // the height and is only written when it changes.
+        if CommitDurationMax::record(
+            &mut self.commit_duration_max,
+            height,
+            commit_duration,
+        ) {
+            if let Some(max) = &self.commit_duration_max {
+                if let Err(error) = self.database.set_commit_duration_max(max) {
+                    tracing::error!(%height, "Failed to record the commit duration: {error:?}");
+                }
+            }
+        }
+        slow
+    }
+
+    /// Processes the block, and schedules the next attempt if it fails.
+    /// After the retries are exhausted, the block is recorded as the dead letter,
+    /// which is cleared once the block is processed. The service keeps retrying
+    /// the block in the background if it continues on errors, and stops otherwise.
+    fn index_block(
+        &mut self,
+        block: SharedImportResult,
+        previous_attempts: u32,
+    ) -> TaskNextAction {
+        let height = *block.sealed_block.entity.header().height();
+        let Err(e) = self.process_block(block.clone()) else {
+            return TaskNextAction::always_continue(self.clear_dead_letter(height));
+        };
+
+        let attempts = previous_attempts.saturating_add(1);
+        tracing::warn!(%height, attempts, "Failed to index the block: {e:?}");
+
+        if attempts == self.indexation_retry.attempts {
+            graphql_metrics().record_indexation_dead_letter();
+            let dead_letter = IndexationDeadLetter {
+                height,
+                error: format!("{e:?}"),
+            };
+            if let Err(error) =
+                self.database.set_indexation_dead_letter(Some(&dead_letter))
+            {
+                tracing::error!(%height, "Failed to record the dead letter: {error:?}");
+            } else {
+                self.dead_letter_height = Some(height);
+            }
+        }
+
+        // The block is retried in the background, or by the shutdown
+        // if the service stops.
+        self.failed_block = Some(FailedBlock { block, attempts });
+        if attempts >= self.indexation_retry.attempts && !self.continue_on_error {
+            // Stop the service to avoid a huge de-synchronization
+            // between on-chain and off-chain databases.
+            return TaskNextAction::Stop
+        }
+        TaskNextAction::ErrorContinue(e)
+    }
+
+    /// Clears the dead letter once the block at its height is indexed.
+    fn clear_dead_letter(&mut self, indexed_height: BlockHeight) -> anyhow::Result<()> {
+        if self.dead_letter_height == Some(indexed_height) {
+            self.database.set_indexation_dead_letter(None)?;
+            self.dead_letter_height = None;
+        }
         Ok(())
     }
 }
 
-/// Process the executor events and update the indexes for the messages and coins.
+/// Returns `true` if the block is at or below the `indexed_height`.
+fn is_indexed(indexed_height: Option<BlockHeight>, block: &ImportResult) -> bool {
+    let height = *block.sealed_block.entity.header().height();
+    let indexed = indexed_height.is_some_and(|indexed_height| height <= indexed_height);
+    if indexed {
+        tracing::debug!(%height, "Skipping the replayed block that is already indexed");
+    }
+    indexed
+}
+
+/// The outcome of the block indexation committed into the off-chain database.
+pub struct IndexedBlock {
+    /// The total number of transactions on the chain.
+    pub total_tx_count: u64,
+    /// The number of pruned statuses of relayed transactions.
+    /// It is `None` if the pruning is disabled.
+    pub pruned_relayed_tx_statuses: Option<u64>,
+    /// The contracts with the info modified by the block.
+    pub modified_contracts: Vec<ContractId>,
+    /// The owner and asset pairs with the balances modified by the block.
+    pub updated_balances: BTreeSet<(Address, AssetId)>,
+    /// The time spent deriving the indexes of the block.
+    pub processing_duration: Duration,
+    /// The time spent on each phase of the derivation of the indexes.
+    pub phases: IndexingPhases,
+    /// The time spent committing the indexes into the database. The blocks
+    /// committed in one batch share the duration of the batch commit.
+    pub commit_duration: Duration,
+}
+
+/// The phase of the block indexation, measured separately to find the slow one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IndexingPhase {
+    /// The owned coins and the coins to spend.
+    Coins,
+    /// The owned messages.
+    Messages,
+    /// The statuses, the owners, and the contracts of the transactions.
+    Transactions,
+    /// The counters, the fees, the balances, and the activity of the owners.
+    Statistics,
+}
+
+impl IndexingPhase {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IndexingPhase::Coins => "coins",
+            IndexingPhase::Messages => "messages",
+            IndexingPhase::Transactions => "transactions",
+            IndexingPhase::Statistics => "statistics",
+        }
+    }
+
+    /// Returns the phase indexing the `event`.
+    fn of(event: &Event) -> Self {
+        match event {
+            Event::CoinCreated(_) | Event::CoinConsumed(_) => IndexingPhase::Coins,
+            Event::MessageImported(_) | Event::MessageConsumed(_) => {
+                IndexingPhase::Messages
+            }
+            Event::ForcedTransactionFailed { .. } => IndexingPhase::Transactions,
+        }
+    }
+}
+
+/// The time spent on each phase of the block indexation. The index families
+/// run in parallel, so the sum of the phases may exceed the processing duration.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct IndexingPhases {
+    pub coins: Duration,
+    pub messages: Duration,
+    pub transactions: Duration,
+    pub statistics: Duration,
+}
+
+impl IndexingPhases {
+    pub fn get(&self, phase: IndexingPhase) -> Duration {
+        match phase {
+            IndexingPhase::Coins => self.coins,
+            IndexingPhase::Messages => self.messages,
+            IndexingPhase::Transactions => self.transactions,
+            IndexingPhase::Statistics => self.statistics,
+        }
+    }
+
+    fn get_mut(&mut self, phase: IndexingPhase) -> &mut Duration {
+        match phase {
+            IndexingPhase::Coins => &mut self.coins,
+            IndexingPhase::Messages => &mut self.messages,
+            IndexingPhase::Transactions => &mut self.transactions,
+            IndexingPhase::Statistics => &mut self.statistics,
+        }
+    }
+
+    /// Runs the `f` within the span of the `phase` and adds its duration to the phase.
+    fn measure<R>(&mut self, phase: IndexingPhase, f: impl FnOnce() -> R) -> R {
+        let (result, duration) = measure_phase(phase, f);
+        self.add(phase, duration);
+        result
+    }
+
+    fn add(&mut self, phase: IndexingPhase, duration: Duration) {
+        let total = self.get_mut(phase);
+        *total = total.saturating_add(duration);
+    }
+}
+
+/// Runs the `f` within the span of the `phase` and returns its duration.
+fn measure_phase<R>(phase: IndexingPhase, f: impl FnOnce() -> R) -> (R, Duration) {
+    let _span =
+        tracing::debug_span!("offchain_index_phase", phase = phase.as_str()).entered();
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Exports the durations of the indexation of the block by phase.
+fn observe_block_index(
+    processing_duration: Duration,
+    commit_duration: Duration,
+    phases: &IndexingPhases,
+) {
+    let metrics = graphql_metrics();
+    metrics.observe_off_chain_block_index(
+        "total",
+        processing_duration
+            .saturating_add(commit_duration)
+            .as_secs_f64(),
+    );
+    metrics.observe_off_chain_block_index("commit", commit_duration.as_secs_f64());
+    for phase in [
+        IndexingPhase::Coins,
+        IndexingPhase::Messages,
+        IndexingPhase::Transactions,
+        IndexingPhase::Statistics,
+    ] {
+        metrics.observe_off_chain_block_index(
+            phase.as_str(),
+            phases.get(phase).as_secs_f64(),
+        );
+    }
+}
+
+/// Derives the off-chain indexes of the imported block, prunes the outdated
+/// historical data, and commits the result into the `database`.
+pub fn commit_block_indexes<D>(
+    config: &IndexationConfig,
+    historical_data_retention: Option<u32>,
+    import_result: &ImportResult,
+    database: &mut D,
+) -> anyhow::Result<IndexedBlock>
+where
+    D: ports::worker::OffChainDatabase,
+{
+    let height = *import_result.sealed_block.entity.header().height();
+    let expired_relayed_statuses =
+        expired_relayed_tx_statuses(historical_data_retention, height, database)?;
+    let mut transaction = database.transaction();
+    let (mut indexed_block, changes) = derive_block_indexes(
+        config,
+        historical_data_retention,
+        &expired_relayed_statuses,
+        import_result,
+        &mut transaction,
+    )?;
+    indexed_block.commit_duration = timed_commit(transaction, changes)?;
+
+    Ok(indexed_block)
+}
+
+/// Applies the `changes` to the `transaction` and commits it into the database.
+/// Returns the duration of the commit.
+fn timed_commit<T>(mut transaction: T, changes: Changes) -> StorageResult<Duration>
+where
+    T: OffChainDatabaseTransaction + Modifiable,
+{
+    let start = Instant::now();
+    transaction.commit_changes(changes)?;
+    transaction.commit()?;
+    Ok(start.elapsed())
+}
+
+/// Applies the `changes` of the block to the `batch`, so the next block of the batch
+/// is indexed on top of them. The delta of the number of transactions is folded into
+/// the total, so the changes of the next block only count its own transactions, and
+/// each block adds its delta on top of the previous ones during the commit.
+fn stack_block_changes<T>(batch: &mut T, changes: Changes) -> StorageResult<()>
+where
+    T: KeyValueInspect<Column = Column> + Modifiable,
+{
+    let folded = match merge_tx_count_delta(&*batch, StorageChanges::Changes(changes))? {
+        StorageChanges::Changes(changes) => vec![changes],
+        StorageChanges::ChangesList(list) => list,
+    };
+    for changes in folded {
+        batch.commit_changes(changes)?;
+    }
+    Ok(())
+}
+
+/// The maximum number of the statuses of the relayed transactions pruned
+/// in one block, so a large backlog doesn't stall the indexation.
+const MAX_PRUNED_RELAYED_TX_STATUSES: usize = 10_000;
+
+/// Returns the statuses of the relayed transactions that left the `retention`
+/// window at the `height`. The transaction of the block can't iterate over
+/// the index, so the statuses are read from the committed `database`.
+fn expired_relayed_tx_statuses<D>(
+    retention: Option<u32>,
+    height: BlockHeight,
+    database: &D,
+) -> StorageResult<Vec<RelayedTransactionStatusHeightKey>>
+where
+    D: ports::worker::OffChainDatabase,
+{
+    match retention.and_then(|retention| u32::from(height).checked_sub(retention)) {
+        Some(oldest_kept) => database.relayed_tx_statuses_before(
+            oldest_kept.into(),
+            MAX_PRUNED_RELAYED_TX_STATUSES,
+        ),
+        None => Ok(vec![]),
+    }
+}
+
+/// Derives the off-chain indexes of the imported block and prunes the outdated
+/// historical data on top of the `storage`. Returns the outcome of the block
+/// and its changes, which are not applied to the `storage`.
+pub fn derive_block_indexes<S>(
+    config: &IndexationConfig,
+    historical_data_retention: Option<u32>,
+    expired_relayed_statuses: &[RelayedTransactionStatusHeightKey],
+    import_result: &ImportResult,
+    storage: &mut S,
+) -> anyhow::Result<(IndexedBlock, Changes)>
+where
+    S: KeyValueInspect<Column = Column> + Modifiable + Sync,
+{
+    let start = Instant::now();
+    let height = *import_result.sealed_block.entity.header().height();
+    let mut transaction = storage.write_transaction();
+    let (total_tx_count, phases) =
+        index_block_phases_in_parallel(config, import_result, &mut transaction)?;
+    let pruned_relayed_tx_statuses = prune_historical_data(
+        historical_data_retention,
+        height,
+        expired_relayed_statuses,
+        &mut transaction,
+    )?;
+
+    let changes = transaction.into_changes();
+    let indexed_block = IndexedBlock {
+        total_tx_count,
+        pruned_relayed_tx_statuses,
+        modified_contracts: modified_contracts(&changes),
+        updated_balances: indexation::balances::updated_balances(
+            &changes,
+            &config.base_asset_id,
+        ),
+        processing_duration: start.elapsed(),
+        phases,
+        commit_duration: Duration::ZERO,
+    };
+    Ok((indexed_block, changes))
+}
+
+/// Prunes the historical data that left the `retention` window at the `height`:
+/// the `expired` statuses of relayed transactions, the supply history of assets,
+/// and the spent coins.
+/// Returns the number of pruned statuses, or `None` if the pruning is disabled.
+fn prune_historical_data<T>(
+    retention: Option<u32>,
+    height: BlockHeight,
+    expired: &[RelayedTransactionStatusHeightKey],
+    transaction: &mut T,
+) -> anyhow::Result<Option<u64>>
+where
+    T: OffChainDatabaseTransaction,
+{
+    let Some(retention) = retention else {
+        return Ok(None);
+    };
+    let pruned = match u32::from(height).checked_sub(retention) {
+        Some(oldest_kept) => {
+            transaction.prune_asset_supply_history_before(oldest_kept.into())?;
+            transaction.prune_spent_coins_before(oldest_kept.into())?;
+            transaction.prune_relayed_statuses(expired)?
+        }
+        None => 0,
+    };
+    Ok(Some(pruned))
+}
+
+/// The settings that define which off-chain indexes are derived from the block.
+#[derive(Debug, Clone)]
+pub struct IndexationConfig {
+    pub chain_id: ChainId,
+    pub da_compression_config: DaCompressionConfig,
+    pub balances_indexation_enabled: bool,
+    pub coins_to_spend_indexation_enabled: bool,
+    pub asset_metadata_indexation_enabled: bool,
+    pub contract_balances_indexation_enabled: bool,
+    pub base_asset_id: AssetId,
+    /// Indexes the coins under their logical owners besides their owners.
+    pub coin_owner_mapper: Option<CoinOwnerMapperRef>,
+    /// Recovers the owners of the failed relayed transactions from their payloads.
+    pub relayed_transactions: Option<RelayedTransactionsProviderRef>,
+    /// Flags the spent coins in the `OwnedCoins` instead of removing them.
+    pub mark_spent_owned_coins: bool,
+}
+
+/// Derives the off-chain indexes of the imported block one index family
+/// after another. Returns the total number of transactions on the chain.
+pub fn index_block_serially<T>(
+    config: &IndexationConfig,
+    import_result: &ImportResult,
+    transaction: &mut T,
+) -> anyhow::Result<u64>
+where
+    T: OffChainDatabaseTransaction,
+{
+    let mut phases = IndexingPhases::default();
+    let total_tx_count =
+        index_transactions(config, import_result, transaction, &mut phases)?;
+    index_balances(config, import_result, transaction)?;
+    index_coins_to_spend(config, import_result, transaction)?;
+    index_da_compression(config, import_result, transaction)?;
+    Ok(total_tx_count)
+}
+
+/// Derives the same indexes as [`index_block_serially`], but each index family
+/// runs on its own thread.
+///
+/// Families write disjoint sets of columns and never read the columns of each
+/// other, so every family works on a separate overlay over the `transaction`.
+/// The changes of the overlays are merged into the `transaction` in a fixed
+/// order only after all families succeed, so the final state doesn't depend
+/// on the scheduling, and a failure of any family leaves the `transaction`
+/// untouched.
+pub fn index_block_in_parallel<T>(
+    config: &IndexationConfig,
+    import_result: &ImportResult,
+    transaction: &mut T,
+) -> anyhow::Result<u64>
+where
+    T: OffChainDatabaseTransaction + KeyValueInspect<Column = Column> + Modifiable + Sync,
+{
+    let (total_tx_count, _) =
+        index_block_phases_in_parallel(config, import_result, transaction)?;
+    Ok(total_tx_count)
+}
+
+/// Runs [`index_block_in_parallel`] and returns the time spent on each phase.
+fn index_block_phases_in_parallel<T>(
+    config: &IndexationConfig,
+    import_result: &ImportResult,
+    transaction: &mut T,
+) -> anyhow::Result<(u64, IndexingPhases)>
+where
+    T: OffChainDatabaseTransaction + KeyValueInspect<Column = Column> + Modifiable + Sync,
+{
+    let storage = &*transaction;
+    let mut phases = IndexingPhases::default();
+    let (
+        transactions,
+        (balances, balances_duration),
+        (coins_to_spend, coins_duration),
+        da_compression,
+    ) = std::thread::scope(|scope| {
+        let balances = scope.spawn(|| {
+            measure_phase(IndexingPhase::Statistics, || {
+                derive_in_overlay(storage, |tx| index_balances(config, import_result, tx))
+            })
+        });
+        let coins_to_spend = scope.spawn(|| {
+            measure_phase(IndexingPhase::Coins, || {
+                derive_in_overlay(storage, |tx| {
+                    index_coins_to_spend(config, import_result, tx)
+                })
+            })
+        });
+        let da_compression = scope.spawn(|| {
+            derive_in_overlay(storage, |tx| {
+                index_da_compression(config, import_result, tx)
+            })
+        });
+        let transactions = derive_in_overlay(storage, |tx| {
+            index_transactions(config, import_result, tx, &mut phases)
+        });
+
+        (
+            transactions,
+            join_family(balances),
+            join_family(coins_to_spend),
+            join_family(da_compression),
+        )
+    });
+
+    let (total_tx_count, transactions) = transactions?;
+    let ((), balances) = balances?;
+    let ((), coins_to_spend) = coins_to_spend?;
+    let ((), da_compression) = da_compression?;
+
+    for changes in [transactions, balances, coins_to_spend, da_compression] {
+        transaction.commit_changes(changes)?;
+    }
+    phases.add(IndexingPhase::Statistics, balances_duration);
+    phases.add(IndexingPhase::Coins, coins_duration);
+
+    Ok((total_tx_count, phases))
+}
+
+/// Runs `f` against an empty overlay over the `storage` and returns the changes
+/// made by it.
+pub(crate) fn derive_in_overlay<S, F, R>(
+    storage: &S,
+    f: F,
+) -> anyhow::Result<(R, Changes)>
+where
+    S: KeyValueInspect<Column = Column>,
+    F: FnOnce(&mut StorageTransaction<&mut StorageTransaction<&S>>) -> anyhow::Result<R>,
+{
+    let mut overlay = storage.read_transaction();
+    let mut transaction = overlay.write_transaction();
+    let result = f(&mut transaction)?;
+    Ok((result, transaction.into_changes()))
+}
+
+fn join_family<R>(handle: ScopedJoinHandle<'_, R>) -> R {
+    handle
+        .join()
+        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+}
+
+/// Indexes the transactions of the block: statuses, owners, contracts, and
+/// the coins and messages owned by addresses.
+fn index_transactions<T>(
+    config: &IndexationConfig,
+    import_result: &ImportResult,
+    db: &mut T,
+    phases: &mut IndexingPhases,
+) -> anyhow::Result<u64>
+where
+    T: OffChainDatabaseTransaction,
+{
+    let block = &import_result.sealed_block.entity;
+    let block_height = *block.header().height();
+    let mut owner_activity = OwnerActivityUpdates::default();
+    let tx_ids = phases.measure(IndexingPhase::Transactions, || {
+        index_transactions_of_block(config, import_result, &mut owner_activity, db)
+    })?;
+
+    let total_tx_count = phases.measure(IndexingPhase::Statistics, || {
+        index_block_fees(import_result, db)?;
+        let total_tx_count = db
+            .increase_tx_count(block.transactions().len() as u64)
+            .unwrap_or_default();
+        Ok::<_, StorageError>(total_tx_count)
+    })?;
+
+    // The mapper of the logical owners looks at the transaction that created the coin.
+    let transactions: HashMap<TxId, &Transaction> = match config.coin_owner_mapper {
+        Some(_) => tx_ids.iter().copied().zip(block.transactions()).collect(),
+        None => HashMap::new(),
+    };
+    let message_spenders = message_spenders(
+        tx_ids.iter().zip(block.transactions()),
+        &import_result.events,
+    );
+    let layout = owned_coins_layout(db)?;
+    // The events of different phases update disjoint entries,
+    // so each phase indexes its events in the order of the block.
+    for phase in [
+        IndexingPhase::Coins,
+        IndexingPhase::Messages,
+        IndexingPhase::Transactions,
+    ] {
+        phases.measure(phase, || {
+            let events = import_result
+                .events
+                .iter()
+                .filter(|event| IndexingPhase::of(event) == phase);
+            for event in events {
+                update_owned_indexes(
+                    event,
+                    block_height,
+                    db,
+                    config.mark_spent_owned_coins,
+                    layout,
+                    &message_spenders,
+                )?;
+                derived_ownership::update(
+                    event,
+                    block_height,
+                    &transactions,
+                    config.coin_owner_mapper.as_deref(),
+                    config.mark_spent_owned_coins,
+                    layout,
+                    db,
+                )?;
+                owner_activity.record_event(event, block_height);
+            }
+            Ok::<_, anyhow::Error>(())
+        })?;
+    }
+    phases.measure(IndexingPhase::Statistics, || owner_activity.apply(db))?;
+
+    Ok(total_tx_count)
+}
+
+/// Indexes the statuses, the owners, and the contracts of the transactions of the block.
+/// Returns the ids of the transactions.
+fn index_transactions_of_block<T>(
+    config: &IndexationConfig,
+    import_result: &ImportResult,
+    owner_activity: &mut OwnerActivityUpdates,
+    db: &mut T,
+) -> anyhow::Result<Vec<TxId>>
+where
+    T: OffChainDatabaseTransaction,
+{
+    let block = &import_result.sealed_block.entity;
+    // The supply history reads the supply before the block,
+    // so it goes before the receipts of the block update it.
+    handle_indexation_result(indexation::asset_metadata::update_supply_history(
+        import_result.tx_status.iter(),
+        *block.header().height(),
+        db,
+        config.asset_metadata_indexation_enabled,
+    ))?;
+    handle_indexation_result(indexation::asset_metadata::update_first_seen(
+        import_result.tx_status.iter(),
+        import_result.events.iter(),
+        *block.header().height(),
+        block.header().time(),
+        db,
+        config.asset_metadata_indexation_enabled,
+    ))?;
+
+    // save the status for every transaction using the finalized block id
+    persist_transaction_status(
+        import_result,
+        config.asset_metadata_indexation_enabled,
+        db,
+    )?;
+    index_failed_relayed_transactions_owners(config, import_result, db)?;
+
+    let block_height = *block.header().height();
+
+    // save the associated owner for each transaction in the block
+    index_tx_owners_for_block(block, db, &config.chain_id, owner_activity)?;
+
+    // save the transaction related information
+    process_transactions(block.transactions().iter(), db)?;
+    index_consensus_parameters_upgrades(import_result, db)?;
+
+    db.storage_as_mut::<FuelBlockIdsToHeights>()
+        .insert(&block.id(), block.header().height())?;
+    index_block_producer(
+        block_height,
+        &block.id(),
+        &import_result.sealed_block.consensus,
+        db,
+    )?;
+
+    let tx_ids = import_result
+        .tx_status
+        .iter()
+        .map(|status| status.id)
+        .collect::<Vec<_>>();
+    db.storage_as_mut::<BlockTransactionIds>()
+        .insert(&block_height, &tx_ids)?;
+    index_contracts_by_block(block_height, tx_ids.iter().zip(block.transactions()), db)?;
+    index_spent_coins(
+        block_height,
+        tx_ids.iter().zip(block.transactions()),
+        &import_result.events,
+        db,
+    )?;
+
+    Ok(tx_ids)
+}
+
+fn index_balances<T>(
+    config: &IndexationConfig,
+    import_result: &ImportResult,
+    db: &mut T,
+) -> anyhow::Result<()>
+where
+    T: OffChainDatabaseTransaction,
+{
+    for event in import_result.events.iter() {
+        handle_indexation_result(indexation::balances::update(
+            event,
+            db,
+            config.balances_indexation_enabled,
+        ))?;
+    }
+    handle_indexation_result(indexation::contract_balances::update(
+        &import_result.sealed_block.entity,
+        import_result.tx_status.iter(),
+        &config.base_asset_id,
+        db,
+        config.contract_balances_indexation_enabled,
+    ))?;
+    Ok(())
+}
+
+fn index_coins_to_spend<T>(
+    config: &IndexationConfig,
+    import_result: &ImportResult,
+    db: &mut T,
+) -> anyhow::Result<()>
+where
+    T: OffChainDatabaseTransaction,
+{
+    for event in import_result.events.iter() {
+        handle_indexation_result(indexation::coins_to_spend::update(
+            event,
+            db,
+            config.coins_to_spend_indexation_enabled,
+            &config.base_asset_id,
+        ))?;
+    }
+    Ok(())
+}
+
+fn index_da_compression<T>(
+    config: &IndexationConfig,
+    import_result: &ImportResult,
+    db: &mut T,
+) -> anyhow::Result<()>
+where
+    T: OffChainDatabaseTransaction,
+{
+    match config.da_compression_config {
+        DaCompressionConfig::Disabled => Ok(()),
+        DaCompressionConfig::Enabled(da_config) => da_compress_block(
+            da_config,
+            &import_result.sealed_block.entity,
+            &import_result.events,
+            db,
+        ),
+    }
+}
+
+/// Storage errors abort the processing of the block, while other
+/// indexation errors are only logged.
+fn handle_indexation_result(result: Result<(), IndexationError>) -> anyhow::Result<()> {
+    match result {
+        Ok(()) => Ok(()),
+        Err(IndexationError::StorageError(err)) => Err(err.into()),
+        Err(err) => {
+            // TODO[RC]: Indexation errors to be correctly handled. See: https://github.com/FuelLabs/fuel-core/issues/2428
+            tracing::error!("Indexation error: {}", err);
+            Ok(())
+        }
+    }
+}
+
+/// Process the executor events of the block at the `block_height`
+/// and update the indexes for the messages and coins.
 pub fn process_executor_events<'a, Iter, T>(
     events: Iter,
+    block_height: BlockHeight,
     block_st_transaction: &mut T,
     balances_indexation_enabled: bool,
     coins_to_spend_indexation_enabled: bool,
@@ -237,74 +1250,193 @@ where
     Iter: Iterator<Item = Cow<'a, Event>>,
     T: OffChainDatabaseTransaction,
 {
+    let layout = owned_coins_layout(block_st_transaction)?;
     for event in events {
-        match update_event_based_indexation(
+        handle_indexation_result(update_event_based_indexation(
             &event,
             block_st_transaction,
             balances_indexation_enabled,
             coins_to_spend_indexation_enabled,
             base_asset_id,
-        ) {
-            Ok(()) => (),
-            Err(IndexationError::StorageError(err)) => {
-                return Err(err.into());
-            }
-            Err(err) => {
-                // TODO[RC]: Indexation errors to be correctly handled. See: https://github.com/FuelLabs/fuel-core/issues/2428
-                tracing::error!("Indexation error: {}", err);
-            }
-        };
-        match event.deref() {
-            Event::MessageImported(message) => {
-                block_st_transaction
-                    .storage_as_mut::<OwnedMessageIds>()
-                    .insert(
-                        &OwnedMessageKey::new(message.recipient(), message.nonce()),
-                        &(),
-                    )?;
-            }
-            Event::MessageConsumed(message) => {
-                block_st_transaction
-                    .storage_as_mut::<OwnedMessageIds>()
-                    .remove(&OwnedMessageKey::new(
-                        message.recipient(),
-                        message.nonce(),
-                    ))?;
+        ))?;
+        // The genesis and the tests don't flag the spent coins.
+        update_owned_indexes(
+            &event,
+            block_height,
+            block_st_transaction,
+            false,
+            layout,
+            &HashMap::new(),
+        )?;
+    }
+    Ok(())
+}
+
+/// Updates the indexes of the coins and messages owned by addresses.
+/// The spent coins are flagged instead of being removed if `mark_spent_coins` is set.
+/// The owned coins are keyed with the `layout` of the `OwnedCoins` column.
+/// The consumed messages are always flagged, with the spender from the `message_spenders`.
+fn update_owned_indexes<T>(
+    event: &Event,
+    block_height: BlockHeight,
+    block_st_transaction: &mut T,
+    mark_spent_coins: bool,
+    layout: OwnedCoinsLayout,
+    message_spenders: &HashMap<Nonce, TxId>,
+) -> StorageResult<()>
+where
+    T: OffChainDatabaseTransaction,
+{
+    match event {
+        Event::MessageImported(message) => {
+            block_st_transaction
+                .storage_as_mut::<OwnedMessageIds>()
+                .insert(
+                    &OwnedMessageKey::new(message.recipient(), message.nonce()),
+                    &OwnedMessageState::Unspent,
+                )?;
+            block_st_transaction
+                .storage_as_mut::<MessagesBySender>()
+                .insert(
+                    &SenderMessageKey::new(message.sender(), message.nonce()),
+                    &(),
+                )?;
+            block_st_transaction
+                .update_owner_last_activity(message.recipient(), block_height)?;
+        }
+        Event::MessageConsumed(message) => {
+            block_st_transaction
+                .storage_as_mut::<OwnedMessageIds>()
+                .insert(
+                    &OwnedMessageKey::new(message.recipient(), message.nonce()),
+                    &OwnedMessageState::Spent(SpentOwnedMessage {
+                        height: block_height,
+                        tx_id: message_spenders.get(message.nonce()).copied(),
+                        message: message.clone(),
+                    }),
+                )?;
+            block_st_transaction
+                .storage_as_mut::<MessagesBySender>()
+                .remove(&SenderMessageKey::new(message.sender(), message.nonce()))?;
+            block_st_transaction.mark_message_spent(message.nonce())?;
+        }
+        Event::CoinCreated(coin) => {
+            let coin_by_owner = owner_coin_id_key(&coin.owner, &coin.utxo_id);
+            insert_owned_coin(
+                block_st_transaction,
+                layout,
+                &coin_by_owner,
+                &OwnedCoinState::Unspent,
+            )?;
+            block_st_transaction
+                .storage_as_mut::<OwnedCoinsByAsset>()
+                .insert(
+                    &OwnedCoinByAssetKey::new(&coin.owner, &coin.asset_id, &coin.utxo_id),
+                    &(),
+                )?;
+            // The owners of the block are classified before its coins are indexed,
+            // so the change returned to the predicate that spent in the block is flagged.
+            let owned_by_predicate = matches!(
                 block_st_transaction
-                    .storage::<SpentMessages>()
-                    .insert(message.nonce(), &())?;
-            }
-            Event::CoinCreated(coin) => {
-                let coin_by_owner = owner_coin_id_key(&coin.owner, &coin.utxo_id);
+                    .storage_as_ref::<KnownPredicateOwners>()
+                    .get(&coin.owner)?
+                    .as_deref(),
+                Some(KnownOwner::Predicate { .. })
+            );
+            if owned_by_predicate {
                 block_st_transaction
-                    .storage_as_mut::<OwnedCoins>()
+                    .storage_as_mut::<PredicateOwnedCoins>()
                     .insert(&coin_by_owner, &())?;
             }
-            Event::CoinConsumed(coin) => {
-                let key = owner_coin_id_key(&coin.owner, &coin.utxo_id);
-                block_st_transaction
-                    .storage_as_mut::<OwnedCoins>()
-                    .remove(&key)?;
-            }
-            Event::ForcedTransactionFailed {
-                id,
+            increase_owned_coin_count(block_st_transaction, &coin.owner, &coin.asset_id)?;
+            block_st_transaction.update_owner_last_activity(&coin.owner, block_height)?;
+        }
+        Event::CoinConsumed(coin) => {
+            let key = owner_coin_id_key(&coin.owner, &coin.utxo_id);
+            spend_owned_coin(
+                block_st_transaction,
+                layout,
+                &key,
                 block_height,
-                failure,
-            } => {
-                let status = RelayedTransactionStatus::Failed {
-                    block_height: *block_height,
-                    failure: failure.clone(),
-                };
-
-                block_st_transaction
-                    .storage_as_mut::<RelayedTransactionStatuses>()
-                    .insert(&Bytes32::from(id.to_owned()), &status)?;
-            }
+                mark_spent_coins,
+            )?;
+            block_st_transaction
+                .storage_as_mut::<OwnedCoinsByAsset>()
+                .remove(&OwnedCoinByAssetKey::new(
+                    &coin.owner,
+                    &coin.asset_id,
+                    &coin.utxo_id,
+                ))?;
+            block_st_transaction
+                .storage_as_mut::<PredicateOwnedCoins>()
+                .remove(&key)?;
+            decrease_owned_coin_count(block_st_transaction, &coin.owner, &coin.asset_id)?;
+        }
+        Event::ForcedTransactionFailed {
+            id,
+            block_height,
+            failure,
+        } => {
+            let status = RelayedTransactionStatus::Failed {
+                block_height: *block_height,
+                failure: failure.clone(),
+            };
+
+            block_st_transaction
+                .insert_relayed_tx_status(&Bytes32::from(id.to_owned()), &status)?;
         }
     }
     Ok(())
 }
 
+/// Increments the number of coins of the `owner` with the `asset_id`.
+pub(crate) fn increase_owned_coin_count<T>(
+    block_st_transaction: &mut T,
+    owner: &Address,
+    asset_id: &AssetId,
+) -> StorageResult<()>
+where
+    T: OffChainDatabaseTransaction,
+{
+    let key = CoinBalancesKey::new(owner, asset_id);
+    let count = block_st_transaction
+        .storage::<OwnedCoinCountByAsset>()
+        .get(&key)?
+        .map(|count| count.into_owned())
+        .unwrap_or_default();
+    block_st_transaction
+        .storage::<OwnedCoinCountByAsset>()
+        .insert(&key, &count.saturating_add(1))
+}
+
+/// Decrements the number of coins of the `owner` with the `asset_id`,
+/// and removes the entry when no coins are left.
+fn decrease_owned_coin_count<T>(
+    block_st_transaction: &mut T,
+    owner: &Address,
+    asset_id: &AssetId,
+) -> StorageResult<()>
+where
+    T: OffChainDatabaseTransaction,
+{
+    let key = CoinBalancesKey::new(owner, asset_id);
+    let count = block_st_transaction
+        .storage::<OwnedCoinCountByAsset>()
+        .get(&key)?
+        .map(|count| count.into_owned())
+        .unwrap_or_default();
+    // The coins created before the count was maintained are not counted until
+    // the backfill, so their spending can't make the count negative.
+    match count.saturating_sub(1) {
+        0 => block_st_transaction
+            .storage::<OwnedCoinCountByAsset>()
+            .remove(&key),
+        count => block_st_transaction
+            .storage::<OwnedCoinCountByAsset>()
+            .insert(&key, &count),
+    }
+}
+
 fn update_event_based_indexation<T>(
     event: &Event,
     block_st_transaction: &mut T,
@@ -349,10 +1481,12 @@ where
 }
 
 /// Associate all transactions within a block to their respective UTXO owners
+/// and to the contracts they reference
 fn index_tx_owners_for_block<T>(
     block: &Block,
     block_st_transaction: &mut T,
     chain_id: &ChainId,
+    owner_activity: &mut OwnerActivityUpdates,
 ) -> anyhow::Result<()>
 where
     T: OffChainDatabaseTransaction,
@@ -374,7 +1508,18 @@ where
                 inputs = tx.inputs().as_slice();
                 outputs = tx.outputs().as_slice();
             }
-            Transaction::Mint(_) => continue,
+            Transaction::Mint(mint) => {
+                // The `Mint` transaction has no owners, but it changes
+                // the balance of the coinbase contract.
+                persist_contracts_index(
+                    block_height,
+                    vec![mint.input_contract().contract_id],
+                    &tx_id,
+                    tx_idx,
+                    block_st_transaction,
+                )?;
+                continue
+            }
             Transaction::Upgrade(tx) => {
                 inputs = tx.inputs().as_slice();
                 outputs = tx.outputs().as_slice();
@@ -390,21 +1535,63 @@ where
         }
         persist_owners_index(
             block_height,
-            inputs,
-            outputs,
+            extract_tx_owners(tx),
             &tx_id,
             tx_idx,
             block_st_transaction,
+            owner_activity,
         )?;
+        persist_contracts_index(
+            block_height,
+            referenced_contracts(inputs, outputs),
+            &tx_id,
+            tx_idx,
+            block_st_transaction,
+        )?;
+        persist_known_owners(inputs, block_st_transaction)?;
     }
     Ok(())
 }
 
-/// Index the tx id by owner for all of the inputs and outputs
-fn persist_owners_index<T>(
+/// Classifies the owners of the spent coins and messages by the way they were spent:
+/// by a predicate or by a signature.
+fn persist_known_owners<T>(inputs: &[Input], db: &mut T) -> StorageResult<()>
+where
+    T: OffChainDatabaseTransaction,
+{
+    for input in inputs {
+        let Some(owner) = input.input_owner() else {
+            continue
+        };
+        let known_owner = match input.predicate() {
+            Some(predicate) => KnownOwner::Predicate {
+                code_hash: Hasher::hash(predicate),
+            },
+            None => KnownOwner::Account,
+        };
+        db.storage_as_mut::<KnownPredicateOwners>()
+            .insert(owner, &known_owner)?;
+    }
+    Ok(())
+}
+
+/// Returns the contracts used by the inputs and created by the outputs.
+fn referenced_contracts(inputs: &[Input], outputs: &[Output]) -> Vec<ContractId> {
+    let used = inputs.iter().filter_map(|input| match input {
+        Input::Contract(contract) => Some(contract.contract_id),
+        _ => None,
+    });
+    let created = outputs.iter().filter_map(|output| match output {
+        Output::ContractCreated { contract_id, .. } => Some(*contract_id),
+        _ => None,
+    });
+    used.chain(created).collect()
+}
+
+/// Index the tx id by each of the referenced contracts
+fn persist_contracts_index<T>(
     block_height: BlockHeight,
-    inputs: &[Input],
-    outputs: &[Output],
+    mut contracts: Vec<ContractId>,
     tx_id: &Bytes32,
     tx_idx: u16,
     db: &mut T,
@@ -412,32 +1599,136 @@ fn persist_owners_index<T>(
 where
     T: OffChainDatabaseTransaction,
 {
-    let mut owners = vec![];
-    for input in inputs {
-        if let Input::CoinSigned(CoinSigned { owner, .. })
-        | Input::CoinPredicate(CoinPredicate { owner, .. }) = input
-        {
-            owners.push(owner);
-        }
+    // the transaction can reference the same contract several times,
+    // but it is indexed once per contract
+    contracts.sort();
+    contracts.dedup();
+
+    for contract_id in contracts {
+        db.storage_as_mut::<ContractTransactions>().insert(
+            &ContractTransactionIndexKey::new(&contract_id, block_height, tx_idx),
+            tx_id,
+        )?;
     }
 
-    for output in outputs {
-        match output {
+    Ok(())
+}
+
+/// Returns the owners of the transaction: the owners of the spent coins,
+/// the recipients of the spent messages and the receivers of the coin outputs,
+/// with the direction of the transaction for each of them.
+///
+/// The contract inputs and outputs have no owner, and the `Mint` transaction
+/// has no owners at all.
+pub fn extract_tx_owners(
+    tx: &Transaction,
+) -> BTreeMap<Address, OwnedTransactionDirection> {
+    let (inputs, outputs) = match tx {
+        Transaction::Script(tx) => (tx.inputs().as_slice(), tx.outputs().as_slice()),
+        Transaction::Create(tx) => (tx.inputs().as_slice(), tx.outputs().as_slice()),
+        Transaction::Upgrade(tx) => (tx.inputs().as_slice(), tx.outputs().as_slice()),
+        Transaction::Upload(tx) => (tx.inputs().as_slice(), tx.outputs().as_slice()),
+        Transaction::Blob(tx) => (tx.inputs().as_slice(), tx.outputs().as_slice()),
+        Transaction::Mint(_) => return BTreeMap::new(),
+    };
+
+    let input_owners: BTreeSet<_> = inputs
+        .iter()
+        .filter_map(Input::input_owner)
+        .copied()
+        .collect();
+    let output_owners: BTreeSet<_> = outputs
+        .iter()
+        .filter_map(|output| match output {
             Output::Coin { to, .. }
             | Output::Change { to, .. }
-            | Output::Variable { to, .. } => {
-                owners.push(to);
+            | Output::Variable { to, .. } => Some(to),
+            Output::Contract(_) | Output::ContractCreated { .. } => None,
+        })
+        .copied()
+        .collect();
+
+    input_owners
+        .union(&output_owners)
+        .map(|owner| {
+            let direction = OwnedTransactionDirection::new(
+                input_owners.contains(owner),
+                output_owners.contains(owner),
+            );
+            (*owner, direction)
+        })
+        .collect()
+}
+
+/// Indexes the failed relayed transactions of the block by the owners of their inputs.
+///
+/// The failed relayed transactions are not included into the block, so their
+/// owners are recovered from the payloads of the transactions relayed to the block.
+/// The payloads that can't be decoded have no owners.
+fn index_failed_relayed_transactions_owners<T>(
+    config: &IndexationConfig,
+    import_result: &ImportResult,
+    db: &mut T,
+) -> anyhow::Result<()>
+where
+    T: OffChainDatabaseTransaction,
+{
+    let Some(provider) = &config.relayed_transactions else {
+        return Ok(());
+    };
+    let failed: BTreeSet<Bytes32> = import_result
+        .events
+        .iter()
+        .filter_map(|event| match event {
+            Event::ForcedTransactionFailed { id, .. } => {
+                Some(Bytes32::from(id.to_owned()))
             }
-            Output::Contract(_) | Output::ContractCreated { .. } => {}
-        }
+            _ => None,
+        })
+        .collect();
+    if failed.is_empty() {
+        return Ok(());
     }
 
-    // dedupe owners from inputs and outputs prior to indexing
-    owners.sort();
-    owners.dedup();
+    let block_height = import_result.sealed_block.entity.header().height();
+    for relayed_transaction in provider.relayed_transactions(block_height)? {
+        let id = Bytes32::from(relayed_transaction.id());
+        if !failed.contains(&id) {
+            continue;
+        }
+        let Ok(tx) =
+            Transaction::from_bytes(relayed_transaction.serialized_transaction())
+        else {
+            continue;
+        };
+        let owners: BTreeSet<Address> = derived_ownership::transaction_inputs(&tx)
+            .iter()
+            .filter_map(Input::input_owner)
+            .copied()
+            .collect();
+        for owner in &owners {
+            db.storage_as_mut::<OwnedRelayedTransactionIds>()
+                .insert(&OwnedRelayedTransactionKey::new(owner, &id), &())?;
+        }
+    }
+    Ok(())
+}
 
-    for owner in owners {
-        db.record_tx_id_owner(owner, block_height, tx_idx, tx_id)?;
+/// Index the tx id by each of the owners of the transaction
+fn persist_owners_index<T>(
+    block_height: BlockHeight,
+    owners: BTreeMap<Address, OwnedTransactionDirection>,
+    tx_id: &Bytes32,
+    tx_idx: u16,
+    db: &mut T,
+    owner_activity: &mut OwnerActivityUpdates,
+) -> StorageResult<()>
+where
+    T: OffChainDatabaseTransaction,
+{
+    for (owner, direction) in &owners {
+        db.record_tx_id_owner(owner, block_height, tx_idx, tx_id, *direction)?;
+        owner_activity.record_transaction(owner, block_height);
     }
 
     Ok(())
@@ -451,18 +1742,29 @@ fn persist_transaction_status<T>(
 where
     T: OffChainDatabaseTransaction,
 {
-    for TransactionExecutionStatus { id, result } in import_result.tx_status.iter() {
-        let status =
-            from_executor_to_status(&import_result.sealed_block.entity, result.clone());
-
-        if db.update_tx_status(id, status)?.is_some() {
-            return Err(anyhow::anyhow!(
-                "Transaction status already exists for tx {}",
-                id
-            )
-            .into());
-        }
+    let updates = import_result
+        .tx_status
+        .iter()
+        .map(|TransactionExecutionStatus { id, result }| {
+            let status = from_executor_to_status(
+                &import_result.sealed_block.entity,
+                result.clone(),
+            );
+            (*id, status)
+        })
+        .collect::<Vec<_>>();
+    let prior_statuses = db.update_tx_statuses(&updates)?;
+    if let Some(((id, _), _)) = updates
+        .iter()
+        .zip(prior_statuses)
+        .find(|(_, prior_status)| prior_status.is_some())
+    {
+        return Err(
+            anyhow::anyhow!("Transaction status already exists for tx {}", id).into(),
+        );
+    }
 
+    for TransactionExecutionStatus { result, .. } in import_result.tx_status.iter() {
         let TransactionExecutionResult::Success { receipts, .. } = result else {
             continue
         };
@@ -472,6 +1774,37 @@ where
     Ok(())
 }
 
+/// Stores the fees collected by the block, with the tips tracked
+/// separately from the base fee.
+fn index_block_fees<T>(import_result: &ImportResult, db: &mut T) -> StorageResult<()>
+where
+    T: OffChainDatabaseTransaction,
+{
+    let block = &import_result.sealed_block.entity;
+    let mut fees = CollectedFees::default();
+    for (tx, status) in block
+        .transactions()
+        .iter()
+        .zip(import_result.tx_status.iter())
+    {
+        fees.record(*status.result.total_fee(), transaction_tip(tx));
+    }
+
+    db.record_block_fees(*block.header().height(), &fees)?;
+    Ok(())
+}
+
+fn transaction_tip(tx: &Transaction) -> u64 {
+    match tx {
+        Transaction::Script(tx) => tx.tip(),
+        Transaction::Create(tx) => tx.tip(),
+        Transaction::Upgrade(tx) => tx.tip(),
+        Transaction::Upload(tx) => tx.tip(),
+        Transaction::Blob(tx) => tx.tip(),
+        Transaction::Mint(_) => 0,
+    }
+}
+
 pub fn process_transactions<'a, I, T>(transactions: I, db: &mut T) -> StorageResult<()>
 where
     I: Iterator<Item = &'a Transaction>,
@@ -480,29 +1813,7 @@ where
     for tx in transactions {
         match tx {
             Transaction::Create(tx) => {
-                let contract_id = tx
-                    .outputs()
-                    .iter()
-                    .filter_map(|output| output.contract_id().cloned())
-                    .next()
-                    .map(Ok::<_, StorageError>)
-                    .unwrap_or_else(|| {
-                        // TODO: Reuse `CreateMetadata` when it will be exported
-                        //  from the `fuel-tx` crate.
-                        let salt = tx.salt();
-                        let storage_slots = tx.storage_slots();
-                        let contract = Contract::try_from(tx)
-                            .map_err(|e| anyhow::anyhow!("{:?}", e))?;
-                        let contract_root = contract.root();
-                        let state_root =
-                            Contract::initial_state_root(storage_slots.iter());
-                        Ok::<_, StorageError>(contract.id(
-                            salt,
-                            &contract_root,
-                            &state_root,
-                        ))
-                    })?;
-
+                let contract_id = deployed_contract_id(tx)?;
                 let salt = *tx.salt();
 
                 db.storage::<ContractsInfo>()
@@ -520,6 +1831,235 @@ where
     Ok(())
 }
 
+/// Stores the consensus parameters introduced by the successful `Upgrade`
+/// transactions of the block. The upgrade of the block creates the next version
+/// after the one used by the block, so the latest upgrade of the block wins.
+pub fn index_consensus_parameters_upgrades<T>(
+    import_result: &ImportResult,
+    db: &mut T,
+) -> StorageResult<()>
+where
+    T: OffChainDatabaseTransaction,
+{
+    let block = &import_result.sealed_block.entity;
+    let transactions = block.transactions().iter().zip(&import_result.tx_status);
+    for (tx, status) in transactions {
+        let Transaction::Upgrade(upgrade) = tx else {
+            continue
+        };
+        let UpgradePurpose::ConsensusParameters { witness_index, .. } =
+            upgrade.upgrade_purpose()
+        else {
+            continue
+        };
+        if !matches!(status.result, TransactionExecutionResult::Success { .. }) {
+            continue
+        }
+
+        let witness = upgrade
+            .witnesses()
+            .get(usize::from(*witness_index))
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "The witness {witness_index} with the consensus parameters is missing"
+                )
+            })?;
+        let consensus_parameters: ConsensusParameters =
+            postcard::from_bytes(witness.as_ref()).map_err(|e| {
+                anyhow::anyhow!("Failed to decode the consensus parameters: {e}")
+            })?;
+        let version = block
+            .header()
+            .consensus_parameters_version()
+            .checked_add(1)
+            .ok_or_else(|| anyhow::anyhow!("Consensus parameters version overflow"))?;
+
+        db.storage_as_mut::<ConsensusParametersHistory>()
+            .insert(&version, &consensus_parameters)?;
+    }
+    Ok(())
+}
+
+/// Records the transaction that spent each coin consumed by the block, so the spender
+/// of the coin is known after the coin is removed from the on-chain state, and indexes
+/// the spent coins by the block in the order of the inputs. The message inputs and
+/// the inputs without the consumed coin, e.g. with the disabled UTXO validation,
+/// are skipped.
+pub fn index_spent_coins<'a, I, T>(
+    block_height: BlockHeight,
+    transactions: I,
+    events: &[Event],
+    db: &mut T,
+) -> StorageResult<()>
+where
+    I: Iterator<Item = (&'a TxId, &'a Transaction)>,
+    T: OffChainDatabaseTransaction,
+{
+    let consumed = events
+        .iter()
+        .filter_map(|event| match event {
+            Event::CoinConsumed(coin) => Some((coin.utxo_id, coin)),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+    if consumed.is_empty() {
+        return Ok(())
+    }
+
+    let mut spent_coins = vec![];
+    for (tx_id, tx) in transactions {
+        let inputs = match tx {
+            Transaction::Script(tx) => tx.inputs().as_slice(),
+            Transaction::Create(tx) => tx.inputs().as_slice(),
+            Transaction::Upgrade(tx) => tx.inputs().as_slice(),
+            Transaction::Upload(tx) => tx.inputs().as_slice(),
+            Transaction::Blob(tx) => tx.inputs().as_slice(),
+            Transaction::Mint(_) => continue,
+        };
+        for input in inputs {
+            let (Input::CoinSigned(CoinSigned { utxo_id, .. })
+            | Input::CoinPredicate(CoinPredicate { utxo_id, .. })) = input
+            else {
+                continue
+            };
+            if let Some(coin) = consumed.get(utxo_id) {
+                spent_coins.push(SpentCoin {
+                    coin: **coin,
+                    tx_id: *tx_id,
+                    block_height,
+                });
+            }
+        }
+    }
+    for (index, spent_coin) in spent_coins.iter().enumerate() {
+        let index = u32::try_from(index).map_err(|e| {
+            anyhow::anyhow!("The block spends more than `u32::MAX` coins, {}", e)
+        })?;
+        db.storage::<SpentCoinsByBlock>().insert(
+            &SpentCoinsByBlockKey {
+                block_height,
+                index,
+            },
+            &spent_coin.coin.utxo_id,
+        )?;
+    }
+    db.record_spent_coins(block_height, &spent_coins)
+}
+
+/// Returns the transactions that consumed the messages of the `events` by their inputs.
+fn message_spenders<'a, I>(transactions: I, events: &[Event]) -> HashMap<Nonce, TxId>
+where
+    I: Iterator<Item = (&'a TxId, &'a Transaction)>,
+{
+    let has_consumed = events
+        .iter()
+        .any(|event| matches!(event, Event::MessageConsumed(_)));
+    if !has_consumed {
+        return HashMap::new()
+    }
+
+    let mut spenders = HashMap::new();
+    for (tx_id, tx) in transactions {
+        let inputs = match tx {
+            Transaction::Script(tx) => tx.inputs().as_slice(),
+            Transaction::Create(tx) => tx.inputs().as_slice(),
+            Transaction::Upgrade(tx) => tx.inputs().as_slice(),
+            Transaction::Upload(tx) => tx.inputs().as_slice(),
+            Transaction::Blob(tx) => tx.inputs().as_slice(),
+            Transaction::Mint(_) => continue,
+        };
+        for nonce in inputs.iter().filter_map(Input::nonce) {
+            spenders.insert(*nonce, *tx_id);
+        }
+    }
+    spenders
+}
+
+/// Indexes the contracts deployed by the `transactions` of the block at
+/// the `block_height` by their position in the block. Only the `Create`
+/// transactions deploy contracts; the `Blob`, `Upload` and `Upgrade`
+/// transactions don't create new contract ids, so they are skipped.
+pub fn index_contracts_by_block<'a, I, T>(
+    block_height: BlockHeight,
+    transactions: I,
+    db: &mut T,
+) -> StorageResult<()>
+where
+    I: Iterator<Item = (&'a TxId, &'a Transaction)>,
+    T: OffChainDatabaseTransaction,
+{
+    for (tx_idx, (tx_id, tx)) in transactions.enumerate() {
+        let Transaction::Create(tx) = tx else {
+            continue
+        };
+        let tx_idx = u16::try_from(tx_idx).map_err(|e| {
+            anyhow::anyhow!("The block has more than `u16::MAX` transactions, {}", e)
+        })?;
+        let deployment = ContractDeployment {
+            contract_id: deployed_contract_id(tx)?,
+            tx_id: *tx_id,
+        };
+        db.storage::<ContractsByBlock>().insert(
+            &ContractsByBlockKey {
+                block_height,
+                tx_idx,
+            },
+            &deployment,
+        )?;
+    }
+    Ok(())
+}
+
+/// Indexes the block at the `block_height` by its producer recovered from
+/// the `consensus`. The genesis block and the blocks with the producer that
+/// can't be recovered are indexed under the [`UNKNOWN_BLOCK_PRODUCER`].
+pub fn index_block_producer<T>(
+    block_height: BlockHeight,
+    block_id: &BlockId,
+    consensus: &Consensus,
+    db: &mut T,
+) -> StorageResult<()>
+where
+    T: OffChainDatabaseTransaction,
+{
+    let producer = consensus.block_producer(block_id).unwrap_or_else(|e| {
+        tracing::warn!(
+            "Failed to recover the producer of the block at height {}: {}",
+            block_height,
+            e
+        );
+        UNKNOWN_BLOCK_PRODUCER
+    });
+    db.storage::<BlocksByProducer>().insert(
+        &BlocksByProducerKey {
+            producer,
+            height: block_height,
+        },
+        &(),
+    )?;
+    Ok(())
+}
+
+/// Returns the id of the contract deployed by the `Create` transaction.
+fn deployed_contract_id(tx: &Create) -> StorageResult<ContractId> {
+    tx.outputs()
+        .iter()
+        .filter_map(|output| output.contract_id().cloned())
+        .next()
+        .map(Ok::<_, StorageError>)
+        .unwrap_or_else(|| {
+            // TODO: Reuse `CreateMetadata` when it will be exported
+            //  from the `fuel-tx` crate.
+            let salt = tx.salt();
+            let storage_slots = tx.storage_slots();
+            let contract =
+                Contract::try_from(tx).map_err(|e| anyhow::anyhow!("{:?}", e))?;
+            let contract_root = contract.root();
+            let state_root = Contract::initial_state_root(storage_slots.iter());
+            Ok::<_, StorageError>(contract.id(salt, &contract_root, &state_root))
+        })
+}
+
 pub fn copy_to_old_blocks<'a, I, T>(blocks: I, db: &mut T) -> StorageResult<()>
 where
     I: Iterator<Item = (&'a BlockHeight, &'a CompressedBlock)>,
@@ -580,6 +2120,10 @@ where
         _: &StateWatcher,
         _: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
+        if self.reconcile_tx_count {
+            reconcile_tx_count(&mut self.off_chain_database)?;
+        }
+
         {
             let db_tx = self.off_chain_database.transaction();
             let total_tx_count = db_tx.get_tx_count().unwrap_or_default();
@@ -594,10 +2138,14 @@ where
         let asset_metadata_indexation_enabled = self
             .off_chain_database
             .asset_metadata_indexation_enabled()?;
+        let contract_balances_indexation_enabled = self
+            .off_chain_database
+            .contract_balances_indexation_enabled()?;
         tracing::info!(
             balances_indexation_enabled,
             coins_to_spend_indexation_enabled,
             asset_metadata_indexation_enabled,
+            contract_balances_indexation_enabled,
             "Indexation availability status"
         );
         tracing::debug!(
@@ -605,9 +2153,14 @@ where
             "Indexation related query costs"
         );
 
+        let dead_letter_height = self
+            .off_chain_database
+            .indexation_dead_letter()?
+            .map(|dead_letter| dead_letter.height);
+        let commit_duration_max = self.off_chain_database.commit_duration_max()?;
+
         let InitializeTask {
             chain_id,
-            da_compression_config,
             tx_status_manager,
             block_importer,
             blocks_events,
@@ -615,7 +2168,15 @@ where
             off_chain_database,
             continue_on_error,
             base_asset_id,
+            historical_data_retention,
+            batch_blocks,
             block_height_subscription_handler,
+            contracts_info_cache,
+            coin_owner_mapper,
+            relayed_transactions,
+            mark_spent_owned_coins,
+            slow_commit_threshold,
+            reconcile_tx_count: _,
         } = self;
 
         let mut task = Task {
@@ -623,13 +2184,24 @@ where
             block_importer: blocks_events,
             database: off_chain_database,
             chain_id,
-            da_compression_config,
             continue_on_error,
             balances_indexation_enabled,
             coins_to_spend_indexation_enabled,
             asset_metadata_indexation_enabled,
+            contract_balances_indexation_enabled,
             base_asset_id,
+            historical_data_retention,
+            batch_blocks,
             block_height_subscription_handler,
+            contracts_info_cache,
+            coin_owner_mapper,
+            relayed_transactions,
+            mark_spent_owned_coins,
+            slow_commit_threshold,
+            commit_duration_max,
+            indexation_retry: IndexationRetry::default(),
+            failed_block: None,
+            dead_letter_height,
         };
 
         let mut target_chain_height = on_chain_database.latest_height()?;
@@ -645,10 +2217,49 @@ where
 
         sync_databases(&mut task, target_chain_height, &block_importer)?;
 
+        // The block of the dead letter could be indexed during the sync.
+        if let (Some(dead_letter_height), Some(indexed_height)) =
+            (task.dead_letter_height, task.indexed_height()?)
+        {
+            if dead_letter_height <= indexed_height {
+                task.clear_dead_letter(dead_letter_height)?;
+            }
+        }
+
         Ok(task)
     }
 }
 
+/// Recomputes the total number of transactions from the statuses of the included
+/// transactions, and corrects the statistic if they disagree, e.g. after a crash
+/// in the middle of the commit. Returns the magnitude of the correction.
+fn reconcile_tx_count<D>(database: &mut D) -> anyhow::Result<u64>
+where
+    D: ports::worker::OffChainDatabase,
+{
+    let recorded = database.transaction().get_tx_count()?;
+    let included = database.included_tx_count()?;
+    let correction = recorded.abs_diff(included);
+    graphql_metrics().record_tx_count_correction(correction);
+
+    if correction == 0 {
+        tracing::info!(
+            tx_count = recorded,
+            "The total transaction count is consistent"
+        );
+        return Ok(0)
+    }
+
+    tracing::warn!(
+        recorded,
+        included,
+        correction,
+        "The total transaction count disagrees with the transaction statuses, correcting it"
+    );
+    database.set_tx_count(included)?;
+    Ok(correction)
+}
+
 fn sync_databases<TxStatusManager, BlockImporter, OffChain>(
     task: &mut Task<TxStatusManager, OffChain>,
     target_chain_height: Option<BlockHeight>,
@@ -672,18 +2283,26 @@ where
             break;
         }
 
-        let next_block_height =
-            off_chain_height.map(|height| BlockHeight::new(height.saturating_add(1)));
-
-        let next_block_height = match next_block_height {
-            Some(block_height) => BlockAt::Specific(block_height),
-            None => BlockAt::Genesis,
-        };
+        // The blocks up to the target height are indexed in batches, each batch
+        // committed into the off-chain database at once.
+        let mut blocks = Vec::with_capacity(task.batch_blocks.get());
+        let mut last_height = off_chain_height;
+        while blocks.len() < task.batch_blocks.get() && last_height < target_chain_height
+        {
+            let next_block_height = match last_height {
+                Some(height) => {
+                    BlockAt::Specific(BlockHeight::new(height.saturating_add(1)))
+                }
+                None => BlockAt::Genesis,
+            };
 
-        let import_result =
-            import_result_provider.block_event_at_height(next_block_height)?;
+            let import_result =
+                import_result_provider.block_event_at_height(next_block_height)?;
+            last_height = Some(*import_result.sealed_block.entity.header().height());
+            blocks.push(import_result);
+        }
 
-        task.process_block(import_result)?
+        task.process_blocks(blocks)?
     }
 
     Ok(())
@@ -695,6 +2314,22 @@ where
     TxStatusManager: ports::worker::TxStatusCompletion,
 {
     async fn run(&mut self, watcher: &mut StateWatcher) -> TaskNextAction {
+        // The next blocks are not processed until the failed block is indexed,
+        // so the off-chain database never skips a height.
+        if let Some(FailedBlock { block, attempts }) = self.failed_block.take() {
+            let backoff = self.indexation_retry.backoff(attempts);
+            tokio::select! {
+                biased;
+
+                _ = watcher.while_started() => {
+                    return TaskNextAction::Stop
+                }
+
+                _ = tokio::time::sleep(backoff) => {}
+            }
+            return self.index_block(block, attempts)
+        }
+
         tokio::select! {
             biased;
 
@@ -704,19 +2339,7 @@ where
 
             result = self.block_importer.next() => {
                 if let Some(block) = result {
-                    let result = self.process_block(block);
-
-                    // In the case of an error, shut down the service to avoid a huge
-                    // de-synchronization between on-chain and off-chain databases.
-                    if let Err(e) = result {
-                        if self.continue_on_error {
-                            TaskNextAction::ErrorContinue(e)
-                        } else {
-                            TaskNextAction::Stop
-                        }
-                    } else {
-                        TaskNextAction::Continue
-                    }
+                    self.index_block(block, 0)
                 } else {
                     TaskNextAction::Stop
                 }
@@ -726,6 +2349,9 @@ where
 
     async fn shutdown(mut self) -> anyhow::Result<()> {
         // Process all remaining blocks before shutdown to not lose any data.
+        if let Some(FailedBlock { block, .. }) = self.failed_block.take() {
+            self.process_block(block)?;
+        }
         loop {
             let result = self.block_importer.next().now_or_never();
 
@@ -756,9 +2382,16 @@ where
         block_importer,
         on_chain_database,
         off_chain_database,
-        da_compression_config,
         continue_on_error,
+        historical_data_retention,
+        batch_blocks,
         consensus_parameters,
+        contracts_info_cache,
+        coin_owner_mapper,
+        relayed_transactions,
+        mark_spent_owned_coins,
+        slow_commit_threshold,
+        reconcile_tx_count,
     } = context;
 
     let off_chain_block_height = off_chain_database.latest_height()?.unwrap_or_default();
@@ -770,12 +2403,19 @@ where
         on_chain_database,
         off_chain_database,
         chain_id: consensus_parameters.chain_id(),
-        da_compression_config,
         continue_on_error,
         base_asset_id: *consensus_parameters.base_asset_id(),
+        historical_data_retention,
+        batch_blocks,
         block_height_subscription_handler: block_height_subscription::Handler::new(
             off_chain_block_height,
         ),
+        contracts_info_cache,
+        coin_owner_mapper,
+        relayed_transactions,
+        mark_spent_owned_coins,
+        slow_commit_threshold,
+        reconcile_tx_count,
     });
 
     Ok(service)
@@ -2,17 +2,87 @@
 
 use super::*;
 use crate::{
-    database::Database,
-    graphql_api::storage::relayed_transactions::RelayedTransactionStatuses,
+    database::{
+        database_description::{
+            off_chain::OffChain,
+            IndexationKind,
+        },
+        Database,
+        OffChainIterableKeyValueView,
+    },
+    graphql_api::{
+        ports::worker::OffChainDatabase,
+        storage::{
+            balances::{
+                CoinBalances,
+                TotalBalanceAmount,
+            },
+            owner_activity::OwnerLastActivity,
+            relayed_transactions::{
+                OwnedRelayedTransactionIds,
+                OwnedRelayedTransactionKey,
+                RelayedTransactionStatusHeights,
+                RelayedTransactionStatuses,
+            },
+            statistic::StatisticTable,
+            SPENT_MESSAGE_COUNT,
+        },
+    },
 };
 use fuel_core_services::stream::IntoBoxStream;
-use fuel_core_storage::StorageAsRef;
+use fuel_core_storage::{
+    iter::{
+        IterDirection,
+        IteratorOverTable,
+    },
+    kv_store::Value,
+    transactional::IntoTransaction,
+    StorageAsRef,
+};
 use fuel_core_types::{
-    fuel_tx::Bytes32,
-    fuel_types::BlockHeight,
+    blockchain::{
+        consensus::poa::PoAConsensus,
+        SealedBlock,
+    },
+    entities::{
+        coins::coin::Coin,
+        relayer::{
+            message::Message,
+            transaction::RelayedTransaction,
+        },
+    },
+    fuel_crypto::{
+        SecretKey,
+        Signature,
+    },
+    fuel_tx::{
+        Address,
+        Bytes32,
+        TransactionBuilder,
+        UtxoId,
+    },
+    fuel_types::{
+        canonical::Serialize,
+        BlockHeight,
+    },
     services::txpool::TransactionStatus,
 };
-use std::sync::Arc;
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Arc,
+    },
+    time::Duration,
+};
 
 struct MockTxStatusManager;
 
@@ -59,6 +129,1248 @@ async fn run__relayed_transaction_events_are_added_to_storage() {
     assert_eq!(*actual, expected);
 }
 
+#[test]
+fn process_block__prunes_relayed_transaction_statuses_outside_of_retention() {
+    let database = Database::<OffChain>::in_memory();
+    let mut task = worker_task_with_block_importer_and_db(
+        tokio_stream::pending().into_boxed(),
+        database.clone(),
+    );
+    task.historical_data_retention = Some(1);
+    let tx_id = |height: u32| Bytes32::from([u8::try_from(height).unwrap(); 32]);
+
+    // given
+    for height in 1..=2u32 {
+        task.process_block(forced_tx_failed_import_result(height, tx_id(height)))
+            .unwrap();
+    }
+
+    // when
+    task.process_block(forced_tx_failed_import_result(3, tx_id(3)))
+        .unwrap();
+
+    // then
+    let storage = database.storage_as_ref::<RelayedTransactionStatuses>();
+    assert!(storage.get(&tx_id(1)).unwrap().is_none());
+    assert!(storage.get(&tx_id(2)).unwrap().is_some());
+    assert!(storage.get(&tx_id(3)).unwrap().is_some());
+    let heights = database
+        .iter_all_keys::<RelayedTransactionStatusHeights>(None)
+        .map(|key| key.unwrap().block_height)
+        .collect::<Vec<_>>();
+    assert_eq!(heights, vec![2.into(), 3.into()]);
+}
+
+#[test]
+fn process_blocks__prunes_relayed_transaction_statuses_outside_of_retention() {
+    let database = Database::<OffChain>::in_memory();
+    let mut task = worker_task_with_block_importer_and_db(
+        tokio_stream::pending().into_boxed(),
+        database.clone(),
+    );
+    task.historical_data_retention = Some(1);
+    let tx_id = |height: u32| Bytes32::from([u8::try_from(height).unwrap(); 32]);
+
+    // given
+    for height in 1..=2u32 {
+        task.process_block(forced_tx_failed_import_result(height, tx_id(height)))
+            .unwrap();
+    }
+    let blocks = (3..=4u32)
+        .map(|height| forced_tx_failed_import_result(height, tx_id(height)))
+        .collect();
+
+    // when
+    task.process_blocks(blocks).unwrap();
+
+    // then
+    let storage = database.storage_as_ref::<RelayedTransactionStatuses>();
+    assert!(storage.get(&tx_id(1)).unwrap().is_none());
+    assert!(storage.get(&tx_id(2)).unwrap().is_none());
+    assert!(storage.get(&tx_id(3)).unwrap().is_some());
+    assert!(storage.get(&tx_id(4)).unwrap().is_some());
+}
+
+#[derive(Debug)]
+struct MockRelayedTransactions(Vec<RelayedTransaction>);
+
+impl ports::worker::RelayedTransactionsProvider for MockRelayedTransactions {
+    fn relayed_transactions(
+        &self,
+        _: &BlockHeight,
+    ) -> StorageResult<Vec<RelayedTransaction>> {
+        Ok(self.0.clone())
+    }
+}
+
+#[test]
+fn process_block__indexes_failed_relayed_transaction_by_owners_of_its_inputs() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let owner: Address = rng.gen();
+    let database = Database::<OffChain>::in_memory();
+    let mut task = worker_task_with_block_importer_and_db(
+        tokio_stream::pending().into_boxed(),
+        database.clone(),
+    );
+
+    // given
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .add_input(Input::coin_signed(
+            rng.gen(),
+            owner,
+            100,
+            Default::default(),
+            Default::default(),
+            0,
+        ))
+        .finalize_as_transaction();
+    let mut failed = RelayedTransaction::default();
+    failed.set_serialized_transaction(tx.to_bytes());
+    let mut succeeded = RelayedTransaction::default();
+    succeeded.set_serialized_transaction(tx.to_bytes());
+    succeeded.set_max_gas(1);
+    task.relayed_transactions = Some(Arc::new(MockRelayedTransactions(vec![
+        failed.clone(),
+        succeeded.clone(),
+    ])));
+
+    // when
+    task.process_block(forced_tx_failed_import_result(1, failed.id().into()))
+        .unwrap();
+
+    // then
+    let ids = database
+        .iter_all_keys::<OwnedRelayedTransactionIds>(None)
+        .map(|key| key.unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        ids,
+        vec![OwnedRelayedTransactionKey::new(&owner, &failed.id().into())]
+    );
+}
+
+#[test]
+fn process_blocks__commits_every_block_of_the_batch() {
+    let database = Database::<OffChain>::in_memory();
+    let mut task = worker_task_with_block_importer_and_db(
+        tokio_stream::pending().into_boxed(),
+        database.clone(),
+    );
+    let tx_id = |height: u32| Bytes32::from([u8::try_from(height).unwrap(); 32]);
+
+    // given
+    let blocks = (1..=3u32)
+        .map(|height| forced_tx_failed_import_result(height, tx_id(height)))
+        .collect();
+
+    // when
+    task.process_blocks(blocks).unwrap();
+
+    // then
+    assert_eq!(
+        OffChainDatabase::latest_height(&database).unwrap(),
+        Some(3.into())
+    );
+    let storage = database.storage_as_ref::<RelayedTransactionStatuses>();
+    for height in 1..=3u32 {
+        assert!(storage.get(&tx_id(height)).unwrap().is_some());
+    }
+}
+
+#[test]
+fn process_blocks__failed_block_aborts_the_whole_batch() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let database = Database::<OffChain>::in_memory();
+    let mut task = worker_task_with_block_importer_and_db(
+        tokio_stream::pending().into_boxed(),
+        database.clone(),
+    );
+    let mut unspent_coins = vec![];
+    let first = Arc::new(transfers_import_result(
+        &mut rng,
+        1.into(),
+        &mut unspent_coins,
+    ));
+    let mut second = transfers_import_result(&mut rng, 2.into(), &mut unspent_coins);
+    let third = Arc::new(transfers_import_result(
+        &mut rng,
+        3.into(),
+        &mut unspent_coins,
+    ));
+
+    // given
+    // The statuses of the transactions of the first block already exist
+    // when the second block is indexed, so the second block fails.
+    second.tx_status = first.tx_status.clone();
+    let blocks = vec![first, Arc::new(second), third];
+
+    // when
+    let result = task.process_blocks(blocks);
+
+    // then
+    assert!(result.is_err());
+    assert_eq!(OffChainDatabase::latest_height(&database).unwrap(), None);
+}
+
+#[test]
+fn process_blocks__failed_commit_leaves_no_block_of_the_batch() {
+    let database = Database::<OffChain>::in_memory();
+    let faulty_database = FaultyDatabase {
+        database: database.clone(),
+        failures: 1,
+        commit_delay: Duration::ZERO,
+    };
+    let mut task = worker_task_with_block_importer_and_db(
+        tokio_stream::pending().into_boxed(),
+        faulty_database,
+    );
+    let tx_id = |height: u32| Bytes32::from([u8::try_from(height).unwrap(); 32]);
+    let blocks = (1..=3u32)
+        .map(|height| forced_tx_failed_import_result(height, tx_id(height)))
+        .collect::<Vec<_>>();
+
+    // given
+    let result = task.process_blocks(blocks.clone());
+    assert!(result.is_err());
+    assert_eq!(OffChainDatabase::latest_height(&database).unwrap(), None);
+    let storage = database.storage_as_ref::<RelayedTransactionStatuses>();
+    for height in 1..=3u32 {
+        assert!(storage.get(&tx_id(height)).unwrap().is_none());
+    }
+
+    // when
+    task.process_blocks(blocks).unwrap();
+
+    // then
+    assert_eq!(
+        OffChainDatabase::latest_height(&database).unwrap(),
+        Some(3.into())
+    );
+}
+
+#[test]
+fn process_blocks__counts_the_transactions_of_every_block_once() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut database = Database::<OffChain>::in_memory();
+    let mut task = worker_task_with_block_importer_and_db(
+        tokio_stream::pending().into_boxed(),
+        database.clone(),
+    );
+    let mut unspent_coins = vec![];
+    let blocks = (1..=3u32)
+        .map(|height| {
+            Arc::new(transfers_import_result(
+                &mut rng,
+                height.into(),
+                &mut unspent_coins,
+            ))
+        })
+        .collect::<Vec<_>>();
+    let mut expected = Database::<OffChain>::in_memory();
+    let mut expected_task = worker_task_with_block_importer_and_db(
+        tokio_stream::pending().into_boxed(),
+        expected.clone(),
+    );
+    for block in blocks.clone() {
+        expected_task.process_block(block).unwrap();
+    }
+
+    // when
+    task.process_blocks(blocks).unwrap();
+
+    // then
+    let (tx_count, balances) = indexed_counters(&mut database);
+    assert_eq!(tx_count, 30);
+    assert_eq!((tx_count, balances), indexed_counters(&mut expected));
+}
+
+#[tokio::test]
+async fn run__retries_failed_block_in_background_until_it_is_indexed() {
+    let database = Database::<OffChain>::in_memory();
+    let tx_id = |height: u32| Bytes32::from([u8::try_from(height).unwrap(); 32]);
+    let blocks = vec![
+        forced_tx_failed_import_result(1, tx_id(1)),
+        forced_tx_failed_import_result(2, tx_id(2)),
+    ];
+    let faulty_database = FaultyDatabase {
+        database: database.clone(),
+        failures: 2,
+        commit_delay: Duration::ZERO,
+    };
+    let mut task = worker_task_with_block_importer_and_db(
+        tokio_stream::iter(blocks).into_boxed(),
+        faulty_database,
+    );
+    task.continue_on_error = true;
+    task.indexation_retry = IndexationRetry {
+        attempts: 1,
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(1),
+    };
+    let mut state_watcher = StateWatcher::started();
+
+    // given
+    let action = task.run(&mut state_watcher).await;
+    assert!(matches!(action, TaskNextAction::ErrorContinue(_)));
+    let dead_letter = database.indexation_dead_letter().unwrap().unwrap();
+    assert_eq!(dead_letter.height, 1.into());
+
+    // when
+    let _ = task.run(&mut state_watcher).await;
+    let dead_letter_after_retry = database.indexation_dead_letter().unwrap();
+    for _ in 0..2 {
+        let _ = task.run(&mut state_watcher).await;
+    }
+
+    // then
+    assert_eq!(dead_letter_after_retry, Some(dead_letter));
+    assert_eq!(
+        OffChainDatabase::latest_height(&database).unwrap(),
+        Some(2.into())
+    );
+    assert_eq!(database.indexation_dead_letter().unwrap(), None);
+    let storage = database.storage_as_ref::<RelayedTransactionStatuses>();
+    assert!(storage.get(&tx_id(1)).unwrap().is_some());
+    assert!(storage.get(&tx_id(2)).unwrap().is_some());
+}
+
+#[tokio::test]
+async fn run__stops_after_retries_are_exhausted_without_continue_on_error() {
+    let database = Database::<OffChain>::in_memory();
+    let block = forced_tx_failed_import_result(1, Bytes32::from([1; 32]));
+    let faulty_database = FaultyDatabase {
+        database: database.clone(),
+        failures: 2,
+        commit_delay: Duration::ZERO,
+    };
+    let mut task = worker_task_with_block_importer_and_db(
+        tokio_stream::iter(vec![block]).into_boxed(),
+        faulty_database,
+    );
+    task.indexation_retry = IndexationRetry {
+        attempts: 2,
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(1),
+    };
+    let mut state_watcher = StateWatcher::started();
+
+    // given
+    let first_attempt = task.run(&mut state_watcher).await;
+
+    // when
+    let last_attempt = task.run(&mut state_watcher).await;
+
+    // then
+    assert!(matches!(first_attempt, TaskNextAction::ErrorContinue(_)));
+    assert!(matches!(last_attempt, TaskNextAction::Stop));
+    let dead_letter = database.indexation_dead_letter().unwrap().unwrap();
+    assert_eq!(dead_letter.height, 1.into());
+    assert_eq!(OffChainDatabase::latest_height(&database).unwrap(), None);
+}
+
+/// Delivers the `deliveries` to the worker one after another, the way the importer
+/// re-delivers the blocks after a crash, and returns the indexed database.
+async fn index_deliveries(deliveries: Vec<SharedImportResult>) -> Database<OffChain> {
+    let database = Database::<OffChain>::in_memory();
+    let count = deliveries.len();
+    let mut task = worker_task_with_block_importer_and_db(
+        tokio_stream::iter(deliveries).into_boxed(),
+        database.clone(),
+    );
+    let mut state_watcher = StateWatcher::started();
+    for _ in 0..count {
+        let _ = task.run(&mut state_watcher).await;
+    }
+    database
+}
+
+/// Returns the total tx count and the coin balances of the `database`.
+fn indexed_counters(
+    database: &mut Database<OffChain>,
+) -> (u64, Vec<(CoinBalancesKey, TotalBalanceAmount)>) {
+    let tx_count = OffChainDatabase::transaction(database)
+        .get_tx_count()
+        .unwrap();
+    let balances = database
+        .iter_all::<CoinBalances>(None)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    (tx_count, balances)
+}
+
+#[tokio::test]
+async fn run__replayed_blocks_are_not_indexed_twice() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut unspent_coins = vec![];
+    let first = Arc::new(transfers_import_result(
+        &mut rng,
+        1.into(),
+        &mut unspent_coins,
+    ));
+    let second = Arc::new(transfers_import_result(
+        &mut rng,
+        2.into(),
+        &mut unspent_coins,
+    ));
+    let mut expected = index_deliveries(vec![first.clone(), second.clone()]).await;
+
+    // given
+    let deliveries = vec![first.clone(), first.clone(), second.clone(), first, second];
+
+    // when
+    let mut replayed = index_deliveries(deliveries).await;
+
+    // then
+    assert_eq!(
+        OffChainDatabase::latest_height(&replayed).unwrap(),
+        Some(2.into())
+    );
+    let (tx_count, balances) = indexed_counters(&mut replayed);
+    assert_eq!(tx_count, 20);
+    assert!(!balances.is_empty());
+    assert_eq!((tx_count, balances), indexed_counters(&mut expected));
+}
+
+#[test]
+fn process_blocks__skips_the_already_indexed_blocks() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut database = Database::<OffChain>::in_memory();
+    let mut task = worker_task_with_block_importer_and_db(
+        tokio_stream::pending().into_boxed(),
+        database.clone(),
+    );
+    let mut unspent_coins = vec![];
+    let first = Arc::new(transfers_import_result(
+        &mut rng,
+        1.into(),
+        &mut unspent_coins,
+    ));
+    let second = Arc::new(transfers_import_result(
+        &mut rng,
+        2.into(),
+        &mut unspent_coins,
+    ));
+
+    // given
+    task.process_block(first.clone()).unwrap();
+
+    // when
+    task.process_blocks(vec![first, second]).unwrap();
+
+    // then
+    assert_eq!(
+        OffChainDatabase::latest_height(&database).unwrap(),
+        Some(2.into())
+    );
+    let (tx_count, _) = indexed_counters(&mut database);
+    assert_eq!(tx_count, 20);
+}
+
+#[test]
+fn process_block__records_the_duration_of_the_slow_commit() {
+    let database = Database::<OffChain>::in_memory();
+    let commit_delay = Duration::from_millis(20);
+    let delayed_database = FaultyDatabase {
+        database: database.clone(),
+        failures: 0,
+        commit_delay,
+    };
+    let mut task = worker_task_with_block_importer_and_db(
+        tokio_stream::pending().into_boxed(),
+        delayed_database,
+    );
+    task.slow_commit_threshold = Duration::from_millis(10);
+    let tx_id = Bytes32::from([1; 32]);
+
+    // given
+    task.process_block(forced_tx_failed_import_result(1, tx_id))
+        .unwrap();
+
+    // when
+    let max = database.commit_duration_max().unwrap().unwrap();
+    let slow = task.observe_commit(2.into(), 1, Duration::ZERO, max.duration);
+    let fast = task.observe_commit(3.into(), 1, Duration::ZERO, Duration::ZERO);
+
+    // then
+    assert_eq!(max.height, 1.into());
+    assert!(max.duration >= commit_delay);
+    assert!(slow);
+    assert!(!fast);
+    let metrics = fuel_core_metrics::encode_metrics().unwrap();
+    assert!(metrics.contains("graphql_off_chain_commit_duration_seconds"));
+    assert!(metrics.contains("graphql_off_chain_block_processing_duration_seconds"));
+    assert!(metrics.contains("graphql_off_chain_slow_commits"));
+}
+
+#[test]
+fn process_block__exports_the_duration_of_each_indexing_phase() {
+    let database = Database::<OffChain>::in_memory();
+    let mut task = worker_task_with_block_importer_and_db(
+        tokio_stream::pending().into_boxed(),
+        database,
+    );
+    let tx_id = Bytes32::from([2; 32]);
+
+    // when
+    task.process_block(forced_tx_failed_import_result(1, tx_id))
+        .unwrap();
+
+    // then
+    let metrics = fuel_core_metrics::encode_metrics().unwrap();
+    for phase in [
+        "total",
+        "commit",
+        "coins",
+        "messages",
+        "transactions",
+        "statistics",
+    ] {
+        let count = format!("offchain_block_index_seconds_count{{phase=\"{phase}\"}}");
+        assert!(metrics.contains(&count), "{phase}");
+    }
+}
+
+#[test]
+fn reconcile_tx_count__corrects_the_count_to_the_included_statuses() {
+    let mut database = Database::<OffChain>::in_memory();
+    let mut task = worker_task_with_block_importer_and_db(
+        tokio_stream::pending().into_boxed(),
+        database.clone(),
+    );
+    let mut rng = StdRng::seed_from_u64(1234);
+    let block = transfers_import_result(&mut rng, 1.into(), &mut vec![]);
+    let included = block.tx_status.len() as u64;
+    task.process_block(Arc::new(block)).unwrap();
+
+    // given
+    database.set_tx_count(included + 3).unwrap();
+
+    // when
+    let correction = reconcile_tx_count(&mut database).unwrap();
+
+    // then
+    assert_eq!(correction, 3);
+    let tx_count = OffChainDatabase::transaction(&mut database)
+        .get_tx_count()
+        .unwrap();
+    assert_eq!(tx_count, included);
+    assert_eq!(reconcile_tx_count(&mut database).unwrap(), 0);
+}
+
+#[test]
+fn process_executor_events__counts_coins_per_owner_and_asset() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut database = Database::<OffChain>::in_memory();
+    let owner: Address = rng.gen();
+    let asset_id: AssetId = rng.gen();
+    let coins: Vec<_> = (0..3)
+        .map(|_| Coin {
+            utxo_id: rng.gen(),
+            owner,
+            amount: 10,
+            asset_id,
+            tx_pointer: Default::default(),
+        })
+        .collect();
+    let mut transaction = database.write_transaction();
+
+    // given
+    let created = coins
+        .iter()
+        .map(|coin| Cow::Owned(Event::CoinCreated(*coin)));
+    process_executor_events(
+        created,
+        Default::default(),
+        &mut transaction,
+        false,
+        false,
+        &asset_id,
+    )
+    .unwrap();
+
+    // when
+    let consumed = coins[..2]
+        .iter()
+        .map(|coin| Cow::Owned(Event::CoinConsumed(*coin)));
+    process_executor_events(
+        consumed,
+        Default::default(),
+        &mut transaction,
+        false,
+        false,
+        &asset_id,
+    )
+    .unwrap();
+
+    // then
+    let count = transaction
+        .storage_as_ref::<OwnedCoinCountByAsset>()
+        .get(&CoinBalancesKey::new(&owner, &asset_id))
+        .unwrap()
+        .map(Cow::into_owned);
+    assert_eq!(count, Some(1));
+}
+
+#[test]
+fn process_executor_events__indexes_owned_coins_by_asset() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut database = Database::<OffChain>::in_memory();
+    let owner: Address = rng.gen();
+    let asset_id: AssetId = rng.gen();
+    let other_asset_id: AssetId = rng.gen();
+    let mut coin = |asset_id| Coin {
+        utxo_id: rng.gen(),
+        owner,
+        amount: 10,
+        asset_id,
+        tx_pointer: Default::default(),
+    };
+    let coins = [coin(asset_id), coin(other_asset_id), coin(asset_id)];
+    let mut transaction = database.write_transaction();
+
+    // given
+    let created = coins
+        .iter()
+        .map(|coin| Cow::Owned(Event::CoinCreated(*coin)));
+    process_executor_events(
+        created,
+        Default::default(),
+        &mut transaction,
+        false,
+        false,
+        &asset_id,
+    )
+    .unwrap();
+
+    // when
+    let consumed = Cow::Owned(Event::CoinConsumed(coins[0]));
+    process_executor_events(
+        std::iter::once(consumed),
+        Default::default(),
+        &mut transaction,
+        false,
+        false,
+        &asset_id,
+    )
+    .unwrap();
+    let changes = transaction.into_changes();
+    database.commit_changes_without_height(changes).unwrap();
+
+    // then
+    let indexed = database
+        .iter_all_filtered_keys::<OwnedCoinsByAsset, _>(
+            Some(OwnedCoinByAssetKey::prefix(&owner, &asset_id)),
+            None,
+            None,
+        )
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(
+        indexed,
+        vec![OwnedCoinByAssetKey::new(
+            &owner,
+            &asset_id,
+            &coins[2].utxo_id
+        )]
+    );
+}
+
+#[test]
+fn process_executor_events__flags_coins_of_known_predicate_owners() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut database = Database::<OffChain>::in_memory();
+    let predicate: Address = rng.gen();
+    let account: Address = rng.gen();
+    let asset_id: AssetId = rng.gen();
+    let mut coin = |owner| Coin {
+        utxo_id: rng.gen(),
+        owner,
+        amount: 10,
+        asset_id,
+        tx_pointer: Default::default(),
+    };
+    let coins = [coin(predicate), coin(account), coin(predicate)];
+    let mut transaction = database.write_transaction();
+    transaction
+        .storage_as_mut::<KnownPredicateOwners>()
+        .insert(
+            &predicate,
+            &KnownOwner::Predicate {
+                code_hash: rng.gen(),
+            },
+        )
+        .unwrap();
+    transaction
+        .storage_as_mut::<KnownPredicateOwners>()
+        .insert(&account, &KnownOwner::Account)
+        .unwrap();
+
+    // given
+    let created = coins
+        .iter()
+        .map(|coin| Cow::Owned(Event::CoinCreated(*coin)));
+    process_executor_events(
+        created,
+        Default::default(),
+        &mut transaction,
+        false,
+        false,
+        &asset_id,
+    )
+    .unwrap();
+
+    // when
+    let consumed = Cow::Owned(Event::CoinConsumed(coins[0]));
+    process_executor_events(
+        std::iter::once(consumed),
+        Default::default(),
+        &mut transaction,
+        false,
+        false,
+        &asset_id,
+    )
+    .unwrap();
+    let changes = transaction.into_changes();
+    database.commit_changes_without_height(changes).unwrap();
+
+    // then
+    let flagged = database
+        .iter_all_keys::<PredicateOwnedCoins>(None)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(
+        flagged,
+        vec![owner_coin_id_key(&predicate, &coins[2].utxo_id)]
+    );
+}
+
+#[test]
+fn process_executor_events__owner_last_activity_only_moves_forward() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut database = Database::<OffChain>::in_memory();
+    let owner: Address = rng.gen();
+    let coin = |utxo_id| Coin {
+        utxo_id,
+        owner,
+        amount: 10,
+        asset_id: Default::default(),
+        tx_pointer: Default::default(),
+    };
+    let mut transaction = database.write_transaction();
+
+    // given
+    let created = Cow::Owned(Event::CoinCreated(coin(rng.gen())));
+    process_executor_events(
+        std::iter::once(created),
+        5.into(),
+        &mut transaction,
+        false,
+        false,
+        &Default::default(),
+    )
+    .unwrap();
+
+    // when
+    let created = Cow::Owned(Event::CoinCreated(coin(rng.gen())));
+    process_executor_events(
+        std::iter::once(created),
+        3.into(),
+        &mut transaction,
+        false,
+        false,
+        &Default::default(),
+    )
+    .unwrap();
+
+    // then
+    let last_activity = transaction
+        .storage_as_ref::<OwnerLastActivity>()
+        .get(&owner)
+        .unwrap()
+        .map(Cow::into_owned);
+    assert_eq!(last_activity, Some(5.into()));
+}
+
+#[test]
+fn process_executor_events__message_spent_twice_is_counted_once() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut database = Database::<OffChain>::in_memory();
+    let mut message = Message::default();
+    message.set_nonce(rng.gen());
+    let base_asset_id: AssetId = rng.gen();
+    let mut transaction = database.write_transaction();
+
+    // given
+    let consumed = Cow::Owned(Event::MessageConsumed(message.clone()));
+    process_executor_events(
+        std::iter::once(consumed.clone()),
+        Default::default(),
+        &mut transaction,
+        false,
+        false,
+        &base_asset_id,
+    )
+    .unwrap();
+
+    // when
+    process_executor_events(
+        std::iter::once(consumed),
+        Default::default(),
+        &mut transaction,
+        false,
+        false,
+        &base_asset_id,
+    )
+    .unwrap();
+
+    // then
+    let count = transaction
+        .storage_as_ref::<StatisticTable<u64>>()
+        .get(SPENT_MESSAGE_COUNT)
+        .unwrap()
+        .map(Cow::into_owned);
+    assert_eq!(count, Some(1));
+}
+
+#[test]
+fn index_tx_owners_for_block__indexes_transaction_once_per_contract() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut database = Database::<OffChain>::in_memory();
+    let contract_id: ContractId = rng.gen();
+    let other_contract_id: ContractId = rng.gen();
+    let height = BlockHeight::from(1);
+
+    // given
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    for (input_index, contract) in [contract_id, contract_id, other_contract_id]
+        .into_iter()
+        .enumerate()
+    {
+        builder.add_input(Input::contract(
+            rng.gen(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract,
+        ));
+        builder.add_output(Output::contract(
+            u16::try_from(input_index).unwrap(),
+            Default::default(),
+            Default::default(),
+        ));
+    }
+    let tx = builder.finalize_as_transaction();
+    let tx_id = tx.id(&ChainId::default());
+    let mut block = Block::default();
+    block.header_mut().set_block_height(height);
+    *block.transactions_mut() = vec![tx];
+
+    // when
+    let mut transaction = database.write_transaction();
+    index_tx_owners_for_block(
+        &block,
+        &mut transaction,
+        &ChainId::default(),
+        &mut OwnerActivityUpdates::default(),
+    )
+    .unwrap();
+    let changes = transaction.into_changes();
+    database.commit_changes_without_height(changes).unwrap();
+
+    // then
+    let indexed = database
+        .iter_all::<ContractTransactions>(None)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let mut expected = vec![
+        (
+            ContractTransactionIndexKey::new(&contract_id, height, 0),
+            tx_id,
+        ),
+        (
+            ContractTransactionIndexKey::new(&other_contract_id, height, 0),
+            tx_id,
+        ),
+    ];
+    expected.sort();
+    assert_eq!(indexed, expected);
+}
+
+#[test]
+fn index_contracts_by_block__indexes_only_create_transactions() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut database = Database::<OffChain>::in_memory();
+    let height = BlockHeight::from(7);
+
+    // given
+    let script = TransactionBuilder::script(vec![], vec![]).finalize_as_transaction();
+    let create = TransactionBuilder::create(vec![1, 2, 3].into(), rng.gen(), vec![])
+        .add_contract_created()
+        .finalize();
+    let contract_id = *create.outputs()[0].contract_id().unwrap();
+    let transactions = vec![script, create.into()];
+    let tx_ids: Vec<TxId> = transactions
+        .iter()
+        .map(|tx| tx.id(&ChainId::default()))
+        .collect();
+
+    // when
+    let mut transaction = database.write_transaction();
+    index_contracts_by_block(
+        height,
+        tx_ids.iter().zip(transactions.iter()),
+        &mut transaction,
+    )
+    .unwrap();
+    let changes = transaction.into_changes();
+    database.commit_changes_without_height(changes).unwrap();
+
+    // then
+    let indexed = database
+        .iter_all::<ContractsByBlock>(None)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let expected = vec![(
+        ContractsByBlockKey {
+            block_height: height,
+            tx_idx: 1,
+        },
+        ContractDeployment {
+            contract_id,
+            tx_id: tx_ids[1],
+        },
+    )];
+    assert_eq!(indexed, expected);
+}
+
+#[test]
+fn index_block_producer__indexes_blocks_by_their_signer() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut database = Database::<OffChain>::in_memory();
+
+    // given
+    let first_key = SecretKey::random(&mut rng);
+    let second_key = SecretKey::random(&mut rng);
+    let signed = |key: &SecretKey, block_id: &BlockId| {
+        Consensus::PoA(PoAConsensus::new(Signature::sign(
+            key,
+            &block_id.as_message(),
+        )))
+    };
+    let blocks = (0u8..5)
+        .map(|height| {
+            (
+                BlockHeight::from(u32::from(height)),
+                BlockId::from([height; 32]),
+            )
+        })
+        .collect::<Vec<_>>();
+    let consensus = vec![
+        Consensus::Genesis(Default::default()),
+        signed(&first_key, &blocks[1].1),
+        signed(&second_key, &blocks[2].1),
+        signed(&first_key, &blocks[3].1),
+        Consensus::PoA(PoAConsensus::new(Signature::default())),
+    ];
+
+    // when
+    let mut transaction = database.write_transaction();
+    for ((height, block_id), consensus) in blocks.iter().zip(consensus.iter()) {
+        index_block_producer(*height, block_id, consensus, &mut transaction).unwrap();
+    }
+    let changes = transaction.into_changes();
+    database.commit_changes_without_height(changes).unwrap();
+
+    // then
+    let indexed = database
+        .iter_all_keys::<BlocksByProducer>(None)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let first_producer = Input::owner(&first_key.public_key());
+    let second_producer = Input::owner(&second_key.public_key());
+    let key = |producer: Address, height: u32| BlocksByProducerKey {
+        producer,
+        height: height.into(),
+    };
+    let mut expected = vec![
+        key(UNKNOWN_BLOCK_PRODUCER, 0),
+        key(first_producer, 1),
+        key(second_producer, 2),
+        key(first_producer, 3),
+        key(UNKNOWN_BLOCK_PRODUCER, 4),
+    ];
+    expected.sort_by_key(|key| (key.producer, key.height));
+    assert_eq!(indexed, expected);
+}
+
+#[test]
+fn index_block_in_parallel__matches_serial_indexation() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let config = indexation_config();
+    let mut serial_database = Database::<OffChain>::in_memory();
+    let mut parallel_database = Database::<OffChain>::in_memory();
+    let mut unspent_coins = vec![];
+
+    for height in 1..=5u32 {
+        // given
+        let import_result =
+            transfers_import_result(&mut rng, height.into(), &mut unspent_coins);
+
+        // when
+        let mut serial = serial_database.write_transaction();
+        let serial_tx_count =
+            index_block_serially(&config, &import_result, &mut serial).unwrap();
+        let mut parallel = parallel_database.write_transaction();
+        let parallel_tx_count =
+            index_block_in_parallel(&config, &import_result, &mut parallel).unwrap();
+
+        // then
+        assert_eq!(serial_tx_count, parallel_tx_count);
+        assert_eq!(serial.changes(), parallel.changes());
+        serial.commit().unwrap();
+        parallel.commit().unwrap();
+    }
+}
+
+#[test]
+fn latest_view__never_observes_spent_coin_as_owned_while_blocks_are_committed() {
+    const BLOCKS: u32 = 50;
+    let mut rng = StdRng::seed_from_u64(2322);
+    let database = Database::<OffChain>::in_memory();
+    let mut task = worker_task_with_block_importer_and_db(
+        tokio_stream::pending().into_boxed(),
+        database.clone(),
+    );
+
+    // given
+    let mut unspent_coins = vec![];
+    let blocks = (1..=BLOCKS)
+        .map(|height| {
+            Arc::new(transfers_import_result(
+                &mut rng,
+                height.into(),
+                &mut unspent_coins,
+            ))
+        })
+        .collect::<Vec<_>>();
+    // The heights of the blocks that created and spent each coin.
+    let mut created_coins = vec![];
+    let mut spent_at = HashMap::new();
+    for block in &blocks {
+        let height = *block.sealed_block.entity.header().height();
+        for event in block.events.iter() {
+            match event {
+                Event::CoinCreated(coin) => created_coins.push((*coin, height)),
+                Event::CoinConsumed(coin) => {
+                    spent_at.insert(coin.utxo_id, height);
+                }
+                _ => {}
+            }
+        }
+    }
+    let spent_coins = created_coins
+        .iter()
+        .filter(|(coin, _)| spent_at.contains_key(&coin.utxo_id))
+        .map(|(coin, _)| *coin)
+        .collect::<Vec<_>>();
+    let indexed = AtomicBool::new(false);
+
+    std::thread::scope(|scope| {
+        let reader = scope.spawn(|| loop {
+            let done = indexed.load(Ordering::Acquire);
+            let view = OffChainDatabase::latest_view(&database).unwrap();
+            let view_height = view.metadata().copied();
+            for (coin, created_at) in &created_coins {
+                let owned = ports::OffChainDatabase::owned_coins_ids(
+                    &view,
+                    &coin.owner,
+                    None,
+                    IterDirection::Forward,
+                    false,
+                )
+                .any(|utxo_id| utxo_id.unwrap() == coin.utxo_id);
+                let spent = ports::OffChainDatabase::spent_coin(&view, &coin.utxo_id)
+                    .unwrap()
+                    .is_some();
+                let is_created = view_height.is_some_and(|h| *created_at <= h);
+                let is_spent = spent_at
+                    .get(&coin.utxo_id)
+                    .is_some_and(|spent_at| view_height.is_some_and(|h| *spent_at <= h));
+
+                // then
+                assert!(
+                    !(owned && spent),
+                    "The coin {} is both owned and spent",
+                    coin.utxo_id
+                );
+                assert_eq!(
+                    owned || spent,
+                    is_created,
+                    "The coin {} created at {} is not indexed exactly once \
+                    at the height {:?}",
+                    coin.utxo_id,
+                    created_at,
+                    view_height
+                );
+                assert_eq!(
+                    spent, is_spent,
+                    "The coin {} is spent in a view of the wrong height {:?}",
+                    coin.utxo_id, view_height
+                );
+            }
+            if done {
+                break
+            }
+        });
+
+        // when
+        for block in blocks {
+            task.process_block(block).unwrap();
+        }
+        indexed.store(true, Ordering::Release);
+        reader.join().unwrap();
+    });
+    let view = OffChainDatabase::latest_view(&database).unwrap();
+    for coin in &spent_coins {
+        let spent_coin = ports::OffChainDatabase::spent_coin(&view, &coin.utxo_id);
+        assert!(spent_coin.unwrap().is_some());
+    }
+}
+
+#[test]
+fn index_block_in_parallel__failure_of_one_family_aborts_the_block() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let config = indexation_config();
+    let mut database = Database::<OffChain>::in_memory();
+    let import_result = transfers_import_result(&mut rng, 1.into(), &mut vec![]);
+    let mut transaction = database.write_transaction();
+    index_block_in_parallel(&config, &import_result, &mut transaction).unwrap();
+    transaction.commit().unwrap();
+
+    // given
+    let mut transaction = database.write_transaction();
+
+    // when
+    // The statuses of the transactions already exist, so the transactions family fails.
+    let result = index_block_in_parallel(&config, &import_result, &mut transaction);
+
+    // then
+    assert!(result.is_err());
+    assert!(transaction.changes().is_empty());
+}
+
+fn indexation_config() -> IndexationConfig {
+    IndexationConfig {
+        chain_id: Default::default(),
+        da_compression_config: DaCompressionConfig::Enabled(
+            fuel_core_compression::config::Config {
+                temporal_registry_retention: Duration::from_secs(3600),
+                registry_capacity: None,
+            },
+        ),
+        balances_indexation_enabled: true,
+        coins_to_spend_indexation_enabled: true,
+        asset_metadata_indexation_enabled: true,
+        contract_balances_indexation_enabled: true,
+        base_asset_id: Default::default(),
+        coin_owner_mapper: None,
+        relayed_transactions: None,
+        mark_spent_owned_coins: false,
+    }
+}
+
+/// Creates a block where every transaction spends one of the `unspent_coins`,
+/// when there is any, and creates two new coins instead.
+fn transfers_import_result(
+    rng: &mut StdRng,
+    height: BlockHeight,
+    unspent_coins: &mut Vec<Coin>,
+) -> ImportResult {
+    const TRANSACTIONS_PER_BLOCK: usize = 10;
+    let chain_id = ChainId::default();
+    let mut transactions = vec![];
+    let mut tx_status = vec![];
+    let mut events = vec![];
+    let mut created_coins = vec![];
+
+    for _ in 0..TRANSACTIONS_PER_BLOCK {
+        let mut builder = TransactionBuilder::script(vec![], vec![]);
+        if let Some(coin) = unspent_coins.pop() {
+            builder.add_input(Input::coin_signed(
+                coin.utxo_id,
+                coin.owner,
+                coin.amount,
+                coin.asset_id,
+                Default::default(),
+                Default::default(),
+            ));
+            builder.add_witness(Default::default());
+            events.push(Event::CoinConsumed(coin));
+        }
+        let recipient: Address = rng.gen();
+        let change: Address = rng.gen();
+        builder.add_output(Output::coin(recipient, 10, AssetId::default()));
+        builder.add_output(Output::change(change, 0, AssetId::default()));
+        let tx = builder.finalize_as_transaction();
+        let tx_id = tx.id(&chain_id);
+
+        for (output_index, owner) in [recipient, change].into_iter().enumerate() {
+            let coin = Coin {
+                utxo_id: UtxoId::new(tx_id, u16::try_from(output_index).unwrap()),
+                owner,
+                amount: rng.gen_range(1..1000),
+                asset_id: AssetId::default(),
+                tx_pointer: Default::default(),
+            };
+            events.push(Event::CoinCreated(coin));
+            created_coins.push(coin);
+        }
+        tx_status.push(TransactionExecutionStatus {
+            id: tx_id,
+            result: TransactionExecutionResult::Success {
+                result: None,
+                receipts: vec![],
+                total_gas: 0,
+                total_fee: 0,
+            },
+        });
+        transactions.push(tx);
+    }
+    unspent_coins.extend(created_coins);
+
+    let mut block = Block::default();
+    block.header_mut().set_block_height(height);
+    *block.transactions_mut() = transactions;
+
+    ImportResult {
+        sealed_block: SealedBlock {
+            entity: block,
+            consensus: Default::default(),
+        },
+        tx_status,
+        events,
+        source: Default::default(),
+    }
+}
+
+fn forced_tx_failed_import_result(height: u32, id: Bytes32) -> SharedImportResult {
+    let mut block = Block::default();
+    block.header_mut().set_block_height(height.into());
+    Arc::new(ImportResult {
+        sealed_block: SealedBlock {
+            entity: block,
+            consensus: Default::default(),
+        },
+        tx_status: vec![],
+        events: vec![Event::ForcedTransactionFailed {
+            id: id.into(),
+            block_height: height.into(),
+            failure: "failure".to_string(),
+        }],
+        source: Default::default(),
+    })
+}
+
 fn block_importer_for_event(event: Event) -> BoxStream<SharedImportResult> {
     let block = Arc::new(ImportResult {
         sealed_block: Default::default(),
@@ -81,12 +1393,240 @@ fn worker_task_with_block_importer_and_db<D: ports::worker::OffChainDatabase>(
         block_importer,
         database,
         chain_id,
-        da_compression_config: DaCompressionConfig::Disabled,
         continue_on_error: false,
         balances_indexation_enabled: true,
         coins_to_spend_indexation_enabled: true,
         asset_metadata_indexation_enabled: true,
+        contract_balances_indexation_enabled: true,
         base_asset_id: Default::default(),
+        historical_data_retention: None,
+        batch_blocks: NonZeroUsize::new(1).expect("1 is not zero"),
         block_height_subscription_handler: Default::default(),
+        contracts_info_cache: ContractsInfoCache::new(0),
+        coin_owner_mapper: None,
+        relayed_transactions: None,
+        mark_spent_owned_coins: false,
+        slow_commit_threshold: Duration::from_secs(1),
+        commit_duration_max: None,
+        indexation_retry: Default::default(),
+        failed_block: None,
+        dead_letter_height: None,
+    }
+}
+
+/// The off-chain database that fails the first `failures` commits,
+/// and delays each commit by the `commit_delay`.
+struct FaultyDatabase {
+    database: Database<OffChain>,
+    failures: usize,
+    commit_delay: Duration,
+}
+
+impl KeyValueInspect for FaultyDatabase {
+    type Column = Column;
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        KeyValueInspect::get(&self.database, key, column)
+    }
+}
+
+impl Modifiable for FaultyDatabase {
+    fn commit_changes(&mut self, changes: Changes) -> StorageResult<()> {
+        if self.failures > 0 {
+            self.failures = self.failures.saturating_sub(1);
+            return Err(anyhow::anyhow!("Injected IO error").into());
+        }
+        std::thread::sleep(self.commit_delay);
+        self.database.commit_changes(changes)
+    }
+}
+
+impl ports::worker::OffChainDatabase for FaultyDatabase {
+    type Transaction<'a>
+        = StorageTransaction<&'a mut Self>
+    where
+        Self: 'a;
+    type View = OffChainIterableKeyValueView;
+
+    fn latest_height(&self) -> StorageResult<Option<BlockHeight>> {
+        OffChainDatabase::latest_height(&self.database)
+    }
+
+    fn latest_view(&self) -> StorageResult<Self::View> {
+        OffChainDatabase::latest_view(&self.database)
+    }
+
+    fn transaction(&mut self) -> Self::Transaction<'_> {
+        self.into_transaction()
+    }
+
+    fn commit_blocks(&mut self, changes: Vec<Changes>) -> StorageResult<()> {
+        if self.failures > 0 {
+            self.failures = self.failures.saturating_sub(1);
+            return Err(anyhow::anyhow!("Injected IO error").into());
+        }
+        std::thread::sleep(self.commit_delay);
+        self.database.commit_changes_of_blocks(changes)
+    }
+
+    fn balances_indexation_enabled(&self) -> StorageResult<bool> {
+        self.database.balances_indexation_enabled()
+    }
+
+    fn coins_to_spend_indexation_enabled(&self) -> StorageResult<bool> {
+        self.database.coins_to_spend_indexation_enabled()
+    }
+
+    fn asset_metadata_indexation_enabled(&self) -> StorageResult<bool> {
+        self.database.asset_metadata_indexation_enabled()
+    }
+
+    fn contract_balances_indexation_enabled(&self) -> StorageResult<bool> {
+        self.database.contract_balances_indexation_enabled()
+    }
+
+    fn owned_coin_counts_indexation_enabled(&self) -> StorageResult<bool> {
+        self.database.owned_coin_counts_indexation_enabled()
+    }
+
+    fn indexation_stale(&self, kind: IndexationKind) -> StorageResult<bool> {
+        self.database.indexation_stale(kind)
+    }
+
+    fn indexation_dead_letter(&self) -> StorageResult<Option<IndexationDeadLetter>> {
+        self.database.indexation_dead_letter()
+    }
+
+    fn set_indexation_dead_letter(
+        &mut self,
+        dead_letter: Option<&IndexationDeadLetter>,
+    ) -> StorageResult<()> {
+        self.database.set_indexation_dead_letter(dead_letter)
+    }
+
+    fn relayed_tx_statuses_before(
+        &self,
+        height: BlockHeight,
+        limit: usize,
+    ) -> StorageResult<Vec<RelayedTransactionStatusHeightKey>> {
+        self.database.relayed_tx_statuses_before(height, limit)
     }
+
+    fn included_tx_count(&self) -> StorageResult<u64> {
+        self.database.included_tx_count()
+    }
+
+    fn set_tx_count(&mut self, tx_count: u64) -> StorageResult<()> {
+        self.database.set_tx_count(tx_count)
+    }
+
+    fn commit_duration_max(&self) -> StorageResult<Option<CommitDurationMax>> {
+        self.database.commit_duration_max()
+    }
+
+    fn set_commit_duration_max(&mut self, max: &CommitDurationMax) -> StorageResult<()> {
+        self.database.set_commit_duration_max(max)
+    }
+
+    fn da_compression_height(&self) -> StorageResult<Option<BlockHeight>> {
+        self.database.da_compression_height()
+    }
+
+    fn commit_da_compression(&mut self, changes: Changes) -> StorageResult<()> {
+        self.database.commit_da_compression(changes)
+    }
+}
+
+#[test]
+fn extract_tx_owners__returns_coin_input_and_output_owners_once() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let owner: Address = rng.gen();
+    let recipient: Address = rng.gen();
+
+    // given
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .add_input(Input::coin_signed(
+            rng.gen(),
+            owner,
+            100,
+            Default::default(),
+            Default::default(),
+            0,
+        ))
+        .add_output(Output::coin(recipient, 50, Default::default()))
+        .add_output(Output::change(owner, 0, Default::default()))
+        .finalize_as_transaction();
+
+    // when
+    let owners = extract_tx_owners(&tx);
+
+    // then
+    assert_eq!(
+        owners,
+        BTreeMap::from([
+            (owner, OwnedTransactionDirection::Any),
+            (recipient, OwnedTransactionDirection::Received),
+        ])
+    );
+}
+
+#[test]
+fn extract_tx_owners__returns_recipients_of_message_inputs() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let coin_recipient: Address = rng.gen();
+    let data_recipient: Address = rng.gen();
+
+    // given
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .add_input(Input::message_coin_signed(
+            rng.gen(),
+            coin_recipient,
+            100,
+            rng.gen(),
+            0,
+        ))
+        .add_input(Input::message_data_signed(
+            rng.gen(),
+            data_recipient,
+            100,
+            rng.gen(),
+            0,
+            vec![1, 2, 3],
+        ))
+        .finalize_as_transaction();
+
+    // when
+    let owners = extract_tx_owners(&tx);
+
+    // then
+    assert_eq!(
+        owners,
+        BTreeMap::from([
+            (coin_recipient, OwnedTransactionDirection::Sent),
+            (data_recipient, OwnedTransactionDirection::Sent),
+        ])
+    );
+}
+
+#[test]
+fn extract_tx_owners__ignores_contract_inputs_and_outputs() {
+    let mut rng = StdRng::seed_from_u64(2322);
+
+    // given
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .add_input(Input::contract(
+            rng.gen(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            rng.gen(),
+        ))
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_as_transaction();
+
+    // when
+    let owners = extract_tx_owners(&tx);
+
+    // then
+    assert!(owners.is_empty());
 }
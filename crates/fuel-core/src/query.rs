@@ -1,4 +1,3 @@
-mod assets;
 mod balance;
 mod blob;
 mod block;
@@ -9,9 +8,16 @@ mod subscriptions;
 mod tx;
 mod upgrades;
 
+pub mod assets;
 pub mod da_compressed;
+pub mod owner_activity;
 
 // TODO: Remove reexporting of everything
 pub use balance::*;
 pub use message::*;
 pub(crate) use subscriptions::*;
+pub use tx::{
+    OwnedTransactionsFilter,
+    TransactionInclusionProof,
+    TransactionStatusFilter,
+};
@@ -1,15 +1,68 @@
 use crate::{
     database::database_description::IndexationKind,
     fuel_core_graphql_api::database::ReadView,
-    graphql_api::storage::assets::AssetDetails,
+    graphql_api::storage::assets::{
+        asset_supply_checkpoint_height,
+        AssetDetails,
+        AssetFirstSeenInfo,
+        AssetSupplyCheckpoints,
+    },
 };
 use fuel_core_storage::{
+    iter::IterDirection,
     not_found,
     Result as StorageResult,
 };
-use fuel_core_types::fuel_tx::AssetId;
+use fuel_core_types::{
+    fuel_tx::AssetId,
+    fuel_types::BlockHeight,
+};
+use futures::Stream;
+
+/// The change of the total supply of the asset in the block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AssetSupplyChange {
+    pub block_height: BlockHeight,
+    /// The net change of the supply in the block.
+    pub delta: i128,
+    /// The total supply after the block.
+    pub supply: u128,
+}
 
 impl ReadView {
+    /// Returns the minted assets with their details after the `start`, in the order
+    /// of asset ids. If the `base_asset_id` is given, the base asset without
+    /// the details goes first, and the cursor of it continues from the first
+    /// minted asset.
+    pub fn assets(
+        &self,
+        start: Option<AssetId>,
+        base_asset_id: Option<AssetId>,
+    ) -> StorageResult<
+        impl Stream<Item = StorageResult<(AssetId, Option<AssetDetails>)>> + '_,
+    > {
+        if !self
+            .indexation_flags
+            .contains(&IndexationKind::AssetMetadata)
+        {
+            return Err(anyhow::anyhow!("Asset metadata index is not available").into());
+        }
+
+        let base_asset = base_asset_id
+            .filter(|base_asset_id| start.is_none() || start == Some(*base_asset_id))
+            .map(|base_asset_id| Ok((base_asset_id, None)));
+        let start = start.filter(|start| Some(*start) != base_asset_id);
+        let minted = self
+            .off_chain
+            .assets_info(start, IterDirection::Forward)
+            .filter(move |result| {
+                !matches!(result, Ok((asset_id, _)) if Some(*asset_id) == base_asset_id)
+            })
+            .map(|result| result.map(|(asset_id, details)| (asset_id, Some(details))));
+
+        Ok(futures::stream::iter(base_asset.into_iter().chain(minted)))
+    }
+
     pub fn get_asset_details(&self, id: &AssetId) -> StorageResult<AssetDetails> {
         if self
             .indexation_flags
@@ -23,4 +76,77 @@ impl ReadView {
             Err(anyhow::anyhow!("Asset metadata index is not available").into())
         }
     }
+
+    /// Returns the block where the asset was seen for the first time,
+    /// or `None` if the asset was never seen.
+    pub fn asset_first_seen(
+        &self,
+        id: &AssetId,
+    ) -> StorageResult<Option<AssetFirstSeenInfo>> {
+        if self
+            .indexation_flags
+            .contains(&IndexationKind::AssetMetadata)
+        {
+            self.off_chain.asset_first_seen(id)
+        } else {
+            Err(anyhow::anyhow!("Asset metadata index is not available").into())
+        }
+    }
+
+    /// Returns the changes of the supply of the asset made after the `after` height,
+    /// in the order of heights.
+    ///
+    /// The supply is derived from the checkpoint of the interval of each change,
+    /// so only the changes of the interval of the `after` height are read
+    /// in addition to the returned ones.
+    pub fn asset_supply_history(
+        &self,
+        asset_id: AssetId,
+        after: Option<BlockHeight>,
+    ) -> StorageResult<impl Stream<Item = StorageResult<AssetSupplyChange>> + '_> {
+        if !self
+            .indexation_flags
+            .contains(&IndexationKind::AssetMetadata)
+        {
+            return Err(anyhow::anyhow!("Asset metadata index is not available").into());
+        }
+
+        let start = after.map(asset_supply_checkpoint_height);
+        let mut checkpoint = None;
+        let mut supply = 0u128;
+        let changes = self
+            .off_chain
+            .asset_supply_changes(&asset_id, start, IterDirection::Forward)
+            .map(move |result| {
+                let (block_height, delta) = result?;
+                let checkpoint_height = asset_supply_checkpoint_height(block_height);
+                if checkpoint != Some(checkpoint_height) {
+                    supply = self
+                        .off_chain
+                        .asset_supply_checkpoint(&asset_id, checkpoint_height)?
+                        .ok_or(not_found!(AssetSupplyCheckpoints))?;
+                    checkpoint = Some(checkpoint_height);
+                }
+                supply = apply_supply_change(supply, delta);
+
+                Ok(AssetSupplyChange {
+                    block_height,
+                    delta,
+                    supply,
+                })
+            })
+            .filter(move |result| {
+                !matches!(result, Ok(change) if Some(change.block_height) <= after)
+            });
+
+        Ok(futures::stream::iter(changes))
+    }
+}
+
+fn apply_supply_change(supply: u128, delta: i128) -> u128 {
+    if delta.is_negative() {
+        supply.saturating_sub(delta.unsigned_abs())
+    } else {
+        supply.saturating_add(delta.unsigned_abs())
+    }
 }
@@ -5,9 +5,13 @@ use std::{
 
 use crate::{
     database::database_description::IndexationKind,
-    fuel_core_graphql_api::database::ReadView,
+    fuel_core_graphql_api::database::{
+        ReadDatabase,
+        ReadView,
+    },
     graphql_api::storage::balances::TotalBalanceAmount,
 };
+use anyhow::anyhow;
 use asset_query::{
     AssetQuery,
     AssetSpendTarget,
@@ -23,6 +27,7 @@ use fuel_core_types::{
         Address,
         AssetId,
     },
+    fuel_types::BlockHeight,
     services::graphql_api::AddressBalance,
 };
 use futures::{
@@ -42,6 +47,7 @@ impl ReadView {
         asset_id: AssetId,
         base_asset_id: AssetId,
     ) -> StorageResult<AddressBalance> {
+        self.ensure_indexation_ready(IndexationKind::Balances)?;
         let amount = if self.indexation_flags.contains(&IndexationKind::Balances) {
             self.off_chain.balance(&owner, &asset_id, &base_asset_id)?
         } else {
@@ -67,6 +73,26 @@ impl ReadView {
         })
     }
 
+    /// Returns the total value of the coins of the `owner` in the `asset_id`.
+    /// Unlike the [`Self::balance`], the messages are not included.
+    pub async fn owned_coin_value(
+        &self,
+        owner: Address,
+        asset_id: AssetId,
+    ) -> StorageResult<TotalBalanceAmount> {
+        self.ensure_indexation_ready(IndexationKind::Balances)?;
+        if self.indexation_flags.contains(&IndexationKind::Balances) {
+            return self.off_chain.coin_balance(&owner, &asset_id)
+        }
+
+        self.owned_coins(&owner, None, IterDirection::Forward, false)
+            .try_filter(|coin| futures::future::ready(coin.asset_id == asset_id))
+            .try_fold(0 as TotalBalanceAmount, |value, coin| async move {
+                Ok(value.saturating_add(coin.amount as TotalBalanceAmount))
+            })
+            .await
+    }
+
     pub fn balances<'a>(
         &'a self,
         owner: &'a Address,
@@ -161,3 +187,59 @@ impl ReadView {
         .yield_each(self.batch_size)
     }
 }
+
+impl ReadDatabase {
+    /// Returns the net change of the balance of the `owner` from the block at
+    /// `from_height` to the block at `to_height`. The balance is zero before the
+    /// first activity of the owner. Requires the balances indexation and the
+    /// history of the off-chain database at both heights.
+    pub fn balance_delta(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        base_asset_id: &AssetId,
+        from_height: BlockHeight,
+        to_height: BlockHeight,
+    ) -> StorageResult<i128> {
+        let view = self.view()?;
+        view.ensure_indexation_ready(IndexationKind::Balances)?;
+        if !view.indexation_flags.contains(&IndexationKind::Balances) {
+            return Err(anyhow!(
+                "The balance delta requires the balances indexation to be enabled"
+            )
+            .into())
+        }
+        if from_height > to_height {
+            return Err(anyhow!(
+                "The `from` height {from_height} is above the `to` height {to_height}"
+            )
+            .into())
+        }
+        match self.indexed_height() {
+            Some(indexed_height) if to_height <= indexed_height => {}
+            indexed_height => {
+                return Err(anyhow!(
+                    "The `to` height {to_height} is above the indexed height {indexed_height:?}"
+                )
+                .into())
+            }
+        }
+
+        let balance_at = |height: BlockHeight| -> StorageResult<i128> {
+            let balance = self.view_at(height)?.off_chain.balance(
+                owner,
+                asset_id,
+                base_asset_id,
+            )?;
+            let balance = i128::try_from(balance).map_err(|_| {
+                anyhow!(
+                    "The balance {balance} at the height {height} overflows the delta"
+                )
+            })?;
+            Ok(balance)
+        };
+        let from = balance_at(from_height)?;
+        let to = balance_at(to_height)?;
+        Ok(to.saturating_sub(from))
+    }
+}
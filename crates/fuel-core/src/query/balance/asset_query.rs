@@ -98,7 +98,7 @@ impl<'a> AssetsQuery<'a> {
         let database = self.database;
         let stream = self
             .database
-            .owned_coins_ids(self.owner, None, IterDirection::Forward)
+            .owned_coins_ids(self.owner, None, IterDirection::Forward, false)
             .map(|id| id.map(CoinId::from))
             .filter(move |result| {
                 if let Ok(id) = result {
@@ -5,10 +5,20 @@ use fuel_core_storage::{
     Result as StorageResult,
 };
 use fuel_core_types::{
-    blockchain::block::CompressedBlock,
-    fuel_types::BlockHeight,
+    blockchain::{
+        block::CompressedBlock,
+        consensus::Consensus,
+        header::BlockHeader,
+    },
+    fuel_types::{
+        Address,
+        BlockHeight,
+    },
+};
+use futures::{
+    Stream,
+    StreamExt,
 };
-use futures::Stream;
 
 impl ReadView {
     pub fn latest_block_height(&self) -> StorageResult<BlockHeight> {
@@ -26,4 +36,48 @@ impl ReadView {
     ) -> impl Stream<Item = StorageResult<CompressedBlock>> + '_ {
         futures::stream::iter(self.blocks(height, direction)).yield_each(self.batch_size)
     }
+
+    /// Returns the headers of the blocks produced by the `producer`.
+    pub fn blocks_by_producer<'a>(
+        &'a self,
+        producer: &'a Address,
+        start_height: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<BlockHeader>> + 'a {
+        self.block_heights_by_producer(producer, start_height, direction)
+            .map(move |height| self.block_header(&height?))
+            .yield_each(self.batch_size)
+    }
+
+    /// Returns the headers and the consensus of the blocks from the `start`
+    /// to the `end`(inclusive) without the ids of their transactions.
+    /// The heights above the latest block are skipped.
+    pub fn block_headers(
+        &self,
+        start: BlockHeight,
+        end: BlockHeight,
+        max_headers: usize,
+    ) -> StorageResult<Vec<(BlockHeader, Consensus)>> {
+        if start > end {
+            return Err(anyhow::anyhow!(
+                "The start height {start} is above the end height {end}"
+            )
+            .into())
+        }
+        let requested = u32::from(end)
+            .saturating_sub(u32::from(start))
+            .saturating_add(1);
+        if usize::try_from(requested).map_or(true, |requested| requested > max_headers) {
+            return Err(anyhow::anyhow!(
+                "The number of requested headers({requested}) is above the limit({max_headers})"
+            )
+            .into())
+        }
+
+        let end = end.min(self.latest_block_height()?);
+        (u32::from(start)..=u32::from(end))
+            .map(BlockHeight::from)
+            .map(|height| Ok((self.block_header(&height)?, self.consensus(&height)?)))
+            .collect()
+    }
 }
@@ -10,7 +10,10 @@ use fuel_core_storage::{
 use fuel_core_types::{
     entities::coins::coin::Coin,
     fuel_tx::UtxoId,
-    fuel_types::Address,
+    fuel_types::{
+        Address,
+        AssetId,
+    },
 };
 use futures::{
     Stream,
@@ -48,19 +51,101 @@ impl ReadView {
         owner: &Address,
         start_coin: Option<UtxoId>,
         direction: IterDirection,
+        include_derived: bool,
     ) -> impl Stream<Item = StorageResult<Coin>> + '_ {
-        self.owned_coins_ids(owner, start_coin, direction)
+        let utxo_ids =
+            self.owned_coins_ids(owner, start_coin, direction, include_derived);
+        self.coins_of_ids(utxo_ids)
+    }
+
+    /// Returns the coins owned directly by the `owner` with the `asset_id`.
+    pub fn owned_coins_by_asset(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        start_coin: Option<UtxoId>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<Coin>> + '_ {
+        let utxo_ids =
+            self.owned_coins_ids_by_asset(owner, asset_id, start_coin, direction);
+        self.coins_of_ids(utxo_ids)
+    }
+
+    /// Returns the coins of the `owner` flagged as owned by a predicate
+    /// when they were indexed.
+    pub fn predicate_owned_coins(
+        &self,
+        owner: &Address,
+        start_coin: Option<UtxoId>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<Coin>> + '_ {
+        let utxo_ids = self.predicate_owned_coins_ids(owner, start_coin, direction);
+        self.coins_of_ids(utxo_ids)
+    }
+
+    /// Loads the coins of the `utxo_ids` in batches.
+    fn coins_of_ids<'a>(
+        &'a self,
+        utxo_ids: impl Stream<Item = StorageResult<UtxoId>> + 'a,
+    ) -> impl Stream<Item = StorageResult<Coin>> + 'a {
+        utxo_ids
             .chunks(self.batch_size)
-            .map(|chunk| {
+            .then(move |chunk| async move {
                 use itertools::Itertools;
 
-                let chunk = chunk.into_iter().try_collect::<_, Vec<_>, _>()?;
-                Ok::<_, StorageError>(chunk)
+                // The coins read before the error are still yielded,
+                // so the exceeded scan budget doesn't drop them.
+                let (utxo_ids, errors): (Vec<_>, Vec<StorageError>) =
+                    chunk.into_iter().partition_result();
+                let coins = self.coins(utxo_ids).await;
+                futures::stream::iter(coins.chain(errors.into_iter().map(Err)))
             })
-            .try_filter_map(move |chunk| async move {
-                let chunk = self.coins(chunk).await;
-                Ok(Some(futures::stream::iter(chunk)))
+            .flatten()
+    }
+
+    /// Returns at most `limit` coins of the `owner` from the `OwnedCoins` index
+    /// that don't exist in the on-chain `Coins` table. The view must be consistent,
+    /// otherwise the coins spent by the blocks not indexed yet are returned too.
+    pub fn orphaned_owned_coins(
+        &self,
+        owner: &Address,
+        limit: usize,
+    ) -> StorageResult<Vec<UtxoId>> {
+        self.off_chain
+            .owned_coins_ids(owner, None, IterDirection::Forward, true)
+            .filter_map(|utxo_id| match utxo_id {
+                Ok(utxo_id) => match self
+                    .on_chain
+                    .as_ref()
+                    .storage::<Coins>()
+                    .contains_key(&utxo_id)
+                {
+                    Ok(true) => None,
+                    Ok(false) => Some(Ok(utxo_id)),
+                    Err(err) => Some(Err(err)),
+                },
+                Err(err) => Some(Err(err)),
             })
-            .try_flatten()
+            .take(limit)
+            .collect()
+    }
+
+    /// Returns at most `limit` coins of the `owner` that follow the `after` coin
+    /// in the key order of the `OwnedCoins` table.
+    pub async fn owned_coins_after(
+        &self,
+        owner: &Address,
+        after: Option<UtxoId>,
+        limit: usize,
+    ) -> StorageResult<Vec<Coin>> {
+        // The iteration starts from the `after` coin inclusively, so skip it.
+        let utxo_ids = self
+            .owned_coins_ids(owner, after, IterDirection::Forward, false)
+            .try_filter(|utxo_id| futures::future::ready(Some(*utxo_id) != after))
+            .take(limit)
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        self.coins(utxo_ids).await.collect()
     }
 }
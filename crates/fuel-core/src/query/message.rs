@@ -1,4 +1,10 @@
-use crate::fuel_core_graphql_api::database::ReadView;
+use crate::fuel_core_graphql_api::{
+    database::ReadView,
+    storage::messages::{
+        OwnedMessageState,
+        SpentOwnedMessage,
+    },
+};
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -36,7 +42,6 @@ use fuel_core_types::{
 use futures::{
     Stream,
     StreamExt,
-    TryStreamExt,
 };
 use itertools::Itertools;
 use std::borrow::Cow;
@@ -44,6 +49,12 @@ use std::borrow::Cow;
 #[cfg(test)]
 mod test;
 
+/// The message of the owner, with the marker if it was spent.
+pub enum OwnedMessage {
+    Unspent(Message),
+    Spent(SpentOwnedMessage),
+}
+
 pub trait MessageQueryData: Send + Sync {
     fn message(&self, message_id: &Nonce) -> StorageResult<Message>;
 
@@ -98,15 +109,64 @@ impl ReadView {
     ) -> impl Stream<Item = StorageResult<Message>> + 'a {
         self.owned_message_ids(owner, start_message_id, direction)
             .chunks(self.batch_size)
-            .map(|chunk| {
-                let chunk = chunk.into_iter().try_collect::<_, Vec<_>, _>()?;
-                Ok(chunk)
+            .then(move |chunk| async move {
+                // The messages read before the error are still yielded,
+                // so the exceeded scan budget doesn't drop them.
+                let (ids, errors): (Vec<_>, Vec<StorageError>) =
+                    chunk.into_iter().partition_result();
+                let messages = self.messages(ids).await;
+                futures::stream::iter(messages.chain(errors.into_iter().map(Err)))
             })
-            .try_filter_map(move |chunk| async move {
-                let chunk = self.messages(chunk).await;
-                Ok::<_, StorageError>(Some(futures::stream::iter(chunk)))
+            .flatten()
+    }
+
+    /// Returns the messages of the `owner`, including the spent ones.
+    pub fn owned_messages_including_spent<'a>(
+        &'a self,
+        owner: &'a Address,
+        start_message_id: Option<Nonce>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<OwnedMessage>> + 'a {
+        self.owned_message_states(owner, start_message_id, direction)
+            .chunks(self.batch_size)
+            .then(move |chunk| async move {
+                // The spent messages are removed from the on-chain state,
+                // so they are taken from the marker.
+                let messages = chunk
+                    .into_iter()
+                    .map(|result| {
+                        result.and_then(|(nonce, state)| match state {
+                            OwnedMessageState::Unspent => {
+                                self.message(&nonce).map(OwnedMessage::Unspent)
+                            }
+                            OwnedMessageState::Spent(spent) => {
+                                Ok(OwnedMessage::Spent(spent))
+                            }
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                // Give a chance to other tasks to run.
+                tokio::task::yield_now().await;
+                futures::stream::iter(messages)
+            })
+            .flatten()
+    }
+
+    pub fn messages_by_sender<'a>(
+        &'a self,
+        sender: &'a Address,
+        start_message_id: Option<Nonce>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<Message>> + 'a {
+        self.message_ids_by_sender(sender, start_message_id, direction)
+            .chunks(self.batch_size)
+            .then(move |chunk| async move {
+                let (ids, errors): (Vec<_>, Vec<StorageError>) =
+                    chunk.into_iter().partition_result();
+                let messages = self.messages(ids).await;
+                futures::stream::iter(messages.chain(errors.into_iter().map(Err)))
             })
-            .try_flatten()
+            .flatten()
     }
 }
 
@@ -186,7 +246,8 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
             )
             .into())
         }
-        Ok(TransactionExecutionStatus::SqueezedOut { reason }) => {
+        Ok(TransactionExecutionStatus::SqueezedOut { reason })
+        | Ok(TransactionExecutionStatus::Rejected { reason, .. }) => {
             return Err(anyhow::anyhow!(
                 "Unable to obtain the message block height. The transaction was squeezed out: {reason}"
             )
@@ -274,6 +335,7 @@ fn message_receipts_proof<T: MessageProofData + ?Sized>(
             }
             Ok(TransactionExecutionStatus::Submitted { .. })
             | Ok(TransactionExecutionStatus::SqueezedOut { .. })
+            | Ok(TransactionExecutionStatus::Rejected { .. })
             | Ok(TransactionExecutionStatus::Failed { .. }) => None,
             Err(err) => Some(Err(err)),
         })
@@ -0,0 +1,170 @@
+use crate::{
+    fuel_core_graphql_api::database::ReadView,
+    query::OwnedTransactionsFilter,
+};
+use fuel_core_storage::{
+    iter::IterDirection,
+    Result as StorageResult,
+};
+use fuel_core_types::{
+    entities::{
+        coins::coin::Coin,
+        relayer::message::Message,
+    },
+    fuel_tx::{
+        Transaction,
+        TxPointer,
+        UtxoId,
+    },
+    fuel_types::{
+        Address,
+        BlockHeight,
+        Nonce,
+    },
+};
+use futures::{
+    Stream,
+    StreamExt,
+};
+use std::future;
+
+/// The entry of the owner's activity feed.
+#[derive(Debug)]
+pub enum OwnerActivityItem {
+    Coin(Coin),
+    Message(Message),
+    Transaction(TxPointer, Transaction),
+}
+
+impl OwnerActivityItem {
+    /// The key that orders the entries of the feed: the block and the transaction
+    /// that produced the entry, and the kind of the entry to break the ties.
+    ///
+    /// Messages are relayed from the DA layer and don't have a position on the chain,
+    /// so they precede the coins and transactions.
+    fn order_key(&self) -> (BlockHeight, u16, u8) {
+        match self {
+            OwnerActivityItem::Message(_) => (BlockHeight::default(), 0, 0),
+            OwnerActivityItem::Coin(coin) => (
+                coin.tx_pointer.block_height(),
+                coin.tx_pointer.tx_index(),
+                1,
+            ),
+            OwnerActivityItem::Transaction(tx_pointer, _) => {
+                (tx_pointer.block_height(), tx_pointer.tx_index(), 2)
+            }
+        }
+    }
+}
+
+/// The position in the owner's activity feed. It keeps the last entry taken from
+/// each of the owner's indexes, so the feed resumes from all of them.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OwnerActivityPosition {
+    pub coin: Option<UtxoId>,
+    pub message: Option<Nonce>,
+    pub transaction: Option<TxPointer>,
+}
+
+impl OwnerActivityPosition {
+    fn advance(&mut self, item: &OwnerActivityItem) {
+        match item {
+            OwnerActivityItem::Coin(coin) => self.coin = Some(coin.utxo_id),
+            OwnerActivityItem::Message(message) => self.message = Some(*message.nonce()),
+            OwnerActivityItem::Transaction(tx_pointer, _) => {
+                self.transaction = Some(*tx_pointer)
+            }
+        }
+    }
+}
+
+impl ReadView {
+    /// Returns the feed of the owner's coins, messages, and transactions that
+    /// follow the `after` position.
+    ///
+    /// The feed merges the owner's indexes, each iterated in its own order, by
+    /// taking the entry with the lowest order key among the next entries of the
+    /// indexes. The merge is deterministic, so the feed resumes from the position
+    /// of any of its entries.
+    pub fn owner_activity_feed<'a>(
+        &'a self,
+        owner: &'a Address,
+        after: OwnerActivityPosition,
+    ) -> impl Stream<Item = StorageResult<(OwnerActivityPosition, OwnerActivityItem)>> + 'a
+    {
+        // The iteration of the indexes includes the start entry, so skip it.
+        let coins = self
+            .owned_coins(owner, after.coin, IterDirection::Forward, false)
+            .filter(move |result| {
+                future::ready(
+                    !matches!(result, Ok(coin) if Some(coin.utxo_id) == after.coin),
+                )
+            })
+            .map(|result| result.map(OwnerActivityItem::Coin));
+        let messages = self
+            .owned_messages(owner, after.message, IterDirection::Forward)
+            .filter(move |result| {
+                future::ready(
+                    !matches!(result, Ok(message) if Some(*message.nonce()) == after.message),
+                )
+            })
+            .map(|result| result.map(OwnerActivityItem::Message));
+        let transactions = self
+            .owned_transactions(
+                *owner,
+                after.transaction,
+                IterDirection::Forward,
+                OwnedTransactionsFilter::default(),
+            )
+            .filter(move |result| {
+                future::ready(
+                    !matches!(result, Ok((tx_pointer, _)) if Some(*tx_pointer) == after.transaction),
+                )
+            })
+            .map(|result| {
+                result.map(|(tx_pointer, tx)| {
+                    OwnerActivityItem::Transaction(tx_pointer, tx)
+                })
+            });
+
+        let streams = (
+            Box::pin(messages.peekable()),
+            Box::pin(coins.peekable()),
+            Box::pin(transactions.peekable()),
+        );
+        futures::stream::unfold(Some((streams, after)), |state| async move {
+            let ((mut messages, mut coins, mut transactions), mut position) = state?;
+            let heads = [
+                messages.as_mut().peek().await,
+                coins.as_mut().peek().await,
+                transactions.as_mut().peek().await,
+            ];
+            // Errors are returned as soon as they are met.
+            let next = heads
+                .iter()
+                .enumerate()
+                .filter_map(|(index, head)| {
+                    head.map(|result| match result {
+                        Ok(item) => (Some(item.order_key()), index),
+                        Err(_) => (None, index),
+                    })
+                })
+                .min()?;
+            let next = match next.1 {
+                0 => messages.next().await,
+                1 => coins.next().await,
+                _ => transactions.next().await,
+            }?;
+
+            match next {
+                Ok(item) => {
+                    position.advance(&item);
+                    let state = ((messages, coins, transactions), position);
+                    Some((Ok((position, item)), Some(state)))
+                }
+                // The feed ends after the error.
+                Err(err) => Some((Err(err), None)),
+            }
+        })
+    }
+}
@@ -66,9 +66,8 @@ fn failure_during_block_production() -> TransactionStatus {
 }
 
 fn squeezed() -> TransactionStatus {
-    TransactionStatus::squeezed_out(
-        fuel_core_txpool::error::Error::Removed(RemovedReason::Ttl).to_string(),
-    )
+    let error = fuel_core_txpool::error::Error::Removed(RemovedReason::Ttl);
+    TransactionStatus::squeezed_out(error.to_string(), error.rejection_reason())
 }
 
 fn squeezed_during_block_production() -> TransactionStatus {
@@ -1,4 +1,11 @@
-use crate::fuel_core_graphql_api::database::ReadView;
+use crate::fuel_core_graphql_api::{
+    database::ReadView,
+    storage::transactions::OwnedTransactionDirection,
+    transaction_trees_cache::{
+        TransactionTree,
+        TransactionTreesCache,
+    },
+};
 use fuel_core_storage::{
     iter::IterDirection,
     not_found,
@@ -7,20 +14,63 @@ use fuel_core_storage::{
     Result as StorageResult,
 };
 use fuel_core_types::{
+    blockchain::header::BlockHeader,
+    entities::relayer::message::MerkleProof,
     fuel_tx::{
         Receipt,
         Transaction,
         TxId,
         TxPointer,
     },
-    fuel_types::Address,
+    fuel_types::{
+        Address,
+        ContractId,
+    },
     services::txpool::TransactionExecutionStatus,
 };
 use futures::{
     Stream,
     StreamExt,
-    TryStreamExt,
 };
+use itertools::Itertools;
+use std::sync::Arc;
+
+/// The status of the transactions returned by the [`ReadView::owned_transactions`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransactionStatusFilter {
+    Success,
+    Failure,
+    SqueezedOut,
+}
+
+impl TransactionStatusFilter {
+    pub fn matches(&self, status: &TransactionExecutionStatus) -> bool {
+        match self {
+            Self::Success => matches!(status, TransactionExecutionStatus::Success { .. }),
+            Self::Failure => matches!(status, TransactionExecutionStatus::Failed { .. }),
+            Self::SqueezedOut => matches!(
+                status,
+                TransactionExecutionStatus::SqueezedOut { .. }
+                    | TransactionExecutionStatus::Rejected { .. }
+            ),
+        }
+    }
+}
+
+/// Narrows the transactions returned by the [`ReadView::owned_transactions`].
+/// The default filter returns all transactions of the owner.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OwnedTransactionsFilter {
+    pub status: Option<TransactionStatusFilter>,
+    pub direction: OwnedTransactionDirection,
+}
+
+/// The proof that the transaction is included in the block.
+pub struct TransactionInclusionProof {
+    /// The proof against the `transactions_root` of the `block_header`.
+    pub proof: MerkleProof,
+    pub block_header: BlockHeader,
+}
 
 impl ReadView {
     pub fn receipts(&self, tx_id: &TxId) -> StorageResult<Vec<Receipt>> {
@@ -30,7 +80,8 @@ impl ReadView {
             TransactionExecutionStatus::Success { receipts, .. }
             | TransactionExecutionStatus::Failed { receipts, .. } => Some(receipts),
             TransactionExecutionStatus::Submitted { .. }
-            | TransactionExecutionStatus::SqueezedOut { .. } => None,
+            | TransactionExecutionStatus::SqueezedOut { .. }
+            | TransactionExecutionStatus::Rejected { .. } => None,
         };
 
         receipts.ok_or(not_found!(Transactions))
@@ -41,24 +92,120 @@ impl ReadView {
         owner: Address,
         start: Option<TxPointer>,
         direction: IterDirection,
+        filter: OwnedTransactionsFilter,
+    ) -> impl Stream<Item = StorageResult<(TxPointer, Transaction)>> + '_ {
+        self.transactions_by_pointers(
+            self.owned_transactions_ids(owner, start, direction, filter),
+        )
+    }
+
+    pub fn contract_transactions(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
     ) -> impl Stream<Item = StorageResult<(TxPointer, Transaction)>> + '_ {
-        self.owned_transactions_ids(owner, start, direction)
-            .chunks(self.batch_size)
-            .map(|chunk| {
+        self.transactions_by_pointers(self.contract_transactions_ids(
+            contract_id,
+            start,
+            direction,
+        ))
+    }
+
+    /// Returns the proof that the transaction is included in its block.
+    /// The tree of the block's transactions is taken from the `trees` cache,
+    /// or is built from the transactions of the block and cached.
+    pub async fn transaction_inclusion_proof(
+        &self,
+        tx_id: &TxId,
+        trees: &TransactionTreesCache,
+    ) -> StorageResult<TransactionInclusionProof> {
+        let not_included = || {
+            StorageError::from(anyhow::anyhow!(
+                "The transaction {tx_id} is not included in any block"
+            ))
+        };
+        let status = match self.tx_status(tx_id) {
+            Ok(status) => status,
+            Err(StorageError::NotFound(_, _)) => return Err(not_included()),
+            Err(err) => return Err(err),
+        };
+        let block_height = match status {
+            TransactionExecutionStatus::Success { block_height, .. }
+            | TransactionExecutionStatus::Failed { block_height, .. } => block_height,
+            TransactionExecutionStatus::Submitted { .. }
+            | TransactionExecutionStatus::SqueezedOut { .. }
+            | TransactionExecutionStatus::Rejected { .. } => return Err(not_included()),
+        };
+
+        let block = self.block(&block_height)?;
+        let block_header = block.header().clone();
+        let block_id = block_header.id();
+        let index = block
+            .transactions()
+            .iter()
+            .position(|id| id == tx_id)
+            .ok_or_else(not_included)?;
+
+        let tree = match trees.get(&block_id) {
+            Some(tree) => tree,
+            None => {
+                let transactions: Vec<_> = self
+                    .transactions(block.transactions().to_vec())
+                    .await
+                    .into_iter()
+                    .try_collect()?;
+                let tree = Arc::new(TransactionTree::new(&transactions));
+                if tree.root() != block_header.transactions_root() {
+                    return Err(anyhow::anyhow!(
+                        "The transactions of the block {block_height} don't match \
+                        its transactions root"
+                    )
+                    .into())
+                }
+                trees.insert(block_id, tree.clone());
+                tree
+            }
+        };
+
+        let proof_index = index as u64;
+        let proof_set = tree.prove(proof_index).ok_or_else(|| {
+            StorageError::from(anyhow::anyhow!(
+                "Unable to generate the Merkle proof for the transaction {tx_id}"
+            ))
+        })?;
+
+        Ok(TransactionInclusionProof {
+            proof: MerkleProof {
+                proof_set,
+                proof_index,
+            },
+            block_header,
+        })
+    }
+
+    /// Loads the transactions of the `ids` in batches, keeping their pointers.
+    fn transactions_by_pointers<'a>(
+        &'a self,
+        ids: impl Stream<Item = StorageResult<(TxPointer, TxId)>> + 'a,
+    ) -> impl Stream<Item = StorageResult<(TxPointer, Transaction)>> + 'a {
+        ids.chunks(self.batch_size)
+            .then(move |chunk| async move {
                 use itertools::Itertools;
 
-                let chunk = chunk.into_iter().try_collect::<_, Vec<_>, _>()?;
-                Ok::<_, StorageError>(chunk)
-            })
-            .try_filter_map(move |chunk| async move {
+                // The transactions read before the error are still yielded,
+                // so the exceeded scan budget doesn't drop them.
+                let (chunk, errors): (Vec<_>, Vec<StorageError>) =
+                    chunk.into_iter().partition_result();
                 let tx_ids = chunk.iter().map(|(_, tx_id)| *tx_id).collect::<Vec<_>>();
                 let txs = self.transactions(tx_ids).await;
                 let txs = txs
                     .into_iter()
                     .zip(chunk)
-                    .map(|(result, (tx_pointer, _))| result.map(|tx| (tx_pointer, tx)));
-                Ok(Some(futures::stream::iter(txs)))
+                    .map(|(result, (tx_pointer, _))| result.map(|tx| (tx_pointer, tx)))
+                    .chain(errors.into_iter().map(Err));
+                futures::stream::iter(txs)
             })
-            .try_flatten()
+            .flatten()
     }
 }
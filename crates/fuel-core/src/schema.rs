@@ -13,6 +13,7 @@ use async_graphql::{
     },
     parser::types::OperationType,
     Context,
+    ErrorExtensions,
     MergedObject,
     MergedSubscription,
     OutputType,
@@ -21,6 +22,7 @@ use async_graphql::{
 };
 use fuel_core_storage::{
     iter::IterDirection,
+    Error as StorageError,
     Result as StorageResult,
 };
 use futures::{
@@ -30,6 +32,7 @@ use futures::{
 use std::borrow::Cow;
 use tokio_stream::StreamExt;
 
+pub mod account;
 pub mod assets;
 pub mod balance;
 pub mod blob;
@@ -42,6 +45,7 @@ pub mod dap;
 pub mod health;
 pub mod message;
 pub mod node_info;
+pub mod old;
 pub mod upgrades;
 
 pub mod gas_price;
@@ -49,10 +53,15 @@ pub mod scalars;
 pub mod tx;
 
 pub mod relayed_tx;
+mod response_size;
+pub mod snapshot;
 pub mod storage;
 
+use response_size::ByteBudget;
+
 #[derive(MergedObject, Default)]
 pub struct Query(
+    account::AccountQuery,
     assets::AssetInfoQuery,
     dap::DapQuery,
     balance::BalanceQuery,
@@ -69,16 +78,29 @@ pub struct Query(
     gas_price::LatestGasPriceQuery,
     gas_price::EstimateGasPriceQuery,
     message::MessageQuery,
+    old::OldChainQuery,
     relayed_tx::RelayedTransactionQuery,
     upgrades::UpgradeQuery,
     storage::StorageQuery,
+    snapshot::SnapshotViewQuery,
 );
 
 #[derive(MergedObject, Default)]
-pub struct Mutation(dap::DapMutation, tx::TxMutation, block::BlockMutation);
+pub struct Mutation(
+    dap::DapMutation,
+    tx::TxMutation,
+    block::BlockMutation,
+    account::AccountMutation,
+);
 
 #[derive(MergedSubscription, Default)]
-pub struct Subscription(tx::TxStatusSubscription, storage::StorageSubscription);
+pub struct Subscription(
+    tx::TxStatusSubscription,
+    storage::StorageSubscription,
+    coins::CoinSubscription,
+    block::BlockSubscription,
+    balance::BalanceSubscription,
+);
 
 pub type CoreSchema = Schema<Query, Mutation, Subscription>;
 pub type CoreSchemaBuilder = SchemaBuilder<Query, Mutation, Subscription>;
@@ -99,6 +121,27 @@ async fn query_pagination<F, Entries, SchemaKey, SchemaValue>(
     last: Option<i32>,
     entries: F,
 ) -> async_graphql::Result<Connection<SchemaKey, SchemaValue, EmptyFields, EmptyFields>>
+where
+    SchemaKey: CursorType + Send + Sync,
+    <SchemaKey as CursorType>::Error: core::fmt::Display + Send + Sync + 'static,
+    SchemaValue: OutputType,
+    F: FnOnce(&Option<SchemaKey>, IterDirection) -> StorageResult<Entries>,
+    Entries: Stream<Item = StorageResult<(SchemaKey, SchemaValue)>>,
+    SchemaKey: Eq,
+{
+    query_pagination_within_budget(after, before, first, last, None, entries).await
+}
+
+/// The same as [`query_pagination`], but the page also ends once the estimated size
+/// of its entries exceeds the `byte_budget`. The page has at least one entry.
+async fn query_pagination_within_budget<F, Entries, SchemaKey, SchemaValue>(
+    after: Option<String>,
+    before: Option<String>,
+    first: Option<i32>,
+    last: Option<i32>,
+    mut byte_budget: Option<ByteBudget<SchemaValue>>,
+    entries: F,
+) -> async_graphql::Result<Connection<SchemaKey, SchemaValue, EmptyFields, EmptyFields>>
 where
     SchemaKey: CursorType + Send + Sync,
     <SchemaKey as CursorType>::Error: core::fmt::Display + Send + Sync + 'static,
@@ -146,7 +189,7 @@ where
             } else if let Some(last) = last {
                 (last, IterDirection::Reverse)
             } else {
-                return Err(anyhow!("Either `first` or `last` should be provided"))
+                return Err(anyhow!("Either `first` or `last` should be provided").into())
             };
 
             let start;
@@ -181,9 +224,14 @@ where
                 false
             });
 
+            let mut scan_budget_exceeded = None;
             let mut count = count.saturating_add(1) /* for `has_next_page` */;
             let entries = entries.take(count).take_while(|result| {
-                if let Ok((key, _)) = result {
+                if let Err(StorageError::ScanBudgetExceeded { limit, cursor }) = result {
+                    scan_budget_exceeded = Some((*limit, cursor.clone()));
+                }
+
+                if let Ok((key, value)) = result {
                     if let Some(end) = end.as_ref() {
                         // take until we've reached the end
                         if key == end {
@@ -191,6 +239,12 @@ where
                             return false
                         }
                     }
+                    if let Some(byte_budget) = byte_budget.as_mut() {
+                        if !byte_budget.charge(value) {
+                            has_next_page = true;
+                            return false
+                        }
+                    }
                     count = count.saturating_sub(1);
                     has_next_page |= count == 0;
                     count != 0
@@ -201,6 +255,16 @@ where
             });
 
             let entries: Vec<_> = entries.try_collect().await?;
+
+            if let Some((limit, cursor)) = scan_budget_exceeded {
+                // Return the entries found within the budget, so the next page
+                // continues from them. Otherwise, the query can be retried
+                // from the last examined entry.
+                if entries.is_empty() {
+                    return Err(scan_budget_exceeded_error(limit, cursor))
+                }
+                has_next_page = true;
+            }
             let entries = entries.into_iter();
 
             let mut connection = Connection::new(has_previous_page, has_next_page);
@@ -211,15 +275,83 @@ where
                     .map(|(key, value)| Edge::new(key, value)),
             );
 
-            Ok::<Connection<SchemaKey, SchemaValue>, anyhow::Error>(connection)
+            Ok::<Connection<SchemaKey, SchemaValue>, async_graphql::Error>(connection)
         },
     )
     .await
 }
 
+/// Rejects the page of the `resolver` requesting more than `limit` entries
+/// with `first` or `last`.
+fn ensure_page_size(
+    resolver: &str,
+    first: Option<i32>,
+    last: Option<i32>,
+    limit: usize,
+) -> async_graphql::Result<()> {
+    let requested = first
+        .into_iter()
+        .chain(last)
+        .map(|requested| usize::try_from(requested).unwrap_or_default())
+        .max()
+        .unwrap_or_default();
+    if requested > limit {
+        return Err(page_size_exceeded_error(resolver, requested, limit))
+    }
+    Ok(())
+}
+
+/// The error with the applied `limit`, so the client can request smaller pages.
+fn page_size_exceeded_error(
+    resolver: &str,
+    requested: usize,
+    limit: usize,
+) -> async_graphql::Error {
+    async_graphql::Error::new(format!(
+        "The page of {requested} entries is above the limit of {limit} entries \
+        of the `{resolver}` query"
+    ))
+    .extend_with(|_, extensions| {
+        extensions.set("code", "PAGE_SIZE_EXCEEDED");
+        extensions.set("limit", limit);
+    })
+}
+
+/// The retriable error with the `cursor` of the last examined entry,
+/// from which the query can be continued.
+fn scan_budget_exceeded_error(
+    limit: usize,
+    cursor: Option<String>,
+) -> async_graphql::Error {
+    async_graphql::Error::new(format!(
+        "The scan budget of {limit} entries is exceeded before any entry is found"
+    ))
+    .extend_with(|_, extensions| {
+        extensions.set("code", "SCAN_BUDGET_EXCEEDED");
+        extensions.set("retriable", true);
+        if let Some(cursor) = cursor {
+            extensions.set("cursor", cursor);
+        }
+    })
+}
+
 pub trait ReadViewProvider {
     /// Returns the read view for the current operation.
     fn read_view(&self) -> StorageResult<Cow<ReadView>>;
+
+    /// Returns the `pinned` view the object was read from, if any, or the read view
+    /// for the current operation. The mutations and subscriptions create a new view
+    /// per call of [`ReadViewProvider::read_view`], so the objects read by them pin
+    /// their view to read all their fields from one snapshot.
+    fn pinned_view<'b>(
+        &'b self,
+        pinned: Option<&'b ReadView>,
+    ) -> StorageResult<Cow<'b, ReadView>> {
+        match pinned {
+            Some(view) => Ok(Cow::Borrowed(view)),
+            None => self.read_view(),
+        }
+    }
 }
 
 impl<'a> ReadViewProvider for Context<'a> {
@@ -0,0 +1,373 @@
+use anyhow::anyhow;
+use async_graphql::{
+    connection::{
+        Connection,
+        EmptyFields,
+    },
+    Context,
+    Enum,
+    Object,
+    SimpleObject,
+    Union,
+};
+use fuel_core_types::fuel_tx::{
+    self,
+    UniqueIdentifier,
+};
+use futures::StreamExt;
+
+use crate::{
+    fuel_core_graphql_api::{
+        api_service::{
+            ChainInfoProvider,
+            OwnerIndexes,
+            ReadDatabase,
+        },
+        query_costs,
+        Config as GraphQLConfig,
+    },
+    graphql_api::{
+        database::ReadView,
+        ports,
+        storage::{
+            known_owners::KnownOwner,
+            owner_activity::OwnerActivity,
+        },
+    },
+    query::owner_activity::{
+        self,
+        OwnerActivityPosition,
+    },
+    schema::{
+        coins::Coin,
+        dap::require_debug,
+        message::Message,
+        scalars::{
+            Address,
+            OwnerActivityCursor,
+            UtxoId,
+            U32,
+            U64,
+        },
+        tx::types::Transaction,
+        ReadViewProvider,
+    },
+};
+
+#[derive(Default)]
+pub struct AccountQuery;
+
+#[Object]
+impl AccountQuery {
+    /// Returns the summary of the activity of the `owner` on the chain,
+    /// or `null` if the `owner` never appeared in transactions or coins.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn account_summary(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Address of the owner")] owner: Address,
+    ) -> async_graphql::Result<Option<AccountSummary>> {
+        let query = ctx.read_view()?;
+        let owner = owner.into();
+        let summary = query
+            .owner_activity(&owner)?
+            .map(|activity| AccountSummary { owner, activity });
+        Ok(summary)
+    }
+
+    /// Returns whether the `address` is known to be a predicate. The owner is
+    /// classified only after it spends a coin or a message for the first time,
+    /// so an `UNKNOWN` owner can still be a predicate or an account.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn is_known_predicate(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Address of the owner")] address: Address,
+    ) -> async_graphql::Result<OwnerKind> {
+        let query = ctx.read_view()?;
+        Ok(OwnerKind::of(query.as_ref(), &address.into())?)
+    }
+
+    /// Returns the height of the last block where the `owner` appeared
+    /// in a transaction or received a coin or a message, or `null`
+    /// if the `owner` was never active.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn owner_last_activity(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Address of the owner")] owner: Address,
+    ) -> async_graphql::Result<Option<U32>> {
+        let query = ctx.read_view()?;
+        let height = query.owner_last_activity(&owner.into())?;
+        Ok(height.map(Into::into))
+    }
+
+    /// Returns the coins, messages, and transactions of the `owner` as one feed.
+    /// The entries are merged by the block and the transaction that produced them.
+    /// Messages don't have a position on the chain, so they precede other entries.
+    /// The cursor keeps the position in each of the owner's indexes, so the next page
+    /// continues all of them.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator\
+        + (query_costs().storage_read + first.unwrap_or_default() as usize) * child_complexity \
+    }")]
+    async fn owner_activity(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Address of the owner")] owner: Address,
+        first: Option<i32>,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<OwnerActivityCursor, OwnerActivityItem, EmptyFields, EmptyFields>,
+    > {
+        let max_page_size = ctx
+            .data_unchecked::<GraphQLConfig>()
+            .config
+            .max_page_sizes
+            .owner_activity;
+        crate::schema::ensure_page_size("ownerActivity", first, None, max_page_size)?;
+        let query = ctx.read_view()?;
+        let chain_id = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params()
+            .chain_id();
+        let owner = fuel_tx::Address::from(owner);
+
+        crate::schema::query_pagination(
+            after,
+            None,
+            first,
+            None,
+            |start: &Option<OwnerActivityCursor>, _| {
+                let after = start.map(Into::into).unwrap_or_default();
+                let feed = query.owner_activity_feed(&owner, after).map(move |result| {
+                    result.map(|(position, item)| {
+                        let item = match item {
+                            owner_activity::OwnerActivityItem::Coin(coin) => {
+                                OwnerActivityItem::Coin(coin.into())
+                            }
+                            owner_activity::OwnerActivityItem::Message(message) => {
+                                OwnerActivityItem::Message(message.into())
+                            }
+                            owner_activity::OwnerActivityItem::Transaction(_, tx) => {
+                                let tx_id = tx.id(&chain_id);
+                                OwnerActivityItem::Transaction(Transaction::from_tx(
+                                    tx_id, tx,
+                                ))
+                            }
+                        };
+                        (position.into(), item)
+                    })
+                });
+                Ok(feed)
+            },
+        )
+        .await
+    }
+}
+
+#[derive(Default)]
+pub struct AccountMutation;
+
+#[Object]
+impl AccountMutation {
+    /// Removes the owned coins, messages, transactions, and the counters of the `owner`
+    /// from the off-chain indexes in one transaction. The on-chain state is untouched,
+    /// so re-indexing the chain restores the removed entries. Requires the `debug` mode
+    /// and the owner pruning to be enabled, and the `confirm` to repeat the `owner`.
+    async fn prune_owner(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Address of the owner")] owner: Address,
+        #[graphql(desc = "The same address as the `owner`, to confirm the pruning")]
+        confirm: Address,
+    ) -> async_graphql::Result<PrunedOwnerIndexes> {
+        require_debug(ctx)?;
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        if !config.config.allow_owner_pruning {
+            return Err(
+                anyhow!("The owner pruning must be enabled to use this endpoint").into(),
+            )
+        }
+        if owner != confirm {
+            return Err(anyhow!("The `confirm` address doesn't match the `owner`").into())
+        }
+
+        let owner: fuel_tx::Address = owner.into();
+        let pruned = ctx.data_unchecked::<OwnerIndexes>().prune_owner(&owner)?;
+        tracing::warn!(%owner, ?pruned, "Pruned the off-chain indexes of the owner");
+        Ok(pruned.into())
+    }
+
+    /// Removes at most `limit` entries of the owned coins index of the `owner` that
+    /// point to the coins missing in the on-chain state, as found by the
+    /// `findOrphanedOwnedCoins`, and returns their ids. The entries are removed only
+    /// if no block was indexed since they were found. Requires the `debug` mode
+    /// to be enabled.
+    async fn repair_orphaned_owned_coins(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Address of the owner")] owner: Address,
+        #[graphql(desc = "\
+            The maximum number of the removed coins, up to the page size limit \
+            of the `coins` query. The limit is used if it is not specified.")]
+        limit: Option<U32>,
+    ) -> async_graphql::Result<RepairedOrphanedOwnedCoins> {
+        require_debug(ctx)?;
+        let max_page_size = ctx
+            .data_unchecked::<GraphQLConfig>()
+            .config
+            .max_page_sizes
+            .coins;
+        let requested = limit.map(|limit| i32::try_from(limit.0).unwrap_or(i32::MAX));
+        crate::schema::ensure_page_size(
+            "repairOrphanedOwnedCoins",
+            requested,
+            None,
+            max_page_size,
+        )?;
+        let limit = limit.map_or(Ok(max_page_size), |limit| usize::try_from(limit.0))?;
+
+        let database: &ReadDatabase = ctx.data_unchecked();
+        let Some((read_view, height)) = database.consistent_view()? else {
+            return Err(anyhow!(
+                "The off-chain database is behind the latest block, try again later"
+            )
+            .into())
+        };
+        let owner: fuel_tx::Address = owner.into();
+        // One more coin is read to know if any orphaned coin remains after the repair.
+        let mut orphaned =
+            read_view.orphaned_owned_coins(&owner, limit.saturating_add(1))?;
+        let has_more = orphaned.len() > limit;
+        orphaned.truncate(limit);
+
+        if !orphaned.is_empty() {
+            let removed = ctx
+                .data_unchecked::<OwnerIndexes>()
+                .remove_owned_coins(&owner, &orphaned, height)?;
+            tracing::warn!(%owner, removed, "Removed the orphaned owned coins of the owner");
+        }
+        Ok(RepairedOrphanedOwnedCoins {
+            removed: orphaned.into_iter().map(Into::into).collect(),
+            has_more,
+        })
+    }
+}
+
+/// The orphaned coins removed from the owned coins index of the owner.
+#[derive(SimpleObject)]
+pub struct RepairedOrphanedOwnedCoins {
+    /// The ids of the removed coins.
+    pub removed: Vec<UtxoId>,
+    /// `true` if more orphaned coins of the owner remain in the index.
+    pub has_more: bool,
+}
+
+/// The number of entries removed from each off-chain index of the owner.
+#[derive(SimpleObject)]
+pub struct PrunedOwnerIndexes {
+    pub owned_coins: U64,
+    pub owned_message_ids: U64,
+    pub owned_transactions: U64,
+    /// The entries counting the coins of the owner per asset.
+    pub owned_coin_counts: U64,
+    /// The activity summary of the owner, `1` if it existed.
+    pub owner_activities: U64,
+}
+
+impl From<ports::PrunedOwnerIndexes> for PrunedOwnerIndexes {
+    fn from(pruned: ports::PrunedOwnerIndexes) -> Self {
+        Self {
+            owned_coins: pruned.owned_coins.into(),
+            owned_message_ids: pruned.owned_message_ids.into(),
+            owned_transactions: pruned.owned_transactions.into(),
+            owned_coin_counts: pruned.owned_coin_counts.into(),
+            owner_activities: pruned.owner_activities.into(),
+        }
+    }
+}
+
+#[derive(Union)]
+pub enum OwnerActivityItem {
+    Coin(Coin),
+    Message(Message),
+    Transaction(Transaction),
+}
+
+impl From<OwnerActivityPosition> for OwnerActivityCursor {
+    fn from(position: OwnerActivityPosition) -> Self {
+        Self {
+            coin: position.coin.map(Into::into),
+            message: position.message.map(Into::into),
+            transaction: position.transaction.map(Into::into),
+        }
+    }
+}
+
+impl From<OwnerActivityCursor> for OwnerActivityPosition {
+    fn from(cursor: OwnerActivityCursor) -> Self {
+        Self {
+            coin: cursor.coin.map(Into::into),
+            message: cursor.message.map(Into::into),
+            transaction: cursor.transaction.map(Into::into),
+        }
+    }
+}
+
+pub struct AccountSummary {
+    owner: fuel_core_types::fuel_tx::Address,
+    activity: OwnerActivity,
+}
+
+#[Object]
+impl AccountSummary {
+    async fn owner(&self) -> Address {
+        self.owner.into()
+    }
+
+    /// The first block where the owner appeared in a transaction or received a coin.
+    async fn first_seen_block(&self) -> U32 {
+        self.activity.first_seen_block.into()
+    }
+
+    /// The last block where the owner appeared in a transaction or its coins changed.
+    async fn last_active_block(&self) -> U32 {
+        self.activity.last_active_block.into()
+    }
+
+    /// The number of transactions that have the owner in inputs or outputs.
+    async fn total_transactions(&self) -> U64 {
+        self.activity.total_transactions.into()
+    }
+
+    /// The number of unspent coins owned by the owner.
+    async fn coin_count(&self) -> U64 {
+        self.activity.coin_count.into()
+    }
+}
+
+/// The kind of the owner, known only after it spends for the first time.
+#[derive(Enum, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum OwnerKind {
+    /// The owner never spent a coin or a message, so its kind is unknown.
+    Unknown,
+    /// The owner spent with a predicate.
+    Predicate,
+    /// The owner spent with a signature.
+    Account,
+}
+
+impl OwnerKind {
+    pub(crate) fn of(
+        query: &ReadView,
+        owner: &fuel_core_types::fuel_tx::Address,
+    ) -> fuel_core_storage::Result<Self> {
+        let kind = match query.known_owner(owner)? {
+            Some(KnownOwner::Predicate { .. }) => OwnerKind::Predicate,
+            Some(KnownOwner::Account) => OwnerKind::Account,
+            None => OwnerKind::Unknown,
+        };
+        Ok(kind)
+    }
+}
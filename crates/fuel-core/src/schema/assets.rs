@@ -1,17 +1,33 @@
 use async_graphql::{
+    connection::{
+        Connection,
+        EmptyFields,
+    },
     Context,
     Object,
 };
+use fuel_core_types::fuel_tx;
+use futures::StreamExt;
 
 use crate::{
-    fuel_core_graphql_api::query_costs,
-    graphql_api::storage::assets::AssetDetails,
+    fuel_core_graphql_api::{
+        api_service::ChainInfoProvider,
+        query_costs,
+    },
+    graphql_api::storage::assets::{
+        AssetDetails,
+        AssetFirstSeenInfo,
+    },
+    query::assets::AssetSupplyChange as SupplyChange,
     schema::{
         scalars::{
             AssetId,
             ContractId,
             SubId,
+            Tai64Timestamp,
+            I128,
             U128,
+            U32,
         },
         ReadViewProvider,
     },
@@ -22,6 +38,46 @@ pub struct AssetInfoQuery;
 
 #[Object]
 impl AssetInfoQuery {
+    /// Returns the assets minted on the chain in the order of asset ids.
+    /// The base asset is not minted, so it is listed only with `include_base_asset`,
+    /// before all minted assets and without the details.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator\
+        + (query_costs().storage_read + first.unwrap_or_default() as usize) * child_complexity \
+    }")]
+    async fn assets(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        #[graphql(desc = "Lists the base asset before the minted assets.")]
+        include_base_asset: Option<bool>,
+    ) -> async_graphql::Result<Connection<AssetId, ChainAsset, EmptyFields, EmptyFields>>
+    {
+        let query = ctx.read_view()?;
+        let base_asset_id = include_base_asset.unwrap_or(false).then(|| {
+            *ctx.data_unchecked::<ChainInfoProvider>()
+                .current_consensus_params()
+                .base_asset_id()
+        });
+
+        crate::schema::query_pagination(
+            after,
+            None,
+            first,
+            None,
+            |start: &Option<AssetId>, _| {
+                let assets = query.assets((*start).map(Into::into), base_asset_id)?;
+                Ok(assets.map(|result| {
+                    result.map(|(asset_id, details)| {
+                        (asset_id.into(), ChainAsset { asset_id, details })
+                    })
+                }))
+            },
+        )
+        .await
+    }
+
     #[graphql(complexity = "query_costs().storage_read")]
     async fn asset_details(
         &self,
@@ -34,6 +90,54 @@ impl AssetInfoQuery {
             .map(|details| details.into())
             .map_err(async_graphql::Error::from)
     }
+
+    /// Returns the block where the asset was seen for the first time, either minted
+    /// or received as a coin, or `null` if the asset was never seen.
+    #[graphql(complexity = "query_costs().storage_read + child_complexity")]
+    async fn asset_first_seen(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "ID of the Asset")] asset_id: AssetId,
+    ) -> async_graphql::Result<Option<AssetFirstSeen>> {
+        let query = ctx.read_view()?;
+        Ok(query
+            .asset_first_seen(&asset_id.into())?
+            .map(AssetFirstSeen))
+    }
+
+    /// Returns the changes of the total supply of the asset per block in the order
+    /// of heights. Mints and burns of the asset within one block are coalesced
+    /// into one change.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator\
+        + (query_costs().storage_read + first.unwrap_or_default() as usize) * child_complexity \
+    }")]
+    async fn asset_supply_history(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "ID of the Asset")] asset_id: AssetId,
+        first: Option<i32>,
+        after: Option<String>,
+    ) -> async_graphql::Result<Connection<U32, AssetSupplyChange, EmptyFields, EmptyFields>>
+    {
+        let query = ctx.read_view()?;
+        let asset_id = asset_id.into();
+
+        crate::schema::query_pagination(
+            after,
+            None,
+            first,
+            None,
+            |start: &Option<U32>, _| {
+                let history =
+                    query.asset_supply_history(asset_id, start.map(Into::into))?;
+                Ok(history.map(|result| {
+                    result.map(|change| (change.block_height.into(), change.into()))
+                }))
+            },
+        )
+        .await
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -67,3 +171,80 @@ impl AssetInfoDetails {
         &self.total_supply
     }
 }
+
+/// The asset listed by the `assets` query.
+#[derive(Clone, Debug)]
+pub struct ChainAsset {
+    asset_id: fuel_tx::AssetId,
+    /// `None` for the base asset.
+    details: Option<AssetDetails>,
+}
+
+#[Object]
+impl ChainAsset {
+    async fn asset_id(&self) -> AssetId {
+        self.asset_id.into()
+    }
+
+    /// The contract that minted the asset, or `null` for the base asset.
+    async fn contract_id(&self) -> Option<ContractId> {
+        self.details
+            .as_ref()
+            .map(|details| details.contract_id.into())
+    }
+
+    /// The sub id of the asset within the contract, or `null` for the base asset.
+    async fn sub_id(&self) -> Option<SubId> {
+        self.details.as_ref().map(|details| details.sub_id.into())
+    }
+
+    /// The total supply of the minted asset, or `null` for the base asset.
+    async fn total_supply(&self) -> Option<U128> {
+        self.details
+            .as_ref()
+            .map(|details| details.total_supply.into())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AssetSupplyChange(SupplyChange);
+
+impl From<SupplyChange> for AssetSupplyChange {
+    fn from(change: SupplyChange) -> Self {
+        AssetSupplyChange(change)
+    }
+}
+
+#[Object]
+impl AssetSupplyChange {
+    /// The height of the block that changed the supply.
+    async fn block_height(&self) -> U32 {
+        self.0.block_height.into()
+    }
+
+    /// The net change of the supply in the block.
+    async fn delta(&self) -> I128 {
+        self.0.delta.into()
+    }
+
+    /// The total supply of the asset after the block.
+    async fn total_supply(&self) -> U128 {
+        self.0.supply.into()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AssetFirstSeen(AssetFirstSeenInfo);
+
+#[Object]
+impl AssetFirstSeen {
+    /// The height of the block where the asset was seen for the first time.
+    async fn block_height(&self) -> U32 {
+        self.0.block_height.into()
+    }
+
+    /// The time of the block where the asset was seen for the first time.
+    async fn time(&self) -> Tai64Timestamp {
+        Tai64Timestamp(self.0.block_time)
+    }
+}
@@ -1,14 +1,27 @@
 use crate::{
     database::database_description::IndexationKind,
     fuel_core_graphql_api::{
-        api_service::ChainInfoProvider,
+        api_service::{
+            ChainInfoProvider,
+            ReadDatabase,
+        },
         query_costs,
+        Config as GraphQLConfig,
+    },
+    graphql_api::{
+        block_height_subscription::{
+            self,
+            BalancesUpdatedEvent,
+        },
+        resolver_metrics,
     },
     schema::{
         scalars::{
             Address,
             AssetId,
+            I128,
             U128,
+            U32,
         },
         ReadViewProvider,
     },
@@ -22,9 +35,18 @@ use async_graphql::{
     Context,
     InputObject,
     Object,
+    SimpleObject,
 };
 use fuel_core_types::services::graphql_api;
-use futures::StreamExt;
+use futures::{
+    Stream,
+    StreamExt,
+};
+use std::sync::Arc;
+use tokio::sync::{
+    OwnedSemaphorePermit,
+    Semaphore,
+};
 
 use super::scalars::U64;
 
@@ -80,6 +102,21 @@ impl BalanceQuery {
         Ok(balance)
     }
 
+    /// Returns the total value of the coins of the owner in the asset in one call.
+    /// Unlike the `balance`, the messages are not included. The value is read
+    /// from the same index as the `balance`, so both queries always agree.
+    #[graphql(complexity = "query_costs().balance_query")]
+    async fn owned_coin_value(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "address of the owner")] owner: Address,
+        #[graphql(desc = "asset_id of the coin")] asset_id: AssetId,
+    ) -> async_graphql::Result<U128> {
+        let query = ctx.read_view()?;
+        let value = query.owned_coin_value(owner.0, asset_id.0).await?;
+        Ok(value.into())
+    }
+
     // TODO: https://github.com/FuelLabs/fuel-core/issues/2496
     // This is the complexity we want to use with "balances()" query, but it's not
     // currently possible, because we need to handle queries with ~10k items.
@@ -113,7 +150,14 @@ impl BalanceQuery {
         before: Option<String>,
     ) -> async_graphql::Result<Connection<AssetId, Balance, EmptyFields, EmptyFields>>
     {
+        let max_page_size = ctx
+            .data_unchecked::<GraphQLConfig>()
+            .config
+            .max_page_sizes
+            .balances;
+        crate::schema::ensure_page_size("balances", first, last, max_page_size)?;
         let query = ctx.read_view()?;
+        query.ensure_indexation_ready(IndexationKind::Balances)?;
         if !query.indexation_flags.contains(&IndexationKind::Balances)
             && (before.is_some() || after.is_some())
         {
@@ -127,14 +171,48 @@ impl BalanceQuery {
             .current_consensus_params()
             .base_asset_id();
         let owner = filter.owner.into();
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            Ok(query
-                .balances(&owner, (*start).map(Into::into), direction, &base_asset_id)
-                .map(|result| {
-                    result.map(|balance| (balance.asset_id.into(), balance.into()))
-                }))
-        })
-        .await
+        let balances = crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start, direction| {
+                Ok(query
+                    .balances(&owner, (*start).map(Into::into), direction, &base_asset_id)
+                    .map(|result| {
+                        result.map(|balance| (balance.asset_id.into(), balance.into()))
+                    }))
+            },
+        );
+        resolver_metrics::instrument("balances", balances).await
+    }
+
+    /// Returns the net change of the balance of the owner from the block at
+    /// `from_height` to the block at `to_height`. The balance before the first
+    /// activity of the owner is zero. Requires the balances indexation and
+    /// the history of the off-chain database at both heights.
+    #[graphql(complexity = "2 * query_costs().storage_read")]
+    async fn balance_delta(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "address of the owner")] owner: Address,
+        #[graphql(desc = "asset_id of the coin")] asset_id: AssetId,
+        #[graphql(desc = "the height of the block before the change")] from_height: U32,
+        #[graphql(desc = "the height of the block after the change")] to_height: U32,
+    ) -> async_graphql::Result<I128> {
+        let read_database: &ReadDatabase = ctx.data_unchecked();
+        let base_asset_id = *ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params()
+            .base_asset_id();
+        let delta = read_database.balance_delta(
+            &owner.0,
+            &asset_id.0,
+            &base_asset_id,
+            from_height.into(),
+            to_height.into(),
+        )?;
+        Ok(delta.into())
     }
 }
 
@@ -143,3 +221,121 @@ impl From<graphql_api::AddressBalance> for Balance {
         Balance(balance)
     }
 }
+
+/// The limit of the active `balanceUpdated` subscriptions.
+#[derive(Clone)]
+pub struct BalanceSubscriptions {
+    permits: Arc<Semaphore>,
+    max: usize,
+}
+
+impl BalanceSubscriptions {
+    pub fn new(max: usize) -> Self {
+        let max = max.min(Semaphore::MAX_PERMITS);
+        Self {
+            permits: Arc::new(Semaphore::new(max)),
+            max,
+        }
+    }
+
+    /// Reserves the place for the subscription until the permit is dropped.
+    fn acquire(&self) -> anyhow::Result<OwnedSemaphorePermit> {
+        self.permits.clone().try_acquire_owned().map_err(|_| {
+            anyhow!(
+                "The limit of {} active balance subscriptions is reached",
+                self.max
+            )
+        })
+    }
+}
+
+/// The balance of the owner after the block that modified it.
+#[derive(SimpleObject)]
+pub struct BalanceUpdate {
+    owner: Address,
+    asset_id: AssetId,
+    /// The balance after the block.
+    amount: U128,
+    /// The height of the block that modified the balance.
+    block_height: U32,
+}
+
+#[derive(Default)]
+pub struct BalanceSubscription;
+
+#[async_graphql::Subscription]
+impl BalanceSubscription {
+    /// Returns a stream of the balances of the `owner` in the `asset_id` after each
+    /// block indexed by the node that modifies it. All modifications of the balance
+    /// within one block are reported as one update. The balance is read after
+    /// the block is indexed, so it matches the answer of the `balance` query.
+    /// Requires the balances indexation.
+    ///
+    /// The number of the active subscriptions is limited by the node. If the stream
+    /// is polled slower than the blocks are indexed, it returns the error about
+    /// the slow subscriber and closes.
+    async fn balance_updated<'a>(
+        &self,
+        ctx: &Context<'a>,
+        #[graphql(desc = "address of the owner")] owner: Address,
+        #[graphql(desc = "asset_id of the coin")] asset_id: AssetId,
+    ) -> async_graphql::Result<
+        impl Stream<Item = async_graphql::Result<BalanceUpdate>> + 'a,
+    > {
+        let read_database: &ReadDatabase = ctx.data_unchecked();
+        let read_view = read_database.view()?;
+        if !read_view
+            .indexation_flags
+            .contains(&IndexationKind::Balances)
+        {
+            return Err(anyhow!(
+                "The balance subscription requires the balances indexation"
+            )
+            .into())
+        }
+        let permit = ctx.data_unchecked::<BalanceSubscriptions>().acquire()?;
+        let subscriber = ctx.data_unchecked::<block_height_subscription::Subscriber>();
+        let base_asset_id = *ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params()
+            .base_asset_id();
+        let key = (owner.0, asset_id.0);
+
+        // The permit lives in the state of the stream and
+        // is released when the subscription is dropped.
+        let stream = subscriber
+            .balances_updated_events()
+            .scan((permit, false), move |(_, closed), event| {
+                let next = match event {
+                    _ if *closed => None,
+                    BalancesUpdatedEvent::Updated(update) => {
+                        Some(update.balances.contains(&key).then_some(Ok(update.height)))
+                    }
+                    BalancesUpdatedEvent::Gap { missed_blocks } => {
+                        *closed = true;
+                        Some(Some(Err(anyhow!(
+                            "{}: the updates of {missed_blocks} blocks were dropped",
+                            super::block::SLOW_SUBSCRIBER_MESSAGE
+                        ))))
+                    }
+                };
+                futures::future::ready(next)
+            })
+            .filter_map(futures::future::ready)
+            .then(move |height| async move {
+                let height = height?;
+                let read_view = read_database.view()?;
+                let balance = read_view
+                    .balance(owner.0, asset_id.0, base_asset_id)
+                    .await?;
+                Ok(BalanceUpdate {
+                    owner,
+                    asset_id,
+                    amount: balance.amount.into(),
+                    block_height: height.into(),
+                })
+            });
+
+        Ok(stream)
+    }
+}
@@ -5,22 +5,36 @@ use super::scalars::{
 };
 use crate::{
     fuel_core_graphql_api::{
-        api_service::ConsensusModule,
+        api_service::{
+            ConsensusModule,
+            ReadDatabase,
+        },
         block_height_subscription,
         database::ReadView,
         query_costs,
+        storage::{
+            blocks,
+            rollbacks,
+        },
         Config as GraphQLConfig,
         IntoApiResult,
     },
     schema::{
         scalars::{
+            Address,
             BlockId,
             Signature,
             U16,
             U32,
             U64,
         },
-        tx::types::Transaction,
+        tx::{
+            receipt::Receipt,
+            types::{
+                Transaction,
+                TransactionStatus,
+            },
+        },
         ReadViewProvider,
     },
 };
@@ -32,6 +46,7 @@ use async_graphql::{
     },
     Context,
     Enum,
+    InputObject,
     Object,
     SimpleObject,
     Union,
@@ -50,6 +65,7 @@ use fuel_core_types::{
         self,
         BlockHeight,
     },
+    services::txpool::TransactionExecutionStatus,
 };
 use futures::{
     Stream,
@@ -57,7 +73,9 @@ use futures::{
     TryStreamExt,
 };
 
-pub struct Block(pub(crate) CompressedBlock);
+/// The block with the view it was read from, if the view is pinned,
+/// see [`ReadViewProvider::pinned_view`].
+pub struct Block(pub(crate) CompressedBlock, pub(crate) Option<ReadView>);
 
 pub struct Header(pub(crate) BlockHeader);
 
@@ -90,6 +108,13 @@ pub struct PoAConsensus {
     signature: Signature,
 }
 
+/// The header of the block with the consensus data that seals it.
+#[derive(SimpleObject)]
+pub struct SignedHeader {
+    pub header: Header,
+    pub consensus: Consensus,
+}
+
 #[derive(Clone, Copy, Debug, Enum, Eq, PartialEq)]
 pub enum BlockVersion {
     V1,
@@ -119,7 +144,7 @@ impl Block {
 
     #[graphql(complexity = "query_costs().storage_read + child_complexity")]
     async fn consensus(&self, ctx: &Context<'_>) -> async_graphql::Result<Consensus> {
-        let query = ctx.read_view()?;
+        let query = ctx.pinned_view(self.1.as_ref())?;
         let height = self.0.header().height();
         Ok(query.consensus(height)?.try_into()?)
     }
@@ -139,7 +164,7 @@ impl Block {
         &self,
         ctx: &Context<'_>,
     ) -> async_graphql::Result<Vec<Transaction>> {
-        let query = ctx.read_view()?;
+        let query = ctx.pinned_view(self.1.as_ref())?;
         let tx_ids = futures::stream::iter(self.0.transactions().iter().copied());
 
         let result = tx_ids
@@ -148,10 +173,12 @@ impl Block {
                 let async_query = query.as_ref().clone();
                 async move {
                     let txs = async_query.transactions(tx_ids.clone()).await;
-                    let txs = txs
-                        .into_iter()
-                        .zip(tx_ids.into_iter())
-                        .map(|(r, tx_id)| r.map(|tx| Transaction::from_tx(tx_id, tx)));
+                    let txs =
+                        txs.into_iter().zip(tx_ids.into_iter()).map(|(r, tx_id)| {
+                            r.map(|tx| {
+                                Transaction::from_tx(tx_id, tx).pinned(self.1.clone())
+                            })
+                        });
 
                     Some(futures::stream::iter(txs))
                 }
@@ -317,6 +344,145 @@ impl BlockQuery {
         })
         .await
     }
+
+    /// Returns the headers of the blocks produced by the `producer`, in the order
+    /// of their heights. The genesis block and the blocks whose producer can't be
+    /// recovered from the consensus are returned for the zero address.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator\
+        + (query_costs().block_header + child_complexity) \
+        * first.unwrap_or_default() as usize \
+    }")]
+    async fn blocks_by_producer(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "address of the block producer")] producer: Address,
+        first: Option<i32>,
+        after: Option<String>,
+    ) -> async_graphql::Result<Connection<U32, Header, EmptyFields, EmptyFields>> {
+        let query = ctx.read_view()?;
+        let producer = producer.0;
+        crate::schema::query_pagination(after, None, first, None, |start, direction| {
+            Ok(query
+                .blocks_by_producer(&producer, start.map(Into::into), direction)
+                .map(|result| {
+                    result.map(|header| ((*header.height()).into(), header.into()))
+                }))
+        })
+        .await
+    }
+
+    /// Returns the ids of the transactions of the block at the `height`
+    /// without fetching the transactions, or `null` if there is no such block.
+    #[graphql(complexity = "query_costs().block_header")]
+    async fn block_transaction_ids(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Height of the block")] height: U32,
+    ) -> async_graphql::Result<Option<Vec<TransactionId>>> {
+        let query = ctx.read_view()?;
+        let height: u32 = height.into();
+        query
+            .block_transaction_ids(&height.into())
+            .map(|ids| ids.into_iter().map(Into::into).collect::<Vec<_>>())
+            .into_api_result()
+    }
+
+    /// Returns the headers and the consensus of the blocks from the `start`
+    /// to the `end`(inclusive) without the transactions of the blocks.
+    /// The heights above the latest block are skipped.
+    #[graphql(complexity = "{\
+        (query_costs().block_header + query_costs().storage_read + child_complexity) \
+        * (end.0.saturating_sub(start.0) as usize + 1) \
+    }")]
+    async fn block_headers(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The height of the first block")] start: U32,
+        #[graphql(desc = "The height of the last block")] end: U32,
+    ) -> async_graphql::Result<Vec<SignedHeader>> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let query = ctx.read_view()?;
+        let headers = query.block_headers(
+            start.into(),
+            end.into(),
+            config.config.max_block_headers_per_request,
+        )?;
+
+        headers
+            .into_iter()
+            .map(|(header, consensus)| {
+                Ok(SignedHeader {
+                    header: header.into(),
+                    consensus: consensus.try_into()?,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the fees collected by the block at the `height`,
+    /// or `null` if there is no such block.
+    #[graphql(complexity = "query_costs().storage_read + child_complexity")]
+    async fn fees_at_height(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Height of the block")] height: U32,
+    ) -> async_graphql::Result<Option<CollectedFees>> {
+        let query = ctx.read_view()?;
+        let height: u32 = height.into();
+        query
+            .block_fees(&height.into())
+            .map(Into::into)
+            .into_api_result()
+    }
+
+    /// Returns the total fees collected by all blocks indexed by the node.
+    #[graphql(complexity = "query_costs().storage_read + child_complexity")]
+    async fn total_fees_collected(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<CollectedFees> {
+        let query = ctx.read_view()?;
+        Ok(query.total_fees()?.into())
+    }
+
+    /// Returns the last rollback of the node, or `null` if the node never rolled back.
+    /// It allows the clients disconnected during the rollback to detect it.
+    #[graphql(complexity = "query_costs().storage_read + child_complexity")]
+    async fn last_rollback(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<ChainRollback>> {
+        let query = ctx.read_view()?;
+        Ok(query.last_chain_rollback()?.map(Into::into))
+    }
+}
+
+/// The fees in the base asset collected by the block producers.
+pub struct CollectedFees(blocks::CollectedFees);
+
+#[Object]
+impl CollectedFees {
+    /// The fee paid for the gas, without the tips.
+    async fn base_fee(&self) -> U64 {
+        self.0.base_fee.into()
+    }
+
+    /// The tips paid by the transactions on top of the base fee.
+    async fn tip(&self) -> U64 {
+        self.0.tip.into()
+    }
+
+    /// The sum of the base fee and the tips.
+    async fn total(&self) -> U64 {
+        self.0.total().into()
+    }
+}
+
+impl From<blocks::CollectedFees> for CollectedFees {
+    fn from(fees: blocks::CollectedFees) -> Self {
+        Self(fees)
+    }
 }
 
 #[derive(Default)]
@@ -414,9 +580,259 @@ impl BlockMutation {
     }
 }
 
+/// The block committed into the off-chain database.
+#[derive(SimpleObject)]
+pub struct BlockIndexed {
+    pub height: U32,
+    pub tx_count: U16,
+    pub timestamp: Tai64Timestamp,
+}
+
+/// Takes the place of the indexed blocks dropped for the subscriber
+/// that read the stream slower than the blocks were indexed.
+#[derive(SimpleObject)]
+pub struct BlockIndexedGap {
+    /// The number of dropped blocks.
+    pub missed_blocks: U64,
+}
+
+#[derive(Union)]
+pub enum BlockIndexedEvent {
+    Indexed(BlockIndexed),
+    Gap(BlockIndexedGap),
+}
+
+/// The rollback of the node from the `from_height` to the `to_height`.
+/// The blocks above the `to_height` are removed from the node.
+#[derive(SimpleObject)]
+pub struct ChainRollback {
+    pub from_height: U32,
+    pub to_height: U32,
+    /// The ids of the removed blocks in the order of their heights. `null` if
+    /// too many blocks were removed, or if the ids of the blocks are unknown.
+    pub block_ids: Option<Vec<BlockId>>,
+}
+
+/// The data included into the blocks streamed by the `blocksWithData` subscription.
+#[derive(InputObject, Clone, Copy, Debug, Default)]
+pub struct BlockDataInclusion {
+    /// Includes the transactions of the block.
+    #[graphql(default)]
+    pub transactions: bool,
+    /// Includes the receipts of the transactions of the block.
+    #[graphql(default)]
+    pub receipts: bool,
+    /// Includes the statuses of the transactions of the block.
+    #[graphql(default)]
+    pub statuses: bool,
+}
+
+/// The block with the data requested from the `blocksWithData` subscription.
+/// The data that was not requested is `null`.
+#[derive(SimpleObject)]
+pub struct BlockWithData {
+    pub block: Block,
+    /// The transactions of the block.
+    pub transactions: Option<Vec<Transaction>>,
+    /// The receipts of each transaction in the order of the transactions of the block.
+    /// The transactions without receipts have an empty list.
+    pub receipts: Option<Vec<Vec<Receipt>>>,
+    /// The statuses of the transactions in the order of the transactions of the block.
+    pub statuses: Option<Vec<TransactionStatus>>,
+}
+
+impl BlockWithData {
+    /// Reads the block at the `height` and its data from the latest view
+    /// of the database, which includes the indexed block. The fields of
+    /// the returned objects are read from the same view.
+    async fn read(
+        read_database: &ReadDatabase,
+        height: BlockHeight,
+        include: BlockDataInclusion,
+    ) -> async_graphql::Result<Self> {
+        let query = read_database.view()?;
+        let block = query.block(&height)?;
+        let tx_ids = block.transactions().to_vec();
+
+        let transactions = if include.transactions {
+            let transactions = query
+                .transactions(tx_ids.clone())
+                .await
+                .into_iter()
+                .zip(tx_ids.iter())
+                .map(|(tx, tx_id)| {
+                    tx.map(|tx| {
+                        Transaction::from_tx(*tx_id, tx).pinned(Some(query.clone()))
+                    })
+                })
+                .collect::<StorageResult<Vec<_>>>()?;
+            Some(transactions)
+        } else {
+            None
+        };
+
+        let (receipts, statuses) = if include.receipts || include.statuses {
+            let statuses = tx_ids
+                .iter()
+                .map(|tx_id| query.tx_status(tx_id))
+                .collect::<StorageResult<Vec<_>>>()?;
+            let receipts = include.receipts.then(|| {
+                statuses
+                    .iter()
+                    .map(|status| match status {
+                        TransactionExecutionStatus::Success { receipts, .. }
+                        | TransactionExecutionStatus::Failed { receipts, .. } => {
+                            receipts.iter().map(Into::into).collect()
+                        }
+                        TransactionExecutionStatus::Submitted { .. }
+                        | TransactionExecutionStatus::SqueezedOut { .. }
+                        | TransactionExecutionStatus::Rejected { .. } => vec![],
+                    })
+                    .collect()
+            });
+            let statuses = include.statuses.then(|| {
+                statuses
+                    .into_iter()
+                    .zip(tx_ids.iter())
+                    .map(|(status, tx_id)| {
+                        TransactionStatus::new(*tx_id, status.into())
+                            .pinned(Some(query.clone()))
+                    })
+                    .collect()
+            });
+            (receipts, statuses)
+        } else {
+            (None, None)
+        };
+
+        Ok(Self {
+            block: Block(block, Some(query)),
+            transactions,
+            receipts,
+            statuses,
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct BlockSubscription;
+
+#[async_graphql::Subscription]
+impl BlockSubscription {
+    /// Returns a stream of the blocks indexed by the node after the subscription.
+    ///
+    /// All subscribers share one bounded buffer of the indexed blocks. If the stream
+    /// is polled slower than the blocks are indexed, the oldest blocks are dropped
+    /// for it, and the `BlockIndexedGap` with the number of dropped blocks takes
+    /// their place.
+    async fn block_indexed(
+        &self,
+        ctx: &Context<'_>,
+    ) -> impl Stream<Item = BlockIndexedEvent> {
+        let subscriber = ctx.data_unchecked::<block_height_subscription::Subscriber>();
+        subscriber.block_indexed_events().map(Into::into)
+    }
+
+    /// Returns a stream of the blocks indexed by the node after the subscription,
+    /// together with the requested data of their transactions. The data is read
+    /// after the block is indexed, so it matches the answers of the queries.
+    /// All fields of one block are read from the same view of the database.
+    ///
+    /// All subscribers share one bounded buffer of the indexed blocks. If the stream
+    /// is polled slower than the blocks are indexed, it returns the error
+    /// about the slow subscriber and closes.
+    #[graphql(complexity = "query_costs().block_transactions + child_complexity")]
+    async fn blocks_with_data<'a>(
+        &self,
+        ctx: &Context<'a>,
+        #[graphql(desc = "The data to include into the blocks.")] include: Option<
+            BlockDataInclusion,
+        >,
+    ) -> impl Stream<Item = async_graphql::Result<BlockWithData>> + 'a {
+        let read_database: &ReadDatabase = ctx.data_unchecked();
+        let subscriber = ctx.data_unchecked::<block_height_subscription::Subscriber>();
+        let include = include.unwrap_or_default();
+
+        subscriber
+            .block_indexed_events()
+            .scan(false, |closed, event| {
+                let next = match event {
+                    _ if *closed => None,
+                    block_height_subscription::BlockIndexedEvent::Indexed(block) => {
+                        Some(Ok(block.height))
+                    }
+                    block_height_subscription::BlockIndexedEvent::Gap { missed_blocks } => {
+                        *closed = true;
+                        Some(Err(anyhow!(
+                            "{SLOW_SUBSCRIBER_MESSAGE}: {missed_blocks} blocks were dropped"
+                        )))
+                    }
+                };
+                futures::future::ready(next)
+            })
+            .then(move |height| async move {
+                BlockWithData::read(read_database, height?, include).await
+            })
+    }
+
+    /// Returns a stream of the rollbacks of the node after the subscription.
+    /// The subscribers that miss a rollback can find the last one with
+    /// the `lastRollback` query.
+    async fn chain_rollback(
+        &self,
+        ctx: &Context<'_>,
+    ) -> impl Stream<Item = ChainRollback> {
+        let notifier =
+            ctx.data_unchecked::<block_height_subscription::ChainRollbackNotifier>();
+        notifier.rollback_events().map(Into::into)
+    }
+}
+
+/// The error returned before closing the `blocksWithData` stream of the subscriber
+/// that didn't keep up with the indexed blocks.
+pub const SLOW_SUBSCRIBER_MESSAGE: &str =
+    "The subscriber is too slow and is disconnected";
+
+impl From<block_height_subscription::BlockIndexedEvent> for BlockIndexedEvent {
+    fn from(event: block_height_subscription::BlockIndexedEvent) -> Self {
+        match event {
+            block_height_subscription::BlockIndexedEvent::Indexed(block) => {
+                BlockIndexedEvent::Indexed(BlockIndexed {
+                    height: block.height.into(),
+                    tx_count: block.tx_count.into(),
+                    timestamp: Tai64Timestamp(block.timestamp),
+                })
+            }
+            block_height_subscription::BlockIndexedEvent::Gap { missed_blocks } => {
+                BlockIndexedEvent::Gap(BlockIndexedGap {
+                    missed_blocks: missed_blocks.into(),
+                })
+            }
+        }
+    }
+}
+
+impl From<rollbacks::ChainRollback> for ChainRollback {
+    fn from(rollback: rollbacks::ChainRollback) -> Self {
+        Self {
+            from_height: rollback.from_height.into(),
+            to_height: rollback.to_height.into(),
+            block_ids: rollback.block_ids.map(|block_ids| {
+                block_ids
+                    .into_iter()
+                    .map(|block_id| {
+                        let bytes: fuel_types::Bytes32 = block_id.into();
+                        bytes.into()
+                    })
+                    .collect()
+            }),
+        }
+    }
+}
+
 impl From<CompressedBlock> for Block {
     fn from(block: CompressedBlock) -> Self {
-        Block(block)
+        Block(block, None)
     }
 }
 
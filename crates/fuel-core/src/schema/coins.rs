@@ -1,34 +1,54 @@
 use std::{
     borrow::Cow,
-    collections::HashSet,
+    collections::{
+        HashMap,
+        HashSet,
+    },
 };
 
 use crate::{
     coins_query::{
         random_improve,
         select_coins_to_spend,
+        select_dust_to_consolidate,
         CoinsQueryError,
+        DustConsolidation,
         SpendQuery,
     },
     database::database_description::IndexationKind,
     fuel_core_graphql_api::{
         query_costs,
-        storage::coins::CoinsToSpendIndexKey,
+        storage::coins::{
+            CoinsToSpendIndexKey,
+            SpentCoin,
+        },
+        Config as GraphQLConfig,
         IntoApiResult,
     },
     graphql_api::{
-        api_service::ChainInfoProvider,
+        api_service::{
+            ChainInfoProvider,
+            ReadDatabase,
+        },
         database::ReadView,
+        extensions::coins_to_spend_fallback::CoinsToSpendFallbackStatus,
+        ports::CoinsToSpendIndexIter,
+        resolver_metrics,
     },
     query::asset_query::{
         AssetSpendTarget,
         Exclude,
     },
     schema::{
+        account::OwnerKind,
+        dap::require_debug,
+        response_size::ByteBudget,
         scalars::{
             Address,
             AssetId,
+            HexString,
             Nonce,
+            TransactionId,
             UtxoId,
             U128,
             U16,
@@ -45,6 +65,17 @@ use async_graphql::{
     },
     Context,
 };
+use fuel_core_storage::{
+    codec::{
+        manual::Manual,
+        Encode,
+        Encoder,
+    },
+    iter::{
+        IntoBoxedIter,
+        IterDirection,
+    },
+};
 use fuel_core_types::{
     entities::coins::{
         self,
@@ -60,10 +91,13 @@ use fuel_core_types::{
         ConsensusParameters,
     },
 };
+use futures::TryStreamExt;
 use itertools::Itertools;
 use tokio_stream::StreamExt;
 
-pub struct Coin(pub(crate) CoinModel);
+/// The coin with the view it was read from, if the view is pinned,
+/// see [`ReadViewProvider::pinned_view`].
+pub struct Coin(pub(crate) CoinModel, pub(crate) Option<ReadView>);
 
 #[async_graphql::Object]
 impl Coin {
@@ -75,6 +109,13 @@ impl Coin {
         self.0.owner.into()
     }
 
+    /// Whether the owner is known to be a predicate, see `isKnownPredicate`.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn owner_kind(&self, ctx: &Context<'_>) -> async_graphql::Result<OwnerKind> {
+        let query = ctx.pinned_view(self.1.as_ref())?;
+        Ok(OwnerKind::of(query.as_ref(), &self.0.owner)?)
+    }
+
     async fn amount(&self) -> U64 {
         self.0.amount.into()
     }
@@ -92,11 +133,41 @@ impl Coin {
     async fn tx_created_idx(&self) -> U16 {
         self.0.tx_pointer.tx_index().into()
     }
+
+    /// The transaction that spent the coin, or `null` if the coin is unspent
+    /// or its spending is outside of the historical data retention window.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn spender(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<CoinSpender>> {
+        let query = ctx.pinned_view(self.1.as_ref())?;
+        let spent_coin = query.spent_coin(&self.0.utxo_id)?;
+        Ok(spent_coin.map(Into::into))
+    }
+}
+
+/// The transaction that spent the coin.
+#[derive(async_graphql::SimpleObject)]
+pub struct CoinSpender {
+    /// The id of the transaction that spent the coin.
+    transaction_id: TransactionId,
+    /// The height of the block that spent the coin.
+    block_height: U32,
+}
+
+impl From<SpentCoin> for CoinSpender {
+    fn from(spent_coin: SpentCoin) -> Self {
+        Self {
+            transaction_id: spent_coin.tx_id.into(),
+            block_height: spent_coin.block_height.into(),
+        }
+    }
 }
 
 impl From<CoinModel> for Coin {
     fn from(value: CoinModel) -> Self {
-        Coin(value)
+        Coin(value, None)
     }
 }
 
@@ -133,6 +204,15 @@ impl MessageCoin {
     async fn da_height(&self) -> U64 {
         self.0.da_height.0.into()
     }
+
+    /// The data of the message, allows to build the message input
+    /// without querying the message.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn data(&self, ctx: &Context<'_>) -> async_graphql::Result<HexString> {
+        let query = ctx.read_view()?;
+        let message = query.message(&self.0.nonce)?;
+        Ok(HexString(message.data().clone()))
+    }
 }
 
 impl From<MessageCoinModel> for MessageCoin {
@@ -168,6 +248,19 @@ impl From<coins::CoinType> for CoinType {
     }
 }
 
+/// The small coins of the owner worth combining into one coin.
+#[derive(async_graphql::SimpleObject)]
+pub struct ConsolidationSuggestion {
+    /// The smallest coins below the dust threshold to combine into one coin.
+    coins: Vec<CoinType>,
+    /// The total amount of the `coins`.
+    total: U128,
+    /// The number of coins below the dust threshold left out of the `coins`.
+    remaining_dust_coins: U64,
+    /// The total amount of the coins below the dust threshold left out of the `coins`.
+    remaining_dust: U128,
+}
+
 #[derive(async_graphql::InputObject)]
 struct CoinFilterInput {
     /// Returns coins owned by the `owner`.
@@ -217,18 +310,56 @@ pub struct CoinQuery;
 
 #[async_graphql::Object]
 impl CoinQuery {
-    /// Gets the coin by `utxo_id`.
+    /// Gets the coin by `utxo_id`. With `include_spent`, the coin spent within
+    /// the historical data retention window is returned too.
     #[graphql(complexity = "query_costs().storage_read + child_complexity")]
     async fn coin(
         &self,
         ctx: &Context<'_>,
         #[graphql(desc = "The ID of the coin")] utxo_id: UtxoId,
+        #[graphql(desc = "Whether to return the coin if it was spent.")]
+        include_spent: Option<bool>,
     ) -> async_graphql::Result<Option<Coin>> {
         let query = ctx.read_view()?;
-        query.coin(utxo_id.0).into_api_result()
+        let coin: Option<Coin> = query.coin(utxo_id.0).into_api_result()?;
+        if coin.is_some() || !include_spent.unwrap_or(false) {
+            return Ok(coin)
+        }
+        let spent_coin = query.spent_coin(&utxo_id.0)?;
+        Ok(spent_coin.map(|spent_coin| spent_coin.coin.into()))
+    }
+
+    /// Returns the transaction that spent the coin with the `utxo_id`, or `null` if
+    /// the coin is unspent or its spending is outside of the historical data
+    /// retention window.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn coin_spender(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the coin")] utxo_id: UtxoId,
+    ) -> async_graphql::Result<Option<CoinSpender>> {
+        let query = ctx.read_view()?;
+        let spent_coin = query.spent_coin(&utxo_id.0)?;
+        Ok(spent_coin.map(Into::into))
+    }
+
+    /// Returns the ids of the coins spent by the block at the `height`, in the order
+    /// of the inputs of its transactions. The spent messages aren't included.
+    #[graphql(complexity = "query_costs().storage_iterator")]
+    async fn spent_coins_at_height(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The height of the block")] height: U32,
+    ) -> async_graphql::Result<Vec<UtxoId>> {
+        let query = ctx.read_view()?;
+        let height: u32 = height.into();
+        let utxo_ids = query.spent_coins_at_height(height.into())?;
+        Ok(utxo_ids.into_iter().map(Into::into).collect())
     }
 
     /// Gets all unspent coins of some `owner` maybe filtered with by `asset_id` per page.
+    /// With `include_derived_ownership`, the coins of which the `owner` is the logical
+    /// owner, like the coins of the predicate controlled by the `owner`'s key, are included.
     #[graphql(complexity = "{\
         query_costs().storage_iterator\
         + (query_costs().storage_read + first.unwrap_or_default() as usize) * child_complexity \
@@ -242,27 +373,126 @@ impl CoinQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
+        #[graphql(
+            desc = "Includes the coins of which the `owner` is the logical owner."
+        )]
+        include_derived_ownership: Option<bool>,
+        #[graphql(
+            desc = "Returns only the coins owned by the predicates. The coin is flagged \
+            as owned by a predicate when it is indexed, if its owner has spent a coin \
+            or a message with the predicate in this or an earlier transaction, see \
+            `ownerKind`. The coins received before that spend aren't flagged."
+        )]
+        only_predicate: Option<bool>,
+        #[graphql(
+            desc = "Ends the page once the estimated size of its entries exceeds \
+            the budget in bytes. The page has at least one entry."
+        )]
+        max_bytes: Option<U32>,
     ) -> async_graphql::Result<Connection<UtxoId, Coin, EmptyFields, EmptyFields>> {
+        let max_page_size = ctx
+            .data_unchecked::<GraphQLConfig>()
+            .config
+            .max_page_sizes
+            .coins;
+        crate::schema::ensure_page_size("coins", first, last, max_page_size)?;
         let query = ctx.read_view()?;
         let owner: fuel_tx::Address = filter.owner.into();
-        crate::schema::query_pagination(after, before, first, last, |start, direction| {
-            let coins = query
-                .owned_coins(&owner, (*start).map(Into::into), direction)
+        let include_derived = include_derived_ownership.unwrap_or(false);
+        let only_predicate = only_predicate.unwrap_or(false);
+        // The coins owned directly by the `owner` are flagged as owned by a predicate
+        // only if the `owner` is a predicate, so the other owners have none of them.
+        if only_predicate
+            && !include_derived
+            && OwnerKind::of(query.as_ref(), &owner)? != OwnerKind::Predicate
+        {
+            return Ok(Connection::new(false, false));
+        }
+        let coins = crate::schema::query_pagination_within_budget(
+            after,
+            before,
+            first,
+            last,
+            ByteBudget::new(max_bytes),
+            |start, direction| {
+                let start = (*start).map(Into::into);
+                // The coins owned directly are indexed by the asset id and by the flag
+                // of the predicate, so only the coins of the logical owners are filtered
+                // after loading.
+                let coins = match &filter.asset_id {
+                    _ if only_predicate && !include_derived => {
+                        futures::future::Either::Left(futures::future::Either::Left(
+                            query.predicate_owned_coins(&owner, start, direction),
+                        ))
+                    }
+                    Some(asset_id) if !include_derived => futures::future::Either::Left(
+                        futures::future::Either::Right(query.owned_coins_by_asset(
+                            &owner,
+                            &asset_id.0,
+                            start,
+                            direction,
+                        )),
+                    ),
+                    _ => futures::future::Either::Right(query.owned_coins(
+                        &owner,
+                        start,
+                        direction,
+                        include_derived,
+                    )),
+                }
                 .filter_map(|result| {
-                    if let (Ok(coin), Some(filter_asset_id)) = (&result, &filter.asset_id)
-                    {
+                    let Ok(coin) = &result else {
+                        return Some(result)
+                    };
+                    if let Some(filter_asset_id) = &filter.asset_id {
                         if coin.asset_id != filter_asset_id.0 {
                             return None
                         }
                     }
+                    if only_predicate && include_derived {
+                        match query.is_predicate_owned_coin(&coin.owner, &coin.utxo_id) {
+                            Ok(true) => {}
+                            Ok(false) => return None,
+                            Err(err) => return Some(Err(err)),
+                        }
+                    }
 
                     Some(result)
                 })
                 .map(|res| res.map(|coin| (coin.utxo_id.into(), coin.into())));
 
-            Ok(coins)
-        })
-        .await
+                Ok(coins)
+            },
+        );
+        resolver_metrics::instrument("coins", coins).await
+    }
+
+    /// Gets the number of unspent coins of the `owner` with the `asset_id`.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn coin_count_by_owner_asset(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The `Address` of the coins owner.")] owner: Address,
+        #[graphql(desc = "The `AssetId` of the coins.")] asset_id: AssetId,
+    ) -> async_graphql::Result<U64> {
+        let query = ctx.read_view()?;
+        let count = query.owned_coin_count_by_asset(&owner.0, &asset_id.0)?;
+        Ok(count.into())
+    }
+
+    /// Returns `true` if the `owner` has any spendable coins of the `asset_id`. It is
+    /// a cheaper check of the emptiness than the `coinsToSpend`, as it stops at the first
+    /// coin without the selection. Requires the coins to spend indexation.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn has_spendable_coins(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The `Address` of the coins owner.")] owner: Address,
+        #[graphql(desc = "The `AssetId` of the coins.")] asset_id: AssetId,
+    ) -> async_graphql::Result<bool> {
+        let query = ctx.read_view()?;
+        let has_coins = query.has_spendable_coins(&owner.0, &asset_id.0)?;
+        Ok(has_coins)
     }
 
     /// For each `query_per_asset`, get some spendable coins(of asset specified by the query) owned by
@@ -326,14 +556,177 @@ impl CoinQuery {
         query_per_asset.truncate(max_input as usize);
 
         let read_view = ctx.read_view()?;
-        let result = read_view
-            .coins_to_spend(owner, &query_per_asset, &exclude, &params, max_input)
+        let selected = resolver_metrics::instrument(
+            "coinsToSpend",
+            read_view.coins_to_spend(
+                owner,
+                &query_per_asset,
+                &exclude,
+                &params,
+                max_input,
+            ),
+        )
+        .await?;
+
+        if selected.used_fallback {
+            if let Some(status) = ctx.data_opt::<CoinsToSpendFallbackStatus>() {
+                status.record();
+            }
+        }
+
+        Ok(selected.coins)
+    }
+
+    /// Suggests the smallest coins of the `owner` with the `asset_id` below the dust
+    /// threshold to combine into one coin, and reports the dust left out of it.
+    /// Requires the coins to spend indexation.
+    #[graphql(complexity = "query_costs().coins_to_spend")]
+    async fn consolidation_suggestion(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The `Address` of the coins owner.")] owner: Address,
+        #[graphql(desc = "The `AssetId` of the coins.")] asset_id: AssetId,
+        #[graphql(desc = "\
+            The coins with the amount below it are dust. \
+            The node's dust threshold is used if it is not specified.")]
+        dust_threshold: Option<U64>,
+        #[graphql(desc = "\
+            The maximum number of coins in the suggestion. \
+            It can't be above the maximum number of the transaction inputs.")]
+        max_inputs: Option<U16>,
+    ) -> async_graphql::Result<ConsolidationSuggestion> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let params = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params();
+        let max_input = params.tx_params().max_inputs();
+
+        let dust_threshold = dust_threshold
+            .map(|threshold| threshold.0)
+            .unwrap_or(config.config.consolidation_dust_threshold);
+        let max_inputs = max_inputs
+            .map(|max| max.0)
+            .unwrap_or(max_input)
+            .min(max_input);
+
+        let read_view = ctx.read_view()?;
+        let suggestion = read_view
+            .consolidation_suggestion(owner.0, asset_id.0, dust_threshold, max_inputs)
             .await?;
 
-        Ok(result)
+        Ok(suggestion)
+    }
+
+    /// Returns the coins of the `owner` from the owned coins index that don't exist
+    /// in the on-chain state. Such entries are left behind by the indexing bugs
+    /// and are removed by the `repairOrphanedOwnedCoins`. Scans all coins of the owner.
+    /// Requires the `debug` feature to be enabled.
+    #[graphql(complexity = "query_costs().storage_iterator")]
+    async fn find_orphaned_owned_coins(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The `Address` of the coins owner.")] owner: Address,
+        #[graphql(desc = "\
+            The maximum number of the returned coins. \
+            The node's database batch size is used if it is not specified.")]
+        limit: Option<U32>,
+    ) -> async_graphql::Result<Vec<UtxoId>> {
+        require_debug(ctx)?;
+        let database: &ReadDatabase = ctx.data_unchecked();
+        let Some((read_view, _)) = database.consistent_view()? else {
+            return Err(anyhow::anyhow!(
+                "The off-chain database is behind the latest block, try again later"
+            )
+            .into())
+        };
+        let limit = limit
+            .map(|limit| usize::try_from(limit.0).unwrap_or(usize::MAX))
+            .unwrap_or(read_view.batch_size);
+
+        let orphaned = read_view.orphaned_owned_coins(&owner.0, limit)?;
+        Ok(orphaned.into_iter().map(Into::into).collect())
+    }
+}
+
+#[derive(Default)]
+pub struct CoinSubscription;
+
+#[async_graphql::Subscription]
+impl CoinSubscription {
+    /// Streams all unspent coins of the `owner` in the key order, in batches of
+    /// `batch_size` coins. The batch size is capped by the node's database batch size,
+    /// which is also used when it is not specified.
+    ///
+    /// Every batch is read from a new view of the database, so the stream doesn't
+    /// hold the storage for its whole lifetime. Coins created or spent while
+    /// the stream is active may or may not be part of it. The fields of the coins
+    /// are read from the view of their batch.
+    async fn stream_owned_coins<'a>(
+        &self,
+        ctx: &Context<'a>,
+        #[graphql(desc = "The `Address` of the coins owner.")] owner: Address,
+        #[graphql(desc = "The maximum number of coins in one batch.")] batch_size: Option<
+            U32,
+        >,
+    ) -> async_graphql::Result<
+        impl futures::Stream<Item = async_graphql::Result<Vec<Coin>>> + 'a,
+    > {
+        let read_database: &ReadDatabase = ctx.data_unchecked();
+        let read_view: &ReadView = ctx.data_unchecked();
+        let max_batch_size = read_view.batch_size;
+        let batch_size = match batch_size {
+            Some(batch_size) if batch_size.0 == 0 => {
+                return Err(anyhow::anyhow!("The batch size should be positive").into())
+            }
+            Some(batch_size) => usize::try_from(batch_size.0)
+                .unwrap_or(usize::MAX)
+                .min(max_batch_size),
+            None => max_batch_size,
+        };
+        let owner: fuel_tx::Address = owner.into();
+
+        // The state is the coin after which the next batch starts,
+        // or `None` when the stream is exhausted.
+        let stream = futures::stream::try_unfold(
+            Some(None),
+            move |after: Option<Option<fuel_tx::UtxoId>>| async move {
+                let Some(after) = after else { return Ok(None) };
+                let read_view = read_database.view()?;
+                let coins = read_view
+                    .owned_coins_after(&owner, after, batch_size)
+                    .await?;
+
+                if coins.is_empty() {
+                    return Ok(None)
+                }
+
+                let next = if coins.len() < batch_size {
+                    None
+                } else {
+                    coins.last().map(|coin| Some(coin.utxo_id))
+                };
+                let coins = coins
+                    .into_iter()
+                    .map(|coin| Coin(coin, Some(read_view.clone())))
+                    .collect();
+
+                Ok::<_, async_graphql::Error>(Some((coins, next)))
+            },
+        );
+
+        Ok(stream)
     }
 }
 
+/// The coins selected by [`ReadView::coins_to_spend`].
+pub struct SelectedCoins {
+    /// The coins per asset in the order of the query.
+    pub coins: Vec<Vec<CoinType>>,
+    /// Whether the coins were selected by scanning the owned coins
+    /// because the coins to spend index was being rebuilt.
+    pub used_fallback: bool,
+}
+
 impl ReadView {
     pub async fn coins_to_spend(
         &self,
@@ -342,16 +735,40 @@ impl ReadView {
         excluded: &Exclude,
         params: &ConsensusParameters,
         max_input: u16,
-    ) -> Result<Vec<Vec<CoinType>>, CoinsQueryError> {
+    ) -> Result<SelectedCoins, CoinsQueryError> {
+        let base_asset_id = params.base_asset_id();
         let indexation_available = self
             .indexation_flags
-            .contains(&IndexationKind::CoinsToSpend);
-        if indexation_available {
-            coins_to_spend_with_cache(owner, query_per_asset, excluded, max_input, self)
-                .await
-        } else {
-            let base_asset_id = params.base_asset_id();
-            coins_to_spend_without_cache(
+            .contains(&IndexationKind::CoinsToSpend)
+            || self
+                .stale_indexation_flags
+                .contains(&IndexationKind::CoinsToSpend);
+
+        if !indexation_available {
+            let coins = coins_to_spend_without_cache(
+                owner,
+                query_per_asset,
+                excluded,
+                max_input,
+                base_asset_id,
+                self,
+            )
+            .await?;
+            return Ok(SelectedCoins {
+                coins,
+                used_fallback: false,
+            })
+        }
+
+        // The staleness is checked on the view instead of the startup flags,
+        // so the index is used again as soon as its backfill completes.
+        let rebuilding = self
+            .off_chain
+            .indexation_stale(IndexationKind::CoinsToSpend)
+            .map_err(CoinsQueryError::StorageError)?;
+
+        if rebuilding {
+            let coins = coins_to_spend_with_fallback(
                 owner,
                 query_per_asset,
                 excluded,
@@ -359,9 +776,69 @@ impl ReadView {
                 base_asset_id,
                 self,
             )
-            .await
+            .await?;
+            Ok(SelectedCoins {
+                coins,
+                used_fallback: true,
+            })
+        } else {
+            let coins = coins_to_spend_with_cache(
+                owner,
+                query_per_asset,
+                excluded,
+                max_input,
+                self,
+            )
+            .await?;
+            Ok(SelectedCoins {
+                coins,
+                used_fallback: false,
+            })
         }
     }
+
+    pub async fn consolidation_suggestion(
+        &self,
+        owner: fuel_tx::Address,
+        asset_id: fuel_tx::AssetId,
+        dust_threshold: u64,
+        max_inputs: u16,
+    ) -> Result<ConsolidationSuggestion, CoinsQueryError> {
+        self.ensure_indexation_ready(IndexationKind::CoinsToSpend)
+            .map_err(CoinsQueryError::StorageError)?;
+        if !self
+            .indexation_flags
+            .contains(&IndexationKind::CoinsToSpend)
+        {
+            return Err(anyhow::anyhow!(
+                "The consolidation suggestion requires the coins to spend indexation"
+            )
+            .into())
+        }
+
+        let CoinsToSpendIndexIter {
+            dust_coins_iter, ..
+        } = self.coins_to_spend_index(&owner, &asset_id);
+        let DustConsolidation {
+            coins,
+            total,
+            remaining_dust_coins,
+            remaining_dust,
+        } = select_dust_to_consolidate(
+            dust_coins_iter,
+            dust_threshold,
+            max_inputs,
+            self.batch_size,
+        )
+        .await?;
+
+        Ok(ConsolidationSuggestion {
+            coins: into_coin_types(&coins, self)?,
+            total: total.into(),
+            remaining_dust_coins: remaining_dust_coins.into(),
+            remaining_dust: remaining_dust.into(),
+        })
+    }
 }
 
 async fn coins_to_spend_without_cache(
@@ -428,7 +905,7 @@ async fn coins_to_spend_with_cache(
             .min(max_input);
 
         let selected_coins = select_coins_to_spend(
-            db.off_chain.coins_to_spend_index(&owner, &asset_id),
+            db.coins_to_spend_index(&owner, &asset_id),
             total_amount,
             max,
             &asset_id,
@@ -437,27 +914,120 @@ async fn coins_to_spend_with_cache(
         )
         .await?;
 
-        let mut coins_per_asset = Vec::with_capacity(selected_coins.len());
-        for coin_or_message_id in into_coin_id(&selected_coins) {
-            let coin_type = match coin_or_message_id {
-                coins::CoinId::Utxo(utxo_id) => {
-                    db.coin(utxo_id).map(|coin| CoinType::Coin(coin.into()))?
-                }
-                coins::CoinId::Message(nonce) => {
-                    let message = db.message(&nonce)?;
-                    let message_coin: message_coin::MessageCoin = message.try_into()?;
-                    CoinType::MessageCoin(message_coin.into())
-                }
-            };
+        all_coins.push(into_coin_types(&selected_coins, db)?);
+    }
+    Ok(all_coins)
+}
+
+/// Selects the coins the same way as [`coins_to_spend_with_cache`], but builds
+/// the keys of the coins to spend index from the owned coins and messages.
+/// The scan of the owned coins is bounded by the scan budget of the view.
+async fn coins_to_spend_with_fallback(
+    owner: fuel_tx::Address,
+    query_per_asset: &[SpendQueryElementInput],
+    excluded: &Exclude,
+    max_input: u16,
+    base_asset_id: &fuel_tx::AssetId,
+    db: &ReadView,
+) -> Result<Vec<Vec<CoinType>>, CoinsQueryError> {
+    let queried_assets: HashSet<fuel_tx::AssetId> = query_per_asset
+        .iter()
+        .map(|asset| asset.asset_id.0)
+        .collect();
 
-            coins_per_asset.push(coin_type);
+    let mut keys_per_asset: HashMap<fuel_tx::AssetId, Vec<CoinsToSpendIndexKey>> =
+        HashMap::with_capacity(queried_assets.len());
+    let coins: Vec<_> = db
+        .owned_coins(&owner, None, IterDirection::Forward, false)
+        .try_collect()
+        .await?;
+    for coin in coins
+        .iter()
+        .filter(|coin| queried_assets.contains(&coin.asset_id))
+    {
+        keys_per_asset
+            .entry(coin.asset_id)
+            .or_default()
+            .push(CoinsToSpendIndexKey::from_coin(coin));
+    }
+
+    if queried_assets.contains(base_asset_id) {
+        let messages: Vec<_> = db
+            .owned_messages(&owner, None, IterDirection::Forward)
+            .try_collect()
+            .await?;
+        // The index contains only the non-retryable messages under the owner's prefix.
+        for message in messages
+            .iter()
+            .filter(|message| !message.is_retryable_message())
+        {
+            keys_per_asset
+                .entry(*base_asset_id)
+                .or_default()
+                .push(CoinsToSpendIndexKey::from_message(message, base_asset_id));
         }
+    }
+
+    let mut all_coins = Vec::with_capacity(query_per_asset.len());
+
+    for asset in query_per_asset {
+        let asset_id = asset.asset_id.0;
+        let total_amount = asset.amount.0;
+        let max = asset
+            .max
+            .map(|max| max.0)
+            .unwrap_or(max_input)
+            .min(max_input);
 
-        all_coins.push(coins_per_asset);
+        let mut keys = keys_per_asset.remove(&asset_id).unwrap_or_default();
+        // Sort the keys by their encoding to visit them in the order of the index.
+        keys.sort_by_cached_key(|key| {
+            Manual::<CoinsToSpendIndexKey>::encode(key)
+                .as_bytes()
+                .into_owned()
+        });
+        let index = CoinsToSpendIndexIter {
+            big_coins_iter: keys.clone().into_iter().rev().map(Ok).into_boxed(),
+            dust_coins_iter: keys.into_iter().map(Ok).into_boxed(),
+        };
+
+        let selected_coins = select_coins_to_spend(
+            index,
+            total_amount,
+            max,
+            &asset_id,
+            excluded,
+            db.batch_size,
+        )
+        .await?;
+
+        all_coins.push(into_coin_types(&selected_coins, db)?);
     }
     Ok(all_coins)
 }
 
+fn into_coin_types(
+    selected: &[CoinsToSpendIndexKey],
+    db: &ReadView,
+) -> Result<Vec<CoinType>, CoinsQueryError> {
+    let mut coin_types = Vec::with_capacity(selected.len());
+    for coin_or_message_id in into_coin_id(selected) {
+        let coin_type = match coin_or_message_id {
+            coins::CoinId::Utxo(utxo_id) => {
+                db.coin(utxo_id).map(|coin| CoinType::Coin(coin.into()))?
+            }
+            coins::CoinId::Message(nonce) => {
+                let message = db.message(&nonce)?;
+                let message_coin: message_coin::MessageCoin = message.try_into()?;
+                CoinType::MessageCoin(message_coin.into())
+            }
+        };
+
+        coin_types.push(coin_type);
+    }
+    Ok(coin_types)
+}
+
 fn into_coin_id(selected: &[CoinsToSpendIndexKey]) -> Vec<CoinId> {
     let mut coins = Vec::with_capacity(selected.len());
     for coin in selected {
@@ -1,6 +1,8 @@
 use crate::{
+    database::database_description::IndexationKind,
     fuel_core_graphql_api::{
         query_costs,
+        storage::contracts::ContractDeployment,
         IntoApiResult,
     },
     schema::{
@@ -9,6 +11,9 @@ use crate::{
             ContractId,
             HexString,
             Salt,
+            TransactionId,
+            TxPointer,
+            U32,
             U64,
         },
         ReadViewProvider,
@@ -24,10 +29,12 @@ use async_graphql::{
     Object,
 };
 use fuel_core_storage::{
+    iter::IterDirection,
     not_found,
     tables::ContractsRawCode,
 };
 use fuel_core_types::{
+    fuel_tx,
     fuel_types,
     services::graphql_api,
 };
@@ -66,6 +73,38 @@ impl Contract {
     }
 }
 
+/// The contract deployed by the `Create` transaction.
+pub struct DeployedContract {
+    tx_pointer: fuel_tx::TxPointer,
+    deployment: ContractDeployment,
+}
+
+#[Object]
+impl DeployedContract {
+    async fn contract_id(&self) -> ContractId {
+        self.deployment.contract_id.into()
+    }
+
+    /// The `Create` transaction deploying the contract.
+    async fn tx_id(&self) -> TransactionId {
+        self.deployment.tx_id.into()
+    }
+
+    /// The height of the block and the index of the transaction within it.
+    async fn tx_pointer(&self) -> TxPointer {
+        self.tx_pointer.into()
+    }
+
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn salt(&self, ctx: &Context<'_>) -> async_graphql::Result<Salt> {
+        let query = ctx.read_view()?;
+        query
+            .contract_salt(&self.deployment.contract_id)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+}
+
 #[derive(Default)]
 pub struct ContractQuery;
 
@@ -89,6 +128,67 @@ impl ContractQuery {
             })
             .into_api_result()
     }
+
+    /// Returns the contracts deployed within the range of the blocks,
+    /// ordered by the block height and the index of the transaction within the block.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator\
+        + (query_costs().storage_read + first.unwrap_or_default() as usize) * child_complexity \
+        + (query_costs().storage_read + last.unwrap_or_default() as usize) * child_complexity\
+    }")]
+    #[allow(clippy::too_many_arguments)]
+    async fn contracts(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The height of the first block of the range, inclusive")]
+        start_height: Option<U32>,
+        #[graphql(desc = "The height of the last block of the range, inclusive")]
+        end_height: Option<U32>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<TxPointer, DeployedContract, EmptyFields, EmptyFields>,
+    > {
+        let query = ctx.read_view()?;
+        let start_height: fuel_types::BlockHeight =
+            start_height.map(Into::into).unwrap_or_default();
+        let end_height: fuel_types::BlockHeight = end_height
+            .map(Into::into)
+            .unwrap_or_else(|| u32::MAX.into());
+        if start_height > end_height {
+            return Err(anyhow::anyhow!(
+                "The `startHeight` {start_height} is greater than the `endHeight` {end_height}"
+            )
+            .into());
+        }
+
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<TxPointer>, direction: IterDirection| {
+                let start = (*start).map(Into::into);
+                let contracts = query
+                    .contracts_by_block(start, start_height..=end_height, direction)
+                    .map(|result| {
+                        result.map(|(tx_pointer, deployment)| {
+                            (
+                                tx_pointer.into(),
+                                DeployedContract {
+                                    tx_pointer,
+                                    deployment,
+                                },
+                            )
+                        })
+                    });
+                Ok(contracts)
+            },
+        )
+        .await
+    }
 }
 
 pub struct ContractBalance(graphql_api::ContractBalance);
@@ -161,6 +261,7 @@ impl ContractBalanceQuery {
         Connection<AssetId, ContractBalance, EmptyFields, EmptyFields>,
     > {
         let query = ctx.read_view()?;
+        query.ensure_indexation_ready(IndexationKind::ContractBalances)?;
 
         crate::schema::query_pagination(after, before, first, last, |start, direction| {
             let balances = query
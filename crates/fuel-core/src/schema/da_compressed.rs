@@ -2,17 +2,44 @@ use super::{
     scalars::HexString,
     ReadViewProvider,
 };
+#[cfg(feature = "fault-proving")]
+use crate::schema::message::MerkleProof;
 use crate::{
     fuel_core_graphql_api::{
+        da_compression,
         query_costs,
+        storage::da_compression::{
+            evictor_cache::MetadataKey,
+            DaCompressionStats,
+            DaRegistration,
+        },
+        Config as GraphQLConfig,
         IntoApiResult,
     },
-    schema::scalars::U32,
+    schema::{
+        dap::require_debug,
+        scalars::{
+            Bytes32,
+            Tai64Timestamp,
+            U32,
+            U64,
+        },
+    },
 };
 use async_graphql::{
+    connection::{
+        Connection,
+        EmptyFields,
+    },
     Context,
+    Enum,
     Object,
 };
+use fuel_core_compression::VersionedBlockPayload;
+use fuel_core_types::{
+    fuel_compression::RegistryKey,
+    fuel_types::BlockHeight,
+};
 
 pub struct DaCompressedBlock {
     bytes: Vec<u8>,
@@ -31,6 +58,337 @@ impl DaCompressedBlock {
     }
 }
 
+pub struct DecompressedDaBlock(da_compression::DecompressedBlock);
+
+#[Object]
+impl DecompressedDaBlock {
+    /// The version of the format of the stored block.
+    async fn format_version(&self) -> U32 {
+        u32::from(self.0.format_version).into()
+    }
+
+    /// The version of the payload of the block.
+    async fn payload_version(&self) -> U32 {
+        u32::from(self.0.payload_version()).into()
+    }
+
+    async fn height(&self) -> U32 {
+        (*self.0.block.height()).into()
+    }
+
+    async fn prev_root(&self) -> Bytes32 {
+        self.0.block.consensus_header().prev_root.into()
+    }
+
+    async fn time(&self) -> Tai64Timestamp {
+        Tai64Timestamp(self.0.block.consensus_header().time)
+    }
+
+    async fn da_height(&self) -> U64 {
+        self.0.block.application_header().da_height.0.into()
+    }
+
+    async fn consensus_parameters_version(&self) -> U32 {
+        self.0
+            .block
+            .application_header()
+            .consensus_parameters_version
+            .into()
+    }
+
+    async fn state_transition_bytecode_version(&self) -> U32 {
+        self.0
+            .block
+            .application_header()
+            .state_transition_bytecode_version
+            .into()
+    }
+
+    /// The number of new registrations in the temporal registry made by the block.
+    async fn registrations(&self) -> U64 {
+        u64::try_from(self.0.block.registrations().count())
+            .unwrap_or(u64::MAX)
+            .into()
+    }
+
+    /// The number of transactions in the block.
+    async fn transaction_count(&self) -> U32 {
+        u32::try_from(self.0.block.transactions().len())
+            .unwrap_or(u32::MAX)
+            .into()
+    }
+
+    /// The whole block as JSON. The transactions are in the compressed form,
+    /// with the keys of the temporal registry instead of the values.
+    async fn json(&self) -> async_graphql::Result<String> {
+        Ok(self.0.to_json()?)
+    }
+}
+
+impl From<da_compression::DecompressedBlock> for DecompressedDaBlock {
+    fn from(block: da_compression::DecompressedBlock) -> Self {
+        Self(block)
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum DaBlockVerificationStatus {
+    /// The block matches the stored checksum.
+    Verified,
+    /// The block was stored without a checksum.
+    Unverified,
+    /// The block doesn't match the stored checksum.
+    Corrupted,
+}
+
+pub struct DaBlockVerification(da_compression::DaBlockVerification);
+
+#[Object]
+impl DaBlockVerification {
+    async fn status(&self) -> DaBlockVerificationStatus {
+        match self.0 {
+            da_compression::DaBlockVerification::Verified { .. } => {
+                DaBlockVerificationStatus::Verified
+            }
+            da_compression::DaBlockVerification::Unverified { .. } => {
+                DaBlockVerificationStatus::Unverified
+            }
+            da_compression::DaBlockVerification::Corrupted { .. } => {
+                DaBlockVerificationStatus::Corrupted
+            }
+        }
+    }
+
+    /// The checksum stored for the block, if any.
+    async fn stored_checksum(&self) -> Option<U32> {
+        match self.0 {
+            da_compression::DaBlockVerification::Verified { checksum } => {
+                Some(checksum.into())
+            }
+            da_compression::DaBlockVerification::Unverified { .. } => None,
+            da_compression::DaBlockVerification::Corrupted { expected, .. } => {
+                Some(expected.into())
+            }
+        }
+    }
+
+    /// The checksum recomputed from the stored block.
+    async fn computed_checksum(&self) -> U32 {
+        match self.0 {
+            da_compression::DaBlockVerification::Verified { checksum }
+            | da_compression::DaBlockVerification::Unverified { checksum } => {
+                checksum.into()
+            }
+            da_compression::DaBlockVerification::Corrupted { actual, .. } => {
+                actual.into()
+            }
+        }
+    }
+}
+
+impl From<da_compression::DaBlockVerification> for DaBlockVerification {
+    fn from(verification: da_compression::DaBlockVerification) -> Self {
+        Self(verification)
+    }
+}
+
+pub struct DaRegistryCode(da_compression::RegistryCode);
+
+#[Object]
+impl DaRegistryCode {
+    /// The hash of the whole code.
+    async fn code_hash(&self) -> Bytes32 {
+        self.0.code_hash.into()
+    }
+
+    /// The size of the whole code in bytes.
+    async fn size(&self) -> U64 {
+        (self.0.size as u64).into()
+    }
+
+    /// The code, truncated to the size cap.
+    async fn bytes(&self) -> HexString {
+        HexString(self.0.bytes.clone())
+    }
+
+    /// Indicates if the code is larger than the size cap.
+    async fn truncated(&self) -> bool {
+        self.0.truncated
+    }
+}
+
+impl From<da_compression::RegistryCode> for DaRegistryCode {
+    fn from(code: da_compression::RegistryCode) -> Self {
+        Self(code)
+    }
+}
+
+pub struct DaCompressionMetrics {
+    height: BlockHeight,
+    stats: DaCompressionStats,
+}
+
+#[Object]
+impl DaCompressionMetrics {
+    /// The height of the compressed block.
+    async fn height(&self) -> U32 {
+        self.height.into()
+    }
+
+    /// The size of the block before the compression in bytes.
+    async fn uncompressed_size(&self) -> U64 {
+        self.stats.uncompressed_size.into()
+    }
+
+    /// The size of the block after the compression in bytes.
+    async fn compressed_size(&self) -> U64 {
+        self.stats.compressed_size.into()
+    }
+
+    /// The ratio of the compressed size to the uncompressed size.
+    async fn ratio(&self) -> f64 {
+        self.stats.ratio()
+    }
+
+    /// The number of new registrations in the temporal registry made by the block.
+    async fn registrations(&self) -> U64 {
+        self.stats.registrations.into()
+    }
+}
+
+impl From<(BlockHeight, DaCompressionStats)> for DaCompressionMetrics {
+    fn from((height, stats): (BlockHeight, DaCompressionStats)) -> Self {
+        Self { height, stats }
+    }
+}
+
+#[cfg(feature = "fault-proving")]
+pub struct DaCompressedBlockProof(da_compression::DaCompressedBlockProof);
+
+#[cfg(feature = "fault-proving")]
+#[Object]
+impl DaCompressedBlockProof {
+    /// The root of the Merkle tree of all compressed blocks at the last compressed block.
+    async fn root(&self) -> Bytes32 {
+        self.0.root.into()
+    }
+
+    /// The number of the compressed blocks in the tree.
+    async fn leaf_count(&self) -> U64 {
+        self.0.leaf_count.into()
+    }
+
+    /// The proof of the postcard encoded block as the leaf of the tree.
+    async fn proof(&self) -> MerkleProof {
+        self.0.proof.clone().into()
+    }
+}
+
+#[cfg(feature = "fault-proving")]
+impl From<da_compression::DaCompressedBlockProof> for DaCompressedBlockProof {
+    fn from(proof: da_compression::DaCompressedBlockProof) -> Self {
+        Self(proof)
+    }
+}
+
+/// The default number of the next eviction candidates returned per keyspace.
+const DEFAULT_EVICTION_CANDIDATES: u32 = 16;
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum DaRegistryKeyspace {
+    Address,
+    AssetId,
+    ContractId,
+    ScriptCode,
+    PredicateCode,
+}
+
+impl From<MetadataKey> for DaRegistryKeyspace {
+    fn from(keyspace: MetadataKey) -> Self {
+        match keyspace {
+            MetadataKey::Address => DaRegistryKeyspace::Address,
+            MetadataKey::AssetId => DaRegistryKeyspace::AssetId,
+            MetadataKey::ContractId => DaRegistryKeyspace::ContractId,
+            MetadataKey::ScriptCode => DaRegistryKeyspace::ScriptCode,
+            MetadataKey::PredicateCode => DaRegistryKeyspace::PredicateCode,
+        }
+    }
+}
+
+pub struct DaBlockRegistration(DaRegistration);
+
+#[Object]
+impl DaBlockRegistration {
+    async fn keyspace(&self) -> DaRegistryKeyspace {
+        self.0.keyspace.into()
+    }
+
+    /// The key in the temporal registry assigned to the value.
+    async fn key(&self) -> U32 {
+        self.0.key.as_u32().into()
+    }
+
+    /// The hash of the registered value.
+    async fn value_hash(&self) -> Bytes32 {
+        self.0.value_hash.into()
+    }
+}
+
+pub struct DaRegistryEvictionCandidate(da_compression::EvictionCandidate);
+
+#[Object]
+impl DaRegistryEvictionCandidate {
+    /// The key of the entry in the temporal registry.
+    async fn key(&self) -> U32 {
+        self.0.key.as_u32().into()
+    }
+
+    /// The time of the last update of the entry, `null` if the key is not used yet.
+    async fn last_updated(&self) -> Option<Tai64Timestamp> {
+        self.0.last_updated.map(Tai64Timestamp)
+    }
+}
+
+pub struct DaRegistryEvictorState(da_compression::RegistryEvictorState);
+
+#[Object]
+impl DaRegistryEvictorState {
+    async fn keyspace(&self) -> DaRegistryKeyspace {
+        self.0.keyspace.into()
+    }
+
+    /// The cursor of the evictor, the key assigned by the last registration.
+    /// It is `null` if nothing was registered in the keyspace yet.
+    async fn latest_assigned_key(&self) -> Option<U32> {
+        self.0.latest_assigned_key.map(|key| key.as_u32().into())
+    }
+
+    /// The number of keys holding a registration. Once it reaches the capacity
+    /// of the registry, the entries with the oldest registration are overwritten.
+    async fn entry_count(&self) -> U32 {
+        self.0.entry_count.into()
+    }
+
+    /// The entries in the order they are overwritten by the next registrations,
+    /// with the capacity of the registry configured for the node.
+    /// The entries still referenced by the block being compressed are skipped
+    /// by the evictor, so the order may differ for them.
+    async fn next_evictions(&self) -> Vec<DaRegistryEvictionCandidate> {
+        self.0
+            .next_evictions
+            .iter()
+            .copied()
+            .map(DaRegistryEvictionCandidate)
+            .collect()
+    }
+}
+
+impl From<da_compression::RegistryEvictorState> for DaRegistryEvictorState {
+    fn from(state: da_compression::RegistryEvictorState) -> Self {
+        Self(state)
+    }
+}
+
 #[derive(Default)]
 pub struct DaCompressedBlockQuery;
 
@@ -47,4 +405,183 @@ impl DaCompressedBlockQuery {
             .da_compressed_block(&height.0.into())
             .into_api_result()
     }
+
+    /// Returns the DA compressed block decoded into the human-readable form.
+    #[graphql(complexity = "query_costs().da_compressed_block_read")]
+    async fn decompressed_da_block(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Height of the block")] height: U32,
+    ) -> async_graphql::Result<Option<DecompressedDaBlock>> {
+        let query = ctx.read_view()?;
+        let bytes = query
+            .da_compressed_block(&height.0.into())
+            .into_api_result::<Vec<u8>, async_graphql::Error>()?;
+        let block = bytes
+            .map(|bytes| da_compression::decompress_da_block(&bytes))
+            .transpose()?;
+        Ok(block.map(Into::into))
+    }
+
+    /// Returns the new registrations in the temporal registry made by the DA
+    /// compressed block, in the order of the keyspaces. Returns `null` if the block
+    /// is unknown or was compressed before the registrations were stored.
+    #[graphql(complexity = "{\
+        query_costs().da_compressed_block_read\
+        + first.unwrap_or_default() as usize * child_complexity \
+    }")]
+    async fn da_compressed_block_registrations(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Height of the block")] height: U32,
+        first: Option<i32>,
+        after: Option<String>,
+    ) -> async_graphql::Result<
+        Option<Connection<U32, DaBlockRegistration, EmptyFields, EmptyFields>>,
+    > {
+        let query = ctx.read_view()?;
+        let Some(registrations) =
+            query.da_compressed_block_registrations(&height.0.into())?
+        else {
+            return Ok(None)
+        };
+
+        let connection = crate::schema::query_pagination(
+            after,
+            None,
+            first,
+            None,
+            |start: &Option<U32>, _| {
+                let skip = start.map(|start| start.0 as usize).unwrap_or_default();
+                let entries = registrations.into_iter().enumerate().skip(skip).map(
+                    |(index, registration)| {
+                        let index = u32::try_from(index).unwrap_or(u32::MAX);
+                        Ok((index.into(), DaBlockRegistration(registration)))
+                    },
+                );
+                Ok(futures::stream::iter(entries))
+            },
+        )
+        .await?;
+        Ok(Some(connection))
+    }
+
+    /// Returns the proof of the inclusion of the DA compressed block in the Merkle
+    /// tree of all compressed blocks, against the root at the last compressed block.
+    /// Returns `null` if the block is not compressed.
+    // 64 * QUERY_COSTS.storage_read because the depth of the binary Merkle tree is at most 64
+    #[cfg(feature = "fault-proving")]
+    #[graphql(complexity = "64 * query_costs().storage_read + child_complexity")]
+    async fn da_compressed_block_proof(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Height of the block")] height: U32,
+    ) -> async_graphql::Result<Option<DaCompressedBlockProof>> {
+        let query = ctx.read_view()?;
+        Ok(query
+            .da_compressed_block_proof(&height.0.into())?
+            .map(Into::into))
+    }
+
+    /// Returns the heights from the `start` to the `end`(inclusive) that have no
+    /// DA compressed block, so the missing blocks can be backfilled.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator + end.0.saturating_sub(start.0) as usize\
+    }")]
+    async fn da_compression_gaps(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The first height of the range")] start: U32,
+        #[graphql(desc = "The last height of the range")] end: U32,
+    ) -> async_graphql::Result<Vec<U32>> {
+        let query = ctx.read_view()?;
+        let gaps = query.da_compression_gaps(start.into(), end.into())?;
+        Ok(gaps.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the compression statistics of the `last` DA compressed blocks,
+    /// starting from the latest one.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator\
+        + (last.0 as usize).saturating_mul(query_costs().storage_read)\
+    }")]
+    async fn da_compression_metrics(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The number of the latest compressed blocks")] last: U32,
+    ) -> async_graphql::Result<Vec<DaCompressionMetrics>> {
+        let query = ctx.read_view()?;
+        let last = usize::try_from(last.0)?;
+        let stats = query.da_compression_stats(last)?;
+        Ok(stats.into_iter().map(Into::into).collect())
+    }
+
+    /// Recomputes the checksum of the DA compressed block and compares it with
+    /// the stored one. Requires the `debug` feature to be enabled.
+    #[graphql(complexity = "query_costs().da_compressed_block_read")]
+    async fn verify_da_block(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Height of the block")] height: U32,
+    ) -> async_graphql::Result<Option<DaBlockVerification>> {
+        require_debug(ctx)?;
+        let query = ctx.read_view()?;
+        query
+            .verify_da_compressed_block(&height.0.into())
+            .into_api_result()
+    }
+
+    /// Returns the script code registered under the key in the temporal
+    /// registry of the DA compression. Requires the `debug` feature to be enabled.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn da_registry_script_code(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The key in the temporal registry")] key: U32,
+    ) -> async_graphql::Result<Option<DaRegistryCode>> {
+        require_debug(ctx)?;
+        let key = RegistryKey::try_from(key.0)?;
+        let query = ctx.read_view()?;
+        Ok(query.da_registry_script_code(&key)?.map(Into::into))
+    }
+
+    /// Returns the predicate code registered under the key in the temporal
+    /// registry of the DA compression. Requires the `debug` feature to be enabled.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn da_registry_predicate_code(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The key in the temporal registry")] key: U32,
+    ) -> async_graphql::Result<Option<DaRegistryCode>> {
+        require_debug(ctx)?;
+        let key = RegistryKey::try_from(key.0)?;
+        let query = ctx.read_view()?;
+        Ok(query.da_registry_predicate_code(&key)?.map(Into::into))
+    }
+
+    /// Returns the state of the evictor of each keyspace of the temporal registry
+    /// of the DA compression: the cursor and the `next` entries to be evicted.
+    /// The registry tables of the fault proving are read when it is enabled.
+    /// Requires the `debug` feature to be enabled.
+    #[graphql(complexity = "{\
+        query_costs().storage_read.saturating_mul(\
+            next.map(|next| next.0 as usize).unwrap_or(DEFAULT_EVICTION_CANDIDATES as usize)\
+                .saturating_add(1)\
+                .saturating_mul(da_compression::DA_REGISTRY_KEYSPACES.len())\
+        )\
+    }")]
+    async fn da_registry_evictor_state(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The number of the next entries to be evicted per keyspace")]
+        next: Option<U32>,
+    ) -> async_graphql::Result<Vec<DaRegistryEvictorState>> {
+        require_debug(ctx)?;
+        let next =
+            usize::try_from(next.map_or(DEFAULT_EVICTION_CANDIDATES, |next| next.0))?;
+        let capacity = ctx.data_unchecked::<GraphQLConfig>().da_registry_capacity;
+        let query = ctx.read_view()?;
+        let states = query.da_registry_evictor_state(next, capacity)?;
+        Ok(states.into_iter().map(Into::into).collect())
+    }
 }
@@ -1,4 +1,19 @@
-use async_graphql::Object;
+use crate::{
+    fuel_core_graphql_api::node_health::{
+        self,
+        NodeHealthReporter,
+    },
+    schema::scalars::{
+        U32,
+        U64,
+    },
+};
+use async_graphql::{
+    Context,
+    Enum,
+    Object,
+    SimpleObject,
+};
 
 #[derive(Default)]
 pub struct HealthQuery;
@@ -9,4 +24,111 @@ impl HealthQuery {
     async fn health(&self) -> bool {
         true
     }
+
+    /// Returns the health of the subsystems of the node, classified by
+    /// the lag behind the chain tip.
+    async fn node_health(&self, ctx: &Context<'_>) -> async_graphql::Result<NodeHealth> {
+        let reporter = ctx.data_unchecked::<NodeHealthReporter>();
+        Ok(reporter.node_health()?.into())
+    }
+}
+
+#[derive(Enum, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SubsystemStatus {
+    Ok,
+    /// The subsystem works, but lags behind or is close to its limits.
+    Degraded,
+    /// The subsystem doesn't make progress.
+    Failing,
+}
+
+/// The progress of the subsystem following the chain tip.
+#[derive(SimpleObject)]
+pub struct HeightHealth {
+    pub status: SubsystemStatus,
+    pub height: Option<U32>,
+    /// The number of blocks the subsystem lags behind the chain tip.
+    pub lag: U32,
+}
+
+#[derive(SimpleObject)]
+pub struct TxPoolHealth {
+    /// The pool is degraded once it is full.
+    pub status: SubsystemStatus,
+    pub tx_count: U64,
+    pub max_tx_count: U64,
+}
+
+#[derive(SimpleObject)]
+pub struct RelayerHealth {
+    /// The relayer is degraded until it is synced with the DA layer.
+    pub status: SubsystemStatus,
+    pub da_height: U64,
+    pub synced: bool,
+}
+
+#[derive(SimpleObject)]
+pub struct IndexationHealth {
+    /// The indexation is failing while a block is in the dead letter.
+    pub status: SubsystemStatus,
+    /// The height of the block that failed to be indexed, if any.
+    pub dead_letter_height: Option<U32>,
+}
+
+/// The health of the node. The disabled subsystems are `null`.
+#[derive(SimpleObject)]
+pub struct NodeHealth {
+    /// The worst status of the subsystems.
+    pub status: SubsystemStatus,
+    pub on_chain: HeightHealth,
+    pub off_chain: HeightHealth,
+    pub da_compression: Option<HeightHealth>,
+    pub txpool: TxPoolHealth,
+    pub relayer: Option<RelayerHealth>,
+    pub indexation: IndexationHealth,
+}
+
+impl From<node_health::SubsystemStatus> for SubsystemStatus {
+    fn from(status: node_health::SubsystemStatus) -> Self {
+        match status {
+            node_health::SubsystemStatus::Ok => Self::Ok,
+            node_health::SubsystemStatus::Degraded => Self::Degraded,
+            node_health::SubsystemStatus::Failing => Self::Failing,
+        }
+    }
+}
+
+impl From<node_health::HeightHealth> for HeightHealth {
+    fn from(health: node_health::HeightHealth) -> Self {
+        Self {
+            status: health.status.into(),
+            height: health.height.map(Into::into),
+            lag: health.lag.into(),
+        }
+    }
+}
+
+impl From<node_health::NodeHealth> for NodeHealth {
+    fn from(health: node_health::NodeHealth) -> Self {
+        Self {
+            status: health.status.into(),
+            on_chain: health.on_chain.into(),
+            off_chain: health.off_chain.into(),
+            da_compression: health.da_compression.map(Into::into),
+            txpool: TxPoolHealth {
+                status: health.txpool.status.into(),
+                tx_count: health.txpool.tx_count.into(),
+                max_tx_count: health.txpool.max_tx_count.into(),
+            },
+            relayer: health.relayer.map(|relayer| RelayerHealth {
+                status: relayer.status.into(),
+                da_height: relayer.da_height.into(),
+                synced: relayer.synced,
+            }),
+            indexation: IndexationHealth {
+                status: health.indexation.status.into(),
+                dead_letter_height: health.indexation.dead_letter_height.map(Into::into),
+            },
+        }
+    }
 }
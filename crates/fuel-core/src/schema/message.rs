@@ -11,11 +11,24 @@ use super::{
     ReadViewProvider,
 };
 use crate::{
-    fuel_core_graphql_api::query_costs,
-    graphql_api::IntoApiResult,
-    schema::scalars::{
-        BlockId,
-        U32,
+    fuel_core_graphql_api::{
+        query_costs,
+        Config as GraphQLConfig,
+    },
+    graphql_api::{
+        extensions::message_proof_cache::MessageProofCacheStatus,
+        message_proof_cache::MessageProofCache,
+        resolver_metrics,
+        storage::messages::MessageProofCacheKey,
+        IntoApiResult,
+    },
+    query::OwnedMessage,
+    schema::{
+        response_size::ByteBudget,
+        scalars::{
+            BlockId,
+            U32,
+        },
     },
 };
 use anyhow::anyhow;
@@ -27,12 +40,24 @@ use async_graphql::{
     Context,
     Enum,
     Object,
+    SimpleObject,
 };
 use fuel_core_services::stream::IntoBoxStream;
 use fuel_core_types::entities;
 use futures::StreamExt;
 
-pub struct Message(pub(crate) entities::relayer::message::Message);
+pub struct Message(
+    pub(crate) entities::relayer::message::Message,
+    pub(crate) Option<MessageSpentStatus>,
+);
+
+/// The block and the transaction that consumed the message.
+#[derive(SimpleObject)]
+pub struct MessageSpentStatus {
+    pub block_height: U32,
+    /// The transaction isn't known if the message wasn't consumed by an input.
+    pub transaction_id: Option<TransactionId>,
+}
 
 #[Object]
 impl Message {
@@ -59,6 +84,11 @@ impl Message {
     async fn da_height(&self) -> U64 {
         self.0.da_height().as_u64().into()
     }
+
+    /// Set for the spent messages returned with `includeSpent`.
+    async fn spent_status(&self) -> Option<&MessageSpentStatus> {
+        self.1.as_ref()
+    }
 }
 
 #[derive(Default)]
@@ -90,16 +120,33 @@ impl MessageQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
+        #[graphql(
+            desc = "Ends the page once the estimated size of its entries exceeds \
+            the budget in bytes. The page has at least one entry."
+        )]
+        max_bytes: Option<U32>,
+        #[graphql(desc = "Returns the spent messages of the `owner` too, with their \
+            `spentStatus`. Only the messages spent after the node started keeping \
+            them are returned.")]
+        include_spent: Option<bool>,
     ) -> async_graphql::Result<Connection<HexString, Message, EmptyFields, EmptyFields>>
     {
+        let max_page_size = ctx
+            .data_unchecked::<GraphQLConfig>()
+            .config
+            .max_page_sizes
+            .messages;
+        crate::schema::ensure_page_size("messages", first, last, max_page_size)?;
         let query = ctx.read_view()?;
         let owner = owner.map(|owner| owner.0);
+        let include_spent = include_spent.unwrap_or(false);
         let owner_ref = owner.as_ref();
-        crate::schema::query_pagination(
+        let messages = crate::schema::query_pagination_within_budget(
             after,
             before,
             first,
             last,
+            ByteBudget::new(max_bytes),
             |start: &Option<HexString>, direction| {
                 let start = if let Some(start) = start.clone() {
                     Some(start.try_into().map_err(|err| anyhow!("{}", err))?)
@@ -107,24 +154,77 @@ impl MessageQuery {
                     None
                 };
 
-                let messages = if let Some(owner) = owner_ref {
-                    query
+                let messages = match owner_ref {
+                    Some(owner) if include_spent => query
+                        .owned_messages_including_spent(owner, start, direction)
+                        .map(|result| result.map(Message::from))
+                        .into_boxed_ref(),
+                    Some(owner) => query
                         .owned_messages(owner, start, direction)
-                        .into_boxed_ref()
-                } else {
-                    query.all_messages(start, direction).into_boxed_ref()
+                        .map(|result| result.map(Message::from))
+                        .into_boxed_ref(),
+                    None => query
+                        .all_messages(start, direction)
+                        .map(|result| result.map(Message::from))
+                        .into_boxed_ref(),
                 };
 
                 let messages = messages.map(|result| {
                     result
-                        .map(|message| ((*message.nonce()).into(), message.into()))
+                        .map(|message| ((*message.0.nonce()).into(), message))
                         .map_err(Into::into)
                 });
 
                 Ok(messages)
             },
-        )
-        .await
+        );
+        resolver_metrics::instrument("messages", messages).await
+    }
+
+    /// Returns the unspent messages sent by the `sender` on the L1, in the order
+    /// of their nonces. The messages imported before the node started indexing
+    /// the senders are not returned.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator\
+        + (query_costs().storage_read + first.unwrap_or_default() as usize) * child_complexity \
+    }")]
+    async fn messages_by_sender(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "address of the sender on the L1")] sender: Address,
+        first: Option<i32>,
+        after: Option<String>,
+    ) -> async_graphql::Result<Connection<HexString, Message, EmptyFields, EmptyFields>>
+    {
+        let query = ctx.read_view()?;
+        let sender = sender.0;
+        let messages = crate::schema::query_pagination(
+            after,
+            None,
+            first,
+            None,
+            |start: &Option<HexString>, direction| {
+                let start = if let Some(start) = start.clone() {
+                    Some(start.try_into().map_err(|err| anyhow!("{}", err))?)
+                } else {
+                    None
+                };
+
+                let messages =
+                    query
+                        .messages_by_sender(&sender, start, direction)
+                        .map(|result| {
+                            result
+                                .map(|message| {
+                                    ((*message.nonce()).into(), message.into())
+                                })
+                                .map_err(Into::into)
+                        });
+
+                Ok(messages)
+            },
+        );
+        resolver_metrics::instrument("messages_by_sender", messages).await
     }
 
     // 256 * QUERY_COSTS.storage_read because the depth of the Merkle tree in the worst case is 256
@@ -150,13 +250,29 @@ impl MessageQuery {
             ))?,
         };
 
-        let proof = crate::query::message_proof(
-            query.as_ref(),
-            transaction_id.into(),
-            nonce.into(),
-            height,
+        let key = MessageProofCacheKey::new(transaction_id.into(), nonce.into(), height);
+        let cache = ctx.data_unchecked::<MessageProofCache>();
+        let (proof, cached) = cache.proof(
+            &key,
+            // The proof is invalid if the commit block at the height has changed.
+            |proof| {
+                let commit_block = query.block(&height)?;
+                Ok(proof.commit_block_header.id() == commit_block.header().id())
+            },
+            || {
+                crate::query::message_proof(
+                    query.as_ref(),
+                    key.transaction_id,
+                    key.nonce,
+                    height,
+                )
+            },
         )?;
 
+        if let Some(status) = ctx.data_opt::<MessageProofCacheStatus>() {
+            status.record(cached);
+        }
+
         Ok(MessageProof(proof))
     }
 
@@ -170,6 +286,13 @@ impl MessageQuery {
         let status = crate::query::message_status(query.as_ref(), nonce.into())?;
         Ok(status.into())
     }
+
+    /// Returns the total number of messages spent on the chain.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn spent_message_count(&self, ctx: &Context<'_>) -> async_graphql::Result<U64> {
+        let query = ctx.read_view()?;
+        Ok(query.spent_message_count()?.into())
+    }
 }
 pub struct MerkleProof(pub(crate) entities::relayer::message::MerkleProof);
 
@@ -232,7 +355,22 @@ impl MessageProof {
 
 impl From<entities::relayer::message::Message> for Message {
     fn from(message: entities::relayer::message::Message) -> Self {
-        Message(message)
+        Message(message, None)
+    }
+}
+
+impl From<OwnedMessage> for Message {
+    fn from(message: OwnedMessage) -> Self {
+        match message {
+            OwnedMessage::Unspent(message) => Message(message, None),
+            OwnedMessage::Spent(spent) => Message(
+                spent.message,
+                Some(MessageSpentStatus {
+                    block_height: spent.height.into(),
+                    transaction_id: spent.tx_id.map(Into::into),
+                }),
+            ),
+        }
     }
 }
 
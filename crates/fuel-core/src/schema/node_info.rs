@@ -1,4 +1,5 @@
 use super::scalars::{
+    Bytes32,
     U32,
     U64,
 };
@@ -14,12 +15,22 @@ use crate::{
             IndexationFlags,
             ReadDatabase,
         },
+        storage::{
+            Column,
+            U64_STATISTICS,
+        },
+    },
+    schema::{
+        dap::require_debug,
+        ReadViewProvider,
     },
 };
 use async_graphql::{
     Context,
     Object,
+    SimpleObject,
 };
+use fuel_core_storage::kv_store::StorageColumn;
 use std::time::UNIX_EPOCH;
 
 pub struct NodeInfo {
@@ -31,6 +42,9 @@ pub struct NodeInfo {
     max_depth: U64,
     node_version: String,
     indexation: IndexationFlags,
+    indexed_height: Option<U32>,
+    da_compression_height: Option<U32>,
+    off_chain_commit_duration_max: Option<OffChainCommitDuration>,
 }
 
 #[Object]
@@ -67,6 +81,23 @@ impl NodeInfo {
         &self.indexation
     }
 
+    /// The height of the last block indexed by the off-chain worker.
+    async fn indexed_height(&self) -> Option<U32> {
+        self.indexed_height
+    }
+
+    /// The height of the last block compressed for the DA. The compression
+    /// runs separately from the indexation, so it can lag behind the `indexed_height`.
+    async fn da_compression_height(&self) -> Option<U32> {
+        self.da_compression_height
+    }
+
+    /// The longest commit of the off-chain worker within the last 10,000 blocks.
+    /// It is tracked even if the metrics are disabled.
+    async fn off_chain_commit_duration_max(&self) -> Option<&OffChainCommitDuration> {
+        self.off_chain_commit_duration_max.as_ref()
+    }
+
     #[graphql(complexity = "query_costs().storage_read + child_complexity")]
     async fn tx_pool_stats(
         &self,
@@ -116,9 +147,95 @@ impl NodeQuery {
             max_size: (config.max_size as u64).into(),
             max_depth: (config.max_txpool_dependency_chain_length as u64).into(),
             node_version: VERSION.to_owned(),
+            indexed_height: db.indexed_height().map(Into::into),
+            da_compression_height: read_view.da_compression_height()?.map(Into::into),
+            off_chain_commit_duration_max: read_view.commit_duration_max()?.map(|max| {
+                OffChainCommitDuration {
+                    height: max.height.into(),
+                    duration_micros: u64::try_from(max.duration.as_micros())
+                        .unwrap_or(u64::MAX)
+                        .into(),
+                }
+            }),
             indexation: read_view.indexation_flags,
         })
     }
+
+    /// Returns the columns of the off-chain database available in this build.
+    /// Requires the `debug` feature to be enabled.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn offchain_columns(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<OffChainColumn>> {
+        require_debug(ctx)?;
+        let columns = enum_iterator::all::<Column>()
+            .map(|column| OffChainColumn {
+                name: column.name(),
+                id: column.id().into(),
+            })
+            .collect();
+        Ok(columns)
+    }
+
+    /// Returns the value of the statistic with the `name` tracked by the off-chain database.
+    /// The known names are listed by the `statistics`.
+    /// Requires the `debug` feature to be enabled.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn statistic(
+        &self,
+        ctx: &Context<'_>,
+        name: String,
+    ) -> async_graphql::Result<Option<U64>> {
+        require_debug(ctx)?;
+        if !U64_STATISTICS.contains(&name.as_str()) {
+            return Err(async_graphql::Error::new(format!(
+                "The statistic `{name}` is unknown"
+            )));
+        }
+        let query = ctx.read_view()?;
+        Ok(query.u64_statistic(&name)?.map(Into::into))
+    }
+
+    /// Returns the names of the statistics available via the `statistic`.
+    /// Requires the `debug` feature to be enabled.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn statistics(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<String>> {
+        require_debug(ctx)?;
+        Ok(U64_STATISTICS.iter().map(|name| name.to_string()).collect())
+    }
+
+    /// Returns the hash of all entries of the off-chain database at the latest indexed
+    /// height, except the node-specific metadata, statistics and caches. Two nodes
+    /// at the same height with the correct indexes return the same hash.
+    /// It reads the whole database. Requires the `debug` feature to be enabled.
+    #[graphql(complexity = "query_costs().storage_iterator")]
+    async fn offchain_state_hash(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Bytes32> {
+        require_debug(ctx)?;
+        let query = ctx.read_view()?;
+        Ok(query.offchain_state_hash()?.into())
+    }
+}
+
+/// The duration of the commit of the block indexes into the off-chain database.
+#[derive(SimpleObject)]
+pub struct OffChainCommitDuration {
+    /// The height of the committed block.
+    height: U32,
+    /// The duration of the commit in microseconds.
+    duration_micros: U64,
+}
+
+/// The column of the off-chain database.
+#[derive(SimpleObject)]
+pub struct OffChainColumn {
+    /// The name of the column.
+    name: String,
+    /// The id of the column. It depends on the features of the build.
+    id: U32,
 }
 
 struct PeerInfo(fuel_core_types::services::p2p::PeerInfo);
@@ -200,4 +317,14 @@ impl IndexationFlags {
     async fn asset_metadata(&self) -> bool {
         self.contains(&IndexationKind::AssetMetadata)
     }
+
+    /// Is contract balances indexation enabled
+    async fn contract_balances(&self) -> bool {
+        self.contains(&IndexationKind::ContractBalances)
+    }
+
+    /// Is the indexation of the number of owned coins per asset enabled
+    async fn owned_coin_counts(&self) -> bool {
+        self.contains(&IndexationKind::OwnedCoinCounts)
+    }
 }
@@ -0,0 +1,153 @@
+use crate::{
+    fuel_core_graphql_api::query_costs,
+    schema::{
+        block::{
+            Consensus,
+            Header,
+        },
+        scalars::{
+            BlockId,
+            HexString,
+            TransactionId,
+            U32,
+            U64,
+        },
+        tx::types::Transaction,
+        ReadViewProvider,
+    },
+};
+use async_graphql::{
+    Context,
+    Object,
+};
+use fuel_core_types::{
+    blockchain::block::CompressedBlock,
+    fuel_tx,
+    fuel_types::{
+        self,
+        canonical::Serialize,
+        BlockHeight,
+    },
+};
+
+#[derive(Default)]
+pub struct OldChainQuery;
+
+#[Object]
+impl OldChainQuery {
+    /// Returns the block with the lowest height retained from before the regenesis,
+    /// or `null` if the node doesn't retain the blocks of the old chain.
+    #[graphql(complexity = "query_costs().storage_iterator + child_complexity")]
+    async fn oldest_retained_block(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<PreRegenesisBlock>> {
+        let query = ctx.read_view()?;
+        let block = query.oldest_retained_block()?;
+        Ok(block.map(|block| PreRegenesisBlock {
+            block,
+            regenesis_height: query.genesis_height,
+        }))
+    }
+
+    /// Returns the transaction retained from before the regenesis. Unlike
+    /// `transaction`, only the transactions of the old chain are returned.
+    #[graphql(complexity = "query_costs().storage_read + child_complexity")]
+    async fn old_transaction(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] tx_id: TransactionId,
+    ) -> async_graphql::Result<Option<PreRegenesisTransaction>> {
+        let query = ctx.read_view()?;
+        let tx_id = tx_id.0;
+        let transaction = query.old_transaction(&tx_id)?;
+        Ok(transaction.map(|transaction| PreRegenesisTransaction {
+            tx_id,
+            transaction,
+            regenesis_height: query.genesis_height,
+        }))
+    }
+}
+
+/// A block of the chain from before the regenesis. The block was produced under
+/// the rules of the old chain, so its header may refer to the consensus parameters
+/// and the state transition bytecode unknown to the current chain.
+pub struct PreRegenesisBlock {
+    block: CompressedBlock,
+    regenesis_height: BlockHeight,
+}
+
+#[Object]
+impl PreRegenesisBlock {
+    async fn id(&self) -> BlockId {
+        let bytes: fuel_types::Bytes32 = self.block.header().id().into();
+        bytes.into()
+    }
+
+    async fn height(&self) -> U32 {
+        let height: u32 = (*self.block.header().height()).into();
+        height.into()
+    }
+
+    async fn header(&self) -> Header {
+        self.block.header().clone().into()
+    }
+
+    #[graphql(complexity = "query_costs().storage_read + child_complexity")]
+    async fn consensus(&self, ctx: &Context<'_>) -> async_graphql::Result<Consensus> {
+        let query = ctx.read_view()?;
+        let height = self.block.header().height();
+        Ok(query.consensus(height)?.try_into()?)
+    }
+
+    #[graphql(complexity = "query_costs().block_transactions_ids")]
+    async fn transaction_ids(&self) -> Vec<TransactionId> {
+        self.block
+            .transactions()
+            .iter()
+            .map(|tx_id| (*tx_id).into())
+            .collect()
+    }
+
+    /// The height of the genesis block of the current chain. The blocks below it
+    /// are from before the regenesis.
+    async fn regenesis_height(&self) -> U32 {
+        self.regenesis_height.into()
+    }
+}
+
+/// A transaction of the chain from before the regenesis. The transaction was
+/// validated under the consensus parameters of the old chain.
+pub struct PreRegenesisTransaction {
+    tx_id: fuel_tx::TxId,
+    transaction: fuel_tx::Transaction,
+    regenesis_height: BlockHeight,
+}
+
+#[Object]
+impl PreRegenesisTransaction {
+    async fn id(&self) -> TransactionId {
+        self.tx_id.into()
+    }
+
+    /// The canonical encoding of the transaction.
+    async fn raw_payload(&self) -> HexString {
+        HexString(self.transaction.to_bytes())
+    }
+
+    /// The size of the canonical encoding of the transaction in bytes.
+    async fn size(&self) -> U64 {
+        (self.transaction.size() as u64).into()
+    }
+
+    /// The transaction decoded with the types of the current chain.
+    async fn transaction(&self) -> Transaction {
+        Transaction::from_tx(self.tx_id, self.transaction.clone())
+    }
+
+    /// The height of the genesis block of the current chain. The transaction
+    /// is from a block below it.
+    async fn regenesis_height(&self) -> U32 {
+        self.regenesis_height.into()
+    }
+}
@@ -1,7 +1,11 @@
 use crate::{
-    fuel_core_graphql_api::query_costs,
+    fuel_core_graphql_api::{
+        query_costs,
+        Config as GraphQLConfig,
+    },
     schema::{
         scalars::{
+            Address,
             RelayedTransactionId,
             U32,
         },
@@ -9,14 +13,23 @@ use crate::{
     },
 };
 use async_graphql::{
+    connection::{
+        Connection,
+        EmptyFields,
+    },
     Context,
     Object,
     Union,
 };
+use fuel_core_storage::Error as StorageError;
 use fuel_core_types::{
     entities::relayer::transaction::RelayedTransactionStatus as FuelRelayedTransactionStatus,
-    fuel_types::BlockHeight,
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+    },
 };
+use futures::TryStreamExt;
 
 #[derive(Default)]
 pub struct RelayedTransactionQuery {}
@@ -33,6 +46,81 @@ impl RelayedTransactionQuery {
         let status = query.relayed_tx_status(id.0)?.map(|status| status.into());
         Ok(status)
     }
+
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator\
+        + (query_costs().storage_read + first.unwrap_or_default() as usize) * child_complexity \
+        + (query_costs().storage_read + last.unwrap_or_default() as usize) * child_complexity\
+    }")]
+    async fn relayed_transactions_by_owner(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The owner of the inputs spent by the relayed txs")]
+        owner: Address,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<RelayedTransactionId, RelayedTransaction, EmptyFields, EmptyFields>,
+    > {
+        let max_page_size = ctx
+            .data_unchecked::<GraphQLConfig>()
+            .config
+            .max_page_sizes
+            .relayed_transactions_by_owner;
+        crate::schema::ensure_page_size(
+            "relayedTransactionsByOwner",
+            first,
+            last,
+            max_page_size,
+        )?;
+        let query = ctx.read_view()?;
+        let query_ref = query.as_ref();
+        let owner = owner.0;
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<RelayedTransactionId>, direction| {
+                let start = start.as_ref().map(|id| id.0);
+                let relayed_txs = query_ref
+                    .owned_relayed_transaction_ids(&owner, start, direction)
+                    .try_filter_map(move |id| async move {
+                        // The statuses are pruned after the retention window,
+                        // while the ids in the owner's index are kept.
+                        let relayed_tx = query_ref.relayed_tx_status(id)?.map(|status| {
+                            let relayed_tx = RelayedTransaction {
+                                id,
+                                status: status.into(),
+                            };
+                            (id.into(), relayed_tx)
+                        });
+                        Ok::<_, StorageError>(relayed_tx)
+                    });
+
+                Ok(relayed_txs)
+            },
+        )
+        .await
+    }
+}
+
+pub struct RelayedTransaction {
+    id: Bytes32,
+    status: RelayedTransactionStatus,
+}
+
+#[Object]
+impl RelayedTransaction {
+    async fn id(&self) -> RelayedTransactionId {
+        self.id.into()
+    }
+
+    async fn status(&self) -> &RelayedTransactionStatus {
+        &self.status
+    }
 }
 
 #[derive(Union, Debug)]
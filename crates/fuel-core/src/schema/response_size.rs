@@ -0,0 +1,100 @@
+//! The estimates of the sizes of the entities in the responses, used to bound
+//! the pages of the paginated queries with the `maxBytes` argument.
+//!
+//! The estimate is the size of the JSON of the entity with all of its scalar fields.
+//! It doesn't depend on the selected fields, so the budget is conservative for
+//! the queries selecting only a few fields.
+
+use crate::schema::{
+    coins::Coin,
+    message::Message,
+    scalars::U32,
+    tx::types::Transaction,
+};
+use fuel_core_types::fuel_types::canonical::Serialize;
+
+/// The size of the name of the field with the quotes and the separators.
+const FIELD_OVERHEAD: usize = 24;
+/// The size of the integer scalar encoded as a string.
+const NUMBER_SIZE: usize = 22;
+
+/// The size of the `bytes` encoded as a hex string with the `0x` prefix.
+const fn hex_size(bytes: usize) -> usize {
+    bytes.saturating_mul(2).saturating_add(4)
+}
+
+pub(crate) trait EstimatedSize {
+    /// Returns the estimated size of the entity in the response in bytes.
+    fn estimated_size(&self) -> usize;
+}
+
+impl EstimatedSize for Coin {
+    fn estimated_size(&self) -> usize {
+        // `utxoId`, `owner`, `assetId`, `amount`, `blockCreated`, `txCreatedIdx`.
+        hex_size(34)
+            .saturating_add(hex_size(32).saturating_mul(2))
+            .saturating_add(NUMBER_SIZE.saturating_mul(3))
+            .saturating_add(FIELD_OVERHEAD.saturating_mul(6))
+    }
+}
+
+impl EstimatedSize for Message {
+    fn estimated_size(&self) -> usize {
+        // `sender`, `recipient`, `nonce`, `amount`, `daHeight`, `data`.
+        let size = hex_size(32)
+            .saturating_mul(3)
+            .saturating_add(NUMBER_SIZE.saturating_mul(2))
+            .saturating_add(hex_size(self.0.data().len()))
+            .saturating_add(FIELD_OVERHEAD.saturating_mul(6));
+        // `spentStatus` with the `blockHeight` and the `transactionId`.
+        match self.1 {
+            Some(_) => size
+                .saturating_add(hex_size(32))
+                .saturating_add(NUMBER_SIZE)
+                .saturating_add(FIELD_OVERHEAD.saturating_mul(3)),
+            None => size,
+        }
+    }
+}
+
+impl EstimatedSize for Transaction {
+    fn estimated_size(&self) -> usize {
+        // `id` and `rawPayload`; the other fields are derived from the payload.
+        hex_size(32)
+            .saturating_add(hex_size(self.0.size()))
+            .saturating_add(FIELD_OVERHEAD.saturating_mul(2))
+    }
+}
+
+/// The limit of the total estimated size of the entities of one page.
+pub(crate) struct ByteBudget<V> {
+    max_bytes: usize,
+    estimate: fn(&V) -> usize,
+    used: usize,
+}
+
+impl<V> ByteBudget<V>
+where
+    V: EstimatedSize,
+{
+    pub(crate) fn new(max_bytes: Option<U32>) -> Option<Self> {
+        max_bytes.map(|max_bytes| Self {
+            max_bytes: usize::try_from(u32::from(max_bytes)).unwrap_or(usize::MAX),
+            estimate: V::estimated_size,
+            used: 0,
+        })
+    }
+}
+
+impl<V> ByteBudget<V> {
+    /// Charges the `value` to the budget. Returns `false` if the `value` doesn't fit
+    /// into the budget. The first value always fits, so the page is never empty.
+    pub(crate) fn charge(&mut self, value: &V) -> bool {
+        let used = self.used.saturating_add((self.estimate)(value));
+        if self.used > 0 && used > self.max_bytes {
+            return false
+        }
+        self.used = used;
+        true
+    }
+}
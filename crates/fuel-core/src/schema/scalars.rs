@@ -79,6 +79,7 @@ macro_rules! number_scalar {
     };
 }
 
+number_scalar!(I128, i128, "I128");
 number_scalar!(U128, u128, "U128");
 number_scalar!(U64, u64, "U64");
 number_scalar!(U32, u32, "U32");
@@ -157,6 +158,87 @@ impl CursorType for SortedTxCursor {
     }
 }
 
+/// The cursor of the pending transactions in the `snapshot#position` form.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PendingTxCursor {
+    pub snapshot: u64,
+    pub position: u64,
+}
+
+impl CursorType for PendingTxCursor {
+    type Error = String;
+
+    fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
+        let (snapshot, position) =
+            s.split_once('#').ok_or("Incorrect format provided")?;
+
+        Ok(Self {
+            snapshot: snapshot.parse().map_err(|_| "Failed to decode snapshot")?,
+            position: position.parse().map_err(|_| "Failed to decode position")?,
+        })
+    }
+
+    fn encode_cursor(&self) -> String {
+        format!("{}#{}", self.snapshot, self.position)
+    }
+}
+
+/// The cursor of the owner's activity feed. It keeps the position in each of
+/// the owner's indexes in the `coin#message#transaction` form. The position is
+/// empty if the feed didn't take entries from the index yet.
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OwnerActivityCursor {
+    pub coin: Option<UtxoId>,
+    pub message: Option<Nonce>,
+    pub transaction: Option<TxPointer>,
+}
+
+impl CursorType for OwnerActivityCursor {
+    type Error = String;
+
+    fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
+        fn decode<T: CursorType<Error = String>>(s: &str) -> Result<Option<T>, String> {
+            if s.is_empty() {
+                Ok(None)
+            } else {
+                T::decode_cursor(s).map(Some)
+            }
+        }
+
+        let mut positions = s.split('#');
+        let (Some(coin), Some(message), Some(transaction), None) = (
+            positions.next(),
+            positions.next(),
+            positions.next(),
+            positions.next(),
+        ) else {
+            return Err("Incorrect format provided".to_string())
+        };
+
+        Ok(Self {
+            coin: decode(coin)?,
+            message: decode(message)?,
+            transaction: decode(transaction)?,
+        })
+    }
+
+    fn encode_cursor(&self) -> String {
+        fn encode<T: CursorType>(position: &Option<T>) -> String {
+            position
+                .as_ref()
+                .map(CursorType::encode_cursor)
+                .unwrap_or_default()
+        }
+
+        format!(
+            "{}#{}#{}",
+            encode(&self.coin),
+            encode(&self.message),
+            encode(&self.transaction)
+        )
+    }
+}
+
 #[derive(Clone, Debug, derive_more::Into, derive_more::From, PartialEq, Eq)]
 pub struct HexString(pub(crate) Vec<u8>);
 
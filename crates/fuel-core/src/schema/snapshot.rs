@@ -0,0 +1,79 @@
+use crate::{
+    fuel_core_graphql_api::{
+        api_service::ReadDatabase,
+        query_costs,
+    },
+    graphql_api::snapshot_views::SnapshotViews,
+    schema::scalars::{
+        U32,
+        U64,
+    },
+};
+use async_graphql::{
+    Context,
+    Object,
+    SimpleObject,
+};
+use std::time::{
+    Duration,
+    Instant,
+};
+
+/// The number of attempts to create the view while the off-chain database
+/// catches up with the latest block.
+const CONSISTENT_VIEW_ATTEMPTS: usize = 20;
+/// The delay between the attempts to create the view.
+const CONSISTENT_VIEW_RETRY_DELAY: Duration = Duration::from_millis(10);
+
+#[derive(Default)]
+pub struct SnapshotViewQuery;
+
+#[Object]
+impl SnapshotViewQuery {
+    /// Pins the view of the on-chain and off-chain databases at the latest block
+    /// height. The queries passing the returned token in the `snapshot_token`
+    /// extension of the request read from the pinned view, until it is released
+    /// after the TTL. The mutations and subscriptions always read the latest state.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn snapshot_view(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<SnapshotView> {
+        let database: &ReadDatabase = ctx.data_unchecked();
+        let snapshot_views: &SnapshotViews = ctx.data_unchecked();
+
+        let mut attempts = 0usize;
+        let (view, height) = loop {
+            if let Some(view) = database.consistent_view()? {
+                break view
+            }
+            attempts = attempts.saturating_add(1);
+            if attempts >= CONSISTENT_VIEW_ATTEMPTS {
+                return Err(anyhow::anyhow!(
+                    "The off-chain database is behind the latest block, try again later"
+                )
+                .into())
+            }
+            tokio::time::sleep(CONSISTENT_VIEW_RETRY_DELAY).await;
+        };
+
+        let token = snapshot_views.pin(view, height)?;
+        let expires_in = token.expires_at.saturating_duration_since(Instant::now());
+        Ok(SnapshotView {
+            token: token.token,
+            height: token.height.into(),
+            expires_in_seconds: expires_in.as_secs().into(),
+        })
+    }
+}
+
+/// The view of the database pinned at the block height.
+#[derive(SimpleObject)]
+pub struct SnapshotView {
+    /// The token to pass in the `snapshot_token` extension of the queries.
+    token: String,
+    /// The height of the block the view is pinned at.
+    height: U32,
+    /// The number of seconds until the view is released.
+    expires_in_seconds: U64,
+}
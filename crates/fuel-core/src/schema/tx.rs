@@ -11,6 +11,8 @@ use crate::{
             BlockProducer,
             ChainInfoProvider,
             DynTxStatusManager,
+            ReadDatabase,
+            TransactionTags,
             TxPool,
         },
         query_costs,
@@ -19,19 +21,28 @@ use crate::{
     },
     graphql_api::{
         database::ReadView,
+        pending_transactions::PendingTransactionsSnapshots,
         ports::MemoryPool,
+        resolver_metrics,
+        transaction_trees_cache::TransactionTreesCache,
     },
     query::{
         asset_query::Exclude,
         transaction_status_change,
+        OwnedTransactionsFilter,
         TxnStatusChangeState,
     },
     schema::{
         coins::ExcludeInput,
+        dap::require_debug,
         gas_price::EstimateGasPriceExt,
+        response_size::ByteBudget,
+        scalars,
         scalars::{
             Address,
+            ContractId,
             HexString,
+            PendingTxCursor,
             SortedTxCursor,
             TransactionId,
             TxPointer,
@@ -42,8 +53,14 @@ use crate::{
                 AssembleTx,
             },
             types::{
+                AddressTxCount,
                 AssembleTransactionResult,
+                PendingTransaction,
+                PendingTransactionsSort,
+                TransactionDirection,
+                TransactionInclusionProof,
                 TransactionStatus,
+                TransactionStatusFilter,
             },
         },
         ReadViewProvider,
@@ -53,9 +70,11 @@ use crate::{
 use async_graphql::{
     connection::{
         Connection,
+        CursorType,
         EmptyFields,
     },
     Context,
+    ErrorExtensions,
     Object,
     Subscription,
 };
@@ -67,6 +86,7 @@ use fuel_core_storage::{
     Result as StorageResult,
 };
 use fuel_core_tx_status_manager::TxStatusMessage;
+use fuel_core_txpool::PendingTransactionsOrder;
 use fuel_core_types::{
     blockchain::transaction::TransactionExt,
     fuel_tx::{
@@ -79,6 +99,7 @@ use fuel_core_types::{
     fuel_types::{
         self,
         canonical::Deserialize,
+        BlockHeight,
     },
     fuel_vm::checked_transaction::{
         CheckPredicateParams,
@@ -90,6 +111,11 @@ use futures::{
     Stream,
     TryStreamExt,
 };
+use predicate_estimation::{
+    estimate_predicates_per_input,
+    PredicateInputEstimation,
+    PredicatesEstimation,
+};
 use std::{
     borrow::Cow,
     future::Future,
@@ -105,6 +131,7 @@ use types::{
 mod assemble_tx;
 pub mod input;
 pub mod output;
+pub mod predicate_estimation;
 pub mod receipt;
 pub mod types;
 pub mod upgrade_purpose;
@@ -125,7 +152,7 @@ impl TxQuery {
         let txpool = ctx.data_unchecked::<TxPool>();
 
         if let Some(transaction) = txpool.transaction(id).await? {
-            Ok(Some(Transaction(transaction, id)))
+            Ok(Some(Transaction::from_tx(id, transaction)))
         } else {
             query
                 .transaction(&id)
@@ -134,6 +161,35 @@ impl TxQuery {
         }
     }
 
+    /// Returns the proof that the transaction is included in its block, verifiable
+    /// against the `transactionsRoot` of the returned block header.
+    /// Fails if the transaction is not included in any block.
+    #[graphql(complexity = "query_costs().tx_get + child_complexity")]
+    async fn transaction_inclusion_proof(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] tx_id: TransactionId,
+    ) -> async_graphql::Result<TransactionInclusionProof> {
+        let query = ctx.read_view()?;
+        let trees = ctx.data_unchecked::<TransactionTreesCache>();
+        let proof = query.transaction_inclusion_proof(&tx_id.0, trees).await?;
+        Ok(proof.into())
+    }
+
+    /// Returns whether each of the transactions is included in a block, in the order
+    /// of the `tx_ids`. It only checks the existence of the execution statuses, so it
+    /// is cheaper than fetching the status of each transaction.
+    #[graphql(complexity = "query_costs().storage_read * tx_ids.len()")]
+    async fn transactions_included(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The IDs of the transactions")] tx_ids: Vec<scalars::Bytes32>,
+    ) -> async_graphql::Result<Vec<bool>> {
+        let query = ctx.read_view()?;
+        let tx_ids = tx_ids.into_iter().map(|id| id.0).collect::<Vec<_>>();
+        Ok(query.txs_included(&tx_ids)?)
+    }
+
     // We assume that each block has 100 transactions.
     #[graphql(complexity = "{\
         (query_costs().tx_get + child_complexity) \
@@ -219,6 +275,17 @@ impl TxQuery {
         .await
     }
 
+    /// Returns the transactions related to the `owner`, ordered by the block height
+    /// and the index within the block. The pages requested with `first` start from
+    /// the oldest transactions, and the pages requested with `last` start from
+    /// the newest ones. The cursor is the position of the transaction, so the cursor
+    /// returned in one direction can be passed to the other one.
+    /// The transactions included after the cursor was returned don't shift
+    /// the pages requested with it, because the positions never change.
+    ///
+    /// The `statusFilter` and `direction` skip the non-matching transactions,
+    /// which still count towards the scan limit of the query. The transactions
+    /// indexed before the direction was tracked match any `direction`.
     #[graphql(complexity = "{\
         query_costs().storage_iterator\
         + (query_costs().storage_read + first.unwrap_or_default() as usize) * child_complexity \
@@ -232,34 +299,188 @@ impl TxQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
+        #[graphql(
+            desc = "Ends the page once the estimated size of its entries exceeds \
+            the budget in bytes. The page has at least one entry."
+        )]
+        max_bytes: Option<U32>,
+        status_filter: Option<TransactionStatusFilter>,
+        #[graphql(default)] direction: TransactionDirection,
     ) -> async_graphql::Result<Connection<TxPointer, Transaction, EmptyFields, EmptyFields>>
     {
         use futures::stream::StreamExt;
+        let max_page_size = ctx
+            .data_unchecked::<GraphQLConfig>()
+            .config
+            .max_page_sizes
+            .transactions_by_owner;
+        crate::schema::ensure_page_size(
+            "transactionsByOwner",
+            first,
+            last,
+            max_page_size,
+        )?;
         let query = ctx.read_view()?;
         let params = ctx
             .data_unchecked::<ChainInfoProvider>()
             .current_consensus_params();
         let owner = fuel_types::Address::from(owner);
+        let filter = OwnedTransactionsFilter {
+            status: status_filter.map(Into::into),
+            direction: direction.into(),
+        };
 
-        crate::schema::query_pagination(
+        let txs = crate::schema::query_pagination_within_budget(
             after,
             before,
             first,
             last,
+            ByteBudget::new(max_bytes),
             |start: &Option<TxPointer>, direction| {
                 let start = (*start).map(Into::into);
-                let txs =
-                    query
-                        .owned_transactions(owner, start, direction)
-                        .map(|result| {
-                            result.map(|(cursor, tx)| {
-                                let tx_id = tx.id(&params.chain_id());
-                                (cursor.into(), Transaction::from_tx(tx_id, tx))
-                            })
-                        });
+                let txs = query
+                    .owned_transactions(owner, start, direction, filter)
+                    .map(|result| {
+                        result.map(|(cursor, tx)| {
+                            let tx_id = tx.id(&params.chain_id());
+                            (cursor.into(), Transaction::from_tx(tx_id, tx))
+                        })
+                    });
                 Ok(txs)
             },
-        )
+        );
+        resolver_metrics::instrument("transactionsByOwner", txs).await
+    }
+
+    /// Returns the transactions that reference the contract in their inputs or outputs,
+    /// ordered by the block height and the index within the block. The transaction
+    /// that references the contract several times is returned once.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator\
+        + (query_costs().storage_read + first.unwrap_or_default() as usize) * child_complexity \
+        + (query_costs().storage_read + last.unwrap_or_default() as usize) * child_complexity\
+    }")]
+    async fn transactions_by_contract(
+        &self,
+        ctx: &Context<'_>,
+        contract_id: ContractId,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<TxPointer, Transaction, EmptyFields, EmptyFields>>
+    {
+        use futures::stream::StreamExt;
+        let query = ctx.read_view()?;
+        let params = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params();
+        let contract_id = fuel_types::ContractId::from(contract_id);
+
+        let txs = crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<TxPointer>, direction| {
+                let start = (*start).map(Into::into);
+                let txs = query
+                    .contract_transactions(contract_id, start, direction)
+                    .map(|result| {
+                        result.map(|(cursor, tx)| {
+                            let tx_id = tx.id(&params.chain_id());
+                            (cursor.into(), Transaction::from_tx(tx_id, tx))
+                        })
+                    });
+                Ok(txs)
+            },
+        );
+        resolver_metrics::instrument("transactionsByContract", txs).await
+    }
+
+    /// Returns the most active addresses sorted by the number of transactions.
+    /// Only a bounded number of addresses is tracked, so the result is approximate
+    /// for the addresses below the tracked threshold.
+    #[graphql(complexity = "query_costs().storage_read + child_complexity")]
+    async fn top_addresses_by_tx_count(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The maximum number of addresses to return")] limit: U32,
+    ) -> async_graphql::Result<Vec<AddressTxCount>> {
+        let query = ctx.read_view()?;
+        let limit = usize::try_from(limit.0)?;
+        let top_addresses = query
+            .top_addresses_by_tx_count(limit)?
+            .into_iter()
+            .map(AddressTxCount)
+            .collect();
+        Ok(top_addresses)
+    }
+
+    /// Returns the transactions in the TxPool in the `sort` order. The first page
+    /// takes the snapshot of the TxPool, and the cursors of the next pages point
+    /// to it, so the pages don't shift while the TxPool changes. The snapshot
+    /// includes the transactions that wait for their dependencies.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator\
+        + first.unwrap_or_default() as usize * child_complexity \
+    }")]
+    async fn pending_transactions(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        #[graphql(default)] sort: PendingTransactionsSort,
+    ) -> async_graphql::Result<
+        Connection<PendingTxCursor, PendingTransaction, EmptyFields, EmptyFields>,
+    > {
+        let snapshots = ctx.data_unchecked::<PendingTransactionsSnapshots>();
+        let order: PendingTransactionsOrder = sort.into();
+        let cursor = after
+            .as_deref()
+            .map(PendingTxCursor::decode_cursor)
+            .transpose()
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        let (snapshot_id, snapshot) = match cursor {
+            Some(cursor) => {
+                let snapshot = snapshots.get(cursor.snapshot).ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "The snapshot of the pending transactions expired, \
+                         start the pagination over"
+                    )
+                })?;
+                if snapshot.order != order {
+                    return Err(anyhow::anyhow!(
+                        "The `sort` differs from the one of the first page"
+                    )
+                    .into())
+                }
+                (cursor.snapshot, snapshot)
+            }
+            None => {
+                let txpool = ctx.data_unchecked::<TxPool>();
+                let transactions = txpool.pending_transactions(order).await?;
+                snapshots.insert(order, &transactions)
+            }
+        };
+        let skip = cursor
+            .map(|cursor| usize::try_from(cursor.position))
+            .transpose()?
+            .unwrap_or_default();
+
+        crate::schema::query_pagination(after, None, first, None, |_, _| {
+            let entries = snapshot.transactions.iter().enumerate().skip(skip).map(
+                |(position, transaction)| {
+                    let cursor = PendingTxCursor {
+                        snapshot: snapshot_id,
+                        position: position as u64,
+                    };
+                    Ok((cursor, PendingTransaction(*transaction)))
+                },
+            );
+            Ok(futures::stream::iter(entries))
+        })
         .await
     }
 
@@ -444,6 +665,31 @@ impl TxQuery {
         Ok(Transaction::from_tx(tx.id(&chain_id), tx))
     }
 
+    /// Estimate the predicate gas for the provided transaction and report
+    /// the gas used or the failure reason of each predicate input.
+    /// Unlike `estimate_predicates`, it doesn't fail if some predicates fail.
+    #[graphql(complexity = "query_costs().estimate_predicates + child_complexity")]
+    async fn estimate_predicates_breakdown(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+    ) -> async_graphql::Result<PredicatesEstimation> {
+        let query = ctx.read_view()?.into_owned();
+
+        let tx = FuelTx::from_bytes(&tx.0)?;
+
+        let (tx, predicates) = ctx.estimate_predicates_breakdown(tx, query).await?;
+        let chain_id = ctx
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params()
+            .chain_id();
+
+        Ok(PredicatesEstimation {
+            transaction: Transaction::from_tx(tx.id(&chain_id), tx),
+            predicates: predicates.into_iter().map(Into::into).collect(),
+        })
+    }
+
     #[cfg(feature = "test-helpers")]
     /// Returns all possible receipts for test purposes.
     async fn all_receipts(&self) -> Vec<receipt::Receipt> {
@@ -469,6 +715,10 @@ impl TxQuery {
         // This can be used to run the dry-run on top of a past block.
         // Requires `--historical-execution` flag to be enabled.
         block_height: Option<U32>,
+        // This can be used to run the dry-run on top of the state after the block
+        // at this height, if the state is still retained by the node.
+        // Requires `--historical-execution` flag to be enabled.
+        at_height: Option<U32>,
     ) -> async_graphql::Result<Vec<DryRunTransactionExecutionStatus>> {
         let config = ctx.data_unchecked::<GraphQLConfig>().clone();
         let block_producer = ctx.data_unchecked::<BlockProducer>();
@@ -484,6 +734,51 @@ impl TxQuery {
             .into());
         }
 
+        if at_height.is_some() && !config.historical_execution {
+            return Err(anyhow::anyhow!(
+                "The `atHeight` parameter requires the `--historical-execution` option"
+            )
+            .into());
+        }
+
+        if block_height.is_some() && at_height.is_some() {
+            return Err(anyhow::anyhow!(
+                "The `blockHeight` and `atHeight` parameters can't be used together"
+            )
+            .into());
+        }
+
+        if let Some(at_height) = at_height {
+            let at_height: BlockHeight = at_height.into();
+            let database: &ReadDatabase = ctx.data_unchecked();
+            let within_chain = database
+                .on_chain_height()
+                .is_some_and(|latest_height| at_height <= latest_height);
+
+            if !within_chain || !database.on_chain_state_available_at(at_height) {
+                return Err(state_unavailable_error(
+                    at_height,
+                    database.earliest_on_chain_state_height(),
+                ))
+            }
+        }
+
+        // The state after the block at `atHeight` is the state
+        // on top of which the next block is produced.
+        let (block_height, state_height) = match at_height {
+            Some(at_height) => {
+                let at_height: BlockHeight = at_height.into();
+                let next_height = at_height.succ().ok_or_else(|| {
+                    anyhow::anyhow!("The `atHeight` parameter is too large")
+                })?;
+                (Some(next_height), Some(at_height))
+            }
+            None => {
+                let block_height: Option<BlockHeight> = block_height.map(Into::into);
+                (block_height, block_height.and_then(|height| height.pred()))
+            }
+        };
+
         let mut transactions = txs
             .iter()
             .map(|tx| FuelTx::from_bytes(&tx.0))
@@ -501,7 +796,7 @@ impl TxQuery {
         let tx_statuses = block_producer
             .dry_run_txs(
                 transactions,
-                block_height.map(|x| x.into()),
+                block_height,
                 None, // TODO(#1749): Pass parameter from API
                 utxo_validation,
                 gas_price.map(|x| x.into()),
@@ -509,7 +804,10 @@ impl TxQuery {
             .await?;
         let tx_statuses = tx_statuses
             .into_iter()
-            .map(|(_, status)| DryRunTransactionExecutionStatus(status))
+            .map(|(_, status)| DryRunTransactionExecutionStatus {
+                status,
+                state_height,
+            })
             .collect();
 
         Ok(tx_statuses)
@@ -541,6 +839,28 @@ impl TxQuery {
     }
 }
 
+/// The error returned when the node doesn't retain the state after the block
+/// at the `requested_height`, with the earliest height that can be requested.
+fn state_unavailable_error(
+    requested_height: BlockHeight,
+    earliest_height: Option<BlockHeight>,
+) -> async_graphql::Error {
+    let message = match earliest_height {
+        Some(earliest_height) => format!(
+            "The state at the height {requested_height} is unavailable, \
+            the earliest available height is {earliest_height}"
+        ),
+        None => format!("The state at the height {requested_height} is unavailable"),
+    };
+    async_graphql::Error::new(message).extend_with(|_, extensions| {
+        extensions.set("code", "STATE_UNAVAILABLE");
+        extensions.set("requestedHeight", u32::from(requested_height));
+        if let Some(earliest_height) = earliest_height {
+            extensions.set("earliestAvailableHeight", u32::from(earliest_height));
+        }
+    })
+}
+
 #[derive(Default)]
 pub struct TxMutation;
 
@@ -564,8 +884,16 @@ impl TxMutation {
         // Requires `--historical-execution` flag to be enabled.
         block_height: Option<U32>,
     ) -> async_graphql::Result<Vec<DryRunTransactionExecutionStatus>> {
-        TxQuery::dry_run(&TxQuery, ctx, txs, utxo_validation, gas_price, block_height)
-            .await
+        TxQuery::dry_run(
+            &TxQuery,
+            ctx,
+            txs,
+            utxo_validation,
+            gas_price,
+            block_height,
+            None,
+        )
+        .await
     }
 
     /// Submits transaction to the `TxPool`.
@@ -597,9 +925,56 @@ impl TxMutation {
             .chain_id();
         let id = tx.id(&chain_id);
 
-        let tx = Transaction(tx, id);
+        let tx = Transaction::from_tx(id, tx);
         Ok(tx)
     }
+
+    /// Attaches the `tag` to the transaction, replacing the previous tag, and returns
+    /// `true`. The tag is the off-chain annotation for the correlation of the transaction
+    /// with the records of the integrator, e.g. the id of the internal request.
+    /// It is returned by the `tag` field of the transaction and isn't affected
+    /// by the updates of the status. Only the transactions with a known status
+    /// can be tagged. Requires the `debug` feature of the node.
+    async fn tag_transaction(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+        #[graphql(desc = "The tag of the transaction")] tag: HexString,
+    ) -> async_graphql::Result<bool> {
+        require_debug(ctx)?;
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let max_tag_size = config.config.max_transaction_tag_size;
+        if max_tag_size == 0 {
+            return Err(
+                anyhow::anyhow!("The tagging of the transactions is disabled").into(),
+            )
+        }
+        if tag.0.len() > max_tag_size {
+            return Err(anyhow::anyhow!(
+                "The tag of {} bytes exceeds the limit of {max_tag_size} bytes",
+                tag.0.len()
+            )
+            .into())
+        }
+
+        let id = id.0;
+        let status = match ctx.read_view()?.tx_status(&id) {
+            Ok(_) => true,
+            Err(StorageError::NotFound(_, _)) => ctx
+                .data_unchecked::<DynTxStatusManager>()
+                .status(id)
+                .await?
+                .is_some(),
+            Err(err) => return Err(err.into()),
+        };
+        if !status {
+            return Err(anyhow::anyhow!("The transaction {id} is unknown").into())
+        }
+
+        ctx.data_unchecked::<TransactionTags>()
+            .tag_transaction(&id, &tag.0)?;
+        Ok(true)
+    }
 }
 
 #[derive(Default)]
@@ -861,6 +1236,12 @@ pub trait ContextExt {
         tx: FuelTx,
         query: impl PredicateStorageRequirements + Send + Sync + 'static,
     ) -> impl Future<Output = anyhow::Result<FuelTx>> + Send;
+
+    fn estimate_predicates_breakdown(
+        &self,
+        tx: FuelTx,
+        query: impl PredicateStorageRequirements + Send + Sync + 'static,
+    ) -> impl Future<Output = anyhow::Result<(FuelTx, Vec<PredicateInputEstimation>)>> + Send;
 }
 
 impl<'a> ContextExt for Context<'a> {
@@ -916,4 +1297,27 @@ impl<'a> ContextExt for Context<'a> {
 
         Ok(tx)
     }
+
+    async fn estimate_predicates_breakdown(
+        &self,
+        mut tx: FuelTx,
+        query: impl PredicateStorageRequirements + Send + Sync + 'static,
+    ) -> anyhow::Result<(FuelTx, Vec<PredicateInputEstimation>)> {
+        let params = self
+            .data_unchecked::<ChainInfoProvider>()
+            .current_consensus_params();
+
+        let memory_pool = self.data_unchecked::<SharedMemoryPool>();
+        let memory = memory_pool.get_memory().await;
+
+        let parameters = CheckPredicateParams::from(params.as_ref());
+        let result = tokio_rayon::spawn_fifo(move || {
+            let predicates =
+                estimate_predicates_per_input(&mut tx, &parameters, memory, &query);
+            (tx, predicates)
+        })
+        .await;
+
+        Ok(result)
+    }
 }
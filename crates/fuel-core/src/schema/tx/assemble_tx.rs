@@ -121,6 +121,7 @@ impl<'a> AssembleArguments<'a> {
                 remaining_input_slots,
             )
             .await?
+            .coins
             .into_iter()
             .next();
 
@@ -0,0 +1,324 @@
+use crate::schema::{
+    scalars::{
+        U16,
+        U64,
+    },
+    tx::types::Transaction,
+};
+use async_graphql::SimpleObject;
+use fuel_core_types::{
+    fuel_tx::{
+        field::Inputs,
+        input::{
+            coin::CoinPredicate,
+            message::{
+                MessageCoinPredicate,
+                MessageDataPredicate,
+            },
+        },
+        Input,
+        Transaction as FuelTx,
+        Word,
+    },
+    fuel_vm::{
+        checked_transaction::{
+            CheckPredicateParams,
+            EstimatePredicates,
+        },
+        interpreter::Memory,
+        predicate::PredicateStorageRequirements,
+    },
+};
+
+/// The result of the estimation of the predicate of the single input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PredicateInputEstimation {
+    pub input_index: u16,
+    /// The gas used by the predicate, `None` if the predicate has failed.
+    pub gas_used: Option<u64>,
+    /// The reason of the failure, `None` if the predicate has succeeded.
+    pub failure_reason: Option<String>,
+}
+
+/// Estimates the predicates of the transaction and reports the result of each
+/// predicate input.
+///
+/// The VM stops the estimation on the first failed predicate, so if the
+/// estimation of the whole transaction fails, each predicate is estimated
+/// separately. During the separate estimation, the other predicate inputs
+/// are replaced with the signed inputs spending the same coins, so only one
+/// predicate is executed per run. The gas of the succeeded predicates is
+/// filled in the transaction even if some other predicate has failed.
+pub fn estimate_predicates_per_input<M, S>(
+    tx: &mut FuelTx,
+    params: &CheckPredicateParams,
+    mut memory: M,
+    storage: &S,
+) -> Vec<PredicateInputEstimation>
+where
+    M: Memory,
+    S: PredicateStorageRequirements,
+{
+    let predicate_indexes: Vec<usize> = inputs_mut(tx)
+        .map(|inputs| {
+            inputs
+                .iter()
+                .enumerate()
+                .filter(|(_, input)| input.predicate().is_some())
+                .map(|(index, _)| index)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if predicate_indexes.is_empty() {
+        return vec![]
+    }
+
+    let mut estimated_tx = tx.clone();
+    if estimated_tx
+        .estimate_predicates(params, &mut memory, storage)
+        .is_ok()
+    {
+        *tx = estimated_tx;
+        let inputs = inputs_mut(tx).map(|inputs| &*inputs);
+        return predicate_indexes
+            .into_iter()
+            .map(|index| PredicateInputEstimation {
+                input_index: input_index(index),
+                gas_used: inputs
+                    .and_then(|inputs| inputs.get(index))
+                    .and_then(Input::predicate_gas_used),
+                failure_reason: None,
+            })
+            .collect()
+    }
+
+    let mut estimations = Vec::with_capacity(predicate_indexes.len());
+    for &index in &predicate_indexes {
+        let mut isolated_tx = tx.clone();
+        if let Some(inputs) = inputs_mut(&mut isolated_tx) {
+            for &other in predicate_indexes.iter().filter(|other| **other != index) {
+                if let Some(input) = inputs.get_mut(other) {
+                    if let Some(signed) = signed_input(input) {
+                        *input = signed;
+                    }
+                }
+            }
+        }
+
+        let result = isolated_tx.estimate_predicates(params, &mut memory, storage);
+        let estimation = match result {
+            Ok(()) => {
+                let gas_used = inputs_mut(&mut isolated_tx)
+                    .and_then(|inputs| inputs.get(index))
+                    .and_then(Input::predicate_gas_used);
+                if let (Some(gas_used), Some(input)) = (
+                    gas_used,
+                    inputs_mut(tx).and_then(|inputs| inputs.get_mut(index)),
+                ) {
+                    set_predicate_gas_used(input, gas_used);
+                }
+                PredicateInputEstimation {
+                    input_index: input_index(index),
+                    gas_used,
+                    failure_reason: None,
+                }
+            }
+            Err(err) => PredicateInputEstimation {
+                input_index: input_index(index),
+                gas_used: None,
+                failure_reason: Some(format!("{:?}", err)),
+            },
+        };
+        estimations.push(estimation);
+    }
+
+    estimations
+}
+
+fn input_index(index: usize) -> u16 {
+    u16::try_from(index).expect("The number of inputs is limited by `u16`; qed")
+}
+
+fn inputs_mut(tx: &mut FuelTx) -> Option<&mut Vec<Input>> {
+    match tx {
+        FuelTx::Script(tx) => Some(tx.inputs_mut()),
+        FuelTx::Create(tx) => Some(tx.inputs_mut()),
+        FuelTx::Upgrade(tx) => Some(tx.inputs_mut()),
+        FuelTx::Upload(tx) => Some(tx.inputs_mut()),
+        FuelTx::Blob(tx) => Some(tx.inputs_mut()),
+        FuelTx::Mint(_) => None,
+    }
+}
+
+fn set_predicate_gas_used(input: &mut Input, gas_used: Word) {
+    match input {
+        Input::CoinPredicate(CoinPredicate {
+            predicate_gas_used, ..
+        })
+        | Input::MessageCoinPredicate(MessageCoinPredicate {
+            predicate_gas_used, ..
+        })
+        | Input::MessageDataPredicate(MessageDataPredicate {
+            predicate_gas_used, ..
+        }) => {
+            *predicate_gas_used = gas_used;
+        }
+        _ => {}
+    }
+}
+
+/// Returns the signed input spending the same coin or message as the predicate input.
+fn signed_input(input: &Input) -> Option<Input> {
+    match input {
+        Input::CoinPredicate(coin) => Some(Input::coin_signed(
+            coin.utxo_id,
+            coin.owner,
+            coin.amount,
+            coin.asset_id,
+            coin.tx_pointer,
+            0,
+        )),
+        Input::MessageCoinPredicate(message) => Some(Input::message_coin_signed(
+            message.sender,
+            message.recipient,
+            message.amount,
+            message.nonce,
+            0,
+        )),
+        Input::MessageDataPredicate(message) => Some(Input::message_data_signed(
+            message.sender,
+            message.recipient,
+            message.amount,
+            message.nonce,
+            0,
+            message.data.to_vec(),
+        )),
+        _ => None,
+    }
+}
+
+/// The transaction with the estimated predicates and the result of each predicate.
+#[derive(SimpleObject)]
+pub struct PredicatesEstimation {
+    /// The transaction with the gas of the succeeded predicates filled in.
+    pub transaction: Transaction,
+    /// The results of the predicate inputs in the order of the inputs.
+    pub predicates: Vec<PredicateEstimation>,
+}
+
+/// The result of the estimation of the predicate of the input.
+#[derive(SimpleObject)]
+pub struct PredicateEstimation {
+    /// The index of the predicate input in the transaction.
+    pub input_index: U16,
+    /// Whether the predicate has returned `true`.
+    pub success: bool,
+    /// The gas used by the predicate, `null` if the predicate has failed.
+    pub gas_used: Option<U64>,
+    /// The reason of the failure, `null` if the predicate has succeeded.
+    pub failure_reason: Option<String>,
+}
+
+impl From<PredicateInputEstimation> for PredicateEstimation {
+    fn from(value: PredicateInputEstimation) -> Self {
+        Self {
+            input_index: value.input_index.into(),
+            success: value.failure_reason.is_none(),
+            gas_used: value.gas_used.map(Into::into),
+            failure_reason: value.failure_reason,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use fuel_core_types::{
+        fuel_asm::{
+            op,
+            RegId,
+        },
+        fuel_tx::{
+            ConsensusParameters,
+            TransactionBuilder,
+        },
+        fuel_vm::{
+            interpreter::MemoryInstance,
+            predicate::EmptyStorage,
+        },
+    };
+
+    fn predicate_input(predicate: Vec<u8>, seed: u8) -> Input {
+        let owner = Input::predicate_owner(&predicate);
+        Input::coin_predicate(
+            [seed; 32].into(),
+            owner,
+            100,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            predicate,
+            vec![],
+        )
+    }
+
+    #[test]
+    fn estimate_predicates_per_input__reports_each_predicate_when_one_fails() {
+        // given
+        let succeeding = op::ret(RegId::ONE).to_bytes().to_vec();
+        let failing = op::ret(RegId::ZERO).to_bytes().to_vec();
+        let mut tx: FuelTx = TransactionBuilder::script(vec![], vec![])
+            .add_input(predicate_input(failing, 1))
+            .add_input(predicate_input(succeeding, 2))
+            .finalize_as_transaction();
+        let params = CheckPredicateParams::from(&ConsensusParameters::standard());
+
+        // when
+        let estimations = estimate_predicates_per_input(
+            &mut tx,
+            &params,
+            MemoryInstance::new(),
+            &EmptyStorage,
+        );
+
+        // then
+        assert_eq!(estimations.len(), 2);
+        assert_eq!(estimations[0].input_index, 0);
+        assert!(estimations[0].gas_used.is_none());
+        assert!(estimations[0].failure_reason.is_some());
+        assert_eq!(estimations[1].input_index, 1);
+        assert!(estimations[1].failure_reason.is_none());
+        let gas_used = estimations[1].gas_used.expect("Predicate succeeded");
+        assert!(gas_used > 0);
+        let inputs = inputs_mut(&mut tx).unwrap();
+        assert_eq!(inputs[1].predicate_gas_used(), Some(gas_used));
+    }
+
+    #[test]
+    fn estimate_predicates_per_input__fills_the_gas_when_all_predicates_succeed() {
+        // given
+        let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+        let mut tx: FuelTx = TransactionBuilder::script(vec![], vec![])
+            .add_input(predicate_input(predicate.clone(), 1))
+            .add_input(predicate_input(predicate, 2))
+            .finalize_as_transaction();
+        let params = CheckPredicateParams::from(&ConsensusParameters::standard());
+
+        // when
+        let estimations = estimate_predicates_per_input(
+            &mut tx,
+            &params,
+            MemoryInstance::new(),
+            &EmptyStorage,
+        );
+
+        // then
+        assert_eq!(estimations.len(), 2);
+        assert!(estimations
+            .iter()
+            .all(|estimation| estimation.failure_reason.is_none()
+                && estimation.gas_used.unwrap_or_default() > 0));
+    }
+}
@@ -9,12 +9,25 @@ use crate::{
         api_service::ChainInfoProvider,
         database::ReadView,
         query_costs,
-        IntoApiResult,
+        tx_status_loader::TransactionStatusLoader,
     },
-    graphql_api::api_service::DynTxStatusManager,
+    graphql_api::{
+        api_service::DynTxStatusManager,
+        pending_transactions,
+        storage::{
+            statistic::AddressTxCount as StorageAddressTxCount,
+            transactions::OwnedTransactionDirection,
+        },
+    },
+    query,
     schema::{
-        block::Block,
+        block::{
+            Block,
+            Header,
+        },
+        message::MerkleProof,
         scalars::{
+            Address,
             AssetId,
             BlobId,
             Bytes32,
@@ -43,6 +56,7 @@ use async_graphql::{
     Union,
 };
 use fuel_core_storage::Error as StorageError;
+use fuel_core_txpool::PendingTransactionsOrder;
 use fuel_core_types::{
     fuel_tx::{
         self,
@@ -76,7 +90,10 @@ use fuel_core_types::{
         Executable,
         TxId,
     },
-    fuel_types::canonical::Serialize,
+    fuel_types::{
+        canonical::Serialize,
+        BlockHeight,
+    },
     fuel_vm::ProgramState as VmProgramState,
     services::{
         executor::{
@@ -91,10 +108,145 @@ use fuel_core_types::{
     tai64::Tai64,
 };
 use std::{
-    sync::Arc,
+    sync::{
+        Arc,
+        OnceLock,
+    },
+    time::SystemTime,
     vec::IntoIter,
 };
 
+pub struct AddressTxCount(pub(crate) StorageAddressTxCount);
+
+#[Object]
+impl AddressTxCount {
+    async fn address(&self) -> Address {
+        self.0.address.into()
+    }
+
+    async fn tx_count(&self) -> U64 {
+        self.0.tx_count.into()
+    }
+}
+
+/// The proof that the transaction is included in the block.
+pub struct TransactionInclusionProof(pub(crate) query::TransactionInclusionProof);
+
+#[Object]
+impl TransactionInclusionProof {
+    /// The proof against the `transactionsRoot` of the block header.
+    async fn proof(&self) -> MerkleProof {
+        self.0.proof.clone().into()
+    }
+
+    /// The header of the block that includes the transaction.
+    async fn block_header(&self) -> Header {
+        self.0.block_header.clone().into()
+    }
+}
+
+impl From<query::TransactionInclusionProof> for TransactionInclusionProof {
+    fn from(proof: query::TransactionInclusionProof) -> Self {
+        TransactionInclusionProof(proof)
+    }
+}
+
+/// The summary of the transaction in the TxPool.
+pub struct PendingTransaction(pub(crate) pending_transactions::PendingTransaction);
+
+#[Object]
+impl PendingTransaction {
+    async fn id(&self) -> TransactionId {
+        self.0.id.into()
+    }
+
+    async fn tip(&self) -> U64 {
+        self.0.tip.into()
+    }
+
+    async fn max_gas(&self) -> U64 {
+        self.0.max_gas.into()
+    }
+
+    /// The time when the transaction was inserted into the TxPool.
+    async fn inserted_at(&self) -> Tai64Timestamp {
+        let unix_time = self
+            .0
+            .inserted_at
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs() as i64;
+        Tai64Timestamp(Tai64::from_unix(unix_time))
+    }
+}
+
+/// The order of the pending transactions.
+#[derive(Enum, Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum PendingTransactionsSort {
+    /// By the tip/gas ratio, the way the block producer selects the transactions.
+    #[default]
+    Ratio,
+    /// By the tip, the highest first.
+    Tip,
+    /// By the insertion time, the oldest first.
+    Time,
+}
+
+impl From<PendingTransactionsSort> for PendingTransactionsOrder {
+    fn from(sort: PendingTransactionsSort) -> Self {
+        match sort {
+            PendingTransactionsSort::Ratio => PendingTransactionsOrder::Ratio,
+            PendingTransactionsSort::Tip => PendingTransactionsOrder::Tip,
+            PendingTransactionsSort::Time => PendingTransactionsOrder::Time,
+        }
+    }
+}
+
+/// The status of the transactions related to the owner.
+#[derive(Enum, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TransactionStatusFilter {
+    /// The transaction was executed successfully.
+    Success,
+    /// The execution of the transaction failed.
+    Failure,
+    /// The transaction was squeezed out of the txpool.
+    SqueezedOut,
+}
+
+impl From<TransactionStatusFilter> for query::TransactionStatusFilter {
+    fn from(filter: TransactionStatusFilter) -> Self {
+        match filter {
+            TransactionStatusFilter::Success => query::TransactionStatusFilter::Success,
+            TransactionStatusFilter::Failure => query::TransactionStatusFilter::Failure,
+            TransactionStatusFilter::SqueezedOut => {
+                query::TransactionStatusFilter::SqueezedOut
+            }
+        }
+    }
+}
+
+/// The direction of the transactions related to the owner.
+#[derive(Enum, Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum TransactionDirection {
+    /// The owner spends the inputs of the transaction.
+    Sent,
+    /// The owner receives the outputs of the transaction.
+    Received,
+    /// All transactions of the owner.
+    #[default]
+    Any,
+}
+
+impl From<TransactionDirection> for OwnedTransactionDirection {
+    fn from(direction: TransactionDirection) -> Self {
+        match direction {
+            TransactionDirection::Sent => OwnedTransactionDirection::Sent,
+            TransactionDirection::Received => OwnedTransactionDirection::Received,
+            TransactionDirection::Any => OwnedTransactionDirection::Any,
+        }
+    }
+}
+
 pub struct ProgramState {
     return_type: ReturnType,
     data: Vec<u8>,
@@ -164,6 +316,8 @@ impl SubmittedStatus {
 pub struct SuccessStatus {
     tx_id: TxId,
     status: Arc<txpool::statuses::Success>,
+    /// The pinned view, see [`ReadViewProvider::pinned_view`].
+    view: Option<ReadView>,
 }
 
 #[Object]
@@ -178,16 +332,16 @@ impl SuccessStatus {
 
     #[graphql(complexity = "query_costs().block_header + child_complexity")]
     async fn block(&self, ctx: &Context<'_>) -> async_graphql::Result<Block> {
-        let query = ctx.read_view()?;
+        let query = ctx.pinned_view(self.view.as_ref())?;
         let block = query.block(&self.status.block_height)?;
-        Ok(block.into())
+        Ok(Block(block, self.view.clone()))
     }
 
     #[graphql(complexity = "query_costs().storage_read + child_complexity")]
     async fn transaction(&self, ctx: &Context<'_>) -> async_graphql::Result<Transaction> {
-        let query = ctx.read_view()?;
+        let query = ctx.pinned_view(self.view.as_ref())?;
         let transaction = query.transaction(&self.tx_id)?;
-        Ok(Transaction::from_tx(self.tx_id, transaction))
+        Ok(Transaction::from_tx(self.tx_id, transaction).pinned(self.view.clone()))
     }
 
     async fn time(&self) -> Tai64Timestamp {
@@ -269,6 +423,8 @@ impl PreconfirmationSuccessStatus {
 pub struct FailureStatus {
     tx_id: TxId,
     status: Arc<txpool::statuses::Failure>,
+    /// The pinned view, see [`ReadViewProvider::pinned_view`].
+    view: Option<ReadView>,
 }
 
 #[Object]
@@ -283,16 +439,16 @@ impl FailureStatus {
 
     #[graphql(complexity = "query_costs().block_header + child_complexity")]
     async fn block(&self, ctx: &Context<'_>) -> async_graphql::Result<Block> {
-        let query = ctx.read_view()?;
+        let query = ctx.pinned_view(self.view.as_ref())?;
         let block = query.block(&self.status.block_height)?;
-        Ok(block.into())
+        Ok(Block(block, self.view.clone()))
     }
 
     #[graphql(complexity = "query_costs().storage_read + child_complexity")]
     async fn transaction(&self, ctx: &Context<'_>) -> async_graphql::Result<Transaction> {
-        let query = ctx.read_view()?;
+        let query = ctx.pinned_view(self.view.as_ref())?;
         let transaction = query.transaction(&self.tx_id)?;
-        Ok(Transaction::from_tx(self.tx_id, transaction))
+        Ok(Transaction::from_tx(self.tx_id, transaction).pinned(self.view.clone()))
     }
 
     async fn time(&self) -> Tai64Timestamp {
@@ -393,9 +549,58 @@ impl SqueezedOutStatus {
         self.tx_id.into()
     }
 
+    /// The legacy description of the reason, kept for compatibility.
     async fn reason(&self) -> String {
         self.status.reason.clone()
     }
+
+    /// The structured reason why the transaction was squeezed out.
+    async fn rejection_reason(&self) -> RejectionReason {
+        self.status.rejection_reason.into()
+    }
+
+    /// The code of the failed validity check, if the reason is `VALIDITY`.
+    async fn rejection_code(&self) -> Option<U16> {
+        match self.status.rejection_reason {
+            txpool::RejectionReason::Validity { code } => Some(code.into()),
+            _ => None,
+        }
+    }
+}
+
+/// The structured reason why the transaction was squeezed out of the TxPool.
+#[derive(Enum, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RejectionReason {
+    /// The reason is not known, e.g. the status was produced by an older node.
+    Unknown,
+    /// The fee of the transaction doesn't cover the minimal gas price.
+    InsufficientFee,
+    /// The transaction depends on a transaction that was squeezed out.
+    DependentTxSqueezedOut,
+    /// An input of the transaction is spent or used by a more worth transaction.
+    InputSpent,
+    /// The transaction stayed in the TxPool longer than its time to live.
+    TtlExpired,
+    /// The TxPool has no space for the transaction.
+    PoolFull,
+    /// The transaction failed a validity check, see `rejectionCode`.
+    Validity,
+}
+
+impl From<txpool::RejectionReason> for RejectionReason {
+    fn from(reason: txpool::RejectionReason) -> Self {
+        match reason {
+            txpool::RejectionReason::Unknown => RejectionReason::Unknown,
+            txpool::RejectionReason::InsufficientFee => RejectionReason::InsufficientFee,
+            txpool::RejectionReason::DependentTxSqueezedOut => {
+                RejectionReason::DependentTxSqueezedOut
+            }
+            txpool::RejectionReason::InputSpent => RejectionReason::InputSpent,
+            txpool::RejectionReason::TtlExpired => RejectionReason::TtlExpired,
+            txpool::RejectionReason::PoolFull => RejectionReason::PoolFull,
+            txpool::RejectionReason::Validity { .. } => RejectionReason::Validity,
+        }
+    }
 }
 
 impl TransactionStatus {
@@ -404,15 +609,19 @@ impl TransactionStatus {
             TxStatus::Submitted(status) => {
                 TransactionStatus::Submitted(SubmittedStatus(status.timestamp))
             }
-            TxStatus::Success(status) => {
-                TransactionStatus::Success(SuccessStatus { tx_id, status })
-            }
+            TxStatus::Success(status) => TransactionStatus::Success(SuccessStatus {
+                tx_id,
+                status,
+                view: None,
+            }),
             TxStatus::SqueezedOut(status) => {
                 TransactionStatus::SqueezedOut(SqueezedOutStatus { status, tx_id })
             }
-            TxStatus::Failure(status) => {
-                TransactionStatus::Failure(FailureStatus { tx_id, status })
-            }
+            TxStatus::Failure(status) => TransactionStatus::Failure(FailureStatus {
+                tx_id,
+                status,
+                view: None,
+            }),
             TxStatus::PreConfirmationSuccess(status) => {
                 TransactionStatus::PreconfirmationSuccess(PreconfirmationSuccessStatus {
                     tx_id,
@@ -434,6 +643,20 @@ impl TransactionStatus {
         }
     }
 
+    /// Pins the fields of the status that read the database to the `view`,
+    /// see [`ReadViewProvider::pinned_view`].
+    pub(crate) fn pinned(mut self, view: Option<ReadView>) -> Self {
+        match &mut self {
+            TransactionStatus::Success(status) => status.view = view,
+            TransactionStatus::Failure(status) => status.view = view,
+            TransactionStatus::Submitted(_)
+            | TransactionStatus::PreconfirmationSuccess(_)
+            | TransactionStatus::SqueezedOut(_)
+            | TransactionStatus::PreconfirmationFailure(_) => {}
+        }
+        self
+    }
+
     pub fn is_final(&self) -> bool {
         match self {
             TransactionStatus::Success(_)
@@ -470,11 +693,29 @@ impl Policies {
     }
 }
 
-pub struct Transaction(pub(crate) fuel_tx::Transaction, pub(crate) fuel_tx::TxId);
+pub struct Transaction(
+    pub(crate) fuel_tx::Transaction,
+    pub(crate) fuel_tx::TxId,
+    OnceLock<Vec<u8>>,
+    Option<ReadView>,
+);
 
 impl Transaction {
     pub fn from_tx(id: fuel_tx::TxId, tx: fuel_tx::Transaction) -> Self {
-        Self(tx, id)
+        Self(tx, id, OnceLock::new(), None)
+    }
+
+    /// Pins the fields of the transaction that read the database to the `view`,
+    /// see [`ReadViewProvider::pinned_view`].
+    pub(crate) fn pinned(mut self, view: Option<ReadView>) -> Self {
+        self.3 = view;
+        self
+    }
+
+    /// The canonical encoding of the transaction. The transaction is serialized
+    /// at most once, so `rawPayload` and `size` of the same object share the bytes.
+    fn canonical_bytes(&self) -> &[u8] {
+        self.2.get_or_init(|| self.0.to_bytes())
     }
 }
 
@@ -766,13 +1007,22 @@ impl Transaction {
         ctx: &Context<'_>,
     ) -> async_graphql::Result<Option<TransactionStatus>> {
         let id = self.1;
-        let query = ctx.read_view()?;
+        let query = ctx.pinned_view(self.3.as_ref())?;
 
         let tx_status_manager = ctx.data_unchecked::<DynTxStatusManager>();
+        let loader = ctx.data_unchecked::<TransactionStatusLoader>();
+
+        let status = get_tx_status(id, query.as_ref(), loader, tx_status_manager).await?;
+        Ok(status.map(|status| status.pinned(self.3.clone())))
+    }
 
-        get_tx_status(id, query.as_ref(), tx_status_manager)
-            .await
-            .map_err(Into::into)
+    /// The tag attached to the transaction by the `tagTransaction` mutation.
+    /// It is kept apart from the status, so it survives the updates of the status.
+    #[graphql(complexity = "query_costs().storage_read + child_complexity")]
+    async fn tag(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<HexString>> {
+        let query = ctx.pinned_view(self.3.as_ref())?;
+        let tag = query.transaction_tag(&self.1)?;
+        Ok(tag.map(HexString))
     }
 
     async fn script(&self) -> Option<HexString> {
@@ -920,7 +1170,13 @@ impl Transaction {
     #[graphql(complexity = "query_costs().tx_raw_payload")]
     /// Return the transaction bytes using canonical encoding
     async fn raw_payload(&self) -> HexString {
-        HexString(self.0.clone().to_bytes())
+        HexString(self.canonical_bytes().to_vec())
+    }
+
+    #[graphql(complexity = "query_costs().tx_raw_payload")]
+    /// Return the size of the canonical encoding of the transaction in bytes
+    async fn size(&self) -> U64 {
+        (self.canonical_bytes().len() as u64).into()
     }
 }
 
@@ -1037,20 +1293,34 @@ impl DryRunFailureStatus {
     }
 }
 
-pub struct DryRunTransactionExecutionStatus(pub TransactionExecutionStatus);
+pub struct DryRunTransactionExecutionStatus {
+    pub status: TransactionExecutionStatus,
+    pub state_height: Option<BlockHeight>,
+}
 
 #[Object]
 impl DryRunTransactionExecutionStatus {
     async fn id(&self) -> TransactionId {
-        TransactionId(self.0.id)
+        TransactionId(self.status.id)
     }
 
     async fn status(&self) -> DryRunTransactionStatus {
-        DryRunTransactionStatus::new(self.0.result.clone())
+        DryRunTransactionStatus::new(self.status.result.clone())
     }
 
     async fn receipts(&self) -> Vec<Receipt> {
-        self.0.result.receipts().iter().map(Into::into).collect()
+        self.status
+            .result
+            .receipts()
+            .iter()
+            .map(Into::into)
+            .collect()
+    }
+
+    /// The height of the block, the state after which was used by the dry-run.
+    /// It is `null` if the dry-run was executed on top of the latest state.
+    async fn state_height(&self) -> Option<U32> {
+        self.state_height.map(Into::into)
     }
 }
 
@@ -1087,18 +1357,17 @@ impl StorageReadReplayEvent {
     }
 }
 
-#[tracing::instrument(level = "debug", skip(query, tx_status_manager), ret, err)]
+#[tracing::instrument(level = "debug", skip(query, loader, tx_status_manager), ret, err)]
 pub(crate) async fn get_tx_status(
     id: fuel_core_types::fuel_types::Bytes32,
     query: &ReadView,
+    loader: &TransactionStatusLoader,
     tx_status_manager: &DynTxStatusManager,
 ) -> Result<Option<TransactionStatus>, StorageError> {
-    let api_result = query
-        .tx_status(&id)
-        .into_api_result::<txpool::TransactionStatus, StorageError>()?;
-    match api_result {
+    let status = loader.load(query, id).await?;
+    match status {
         Some(status) => {
-            let status = TransactionStatus::new(id, status);
+            let status = TransactionStatus::new(id, status.into());
             Ok(Some(status))
         }
         None => {
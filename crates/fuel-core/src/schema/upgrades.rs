@@ -23,6 +23,7 @@ use fuel_core_types::{
     fuel_types,
     fuel_vm::UploadedBytecode as StorageUploadedBytecode,
 };
+use std::sync::Arc;
 
 #[derive(Default)]
 pub struct UpgradeQuery;
@@ -42,6 +43,31 @@ impl UpgradeQuery {
         Ok(ConsensusParameters(params))
     }
 
+    /// Returns the consensus parameters of the `version` indexed by the node,
+    /// including the versions from before regenesis.
+    #[graphql(complexity = "query_costs().storage_read + child_complexity")]
+    async fn consensus_parameters_by_version(
+        &self,
+        ctx: &Context<'_>,
+        version: ConsensusParametersVersion,
+    ) -> async_graphql::Result<Option<ConsensusParameters>> {
+        let query = ctx.read_view()?;
+        let params = query.consensus_parameters_by_version(version)?;
+
+        Ok(params.map(|params| ConsensusParameters(Arc::new(params))))
+    }
+
+    /// Returns the versions of the consensus parameters indexed by the node
+    /// in ascending order.
+    #[graphql(complexity = "query_costs().storage_iterator")]
+    async fn consensus_parameters_versions(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<ConsensusParametersVersion>> {
+        let query = ctx.read_view()?;
+        Ok(query.consensus_parameters_versions()?)
+    }
+
     #[graphql(complexity = "query_costs().storage_read + child_complexity")]
     async fn state_transition_bytecode_by_version(
         &self,
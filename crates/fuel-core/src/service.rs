@@ -60,10 +60,17 @@ use crate::{
 use self::adapters::BlockImporterAdapter;
 
 pub mod adapters;
+pub mod balance_check;
 pub mod config;
+pub mod da_compressed_blocks_reencoding;
 pub mod genesis;
+pub mod integrity_check;
 pub mod metrics;
+pub mod offline_indexer;
+pub mod owned_coins_compaction;
+pub mod owned_coins_migration;
 mod query;
+pub mod reindex;
 pub mod sub_services;
 pub mod vm_pool;
 
@@ -130,6 +137,12 @@ impl FuelService {
         // initialize state
         tracing::info!("Initializing database");
         database.check_version()?;
+        integrity_check::verify_on_start(&database, &config.integrity_check)?;
+        // Cleared by the clean shutdown, so the next start knows if it didn't happen.
+        database.set_dirty(true)?;
+        database
+            .off_chain()
+            .init_owned_coins_layout(config.graphql_config.owned_coins_layout)?;
 
         Self::make_database_compatible_with_config(
             &mut database,
@@ -480,6 +493,10 @@ impl RunnableTask for Task {
                 );
             }
         }
+
+        if let Err(err) = self.shared.database.set_dirty(false) {
+            tracing::error!("Failed to clear the dirty flag of the databases: {}", err);
+        }
         Ok(())
     }
 }
@@ -488,6 +505,7 @@ impl RunnableTask for Task {
 #[cfg(test)]
 mod tests {
     use crate::{
+        combined_database::CombinedDatabase,
         service::{
             Config,
             FuelService,
@@ -495,6 +513,11 @@ mod tests {
         ShutdownListener,
     };
     use fuel_core_services::State;
+    use fuel_core_storage::{
+        column::Column,
+        kv_store::KeyValueMutate,
+        transactional::ReadTransaction,
+    };
     use std::{
         thread::sleep,
         time::Duration,
@@ -581,4 +604,54 @@ mod tests {
             assert_eq!(service.borrow_and_update().clone(), State::Stopped);
         }
     }
+
+    #[tokio::test]
+    async fn send_stop_signal_and_await_shutdown__clears_the_dirty_flag() {
+        // given
+        let service = FuelService::new_node(Config::local_node()).await.unwrap();
+        assert!(service.shared.database.is_dirty().unwrap());
+
+        // when
+        service.send_stop_signal_and_await_shutdown().await.unwrap();
+
+        // then
+        assert!(!service.shared.database.is_dirty().unwrap());
+    }
+
+    #[tokio::test]
+    async fn new__refuses_to_start_with_corrupted_column_after_unclean_shutdown() {
+        // given
+        let database = CombinedDatabase::default();
+        let mut transaction = database.on_chain().read_transaction();
+        transaction
+            .put(&[1; 34], Column::Coins, vec![0xff].into())
+            .unwrap();
+        database
+            .on_chain()
+            .commit_changes_without_height(transaction.into_changes())
+            .unwrap();
+        database.set_dirty(true).unwrap();
+        let mut shutdown = ShutdownListener::spawn();
+
+        // when
+        let result = FuelService::new(database, Config::local_node(), &mut shutdown);
+
+        // then
+        let Err(err) = result else {
+            panic!("The service started with the corrupted column");
+        };
+        let err = err.to_string();
+        assert!(
+            err.starts_with(
+                "The integrity check found corrupted columns \
+                (more than 0 failures per column): \
+                on_chain/Coins: 1 of 1 sampled values failed to decode ("
+            ),
+            "{err}"
+        );
+        assert!(
+            err.ends_with("Restore the databases or start with `--force` to ignore it"),
+            "{err}"
+        );
+    }
 }
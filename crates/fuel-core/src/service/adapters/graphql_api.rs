@@ -2,6 +2,7 @@ use super::{
     BlockImporterAdapter,
     BlockProducerAdapter,
     ChainStateInfoProvider,
+    MaybeRelayerAdapter,
     SharedMemoryPool,
     StaticGasPrice,
     TxStatusManagerAdapter,
@@ -18,6 +19,8 @@ use crate::{
         DatabaseMessageProof,
         GasPriceEstimate,
         P2pPort,
+        RelayerSyncPort,
+        RelayerSyncState,
         TxPoolPort,
     },
     graphql_api::ports::{
@@ -37,7 +40,11 @@ use async_trait::async_trait;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_tx_status_manager::TxStatusMessage;
-use fuel_core_txpool::TxPoolStats;
+use fuel_core_txpool::{
+    PendingTransactionsOrder,
+    TxInfo,
+    TxPoolStats,
+};
 use fuel_core_types::{
     blockchain::header::{
         ConsensusParametersVersion,
@@ -105,6 +112,16 @@ impl TxPoolPort for TxPoolAdapter {
     fn latest_pool_stats(&self) -> TxPoolStats {
         self.service.latest_stats()
     }
+
+    async fn pending_transactions(
+        &self,
+        order: PendingTransactionsOrder,
+    ) -> anyhow::Result<Vec<TxInfo>> {
+        self.service
+            .ordered_snapshot(order)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
 }
 
 impl DatabaseMessageProof for OnChainIterableKeyValueView {
@@ -178,6 +195,25 @@ impl P2pPort for P2PAdapter {
     }
 }
 
+impl RelayerSyncPort for MaybeRelayerAdapter {
+    fn relayer_sync_state(&self) -> Option<RelayerSyncState> {
+        #[cfg(feature = "relayer")]
+        {
+            self.relayer_synced.as_ref().map(|sync| {
+                let state = sync.sync_state();
+                RelayerSyncState {
+                    da_height: state.da_block_height(),
+                    synced: state.is_synced(),
+                }
+            })
+        }
+        #[cfg(not(feature = "relayer"))]
+        {
+            None
+        }
+    }
+}
+
 impl worker::TxStatusCompletion for TxStatusManagerAdapter {
     fn send_complete(
         &self,
@@ -1,9 +1,34 @@
+#[cfg(feature = "fault-proving")]
+use crate::graphql_api::storage::da_compression::v2::{
+    entry_counts::DaCompressionTemporalRegistryEntryCountsV2,
+    evictor_cache::DaCompressionTemporalRegistryEvictorCacheV2,
+    predicate_code::DaCompressionTemporalRegistryPredicateCodeV2,
+    script_code::DaCompressionTemporalRegistryScriptCodeV2,
+    timestamps::DaCompressionTemporalRegistryTimestampsV2,
+};
+#[cfg(not(feature = "fault-proving"))]
+use crate::graphql_api::storage::da_compression::{
+    DaCompressionTemporalRegistryEntryCounts,
+    DaCompressionTemporalRegistryEvictorCache,
+    DaCompressionTemporalRegistryPredicateCode,
+    DaCompressionTemporalRegistryScriptCode,
+    DaCompressionTemporalRegistryTimestamps,
+};
+#[cfg(feature = "fault-proving")]
+use crate::graphql_api::{
+    da_compression::DaCompressedBlockProof,
+    storage::da_compression::v2::compressed_blocks::{
+        DaCompressedBlocksMerkleData,
+        DaCompressedBlocksMerkleMetadata,
+    },
+};
 use crate::{
     database::{
         database_description::{
             off_chain::OffChain,
             IndexationKind,
         },
+        metadata::MetadataTable,
         Database,
         OffChainIterableKeyValueView,
         OffChainKeyValueView,
@@ -11,14 +36,64 @@ use crate::{
     fuel_core_graphql_api::{
         ports::{
             worker,
+            MessageProofCacheStorage,
             OffChainDatabase,
             OffChainDatabaseAt,
+            OwnerIndexesPruner,
+            PrunedOwnerIndexes,
+            TransactionTagger,
         },
         storage::{
-            contracts::ContractsInfo,
-            da_compression::DaCompressedBlocks,
-            relayed_transactions::RelayedTransactionStatuses,
-            transactions::OwnedTransactionIndexCursor,
+            blocks::{
+                BlockFees,
+                BlockTransactionIds,
+                CollectedFees,
+            },
+            contracts::{
+                ContractBalances,
+                ContractDeployment,
+                ContractsByBlock,
+                ContractsByBlockKey,
+                ContractsInfo,
+            },
+            da_compression::{
+                evictor_cache::MetadataKey,
+                timestamps::TimestampKey,
+                versioned_block_codec::da_block_payload,
+                DaCompressedBlockChecksums,
+                DaCompressedBlockRegistrations,
+                DaCompressedBlockStats,
+                DaCompressedBlocks,
+                DaCompressionStats,
+                DaRegistration,
+            },
+            dead_letter::IndexationDeadLetter,
+            messages::{
+                MessageProofCache,
+                MessageProofCacheEntries,
+                MessageProofCacheKey,
+                OwnedMessageIds,
+                OwnedMessageState,
+            },
+            relayed_transactions::{
+                OwnedRelayedTransactionIds,
+                OwnedRelayedTransactionKey,
+                RelayedTransactionStatusHeightKey,
+                RelayedTransactionStatusHeights,
+                RelayedTransactionStatuses,
+            },
+            rollbacks::{
+                ChainRollback,
+                LastChainRollback,
+                LAST_CHAIN_ROLLBACK_KEY,
+            },
+            transactions::{
+                OwnedTransactionIndexCursor,
+                OwnedTransactionValue,
+                OwnedTransactions,
+                TransactionStatuses,
+                TransactionTags,
+            },
         },
     },
     graphql_api::{
@@ -27,6 +102,11 @@ use crate::{
         storage::{
             assets::{
                 AssetDetails,
+                AssetFirstSeen,
+                AssetFirstSeenInfo,
+                AssetSupplyCheckpoints,
+                AssetSupplyHistory,
+                AssetSupplyHistoryKey,
                 AssetsInfo,
             },
             balances::{
@@ -36,48 +116,124 @@ use crate::{
                 MessageBalances,
                 TotalBalanceAmount,
             },
-            coins::CoinsToSpendIndex,
+            coins::{
+                owned_coins_layout,
+                owner_coin_id_key,
+                take_owned_coin,
+                CoinsToSpendIndex,
+                OwnedCoinCountByAsset,
+                OwnedCoinKey,
+                OwnedCoinState,
+                OwnedCoins,
+                OwnedCoinsByAsset,
+                OwnedCoinsLayout,
+                PredicateOwnedCoins,
+                ShardedOwnedCoins,
+                SpentCoin,
+                SpentCoins,
+                SpentCoinsByBlock,
+            },
+            known_owners::{
+                KnownOwner,
+                KnownPredicateOwners,
+            },
             old::{
                 OldFuelBlockConsensus,
                 OldFuelBlocks,
                 OldTransactions,
             },
+            owner_activity::{
+                OwnerActivities,
+                OwnerActivity,
+                OwnerLastActivity,
+            },
+            statistic::{
+                AddressTxCount,
+                CommitDurationMax,
+                StatisticTable,
+                TopAddressesByTxCount,
+            },
+            upgrades::ConsensusParametersHistory,
+            Column,
+            DA_COMPRESSION_HEIGHT,
+            INDEXATION_DEAD_LETTER,
+            MESSAGE_PROOF_CACHE_ENTRIES,
+            OFF_CHAIN_COMMIT_DURATION_MAX,
+            SPENT_MESSAGE_COUNT,
+            TOP_ADDRESSES_BY_TX_COUNT,
+            TOTAL_FEES,
+            TX_COUNT,
+            TX_COUNT_DELTA,
         },
     },
 };
+#[cfg(feature = "fault-proving")]
+use fuel_core_storage::tables::merkle::DenseMetadataKey;
 use fuel_core_storage::{
     blueprint::BlueprintInspect,
-    codec::Encode,
+    codec::{
+        manual::Manual,
+        postcard::Postcard,
+        Decode,
+        Encode,
+    },
     iter::{
         BoxedIter,
         IntoBoxedIter,
         IterDirection,
+        IterableStore,
+        IterableTable,
         IteratorOverTable,
     },
-    kv_store::KeyValueInspect,
+    kv_store::{
+        KVItem,
+        KeyValueInspect,
+        StorageColumn,
+        Value,
+    },
     not_found,
     structured_storage::TableWithBlueprint,
     transactional::{
+        AtomicView,
+        Changes,
         IntoTransaction,
+        ReadTransaction,
         StorageTransaction,
     },
+    ContractsAssetKey,
     Error as StorageError,
+    Mappable,
     Result as StorageResult,
+    StorageAsMut,
     StorageAsRef,
+    StorageInspect,
+    StorageMutate,
 };
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
         consensus::Consensus,
+        header::{
+            BlockHeader,
+            ConsensusParametersVersion,
+        },
         primitives::BlockId,
     },
-    entities::relayer::transaction::RelayedTransactionStatus,
+    entities::relayer::{
+        message::MessageProof,
+        transaction::RelayedTransactionStatus,
+    },
+    fuel_compression::RegistryKey,
+    fuel_crypto::Hasher,
     fuel_tx::{
+        input::PredicateCode,
         Address,
         AssetId,
         Bytes32,
+        ConsensusParameters,
         ContractId,
         Salt,
+        ScriptCode,
         Transaction,
         TxId,
         TxPointer,
@@ -87,9 +243,21 @@ use fuel_core_types::{
         BlockHeight,
         Nonce,
     },
-    services::txpool,
+    services::{
+        graphql_api::ContractBalance,
+        txpool,
+    },
+    tai64::Tai64,
+};
+#[cfg(feature = "fault-proving")]
+use fuel_core_types::{
+    entities::relayer::message::MerkleProof,
+    fuel_merkle::binary::MerkleTree,
+};
+use std::{
+    borrow::Borrow,
+    iter,
 };
-use std::iter;
 
 impl OffChainDatabase for OffChainIterableKeyValueView {
     fn block_height(&self, id: &BlockId) -> StorageResult<BlockHeight> {
@@ -97,6 +265,25 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
             .and_then(|height| height.ok_or(not_found!("BlockHeight")))
     }
 
+    fn block_heights_by_producer(
+        &self,
+        producer: &Address,
+        start_height: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<BlockHeight>> {
+        self.block_heights_by_producer(producer, start_height, Some(direction))
+            .into_boxed()
+    }
+
+    fn block_transaction_ids(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<Vec<TxId>>> {
+        self.storage_as_ref::<BlockTransactionIds>()
+            .get(height)
+            .map(|ids| ids.map(|ids| ids.into_owned()))
+    }
+
     fn da_compressed_block(&self, height: &BlockHeight) -> StorageResult<Vec<u8>> {
         let column = <DaCompressedBlocks as TableWithBlueprint>::column();
         let encoder =
@@ -105,9 +292,164 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
                 Self,
             >>::KeyCodec::encode(height);
 
-        self.get(encoder.as_ref(), column)?
-            .ok_or_else(|| not_found!(DaCompressedBlocks))
-            .map(|value| value.to_vec())
+        let bytes = self
+            .get(encoder.as_ref(), column)?
+            .ok_or_else(|| not_found!(DaCompressedBlocks))?;
+        let payload = da_block_payload(&bytes)?;
+        Ok(payload.into_owned())
+    }
+
+    fn da_compressed_block_checksum(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<u32>> {
+        self.storage_as_ref::<DaCompressedBlockChecksums>()
+            .get(height)
+            .map(|checksum| checksum.map(|checksum| *checksum))
+    }
+
+    fn da_compressed_block_heights(
+        &self,
+        start: BlockHeight,
+    ) -> BoxedIter<'_, StorageResult<BlockHeight>> {
+        self.iter_all_by_start_keys::<DaCompressedBlocks>(
+            Some(&start),
+            Some(IterDirection::Forward),
+        )
+    }
+
+    fn da_compression_height(&self) -> StorageResult<Option<BlockHeight>> {
+        da_compression_height(self)
+    }
+
+    fn commit_duration_max(&self) -> StorageResult<Option<CommitDurationMax>> {
+        commit_duration_max(self)
+    }
+
+    fn da_compression_stats(
+        &self,
+    ) -> BoxedIter<'_, StorageResult<(BlockHeight, DaCompressionStats)>> {
+        self.iter_all::<DaCompressedBlockStats>(Some(IterDirection::Reverse))
+    }
+
+    fn da_compressed_block_registrations(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<Vec<DaRegistration>>> {
+        self.storage_as_ref::<DaCompressedBlockRegistrations>()
+            .get(height)
+            .map(|registrations| {
+                registrations.map(|registrations| registrations.into_owned())
+            })
+    }
+
+    #[cfg(feature = "fault-proving")]
+    fn da_compressed_block_proof(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<DaCompressedBlockProof>> {
+        let Some(block_metadata) = self
+            .storage_as_ref::<DaCompressedBlocksMerkleMetadata>()
+            .get(&DenseMetadataKey::Primary(*height))?
+        else {
+            return Ok(None)
+        };
+        let latest_metadata = self
+            .storage_as_ref::<DaCompressedBlocksMerkleMetadata>()
+            .get(&DenseMetadataKey::Latest)?
+            .ok_or(not_found!(DaCompressedBlocksMerkleMetadata))?;
+
+        let tree: MerkleTree<DaCompressedBlocksMerkleData, _> =
+            MerkleTree::load(self, latest_metadata.version())
+                .map_err(|err| StorageError::Other(anyhow::anyhow!(err)))?;
+        let proof_index = block_metadata
+            .version()
+            .checked_sub(1)
+            .ok_or(anyhow::anyhow!("The count of compressed blocks is zero"))?;
+        let (_, proof_set) = tree
+            .prove(proof_index)
+            .map_err(|err| StorageError::Other(anyhow::anyhow!(err)))?;
+
+        Ok(Some(DaCompressedBlockProof {
+            root: (*latest_metadata.root()).into(),
+            leaf_count: latest_metadata.version(),
+            proof: MerkleProof {
+                proof_set,
+                proof_index,
+            },
+        }))
+    }
+
+    fn da_registry_script_code(
+        &self,
+        key: &RegistryKey,
+    ) -> StorageResult<Option<ScriptCode>> {
+        #[cfg(not(feature = "fault-proving"))]
+        let code = self
+            .storage_as_ref::<DaCompressionTemporalRegistryScriptCode>()
+            .get(key)?;
+        #[cfg(feature = "fault-proving")]
+        let code = self
+            .storage_as_ref::<DaCompressionTemporalRegistryScriptCodeV2>()
+            .get(key)?;
+        Ok(code.map(|code| code.into_owned()))
+    }
+
+    fn da_registry_predicate_code(
+        &self,
+        key: &RegistryKey,
+    ) -> StorageResult<Option<PredicateCode>> {
+        #[cfg(not(feature = "fault-proving"))]
+        let code = self
+            .storage_as_ref::<DaCompressionTemporalRegistryPredicateCode>()
+            .get(key)?;
+        #[cfg(feature = "fault-proving")]
+        let code = self
+            .storage_as_ref::<DaCompressionTemporalRegistryPredicateCodeV2>()
+            .get(key)?;
+        Ok(code.map(|code| code.into_owned()))
+    }
+
+    fn da_registry_latest_assigned_key(
+        &self,
+        keyspace: MetadataKey,
+    ) -> StorageResult<Option<RegistryKey>> {
+        #[cfg(not(feature = "fault-proving"))]
+        let key = self
+            .storage_as_ref::<DaCompressionTemporalRegistryEvictorCache>()
+            .get(&keyspace)?;
+        #[cfg(feature = "fault-proving")]
+        let key = self
+            .storage_as_ref::<DaCompressionTemporalRegistryEvictorCacheV2>()
+            .get(&keyspace)?;
+        Ok(key.map(|key| key.into_owned()))
+    }
+
+    fn da_registry_entry_count(
+        &self,
+        keyspace: MetadataKey,
+    ) -> StorageResult<Option<u32>> {
+        #[cfg(not(feature = "fault-proving"))]
+        let count = self
+            .storage_as_ref::<DaCompressionTemporalRegistryEntryCounts>()
+            .get(&keyspace)?;
+        #[cfg(feature = "fault-proving")]
+        let count = self
+            .storage_as_ref::<DaCompressionTemporalRegistryEntryCountsV2>()
+            .get(&keyspace)?;
+        Ok(count.map(|count| count.into_owned()))
+    }
+
+    fn da_registry_timestamp(&self, key: &TimestampKey) -> StorageResult<Option<Tai64>> {
+        #[cfg(not(feature = "fault-proving"))]
+        let timestamp = self
+            .storage_as_ref::<DaCompressionTemporalRegistryTimestamps>()
+            .get(key)?;
+        #[cfg(feature = "fault-proving")]
+        let timestamp = self
+            .storage_as_ref::<DaCompressionTemporalRegistryTimestampsV2>()
+            .get(key)?;
+        Ok(timestamp.map(|timestamp| timestamp.into_owned()))
     }
 
     fn tx_status(
@@ -119,17 +461,112 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
             .ok_or(not_found!("TransactionId"))?
     }
 
+    fn tx_statuses(
+        &self,
+        tx_ids: &[TxId],
+    ) -> Vec<StorageResult<Option<txpool::TransactionExecutionStatus>>> {
+        // TODO: Use multiget when it's implemented.
+        //  https://github.com/FuelLabs/fuel-core/issues/2344
+        tx_ids
+            .iter()
+            .map(|tx_id| self.get_tx_status(tx_id))
+            .collect()
+    }
+
+    fn txs_included(&self, tx_ids: &[TxId]) -> Vec<StorageResult<bool>> {
+        tx_ids
+            .iter()
+            .map(|tx_id| {
+                self.storage_as_ref::<TransactionStatuses>()
+                    .contains_key(tx_id)
+            })
+            .collect()
+    }
+
+    fn transaction_tag(&self, tx_id: &TxId) -> StorageResult<Option<Vec<u8>>> {
+        let tag = self
+            .storage_as_ref::<TransactionTags>()
+            .get(tx_id)?
+            .map(|tag| tag.into_owned());
+        Ok(tag)
+    }
+
     fn owned_coins_ids(
         &self,
         owner: &Address,
         start_coin: Option<UtxoId>,
         direction: IterDirection,
+        include_derived: bool,
     ) -> BoxedIter<'_, StorageResult<UtxoId>> {
-        self.owned_coins_ids(owner, start_coin, Some(direction))
+        self.owned_coins_ids(owner, start_coin, Some(direction), include_derived)
             .map(|res| res.map_err(StorageError::from))
             .into_boxed()
     }
 
+    fn owned_coins_ids_by_asset(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        start_coin: Option<UtxoId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<UtxoId>> {
+        self.owned_coins_ids_by_asset(owner, asset_id, start_coin, Some(direction))
+            .into_boxed()
+    }
+
+    fn predicate_owned_coins_ids(
+        &self,
+        owner: &Address,
+        start_coin: Option<UtxoId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<UtxoId>> {
+        self.predicate_owned_coins_ids(owner, start_coin, Some(direction))
+            .into_boxed()
+    }
+
+    fn is_predicate_owned_coin(
+        &self,
+        owner: &Address,
+        utxo_id: &UtxoId,
+    ) -> StorageResult<bool> {
+        self.storage_as_ref::<PredicateOwnedCoins>()
+            .contains_key(&OwnedCoinKey::new(owner, utxo_id))
+    }
+
+    fn spent_coin(&self, utxo_id: &UtxoId) -> StorageResult<Option<SpentCoin>> {
+        let spent_coin = self
+            .storage_as_ref::<SpentCoins>()
+            .get(utxo_id)?
+            .map(|spent_coin| spent_coin.into_owned());
+        Ok(spent_coin)
+    }
+
+    fn spent_coins_at_height(
+        &self,
+        height: BlockHeight,
+    ) -> BoxedIter<'_, StorageResult<UtxoId>> {
+        self.iter_all_filtered::<SpentCoinsByBlock, _>(
+            Some(height.to_bytes()),
+            None,
+            Some(IterDirection::Forward),
+        )
+        .map(|result| result.map(|(_, utxo_id)| utxo_id))
+        .into_boxed()
+    }
+
+    fn owned_coin_count_by_asset(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> StorageResult<u64> {
+        let count = self
+            .storage_as_ref::<OwnedCoinCountByAsset>()
+            .get(&CoinBalancesKey::new(owner, asset_id))?
+            .map(|count| count.into_owned())
+            .unwrap_or_default();
+        Ok(count)
+    }
+
     fn owned_message_ids(
         &self,
         owner: &Address,
@@ -141,12 +578,34 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
             .into_boxed()
     }
 
+    fn owned_message_states(
+        &self,
+        owner: &Address,
+        start_message_id: Option<Nonce>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(Nonce, OwnedMessageState)>> {
+        self.owned_message_states(owner, start_message_id, Some(direction))
+            .map(|result| result.map_err(StorageError::from))
+            .into_boxed()
+    }
+
+    fn message_ids_by_sender(
+        &self,
+        sender: &Address,
+        start_message_id: Option<Nonce>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<Nonce>> {
+        self.message_ids_by_sender(sender, start_message_id, Some(direction))
+            .map(|result| result.map_err(StorageError::from))
+            .into_boxed()
+    }
+
     fn owned_transactions_ids(
         &self,
         owner: Address,
         start: Option<TxPointer>,
         direction: IterDirection,
-    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>> {
+    ) -> BoxedIter<StorageResult<(TxPointer, OwnedTransactionValue)>> {
         let start = start.map(|tx_pointer| OwnedTransactionIndexCursor {
             block_height: tx_pointer.block_height(),
             tx_idx: tx_pointer.tx_index(),
@@ -156,6 +615,32 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
             .into_boxed()
     }
 
+    fn contract_transactions_ids(
+        &self,
+        contract_id: ContractId,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, TxId)>> {
+        let start = start.map(|tx_pointer| OwnedTransactionIndexCursor {
+            block_height: tx_pointer.block_height(),
+            tx_idx: tx_pointer.tx_index(),
+        });
+        self.contract_transactions(contract_id, start, Some(direction))
+            .map(|result| result.map_err(StorageError::from))
+            .into_boxed()
+    }
+
+    fn contracts_by_block(
+        &self,
+        start: Option<TxPointer>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(TxPointer, ContractDeployment)>> {
+        let start = start.map(ContractsByBlockKey::from);
+        self.iter_all_by_start::<ContractsByBlock>(start.as_ref(), Some(direction))
+            .map(|result| result.map(|(key, deployment)| (key.into(), deployment)))
+            .into_boxed()
+    }
+
     fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt> {
         let salt = *self
             .storage_as_ref::<ContractsInfo>()
@@ -176,6 +661,11 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
         Ok(block)
     }
 
+    fn old_block_header(&self, height: &BlockHeight) -> StorageResult<BlockHeader> {
+        self.old_block_header(height)?
+            .ok_or(not_found!(OldFuelBlocks))
+    }
+
     fn old_blocks(
         &self,
         height: Option<BlockHeight>,
@@ -200,6 +690,22 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
             .map(|tx| tx.map(|tx| tx.into_owned()))
     }
 
+    fn consensus_parameters_by_version(
+        &self,
+        version: ConsensusParametersVersion,
+    ) -> StorageResult<Option<ConsensusParameters>> {
+        self.storage_as_ref::<ConsensusParametersHistory>()
+            .get(&version)
+            .map(|params| params.map(|params| params.into_owned()))
+    }
+
+    fn consensus_parameters_versions(
+        &self,
+    ) -> BoxedIter<'_, StorageResult<ConsensusParametersVersion>> {
+        self.iter_all_keys::<ConsensusParametersHistory>(Some(IterDirection::Forward))
+            .into_boxed()
+    }
+
     fn relayed_tx_status(
         &self,
         id: Bytes32,
@@ -207,50 +713,195 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
         let status = self
             .storage_as_ref::<RelayedTransactionStatuses>()
             .get(&id)
-            .map_err(StorageError::from)?
+            .map_err(StorageError::Codec)?
             .map(|cow| cow.into_owned());
         Ok(status)
     }
 
+    fn owned_relayed_transaction_ids(
+        &self,
+        owner: &Address,
+        start: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<Bytes32>> {
+        let start = start.map(|id| OwnedRelayedTransactionKey::new(owner, &id));
+        self.iter_all_filtered_keys::<OwnedRelayedTransactionIds, _>(
+            Some(*owner),
+            start.as_ref(),
+            Some(direction),
+        )
+        .map(|result| result.map(|key| *key.relayed_tx_id()))
+        .into_boxed()
+    }
+
     fn message_is_spent(&self, nonce: &Nonce) -> StorageResult<bool> {
         self.message_is_spent(nonce)
     }
 
+    fn spent_message_count(&self) -> StorageResult<u64> {
+        let spent_message_count = self
+            .storage_as_ref::<StatisticTable<u64>>()
+            .get(SPENT_MESSAGE_COUNT)?
+            .unwrap_or_default()
+            .into_owned();
+        Ok(spent_message_count)
+    }
+
+    fn u64_statistic(&self, name: &str) -> StorageResult<Option<u64>> {
+        let statistic = self.storage_as_ref::<StatisticTable<u64>>();
+        let value = statistic.get(name)?.map(|value| value.into_owned());
+        if name != TX_COUNT {
+            return Ok(value);
+        }
+
+        // The delta left by a commit without the merge is folded only by the next commit.
+        let delta = statistic
+            .get(TX_COUNT_DELTA)?
+            .map(|delta| delta.into_owned());
+        Ok(match (value, delta) {
+            (Some(value), Some(delta)) => Some(value.saturating_add(delta)),
+            (value, delta) => value.or(delta),
+        })
+    }
+
+    fn block_fees(&self, height: &BlockHeight) -> StorageResult<CollectedFees> {
+        self.storage_as_ref::<BlockFees>()
+            .get(height)?
+            .ok_or(not_found!(BlockFees))
+            .map(|fees| fees.into_owned())
+    }
+
+    fn total_fees(&self) -> StorageResult<CollectedFees> {
+        let total_fees = self
+            .storage_as_ref::<StatisticTable<CollectedFees>>()
+            .get(TOTAL_FEES)?
+            .unwrap_or_default()
+            .into_owned();
+        Ok(total_fees)
+    }
+
     fn asset_info(&self, asset_id: &AssetId) -> StorageResult<Option<AssetDetails>> {
         self.storage_as_ref::<AssetsInfo>()
             .get(asset_id)
             .map(|opt| opt.map(|cow| cow.into_owned()))
     }
 
+    fn assets_info(
+        &self,
+        start: Option<AssetId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(AssetId, AssetDetails)>> {
+        self.iter_all_by_start::<AssetsInfo>(start.as_ref(), Some(direction))
+            .into_boxed()
+    }
+
+    fn asset_first_seen(
+        &self,
+        asset_id: &AssetId,
+    ) -> StorageResult<Option<AssetFirstSeenInfo>> {
+        self.storage_as_ref::<AssetFirstSeen>()
+            .get(asset_id)
+            .map(|opt| opt.map(|cow| cow.into_owned()))
+    }
+
+    fn asset_supply_changes(
+        &self,
+        asset_id: &AssetId,
+        start: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(BlockHeight, i128)>> {
+        let start = start.map(|height| AssetSupplyHistoryKey::new(asset_id, height));
+        self.iter_all_filtered::<AssetSupplyHistory, _>(
+            Some(*asset_id),
+            start.as_ref(),
+            Some(direction),
+        )
+        .map(|result| result.map(|(key, delta)| (key.block_height, delta)))
+        .into_boxed()
+    }
+
+    fn asset_supply_checkpoint(
+        &self,
+        asset_id: &AssetId,
+        height: BlockHeight,
+    ) -> StorageResult<Option<u128>> {
+        self.storage_as_ref::<AssetSupplyCheckpoints>()
+            .get(&AssetSupplyHistoryKey::new(asset_id, height))
+            .map(|supply| supply.map(|supply| *supply))
+    }
+
+    fn top_addresses_by_tx_count(
+        &self,
+        limit: usize,
+    ) -> StorageResult<Vec<AddressTxCount>> {
+        let top_addresses = self
+            .storage_as_ref::<StatisticTable<TopAddressesByTxCount>>()
+            .get(TOP_ADDRESSES_BY_TX_COUNT)?
+            .unwrap_or_default();
+        Ok(top_addresses.top(limit))
+    }
+
+    fn owner_activity(&self, owner: &Address) -> StorageResult<Option<OwnerActivity>> {
+        self.storage_as_ref::<OwnerActivities>()
+            .get(owner)
+            .map(|opt| opt.map(|cow| cow.into_owned()))
+    }
+
+    fn owner_last_activity(&self, owner: &Address) -> StorageResult<Option<BlockHeight>> {
+        self.storage_as_ref::<OwnerLastActivity>()
+            .get(owner)
+            .map(|opt| opt.map(|cow| cow.into_owned()))
+    }
+
+    fn known_owner(&self, owner: &Address) -> StorageResult<Option<KnownOwner>> {
+        self.storage_as_ref::<KnownPredicateOwners>()
+            .get(owner)
+            .map(|opt| opt.map(|cow| cow.into_owned()))
+    }
+
+    fn contract_balances(
+        &self,
+        contract: ContractId,
+        start_asset: Option<AssetId>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<ContractBalance>> {
+        let start_asset =
+            start_asset.map(|asset| ContractsAssetKey::new(&contract, &asset));
+        self.iter_all_filtered::<ContractBalances, _>(
+            Some(contract),
+            start_asset.as_ref(),
+            Some(direction),
+        )
+        .map(|result| {
+            result.map(|(key, amount)| ContractBalance {
+                owner: *key.contract_id(),
+                amount,
+                asset_id: *key.asset_id(),
+            })
+        })
+        .into_boxed()
+    }
+
     fn balance(
         &self,
         owner: &Address,
         asset_id: &AssetId,
         base_asset_id: &AssetId,
     ) -> StorageResult<TotalBalanceAmount> {
-        let coins = self
+        total_balance(self, owner, asset_id, base_asset_id)
+    }
+
+    fn coin_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> StorageResult<TotalBalanceAmount> {
+        let amount = self
             .storage_as_ref::<CoinBalances>()
             .get(&CoinBalancesKey::new(owner, asset_id))?
             .unwrap_or_default()
-            .into_owned() as TotalBalanceAmount;
-
-        if base_asset_id == asset_id {
-            let MessageBalance {
-                retryable: _, // TODO: https://github.com/FuelLabs/fuel-core/issues/2448
-                non_retryable,
-            } = self
-                .storage_as_ref::<MessageBalances>()
-                .get(owner)?
-                .unwrap_or_default()
-                .into_owned();
-
-            let total = coins.checked_add(non_retryable).ok_or(anyhow::anyhow!(
-                "Total balance overflow: coins: {coins}, messages: {non_retryable}"
-            ))?;
-            Ok(total)
-        } else {
-            Ok(coins)
-        }
+            .into_owned();
+        Ok(amount)
     }
 
     fn balances<'a>(
@@ -323,6 +974,55 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
                 .into_boxed(),
         }
     }
+
+    fn has_coins_to_spend(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> StorageResult<bool> {
+        let prefix: Vec<_> = NON_RETRYABLE_BYTE
+            .as_ref()
+            .iter()
+            .copied()
+            .chain(owner.iter().copied())
+            .chain(asset_id.iter().copied())
+            .collect();
+
+        // Only the keys are read, and the iteration stops at the first one.
+        let first = self
+            .iter_all_filtered_keys::<CoinsToSpendIndex, _>(Some(&prefix), None, None)
+            .next()
+            .transpose()?;
+        Ok(first.is_some())
+    }
+
+    fn indexation_stale(&self, kind: IndexationKind) -> StorageResult<bool> {
+        let stale = self
+            .storage_as_ref::<MetadataTable<OffChain>>()
+            .get(&())?
+            .is_some_and(|metadata| metadata.indexation_stale(kind));
+        Ok(stale)
+    }
+
+    fn indexation_dead_letter(&self) -> StorageResult<Option<IndexationDeadLetter>> {
+        let dead_letter = self
+            .storage_as_ref::<StatisticTable<IndexationDeadLetter>>()
+            .get(INDEXATION_DEAD_LETTER)?
+            .map(|dead_letter| dead_letter.into_owned());
+        Ok(dead_letter)
+    }
+
+    fn last_chain_rollback(&self) -> StorageResult<Option<ChainRollback>> {
+        let rollback = self
+            .storage_as_ref::<LastChainRollback>()
+            .get(&LAST_CHAIN_ROLLBACK_KEY)?
+            .map(|rollback| rollback.into_owned());
+        Ok(rollback)
+    }
+
+    fn offchain_state_hash(&self) -> StorageResult<Bytes32> {
+        offchain_state_hash(self)
+    }
 }
 
 impl OffChainIterableKeyValueView {
@@ -412,26 +1112,514 @@ impl worker::OffChainDatabase for Database<OffChain> {
         = StorageTransaction<&'a mut Self>
     where
         Self: 'a;
+    type View = OffChainIterableKeyValueView;
 
     fn latest_height(&self) -> StorageResult<Option<BlockHeight>> {
         Ok(fuel_core_storage::transactional::HistoricalView::latest_height(self))
     }
 
+    fn latest_view(&self) -> StorageResult<Self::View> {
+        fuel_core_storage::transactional::AtomicView::latest_view(self)
+    }
+
     fn transaction(&mut self) -> Self::Transaction<'_> {
         self.into_transaction()
     }
 
+    fn commit_blocks(&mut self, changes: Vec<Changes>) -> StorageResult<()> {
+        self.commit_changes_of_blocks(changes)
+    }
+
     fn balances_indexation_enabled(&self) -> StorageResult<bool> {
-        self.indexation_available(IndexationKind::Balances)
+        indexation_enabled(self, IndexationKind::Balances)
     }
 
     fn coins_to_spend_indexation_enabled(&self) -> StorageResult<bool> {
-        self.indexation_available(IndexationKind::CoinsToSpend)
+        indexation_enabled(self, IndexationKind::CoinsToSpend)
     }
 
     fn asset_metadata_indexation_enabled(&self) -> StorageResult<bool> {
-        self.indexation_available(IndexationKind::AssetMetadata)
+        indexation_enabled(self, IndexationKind::AssetMetadata)
+    }
+
+    fn contract_balances_indexation_enabled(&self) -> StorageResult<bool> {
+        indexation_enabled(self, IndexationKind::ContractBalances)
+    }
+
+    fn owned_coin_counts_indexation_enabled(&self) -> StorageResult<bool> {
+        indexation_enabled(self, IndexationKind::OwnedCoinCounts)
+    }
+
+    fn indexation_stale(&self, kind: IndexationKind) -> StorageResult<bool> {
+        Database::indexation_stale(self, kind)
+    }
+
+    fn indexation_dead_letter(&self) -> StorageResult<Option<IndexationDeadLetter>> {
+        let dead_letter = self
+            .storage_as_ref::<StatisticTable<IndexationDeadLetter>>()
+            .get(INDEXATION_DEAD_LETTER)?
+            .map(|dead_letter| dead_letter.into_owned());
+        Ok(dead_letter)
+    }
+
+    fn set_indexation_dead_letter(
+        &mut self,
+        dead_letter: Option<&IndexationDeadLetter>,
+    ) -> StorageResult<()> {
+        let mut transaction = self.read_transaction();
+        let mut storage =
+            transaction.storage_as_mut::<StatisticTable<IndexationDeadLetter>>();
+        match dead_letter {
+            Some(dead_letter) => storage.insert(INDEXATION_DEAD_LETTER, dead_letter)?,
+            None => storage.remove(INDEXATION_DEAD_LETTER)?,
+        }
+        self.commit_changes_without_height(transaction.into_changes())
+    }
+
+    fn relayed_tx_statuses_before(
+        &self,
+        height: BlockHeight,
+        limit: usize,
+    ) -> StorageResult<Vec<RelayedTransactionStatusHeightKey>> {
+        self.iter_all_keys::<RelayedTransactionStatusHeights>(Some(
+            IterDirection::Forward,
+        ))
+        .take_while(|key| key.as_ref().map_or(true, |key| key.block_height < height))
+        .take(limit)
+        .collect()
+    }
+
+    fn included_tx_count(&self) -> StorageResult<u64> {
+        let mut tx_count = 0u64;
+        for entry in self.iter_all::<TransactionStatuses>(None) {
+            let (_, status) = entry?;
+            if matches!(
+                status,
+                txpool::TransactionExecutionStatus::Success { .. }
+                    | txpool::TransactionExecutionStatus::Failed { .. }
+            ) {
+                tx_count = tx_count.saturating_add(1);
+            }
+        }
+        Ok(tx_count)
     }
+
+    fn set_tx_count(&mut self, tx_count: u64) -> StorageResult<()> {
+        let mut transaction = self.read_transaction();
+        transaction
+            .storage_as_mut::<StatisticTable<u64>>()
+            .insert(TX_COUNT, &tx_count)?;
+        transaction
+            .storage_as_mut::<StatisticTable<u64>>()
+            .remove(TX_COUNT_DELTA)?;
+        self.commit_changes_without_height(transaction.into_changes())
+    }
+
+    fn commit_duration_max(&self) -> StorageResult<Option<CommitDurationMax>> {
+        commit_duration_max(self)
+    }
+
+    fn set_commit_duration_max(&mut self, max: &CommitDurationMax) -> StorageResult<()> {
+        let mut transaction = self.read_transaction();
+        transaction
+            .storage_as_mut::<StatisticTable<CommitDurationMax>>()
+            .insert(OFF_CHAIN_COMMIT_DURATION_MAX, max)?;
+        self.commit_changes_without_height(transaction.into_changes())
+    }
+
+    fn da_compression_height(&self) -> StorageResult<Option<BlockHeight>> {
+        da_compression_height(self)
+    }
+
+    fn commit_da_compression(&mut self, changes: Changes) -> StorageResult<()> {
+        self.commit_changes_without_height(changes)
+    }
+}
+
+/// The columns skipped by the [`offchain_state_hash`], because their content is local
+/// to the node rather than derived from the indexed blocks: the metadata of the database,
+/// the cache of the message proofs, and the tags attached to the transactions by
+/// the operator. The dictionaries are skipped too, because they only encode
+/// the DA compressed blocks, which are hashed decoded.
+const STATE_HASH_SKIPPED_COLUMNS: [Column; 4] = [
+    Column::Metadata,
+    Column::MessageProofCache,
+    Column::TransactionTags,
+    Column::DaCompressionDictionaries,
+];
+
+/// The keys of the `Statistic` column skipped by the [`offchain_state_hash`],
+/// because they track the node itself: the failed indexation, the durations
+/// of the commits, and the order of use of the cached message proofs.
+const STATE_HASH_SKIPPED_STATISTICS: [&str; 3] = [
+    INDEXATION_DEAD_LETTER,
+    OFF_CHAIN_COMMIT_DURATION_MAX,
+    MESSAGE_PROOF_CACHE_ENTRIES,
+];
+
+/// Folds the key-value pairs of all columns into one hash. The columns are visited
+/// in the order of their ids. The empty columns are skipped, so the columns
+/// of the `fault-proving` build don't change the hash while they are unused.
+///
+/// The entries are hashed in the form independent of the configuration of the node,
+/// see [`state_hash_entries`], so the nodes that indexed the same blocks have
+/// the same hash.
+fn offchain_state_hash<S>(storage: &S) -> StorageResult<Bytes32>
+where
+    S: IterableStore<Column = Column>,
+{
+    fn input_bytes(hasher: &mut Hasher, bytes: &[u8]) {
+        let len = u64::try_from(bytes.len()).unwrap_or(u64::MAX);
+        hasher.input(len.to_be_bytes());
+        hasher.input(bytes);
+    }
+
+    let mut columns = enum_iterator::all::<Column>()
+        .filter(|column| !STATE_HASH_SKIPPED_COLUMNS.contains(column))
+        .collect::<Vec<_>>();
+    columns.sort_by_key(|column| column.id());
+
+    let mut hasher = Hasher::default();
+    for column in columns {
+        let mut entries = state_hash_entries(storage, column).peekable();
+        if entries.peek().is_none() {
+            continue
+        }
+
+        // The tags separate the ids of the columns from their entries.
+        hasher.input([0u8]);
+        hasher.input(column.id().to_be_bytes());
+        for entry in entries {
+            let (key, value) = entry?;
+            hasher.input([1u8]);
+            input_bytes(&mut hasher, &key);
+            input_bytes(&mut hasher, &value);
+        }
+    }
+
+    Ok(hasher.digest())
 }
 
-impl OffChainDatabaseAt for OffChainKeyValueView {}
+/// Returns the entries of the `column` hashed by the [`offchain_state_hash`].
+/// The encodings chosen by the configuration of the node are decoded:
+/// - The keys of the `OwnedCoins` are in the plain layout, and the coins flagged
+///   as spent are skipped, as if they were removed.
+/// - The DA compressed blocks are the postcard encoded blocks, without the zstd.
+/// - The node-local keys of the `Statistic` are skipped.
+fn state_hash_entries<S>(storage: &S, column: Column) -> BoxedIter<'_, KVItem>
+where
+    S: IterableStore<Column = Column>,
+{
+    let entries = storage.iter_store(column, None, None, IterDirection::Forward);
+    match column {
+        Column::Statistic => {
+            let skipped = STATE_HASH_SKIPPED_STATISTICS
+                .map(|key| Postcard::encode(key).into_owned());
+            entries
+                .filter(
+                    move |entry| !matches!(entry, Ok((key, _)) if skipped.contains(key)),
+                )
+                .into_boxed()
+        }
+        Column::DaCompressedBlocks => entries
+            .map(|entry| {
+                let (key, value) = entry?;
+                let payload = da_block_payload(&value).map_err(StorageError::Codec)?;
+                Ok((key, Value::from(payload.as_ref())))
+            })
+            .into_boxed(),
+        Column::OwnedCoins => owned_coins_state_entries(entries).into_boxed(),
+        _ => entries,
+    }
+}
+
+/// Converts the entries of the `OwnedCoins` column into the plain layout.
+/// Both layouts start the key with the owner, so only the coins of the same
+/// owner are sorted again.
+fn owned_coins_state_entries(
+    entries: BoxedIter<'_, KVItem>,
+) -> impl Iterator<Item = KVItem> + '_ {
+    let mut entries = entries.peekable();
+    let owners = iter::from_fn(move || {
+        let first = entries.next()?;
+        let coins = first.and_then(|first| {
+            let owner = first.0.get(..Address::LEN).unwrap_or_default().to_vec();
+            let mut coins = vec![plain_owned_coin(first)?];
+            while let Some(Ok((key, _))) = entries.peek() {
+                if !key.starts_with(&owner) {
+                    break
+                }
+                let entry = entries.next().expect("The entry was peeked above")?;
+                coins.push(plain_owned_coin(entry)?);
+            }
+            let mut coins = coins.into_iter().flatten().collect::<Vec<_>>();
+            coins.sort_by(|a, b| a.0.cmp(&b.0));
+            Ok(coins)
+        });
+        Some(coins)
+    });
+    owners.flat_map(|coins| match coins {
+        Ok(coins) => coins.into_iter().map(Ok).collect::<Vec<_>>(),
+        Err(e) => vec![Err(e)],
+    })
+}
+
+/// Returns the entry of the owned coin with the key in the plain layout,
+/// or `None` if the coin is flagged as spent.
+fn plain_owned_coin(
+    (key, value): (Vec<u8>, Value),
+) -> StorageResult<Option<(Vec<u8>, Value)>> {
+    let state = Manual::<OwnedCoinState>::decode(&value).map_err(StorageError::Codec)?;
+    if state.is_spent() {
+        return Ok(None)
+    }
+    let key = Manual::<OwnedCoinKey>::decode(&key).map_err(StorageError::Codec)?;
+    Ok(Some((Manual::<OwnedCoinKey>::encode(&key).to_vec(), value)))
+}
+
+fn commit_duration_max<S>(storage: &S) -> StorageResult<Option<CommitDurationMax>>
+where
+    S: StorageInspect<StatisticTable<CommitDurationMax>, Error = StorageError>,
+{
+    let max = storage
+        .storage_as_ref::<StatisticTable<CommitDurationMax>>()
+        .get(OFF_CHAIN_COMMIT_DURATION_MAX)?
+        .map(|max| max.into_owned());
+    Ok(max)
+}
+
+/// Returns the height of the last block compressed for the DA. The databases
+/// compressed before the height was tracked fall back to the height of
+/// the last compressed block.
+fn da_compression_height<S>(storage: &S) -> StorageResult<Option<BlockHeight>>
+where
+    S: StorageInspect<StatisticTable<BlockHeight>, Error = StorageError>
+        + IteratorOverTable
+        + IterableTable<DaCompressedBlocks>,
+{
+    let height = storage
+        .storage_as_ref::<StatisticTable<BlockHeight>>()
+        .get(DA_COMPRESSION_HEIGHT)?;
+    if let Some(height) = height {
+        return Ok(Some(height.into_owned()))
+    }
+
+    storage
+        .iter_all_keys::<DaCompressedBlocks>(Some(IterDirection::Reverse))
+        .next()
+        .transpose()
+}
+
+/// The indexation is enabled if it is available and its index is not being rebuilt.
+fn indexation_enabled(
+    database: &Database<OffChain>,
+    kind: IndexationKind,
+) -> StorageResult<bool> {
+    Ok(database.indexation_available(kind)? && !database.indexation_stale(kind)?)
+}
+
+impl MessageProofCacheStorage for Database<OffChain> {
+    fn cached_message_proof(
+        &self,
+        key: &MessageProofCacheKey,
+    ) -> StorageResult<Option<MessageProof>> {
+        self.storage_as_ref::<MessageProofCache>()
+            .get(key)
+            .map(|opt| opt.map(|cow| cow.into_owned()))
+    }
+
+    fn message_proof_cache_entries(&self) -> StorageResult<MessageProofCacheEntries> {
+        let entries = self
+            .storage_as_ref::<StatisticTable<MessageProofCacheEntries>>()
+            .get(MESSAGE_PROOF_CACHE_ENTRIES)?
+            .map(|entries| entries.into_owned())
+            .unwrap_or_default();
+        Ok(entries)
+    }
+
+    fn update_message_proof_cache(
+        &self,
+        entries: &MessageProofCacheEntries,
+        proof: Option<(&MessageProofCacheKey, &MessageProof)>,
+        evicted: &[MessageProofCacheKey],
+    ) -> StorageResult<()> {
+        let mut transaction = self.read_transaction();
+        if let Some((key, proof)) = proof {
+            transaction
+                .storage_as_mut::<MessageProofCache>()
+                .insert(key, proof)?;
+        }
+        for key in evicted {
+            transaction
+                .storage_as_mut::<MessageProofCache>()
+                .remove(key)?;
+        }
+        transaction
+            .storage_as_mut::<StatisticTable<MessageProofCacheEntries>>()
+            .insert(MESSAGE_PROOF_CACHE_ENTRIES, entries)?;
+        self.commit_changes_without_height(transaction.into_changes())
+    }
+}
+
+impl OwnerIndexesPruner for Database<OffChain> {
+    fn prune_owner(&self, owner: &Address) -> StorageResult<PrunedOwnerIndexes> {
+        let view = self.latest_view()?;
+        let height = view.metadata().copied();
+        let mut transaction = self.read_transaction();
+
+        // The decoded keys are encoded back with the layout of the column.
+        let owned_coins = match owned_coins_layout(&view)? {
+            OwnedCoinsLayout::Plain => {
+                remove_owner_entries::<OwnedCoins, _>(&view, &mut transaction, owner)?
+            }
+            OwnedCoinsLayout::Sharded => remove_owner_entries::<ShardedOwnedCoins, _>(
+                &view,
+                &mut transaction,
+                owner,
+            )?,
+        };
+        let pruned = PrunedOwnerIndexes {
+            owned_coins,
+            owned_message_ids: remove_owner_entries::<OwnedMessageIds, _>(
+                &view,
+                &mut transaction,
+                owner,
+            )?,
+            owned_transactions: remove_owner_entries::<OwnedTransactions, _>(
+                &view,
+                &mut transaction,
+                owner,
+            )?,
+            owned_coin_counts: remove_owner_entries::<OwnedCoinCountByAsset, _>(
+                &view,
+                &mut transaction,
+                owner,
+            )?,
+            owner_activities: transaction
+                .storage_as_mut::<OwnerActivities>()
+                .take(owner)?
+                .map_or(0, |_| 1),
+        };
+        transaction
+            .storage_as_mut::<OwnerLastActivity>()
+            .remove(owner)?;
+        // The coins by asset mirror the owned coins, so they aren't counted separately.
+        remove_owner_entries::<OwnedCoinsByAsset, _>(&view, &mut transaction, owner)?;
+
+        // The entries were collected at the `height`, so the block indexed
+        // in the meantime could add new entries of the owner.
+        if !self.commit_changes_without_height_at(height, transaction.into_changes())? {
+            return Err(anyhow::anyhow!(
+                "The off-chain database changed during the pruning of the owner, retry"
+            )
+            .into())
+        }
+        Ok(pruned)
+    }
+
+    fn remove_owned_coins(
+        &self,
+        owner: &Address,
+        utxo_ids: &[UtxoId],
+        height: BlockHeight,
+    ) -> StorageResult<u64> {
+        let mut transaction = self.read_transaction();
+        let layout = owned_coins_layout(&transaction)?;
+        let mut removed = 0u64;
+        for utxo_id in utxo_ids {
+            let key = owner_coin_id_key(owner, utxo_id);
+            if take_owned_coin(&mut transaction, layout, &key)?.is_some() {
+                removed = removed.saturating_add(1);
+            }
+        }
+
+        if !self
+            .commit_changes_without_height_at(Some(height), transaction.into_changes())?
+        {
+            return Err(anyhow::anyhow!(
+                "The off-chain database changed since the coins were checked, retry"
+            )
+            .into())
+        }
+        Ok(removed)
+    }
+}
+
+impl TransactionTagger for Database<OffChain> {
+    fn tag_transaction(&self, tx_id: &TxId, tag: &[u8]) -> StorageResult<()> {
+        let mut transaction = self.read_transaction();
+        transaction
+            .storage_as_mut::<TransactionTags>()
+            .insert(tx_id, &tag.to_vec())?;
+        self.commit_changes_without_height(transaction.into_changes())
+    }
+}
+
+/// Removes the entries of the table that have the `owner` as the key prefix
+/// and returns the number of removed entries.
+fn remove_owner_entries<M, T>(
+    view: &OffChainIterableKeyValueView,
+    transaction: &mut T,
+    owner: &Address,
+) -> StorageResult<u64>
+where
+    M: Mappable,
+    OffChainIterableKeyValueView: IterableTable<M>,
+    T: StorageMutate<M, Error = StorageError>,
+{
+    let mut removed = 0;
+    for key in view.iter_all_filtered_keys::<M, _>(Some(owner), None, None) {
+        let key = key?;
+        transaction.storage_as_mut::<M>().remove(key.borrow())?;
+        removed += 1;
+    }
+    Ok(removed)
+}
+
+impl OffChainDatabaseAt for OffChainKeyValueView {
+    fn balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        base_asset_id: &AssetId,
+    ) -> StorageResult<TotalBalanceAmount> {
+        total_balance(self, owner, asset_id, base_asset_id)
+    }
+}
+
+/// Returns the balance of the `owner` from the balances index. The balance of
+/// the base asset includes the non-retryable messages.
+fn total_balance<S>(
+    storage: &S,
+    owner: &Address,
+    asset_id: &AssetId,
+    base_asset_id: &AssetId,
+) -> StorageResult<TotalBalanceAmount>
+where
+    S: StorageInspect<CoinBalances, Error = StorageError>
+        + StorageInspect<MessageBalances, Error = StorageError>,
+{
+    let coins = storage
+        .storage_as_ref::<CoinBalances>()
+        .get(&CoinBalancesKey::new(owner, asset_id))?
+        .unwrap_or_default()
+        .into_owned() as TotalBalanceAmount;
+
+    if base_asset_id == asset_id {
+        let MessageBalance {
+            retryable: _, // TODO: https://github.com/FuelLabs/fuel-core/issues/2448
+            non_retryable,
+        } = storage
+            .storage_as_ref::<MessageBalances>()
+            .get(owner)?
+            .unwrap_or_default()
+            .into_owned();
+
+        let total = coins.checked_add(non_retryable).ok_or(anyhow::anyhow!(
+            "Total balance overflow: coins: {coins}, messages: {non_retryable}"
+        ))?;
+        Ok(total)
+    } else {
+        Ok(coins)
+    }
+}
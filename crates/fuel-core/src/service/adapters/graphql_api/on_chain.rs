@@ -40,6 +40,7 @@ use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
         consensus::Consensus,
+        header::BlockHeader,
         primitives::DaBlockHeight,
     },
     entities::relayer::message::Message,
@@ -77,6 +78,11 @@ impl DatabaseBlocks for OnChainIterableKeyValueView {
         Ok(block)
     }
 
+    fn block_header(&self, height: &BlockHeight) -> StorageResult<BlockHeader> {
+        self.block_header(height)?
+            .ok_or_else(|| not_found!(FuelBlocks))
+    }
+
     fn blocks(
         &self,
         height: Option<BlockHeight>,
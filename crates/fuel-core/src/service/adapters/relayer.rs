@@ -1,9 +1,13 @@
-use crate::database::{
-    database_description::relayer::Relayer,
-    Database,
+use crate::{
+    database::{
+        database_description::relayer::Relayer,
+        Database,
+    },
+    fuel_core_graphql_api::ports::worker,
 };
 use fuel_core_relayer::ports::Transactional;
 use fuel_core_storage::{
+    not_found,
     transactional::{
         AtomicView,
         HistoricalView,
@@ -15,6 +19,8 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
+    entities::relayer::transaction::RelayedTransaction,
+    fuel_types::BlockHeight,
     services::relayer::Event,
 };
 
@@ -45,3 +51,49 @@ impl Database<Relayer> {
         Ok(events)
     }
 }
+
+/// Reads the relayed transactions of the blocks from the relayer database,
+/// using the DA heights of the blocks from the on-chain database.
+#[derive(Debug, Clone)]
+pub struct RelayedTransactionsAdapter {
+    on_chain: Database,
+    relayer: Database<Relayer>,
+}
+
+impl RelayedTransactionsAdapter {
+    pub fn new(on_chain: Database, relayer: Database<Relayer>) -> Self {
+        Self { on_chain, relayer }
+    }
+}
+
+impl worker::RelayedTransactionsProvider for RelayedTransactionsAdapter {
+    fn relayed_transactions(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Vec<RelayedTransaction>> {
+        // The first block has no previous block to start the DA range from,
+        // and the genesis doesn't execute the relayed transactions.
+        let Some(previous_height) = height.pred() else {
+            return Ok(vec![]);
+        };
+        let view = self.on_chain.latest_view()?;
+        let da_height = |height: &BlockHeight| -> StorageResult<u64> {
+            let header = view
+                .block_header(height)?
+                .ok_or_else(|| not_found!("FuelBlocks"))?;
+            Ok(header.da_height().0)
+        };
+        let first_da_height = da_height(&previous_height)?.saturating_add(1);
+        let last_da_height = da_height(height)?;
+
+        let mut transactions = vec![];
+        for da_height in first_da_height..=last_da_height {
+            let events = self.relayer.get_events(&da_height.into())?;
+            transactions.extend(events.into_iter().filter_map(|event| match event {
+                Event::Transaction(transaction) => Some(transaction),
+                Event::Message(_) => None,
+            }));
+        }
+        Ok(transactions)
+    }
+}
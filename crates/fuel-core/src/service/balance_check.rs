@@ -0,0 +1,608 @@
+//! The balance check detects the drift of the balances index while the node
+//! is running.
+//!
+//! The check samples one owner per tick, recomputes the balances of the owner
+//! from the owned coins and messages, and compares them with the cached
+//! `CoinBalances` and `MessageBalances`. The owners are visited in the order
+//! of their addresses, and the check starts over after the last one.
+//!
+//! The off-chain view and the on-chain view of the check are pinned to the same
+//! height, so the blocks imported during the check don't cause false mismatches.
+//! If the on-chain database doesn't have the state at the height of the off-chain
+//! database, the owner is checked on the next tick.
+
+use crate::{
+    database::{
+        database_description::{
+            off_chain::OffChain,
+            on_chain::OnChain,
+            IndexationKind,
+        },
+        Database,
+        OffChainIterableKeyValueView,
+        OnChainKeyValueView,
+    },
+    graphql_api::storage::{
+        balances::{
+            CoinBalances,
+            CoinBalancesKey,
+            MessageBalance,
+            MessageBalances,
+            TotalBalanceAmount,
+        },
+        coins::OwnedCoins,
+        messages::OwnedMessageIds,
+    },
+};
+use fuel_core_metrics::graphql_metrics::graphql_metrics;
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+    TaskNextAction,
+};
+use fuel_core_storage::{
+    iter::{
+        IterDirection,
+        IteratorOverTable,
+    },
+    tables::{
+        Coins,
+        Messages,
+    },
+    transactional::{
+        AtomicView,
+        HistoricalView,
+        ReadTransaction,
+    },
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        Address,
+        AssetId,
+    },
+    fuel_types::BlockHeight,
+};
+use std::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+    },
+    num::NonZeroU32,
+    time::Duration,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// The number of owners checked per minute.
+    pub owners_per_minute: NonZeroU32,
+    /// Overwrites the mismatching cached balances with the recomputed ones.
+    pub repair: bool,
+}
+
+/// The cached balance of the owner that differs from the recomputed one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BalanceMismatch {
+    Coin {
+        asset_id: AssetId,
+        cached: TotalBalanceAmount,
+        expected: TotalBalanceAmount,
+    },
+    Message {
+        cached: MessageBalance,
+        expected: MessageBalance,
+    },
+}
+
+/// The result of the check of one owner.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OwnerCheck {
+    pub owner: Address,
+    /// The height of the pinned views used by the check.
+    pub height: Option<BlockHeight>,
+    pub mismatches: Vec<BalanceMismatch>,
+    /// `true` if the mismatches were repaired.
+    pub repaired: bool,
+}
+
+/// The initialization task starts the interval of the checks.
+pub struct InitializeTask {
+    config: Config,
+    on_chain: Database<OnChain>,
+    off_chain: Database<OffChain>,
+}
+
+pub struct Task {
+    on_chain: Database<OnChain>,
+    off_chain: Database<OffChain>,
+    interval: tokio::time::Interval,
+    repair: bool,
+    /// The last checked owner.
+    last_owner: Option<Address>,
+}
+
+impl Task {
+    fn new(
+        config: Config,
+        on_chain: Database<OnChain>,
+        off_chain: Database<OffChain>,
+    ) -> Self {
+        let period = Duration::from_secs(60) / config.owners_per_minute.get();
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        Self {
+            on_chain,
+            off_chain,
+            interval,
+            repair: config.repair,
+            last_owner: None,
+        }
+    }
+
+    /// Checks the owner that follows the last checked owner. Returns `None` if
+    /// the balances index is not available or the consistent views can't be pinned.
+    pub fn check_next_owner(&mut self) -> StorageResult<Option<OwnerCheck>> {
+        if !self
+            .off_chain
+            .indexation_available(IndexationKind::Balances)?
+            || self.off_chain.indexation_stale(IndexationKind::Balances)?
+        {
+            return Ok(None)
+        }
+
+        let off_chain = self.off_chain.latest_view()?;
+        let height = off_chain.metadata().copied();
+        let Some(on_chain) = self.on_chain_view_at(height)? else {
+            tracing::debug!(
+                ?height,
+                "The on-chain state at the indexed height is unavailable, \
+                 skipping the balance check"
+            );
+            return Ok(None)
+        };
+
+        let owner = match next_owner(&off_chain, self.last_owner.as_ref())? {
+            Some(owner) => owner,
+            // Starts over after the last owner.
+            None => match next_owner(&off_chain, None)? {
+                Some(owner) => owner,
+                None => return Ok(None),
+            },
+        };
+        self.last_owner = Some(owner);
+
+        let mismatches = find_mismatches(&off_chain, &on_chain, &owner)?;
+        let repaired = self.repair
+            && !mismatches.is_empty()
+            && self.repair_balances(&owner, height, &mismatches)?;
+
+        Ok(Some(OwnerCheck {
+            owner,
+            height,
+            mismatches,
+            repaired,
+        }))
+    }
+
+    fn on_chain_view_at(
+        &self,
+        height: Option<BlockHeight>,
+    ) -> StorageResult<Option<OnChainKeyValueView>> {
+        match height {
+            // The view is unavailable if the on-chain database moved further
+            // and doesn't keep the history of the `height`.
+            Some(height) => Ok(self.on_chain.view_at(&height).ok()),
+            None if HistoricalView::latest_height(&self.on_chain).is_none() => {
+                Ok(Some(self.on_chain.latest_view()?.into_key_value_view()))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Overwrites the cached balances with the recomputed ones. The changes are
+    /// committed only if no block was indexed since the balances were recomputed,
+    /// otherwise the owner is repaired on the next round.
+    fn repair_balances(
+        &self,
+        owner: &Address,
+        height: Option<BlockHeight>,
+        mismatches: &[BalanceMismatch],
+    ) -> StorageResult<bool> {
+        let mut tx = self.off_chain.read_transaction();
+        for mismatch in mismatches {
+            match mismatch {
+                BalanceMismatch::Coin {
+                    asset_id, expected, ..
+                } => {
+                    tx.storage_as_mut::<CoinBalances>()
+                        .insert(&CoinBalancesKey::new(owner, asset_id), expected)?;
+                }
+                BalanceMismatch::Message { expected, .. } => {
+                    tx.storage_as_mut::<MessageBalances>()
+                        .insert(owner, expected)?;
+                }
+            }
+        }
+        let changes = tx.into_changes();
+        self.off_chain
+            .commit_changes_without_height_at(height, changes)
+    }
+
+    fn report(&self, check: &OwnerCheck) {
+        for mismatch in &check.mismatches {
+            tracing::warn!(
+                owner = %check.owner,
+                height = ?check.height,
+                ?mismatch,
+                repaired = check.repaired,
+                "The cached balance differs from the recomputed one"
+            );
+            graphql_metrics().record_balance_mismatch();
+            if check.repaired {
+                graphql_metrics().record_balance_repair();
+            }
+        }
+    }
+}
+
+/// Returns the first owner with a cached balance after the `after` owner.
+fn next_owner(
+    view: &OffChainIterableKeyValueView,
+    after: Option<&Address>,
+) -> StorageResult<Option<Address>> {
+    let is_next = |owner: &Address| after.map_or(true, |after| owner > after);
+
+    // The coin balances of the `after` owner end with the largest asset id.
+    let start =
+        after.map(|after| CoinBalancesKey::new(after, &AssetId::new([u8::MAX; 32])));
+    let coin_owner = view
+        .iter_all_filtered_keys::<CoinBalances, [u8; 0]>(
+            None,
+            start.as_ref(),
+            Some(IterDirection::Forward),
+        )
+        .map(|result| result.map(|key| *key.address()))
+        .find(|result| result.as_ref().map_or(true, is_next))
+        .transpose()?;
+
+    let message_owner = view
+        .iter_all_filtered_keys::<MessageBalances, [u8; 0]>(
+            None,
+            after,
+            Some(IterDirection::Forward),
+        )
+        .find(|result| result.as_ref().map_or(true, is_next))
+        .transpose()?;
+
+    Ok(coin_owner.into_iter().chain(message_owner).min())
+}
+
+/// Recomputes the balances of the `owner` from the owned coins and messages
+/// and returns the cached balances that differ from them.
+fn find_mismatches(
+    off_chain: &OffChainIterableKeyValueView,
+    on_chain: &OnChainKeyValueView,
+    owner: &Address,
+) -> StorageResult<Vec<BalanceMismatch>> {
+    let mut expected_coins = BTreeMap::<AssetId, TotalBalanceAmount>::new();
+    for utxo_id in off_chain.owned_coins_ids(owner, None, None, false) {
+        let utxo_id = utxo_id?;
+        let Some(coin) = on_chain.storage::<Coins>().get(&utxo_id)? else {
+            tracing::warn!(%owner, %utxo_id, "The owned coin doesn't exist");
+            continue
+        };
+        let balance = expected_coins.entry(*coin.asset_id()).or_default();
+        *balance = balance.saturating_add(u128::from(*coin.amount()));
+    }
+
+    let mut cached_coins = BTreeMap::<AssetId, TotalBalanceAmount>::new();
+    for entry in off_chain.iter_all_filtered::<CoinBalances, _>(Some(owner), None, None) {
+        let (key, amount) = entry?;
+        cached_coins.insert(*key.asset_id(), amount);
+    }
+
+    let mut mismatches = Vec::new();
+    let asset_ids = expected_coins
+        .keys()
+        .chain(cached_coins.keys())
+        .copied()
+        .collect::<BTreeSet<_>>();
+    for asset_id in asset_ids {
+        let expected = expected_coins.get(&asset_id).copied().unwrap_or_default();
+        let cached = cached_coins.get(&asset_id).copied().unwrap_or_default();
+        if expected != cached {
+            mismatches.push(BalanceMismatch::Coin {
+                asset_id,
+                cached,
+                expected,
+            });
+        }
+    }
+
+    let mut expected_messages = MessageBalance::default();
+    for nonce in off_chain.owned_message_ids(owner, None, None) {
+        let nonce = nonce?;
+        let Some(message) = on_chain.storage::<Messages>().get(&nonce)? else {
+            tracing::warn!(%owner, %nonce, "The owned message doesn't exist");
+            continue
+        };
+        let amount = u128::from(message.amount());
+        if message.is_retryable_message() {
+            expected_messages.retryable =
+                expected_messages.retryable.saturating_add(amount);
+        } else {
+            expected_messages.non_retryable =
+                expected_messages.non_retryable.saturating_add(amount);
+        }
+    }
+    let cached_messages = off_chain
+        .storage::<MessageBalances>()
+        .get(owner)?
+        .map(|balance| balance.into_owned())
+        .unwrap_or_default();
+    if cached_messages != expected_messages {
+        mismatches.push(BalanceMismatch::Message {
+            cached: cached_messages,
+            expected: expected_messages,
+        });
+    }
+
+    Ok(mismatches)
+}
+
+#[async_trait::async_trait]
+impl RunnableService for InitializeTask {
+    const NAME: &'static str = "BalanceCheck";
+    type SharedData = ();
+    type Task = Task;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let InitializeTask {
+            config,
+            on_chain,
+            off_chain,
+        } = self;
+        tracing::info!(
+            owners_per_minute = %config.owners_per_minute,
+            repair = config.repair,
+            "Starting the balance check"
+        );
+        Ok(Task::new(config, on_chain, off_chain))
+    }
+}
+
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> TaskNextAction {
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                TaskNextAction::Stop
+            }
+
+            _ = self.interval.tick() => {
+                match self.check_next_owner() {
+                    Ok(Some(check)) => {
+                        self.report(&check);
+                        TaskNextAction::Continue
+                    }
+                    Ok(None) => TaskNextAction::Continue,
+                    Err(e) => TaskNextAction::ErrorContinue(e.into()),
+                }
+            }
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    config: Config,
+    on_chain: Database<OnChain>,
+    off_chain: Database<OffChain>,
+) -> ServiceRunner<InitializeTask> {
+    ServiceRunner::new(InitializeTask {
+        config,
+        on_chain,
+        off_chain,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use crate::graphql_api::storage::{
+        coins::{
+            OwnedCoinKey,
+            OwnedCoinState,
+        },
+        messages::OwnedMessageKey,
+    };
+    use fuel_core_types::{
+        entities::{
+            coins::coin::CompressedCoin,
+            relayer::message::{
+                Message,
+                MessageV1,
+            },
+        },
+        fuel_tx::UtxoId,
+        fuel_types::Nonce,
+    };
+
+    const OWNER: Address = Address::new([1; 32]);
+    const ASSET_ID: AssetId = AssetId::new([2; 32]);
+
+    fn config(repair: bool) -> Config {
+        Config {
+            owners_per_minute: NonZeroU32::new(60).unwrap(),
+            repair,
+        }
+    }
+
+    /// Creates the databases with a coin of 100 and a non-retryable message
+    /// of 30 owned by the `OWNER`, and the matching cached balances.
+    fn databases() -> (Database<OnChain>, Database<OffChain>) {
+        let mut on_chain = Database::<OnChain>::default();
+        let mut off_chain = Database::<OffChain>::default();
+
+        let utxo_id = UtxoId::new([3; 32].into(), 0);
+        let mut coin = CompressedCoin::default();
+        coin.set_owner(OWNER);
+        coin.set_asset_id(ASSET_ID);
+        coin.set_amount(100);
+        on_chain
+            .storage_as_mut::<Coins>()
+            .insert(&utxo_id, &coin)
+            .unwrap();
+        off_chain
+            .storage_as_mut::<OwnedCoins>()
+            .insert(
+                &OwnedCoinKey::new(&OWNER, &utxo_id),
+                &OwnedCoinState::Unspent,
+            )
+            .unwrap();
+
+        let nonce = Nonce::new([4; 32]);
+        let message = Message::V1(MessageV1 {
+            recipient: OWNER,
+            nonce,
+            amount: 30,
+            ..Default::default()
+        });
+        on_chain
+            .storage_as_mut::<Messages>()
+            .insert(&nonce, &message)
+            .unwrap();
+        off_chain
+            .storage_as_mut::<OwnedMessageIds>()
+            .insert(&OwnedMessageKey::new(&OWNER, &nonce), &Default::default())
+            .unwrap();
+
+        off_chain
+            .storage_as_mut::<CoinBalances>()
+            .insert(&CoinBalancesKey::new(&OWNER, &ASSET_ID), &100)
+            .unwrap();
+        off_chain
+            .storage_as_mut::<MessageBalances>()
+            .insert(
+                &OWNER,
+                &MessageBalance {
+                    retryable: 0,
+                    non_retryable: 30,
+                },
+            )
+            .unwrap();
+        (on_chain, off_chain)
+    }
+
+    fn cached_coin_balance(off_chain: &Database<OffChain>) -> TotalBalanceAmount {
+        off_chain
+            .storage::<CoinBalances>()
+            .get(&CoinBalancesKey::new(&OWNER, &ASSET_ID))
+            .unwrap()
+            .unwrap()
+            .into_owned()
+    }
+
+    #[tokio::test]
+    async fn check_next_owner__consistent_balances_have_no_mismatches() {
+        // given
+        let (on_chain, off_chain) = databases();
+        let mut task = Task::new(config(false), on_chain, off_chain);
+
+        // when
+        let check = task.check_next_owner().unwrap().unwrap();
+
+        // then
+        assert_eq!(check.owner, OWNER);
+        assert_eq!(check.mismatches, vec![]);
+        assert!(!check.repaired);
+    }
+
+    #[tokio::test]
+    async fn check_next_owner__detects_corrupted_balance_without_repair() {
+        // given
+        let (on_chain, mut off_chain) = databases();
+        off_chain
+            .storage_as_mut::<CoinBalances>()
+            .insert(&CoinBalancesKey::new(&OWNER, &ASSET_ID), &150)
+            .unwrap();
+        let mut task = Task::new(config(false), on_chain, off_chain.clone());
+
+        // when
+        let check = task.check_next_owner().unwrap().unwrap();
+
+        // then
+        assert_eq!(
+            check.mismatches,
+            vec![BalanceMismatch::Coin {
+                asset_id: ASSET_ID,
+                cached: 150,
+                expected: 100,
+            }]
+        );
+        assert!(!check.repaired);
+        assert_eq!(cached_coin_balance(&off_chain), 150);
+    }
+
+    #[tokio::test]
+    async fn check_next_owner__repairs_corrupted_balances() {
+        // given
+        let (on_chain, mut off_chain) = databases();
+        off_chain
+            .storage_as_mut::<CoinBalances>()
+            .insert(&CoinBalancesKey::new(&OWNER, &ASSET_ID), &150)
+            .unwrap();
+        off_chain
+            .storage_as_mut::<MessageBalances>()
+            .remove(&OWNER)
+            .unwrap();
+        let mut task = Task::new(config(true), on_chain, off_chain.clone());
+
+        // when
+        let check = task.check_next_owner().unwrap().unwrap();
+
+        // then
+        assert_eq!(check.mismatches.len(), 2);
+        assert!(check.repaired);
+        assert_eq!(cached_coin_balance(&off_chain), 100);
+        let check = task.check_next_owner().unwrap().unwrap();
+        assert_eq!(check.mismatches, vec![]);
+    }
+
+    #[tokio::test]
+    async fn check_next_owner__visits_owners_in_order_and_starts_over() {
+        // given
+        let (on_chain, mut off_chain) = databases();
+        let other_owner = Address::new([9; 32]);
+        off_chain
+            .storage_as_mut::<CoinBalances>()
+            .insert(&CoinBalancesKey::new(&other_owner, &ASSET_ID), &0)
+            .unwrap();
+        let mut task = Task::new(config(false), on_chain, off_chain);
+
+        // when
+        let owners = (0..3)
+            .map(|_| task.check_next_owner().unwrap().unwrap().owner)
+            .collect::<Vec<_>>();
+
+        // then
+        assert_eq!(owners, vec![OWNER, other_owner, OWNER]);
+    }
+}
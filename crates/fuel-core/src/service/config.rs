@@ -36,13 +36,15 @@ use fuel_core_types::{
 use crate::{
     combined_database::CombinedDatabaseConfig,
     graphql_api::{
+        indexation::derived_ownership::CoinOwnerMapperRef,
+        storage::da_compression::versioned_block_codec::DaBlockEncoding,
         worker_service::DaCompressionConfig,
         ServiceConfig as GraphQLConfig,
     },
+    service::integrity_check,
 };
 
 use fuel_core_types::fuel_types::AssetId;
-#[cfg(feature = "parallel-executor")]
 use std::num::NonZeroUsize;
 
 #[derive(Clone, Debug)]
@@ -73,6 +75,28 @@ pub struct Config {
     pub block_producer: fuel_core_producer::Config,
     pub gas_price_config: GasPriceConfig,
     pub da_compression: DaCompressionConfig,
+    /// The encoding of the da-compressed blocks at rest.
+    pub da_compression_encoding: DaBlockEncoding,
+    /// The number of blocks for which the historical data, like the statuses of relayed
+    /// transactions and the supply history of assets, is kept.
+    /// If `None`, the data is never pruned.
+    pub historical_data_retention: Option<u32>,
+    /// The number of blocks indexed by the off-chain worker in one batch during
+    /// the catch-up sync. The blocks of the batch are committed at once, and the
+    /// off-chain height advances only after that commit, so larger batches trade
+    /// the latency of the indexes for the throughput of the sync.
+    pub off_chain_worker_batch_blocks: NonZeroUsize,
+    /// The commits of the block indexes into the off-chain database longer
+    /// than the threshold are logged as slow.
+    pub off_chain_slow_commit_threshold: Duration,
+    /// If `true`, the off-chain worker recomputes the total number of transactions
+    /// from the transaction statuses on startup. It scans all statuses.
+    pub reconcile_tx_count: bool,
+    /// If given, the off-chain worker additionally indexes the coins
+    /// under the logical owners returned by the mapper.
+    pub coin_owner_mapper: Option<CoinOwnerMapperRef>,
+    /// The integrity check of the databases before the start of the node.
+    pub integrity_check: integrity_check::Config,
     pub block_importer: fuel_core_importer::Config,
     #[cfg(feature = "relayer")]
     pub relayer: Option<RelayerConfig>,
@@ -141,6 +165,8 @@ impl Config {
             #[cfg(feature = "rocksdb")]
             state_rewind_policy:
                 crate::state::historical_rocksdb::StateRewindPolicy::RewindFullRange,
+            #[cfg(feature = "rocksdb")]
+            index_diff_retention_blocks: None,
         };
 
         #[cfg(feature = "p2p")]
@@ -168,6 +194,29 @@ impl Config {
                 api_request_timeout: Duration::from_secs(60),
                 assemble_tx_dry_run_limit: 3,
                 assemble_tx_estimate_predicates_limit: 5,
+                contracts_info_cache_capacity: 1024,
+                message_proof_cache_capacity: 0,
+                transaction_trees_cache_capacity: 64,
+                max_scanned_entries: 10000,
+                max_block_headers_per_request: 1000,
+                consolidation_dust_threshold: 1000,
+                balance_check_owners_per_minute: 0,
+                balance_check_repair: false,
+                allow_owner_pruning: false,
+                mark_spent_owned_coins: false,
+                owned_coins_compaction_interval: Duration::from_secs(1),
+                owned_coins_compaction_min_spent_age: 1000,
+                owned_coins_compaction_entries_per_run: NonZeroUsize::new(10_000)
+                    .expect("10000 is not zero"),
+                owned_coins_layout: Default::default(),
+                snapshot_view_ttl: Duration::from_secs(30),
+                max_snapshot_views: 64,
+                max_balance_subscriptions: 1024,
+                max_transaction_tag_size: 0,
+                ready_when_offchain_synced: None,
+                health_degraded_lag: 10,
+                health_failing_lag: 100,
+                max_page_sizes: Default::default(),
                 costs: Default::default(),
                 required_fuel_block_height_tolerance: 10,
                 required_fuel_block_height_timeout: Duration::from_secs(30),
@@ -196,6 +245,13 @@ impl Config {
                 ..Default::default()
             },
             da_compression: DaCompressionConfig::Disabled,
+            da_compression_encoding: DaBlockEncoding::Plain,
+            historical_data_retention: None,
+            off_chain_worker_batch_blocks: NonZeroUsize::new(1).expect("1 is not zero"),
+            off_chain_slow_commit_threshold: Duration::from_secs(1),
+            reconcile_tx_count: false,
+            coin_owner_mapper: None,
+            integrity_check: Default::default(),
             gas_price_config,
             block_importer,
             #[cfg(feature = "relayer")]
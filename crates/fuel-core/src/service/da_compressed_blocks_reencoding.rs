@@ -0,0 +1,470 @@
+//! The re-encoding of the DA compressed blocks stored before the zstd encoding
+//! at rest was enabled.
+//!
+//! The blocks are compressed with the dictionary trained on the newest stored
+//! blocks. The dictionary is trained once, before the first block is re-encoded,
+//! and is shared with the DA compression, so the newly compressed blocks use it too.
+//! If there are too few blocks to train the dictionary, the blocks are compressed
+//! without it.
+//!
+//! The re-encoding walks the table in the order of the heights, a bounded number
+//! of entries per tick, and commits each run atomically. The decoding of the
+//! blocks doesn't depend on their encoding, so the re-encoding can be interrupted
+//! at any time. It stops after the first pass that found no plain block.
+
+use crate::{
+    database::{
+        database_description::off_chain::OffChain,
+        Database,
+    },
+    graphql_api::{
+        ports::worker::OffChainDatabaseTransaction,
+        storage::{
+            da_compression::{
+                dictionaries::{
+                    register_da_dictionary,
+                    DaCompressionDictionaries,
+                    DaDictionary,
+                    SharedDaDictionary,
+                },
+                versioned_block_codec::{
+                    split_da_format,
+                    DaBlockEncoding,
+                    DA_FORMAT_V0,
+                },
+                DaCompressedBlocks,
+            },
+            Column,
+        },
+        worker_service::derive_in_overlay,
+    },
+};
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+    TaskNextAction,
+};
+use fuel_core_storage::{
+    codec::{
+        postcard::Postcard,
+        primitive::Primitive,
+        Encode,
+    },
+    iter::{
+        IterDirection,
+        IteratorOverTable,
+    },
+    kv_store::KeyValueInspect,
+    structured_storage::TableWithBlueprint,
+    transactional::{
+        AtomicView,
+        ReadTransaction,
+    },
+    StorageAsMut,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+use std::{
+    num::NonZeroUsize,
+    time::Duration,
+};
+
+/// The number of the newest blocks the dictionary is trained on.
+const TRAINING_SAMPLES: usize = 1024;
+
+/// The dictionary is not trained on fewer blocks, because it wouldn't
+/// generalize to the blocks compressed with it later.
+const MIN_TRAINING_SAMPLES: usize = 64;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// The period between the runs of the re-encoding.
+    pub interval: Duration,
+    /// The maximum number of entries examined by one run.
+    pub entries_per_run: NonZeroUsize,
+    /// The zstd compression level.
+    pub level: i32,
+}
+
+/// The result of one run of the re-encoding.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ReencodingRun {
+    /// The number of examined entries.
+    pub examined: usize,
+    /// The number of re-encoded plain blocks.
+    pub reencoded: usize,
+    /// `true` if the run reached the end of the table.
+    pub finished_pass: bool,
+}
+
+/// The initialization task starts the interval of the re-encoding.
+pub struct InitializeTask {
+    config: Config,
+    dictionary: SharedDaDictionary,
+    off_chain: Database<OffChain>,
+}
+
+pub struct Task {
+    off_chain: Database<OffChain>,
+    interval: tokio::time::Interval,
+    entries_per_run: NonZeroUsize,
+    level: i32,
+    dictionary: SharedDaDictionary,
+    /// `true` once the training of the dictionary was attempted.
+    trained: bool,
+    /// The last examined entry.
+    last_key: Option<BlockHeight>,
+    /// The number of blocks re-encoded during the current pass.
+    reencoded_in_pass: usize,
+    /// `true` once a pass found no plain block.
+    done: bool,
+}
+
+impl Task {
+    fn new(
+        config: Config,
+        dictionary: SharedDaDictionary,
+        off_chain: Database<OffChain>,
+    ) -> Self {
+        let mut interval = tokio::time::interval(config.interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        Self {
+            off_chain,
+            interval,
+            entries_per_run: config.entries_per_run,
+            level: config.level,
+            dictionary,
+            trained: false,
+            last_key: None,
+            reencoded_in_pass: 0,
+            done: false,
+        }
+    }
+
+    /// Trains the dictionary on the newest blocks, stores it, and shares it
+    /// with the DA compression. Does nothing if the dictionary already exists
+    /// or there are too few blocks.
+    pub fn train_dictionary(&mut self) -> anyhow::Result<()> {
+        if self.dictionary.read().is_some() {
+            self.trained = true;
+            return Ok(())
+        }
+
+        let view = self.off_chain.latest_view()?;
+        let height = view.metadata().copied();
+        let blocks = view
+            .iter_all::<DaCompressedBlocks>(Some(IterDirection::Reverse))
+            .take(TRAINING_SAMPLES)
+            .collect::<Result<Vec<_>, _>>()?;
+        let Some((newest, _)) = blocks.first() else {
+            return Ok(())
+        };
+        if blocks.len() < MIN_TRAINING_SAMPLES {
+            tracing::info!(
+                blocks = blocks.len(),
+                "Too few DA compressed blocks to train the zstd dictionary"
+            );
+            self.trained = true;
+            return Ok(())
+        }
+
+        let samples = blocks
+            .iter()
+            .map(|(_, block)| Postcard::encode(block).into_owned())
+            .collect::<Vec<_>>();
+        let dictionary = match DaDictionary::train(&samples) {
+            Ok(dictionary) => dictionary,
+            Err(e) => {
+                tracing::warn!("Failed to train the zstd dictionary: {e:?}");
+                self.trained = true;
+                return Ok(())
+            }
+        };
+
+        let mut transaction = self.off_chain.read_transaction();
+        transaction
+            .storage_as_mut::<DaCompressionDictionaries>()
+            .insert(newest, &dictionary.bytes().to_vec())?;
+        if !self
+            .off_chain
+            .commit_changes_without_height_at(height, transaction.into_changes())?
+        {
+            return Ok(())
+        }
+
+        tracing::info!(
+            id = dictionary.id(),
+            size = dictionary.bytes().len(),
+            "Trained the zstd dictionary of the DA compressed blocks"
+        );
+        register_da_dictionary(&dictionary);
+        *self.dictionary.write() = Some(dictionary);
+        self.trained = true;
+        Ok(())
+    }
+
+    /// Re-encodes the plain blocks among the entries that follow the last
+    /// examined entry. Returns `None` if a block was indexed during the run;
+    /// the same entries are examined on the next run.
+    pub fn reencode_next_range(&mut self) -> anyhow::Result<Option<ReencodingRun>> {
+        let view = self.off_chain.latest_view()?;
+        let height = view.metadata().copied();
+
+        let limit = self.entries_per_run.get();
+        let heights = view
+            .iter_all_filtered_keys::<DaCompressedBlocks, [u8; 0]>(
+                None,
+                self.last_key.as_ref(),
+                Some(IterDirection::Forward),
+            )
+            // The iteration starts from the last examined entry inclusively.
+            .filter(|result| {
+                result
+                    .as_ref()
+                    .map_or(true, |key| Some(key) != self.last_key.as_ref())
+            })
+            .take(limit)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let encoding = DaBlockEncoding::Zstd { level: self.level };
+        let dictionary = self.dictionary.read().clone();
+        let (reencoded, changes) = derive_in_overlay(&self.off_chain, |tx| {
+            let mut reencoded = 0usize;
+            for height in &heights {
+                if !is_plain(&*tx, height)? {
+                    continue
+                }
+                tx.encode_da_compressed_block(height, encoding, dictionary.as_ref())?;
+                reencoded = reencoded.saturating_add(1);
+            }
+            Ok(reencoded)
+        })?;
+
+        // The entries were collected at the `height`, so the run is committed
+        // only if no block was indexed in the meantime.
+        if reencoded > 0
+            && !self
+                .off_chain
+                .commit_changes_without_height_at(height, changes)?
+        {
+            return Ok(None)
+        }
+
+        self.reencoded_in_pass = self.reencoded_in_pass.saturating_add(reencoded);
+        let finished_pass = heights.len() < limit;
+        if finished_pass {
+            self.done = self.reencoded_in_pass == 0;
+            self.reencoded_in_pass = 0;
+            self.last_key = None;
+        } else {
+            self.last_key = heights.last().copied();
+        }
+        Ok(Some(ReencodingRun {
+            examined: heights.len(),
+            reencoded,
+            finished_pass,
+        }))
+    }
+
+    fn run_once(&mut self) -> anyhow::Result<()> {
+        if !self.trained {
+            self.train_dictionary()?;
+        }
+        if let Some(run) = self.reencode_next_range()? {
+            if run.reencoded > 0 {
+                tracing::debug!(
+                    reencoded = run.reencoded,
+                    "Re-encoded the DA compressed blocks"
+                );
+            }
+            if self.done {
+                tracing::info!("All DA compressed blocks are encoded with zstd");
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns `true` if the block at the `height` is stored in the plain format.
+fn is_plain<S>(storage: &S, height: &BlockHeight) -> anyhow::Result<bool>
+where
+    S: KeyValueInspect<Column = Column>,
+{
+    let key = <Primitive<4> as Encode<BlockHeight>>::encode(height);
+    let bytes = storage.get(key.as_ref(), DaCompressedBlocks::column())?;
+    Ok(bytes.is_some_and(|bytes| split_da_format(&bytes).0 == DA_FORMAT_V0))
+}
+
+#[async_trait::async_trait]
+impl RunnableService for InitializeTask {
+    const NAME: &'static str = "DaCompressedBlocksReencoding";
+    type SharedData = ();
+    type Task = Task;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let InitializeTask {
+            config,
+            dictionary,
+            off_chain,
+        } = self;
+        tracing::info!(
+            interval = ?config.interval,
+            entries_per_run = %config.entries_per_run,
+            level = config.level,
+            "Starting the re-encoding of the DA compressed blocks"
+        );
+        Ok(Task::new(config, dictionary, off_chain))
+    }
+}
+
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> TaskNextAction {
+        if self.done {
+            watcher.while_started().await;
+            return TaskNextAction::Stop
+        }
+
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                TaskNextAction::Stop
+            }
+
+            _ = self.interval.tick() => {
+                match self.run_once() {
+                    Ok(()) => TaskNextAction::Continue,
+                    Err(e) => TaskNextAction::ErrorContinue(e),
+                }
+            }
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    config: Config,
+    dictionary: SharedDaDictionary,
+    off_chain: Database<OffChain>,
+) -> ServiceRunner<InitializeTask> {
+    ServiceRunner::new(InitializeTask {
+        config,
+        dictionary,
+        off_chain,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use crate::graphql_api::storage::{
+        blocks::FuelBlockIdsToHeights,
+        da_compression::versioned_block_codec::DA_FORMAT_ZSTD,
+    };
+    use fuel_core_compression::VersionedCompressedBlock;
+    use fuel_core_storage::{
+        transactional::WriteTransaction,
+        StorageAsRef,
+    };
+
+    fn config(entries_per_run: usize) -> Config {
+        Config {
+            interval: Duration::from_secs(1),
+            entries_per_run: NonZeroUsize::new(entries_per_run).unwrap(),
+            level: 3,
+        }
+    }
+
+    /// Creates the database with the `count` plain DA compressed blocks.
+    fn database(count: u32) -> Database<OffChain> {
+        let mut database = Database::<OffChain>::default();
+        let mut transaction = database.write_transaction();
+        for height in 0..count {
+            transaction
+                .storage_as_mut::<DaCompressedBlocks>()
+                .insert(&height.into(), &VersionedCompressedBlock::default())
+                .unwrap();
+        }
+        transaction
+            .storage_as_mut::<FuelBlockIdsToHeights>()
+            .insert(&Default::default(), &BlockHeight::from(count))
+            .unwrap();
+        transaction.commit().unwrap();
+        database
+    }
+
+    fn format(database: &Database<OffChain>, height: u32) -> u8 {
+        let key = <Primitive<4> as Encode<BlockHeight>>::encode(&height.into());
+        let bytes =
+            KeyValueInspect::get(database, key.as_ref(), DaCompressedBlocks::column())
+                .unwrap()
+                .unwrap();
+        split_da_format(&bytes).0
+    }
+
+    #[tokio::test]
+    async fn reencode_next_range__plain_blocks_are_compressed_and_decode_equal() {
+        // given
+        let database = database(3);
+        let mut task = Task::new(config(10), Default::default(), database.clone());
+
+        // when
+        let run = task.reencode_next_range().unwrap();
+
+        // then
+        assert_eq!(
+            run,
+            Some(ReencodingRun {
+                examined: 3,
+                reencoded: 3,
+                finished_pass: true,
+            })
+        );
+        for height in 0..3u32 {
+            assert_eq!(format(&database, height), DA_FORMAT_ZSTD);
+            let block = database
+                .storage::<DaCompressedBlocks>()
+                .get(&height.into())
+                .unwrap()
+                .unwrap()
+                .into_owned();
+            assert_eq!(block, VersionedCompressedBlock::default());
+        }
+    }
+
+    #[tokio::test]
+    async fn reencode_next_range__skips_compressed_blocks_and_stops_after_clean_pass() {
+        // given
+        let database = database(4);
+        let mut task = Task::new(config(3), Default::default(), database.clone());
+        task.reencode_next_range().unwrap();
+
+        // when
+        let runs = (0..3)
+            .map(|_| task.reencode_next_range().unwrap().unwrap())
+            .collect::<Vec<_>>();
+
+        // then
+        let reencoded = runs.iter().map(|run| run.reencoded).collect::<Vec<_>>();
+        assert_eq!(reencoded, vec![1, 0, 0]);
+        assert!(runs[0].finished_pass);
+        assert!(runs[2].finished_pass);
+        assert!(task.done);
+        for height in 0..4u32 {
+            assert_eq!(format(&database, height), DA_FORMAT_ZSTD);
+        }
+    }
+}
@@ -12,6 +12,7 @@ use crate::{
         genesis_progress::GenesisMetadata,
         Database,
     },
+    graphql_api::storage::upgrades::ConsensusParametersHistory,
     service::config::Config,
 };
 use fuel_core_chain_config::GenesisCommitment;
@@ -60,7 +61,15 @@ use fuel_core_types::{
 };
 use itertools::Itertools;
 
-pub use exporter::Exporter;
+pub use exporter::{
+    Exporter,
+    DEFAULT_GROUP_BYTES,
+};
+pub use importer::{
+    import_offchain_snapshot,
+    OffChainSnapshotImportMode,
+    OffChainSnapshotImportReport,
+};
 pub use task_manager::NotifyCancel;
 
 mod exporter;
@@ -130,6 +139,13 @@ pub async fn execute_genesis_block(
             .storage_as_mut::<GenesisMetadata<OffChain>>()
             .remove(&key)?;
     }
+    // The versions from before regenesis are imported from the snapshot above.
+    database_transaction_off_chain
+        .storage_as_mut::<ConsensusParametersHistory>()
+        .insert(
+            &genesis_block.header().consensus_parameters_version(),
+            &chain_config.consensus_parameters,
+        )?;
     database_transaction_off_chain.commit()?;
 
     let mut database_transaction_on_chain = db.on_chain().read_transaction();
@@ -685,7 +701,7 @@ mod tests {
         db.off_chain()
             .latest_view()
             .unwrap()
-            .owned_coins_ids(owner, None, None)
+            .owned_coins_ids(owner, None, None, false)
             .map(|r| {
                 let coin_id = r.unwrap();
                 db.on_chain()
@@ -1,20 +1,37 @@
 use crate::{
     combined_database::CombinedDatabase,
     database::{
-        database_description::DatabaseDescription,
-        Database,
+        database_description::IndexationKind,
+        OffChainIterableKeyValueView,
+        OnChainIterableKeyValueView,
     },
     fuel_core_graphql_api::storage::{
-        messages::SpentMessages,
+        balances::{
+            CoinBalances,
+            MessageBalances,
+        },
+        coins::{
+            CoinsToSpendIndex,
+            DerivedCoinOwners,
+            OwnedCoins,
+        },
+        messages::{
+            MessagesBySender,
+            OwnedMessageIds,
+            SpentMessages,
+        },
         transactions::{
             OwnedTransactions,
             TransactionStatuses,
         },
     },
-    graphql_api::storage::old::{
-        OldFuelBlockConsensus,
-        OldFuelBlocks,
-        OldTransactions,
+    graphql_api::storage::{
+        old::{
+            OldFuelBlockConsensus,
+            OldFuelBlocks,
+            OldTransactions,
+        },
+        upgrades::ConsensusParametersHistory,
     },
 };
 use fuel_core_chain_config::{
@@ -23,6 +40,7 @@ use fuel_core_chain_config::{
     LastBlockConfig,
     SnapshotFragment,
     SnapshotMetadata,
+    SnapshotVersion,
     SnapshotWriter,
     StateConfigBuilder,
     TableEntry,
@@ -32,6 +50,7 @@ use fuel_core_storage::{
     iter::{
         IterDirection,
         IterableTable,
+        IteratorOverTable,
     },
     kv_store::StorageColumn,
     structured_storage::TableWithBlueprint,
@@ -58,18 +77,39 @@ use fuel_core_types::{
     fuel_vm::BlobData,
 };
 use itertools::Itertools;
+use std::{
+    sync::mpsc::SyncSender,
+    time::Duration,
+};
 
 use super::{
     progress::MultipleProgressReporter,
-    task_manager::TaskManager,
+    task_manager::{
+        CancellationToken,
+        TaskManager,
+    },
     NotifyCancel,
 };
 
+/// The default limit on the serialized size of the entries in a group.
+pub const DEFAULT_GROUP_BYTES: usize = 4 * 1024 * 1024;
+
+/// The number of groups read from the database ahead of the writer of the table.
+const GROUPS_IN_FLIGHT: usize = 2;
+
+/// The number of times the views of the databases are taken until they are at the same height.
+const VIEWS_AT_SAME_HEIGHT_ATTEMPTS: usize = 50;
+
+/// The delay before the views of the databases are taken again.
+const VIEWS_AT_SAME_HEIGHT_RETRY_DELAY: Duration = Duration::from_millis(100);
+
 pub struct Exporter<Fun, N> {
     db: CombinedDatabase,
     prev_chain_config: ChainConfig,
     writer: Fun,
     group_size: usize,
+    group_bytes: usize,
+    include_off_chain_indexes: bool,
     task_manager: TaskManager<SnapshotFragment, N>,
     multi_progress: MultipleProgressReporter,
 }
@@ -91,6 +131,8 @@ where
             prev_chain_config,
             writer,
             group_size,
+            group_bytes: DEFAULT_GROUP_BYTES,
+            include_off_chain_indexes: false,
             task_manager: TaskManager::new(cancel_token),
             multi_progress: MultipleProgressReporter::new(tracing::info_span!(
                 "snapshot_exporter"
@@ -98,15 +140,34 @@ where
         }
     }
 
+    /// Includes the off-chain indexes derived from the on-chain state into the full snapshot.
+    /// It allows skipping the re-indexation during the regenesis.
+    pub fn with_off_chain_indexes(mut self, include: bool) -> Self {
+        self.include_off_chain_indexes = include;
+        self
+    }
+
+    /// Closes a group once the serialized size of its entries reaches the `group_bytes`,
+    /// even if it has less than `group_size` entries. Together with the `group_size`
+    /// it bounds the memory used to export a table.
+    pub fn with_group_bytes(mut self, group_bytes: usize) -> Self {
+        self.group_bytes = group_bytes;
+        self
+    }
+
     pub async fn write_full_snapshot(mut self) -> Result<(), anyhow::Error> {
+        // All tables are read from the views of the databases at the same height,
+        // so the blocks committed during the export don't tear the snapshot.
+        let (on_chain, off_chain) = self.views_at_same_height().await?;
+
         macro_rules! export {
-            ($db: expr, $($table: ty),*) => {
-                $(self.spawn_task::<$table, _>(None, $db)?;)*
+            ($view: expr, $($table: ty),*) => {
+                $(self.spawn_task::<$table, _>(None, $view.clone())?;)*
             };
         }
 
         export!(
-            |ctx: &Self| ctx.db.on_chain(),
+            on_chain,
             Coins,
             Messages,
             BlobData,
@@ -123,16 +184,114 @@ where
         );
 
         export!(
-            |ctx: &Self| ctx.db.off_chain(),
+            off_chain,
             TransactionStatuses,
             OwnedTransactions,
             OldFuelBlocks,
             OldFuelBlockConsensus,
             OldTransactions,
-            SpentMessages
+            SpentMessages,
+            ConsensusParametersHistory
         );
 
-        self.finalize().await?;
+        if self.include_off_chain_indexes {
+            self.ensure_off_chain_indexes_available()?;
+
+            export!(
+                off_chain,
+                OwnedCoins,
+                DerivedCoinOwners,
+                OwnedMessageIds,
+                MessagesBySender,
+                CoinBalances,
+                MessageBalances,
+                CoinsToSpendIndex
+            );
+        }
+
+        self.finalize(&on_chain).await?;
+
+        Ok(())
+    }
+
+    /// Writes the off-chain tables, together with the off-chain indexes, into the snapshot.
+    /// Tables are independent, so `threads` workers export them concurrently
+    /// from the view of the off-chain database at the height of the on-chain view.
+    ///
+    /// The snapshot is read back by the [`import_offchain_snapshot`](super::import_offchain_snapshot).
+    pub async fn export_offchain_snapshot_parallel(
+        mut self,
+        threads: usize,
+    ) -> anyhow::Result<()> {
+        self.ensure_off_chain_indexes_available()?;
+        self.include_off_chain_indexes = true;
+
+        let (on_chain, off_chain) = self.views_at_same_height().await?;
+
+        let mut jobs = Vec::new();
+        macro_rules! export {
+            ($($table: ty),*) => {
+                $(jobs.push(self.export_job::<$table, _>(None, off_chain.clone())?);)*
+            };
+        }
+        export!(
+            TransactionStatuses,
+            OwnedTransactions,
+            OldFuelBlocks,
+            OldFuelBlockConsensus,
+            OldTransactions,
+            SpentMessages,
+            ConsensusParametersHistory,
+            OwnedCoins,
+            DerivedCoinOwners,
+            OwnedMessageIds,
+            MessagesBySender,
+            CoinBalances,
+            MessageBalances,
+            CoinsToSpendIndex
+        );
+
+        let threads = threads.clamp(1, jobs.len());
+        let writer_fragment = self.create_writer()?.partial_close()?;
+        self.task_manager.spawn_blocking(move |cancel| {
+            let jobs = parking_lot::Mutex::new(jobs.into_iter());
+            let fragments = std::thread::scope(|scope| {
+                let workers = (0..threads)
+                    .map(|_| {
+                        scope.spawn(|| -> anyhow::Result<Vec<SnapshotFragment>> {
+                            let mut fragments = Vec::new();
+                            loop {
+                                let Some(job) = jobs.lock().next() else {
+                                    return Ok(fragments);
+                                };
+                                fragments.push(job(&cancel)?);
+                            }
+                        })
+                    })
+                    .collect::<Vec<_>>();
+
+                workers
+                    .into_iter()
+                    .map(|worker| {
+                        worker
+                            .join()
+                            .map_err(|_| {
+                                anyhow::anyhow!("The table export worker panicked")
+                            })
+                            .and_then(|fragments| fragments)
+                    })
+                    .flatten_ok()
+                    .collect::<anyhow::Result<Vec<_>>>()
+            })?;
+
+            fragments
+                .into_iter()
+                .try_fold(writer_fragment, |fragment, next_fragment| {
+                    fragment.merge(next_fragment)
+                })
+        });
+
+        self.finalize(&on_chain).await?;
 
         Ok(())
     }
@@ -141,9 +300,11 @@ where
         mut self,
         contract_id: ContractId,
     ) -> Result<(), anyhow::Error> {
+        let on_chain = self.db.on_chain().latest_view()?;
+
         macro_rules! export {
             ($($table: ty),*) => {
-                $(self.spawn_task::<$table, _>(Some(contract_id.as_ref()), |ctx: &Self| ctx.db.on_chain())?;)*
+                $(self.spawn_task::<$table, _>(Some(contract_id.as_ref()), on_chain.clone())?;)*
             };
         }
         export!(
@@ -153,14 +314,59 @@ where
             ContractsRawCode
         );
 
-        self.finalize().await?;
+        self.finalize(&on_chain).await?;
+
+        Ok(())
+    }
+
+    /// Returns the latest views of the on-chain and off-chain databases at the same height.
+    /// The off-chain database indexes the blocks after they are committed on-chain,
+    /// so the views are taken again until it catches up, or the export fails.
+    async fn views_at_same_height(
+        &self,
+    ) -> anyhow::Result<(OnChainIterableKeyValueView, OffChainIterableKeyValueView)> {
+        for _ in 1..VIEWS_AT_SAME_HEIGHT_ATTEMPTS {
+            let on_chain = self.db.on_chain().latest_view()?;
+            let off_chain = self.db.off_chain().latest_view()?;
+            if on_chain.metadata() == off_chain.metadata() {
+                return Ok((on_chain, off_chain));
+            }
+            tokio::time::sleep(VIEWS_AT_SAME_HEIGHT_RETRY_DELAY).await;
+        }
 
+        let on_chain = self.db.on_chain().latest_view()?;
+        let off_chain = self.db.off_chain().latest_view()?;
+        if on_chain.metadata() != off_chain.metadata() {
+            anyhow::bail!(
+                "The off-chain database at height {:?} is not at the height {:?} of the on-chain database",
+                off_chain.metadata(),
+                on_chain.metadata()
+            );
+        }
+        Ok((on_chain, off_chain))
+    }
+
+    fn ensure_off_chain_indexes_available(&self) -> anyhow::Result<()> {
+        for kind in [IndexationKind::Balances, IndexationKind::CoinsToSpend] {
+            if !self.db.off_chain().indexation_available(kind)? {
+                anyhow::bail!(
+                    "Unable to export the off-chain indexes: the {kind:?} indexation is not available"
+                );
+            }
+        }
         Ok(())
     }
 
-    async fn finalize(self) -> anyhow::Result<SnapshotMetadata> {
-        let writer = self.create_writer()?;
-        let view = self.db.on_chain().latest_view()?;
+    async fn finalize(
+        self,
+        view: &OnChainIterableKeyValueView,
+    ) -> anyhow::Result<SnapshotMetadata> {
+        let version = if self.include_off_chain_indexes {
+            SnapshotVersion::V2
+        } else {
+            SnapshotVersion::V1
+        };
+        let writer = self.create_writer()?.with_version(version);
         let latest_block = view.latest_block()?;
         let blocks_root =
             view.block_header_merkle_root(latest_block.header().height())?;
@@ -182,42 +388,122 @@ where
         (self.writer)()
     }
 
-    fn spawn_task<T, DbDesc>(
+    fn spawn_task<T, S>(
         &mut self,
         prefix: Option<&[u8]>,
-        db_picker: impl FnOnce(&Self) -> &Database<DbDesc>,
+        storage: S,
     ) -> anyhow::Result<()>
     where
         T: TableWithBlueprint + 'static + Send + Sync,
-        TableEntry<T>: serde::Serialize,
+        TableEntry<T>: serde::Serialize + Send,
+        StateConfigBuilder: AddTable<T>,
+        S: IterableTable<T> + Send + Sync + 'static,
+    {
+        let job = self.export_job::<T, S>(prefix, storage)?;
+        self.task_manager.spawn_blocking(move |cancel| job(&cancel));
+
+        Ok(())
+    }
+
+    /// Prepares the export of the table `T` from the `storage`, so it can run on any thread.
+    fn export_job<T, S>(
+        &self,
+        prefix: Option<&[u8]>,
+        storage: S,
+    ) -> anyhow::Result<ExportJob<N>>
+    where
+        T: TableWithBlueprint + 'static + Send + Sync,
+        TableEntry<T>: serde::Serialize + Send,
         StateConfigBuilder: AddTable<T>,
-        DbDesc: DatabaseDescription,
-        Database<DbDesc>: IterableTable<T>,
+        S: IterableTable<T> + Send + Sync + 'static,
     {
         let mut writer = self.create_writer()?;
         let group_size = self.group_size;
+        let group_bytes = self.group_bytes;
 
-        let db = db_picker(self).clone();
         let prefix = prefix.map(|p| p.to_vec());
         // TODO:
         // [1857](https://github.com/FuelLabs/fuel-core/issues/1857)
         // RocksDb can provide an estimate for the number of items.
         let progress_tracker =
             self.multi_progress.table_reporter(None, T::column().name());
-        self.task_manager.spawn_blocking(move |cancel| {
-            db.entries::<T>(prefix, IterDirection::Forward)
-                .chunks(group_size)
-                .into_iter()
-                .take_while(|_| !cancel.is_cancelled())
-                .enumerate()
-                .try_for_each(|(index, chunk)| {
-                    progress_tracker.set_index(index);
+        Ok(Box::new(move |cancel| {
+            // The bounded channel blocks the iteration over the database
+            // until the writer catches up.
+            let (sender, receiver) = std::sync::mpsc::sync_channel(GROUPS_IN_FLIGHT);
+            std::thread::scope(|scope| {
+                let reader = scope.spawn(|| {
+                    send_groups::<T, _, _>(
+                        &storage,
+                        prefix,
+                        group_size,
+                        group_bytes,
+                        cancel,
+                        sender,
+                    )
+                });
+
+                let mut written = 0usize;
+                for group in receiver {
+                    written = written.saturating_add(group.len());
+                    writer.write(group)?;
+                    progress_tracker.set_entries_written(written);
+                }
 
-                    writer.write(chunk.try_collect()?)
-                })?;
+                reader
+                    .join()
+                    .map_err(|_| anyhow::anyhow!("The table reader panicked"))?
+            })?;
             writer.partial_close()
-        });
+        }))
+    }
+}
 
-        Ok(())
+/// The export of a single table into its own snapshot fragment.
+type ExportJob<N> =
+    Box<dyn FnOnce(&CancellationToken<N>) -> anyhow::Result<SnapshotFragment> + Send>;
+
+/// Sends the entries of the table `T` to the `sender` in groups. A group is sent
+/// once it has `group_size` entries or the serialized size of its entries
+/// reaches `group_bytes`.
+fn send_groups<T, S, N>(
+    storage: &S,
+    prefix: Option<Vec<u8>>,
+    group_size: usize,
+    group_bytes: usize,
+    cancel: &CancellationToken<N>,
+    sender: SyncSender<Vec<TableEntry<T>>>,
+) -> anyhow::Result<()>
+where
+    T: TableWithBlueprint,
+    TableEntry<T>: serde::Serialize,
+    S: IterableTable<T>,
+    N: NotifyCancel,
+{
+    let mut group = Vec::new();
+    let mut size = 0usize;
+    let entries = storage
+        .iter_all_filtered::<T, _>(prefix, None, Some(IterDirection::Forward))
+        .map_ok(|(key, value)| TableEntry { key, value });
+    for entry in entries {
+        if cancel.is_cancelled() {
+            break
+        }
+        let entry = entry?;
+        size = size.saturating_add(postcard::to_stdvec(&entry)?.len());
+        group.push(entry);
+
+        if group.len() >= group_size || size >= group_bytes {
+            size = 0;
+            if sender.send(std::mem::take(&mut group)).is_err() {
+                // The writer has failed, and it reports the error.
+                return Ok(())
+            }
+        }
+    }
+
+    if !group.is_empty() {
+        let _ = sender.send(group);
     }
+    Ok(())
 }
@@ -10,10 +10,26 @@ use crate::{
     },
     fuel_core_graphql_api::storage::messages::SpentMessages,
     graphql_api::storage::{
+        balances::{
+            CoinBalances,
+            MessageBalances,
+        },
         blocks::FuelBlockIdsToHeights,
-        coins::OwnedCoins,
-        contracts::ContractsInfo,
-        messages::OwnedMessageIds,
+        coins::{
+            CoinsToSpendIndex,
+            DerivedCoinOwners,
+            OwnedCoinCountByAsset,
+            OwnedCoins,
+            OwnedCoinsByAsset,
+        },
+        contracts::{
+            ContractBalances,
+            ContractsInfo,
+        },
+        messages::{
+            MessagesBySender,
+            OwnedMessageIds,
+        },
         old::{
             OldFuelBlockConsensus,
             OldFuelBlocks,
@@ -23,12 +39,14 @@ use crate::{
             OwnedTransactions,
             TransactionStatuses,
         },
+        upgrades::ConsensusParametersHistory,
     },
 };
 use core::marker::PhantomData;
 use fuel_core_chain_config::{
     AsTable,
     SnapshotReader,
+    SnapshotVersion,
     StateConfig,
     TableEntry,
 };
@@ -69,8 +87,15 @@ use import_task::{
 
 mod import_task;
 mod off_chain;
+mod off_chain_snapshot;
 mod on_chain;
 
+pub use off_chain_snapshot::{
+    import_offchain_snapshot,
+    OffChainSnapshotImportMode,
+    OffChainSnapshotImportReport,
+};
+
 const GROUPS_NUMBER_FOR_PARALLELIZATION: usize = 10;
 
 pub struct SnapshotImporter<N = StateWatcher> {
@@ -141,25 +166,53 @@ impl SnapshotImporter {
         self.spawn_worker_off_chain::<TransactionStatuses, TransactionStatuses>()?;
         self.spawn_worker_off_chain::<OwnedTransactions, OwnedTransactions>()?;
         self.spawn_worker_off_chain::<SpentMessages, SpentMessages>()?;
-        self.spawn_worker_off_chain::<Messages, OwnedMessageIds>()?;
-        self.spawn_worker_off_chain::<Coins, OwnedCoins>()?;
+        if self.snapshot_contains_off_chain_indexes() {
+            self.spawn_worker_off_chain::<OwnedMessageIds, OwnedMessageIds>()?;
+            self.spawn_worker_off_chain::<MessagesBySender, MessagesBySender>()?;
+            self.spawn_worker_off_chain::<OwnedCoins, OwnedCoins>()?;
+            self.spawn_worker_off_chain::<DerivedCoinOwners, DerivedCoinOwners>()?;
+            self.spawn_worker_off_chain::<CoinBalances, CoinBalances>()?;
+            self.spawn_worker_off_chain::<MessageBalances, MessageBalances>()?;
+            self.spawn_worker_off_chain::<CoinsToSpendIndex, CoinsToSpendIndex>()?;
+            self.spawn_worker_off_chain::<Coins, OwnedCoinCountByAsset>()?;
+            self.spawn_worker_off_chain::<Coins, OwnedCoinsByAsset>()?;
+        } else {
+            self.spawn_worker_off_chain::<Messages, OwnedMessageIds>()?;
+            self.spawn_worker_off_chain::<Coins, OwnedCoins>()?;
+        }
         self.spawn_worker_off_chain::<FuelBlocks, OldFuelBlocks>()?;
         self.spawn_worker_off_chain::<Transactions, OldTransactions>()?;
         self.spawn_worker_off_chain::<SealedBlockConsensus, OldFuelBlockConsensus>()?;
         self.spawn_worker_off_chain::<ContractsInfo, ContractsInfo>()?;
+        self.spawn_worker_off_chain::<ContractsAssets, ContractBalances>()?;
         self.spawn_worker_off_chain::<Transactions, ContractsInfo>()?;
         self.spawn_worker_off_chain::<OldTransactions, ContractsInfo>()?;
         self.spawn_worker_off_chain::<OldFuelBlocks, OldFuelBlocks>()?;
         self.spawn_worker_off_chain::<OldFuelBlockConsensus, OldFuelBlockConsensus>()?;
         self.spawn_worker_off_chain::<OldTransactions, OldTransactions>()?;
+        self.spawn_worker_off_chain::<ConsensusParametersHistory, ConsensusParametersHistory>()?;
         self.spawn_worker_off_chain::<FuelBlocks, FuelBlockIdsToHeights>()?;
         self.spawn_worker_off_chain::<OldFuelBlocks, FuelBlockIdsToHeights>()?;
 
+        let off_chain = self.db.off_chain().clone();
+        let genesis_height = *self.genesis_block.header().height();
         self.task_manager.wait().await?;
 
+        // The activity combines several owner indexes imported concurrently above.
+        tokio::task::spawn_blocking(move || {
+            off_chain::rebuild_owner_activities(off_chain, genesis_height)
+        })
+        .await??;
+
         Ok(())
     }
 
+    /// The snapshot may contain the off-chain indexes derived from the on-chain state.
+    /// In that case they are imported as is instead of being recalculated.
+    fn snapshot_contains_off_chain_indexes(&self) -> bool {
+        self.snapshot_reader.version() >= SnapshotVersion::V2
+    }
+
     pub fn spawn_worker_on_chain<TableBeingWritten>(&mut self) -> anyhow::Result<()>
     where
         TableBeingWritten: TableWithBlueprint + 'static + Send,
@@ -1,15 +1,39 @@
 use crate::{
     database::{
-        database_description::off_chain::OffChain,
+        database_description::{
+            off_chain::OffChain,
+            DatabaseDescription,
+        },
         GenesisDatabase,
     },
     fuel_core_graphql_api::storage::messages::SpentMessages,
     graphql_api::{
+        indexation::owner_activity::OwnerActivityUpdates,
+        ports::worker::OffChainDatabaseTransaction,
         storage::{
+            balances::{
+                CoinBalances,
+                MessageBalances,
+            },
             blocks::FuelBlockIdsToHeights,
-            coins::OwnedCoins,
-            contracts::ContractsInfo,
-            messages::OwnedMessageIds,
+            coins::{
+                insert_owned_coin,
+                owned_coins_layout,
+                CoinsToSpendIndex,
+                DerivedCoinOwners,
+                OwnedCoinByAssetKey,
+                OwnedCoinCountByAsset,
+                OwnedCoins,
+                OwnedCoinsByAsset,
+            },
+            contracts::{
+                ContractBalances,
+                ContractsInfo,
+            },
+            messages::{
+                MessagesBySender,
+                OwnedMessageIds,
+            },
             old::{
                 OldFuelBlockConsensus,
                 OldFuelBlocks,
@@ -19,23 +43,36 @@ use crate::{
                 OwnedTransactions,
                 TransactionStatuses,
             },
+            upgrades::ConsensusParametersHistory,
         },
         worker_service,
     },
 };
 use fuel_core_chain_config::TableEntry;
 use fuel_core_storage::{
+    iter::IteratorOverTable,
+    structured_storage::TableWithBlueprint,
     tables::{
         Coins,
+        ContractsAssets,
         FuelBlocks,
         Messages,
         SealedBlockConsensus,
         Transactions,
     },
-    transactional::StorageTransaction,
+    transactional::{
+        StorageTransaction,
+        WriteTransaction,
+    },
+    Error as StorageError,
     StorageAsMut,
+    StorageMutate,
+};
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::executor::Event,
 };
-use fuel_core_types::services::executor::Event;
+use itertools::Itertools;
 use std::borrow::Cow;
 
 use super::{
@@ -43,6 +80,10 @@ use super::{
     Handler,
 };
 
+/// The number of owners whose activity is written by one database transaction
+/// during [`rebuild_owner_activities`].
+const OWNER_ACTIVITIES_PER_TRANSACTION: usize = 10_000;
+
 fn balances_indexation_enabled() -> bool {
     use std::sync::OnceLock;
 
@@ -147,6 +188,7 @@ impl ImportTable for Handler<OwnedMessageIds, Messages> {
             .map(|TableEntry { value, .. }| Cow::Owned(Event::MessageImported(value)));
         worker_service::process_executor_events(
             events,
+            self.block_height,
             tx,
             balances_indexation_enabled(),
             coins_to_spend_indexation_enabled(),
@@ -171,6 +213,7 @@ impl ImportTable for Handler<OwnedCoins, Coins> {
         });
         worker_service::process_executor_events(
             events,
+            self.block_height,
             tx,
             balances_indexation_enabled(),
             coins_to_spend_indexation_enabled(),
@@ -180,6 +223,125 @@ impl ImportTable for Handler<OwnedCoins, Coins> {
     }
 }
 
+/// The off-chain index exported into the snapshot as is. It is imported without
+/// recalculation from the on-chain state.
+pub trait OffChainSnapshotIndex:
+    TableWithBlueprint<Column = <OffChain as DatabaseDescription>::Column>
+{
+}
+
+impl OffChainSnapshotIndex for MessagesBySender {}
+impl OffChainSnapshotIndex for DerivedCoinOwners {}
+impl OffChainSnapshotIndex for CoinBalances {}
+impl OffChainSnapshotIndex for MessageBalances {}
+impl OffChainSnapshotIndex for CoinsToSpendIndex {}
+
+impl<T> ImportTable for Handler<T, T>
+where
+    T: OffChainSnapshotIndex,
+    for<'a> StorageTransaction<&'a mut GenesisDatabase<OffChain>>:
+        StorageMutate<T, Error = StorageError>,
+{
+    type TableInSnapshot = T;
+    type TableBeingWritten = T;
+    type DbDesc = OffChain;
+
+    fn process(
+        &mut self,
+        group: Vec<TableEntry<Self::TableInSnapshot>>,
+        tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
+    ) -> anyhow::Result<()> {
+        for entry in group {
+            tx.storage_as_mut::<T>().insert(&entry.key, &entry.value)?;
+        }
+        Ok(())
+    }
+}
+
+impl ImportTable for Handler<OwnedMessageIds, OwnedMessageIds> {
+    type TableInSnapshot = OwnedMessageIds;
+    type TableBeingWritten = OwnedMessageIds;
+    type DbDesc = OffChain;
+
+    fn process(
+        &mut self,
+        group: Vec<TableEntry<Self::TableInSnapshot>>,
+        tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
+    ) -> anyhow::Result<()> {
+        for entry in group {
+            tx.storage_as_mut::<OwnedMessageIds>()
+                .insert(&entry.key, &entry.value)?;
+            tx.update_owner_last_activity(entry.key.address(), self.block_height)?;
+        }
+        Ok(())
+    }
+}
+
+impl ImportTable for Handler<OwnedCoins, OwnedCoins> {
+    type TableInSnapshot = OwnedCoins;
+    type TableBeingWritten = OwnedCoins;
+    type DbDesc = OffChain;
+
+    fn process(
+        &mut self,
+        group: Vec<TableEntry<Self::TableInSnapshot>>,
+        tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
+    ) -> anyhow::Result<()> {
+        // The coins flagged as spent are not carried over into the new network.
+        let layout = owned_coins_layout(tx)?;
+        for entry in group.into_iter().filter(|entry| !entry.value.is_spent()) {
+            insert_owned_coin(tx, layout, &entry.key, &entry.value)?;
+            tx.update_owner_last_activity(&entry.key.owner, self.block_height)?;
+        }
+        Ok(())
+    }
+}
+
+/// The snapshot with the off-chain indexes doesn't contain the coin counts,
+/// so they are derived from the coins.
+impl ImportTable for Handler<OwnedCoinCountByAsset, Coins> {
+    type TableInSnapshot = Coins;
+    type TableBeingWritten = OwnedCoinCountByAsset;
+    type DbDesc = OffChain;
+
+    fn process(
+        &mut self,
+        group: Vec<TableEntry<Self::TableInSnapshot>>,
+        tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
+    ) -> anyhow::Result<()> {
+        for TableEntry { value, .. } in group {
+            worker_service::increase_owned_coin_count(
+                tx,
+                value.owner(),
+                value.asset_id(),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The snapshot with the off-chain indexes doesn't contain the coins by asset,
+/// so they are derived from the coins.
+impl ImportTable for Handler<OwnedCoinsByAsset, Coins> {
+    type TableInSnapshot = Coins;
+    type TableBeingWritten = OwnedCoinsByAsset;
+    type DbDesc = OffChain;
+
+    fn process(
+        &mut self,
+        group: Vec<TableEntry<Self::TableInSnapshot>>,
+        tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
+    ) -> anyhow::Result<()> {
+        for TableEntry { key, value } in group {
+            tx.storage_as_mut::<OwnedCoinsByAsset>().insert(
+                &OwnedCoinByAssetKey::new(value.owner(), value.asset_id(), &key),
+                &(),
+            )?;
+        }
+        Ok(())
+    }
+}
+
 impl ImportTable for Handler<ContractsInfo, ContractsInfo> {
     type TableInSnapshot = ContractsInfo;
     type TableBeingWritten = ContractsInfo;
@@ -198,6 +360,25 @@ impl ImportTable for Handler<ContractsInfo, ContractsInfo> {
     }
 }
 
+/// The balances of contracts are derived from the on-chain state of the snapshot.
+impl ImportTable for Handler<ContractBalances, ContractsAssets> {
+    type TableInSnapshot = ContractsAssets;
+    type TableBeingWritten = ContractBalances;
+    type DbDesc = OffChain;
+
+    fn process(
+        &mut self,
+        group: Vec<TableEntry<Self::TableInSnapshot>>,
+        tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
+    ) -> anyhow::Result<()> {
+        for entry in group {
+            tx.storage::<ContractBalances>()
+                .insert(&entry.key, &entry.value)?;
+        }
+        Ok(())
+    }
+}
+
 impl ImportTable for Handler<ContractsInfo, Transactions> {
     type TableInSnapshot = Transactions;
     type TableBeingWritten = ContractsInfo;
@@ -349,7 +530,24 @@ impl ImportTable for Handler<SpentMessages, SpentMessages> {
         tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
     ) -> anyhow::Result<()> {
         for entry in group {
-            tx.storage_as_mut::<SpentMessages>()
+            tx.mark_message_spent(&entry.key)?;
+        }
+        Ok(())
+    }
+}
+
+impl ImportTable for Handler<ConsensusParametersHistory, ConsensusParametersHistory> {
+    type TableInSnapshot = ConsensusParametersHistory;
+    type TableBeingWritten = ConsensusParametersHistory;
+    type DbDesc = OffChain;
+
+    fn process(
+        &mut self,
+        group: Vec<TableEntry<Self::TableInSnapshot>>,
+        tx: &mut StorageTransaction<&mut GenesisDatabase<Self::DbDesc>>,
+    ) -> anyhow::Result<()> {
+        for entry in group {
+            tx.storage_as_mut::<ConsensusParametersHistory>()
                 .insert(&entry.key, &entry.value)?;
         }
         Ok(())
@@ -391,3 +589,50 @@ impl ImportTable for Handler<FuelBlockIdsToHeights, OldFuelBlocks> {
         Ok(())
     }
 }
+
+/// Rebuilds the activity of owners from the imported owner indexes.
+///
+/// The indexes are imported by concurrent workers, so the activity is derived
+/// once all of them are done. Both indexes are sorted by the owner, which allows
+/// walking them together and overwriting the record of each owner exactly once.
+/// It makes the rebuild safe to repeat after an interrupted genesis.
+pub(crate) fn rebuild_owner_activities(
+    mut db: GenesisDatabase<OffChain>,
+    genesis_height: BlockHeight,
+) -> anyhow::Result<()> {
+    let view = db.clone();
+    let transactions = view
+        .iter_all_keys::<OwnedTransactions>(None)
+        .map_ok(|key| (key.owner, Some(key.block_height)));
+    let coins = view
+        .iter_all_keys::<OwnedCoins>(None)
+        .map_ok(|key| (key.owner, None));
+    let entries = transactions.merge_by(coins, |left, right| match (left, right) {
+        (Ok((left, _)), Ok((right, _))) => left <= right,
+        _ => true,
+    });
+
+    let mut updates = OwnerActivityUpdates::default();
+    let mut last_owner = None;
+    for entry in entries {
+        let (owner, block_height) = entry?;
+        // Flush only between owners, so each record accounts for all entries of its owner.
+        if last_owner != Some(owner) && updates.len() >= OWNER_ACTIVITIES_PER_TRANSACTION
+        {
+            let mut tx = db.write_transaction();
+            core::mem::take(&mut updates).overwrite(&mut tx)?;
+            tx.commit()?;
+        }
+        last_owner = Some(owner);
+
+        match block_height {
+            Some(block_height) => updates.record_transaction(&owner, block_height),
+            None => updates.record_coin_created(&owner, genesis_height),
+        }
+    }
+
+    let mut tx = db.write_transaction();
+    updates.overwrite(&mut tx)?;
+    tx.commit()?;
+    Ok(())
+}
@@ -0,0 +1,211 @@
+use crate::{
+    database::{
+        database_description::off_chain::OffChain,
+        Database,
+    },
+    fuel_core_graphql_api::storage::{
+        balances::{
+            CoinBalances,
+            MessageBalances,
+        },
+        coins::{
+            insert_owned_coin,
+            owned_coins_layout,
+            CoinsToSpendIndex,
+            DerivedCoinOwners,
+            OwnedCoins,
+        },
+        messages::{
+            MessagesBySender,
+            OwnedMessageIds,
+            SpentMessages,
+        },
+        old::{
+            OldFuelBlockConsensus,
+            OldFuelBlocks,
+            OldTransactions,
+        },
+        transactions::{
+            OwnedTransactions,
+            TransactionStatuses,
+        },
+        upgrades::ConsensusParametersHistory,
+    },
+};
+use fuel_core_chain_config::{
+    AsTable,
+    SnapshotMetadata,
+    SnapshotReader,
+    SnapshotVersion,
+    StateConfig,
+    TableEntry,
+};
+use fuel_core_storage::{
+    kv_store::StorageColumn,
+    structured_storage::TableWithBlueprint,
+    transactional::{
+        StorageTransaction,
+        WriteTransaction,
+    },
+    Result as StorageResult,
+    StorageAsMut,
+};
+use std::{
+    collections::BTreeMap,
+    path::Path,
+};
+
+/// Defines how the [`import_offchain_snapshot`] handles the bad records.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OffChainSnapshotImportMode {
+    /// The first bad record aborts the whole import and nothing is written.
+    #[default]
+    Strict,
+    /// The bad records are skipped and reported, the rest of the snapshot is imported.
+    Lenient,
+}
+
+/// The result of the [`import_offchain_snapshot`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OffChainSnapshotImportReport {
+    /// The number of the imported records.
+    pub imported: u64,
+    /// The errors of the skipped records by the name of the table.
+    /// It is always empty in the [`OffChainSnapshotImportMode::Strict`] mode.
+    pub skipped: BTreeMap<String, Vec<String>>,
+}
+
+impl OffChainSnapshotImportReport {
+    /// Returns `true` if no record was skipped.
+    pub fn is_complete(&self) -> bool {
+        self.skipped.is_empty()
+    }
+}
+
+/// Imports the off-chain tables of the snapshot written by the
+/// [`Exporter::export_offchain_snapshot_parallel`](crate::service::genesis::Exporter::export_offchain_snapshot_parallel)
+/// from the `dir` into the `db`. The records are committed at once after all tables are read.
+///
+/// In the [`OffChainSnapshotImportMode::Lenient`] mode the read and insert errors
+/// are collected per table instead of aborting the import. A group that can't be read
+/// is skipped as a whole, and a table that can't be opened is skipped entirely.
+pub fn import_offchain_snapshot(
+    db: &mut Database<OffChain>,
+    dir: &Path,
+    mode: OffChainSnapshotImportMode,
+) -> anyhow::Result<OffChainSnapshotImportReport> {
+    let reader = SnapshotReader::open(SnapshotMetadata::read(dir)?)?;
+    if reader.version() < SnapshotVersion::V2 {
+        anyhow::bail!("The snapshot doesn't contain the off-chain indexes");
+    }
+
+    let mut import = SnapshotImport {
+        reader,
+        mode,
+        report: OffChainSnapshotImportReport::default(),
+        transaction: db.write_transaction(),
+    };
+
+    macro_rules! import {
+        ($($table: ty),*) => {
+            $(import.table::<$table>(|tx, entry| {
+                tx.storage_as_mut::<$table>().insert(&entry.key, &entry.value)
+            })?;)*
+        };
+    }
+    import!(
+        TransactionStatuses,
+        OwnedTransactions,
+        OldFuelBlocks,
+        OldFuelBlockConsensus,
+        OldTransactions,
+        SpentMessages,
+        ConsensusParametersHistory,
+        DerivedCoinOwners,
+        OwnedMessageIds,
+        MessagesBySender,
+        CoinBalances,
+        MessageBalances,
+        CoinsToSpendIndex
+    );
+
+    // The coins are written with the layout of the `db`, not the one of the exporting node.
+    let layout = owned_coins_layout(&import.transaction)?;
+    import.table::<OwnedCoins>(|tx, entry| {
+        insert_owned_coin(tx, layout, &entry.key, &entry.value)
+    })?;
+
+    let SnapshotImport {
+        report,
+        transaction,
+        ..
+    } = import;
+    transaction.commit()?;
+
+    Ok(report)
+}
+
+struct SnapshotImport<'a> {
+    reader: SnapshotReader,
+    mode: OffChainSnapshotImportMode,
+    report: OffChainSnapshotImportReport,
+    transaction: StorageTransaction<&'a mut Database<OffChain>>,
+}
+
+impl<'a> SnapshotImport<'a> {
+    /// Inserts the entries of the table `T` from the snapshot with the `insert`.
+    fn table<T>(
+        &mut self,
+        mut insert: impl FnMut(
+            &mut StorageTransaction<&'a mut Database<OffChain>>,
+            TableEntry<T>,
+        ) -> StorageResult<()>,
+    ) -> anyhow::Result<()>
+    where
+        T: TableWithBlueprint,
+        StateConfig: AsTable<T>,
+        TableEntry<T>: serde::de::DeserializeOwned,
+    {
+        let name = T::column().name();
+        let mode = self.mode;
+        let mut errors = vec![];
+        let mut skip = |error: anyhow::Error| match mode {
+            OffChainSnapshotImportMode::Strict => {
+                Err(error.context(format!("Failed to import the {name} table")))
+            }
+            OffChainSnapshotImportMode::Lenient => {
+                errors.push(format!("{error:#}"));
+                Ok(())
+            }
+        };
+
+        match self.reader.read::<T>() {
+            Ok(groups) => {
+                for group in groups {
+                    let group = match group {
+                        Ok(group) => group,
+                        Err(err) => {
+                            skip(err.context("The group is unreadable"))?;
+                            continue
+                        }
+                    };
+                    for entry in group {
+                        match insert(&mut self.transaction, entry) {
+                            Ok(()) => {
+                                self.report.imported =
+                                    self.report.imported.saturating_add(1)
+                            }
+                            Err(err) => skip(err.into())?,
+                        }
+                    }
+                }
+            }
+            Err(err) => skip(err.context("The table is unreadable"))?,
+        }
+
+        if !errors.is_empty() {
+            self.report.skipped.insert(name, errors);
+        }
+        Ok(())
+    }
+}
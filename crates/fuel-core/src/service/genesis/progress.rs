@@ -78,6 +78,15 @@ impl ProgressReporter {
             })
         }
     }
+
+    /// Sets the number of the entries written so far.
+    pub fn set_entries_written(&self, written: usize) {
+        let written = u64::try_from(written).unwrap_or(u64::MAX);
+        self.bar.set_position(written);
+        if let ReportMethod::Logs(span) = &self.target {
+            span.in_scope(|| tracing::info!("Written entries: {written}"))
+        }
+    }
 }
 
 pub struct MultipleProgressReporter {
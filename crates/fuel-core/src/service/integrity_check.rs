@@ -0,0 +1,468 @@
+//! The integrity check of the databases at the start of the node.
+//!
+//! The check samples the first values of every column backed by a known table
+//! and decodes them with the type of the table. It runs if it is requested by
+//! the configuration, or if the dirty flag shows that the previous run didn't
+//! shut down cleanly. The start is refused if any column has more values that
+//! failed to decode than the threshold allows.
+
+use crate::{
+    combined_database::CombinedDatabase,
+    database::database_description::{
+        gas_price::GasPriceDatabase,
+        off_chain::OffChain,
+        on_chain::OnChain,
+        DatabaseDescription,
+    },
+    graphql_api::storage::{
+        assets::{
+            AssetFirstSeen,
+            AssetSupplyCheckpoints,
+            AssetSupplyHistory,
+            AssetsInfo,
+        },
+        balances::{
+            CoinBalances,
+            MessageBalances,
+        },
+        blocks::{
+            BlockFees,
+            BlockTransactionIds,
+            BlocksByProducer,
+            FuelBlockIdsToHeights,
+        },
+        coins::{
+            CoinsToSpendIndex,
+            DerivedCoinOwners,
+            OwnedCoinCountByAsset,
+            OwnedCoins,
+            OwnedCoinsByAsset,
+            PredicateOwnedCoins,
+            SpentCoins,
+            SpentCoinsByBlock,
+            SpentCoinsByHeight,
+        },
+        contracts::{
+            ContractBalances,
+            ContractsByBlock,
+            ContractsInfo,
+        },
+        da_compression::{
+            dictionaries::{
+                load_da_dictionaries,
+                DaCompressionDictionaries,
+            },
+            DaCompressedBlockChecksums,
+            DaCompressedBlockRegistrations,
+            DaCompressedBlockStats,
+            DaCompressedBlocks,
+        },
+        known_owners::KnownPredicateOwners,
+        messages::{
+            MessageProofCache,
+            MessagesBySender,
+            OwnedMessageIds,
+            SpentMessages,
+        },
+        old::{
+            OldFuelBlockConsensus,
+            OldFuelBlocks,
+            OldTransactions,
+        },
+        owner_activity::{
+            OwnerActivities,
+            OwnerLastActivity,
+        },
+        relayed_transactions::{
+            OwnedRelayedTransactionIds,
+            RelayedTransactionStatusHeights,
+            RelayedTransactionStatuses,
+        },
+        transactions::{
+            ContractTransactions,
+            OwnedTransactions,
+            TransactionStatuses,
+        },
+        upgrades::ConsensusParametersHistory,
+    },
+};
+use fuel_core_gas_price_service::common::fuel_core_storage_adapter::storage::{
+    GasPriceMetadata,
+    RecordedHeights,
+    UnrecordedBlocksTable,
+};
+use fuel_core_storage::{
+    iter::{
+        IterDirection,
+        IterableTable,
+        IteratorOverTable,
+    },
+    kv_store::StorageColumn,
+    structured_storage::TableWithBlueprint,
+    tables::{
+        merkle::{
+            ContractsAssetsMerkleData,
+            ContractsAssetsMerkleMetadata,
+            ContractsStateMerkleData,
+            ContractsStateMerkleMetadata,
+            FuelBlockMerkleData,
+            FuelBlockMerkleMetadata,
+        },
+        BlobData,
+        Coins,
+        ConsensusParametersVersions,
+        ContractsAssets,
+        ContractsLatestUtxo,
+        ContractsRawCode,
+        ContractsState,
+        FuelBlocks,
+        Messages,
+        ProcessedTransactions,
+        SealedBlockConsensus,
+        StateTransitionBytecodeVersions,
+        Transactions,
+        UploadedBytecodes,
+    },
+    transactional::AtomicView,
+    Result as StorageResult,
+};
+use std::collections::BTreeMap;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// Runs the check even if the databases were closed cleanly.
+    pub verify_on_start: bool,
+    /// The number of the values sampled per column.
+    pub samples_per_column: usize,
+    /// The number of the values per column that may fail to decode
+    /// without blocking the start.
+    pub max_failures_per_column: u64,
+    /// Starts the node even if the check found corrupted columns.
+    pub force: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            verify_on_start: false,
+            samples_per_column: 1000,
+            max_failures_per_column: 0,
+            force: false,
+        }
+    }
+}
+
+/// The result of the check of one column.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ColumnReport {
+    /// The number of the sampled values.
+    pub sampled: u64,
+    /// The number of the sampled values that failed to decode.
+    pub failures: u64,
+    /// The error of the first value that failed to decode.
+    pub first_error: Option<String>,
+}
+
+/// The results of the check by the `<database>/<column>` name.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntegrityReport {
+    pub columns: BTreeMap<String, ColumnReport>,
+}
+
+impl IntegrityReport {
+    /// Returns the columns with more failures than the `max_failures`.
+    pub fn corrupted_columns(
+        &self,
+        max_failures: u64,
+    ) -> impl Iterator<Item = (&String, &ColumnReport)> {
+        self.columns
+            .iter()
+            .filter(move |(_, column)| column.failures > max_failures)
+    }
+}
+
+/// Runs the check if it is required by the `config` or by the dirty flag
+/// and fails if it found the corrupted columns, unless the start is forced.
+pub fn verify_on_start(
+    database: &CombinedDatabase,
+    config: &Config,
+) -> anyhow::Result<Option<IntegrityReport>> {
+    let dirty = database.is_dirty()?;
+    if !config.verify_on_start && !dirty {
+        return Ok(None)
+    }
+    if dirty {
+        tracing::warn!(
+            "The databases weren't closed cleanly by the previous run, checking their integrity"
+        );
+    }
+
+    let report = check_integrity(database, config.samples_per_column)?;
+    let corrupted = report
+        .corrupted_columns(config.max_failures_per_column)
+        .map(|(name, column)| {
+            format!(
+                "{name}: {} of {} sampled values failed to decode ({})",
+                column.failures,
+                column.sampled,
+                column.first_error.as_deref().unwrap_or_default()
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if corrupted.is_empty() {
+        tracing::info!(
+            "The integrity check sampled {} columns without corruption",
+            report.columns.len()
+        );
+        return Ok(Some(report))
+    }
+
+    let message = format!(
+        "The integrity check found corrupted columns \
+        (more than {} failures per column): {}",
+        config.max_failures_per_column,
+        corrupted.join("; ")
+    );
+    if config.force {
+        tracing::error!("{message}. Starting anyway because the start is forced");
+        Ok(Some(report))
+    } else {
+        Err(anyhow::anyhow!(
+            "{message}. Restore the databases or start with `--force` to ignore it"
+        ))
+    }
+}
+
+/// Samples up to the `samples` values of every known column of the `database`.
+pub fn check_integrity(
+    database: &CombinedDatabase,
+    samples: usize,
+) -> StorageResult<IntegrityReport> {
+    macro_rules! sample {
+        ($report:expr, $view:expr, $database:ty, [$($table:ty),* $(,)?]) => {
+            $(
+                sample::<$table, $database, _>(&$view, samples, $report);
+            )*
+        };
+    }
+
+    let mut report = IntegrityReport::default();
+
+    let on_chain = database.on_chain().latest_view()?;
+    sample!(
+        &mut report,
+        on_chain,
+        OnChain,
+        [
+            ContractsRawCode,
+            ContractsState,
+            ContractsLatestUtxo,
+            ContractsAssets,
+            Coins,
+            Transactions,
+            FuelBlocks,
+            FuelBlockMerkleData,
+            FuelBlockMerkleMetadata,
+            ContractsAssetsMerkleData,
+            ContractsAssetsMerkleMetadata,
+            ContractsStateMerkleData,
+            ContractsStateMerkleMetadata,
+            Messages,
+            ProcessedTransactions,
+            SealedBlockConsensus,
+            ConsensusParametersVersions,
+            StateTransitionBytecodeVersions,
+            UploadedBytecodes,
+            BlobData,
+        ]
+    );
+
+    let off_chain = database.off_chain().latest_view()?;
+    // The blocks compressed with the dictionaries can't be decoded without them.
+    load_da_dictionaries(&off_chain)?;
+    sample!(
+        &mut report,
+        off_chain,
+        OffChain,
+        [
+            OwnedCoins,
+            TransactionStatuses,
+            OwnedTransactions,
+            OwnedMessageIds,
+            FuelBlockIdsToHeights,
+            ContractsInfo,
+            OldFuelBlocks,
+            OldFuelBlockConsensus,
+            OldTransactions,
+            RelayedTransactionStatuses,
+            SpentMessages,
+            DaCompressedBlocks,
+            CoinBalances,
+            MessageBalances,
+            AssetsInfo,
+            CoinsToSpendIndex,
+            OwnerActivities,
+            DaCompressedBlockChecksums,
+            OwnedCoinCountByAsset,
+            OwnedRelayedTransactionIds,
+            RelayedTransactionStatusHeights,
+            BlockTransactionIds,
+            AssetSupplyHistory,
+            AssetSupplyCheckpoints,
+            AssetFirstSeen,
+            MessageProofCache,
+            ContractBalances,
+            ContractTransactions,
+            KnownPredicateOwners,
+            DaCompressedBlockStats,
+            DerivedCoinOwners,
+            OwnerLastActivity,
+            ContractsByBlock,
+            OwnedCoinsByAsset,
+            PredicateOwnedCoins,
+            BlockFees,
+            DaCompressedBlockRegistrations,
+            ConsensusParametersHistory,
+            DaCompressionDictionaries,
+            MessagesBySender,
+            BlocksByProducer,
+            SpentCoins,
+            SpentCoinsByHeight,
+            SpentCoinsByBlock,
+        ]
+    );
+
+    #[cfg(feature = "relayer")]
+    {
+        use crate::database::database_description::relayer::Relayer;
+
+        let relayer = database.relayer().latest_view()?;
+        sample!(
+            &mut report,
+            relayer,
+            Relayer,
+            [fuel_core_relayer::storage::EventsHistory]
+        );
+    }
+
+    let gas_price = database.gas_price().latest_view()?;
+    sample!(
+        &mut report,
+        gas_price,
+        GasPriceDatabase,
+        [GasPriceMetadata, UnrecordedBlocksTable, RecordedHeights]
+    );
+
+    Ok(report)
+}
+
+fn sample<T, Description, S>(view: &S, samples: usize, report: &mut IntegrityReport)
+where
+    T: TableWithBlueprint,
+    T::Column: StorageColumn,
+    Description: DatabaseDescription,
+    S: IterableTable<T>,
+{
+    let mut column = ColumnReport::default();
+    for entry in view
+        .iter_all::<T>(Some(IterDirection::Forward))
+        .take(samples)
+    {
+        column.sampled = column.sampled.saturating_add(1);
+        if let Err(err) = entry {
+            column.failures = column.failures.saturating_add(1);
+            column.first_error.get_or_insert_with(|| err.to_string());
+        }
+    }
+
+    let name = format!("{}/{}", Description::name(), T::column().name());
+    report.columns.insert(name, column);
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        column::Column,
+        kv_store::KeyValueMutate,
+        transactional::ReadTransaction,
+    };
+
+    fn database_with_corrupted_coin() -> CombinedDatabase {
+        let database = CombinedDatabase::default();
+        let mut transaction = database.on_chain().read_transaction();
+        transaction
+            .put(&[1; 34], Column::Coins, vec![0xff].into())
+            .unwrap();
+        database
+            .on_chain()
+            .commit_changes_without_height(transaction.into_changes())
+            .unwrap();
+        database
+    }
+
+    #[test]
+    fn verify_on_start__skips_the_check_after_the_clean_shutdown() {
+        // given
+        let database = database_with_corrupted_coin();
+
+        // when
+        let report = verify_on_start(&database, &Config::default()).unwrap();
+
+        // then
+        assert_eq!(report, None);
+    }
+
+    #[test]
+    fn verify_on_start__refuses_the_corrupted_column_after_the_unclean_shutdown() {
+        // given
+        let database = database_with_corrupted_coin();
+        database.set_dirty(true).unwrap();
+
+        // when
+        let result = verify_on_start(&database, &Config::default());
+
+        // then
+        let err = result.unwrap_err().to_string();
+        assert!(
+            err.contains("on_chain/Coins: 1 of 1 sampled values failed to decode"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn verify_on_start__reports_the_corrupted_column_when_the_start_is_forced() {
+        // given
+        let database = database_with_corrupted_coin();
+        let config = Config {
+            verify_on_start: true,
+            force: true,
+            ..Default::default()
+        };
+
+        // when
+        let report = verify_on_start(&database, &config).unwrap().unwrap();
+
+        // then
+        let corrupted = report.corrupted_columns(0).collect::<Vec<_>>();
+        assert_eq!(corrupted.len(), 1);
+        assert_eq!(corrupted[0].0, "on_chain/Coins");
+        assert_eq!(corrupted[0].1.failures, 1);
+    }
+
+    #[test]
+    fn set_dirty__false_clears_the_flag() {
+        // given
+        let database = CombinedDatabase::default();
+        database.set_dirty(true).unwrap();
+        assert!(database.is_dirty().unwrap());
+
+        // when
+        database.set_dirty(false).unwrap();
+
+        // then
+        assert!(!database.is_dirty().unwrap());
+    }
+}
@@ -0,0 +1,507 @@
+//! The offline indexer builds the databases of the node from an export of sealed
+//! blocks without running the consensus, P2P, or the transaction pool.
+//!
+//! Each block is executed and committed by the block importer, and the result
+//! of the import is indexed by the same code as the GraphQL worker uses.
+//! The resulting databases can be served by a read-only API node.
+//!
+//! The export is a sequence of sealed blocks. Each block is serialized with
+//! `postcard` and prefixed by the length of the serialized block as
+//! a little-endian `u32`.
+
+use crate::{
+    combined_database::CombinedDatabase,
+    database::{
+        database_description::off_chain::OffChain,
+        Database,
+    },
+    graphql_api::{
+        ports::worker::{
+            BlockAt,
+            OffChainDatabase,
+        },
+        worker_service::{
+            commit_block_indexes,
+            IndexationConfig,
+        },
+    },
+    service::{
+        adapters::{
+            import_result_provider::ImportResultProvider,
+            BlockImporterAdapter,
+            ExecutorAdapter,
+            VerifierAdapter,
+        },
+        genesis,
+        Config,
+    },
+};
+use anyhow::Context;
+use fuel_core_services::{
+    stream::BoxStream,
+    State,
+    StateWatcher,
+};
+use fuel_core_storage::{
+    transactional::{
+        AtomicView,
+        HistoricalView,
+    },
+    IsNotFound,
+};
+use fuel_core_types::{
+    blockchain::SealedBlock,
+    fuel_types::BlockHeight,
+    services::block_importer::{
+        ImportResult,
+        SharedImportResult,
+    },
+};
+use futures::StreamExt;
+use std::{
+    collections::VecDeque,
+    fs::File,
+    io::{
+        BufReader,
+        ErrorKind,
+        Read,
+        Write,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The number of indexed blocks between the reports of the progress.
+const PROGRESS_INTERVAL: u64 = 1000;
+
+/// The summary of the indexation of the export.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct IndexationReport {
+    /// The number of blocks indexed by this run.
+    pub indexed_blocks: u64,
+    /// The number of blocks skipped because they were indexed before.
+    pub skipped_blocks: u64,
+    /// The time spent on the indexation.
+    pub elapsed: Duration,
+}
+
+impl IndexationReport {
+    /// Returns the number of indexed blocks per second.
+    pub fn blocks_per_second(&self) -> f64 {
+        blocks_per_second(self.indexed_blocks, self.elapsed)
+    }
+}
+
+fn blocks_per_second(blocks: u64, elapsed: Duration) -> f64 {
+    let seconds = elapsed.as_secs_f64();
+    if seconds > 0.0 {
+        blocks as f64 / seconds
+    } else {
+        0.0
+    }
+}
+
+/// Imports the sealed blocks and derives the off-chain indexes from them.
+pub struct OfflineIndexer {
+    database: CombinedDatabase,
+    off_chain: Database<OffChain>,
+    importer: BlockImporterAdapter,
+    import_results: BoxStream<SharedImportResult>,
+    indexation_config: IndexationConfig,
+    historical_data_retention: Option<u32>,
+    indexed_height: BlockHeight,
+}
+
+impl OfflineIndexer {
+    /// Prepares the `database` for the indexation.
+    ///
+    /// It executes the genesis block if the chain is not initialized, and
+    /// indexes the blocks that were imported but not indexed by the previous run.
+    pub async fn new(config: Config, database: CombinedDatabase) -> anyhow::Result<Self> {
+        let config = config.make_config_consistent();
+        database.check_version()?;
+
+        let chain_config = config.snapshot_reader.chain_config();
+        let consensus_parameters = &chain_config.consensus_parameters;
+        let chain_id = consensus_parameters.chain_id();
+
+        // Nobody listens for new transactions and pre-confirmations while indexing.
+        let (_, new_txs_watcher) = tokio::sync::watch::channel(());
+        let (preconfirmation_sender, _) = tokio::sync::mpsc::channel(1);
+        let executor = ExecutorAdapter::new(
+            database.on_chain().clone(),
+            database.relayer().clone(),
+            fuel_core_upgradable_executor::config::Config {
+                forbid_fake_coins_default: config.utxo_validation,
+                native_executor_version: config.native_executor_version,
+                allow_historical_execution: config.historical_execution,
+            },
+            new_txs_watcher,
+            preconfirmation_sender,
+        );
+        let genesis_block = genesis::create_genesis_block(&config).compress(&chain_id);
+        let verifier = VerifierAdapter::new(
+            &genesis_block,
+            chain_config.consensus.clone(),
+            database.on_chain().clone(),
+        );
+        let importer = BlockImporterAdapter::new(
+            chain_id,
+            config.block_importer.clone(),
+            database.on_chain().clone(),
+            executor.clone(),
+            verifier,
+        );
+        let import_results = importer.events_shared_result();
+
+        let off_chain = database.off_chain().clone();
+        #[cfg(feature = "relayer")]
+        let relayed_transactions: Option<
+            crate::graphql_api::ports::worker::RelayedTransactionsProviderRef,
+        > = Some(std::sync::Arc::new(
+            crate::service::adapters::relayer::RelayedTransactionsAdapter::new(
+                database.on_chain().clone(),
+                database.relayer().clone(),
+            ),
+        ));
+        #[cfg(not(feature = "relayer"))]
+        let relayed_transactions = None;
+        let indexation_config = IndexationConfig {
+            chain_id,
+            da_compression_config: config.da_compression.clone(),
+            balances_indexation_enabled: off_chain.balances_indexation_enabled()?,
+            coins_to_spend_indexation_enabled: off_chain
+                .coins_to_spend_indexation_enabled()?,
+            asset_metadata_indexation_enabled: off_chain
+                .asset_metadata_indexation_enabled()?,
+            contract_balances_indexation_enabled: off_chain
+                .contract_balances_indexation_enabled()?,
+            base_asset_id: *consensus_parameters.base_asset_id(),
+            coin_owner_mapper: config.coin_owner_mapper.clone(),
+            relayed_transactions,
+            mark_spent_owned_coins: config.graphql_config.mark_spent_owned_coins,
+        };
+
+        let mut indexer = Self {
+            database,
+            off_chain,
+            importer,
+            import_results,
+            indexation_config,
+            historical_data_retention: config.historical_data_retention,
+            indexed_height: BlockHeight::default(),
+        };
+
+        indexer.prepare_genesis(&config).await?;
+        indexer.sync_off_chain(ImportResultProvider::new(
+            indexer.database.on_chain().clone(),
+            executor,
+        ))?;
+
+        Ok(indexer)
+    }
+
+    /// Returns the height of the last indexed block.
+    pub fn indexed_height(&self) -> BlockHeight {
+        self.indexed_height
+    }
+
+    /// Imports and indexes the `blocks`. The blocks at or below the indexed height
+    /// are skipped, so the indexation of the same export can be resumed.
+    ///
+    /// Fails if the next block doesn't follow the last indexed block.
+    pub async fn index<I>(&mut self, blocks: I) -> anyhow::Result<IndexationReport>
+    where
+        I: IntoIterator<Item = anyhow::Result<SealedBlock>>,
+    {
+        let start = Instant::now();
+        let mut report = IndexationReport::default();
+
+        for block in blocks {
+            let block = block?;
+            let height = *block.entity.header().height();
+            if height <= self.indexed_height {
+                report.skipped_blocks = report.skipped_blocks.saturating_add(1);
+                continue;
+            }
+
+            let expected_height = self
+                .indexed_height
+                .succ()
+                .ok_or_else(|| anyhow::anyhow!("The block height overflowed"))?;
+            if height != expected_height {
+                return Err(anyhow::anyhow!(
+                    "The blocks are not contiguous: expected the block at height \
+                    {expected_height}, but got the block at height {height}"
+                ));
+            }
+
+            self.importer
+                .execute_and_commit(block)
+                .await
+                .with_context(|| {
+                    format!("Failed to import the block at height {height}")
+                })?;
+            self.index_next_import_result().await?;
+
+            report.indexed_blocks = report.indexed_blocks.saturating_add(1);
+            if report.indexed_blocks % PROGRESS_INTERVAL == 0 {
+                tracing::info!(
+                    %height,
+                    indexed_blocks = report.indexed_blocks,
+                    blocks_per_second =
+                        blocks_per_second(report.indexed_blocks, start.elapsed()),
+                    "Indexing the blocks"
+                );
+            }
+        }
+
+        report.elapsed = start.elapsed();
+        Ok(report)
+    }
+
+    async fn prepare_genesis(&mut self, config: &Config) -> anyhow::Result<()> {
+        let Err(err) = self.database.on_chain().latest_view()?.get_genesis() else {
+            return Ok(());
+        };
+        if !err.is_not_found() {
+            return Err(err.into());
+        }
+
+        // The sender is kept alive, so the genesis is never considered cancelled.
+        let (_state, receiver) = tokio::sync::watch::channel(State::Started);
+        let result = genesis::execute_genesis_block(
+            StateWatcher::from(receiver),
+            config,
+            &self.database,
+        )
+        .await?;
+        self.importer.block_importer.commit_result(result).await?;
+        self.index_next_import_result().await
+    }
+
+    /// Indexes the blocks committed into the on-chain database,
+    /// but not indexed before the previous run was interrupted.
+    fn sync_off_chain(
+        &mut self,
+        import_result_provider: ImportResultProvider,
+    ) -> anyhow::Result<()> {
+        let on_chain_height = HistoricalView::latest_height(self.database.on_chain());
+        loop {
+            let off_chain_height = HistoricalView::latest_height(&self.off_chain);
+            if off_chain_height >= on_chain_height {
+                break;
+            }
+
+            let next_block_height = match off_chain_height {
+                Some(height) => BlockAt::Specific(
+                    height
+                        .succ()
+                        .ok_or_else(|| anyhow::anyhow!("The block height overflowed"))?,
+                ),
+                None => BlockAt::Genesis,
+            };
+            let import_result =
+                import_result_provider.result_at_height(next_block_height)?;
+            self.index_import_result(&import_result)?;
+        }
+
+        if let Some(height) = on_chain_height {
+            self.indexed_height = height;
+        }
+        Ok(())
+    }
+
+    async fn index_next_import_result(&mut self) -> anyhow::Result<()> {
+        let import_result = self
+            .import_results
+            .next()
+            .await
+            .ok_or_else(|| anyhow::anyhow!("The block importer stopped"))?;
+        self.index_import_result(&import_result)
+    }
+
+    fn index_import_result(
+        &mut self,
+        import_result: &ImportResult,
+    ) -> anyhow::Result<()> {
+        commit_block_indexes(
+            &self.indexation_config,
+            self.historical_data_retention,
+            import_result,
+            &mut self.off_chain,
+        )?;
+        self.indexed_height = *import_result.sealed_block.entity.header().height();
+        Ok(())
+    }
+}
+
+/// Writes the `blocks` into the `writer` in the format of the export.
+pub fn write_blocks<'a, W, I>(mut writer: W, blocks: I) -> anyhow::Result<()>
+where
+    W: Write,
+    I: IntoIterator<Item = &'a SealedBlock>,
+{
+    for block in blocks {
+        let bytes = postcard::to_allocvec(block)?;
+        let len = u32::try_from(bytes.len())?;
+        writer.write_all(&len.to_le_bytes())?;
+        writer.write_all(&bytes)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Reads the sealed blocks from the export at the `path`.
+///
+/// If the `path` is a directory, the files of the directory are read one after
+/// another in the lexicographic order of their names.
+pub fn read_blocks(path: &Path) -> anyhow::Result<ExportReader> {
+    let files = if path.is_dir() {
+        let mut files = Vec::new();
+        for entry in std::fs::read_dir(path)? {
+            let path = entry?.path();
+            if path.is_file() {
+                files.push(path);
+            }
+        }
+        files.sort();
+        files
+    } else {
+        vec![path.to_path_buf()]
+    };
+
+    Ok(ExportReader {
+        files: files.into(),
+        current: None,
+    })
+}
+
+/// The iterator over the sealed blocks of the export.
+pub struct ExportReader {
+    files: VecDeque<PathBuf>,
+    current: Option<BufReader<File>>,
+}
+
+impl Iterator for ExportReader {
+    type Item = anyhow::Result<SealedBlock>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let reader = match &mut self.current {
+                Some(reader) => reader,
+                None => {
+                    let path = self.files.pop_front()?;
+                    match File::open(&path) {
+                        Ok(file) => self.current.insert(BufReader::new(file)),
+                        Err(err) => {
+                            self.files.clear();
+                            return Some(Err(anyhow::anyhow!(err)
+                                .context(format!("Failed to open {path:?}"))));
+                        }
+                    }
+                }
+            };
+
+            match read_block(reader) {
+                Ok(Some(block)) => return Some(Ok(block)),
+                Ok(None) => self.current = None,
+                Err(err) => {
+                    self.files.clear();
+                    self.current = None;
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
+
+/// Reads the next block from the `reader`. Returns `None` at the end of the file.
+fn read_block<R: Read>(reader: &mut R) -> anyhow::Result<Option<SealedBlock>> {
+    let mut len = [0u8; 4];
+    match reader.read_exact(&mut len) {
+        Ok(()) => {}
+        Err(err) if err.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+        Err(err) => return Err(err.into()),
+    }
+
+    let len = usize::try_from(u32::from_le_bytes(len))?;
+    let mut bytes = vec![0u8; len];
+    reader
+        .read_exact(&mut bytes)
+        .context("The export ends in the middle of a block")?;
+    let block = postcard::from_bytes(&bytes)?;
+    Ok(Some(block))
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use fuel_core_types::blockchain::{
+        block::Block,
+        consensus::Consensus,
+    };
+
+    fn sealed_block(height: u32) -> SealedBlock {
+        let mut block = Block::default();
+        block.header_mut().set_block_height(height.into());
+        SealedBlock {
+            entity: block,
+            consensus: Consensus::PoA(Default::default()),
+        }
+    }
+
+    fn read_all(mut bytes: &[u8]) -> Vec<anyhow::Result<SealedBlock>> {
+        let mut blocks = Vec::new();
+        loop {
+            match read_block(&mut bytes) {
+                Ok(Some(block)) => blocks.push(Ok(block)),
+                Ok(None) => return blocks,
+                Err(err) => {
+                    blocks.push(Err(err));
+                    return blocks;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn read_block__returns_written_blocks_in_order() {
+        // given
+        let blocks: Vec<_> = (1..=6).map(sealed_block).collect();
+        let mut bytes = Vec::new();
+        write_blocks(&mut bytes, &blocks).unwrap();
+
+        // when
+        let read: Vec<_> = read_all(&bytes)
+            .into_iter()
+            .collect::<anyhow::Result<_>>()
+            .unwrap();
+
+        // then
+        assert_eq!(read, blocks);
+    }
+
+    #[test]
+    fn read_block__fails_on_truncated_block() {
+        // given
+        let mut bytes = Vec::new();
+        write_blocks(&mut bytes, &[sealed_block(1), sealed_block(2)]).unwrap();
+        bytes.truncate(bytes.len().saturating_sub(1));
+
+        // when
+        let read = read_all(&bytes);
+
+        // then
+        assert_eq!(read.len(), 2);
+        assert_eq!(read[0].as_ref().unwrap(), &sealed_block(1));
+        assert!(read[1].is_err());
+    }
+}
@@ -0,0 +1,375 @@
+//! The compaction of the owned coins removes the coins flagged as spent
+//! from the `OwnedCoins` table.
+//!
+//! If the spent coins are flagged instead of being removed, the indexation
+//! of the blocks doesn't leave the tombstones of RocksDB in the prefixes of
+//! the active owners, which slow down the scans of their coins. The flagged
+//! coins are removed by the compaction only once they are cold, i.e. were
+//! spent at least `min_spent_age` blocks ago.
+//!
+//! The compaction walks the table in the order of the keys, a bounded number
+//! of entries per tick, and starts over after the last one. Each run is
+//! committed atomically, and the flagged coins are never returned by the
+//! queries, so the compaction can be interrupted at any time. The position
+//! of the walk is not persisted; after the restart it starts from the beginning.
+
+use crate::{
+    database::{
+        database_description::off_chain::OffChain,
+        Database,
+        OffChainIterableKeyValueView,
+    },
+    graphql_api::storage::coins::{
+        owned_coins_layout,
+        take_owned_coin,
+        OwnedCoinKey,
+        OwnedCoinState,
+        OwnedCoins,
+        OwnedCoinsLayout,
+        ShardedOwnedCoins,
+    },
+};
+use fuel_core_metrics::graphql_metrics::graphql_metrics;
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+    TaskNextAction,
+};
+use fuel_core_storage::{
+    iter::{
+        IterDirection,
+        IterableTable,
+        IteratorOverTable,
+    },
+    transactional::{
+        AtomicView,
+        ReadTransaction,
+    },
+    Mappable,
+    Result as StorageResult,
+};
+use std::{
+    num::NonZeroUsize,
+    time::Duration,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// The period between the runs of the compaction.
+    pub interval: Duration,
+    /// The number of blocks since the spending after which the flagged coin is removed.
+    pub min_spent_age: u32,
+    /// The maximum number of entries examined by one run.
+    pub entries_per_run: NonZeroUsize,
+}
+
+/// The result of one run of the compaction.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CompactionRun {
+    /// The number of examined entries.
+    pub examined: usize,
+    /// The number of removed spent coins.
+    pub removed: usize,
+    /// `true` if the run reached the end of the table.
+    pub finished_pass: bool,
+}
+
+/// The initialization task starts the interval of the compaction.
+pub struct InitializeTask {
+    config: Config,
+    off_chain: Database<OffChain>,
+}
+
+pub struct Task {
+    off_chain: Database<OffChain>,
+    interval: tokio::time::Interval,
+    min_spent_age: u32,
+    entries_per_run: NonZeroUsize,
+    /// The last examined entry.
+    last_key: Option<OwnedCoinKey>,
+}
+
+impl Task {
+    fn new(config: Config, off_chain: Database<OffChain>) -> Self {
+        let mut interval = tokio::time::interval(config.interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        Self {
+            off_chain,
+            interval,
+            min_spent_age: config.min_spent_age,
+            entries_per_run: config.entries_per_run,
+            last_key: None,
+        }
+    }
+
+    /// Examines the entries that follow the last examined entry and removes
+    /// the cold spent coins among them. Returns `None` if a block was indexed
+    /// during the run; the same entries are examined on the next run.
+    pub fn compact_next_range(&mut self) -> StorageResult<Option<CompactionRun>> {
+        let view = self.off_chain.latest_view()?;
+        let height = view.metadata().copied();
+        let Some(current_height) = height else {
+            return Ok(Some(CompactionRun::default()))
+        };
+
+        let limit = self.entries_per_run.get();
+        let layout = owned_coins_layout(&view)?;
+        let entries = match layout {
+            OwnedCoinsLayout::Plain => {
+                next_entries::<OwnedCoins>(&view, self.last_key, limit)?
+            }
+            OwnedCoinsLayout::Sharded => next_entries::<ShardedOwnedCoins>(
+                &view,
+                self.last_key.as_ref().map(Into::into),
+                limit,
+            )?
+            .into_iter()
+            .map(|(key, state)| (key.into(), state))
+            .collect(),
+        };
+
+        let mut transaction = self.off_chain.read_transaction();
+        let mut removed = 0usize;
+        for (key, state) in &entries {
+            let OwnedCoinState::Spent { height } = state else {
+                continue
+            };
+            let cold_at = u32::from(*height).saturating_add(self.min_spent_age);
+            if cold_at > u32::from(current_height) {
+                continue
+            }
+            take_owned_coin(&mut transaction, layout, key)?;
+            removed = removed.saturating_add(1);
+        }
+
+        // The entries were collected at the `height`, so the run is committed
+        // only if no block was indexed in the meantime.
+        if removed > 0
+            && !self
+                .off_chain
+                .commit_changes_without_height_at(height, transaction.into_changes())?
+        {
+            return Ok(None)
+        }
+
+        let finished_pass = entries.len() < limit;
+        self.last_key = if finished_pass {
+            None
+        } else {
+            entries.last().map(|(key, _)| *key)
+        };
+        Ok(Some(CompactionRun {
+            examined: entries.len(),
+            removed,
+            finished_pass,
+        }))
+    }
+}
+
+/// Returns up to the `limit` entries of the table that follow the `last_key`.
+fn next_entries<M>(
+    view: &OffChainIterableKeyValueView,
+    last_key: Option<M::OwnedKey>,
+    limit: usize,
+) -> StorageResult<Vec<(M::OwnedKey, M::OwnedValue)>>
+where
+    M: Mappable<Key = <M as Mappable>::OwnedKey>,
+    M::OwnedKey: PartialEq,
+    OffChainIterableKeyValueView: IterableTable<M>,
+{
+    view.iter_all_filtered::<M, [u8; 0]>(
+        None,
+        last_key.as_ref(),
+        Some(IterDirection::Forward),
+    )
+    // The iteration starts from the last examined entry inclusively.
+    .filter(|result| {
+        result
+            .as_ref()
+            .map_or(true, |(key, _)| Some(key) != last_key.as_ref())
+    })
+    .take(limit)
+    .collect()
+}
+
+#[async_trait::async_trait]
+impl RunnableService for InitializeTask {
+    const NAME: &'static str = "OwnedCoinsCompaction";
+    type SharedData = ();
+    type Task = Task;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let InitializeTask { config, off_chain } = self;
+        tracing::info!(
+            interval = ?config.interval,
+            min_spent_age = config.min_spent_age,
+            entries_per_run = %config.entries_per_run,
+            "Starting the compaction of the owned coins"
+        );
+        Ok(Task::new(config, off_chain))
+    }
+}
+
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> TaskNextAction {
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                TaskNextAction::Stop
+            }
+
+            _ = self.interval.tick() => {
+                match self.compact_next_range() {
+                    Ok(Some(run)) => {
+                        graphql_metrics().record_compacted_owned_coins(run.removed as u64);
+                        TaskNextAction::Continue
+                    }
+                    Ok(None) => TaskNextAction::Continue,
+                    Err(e) => TaskNextAction::ErrorContinue(e.into()),
+                }
+            }
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    config: Config,
+    off_chain: Database<OffChain>,
+) -> ServiceRunner<InitializeTask> {
+    ServiceRunner::new(InitializeTask { config, off_chain })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use crate::graphql_api::storage::{
+        blocks::FuelBlockIdsToHeights,
+        coins::owner_coin_id_key,
+    };
+    use fuel_core_storage::{
+        transactional::WriteTransaction,
+        StorageAsMut,
+        StorageAsRef,
+    };
+    use fuel_core_types::{
+        fuel_tx::{
+            Address,
+            UtxoId,
+        },
+        fuel_types::BlockHeight,
+    };
+
+    const OWNER: Address = Address::new([1; 32]);
+
+    fn config(min_spent_age: u32, entries_per_run: usize) -> Config {
+        Config {
+            interval: Duration::from_secs(1),
+            min_spent_age,
+            entries_per_run: NonZeroUsize::new(entries_per_run).unwrap(),
+        }
+    }
+
+    fn key(index: u16) -> OwnedCoinKey {
+        owner_coin_id_key(&OWNER, &UtxoId::new([2; 32].into(), index))
+    }
+
+    /// Creates the database at the `height` with the coins of the `OWNER`
+    /// in the `states`, in the order of their keys.
+    fn database(height: u32, states: &[OwnedCoinState]) -> Database<OffChain> {
+        let mut database = Database::<OffChain>::default();
+        let mut transaction = database.write_transaction();
+        for (index, state) in states.iter().enumerate() {
+            transaction
+                .storage_as_mut::<OwnedCoins>()
+                .insert(&key(u16::try_from(index).unwrap()), state)
+                .unwrap();
+        }
+        transaction
+            .storage_as_mut::<FuelBlockIdsToHeights>()
+            .insert(&Default::default(), &BlockHeight::from(height))
+            .unwrap();
+        transaction.commit().unwrap();
+        database
+    }
+
+    fn spent(height: u32) -> OwnedCoinState {
+        OwnedCoinState::Spent {
+            height: height.into(),
+        }
+    }
+
+    fn remaining(database: &Database<OffChain>) -> Vec<OwnedCoinKey> {
+        database
+            .iter_all_keys::<OwnedCoins>(None)
+            .collect::<StorageResult<Vec<_>>>()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn compact_next_range__removes_only_the_cold_spent_coins() {
+        // given
+        let states = [
+            OwnedCoinState::Unspent,
+            spent(10),
+            spent(95),
+            OwnedCoinState::Unspent,
+        ];
+        let database = database(100, &states);
+        let mut task = Task::new(config(10, 100), database.clone());
+
+        // when
+        let run = task.compact_next_range().unwrap();
+
+        // then
+        assert_eq!(
+            run,
+            Some(CompactionRun {
+                examined: 4,
+                removed: 1,
+                finished_pass: true,
+            })
+        );
+        assert_eq!(remaining(&database), vec![key(0), key(2), key(3)]);
+        assert!(database
+            .storage::<OwnedCoins>()
+            .get(&key(1))
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn compact_next_range__continues_from_the_last_examined_entry() {
+        // given
+        let states = [spent(1), spent(1), spent(1), spent(1), spent(1)];
+        let database = database(100, &states);
+        let mut task = Task::new(config(0, 2), database.clone());
+
+        // when
+        let runs = (0..3)
+            .map(|_| task.compact_next_range().unwrap().unwrap())
+            .collect::<Vec<_>>();
+
+        // then
+        let removed = runs.iter().map(|run| run.removed).collect::<Vec<_>>();
+        assert_eq!(removed, vec![2, 2, 1]);
+        assert!(runs[2].finished_pass);
+        assert!(remaining(&database).is_empty());
+        assert_eq!(task.last_key, None);
+    }
+}
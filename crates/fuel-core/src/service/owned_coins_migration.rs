@@ -0,0 +1,280 @@
+//! The migration of the `OwnedCoins` column between the layouts of its keys.
+//!
+//! The entries stored in the other layout are re-keyed in batches, each batch
+//! is committed atomically. The layout recorded in the metadata is switched
+//! only after the last batch, so the node must not run during the migration.
+//! The entries already stored in the requested layout are skipped, so the
+//! interrupted migration is finished by running it again.
+
+use crate::{
+    database::{
+        database_description::off_chain::OffChain,
+        Database,
+    },
+    graphql_api::storage::{
+        coins::{
+            insert_owned_coin,
+            owned_coins_layout,
+            take_owned_coin,
+            OwnedCoinKey,
+            OwnedCoinState,
+            OwnedCoinsLayout,
+        },
+        Column,
+    },
+};
+use fuel_core_storage::{
+    codec::{
+        manual::Manual,
+        Decode,
+    },
+    iter::{
+        IterDirection,
+        IterableStore,
+    },
+    kv_store::{
+        Key,
+        Value,
+    },
+    transactional::{
+        AtomicView,
+        ReadTransaction,
+    },
+};
+use std::{
+    num::NonZeroUsize,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The result of the migration.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MigrationReport {
+    /// The layout of the column before the migration.
+    pub from: OwnedCoinsLayout,
+    /// The number of the re-keyed entries.
+    pub migrated_entries: u64,
+    pub elapsed: Duration,
+}
+
+/// Re-keys the entries of the `OwnedCoins` column into the `layout`
+/// and records it, `batch_size` entries per commit.
+pub fn migrate_owned_coins(
+    off_chain: &Database<OffChain>,
+    layout: OwnedCoinsLayout,
+    batch_size: NonZeroUsize,
+) -> anyhow::Result<MigrationReport> {
+    let start = Instant::now();
+    let from = owned_coins_layout(off_chain)?;
+    let mut report = MigrationReport {
+        from,
+        ..Default::default()
+    };
+
+    let mut last_key = None;
+    loop {
+        let (batch, examined_key, finished) =
+            next_batch(off_chain, layout, last_key.as_ref(), batch_size)?;
+
+        let mut transaction = off_chain.read_transaction();
+        for (key, value) in &batch {
+            let key = Manual::<OwnedCoinKey>::decode(key)?;
+            let state = Manual::<OwnedCoinState>::decode(value)?;
+            // The entry is stored in the other layout than the requested one.
+            let source = match layout {
+                OwnedCoinsLayout::Plain => OwnedCoinsLayout::Sharded,
+                OwnedCoinsLayout::Sharded => OwnedCoinsLayout::Plain,
+            };
+            take_owned_coin(&mut transaction, source, &key)?;
+            insert_owned_coin(&mut transaction, layout, &key, &state)?;
+        }
+        off_chain.commit_changes_without_height(transaction.into_changes())?;
+        report.migrated_entries =
+            report.migrated_entries.saturating_add(batch.len() as u64);
+
+        if finished {
+            break
+        }
+        last_key = examined_key;
+    }
+
+    off_chain.set_owned_coins_layout(layout)?;
+    report.elapsed = start.elapsed();
+    Ok(report)
+}
+
+/// Collects up to the `batch_size` entries stored in the other layout than the `layout`
+/// after the `last_key`. Returns them with the last examined key, and `true` if
+/// the end of the column was reached.
+fn next_batch(
+    off_chain: &Database<OffChain>,
+    layout: OwnedCoinsLayout,
+    last_key: Option<&Key>,
+    batch_size: NonZeroUsize,
+) -> anyhow::Result<(Vec<(Key, Value)>, Option<Key>, bool)> {
+    let view = off_chain.latest_view()?;
+    let mut batch = Vec::new();
+    let mut examined_key = None;
+    for entry in view.iter_store(
+        Column::OwnedCoins,
+        None,
+        last_key.map(Vec::as_slice),
+        IterDirection::Forward,
+    ) {
+        let (key, value) = entry?;
+        // The iteration starts from the last examined entry inclusively.
+        if Some(&key) == last_key {
+            continue
+        }
+        examined_key = Some(key.clone());
+        if key.len() != layout.key_size() {
+            batch.push((key, value));
+            if batch.len() >= batch_size.get() {
+                return Ok((batch, examined_key, false))
+            }
+        }
+    }
+    Ok((batch, examined_key, true))
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+#[allow(clippy::arithmetic_side_effects)]
+mod tests {
+    use super::*;
+    use crate::graphql_api::storage::coins::owner_coin_id_key;
+    use fuel_core_types::fuel_tx::{
+        Address,
+        UtxoId,
+    };
+
+    fn coins() -> Vec<(Address, UtxoId)> {
+        let owners = [Address::from([1; 32]), Address::from([2; 32])];
+        owners
+            .into_iter()
+            .flat_map(|owner| {
+                (0..50u16).map(move |index| (owner, UtxoId::new([3; 32].into(), index)))
+            })
+            .collect()
+    }
+
+    fn owned_coins(database: &Database<OffChain>, owner: &Address) -> Vec<UtxoId> {
+        database
+            .latest_view()
+            .unwrap()
+            .owned_coins_ids(owner, None, None, true)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn migrate_owned_coins__rekeys_all_entries_into_the_sharded_layout() {
+        // given
+        let database = Database::<OffChain>::default();
+        let mut transaction = database.read_transaction();
+        for (owner, utxo_id) in coins() {
+            insert_owned_coin(
+                &mut transaction,
+                OwnedCoinsLayout::Plain,
+                &owner_coin_id_key(&owner, &utxo_id),
+                &OwnedCoinState::Unspent,
+            )
+            .unwrap();
+        }
+        database
+            .commit_changes_without_height(transaction.into_changes())
+            .unwrap();
+        let owner = Address::from([1; 32]);
+        let before = owned_coins(&database, &owner);
+
+        // when
+        let report = migrate_owned_coins(
+            &database,
+            OwnedCoinsLayout::Sharded,
+            NonZeroUsize::new(7).unwrap(),
+        )
+        .unwrap();
+
+        // then
+        assert_eq!(report.from, OwnedCoinsLayout::Plain);
+        assert_eq!(report.migrated_entries, coins().len() as u64);
+        assert_eq!(
+            owned_coins_layout(&database).unwrap(),
+            OwnedCoinsLayout::Sharded
+        );
+        assert_eq!(owned_coins(&database, &owner), before);
+        let plain_keys = database
+            .latest_view()
+            .unwrap()
+            .iter_store_keys(Column::OwnedCoins, None, None, IterDirection::Forward)
+            .filter(|key| {
+                key.as_ref().unwrap().len() == OwnedCoinsLayout::Plain.key_size()
+            })
+            .count();
+        assert_eq!(plain_keys, 0);
+    }
+
+    #[test]
+    fn migrate_owned_coins__finishes_the_interrupted_migration() {
+        // given
+        let database = Database::<OffChain>::default();
+        let mut transaction = database.read_transaction();
+        // The interrupted migration left a part of the entries in each layout.
+        for (index, (owner, utxo_id)) in coins().into_iter().enumerate() {
+            let layout = if index % 2 == 0 {
+                OwnedCoinsLayout::Plain
+            } else {
+                OwnedCoinsLayout::Sharded
+            };
+            insert_owned_coin(
+                &mut transaction,
+                layout,
+                &owner_coin_id_key(&owner, &utxo_id),
+                &OwnedCoinState::Unspent,
+            )
+            .unwrap();
+        }
+        database
+            .commit_changes_without_height(transaction.into_changes())
+            .unwrap();
+
+        // when
+        let report = migrate_owned_coins(
+            &database,
+            OwnedCoinsLayout::Plain,
+            NonZeroUsize::new(1000).unwrap(),
+        )
+        .unwrap();
+
+        // then
+        assert_eq!(report.migrated_entries, coins().len() as u64 / 2);
+        let expected = coins()
+            .into_iter()
+            .filter(|(owner, _)| owner == &Address::from([2; 32]))
+            .map(|(_, utxo_id)| utxo_id)
+            .collect::<Vec<_>>();
+        assert_eq!(owned_coins(&database, &Address::from([2; 32])), expected);
+    }
+
+    #[test]
+    fn init_owned_coins_layout__refuses_the_other_layout_of_the_existing_database() {
+        // given
+        let database = Database::<OffChain>::default();
+        database
+            .init_owned_coins_layout(OwnedCoinsLayout::Sharded)
+            .unwrap();
+
+        // when
+        let result = database.init_owned_coins_layout(OwnedCoinsLayout::Plain);
+
+        // then
+        let err = result.unwrap_err().to_string();
+        assert!(
+            err.contains("uses the `sharded` layout of the owned coins"),
+            "{err}"
+        );
+        assert!(err.contains("migrate-owned-coins --layout plain"), "{err}");
+    }
+}
@@ -0,0 +1,484 @@
+//! The targeted reindex rebuilds the requested off-chain index columns without
+//! rebuilding the rest of the off-chain database.
+//!
+//! The columns are grouped by the derivation that writes them. Requesting one
+//! column rebuilds every column of its derivation, because they are updated
+//! together from the same events. The derivations are fed by the current
+//! on-chain state, the same way as the genesis importer derives them from
+//! the snapshot.
+//!
+//! While the columns are rebuilt, their derivation is marked stale in the
+//! metadata of the off-chain database. The node doesn't use a stale index
+//! and the queries served by it fail until the reindex is finished, except
+//! the coins to spend, which are selected by scanning the owned coins instead.
+//!
+//! The number of owned coins per asset isn't counted by the databases created
+//! before it was maintained, so its reindex also backfills the counts and
+//! makes them available.
+//!
+//! The columns indexing the blocks aren't fed by the on-chain state, but are
+//! replayed from the stored blocks, including the blocks imported during
+//! the regenesis. They aren't part of any derivation, so they aren't marked
+//! stale while they are rebuilt.
+
+use crate::{
+    combined_database::CombinedDatabase,
+    database::{
+        database_description::{
+            off_chain::OffChain,
+            IndexationKind,
+        },
+        Database,
+    },
+    graphql_api::{
+        indexation::{
+            self,
+            error::IndexationError,
+        },
+        storage::{
+            balances::{
+                CoinBalances,
+                MessageBalances,
+            },
+            blocks::BlocksByProducer,
+            coins::{
+                CoinsToSpendIndex,
+                OwnedCoinCountByAsset,
+            },
+            contracts::{
+                ContractBalances,
+                ContractsByBlock,
+            },
+            old::{
+                OldFuelBlockConsensus,
+                OldFuelBlocks,
+                OldTransactions,
+            },
+            Column,
+        },
+        worker_service::{
+            increase_owned_coin_count,
+            index_block_producer,
+            index_contracts_by_block,
+        },
+    },
+};
+use anyhow::Context;
+use fuel_core_storage::{
+    iter::{
+        IterableTable,
+        IteratorOverTable,
+    },
+    not_found,
+    tables::{
+        Coins,
+        ContractsAssets,
+        FuelBlocks,
+        Messages,
+        SealedBlockConsensus,
+        Transactions,
+    },
+    transactional::{
+        ReadTransaction,
+        StorageTransaction,
+        WriteTransaction,
+    },
+    Error as StorageError,
+    Mappable,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+    StorageMutate,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::CompressedBlock,
+        consensus::Consensus,
+    },
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
+    fuel_types::{
+        AssetId,
+        BlockHeight,
+    },
+    services::executor::Event,
+};
+use std::time::{
+    Duration,
+    Instant,
+};
+
+/// The number of entries written or removed by one database transaction.
+const ENTRIES_PER_TRANSACTION: usize = 10_000;
+
+/// The off-chain columns written by each derivation that can be rebuilt.
+///
+/// The asset metadata is derived from the history of receipts, which can't be
+/// recovered from the current on-chain state, so its columns can't be rebuilt.
+const DERIVATIONS: [(IndexationKind, &[Column]); 4] = [
+    (
+        IndexationKind::Balances,
+        &[Column::CoinBalances, Column::MessageBalances],
+    ),
+    (IndexationKind::CoinsToSpend, &[Column::CoinsToSpend]),
+    (
+        IndexationKind::ContractBalances,
+        &[Column::ContractBalances],
+    ),
+    (
+        IndexationKind::OwnedCoinCounts,
+        &[Column::OwnedCoinCountByAsset],
+    ),
+];
+
+/// The off-chain columns indexing the blocks that can be rebuilt.
+const BLOCK_COLUMNS: [Column; 2] = [Column::ContractsByBlock, Column::BlocksByProducer];
+
+/// The summary of the targeted reindex.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ReindexReport {
+    /// The derivations that were rebuilt.
+    pub rebuilt: Vec<IndexationKind>,
+    /// The columns indexing the blocks that were rebuilt.
+    pub rebuilt_block_columns: Vec<Column>,
+    /// The number of entries removed from the rebuilt columns.
+    pub removed_entries: u64,
+    /// The number of on-chain entries replayed through the derivations.
+    pub replayed_entries: u64,
+    /// The time spent on the reindex.
+    pub elapsed: Duration,
+}
+
+/// Returns the off-chain column with the `name`, if it can be rebuilt.
+pub fn reindexable_column(name: &str) -> Option<Column> {
+    DERIVATIONS
+        .iter()
+        .flat_map(|(_, columns)| columns.iter())
+        .chain(BLOCK_COLUMNS.iter())
+        .find(|column| <&str>::from(*column) == name)
+        .copied()
+}
+
+/// Returns the derivations that write the `columns`.
+fn derivations_of(columns: &[Column]) -> anyhow::Result<Vec<IndexationKind>> {
+    let mut kinds = Vec::new();
+    for column in columns {
+        if BLOCK_COLUMNS.contains(column) {
+            continue
+        }
+        let (kind, _) = DERIVATIONS
+            .iter()
+            .find(|(_, derived)| derived.contains(column))
+            .with_context(|| format!("The column {column:?} can't be reindexed"))?;
+        if !kinds.contains(kind) {
+            kinds.push(*kind);
+        }
+    }
+    Ok(kinds)
+}
+
+/// Clears the off-chain `columns` and derives them again from the on-chain state.
+///
+/// The databases must be at the same height, so the node must not run
+/// while the columns are rebuilt. If the reindex is interrupted, the
+/// derivations stay stale until it is run again.
+pub fn reindex(
+    database: &CombinedDatabase,
+    base_asset_id: &AssetId,
+    columns: &[Column],
+) -> anyhow::Result<ReindexReport> {
+    let start = Instant::now();
+    let kinds = derivations_of(columns)?;
+    let mut block_columns: Vec<Column> = BLOCK_COLUMNS
+        .into_iter()
+        .filter(|column| columns.contains(column))
+        .collect();
+    block_columns.dedup();
+    let mut off_chain = database.off_chain().clone();
+
+    for kind in &kinds {
+        // The owned coin counts are backfilled if they aren't available.
+        if *kind != IndexationKind::OwnedCoinCounts
+            && !off_chain.indexation_available(*kind)?
+        {
+            anyhow::bail!("The {kind:?} indexation is not enabled for the database");
+        }
+    }
+    let on_chain_height = database.on_chain().latest_height_from_metadata()?;
+    let off_chain_height = off_chain.latest_height_from_metadata()?;
+    if on_chain_height != off_chain_height {
+        anyhow::bail!(
+            "The off-chain database height({off_chain_height:?}) doesn't match \
+            the on-chain database height({on_chain_height:?})"
+        );
+    }
+
+    off_chain.set_indexation_stale(&kinds, true)?;
+
+    let mut report = ReindexReport {
+        rebuilt: kinds.clone(),
+        rebuilt_block_columns: block_columns.clone(),
+        ..Default::default()
+    };
+    for kind in &kinds {
+        report.removed_entries = report
+            .removed_entries
+            .saturating_add(clear_derivation(&off_chain, *kind)?);
+    }
+
+    let balances = kinds.contains(&IndexationKind::Balances);
+    let coins_to_spend = kinds.contains(&IndexationKind::CoinsToSpend);
+    if balances || coins_to_spend {
+        let coins = database.on_chain().iter_all::<Coins>(None).map(|result| {
+            result.map(|(key, value)| Event::CoinCreated(value.uncompress(key)))
+        });
+        let messages = database
+            .on_chain()
+            .iter_all::<Messages>(None)
+            .map(|result| result.map(|(_, value)| Event::MessageImported(value)));
+
+        let mut events = coins.chain(messages).peekable();
+        while events.peek().is_some() {
+            let mut transaction = off_chain.write_transaction();
+            for event in events.by_ref().take(ENTRIES_PER_TRANSACTION) {
+                let event = event?;
+                indexation::balances::update(&event, &mut transaction, balances)
+                    .map_err(into_anyhow)?;
+                indexation::coins_to_spend::update(
+                    &event,
+                    &mut transaction,
+                    coins_to_spend,
+                    base_asset_id,
+                )
+                .map_err(into_anyhow)?;
+                report.replayed_entries = report.replayed_entries.saturating_add(1);
+            }
+            let changes = transaction.into_changes();
+            off_chain.commit_changes_without_height(changes)?;
+        }
+    }
+
+    if kinds.contains(&IndexationKind::ContractBalances) {
+        let mut balances = database
+            .on_chain()
+            .iter_all::<ContractsAssets>(None)
+            .peekable();
+        while balances.peek().is_some() {
+            let mut transaction = off_chain.write_transaction();
+            for balance in balances.by_ref().take(ENTRIES_PER_TRANSACTION) {
+                let (key, amount) = balance?;
+                transaction
+                    .storage_as_mut::<ContractBalances>()
+                    .insert(&key, &amount)?;
+                report.replayed_entries = report.replayed_entries.saturating_add(1);
+            }
+            let changes = transaction.into_changes();
+            off_chain.commit_changes_without_height(changes)?;
+        }
+    }
+
+    if kinds.contains(&IndexationKind::OwnedCoinCounts) {
+        let mut coins = database.on_chain().iter_all::<Coins>(None).peekable();
+        while coins.peek().is_some() {
+            let mut transaction = off_chain.write_transaction();
+            for coin in coins.by_ref().take(ENTRIES_PER_TRANSACTION) {
+                let (_, coin) = coin?;
+                increase_owned_coin_count(
+                    &mut transaction,
+                    coin.owner(),
+                    coin.asset_id(),
+                )?;
+                report.replayed_entries = report.replayed_entries.saturating_add(1);
+            }
+            let changes = transaction.into_changes();
+            off_chain.commit_changes_without_height(changes)?;
+        }
+        off_chain.set_indexation_available(&[IndexationKind::OwnedCoinCounts], true)?;
+    }
+
+    if block_columns.contains(&Column::ContractsByBlock) {
+        report.removed_entries =
+            report
+                .removed_entries
+                .saturating_add(clear_table::<ContractsByBlock, _>(&off_chain)?);
+
+        let on_chain = database.on_chain();
+        let old_blocks = off_chain.iter_all::<OldFuelBlocks>(None);
+        let replayed = replay_contract_deployments(&off_chain, old_blocks, |tx_id| {
+            off_chain
+                .storage::<OldTransactions>()
+                .get(tx_id)?
+                .ok_or(not_found!(OldTransactions))
+                .map(|tx| tx.into_owned())
+        })?;
+        report.replayed_entries = report.replayed_entries.saturating_add(replayed);
+
+        let blocks = on_chain.iter_all::<FuelBlocks>(None);
+        let replayed = replay_contract_deployments(&off_chain, blocks, |tx_id| {
+            on_chain
+                .storage::<Transactions>()
+                .get(tx_id)?
+                .ok_or(not_found!(Transactions))
+                .map(|tx| tx.into_owned())
+        })?;
+        report.replayed_entries = report.replayed_entries.saturating_add(replayed);
+    }
+
+    if block_columns.contains(&Column::BlocksByProducer) {
+        report.removed_entries =
+            report
+                .removed_entries
+                .saturating_add(clear_table::<BlocksByProducer, _>(&off_chain)?);
+
+        let on_chain = database.on_chain();
+        let old_blocks = off_chain.iter_all::<OldFuelBlocks>(None);
+        let replayed = replay_block_producers(&off_chain, old_blocks, |height| {
+            off_chain
+                .storage::<OldFuelBlockConsensus>()
+                .get(height)?
+                .ok_or(not_found!(OldFuelBlockConsensus))
+                .map(|consensus| consensus.into_owned())
+        })?;
+        report.replayed_entries = report.replayed_entries.saturating_add(replayed);
+
+        let blocks = on_chain.iter_all::<FuelBlocks>(None);
+        let replayed = replay_block_producers(&off_chain, blocks, |height| {
+            on_chain
+                .storage::<SealedBlockConsensus>()
+                .get(height)?
+                .ok_or(not_found!(SealedBlockConsensus))
+                .map(|consensus| consensus.into_owned())
+        })?;
+        report.replayed_entries = report.replayed_entries.saturating_add(replayed);
+    }
+
+    off_chain.set_indexation_stale(&kinds, false)?;
+    report.elapsed = start.elapsed();
+    Ok(report)
+}
+
+/// Indexes the contracts deployed by the `blocks` and returns the number
+/// of the replayed blocks. The transactions of the blocks are loaded by
+/// the `transaction`.
+fn replay_contract_deployments<B, F>(
+    off_chain: &Database<OffChain>,
+    blocks: B,
+    transaction: F,
+) -> anyhow::Result<u64>
+where
+    B: Iterator<Item = StorageResult<(BlockHeight, CompressedBlock)>>,
+    F: Fn(&TxId) -> StorageResult<Transaction>,
+{
+    let mut replayed = 0u64;
+    let mut blocks = blocks.peekable();
+    while blocks.peek().is_some() {
+        let mut storage_transaction = off_chain.write_transaction();
+        for block in blocks.by_ref().take(ENTRIES_PER_TRANSACTION) {
+            let (height, block) = block?;
+            let tx_ids = block.transactions();
+            let transactions = tx_ids
+                .iter()
+                .map(&transaction)
+                .collect::<StorageResult<Vec<_>>>()?;
+            index_contracts_by_block(
+                height,
+                tx_ids.iter().zip(transactions.iter()),
+                &mut storage_transaction,
+            )?;
+            replayed = replayed.saturating_add(1);
+        }
+        let changes = storage_transaction.into_changes();
+        off_chain.commit_changes_without_height(changes)?;
+    }
+    Ok(replayed)
+}
+
+/// Indexes the `blocks` by their producers and returns the number of the
+/// replayed blocks. The consensus of the blocks is loaded by the `consensus`.
+fn replay_block_producers<B, F>(
+    off_chain: &Database<OffChain>,
+    blocks: B,
+    consensus: F,
+) -> anyhow::Result<u64>
+where
+    B: Iterator<Item = StorageResult<(BlockHeight, CompressedBlock)>>,
+    F: Fn(&BlockHeight) -> StorageResult<Consensus>,
+{
+    let mut replayed = 0u64;
+    let mut blocks = blocks.peekable();
+    while blocks.peek().is_some() {
+        let mut storage_transaction = off_chain.write_transaction();
+        for block in blocks.by_ref().take(ENTRIES_PER_TRANSACTION) {
+            let (height, block) = block?;
+            index_block_producer(
+                height,
+                &block.id(),
+                &consensus(&height)?,
+                &mut storage_transaction,
+            )?;
+            replayed = replayed.saturating_add(1);
+        }
+        let changes = storage_transaction.into_changes();
+        off_chain.commit_changes_without_height(changes)?;
+    }
+    Ok(replayed)
+}
+
+fn into_anyhow(error: IndexationError) -> anyhow::Error {
+    match error {
+        IndexationError::StorageError(error) => error.into(),
+        error => anyhow::anyhow!("{error}"),
+    }
+}
+
+/// Removes all entries of the columns of the derivation.
+fn clear_derivation(
+    off_chain: &Database<OffChain>,
+    kind: IndexationKind,
+) -> anyhow::Result<u64> {
+    let removed = match kind {
+        IndexationKind::Balances => clear_table::<CoinBalances, _>(off_chain)?
+            .saturating_add(clear_table::<MessageBalances, _>(off_chain)?),
+        IndexationKind::CoinsToSpend => clear_table::<CoinsToSpendIndex, _>(off_chain)?,
+        IndexationKind::ContractBalances => {
+            clear_table::<ContractBalances, _>(off_chain)?
+        }
+        IndexationKind::OwnedCoinCounts => {
+            clear_table::<OwnedCoinCountByAsset, _>(off_chain)?
+        }
+        IndexationKind::AssetMetadata => {
+            anyhow::bail!("The {kind:?} indexation can't be reindexed")
+        }
+    };
+    Ok(removed)
+}
+
+fn clear_table<M, K>(off_chain: &Database<OffChain>) -> anyhow::Result<u64>
+where
+    M: Mappable<Key = K, OwnedKey = K>,
+    Database<OffChain>: IterableTable<M>,
+    for<'a> StorageTransaction<&'a Database<OffChain>>:
+        StorageMutate<M, Error = StorageError>,
+{
+    let mut removed = 0u64;
+    loop {
+        // The removed keys are committed before the next batch is read,
+        // so every batch starts from the beginning of the table.
+        let keys = off_chain
+            .iter_all_keys::<M>(None)
+            .take(ENTRIES_PER_TRANSACTION)
+            .collect::<Result<Vec<_>, _>>()?;
+        if keys.is_empty() {
+            return Ok(removed)
+        }
+
+        let mut transaction = off_chain.read_transaction();
+        for key in &keys {
+            transaction.storage_as_mut::<M>().remove(key)?;
+        }
+        off_chain.commit_changes_without_height(transaction.into_changes())?;
+        removed = removed.saturating_add(keys.len() as u64);
+    }
+}
@@ -1,6 +1,13 @@
 #![allow(clippy::let_unit_value)]
 
-use std::sync::Arc;
+use std::{
+    num::{
+        NonZeroU32,
+        NonZeroUsize,
+    },
+    sync::Arc,
+    time::Duration,
+};
 
 use tokio::sync::Mutex;
 
@@ -57,7 +64,20 @@ use crate::{
         self,
         Config as GraphQLConfig,
     },
-    graphql_api::worker_service,
+    graphql_api::{
+        contracts_info_cache::ContractsInfoCache,
+        da_compression_service,
+        message_proof_cache::MessageProofCache,
+        node_health::NodeHealthSources,
+        storage::da_compression::{
+            dictionaries::load_da_dictionaries,
+            versioned_block_codec::DaBlockEncoding,
+        },
+        worker_service::{
+            self,
+            DaCompressionConfig,
+        },
+    },
     schema::build_schema,
     service::{
         adapters::{
@@ -80,6 +100,9 @@ use crate::{
             UniversalGasPriceProvider,
             VerifierAdapter,
         },
+        balance_check,
+        da_compressed_blocks_reencoding,
+        owned_coins_compaction,
         Config,
         SharedState,
         SubServices,
@@ -372,7 +395,7 @@ pub fn init_sub_services(
         super::adapters::ConsensusAdapter::new(
             verifier.clone(),
             config.relayer_consensus_config.clone(),
-            relayer_adapter,
+            relayer_adapter.clone(),
         ),
         config.sync,
     )?;
@@ -382,20 +405,107 @@ pub fn init_sub_services(
 
     let graphql_block_importer =
         GraphQLBlockImporter::new(importer_adapter.clone(), import_result_provider);
+    let contracts_info_cache =
+        ContractsInfoCache::new(config.graphql_config.contracts_info_cache_capacity);
+    #[cfg(feature = "relayer")]
+    let relayed_transactions: Option<
+        crate::graphql_api::ports::worker::RelayedTransactionsProviderRef,
+    > = Some(Arc::new(
+        super::adapters::relayer::RelayedTransactionsAdapter::new(
+            database.on_chain().clone(),
+            database.relayer().clone(),
+        ),
+    ));
+    // Without the relayer, the blocks don't process relayed transactions.
+    #[cfg(not(feature = "relayer"))]
+    let relayed_transactions = None;
     let graphql_worker_context = worker_service::Context {
         tx_status_manager: tx_status_manager_adapter.clone(),
-        block_importer: graphql_block_importer,
+        block_importer: graphql_block_importer.clone(),
         on_chain_database: database.on_chain().clone(),
         off_chain_database: database.off_chain().clone(),
-        da_compression_config: config.da_compression.clone(),
         continue_on_error: config.continue_on_error,
+        historical_data_retention: config.historical_data_retention,
+        batch_blocks: config.off_chain_worker_batch_blocks,
         consensus_parameters: &chain_config.consensus_parameters,
+        contracts_info_cache: contracts_info_cache.clone(),
+        coin_owner_mapper: config.coin_owner_mapper.clone(),
+        relayed_transactions,
+        slow_commit_threshold: config.off_chain_slow_commit_threshold,
+        reconcile_tx_count: config.reconcile_tx_count,
+        mark_spent_owned_coins: config.graphql_config.mark_spent_owned_coins,
     };
     let graphql_worker =
         fuel_core_graphql_api::worker_service::new_service(graphql_worker_context)?;
 
     let graphql_block_height_subscription_handle = graphql_worker.shared.clone();
 
+    // The stored blocks may be compressed with the dictionaries regardless
+    // of the current encoding, so they are always loaded.
+    let da_dictionary = load_da_dictionaries(database.off_chain())?;
+    let da_compression = match &config.da_compression {
+        DaCompressionConfig::Enabled(compression_config) => {
+            Some(da_compression_service::new_service(
+                *compression_config,
+                config.da_compression_encoding,
+                da_dictionary.clone(),
+                graphql_block_importer,
+                database.on_chain().clone(),
+                database.off_chain().clone(),
+            ))
+        }
+        DaCompressionConfig::Disabled => None,
+    };
+    let da_compressed_blocks_reencoding = match config.da_compression_encoding {
+        DaBlockEncoding::Zstd { level } if da_compression.is_some() => {
+            Some(da_compressed_blocks_reencoding::new_service(
+                da_compressed_blocks_reencoding::Config {
+                    interval: Duration::from_secs(1),
+                    entries_per_run: NonZeroUsize::new(1000).expect("1000 is not zero"),
+                    level,
+                },
+                da_dictionary,
+                database.off_chain().clone(),
+            ))
+        }
+        _ => None,
+    };
+
+    let balance_check = NonZeroU32::new(
+        config.graphql_config.balance_check_owners_per_minute,
+    )
+    .map(|owners_per_minute| {
+        balance_check::new_service(
+            balance_check::Config {
+                owners_per_minute,
+                repair: config.graphql_config.balance_check_repair,
+            },
+            database.on_chain().clone(),
+            database.off_chain().clone(),
+        )
+    });
+
+    let owned_coins_compaction =
+        config.graphql_config.mark_spent_owned_coins.then(|| {
+            owned_coins_compaction::new_service(
+                owned_coins_compaction::Config {
+                    interval: config.graphql_config.owned_coins_compaction_interval,
+                    min_spent_age: config
+                        .graphql_config
+                        .owned_coins_compaction_min_spent_age,
+                    entries_per_run: config
+                        .graphql_config
+                        .owned_coins_compaction_entries_per_run,
+                },
+                database.off_chain().clone(),
+            )
+        });
+
+    let message_proof_cache = MessageProofCache::new(
+        database.off_chain().clone(),
+        config.graphql_config.message_proof_cache_capacity,
+    );
+
     let graphql_config = GraphQLConfig {
         config: config.graphql_config.clone(),
         utxo_validation: config.utxo_validation,
@@ -406,6 +516,12 @@ pub fn init_sub_services(
         max_size: config.txpool.pool_limits.max_bytes_size,
         max_txpool_dependency_chain_length: config.txpool.max_txs_chain_count,
         chain_name,
+        da_registry_capacity: match &config.da_compression {
+            DaCompressionConfig::Enabled(compression_config) => {
+                compression_config.registry_capacity
+            }
+            DaCompressionConfig::Disabled => None,
+        },
     };
 
     let graph_ql = fuel_core_graphql_api::api_service::new_service(
@@ -414,7 +530,7 @@ pub fn init_sub_services(
         schema,
         database.on_chain().clone(),
         database.off_chain().clone(),
-        Box::new(tx_pool_adapter),
+        Box::new(tx_pool_adapter.clone()),
         Box::new(tx_status_manager_adapter.clone()),
         Box::new(producer_adapter),
         Box::new(poa_adapter.clone()),
@@ -423,6 +539,18 @@ pub fn init_sub_services(
         Box::new(chain_state_info_provider),
         SharedMemoryPool::new(config.memory_pool_size),
         graphql_block_height_subscription_handle,
+        database.rollback_notifier().clone(),
+        contracts_info_cache,
+        message_proof_cache,
+        Box::new(database.off_chain().clone()),
+        Box::new(database.off_chain().clone()),
+        NodeHealthSources {
+            txpool: Box::new(tx_pool_adapter),
+            relayer: Box::new(relayer_adapter),
+            da_compression_height: da_compression
+                .as_ref()
+                .map(|service| service.shared.clone()),
+        },
     )?;
 
     let shared = SharedState {
@@ -465,6 +593,18 @@ pub fn init_sub_services(
 
     services.push(Box::new(graph_ql));
     services.push(Box::new(graphql_worker));
+    if let Some(da_compression) = da_compression {
+        services.push(Box::new(da_compression));
+    }
+    if let Some(da_compressed_blocks_reencoding) = da_compressed_blocks_reencoding {
+        services.push(Box::new(da_compressed_blocks_reencoding));
+    }
+    if let Some(balance_check) = balance_check {
+        services.push(Box::new(balance_check));
+    }
+    if let Some(owned_coins_compaction) = owned_coins_compaction {
+        services.push(Box::new(owned_coins_compaction));
+    }
     services.push(Box::new(tx_status_manager));
 
     // always make sure that the block producer is inserted last
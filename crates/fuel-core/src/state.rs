@@ -12,7 +12,10 @@ use fuel_core_storage::{
         IterableStore,
     },
     kv_store::StorageColumn,
-    transactional::StorageChanges,
+    transactional::{
+        Changes,
+        StorageChanges,
+    },
     Result as StorageResult,
 };
 use std::fmt::Debug;
@@ -60,6 +63,24 @@ pub trait TransactableStorage<Height>: IterableStore + Debug + Send + Sync {
         changes: StorageChanges,
     ) -> StorageResult<()>;
 
+    /// Commits the changes of several consecutive heights at once. The `changes`
+    /// are ordered by the height, and each of them is built on top of the previous
+    /// ones. By default, they are merged and committed as the changes of the last
+    /// height, which is enough for the storages without the history.
+    fn commit_changes_of_heights(
+        &self,
+        changes: Vec<(Height, Changes)>,
+    ) -> StorageResult<()> {
+        let mut last_height = None;
+        let mut list = Vec::with_capacity(changes.len());
+        for (height, changes) in changes {
+            last_height = Some(height);
+            list.push(changes);
+        }
+        let changes = merge_changes(list);
+        self.commit_changes(last_height, StorageChanges::Changes(changes))
+    }
+
     fn view_at_height(
         &self,
         height: &Height,
@@ -70,6 +91,18 @@ pub trait TransactableStorage<Height>: IterableStore + Debug + Send + Sync {
     fn rollback_block_to(&self, height: &Height) -> StorageResult<()>;
 }
 
+/// Merges the `changes` into one, so the later operations on the same key
+/// replace the earlier ones.
+pub fn merge_changes(changes: impl IntoIterator<Item = Changes>) -> Changes {
+    let mut merged = Changes::default();
+    for changes in changes {
+        for (column, operations) in changes {
+            merged.entry(column).or_default().extend(operations);
+        }
+    }
+    merged
+}
+
 // It is used only to allow conversion of the `StorageTransaction` into the `DataSource`.
 #[cfg(feature = "test-helpers")]
 impl<Height, S> TransactableStorage<Height>
@@ -18,6 +18,7 @@ use crate::{
         },
         iterable_key_value_view::IterableKeyValueViewWrapper,
         key_value_view::KeyValueViewWrapper,
+        merge_changes,
         rocks_db::RocksDb,
         ColumnType,
         HeightType,
@@ -44,6 +45,7 @@ use fuel_core_storage::{
     transactional::{
         Changes,
         ConflictPolicy,
+        Modifiable,
         ReadTransaction,
         StorageChanges,
         StorageTransaction,
@@ -86,6 +88,22 @@ pub enum StateRewindPolicy {
     RewindRange { size: NonZeroU64 },
 }
 
+impl StateRewindPolicy {
+    /// Limits the policy to keep the history of at most `blocks` last blocks.
+    /// The limit never extends the history kept by the policy.
+    pub fn limited_to(self, blocks: NonZeroU64) -> Self {
+        match self {
+            StateRewindPolicy::NoRewind => StateRewindPolicy::NoRewind,
+            StateRewindPolicy::RewindFullRange => {
+                StateRewindPolicy::RewindRange { size: blocks }
+            }
+            StateRewindPolicy::RewindRange { size } => StateRewindPolicy::RewindRange {
+                size: size.min(blocks),
+            },
+        }
+    }
+}
+
 /// Implementation of a database
 #[derive(Debug)]
 pub struct HistoricalRocksDB<Description> {
@@ -134,19 +152,31 @@ where
         })
     }
 
-    fn reverse_history_changes(&self, changes: &Changes) -> StorageResult<Changes> {
+    /// Returns the changes that revert the `changes` applied on top of the database
+    /// with the not yet committed `pending` changes.
+    fn reverse_history_changes(
+        &self,
+        pending: &Changes,
+        changes: &Changes,
+    ) -> StorageResult<Changes> {
         let mut reverse_changes = Changes::default();
 
         for (column, column_changes) in changes {
             let results = self
                 .db
                 .multi_get(*column, column_changes.iter().map(|(k, _)| k))?;
+            let pending_column = pending.get(column);
 
             let entry = reverse_changes
                 .entry(*column)
                 .or_insert_with(Default::default);
 
             for (was, (key, became)) in results.into_iter().zip(column_changes.iter()) {
+                let was = match pending_column.and_then(|pending| pending.get(key)) {
+                    Some(WriteOperation::Insert(value)) => Some(value.to_vec()),
+                    Some(WriteOperation::Remove) => None,
+                    None => was,
+                };
                 match (was, became) {
                     (None, WriteOperation::Remove) => {
                         // Do nothing since it was not existing, and it was removed.
@@ -219,6 +249,7 @@ where
         &self,
         storage_transaction: &mut StorageTransaction<T>,
         height: &Description::Height,
+        pending: &Changes,
     ) -> StorageResult<()>
     where
         T: KeyValueInspect<Column = Column<Description>>,
@@ -229,7 +260,7 @@ where
         let height_u64 = height.as_u64();
 
         let reverse_changes =
-            self.reverse_history_changes(storage_transaction.changes())?;
+            self.reverse_history_changes(pending, storage_transaction.changes())?;
 
         cleanup_old_changes(
             self,
@@ -575,16 +606,20 @@ where
             if self.state_rewind_policy != StateRewindPolicy::NoRewind {
                 let all_changes = match changes {
                     StorageChanges::Changes(changes) => changes,
-                    StorageChanges::ChangesList(list) => {
-                        list.into_iter().flatten().collect()
-                    }
+                    // The changes of one column may be split across the list,
+                    // so they are merged by key instead of replacing each other.
+                    StorageChanges::ChangesList(list) => merge_changes(list),
                 };
                 let mut storage_transaction = StorageTransaction::transaction(
                     &self.db,
                     ConflictPolicy::Overwrite,
                     all_changes,
                 );
-                self.store_modifications_history(&mut storage_transaction, &height)?;
+                self.store_modifications_history(
+                    &mut storage_transaction,
+                    &height,
+                    &Changes::default(),
+                )?;
                 changes = StorageChanges::Changes(storage_transaction.into_changes());
             }
         }
@@ -594,6 +629,38 @@ where
         Ok(())
     }
 
+    fn commit_changes_of_heights(
+        &self,
+        changes: Vec<(Description::Height, Changes)>,
+    ) -> StorageResult<()> {
+        // The history of each height is built on top of the changes of the previous
+        // heights, so the rollback can stop at any height of the batch.
+        let mut batch = StorageTransaction::transaction(
+            &self.db,
+            ConflictPolicy::Overwrite,
+            Changes::default(),
+        );
+        for (height, changes) in changes {
+            let mut storage_transaction = StorageTransaction::transaction(
+                &batch,
+                ConflictPolicy::Overwrite,
+                changes,
+            );
+            self.store_modifications_history(
+                &mut storage_transaction,
+                &height,
+                batch.changes(),
+            )?;
+            let changes = storage_transaction.into_changes();
+            batch.commit_changes(changes)?;
+        }
+
+        self.db
+            .commit_changes(&StorageChanges::Changes(batch.into_changes()))?;
+
+        Ok(())
+    }
+
     fn view_at_height(
         &self,
         height: &Description::Height,
@@ -676,6 +743,28 @@ mod tests {
         ContractsAssetKey::new(&[123; 32].into(), &[213; 32].into())
     }
 
+    #[test]
+    fn state_rewind_policy_limited_to__never_extends_the_history() {
+        let size = |blocks: u64| NonZeroU64::new(blocks).unwrap();
+
+        assert_eq!(
+            StateRewindPolicy::NoRewind.limited_to(size(10)),
+            StateRewindPolicy::NoRewind
+        );
+        assert_eq!(
+            StateRewindPolicy::RewindFullRange.limited_to(size(10)),
+            StateRewindPolicy::RewindRange { size: size(10) }
+        );
+        assert_eq!(
+            StateRewindPolicy::RewindRange { size: size(5) }.limited_to(size(10)),
+            StateRewindPolicy::RewindRange { size: size(5) }
+        );
+        assert_eq!(
+            StateRewindPolicy::RewindRange { size: size(20) }.limited_to(size(10)),
+            StateRewindPolicy::RewindRange { size: size(10) }
+        );
+    }
+
     #[test]
     fn historical_rocksdb_read_original_database_works() {
         // Given
@@ -1158,6 +1247,81 @@ mod tests {
         }
     }
 
+    #[test]
+    fn commit_changes_of_heights__allows_rollback_to_each_height_of_the_batch() {
+        // Given
+        let rocks_db = RocksDb::<Historical<OnChain>>::default_open_temp().unwrap();
+        let historical_rocks_db =
+            HistoricalRocksDB::new(rocks_db, StateRewindPolicy::RewindFullRange).unwrap();
+        let changes_with_balance = |balance: u64| {
+            let mut transaction = historical_rocks_db.read_transaction();
+            transaction
+                .storage_as_mut::<ContractsAssets>()
+                .insert(&key(), &balance)
+                .unwrap();
+            transaction.into_changes()
+        };
+        historical_rocks_db
+            .commit_changes(Some(1u32.into()), changes_with_balance(100).into())
+            .unwrap();
+        let batch = vec![
+            (2u32.into(), changes_with_balance(200)),
+            (3u32.into(), changes_with_balance(300)),
+        ];
+
+        // When
+        historical_rocks_db
+            .commit_changes_of_heights(batch)
+            .unwrap();
+
+        // Then
+        let balance = |db: &HistoricalRocksDB<OnChain>| {
+            db.read_transaction()
+                .storage_as_ref::<ContractsAssets>()
+                .get(&key())
+                .unwrap()
+                .unwrap()
+                .into_owned()
+        };
+        assert_eq!(balance(&historical_rocks_db), 300);
+        historical_rocks_db.rollback_block_to(2).unwrap();
+        assert_eq!(balance(&historical_rocks_db), 200);
+        historical_rocks_db.rollback_block_to(1).unwrap();
+        assert_eq!(balance(&historical_rocks_db), 100);
+    }
+
+    #[test]
+    fn commit_changes__keeps_the_changes_of_one_column_split_across_the_list() {
+        // Given
+        let rocks_db = RocksDb::<Historical<OnChain>>::default_open_temp().unwrap();
+        let historical_rocks_db =
+            HistoricalRocksDB::new(rocks_db, StateRewindPolicy::RewindFullRange).unwrap();
+        let other_key = ContractsAssetKey::new(&[123; 32].into(), &[214; 32].into());
+        let changes_with_balance = |key: &ContractsAssetKey, balance: u64| {
+            let mut transaction = historical_rocks_db.read_transaction();
+            transaction
+                .storage_as_mut::<ContractsAssets>()
+                .insert(key, &balance)
+                .unwrap();
+            transaction.into_changes()
+        };
+        let changes = StorageChanges::ChangesList(vec![
+            changes_with_balance(&key(), 100),
+            changes_with_balance(&other_key, 200),
+        ]);
+
+        // When
+        historical_rocks_db
+            .commit_changes(Some(1u32.into()), changes)
+            .unwrap();
+
+        // Then
+        let transaction = historical_rocks_db.read_transaction();
+        let storage = transaction.storage_as_ref::<ContractsAssets>();
+        assert_eq!(storage.get(&key()).unwrap().unwrap().into_owned(), 100);
+        assert_eq!(storage.get(&other_key).unwrap().unwrap().into_owned(), 200);
+    }
+
     #[test]
     fn state_rewind_policy__rewind_range_1__second_rollback_fails() {
         // Given
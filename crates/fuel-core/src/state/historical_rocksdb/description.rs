@@ -1,4 +1,7 @@
-use crate::database::database_description::DatabaseDescription;
+use crate::database::database_description::{
+    CompressionPolicy,
+    DatabaseDescription,
+};
 use fuel_core_storage::kv_store::StorageColumn;
 
 pub const HISTORY_COLUMN_ID: u32 = u32::MAX / 2;
@@ -92,6 +95,17 @@ where
             Column::HistoryColumn | Column::HistoryV2Column => Some(8),
         }
     }
+
+    fn compression_policy(column: &Self::Column) -> CompressionPolicy {
+        match column {
+            Column::OriginalColumn(c) | Column::HistoricalDuplicateColumn(c) => {
+                Description::compression_policy(c)
+            }
+            Column::HistoryColumn | Column::HistoryV2Column => {
+                CompressionPolicy::default()
+            }
+        }
+    }
 }
 
 #[cfg(test)]
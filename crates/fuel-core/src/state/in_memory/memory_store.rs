@@ -63,11 +63,14 @@ where
     Description: DatabaseDescription,
 {
     fn default() -> Self {
-        use strum::EnumCount;
+        // The ids of the columns may have gaps, so the tables are allocated
+        // up to the largest id.
+        let tables = enum_iterator::all::<Description::Column>()
+            .map(|column| column.as_usize().saturating_add(1))
+            .max()
+            .unwrap_or_default();
         Self {
-            inner: (0..Description::Column::COUNT)
-                .map(|_| Mutex::new(BTreeMap::new()))
-                .collect(),
+            inner: (0..tables).map(|_| Mutex::new(BTreeMap::new())).collect(),
             _marker: Default::default(),
         }
     }
@@ -360,4 +363,20 @@ mod tests {
 
         assert!(!db.exists(&key, Column::Metadata).unwrap());
     }
+
+    #[test]
+    fn can_use_all_columns_with_sparse_ids() {
+        use crate::database::database_description::off_chain::OffChain;
+        type OffChainColumn = <OffChain as DatabaseDescription>::Column;
+
+        let key = vec![0x00];
+        let mut db = MemoryStore::<OffChain>::default();
+
+        for column in enum_iterator::all::<OffChainColumn>() {
+            let expected = Value::from(column.id().to_be_bytes());
+            db.put(&key, column, expected.clone()).unwrap();
+
+            assert_eq!(db.get(&key, column).unwrap().unwrap(), expected);
+        }
+    }
 }
@@ -1,7 +1,10 @@
 use crate::{
     database::{
         convert_to_rocksdb_direction,
-        database_description::DatabaseDescription,
+        database_description::{
+            CompressionPolicy,
+            DatabaseDescription,
+        },
         Error as DatabaseError,
         Result as DatabaseResult,
     },
@@ -120,6 +123,9 @@ pub struct DatabaseConfig {
     pub cache_capacity: Option<usize>,
     pub max_fds: i32,
     pub columns_policy: ColumnsPolicy,
+    /// Allows opening the database with columns unknown to this version of the node,
+    /// for example, created by a newer version. Unknown columns are ignored.
+    pub allow_unknown_columns: bool,
 }
 
 #[cfg(feature = "test-helpers")]
@@ -129,6 +135,7 @@ impl DatabaseConfig {
             cache_capacity: None,
             max_fds: 512,
             columns_policy: ColumnsPolicy::Lazy,
+            allow_unknown_columns: false,
         }
     }
 }
@@ -169,6 +176,7 @@ where
             cache_capacity: None,
             max_fds: 512,
             columns_policy: ColumnsPolicy::Lazy,
+            allow_unknown_columns: false,
         })
     }
 
@@ -336,6 +344,10 @@ where
 
         let existing_column_families = DB::list_cf(&opts, &path).unwrap_or_default();
 
+        if !database_config.allow_unknown_columns {
+            Self::ensure_no_unknown_columns(&existing_column_families)?;
+        }
+
         let mut cf_descriptors_to_open = BTreeMap::new();
         let mut cf_descriptors_to_create = BTreeMap::new();
         for column in columns.clone() {
@@ -507,6 +519,35 @@ where
         }
     }
 
+    /// Returns an error if the database contains a column not known to this version
+    /// of the node, which means that the database was created by a newer version.
+    fn ensure_no_unknown_columns(
+        existing_column_families: &[String],
+    ) -> DatabaseResult<()> {
+        let known_columns = enum_iterator::all::<Description::Column>()
+            .map(|column| Self::col_name(column.id()))
+            .collect::<HashSet<_>>();
+
+        let unknown_column = existing_column_families
+            .iter()
+            .filter(|name| !known_columns.contains(*name))
+            .filter_map(|name| {
+                let id = name.strip_prefix("col-")?.parse::<u32>().ok()?;
+                Some((id, name))
+            })
+            .min_by_key(|(id, _)| *id);
+
+        if let Some((id, name)) = unknown_column {
+            return Err(DatabaseError::UnknownColumn {
+                database: Description::name(),
+                id,
+                name: name.clone(),
+            });
+        }
+
+        Ok(())
+    }
+
     fn col_name(column: u32) -> String {
         format!("col-{}", column)
     }
@@ -522,6 +563,9 @@ where
 
     fn cf_opts(column: Description::Column, block_opts: &BlockBasedOptions) -> Options {
         let mut opts = Self::default_opts(block_opts);
+        opts.set_compression_type(compression_type(Description::compression_policy(
+            &column,
+        )));
 
         // All double-keys should be configured here
         if let Some(size) = Description::prefix(&column) {
@@ -797,6 +841,7 @@ where
             cache_capacity: None,
             max_fds: -1,
             columns_policy: ColumnsPolicy::Lazy,
+            allow_unknown_columns: false,
         };
 
         let db = Self::default_open(db_dir, db_config)?;
@@ -1061,6 +1106,14 @@ where
     }
 }
 
+fn compression_type(policy: CompressionPolicy) -> DBCompressionType {
+    match policy {
+        CompressionPolicy::None => DBCompressionType::None,
+        CompressionPolicy::Lz4 => DBCompressionType::Lz4,
+        CompressionPolicy::Zstd => DBCompressionType::Zstd,
+    }
+}
+
 /// The `None` means overflow, so there is not following prefix.
 fn next_prefix(mut prefix: Vec<u8>) -> Option<Vec<u8>> {
     for byte in prefix.iter_mut().rev() {
@@ -1162,6 +1215,101 @@ mod tests {
         assert_eq!(Ok(()), database_with_new_columns);
     }
 
+    #[test]
+    fn cf_opts__compresses_only_the_bulk_columns_of_the_off_chain_database() {
+        use crate::{
+            database::database_description::off_chain::OffChain,
+            graphql_api::storage::Column as OffChainColumn,
+        };
+
+        // Given
+        let tmp_dir = TempDir::new().unwrap();
+        let mut db = RocksDb::<OffChain>::default_open(
+            tmp_dir.path(),
+            DatabaseConfig::config_for_tests(),
+        )
+        .unwrap();
+        let columns = [OffChainColumn::OldTransactions, OffChainColumn::OwnedCoins];
+        for column in columns {
+            for key in 0u8..16 {
+                db.put(&[key; 32], column, vec![key; 64 * 1024].into())
+                    .unwrap();
+            }
+        }
+
+        // When
+        let sizes = columns.map(|column| {
+            let cf = db.cf(column);
+            db.db.flush_cf(&cf).unwrap();
+            db.db
+                .property_int_value_cf(&cf, "rocksdb.total-sst-files-size")
+                .unwrap()
+                .unwrap()
+        });
+
+        // Then
+        let [compressed, uncompressed] = sizes;
+        assert!(compressed.saturating_mul(10) < uncompressed, "{sizes:?}");
+    }
+
+    fn create_db_with_unknown_column() -> TempDir {
+        let (db, tmp_dir) = create_db();
+        db.db
+            .create_cf("col-9999", &Options::default())
+            .expect("Failed to create the unknown column");
+        drop(db);
+        tmp_dir
+    }
+
+    #[test]
+    fn open__fails_when_database_has_unknown_column() {
+        // Given
+        let tmp_dir = create_db_with_unknown_column();
+
+        // When
+        let result = RocksDb::<OnChain>::default_open(
+            tmp_dir.path(),
+            DatabaseConfig::config_for_tests(),
+        )
+        .map(|_| ());
+
+        // Then
+        let err = result.expect_err("Expected the unknown column to be rejected");
+        assert!(matches!(
+            &err,
+            DatabaseError::UnknownColumn { id: 9999, name, .. } if name == "col-9999"
+        ));
+        assert!(err.to_string().contains("created by a newer version"));
+    }
+
+    #[test]
+    fn open__ignores_unknown_column_when_allowed() {
+        // Given
+        let tmp_dir = create_db_with_unknown_column();
+        let config = DatabaseConfig {
+            allow_unknown_columns: true,
+            ..DatabaseConfig::config_for_tests()
+        };
+
+        // When
+        let mut db = RocksDb::<OnChain>::default_open(tmp_dir.path(), config)
+            .expect("Failed to open the database with the unknown column");
+        let read_only = RocksDb::<OnChain>::open_read_only(
+            tmp_dir.path(),
+            vec![Column::Coins],
+            false,
+            config,
+        )
+        .map(|_| ());
+
+        // Then
+        let key = vec![0xA, 0xB, 0xC];
+        let value = Value::from([1, 2, 3]);
+        db.put(&key, Column::Metadata, value.clone()).unwrap();
+        assert_eq!(db.get(&key, Column::Metadata).unwrap().unwrap(), value);
+        assert_eq!(Ok(()), read_only);
+    }
+
     #[test]
     fn can_put_and_read() {
         let key = vec![0xA, 0xB, 0xC];
@@ -8,12 +8,16 @@ use crate::{
 use prometheus_client::{
     encoding::EncodeLabelSet,
     metrics::{
+        counter::Counter,
         family::Family,
         gauge::Gauge,
         histogram::Histogram,
     },
 };
-use std::sync::OnceLock;
+use std::sync::{
+    atomic::AtomicU64,
+    OnceLock,
+};
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
 pub struct Label {
@@ -21,20 +25,101 @@ pub struct Label {
     path: String,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct TxCountLabel {
+    // the bucket of the number of transactions in the block
+    tx_count: &'static str,
+}
+
+impl TxCountLabel {
+    fn new(tx_count: u64) -> Self {
+        let tx_count = match tx_count {
+            0 => "0",
+            1..=10 => "1-10",
+            11..=100 => "11-100",
+            101..=1_000 => "101-1000",
+            _ => "1001+",
+        };
+        Self { tx_count }
+    }
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct PhaseLabel {
+    // the phase of the block indexation, or `total` for the whole indexation
+    phase: &'static str,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct ResolverLabel {
+    // the name of the resolver reading the storage
+    resolver: &'static str,
+}
+
 pub struct GraphqlMetrics {
     // using gauges in case blocks are rolled back for any reason
     pub total_txs_count: Gauge,
     requests: Family<Label, Histogram>,
     queries_complexity: Histogram,
+    pruned_relayed_tx_statuses: Histogram,
+    indexation_dead_letters: Counter,
+    contracts_info_cache_hits: Counter,
+    contracts_info_cache_misses: Counter,
+    balance_mismatches: Counter,
+    balance_repairs: Counter,
+    da_uncompressed_block_size: Gauge,
+    da_compressed_block_size: Gauge,
+    da_compression_ratio: Gauge<f64, AtomicU64>,
+    da_compression_registrations: Gauge,
+    off_chain_commit_duration: Family<TxCountLabel, Histogram>,
+    off_chain_block_processing_duration: Family<TxCountLabel, Histogram>,
+    off_chain_slow_commits: Counter,
+    off_chain_block_index_duration: Family<PhaseLabel, Histogram>,
+    tx_count_correction: Gauge,
+    compacted_owned_coins: Counter,
+    storage_resolver_calls: Family<ResolverLabel, Counter>,
+    storage_resolver_errors: Family<ResolverLabel, Counter>,
+    storage_resolver_duration: Family<ResolverLabel, Histogram>,
 }
 
 impl GraphqlMetrics {
     fn new() -> Self {
         let tx_count_gauge = Gauge::default();
         let queries_complexity = Histogram::new(buckets_complexity());
+        let pruned_relayed_tx_statuses = Histogram::new(buckets_pruned_entries());
+        let indexation_dead_letters = Counter::default();
+        let contracts_info_cache_hits = Counter::default();
+        let contracts_info_cache_misses = Counter::default();
+        let balance_mismatches = Counter::default();
+        let balance_repairs = Counter::default();
+        let da_uncompressed_block_size = Gauge::default();
+        let da_compressed_block_size = Gauge::default();
+        let da_compression_ratio = Gauge::default();
+        let da_compression_registrations = Gauge::default();
         let requests = Family::<Label, Histogram>::new_with_constructor(|| {
             Histogram::new(buckets(Buckets::Timing))
         });
+        let off_chain_commit_duration =
+            Family::<TxCountLabel, Histogram>::new_with_constructor(|| {
+                Histogram::new(buckets(Buckets::Timing))
+            });
+        let off_chain_block_processing_duration =
+            Family::<TxCountLabel, Histogram>::new_with_constructor(|| {
+                Histogram::new(buckets(Buckets::Timing))
+            });
+        let off_chain_slow_commits = Counter::default();
+        let off_chain_block_index_duration =
+            Family::<PhaseLabel, Histogram>::new_with_constructor(|| {
+                Histogram::new(buckets(Buckets::Timing))
+            });
+        let tx_count_correction = Gauge::default();
+        let compacted_owned_coins = Counter::default();
+        let storage_resolver_calls = Family::<ResolverLabel, Counter>::default();
+        let storage_resolver_errors = Family::<ResolverLabel, Counter>::default();
+        let storage_resolver_duration =
+            Family::<ResolverLabel, Histogram>::new_with_constructor(|| {
+                Histogram::new(buckets(Buckets::Timing))
+            });
         let mut registry = global_registry().registry.lock();
         registry.register("graphql_request_duration_seconds", "", requests.clone());
         registry.register(
@@ -49,10 +134,143 @@ impl GraphqlMetrics {
             tx_count_gauge.clone(),
         );
 
+        registry.register(
+            "graphql_pruned_relayed_tx_statuses",
+            "The number of relayed transaction statuses pruned per block",
+            pruned_relayed_tx_statuses.clone(),
+        );
+
+        registry.register(
+            "graphql_indexation_dead_letters",
+            "The number of blocks that failed to be indexed after all retries",
+            indexation_dead_letters.clone(),
+        );
+
+        registry.register(
+            "graphql_contracts_info_cache_hits",
+            "The number of contracts info reads served by the cache",
+            contracts_info_cache_hits.clone(),
+        );
+
+        registry.register(
+            "graphql_contracts_info_cache_misses",
+            "The number of contracts info reads that missed the cache",
+            contracts_info_cache_misses.clone(),
+        );
+
+        registry.register(
+            "graphql_balance_mismatches",
+            "The number of cached balances that differ from the recomputed ones",
+            balance_mismatches.clone(),
+        );
+
+        registry.register(
+            "graphql_balance_repairs",
+            "The number of cached balances overwritten with the recomputed ones",
+            balance_repairs.clone(),
+        );
+
+        registry.register(
+            "graphql_da_uncompressed_block_size_bytes",
+            "The size of the last DA compressed block before the compression",
+            da_uncompressed_block_size.clone(),
+        );
+
+        registry.register(
+            "graphql_da_compressed_block_size_bytes",
+            "The size of the last DA compressed block after the compression",
+            da_compressed_block_size.clone(),
+        );
+
+        registry.register(
+            "graphql_da_compression_ratio",
+            "The ratio of the compressed to the uncompressed size of the last DA compressed block",
+            da_compression_ratio.clone(),
+        );
+
+        registry.register(
+            "graphql_da_compression_registrations",
+            "The number of new registry registrations made by the last DA compressed block",
+            da_compression_registrations.clone(),
+        );
+
+        registry.register(
+            "graphql_off_chain_commit_duration_seconds",
+            "The duration of the commit of the block indexes into the off-chain database",
+            off_chain_commit_duration.clone(),
+        );
+
+        registry.register(
+            "graphql_off_chain_block_processing_duration_seconds",
+            "The duration of the derivation of the block indexes before the commit",
+            off_chain_block_processing_duration.clone(),
+        );
+
+        registry.register(
+            "graphql_off_chain_slow_commits",
+            "The number of off-chain commits that exceeded the slow commit threshold",
+            off_chain_slow_commits.clone(),
+        );
+
+        registry.register(
+            "offchain_block_index_seconds",
+            "The duration of the indexation and the commit of the block into the off-chain database, by phase",
+            off_chain_block_index_duration.clone(),
+        );
+
+        registry.register(
+            "graphql_tx_count_correction",
+            "The magnitude of the correction of the total transaction count made by the startup reconciliation",
+            tx_count_correction.clone(),
+        );
+
+        registry.register(
+            "graphql_compacted_owned_coins",
+            "The number of spent coins removed from the owned coins by the compaction",
+            compacted_owned_coins.clone(),
+        );
+
+        registry.register(
+            "graphql_storage_resolver_calls",
+            "The number of calls of the resolvers reading the storage",
+            storage_resolver_calls.clone(),
+        );
+
+        registry.register(
+            "graphql_storage_resolver_errors",
+            "The number of calls of the resolvers reading the storage that failed",
+            storage_resolver_errors.clone(),
+        );
+
+        registry.register(
+            "graphql_storage_resolver_duration_seconds",
+            "The duration of the resolvers reading the storage",
+            storage_resolver_duration.clone(),
+        );
+
         Self {
             total_txs_count: tx_count_gauge,
             queries_complexity,
             requests,
+            pruned_relayed_tx_statuses,
+            indexation_dead_letters,
+            contracts_info_cache_hits,
+            contracts_info_cache_misses,
+            balance_mismatches,
+            balance_repairs,
+            da_uncompressed_block_size,
+            da_compressed_block_size,
+            da_compression_ratio,
+            da_compression_registrations,
+            off_chain_commit_duration,
+            off_chain_block_processing_duration,
+            off_chain_slow_commits,
+            off_chain_block_index_duration,
+            tx_count_correction,
+            compacted_owned_coins,
+            storage_resolver_calls,
+            storage_resolver_errors,
+            storage_resolver_duration,
         }
     }
 
@@ -66,6 +284,93 @@ impl GraphqlMetrics {
     pub fn graphql_complexity_observe(&self, complexity: f64) {
         self.queries_complexity.observe(complexity);
     }
+
+    pub fn observe_pruned_relayed_tx_statuses(&self, pruned: u64) {
+        self.pruned_relayed_tx_statuses.observe(pruned as f64);
+    }
+
+    pub fn record_indexation_dead_letter(&self) {
+        self.indexation_dead_letters.inc();
+    }
+
+    pub fn record_contracts_info_cache_hit(&self) {
+        self.contracts_info_cache_hits.inc();
+    }
+
+    pub fn record_contracts_info_cache_miss(&self) {
+        self.contracts_info_cache_misses.inc();
+    }
+
+    pub fn record_balance_mismatch(&self) {
+        self.balance_mismatches.inc();
+    }
+
+    pub fn record_balance_repair(&self) {
+        self.balance_repairs.inc();
+    }
+
+    pub fn record_da_compression(
+        &self,
+        uncompressed_size: u64,
+        compressed_size: u64,
+        ratio: f64,
+        registrations: u64,
+    ) {
+        self.da_uncompressed_block_size
+            .set(i64::try_from(uncompressed_size).unwrap_or(i64::MAX));
+        self.da_compressed_block_size
+            .set(i64::try_from(compressed_size).unwrap_or(i64::MAX));
+        self.da_compression_ratio.set(ratio);
+        self.da_compression_registrations
+            .set(i64::try_from(registrations).unwrap_or(i64::MAX));
+    }
+
+    pub fn observe_off_chain_commit(&self, tx_count: u64, seconds: f64) {
+        self.off_chain_commit_duration
+            .get_or_create(&TxCountLabel::new(tx_count))
+            .observe(seconds);
+    }
+
+    pub fn observe_off_chain_block_processing(&self, tx_count: u64, seconds: f64) {
+        self.off_chain_block_processing_duration
+            .get_or_create(&TxCountLabel::new(tx_count))
+            .observe(seconds);
+    }
+
+    pub fn record_off_chain_slow_commit(&self) {
+        self.off_chain_slow_commits.inc();
+    }
+
+    pub fn observe_off_chain_block_index(&self, phase: &'static str, seconds: f64) {
+        self.off_chain_block_index_duration
+            .get_or_create(&PhaseLabel { phase })
+            .observe(seconds);
+    }
+
+    pub fn record_tx_count_correction(&self, correction: u64) {
+        self.tx_count_correction
+            .set(i64::try_from(correction).unwrap_or(i64::MAX));
+    }
+
+    pub fn record_compacted_owned_coins(&self, removed: u64) {
+        self.compacted_owned_coins.inc_by(removed);
+    }
+
+    pub fn observe_storage_resolver(
+        &self,
+        resolver: &'static str,
+        seconds: f64,
+        failed: bool,
+    ) {
+        let label = ResolverLabel { resolver };
+        self.storage_resolver_calls.get_or_create(&label).inc();
+        if failed {
+            self.storage_resolver_errors.get_or_create(&label).inc();
+        }
+        self.storage_resolver_duration
+            .get_or_create(&label)
+            .observe(seconds);
+    }
 }
 
 static GRAPHQL_METRICS: OnceLock<GraphqlMetrics> = OnceLock::new();
@@ -89,3 +394,7 @@ fn buckets_complexity() -> impl Iterator<Item = f64> {
     ]
     .into_iter()
 }
+
+fn buckets_pruned_entries() -> impl Iterator<Item = f64> {
+    [0.0, 1.0, 5.0, 10.0, 50.0, 100.0, 500.0, 1_000.0].into_iter()
+}
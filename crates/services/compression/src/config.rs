@@ -24,6 +24,7 @@ impl From<CompressionConfig> for fuel_core_compression::Config {
     fn from(config: CompressionConfig) -> Self {
         Self {
             temporal_registry_retention: config.temporal_registry_retention(),
+            registry_capacity: None,
         }
     }
 }
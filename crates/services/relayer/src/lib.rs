@@ -34,6 +34,7 @@ pub use service::{
     new_service,
     Service,
     SharedState,
+    SyncState,
 };
 
 #[cfg(test)]
@@ -324,6 +324,11 @@ impl SharedState {
         Ok(())
     }
 
+    /// Get the current state of the synchronization with the DA layer.
+    pub fn sync_state(&self) -> SyncState {
+        *self.synced.borrow()
+    }
+
     /// Get finalized da height that represents last block from da layer that got finalized.
     /// Panics if height is not set as of initialization of the relayer.
     pub fn get_finalized_da_height(&self) -> DaBlockHeight {
@@ -14,7 +14,10 @@ use fuel_core_types::{
         Bytes32,
         TxId,
     },
-    services::txpool::TransactionStatus,
+    services::txpool::{
+        RejectionReason,
+        TransactionStatus,
+    },
 };
 
 use crate::{
@@ -211,7 +214,15 @@ impl TxStatusManager {
     pub fn notify_skipped_txs(&mut self, tx_ids_and_reason: Vec<(Bytes32, String)>) {
         tx_ids_and_reason.into_iter().for_each(|(tx_id, reason)| {
             let error = Error::SkippedTransaction(reason);
-            self.status_update(tx_id, TransactionStatus::squeezed_out(error.to_string()));
+            self.status_update(
+                tx_id,
+                TransactionStatus::squeezed_out(
+                    error.to_string(),
+                    RejectionReason::Validity {
+                        code: RejectionReason::SKIPPED_CODE,
+                    },
+                ),
+            );
         });
     }
 }
@@ -232,7 +243,10 @@ mod tests {
             SeedableRng,
         },
         fuel_tx::Bytes32,
-        services::txpool::TransactionStatus,
+        services::txpool::{
+            RejectionReason,
+            TransactionStatus,
+        },
         tai64::Tai64,
     };
 
@@ -253,7 +267,7 @@ mod tests {
     }
 
     fn squeezed_out() -> TransactionStatus {
-        TransactionStatus::squeezed_out("fishy tx".to_string())
+        TransactionStatus::squeezed_out("fishy tx".to_string(), Default::default())
     }
 
     fn preconfirmation_squeezed_out() -> TransactionStatus {
@@ -344,6 +358,30 @@ mod tests {
         TxStatusManager::is_prunable(&status)
     }
 
+    #[tokio::test]
+    async fn notify_skipped_txs__squeezes_out_with_skipped_validity_reason() {
+        // Given
+        let tx_status_change = TxStatusChange::new(100, Duration::from_secs(360));
+        let mut tx_status_manager = TxStatusManager::new(tx_status_change, TTL, false);
+        let tx_id: Bytes32 = [1u8; 32].into();
+
+        // When
+        tx_status_manager.notify_skipped_txs(vec![(tx_id, "Not enough gas".to_string())]);
+
+        // Then
+        let Some(TransactionStatus::SqueezedOut(status)) =
+            tx_status_manager.status(&tx_id)
+        else {
+            panic!("Expected the transaction to be squeezed out")
+        };
+        assert_eq!(
+            status.rejection_reason,
+            RejectionReason::Validity {
+                code: RejectionReason::SKIPPED_CODE
+            }
+        );
+    }
+
     mod equal_ids {
         use std::time::Duration;
 
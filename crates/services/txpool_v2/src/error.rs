@@ -9,6 +9,7 @@ use fuel_core_types::{
     },
     fuel_types::Nonce,
     fuel_vm::checked_transaction::CheckError,
+    services::txpool::RejectionReason,
 };
 
 use crate::{
@@ -44,6 +45,8 @@ pub enum Error {
     Removed(RemovedReason),
     #[display(fmt = "Transaction has been skipped during block insertion: {_0}")]
     SkippedTransaction(String),
+    #[display(fmt = "Transaction has been skipped during block insertion: {_0}")]
+    SkippedDependentTransaction(String),
     #[display(fmt = "Too much transactions are in queue to be inserted. Can't add more")]
     TooManyQueuedTransactions,
     #[display(fmt = "Unable send a request because service is closed")]
@@ -61,6 +64,54 @@ pub enum Error {
     },
 }
 
+impl Error {
+    /// Returns the structured reason of the rejection caused by the error.
+    pub fn rejection_reason(&self) -> RejectionReason {
+        match self {
+            Error::InsufficientMaxFee { .. }
+            | Error::Removed(RemovedReason::LessWorth(_)) => {
+                RejectionReason::InsufficientFee
+            }
+            Error::SkippedDependentTransaction(_) => {
+                RejectionReason::DependentTxSqueezedOut
+            }
+            Error::Collided(_)
+            | Error::InputValidation(InputValidationError::UtxoNotFound(_)) => {
+                RejectionReason::InputSpent
+            }
+            Error::Removed(RemovedReason::Ttl) => RejectionReason::TtlExpired,
+            Error::NotInsertedLimitHit
+            | Error::TooManyQueuedTransactions
+            | Error::ServiceQueueFull => RejectionReason::PoolFull,
+            Error::ConsensusValidity(_) => RejectionReason::Validity {
+                code: RejectionReason::CONSENSUS_VALIDITY_CODE,
+            },
+            Error::WasmValidity(_) => RejectionReason::Validity {
+                code: RejectionReason::WASM_VALIDITY_CODE,
+            },
+            Error::InputValidation(_) => RejectionReason::Validity {
+                code: RejectionReason::INPUT_VALIDATION_CODE,
+            },
+            Error::Blacklisted(_) => RejectionReason::Validity {
+                code: RejectionReason::BLACKLISTED_CODE,
+            },
+            Error::MintIsDisallowed => RejectionReason::Validity {
+                code: RejectionReason::MINT_CODE,
+            },
+            Error::Dependency(_) => RejectionReason::Validity {
+                code: RejectionReason::DEPENDENCY_CODE,
+            },
+            Error::SkippedTransaction(_) => RejectionReason::Validity {
+                code: RejectionReason::SKIPPED_CODE,
+            },
+            Error::GasPriceNotFound(_)
+            | Error::Database(_)
+            | Error::Storage(_)
+            | Error::ServiceCommunicationFailed => RejectionReason::Unknown,
+        }
+    }
+}
+
 #[derive(Clone, Debug, derive_more::Display)]
 pub enum RemovedReason {
     #[display(
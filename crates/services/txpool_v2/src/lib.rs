@@ -63,9 +63,13 @@ fuel_core_trace::enable_tracing!();
 
 use fuel_core_types::fuel_asm::Word;
 pub use pool::TxPoolStats;
-pub use selection_algorithms::Constraints;
+pub use selection_algorithms::{
+    Constraints,
+    PendingTransactionsOrder,
+};
 pub use service::{
     new_service,
     Service,
+    TxInfo,
 };
 pub use shared_state::SharedState;
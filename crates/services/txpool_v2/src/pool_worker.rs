@@ -221,6 +221,9 @@ pub(super) enum PoolReadRequest {
         max_txs: usize,
         response_channel: oneshot::Sender<Vec<TxId>>,
     },
+    AllTxs {
+        response_channel: oneshot::Sender<Vec<TxInfo>>,
+    },
 }
 
 #[allow(clippy::upper_case_acronyms)]
@@ -318,6 +321,9 @@ where
                         PoolReadRequest::TxIds { max_txs, response_channel } => {
                             self.get_tx_ids(max_txs, response_channel);
                         }
+                        PoolReadRequest::AllTxs { response_channel } => {
+                            self.get_all_txs(response_channel);
+                        }
                         PoolReadRequest::Txs { tx_ids, response_channel } => {
                             self.get_txs(tx_ids, response_channel);
                         }
@@ -506,7 +512,7 @@ where
                 let tx_id = tx.id();
                 if let Err(e) = self.notification_sender.try_send(PoolNotification::Removed {
                     tx_id,
-                    error: Error::SkippedTransaction(
+                    error: Error::SkippedDependentTransaction(
                         format!("Parent transaction with id: {tx_id}, was removed because of: {reason}")
                     )
                 }) {
@@ -559,6 +565,21 @@ where
         }
     }
 
+    fn get_all_txs(&mut self, txs_sender: oneshot::Sender<Vec<TxInfo>>) {
+        let txs: Vec<TxInfo> = self
+            .pool
+            .iter_tx_ids()
+            .filter_map(|tx_id| self.pool.get(tx_id))
+            .map(|tx| TxInfo {
+                tx: tx.transaction.clone(),
+                creation_instant: tx.creation_instant,
+            })
+            .collect();
+        if txs_sender.send(txs).is_err() {
+            tracing::error!("Failed to send all txs from PoolWorker");
+        }
+    }
+
     fn get_non_existing_txs(
         &mut self,
         tx_ids: Vec<TxId>,
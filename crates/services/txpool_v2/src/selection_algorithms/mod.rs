@@ -1,6 +1,11 @@
-use crate::storage::{
-    RemovedTransactions,
-    StorageData,
+use std::cmp::Reverse;
+
+use crate::{
+    service::TxInfo,
+    storage::{
+        RemovedTransactions,
+        StorageData,
+    },
 };
 
 pub mod ratio_tip_gas;
@@ -17,6 +22,41 @@ pub struct Constraints {
     pub maximum_block_size: u32,
 }
 
+/// The order of the snapshot of the transactions in the pool.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PendingTransactionsOrder {
+    /// By the tip/gas ratio, the way the block producer selects the transactions.
+    #[default]
+    Ratio,
+    /// By the tip, the highest first.
+    Tip,
+    /// By the insertion time, the oldest first.
+    Time,
+}
+
+impl PendingTransactionsOrder {
+    /// Sorts the transactions in the order. The ties are resolved by the insertion
+    /// time and then by the transaction id, so the order is deterministic.
+    pub(crate) fn sort(self, txs: &mut [TxInfo]) {
+        match self {
+            PendingTransactionsOrder::Ratio => txs.sort_by_cached_key(|info| {
+                let tx = info.tx();
+                let ratio = ratio_tip_gas::RatioTipGas::new(
+                    tx.tip().saturating_add(1),
+                    tx.max_gas(),
+                );
+                (Reverse(ratio), info.creation_instant, Reverse(tx.id()))
+            }),
+            PendingTransactionsOrder::Tip => txs.sort_by_cached_key(|info| {
+                (Reverse(info.tx.tip()), info.creation_instant, info.tx.id())
+            }),
+            PendingTransactionsOrder::Time => {
+                txs.sort_by_cached_key(|info| (info.creation_instant, info.tx.id()))
+            }
+        }
+    }
+}
+
 /// The selection algorithm is responsible for selecting the best transactions to include in a block.
 pub trait SelectionAlgorithm {
     /// The storage type of the selection algorithm.
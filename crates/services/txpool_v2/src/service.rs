@@ -439,13 +439,19 @@ where
 
                 self.tx_status_manager.status_update(
                     tx_id,
-                    TransactionStatus::squeezed_out(error.to_string()),
+                    TransactionStatus::squeezed_out(
+                        error.to_string(),
+                        error.rejection_reason(),
+                    ),
                 );
             }
             PoolNotification::Removed { tx_id, error } => {
                 self.tx_status_manager.status_update(
                     tx_id,
-                    TransactionStatus::squeezed_out(error.to_string()),
+                    TransactionStatus::squeezed_out(
+                        error.to_string(),
+                        error.rejection_reason(),
+                    ),
                 );
             }
         }
@@ -531,7 +537,10 @@ where
 
                     tx_status_manager.status_update(
                         tx_id,
-                        TransactionStatus::squeezed_out(err.to_string()),
+                        TransactionStatus::squeezed_out(
+                            err.to_string(),
+                            err.rejection_reason(),
+                        ),
                     );
                     return
                 }
@@ -25,6 +25,7 @@ use crate::{
         PoolReadRequest,
         PoolRemoveRequest,
     },
+    selection_algorithms::PendingTransactionsOrder,
     service::{
         TxInfo,
         WritePoolRequest,
@@ -140,6 +141,26 @@ impl SharedState {
             .map_err(|_| Error::ServiceCommunicationFailed)
     }
 
+    /// Returns the snapshot of all transactions in the pool sorted in the `order`.
+    /// The pool only clones the transactions, the sorting happens outside of it.
+    pub async fn ordered_snapshot(
+        &self,
+        order: PendingTransactionsOrder,
+    ) -> Result<Vec<TxInfo>, Error> {
+        let (response_channel, result_receiver) = oneshot::channel();
+
+        self.request_read_sender
+            .send(PoolReadRequest::AllTxs { response_channel })
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)?;
+
+        let mut txs = result_receiver
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)?;
+        order.sort(&mut txs);
+        Ok(txs)
+    }
+
     /// Get a notifier that is notified when new executable transactions are added to the pool.
     pub fn get_new_executable_txs_notifier(&self) -> watch::Receiver<()> {
         self.new_executable_txs_notifier.subscribe()
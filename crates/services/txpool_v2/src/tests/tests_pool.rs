@@ -61,6 +61,7 @@ use fuel_core_types::{
         },
         PredicateVerificationFailed,
     },
+    services::txpool::RejectionReason,
 };
 
 #[test]
@@ -98,6 +99,12 @@ fn insert__tx_with_blacklisted_utxo_id() {
     assert!(
         matches!(err, Error::Blacklisted(BlacklistedError::BlacklistedUTXO(id)) if id == utxo_id)
     );
+    assert_eq!(
+        err.rejection_reason(),
+        RejectionReason::Validity {
+            code: RejectionReason::BLACKLISTED_CODE
+        }
+    );
     universe.assert_pool_integrity(&[]);
 }
 
@@ -324,6 +331,7 @@ fn insert__unknown_utxo_returns_error() {
     assert!(
         matches!(err, Error::InputValidation(InputValidationError::UtxoNotFound(id)) if id == utxo_id)
     );
+    assert_eq!(err.rejection_reason(), RejectionReason::InputSpent);
     universe.assert_pool_integrity(&[]);
 }
 
@@ -370,6 +378,7 @@ fn insert__colliding_dependent_and_underpriced_returns_error() {
     // Then
     let err = result3.unwrap_err();
     assert!(matches!(err, Error::Collided(CollisionReason::Utxo(id)) if id == utxo_id));
+    assert_eq!(err.rejection_reason(), RejectionReason::InputSpent);
     universe.assert_pool_integrity(&[tx1, tx2]);
 }
 
@@ -510,6 +519,7 @@ fn insert__tx_limit_hit() {
     // Then
     let err = result2.unwrap_err();
     assert!(matches!(err, Error::NotInsertedLimitHit));
+    assert_eq!(err.rejection_reason(), RejectionReason::PoolFull);
     universe.assert_pool_integrity(&[pool_tx]);
 }
 
@@ -613,6 +623,12 @@ fn insert__dependency_chain_length_hit() {
         err,
         Error::Dependency(DependencyError::NotInsertedChainDependencyTooBig)
     ));
+    assert_eq!(
+        err.rejection_reason(),
+        RejectionReason::Validity {
+            code: RejectionReason::DEPENDENCY_CODE
+        }
+    );
     universe.assert_pool_integrity(&[tx1, tx2]);
 }
 
@@ -905,6 +921,7 @@ fn insert__tx_below_min_gas_price() {
 
     // Then
     assert!(matches!(err, Error::InsufficientMaxFee { .. }));
+    assert_eq!(err.rejection_reason(), RejectionReason::InsufficientFee);
     universe.assert_pool_integrity(&[]);
 }
 
@@ -1078,6 +1095,12 @@ fn insert__tx_with_predicates_incorrect_owner() {
             ValidityError::InputPredicateOwner { index: 0 }
         ))
     ));
+    assert_eq!(
+        err.rejection_reason(),
+        RejectionReason::Validity {
+            code: RejectionReason::CONSENSUS_VALIDITY_CODE
+        }
+    );
     universe.assert_pool_integrity(&[]);
 }
 
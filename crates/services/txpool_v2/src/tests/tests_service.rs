@@ -5,13 +5,18 @@ use fuel_core_types::{
         consensus::Sealed,
     },
     fuel_tx::{
+        Finalizable,
+        TransactionBuilder,
         UniqueIdentifier,
         UtxoId,
     },
     fuel_types::ChainId,
     services::{
         block_importer::ImportResult,
-        txpool::TransactionStatus,
+        txpool::{
+            RejectionReason,
+            TransactionStatus,
+        },
     },
 };
 use std::{
@@ -26,9 +31,11 @@ use crate::{
         universe::{
             TestPoolUniverse,
             DEFAULT_EXPIRATION_HEIGHT,
+            GAS_LIMIT,
         },
     },
     Constraints,
+    PendingTransactionsOrder,
 };
 
 #[tokio::test]
@@ -464,7 +471,8 @@ async fn simple_insert_removal() {
         .await_expected_tx_statuses(ids, |status| {
             matches!(status, TransactionStatus::SqueezedOut(s)
                     if s.reason == "Transaction is removed: Transaction expired \
-                    because it exceeded the configured time to live `tx-pool-ttl`.")
+                    because it exceeded the configured time to live `tx-pool-ttl`."
+                    && s.rejection_reason == RejectionReason::TtlExpired)
         })
         .await
         .unwrap();
@@ -564,10 +572,134 @@ async fn pending_pool__returns_error_after_timeout_for_transaction_that_spends_a
     universe
         .await_expected_tx_statuses(ids, |status| {
             matches!(status, TransactionStatus::SqueezedOut(s)
-                    if s.reason == "Transaction input validation failed: UTXO (id: cd590cc7b217fad36bc7e48743d5164cee0415acdcbd4cfa90f464e8c77a57b30000) does not exist")
+                    if s.reason == "Transaction input validation failed: UTXO (id: cd590cc7b217fad36bc7e48743d5164cee0415acdcbd4cfa90f464e8c77a57b30000) does not exist"
+                    && s.rejection_reason == RejectionReason::InputSpent)
+        })
+        .await
+        .unwrap();
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn insert__more_worth_tx_squeezes_out_less_worth_tx_with_insufficient_fee() {
+    // Given
+    let mut universe = TestPoolUniverse::default();
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    let common_coin = universe.setup_coin().1;
+    let tx1 =
+        universe.build_script_transaction(Some(vec![common_coin.clone()]), None, 10);
+    let tx2 = universe.build_script_transaction(Some(vec![common_coin]), None, 20);
+    service.shared.insert(tx1.clone()).await.unwrap();
+
+    // When
+    service.shared.insert(tx2).await.unwrap();
+
+    // Then
+    universe
+        .await_expected_tx_statuses(vec![tx1.id(&Default::default())], |status| {
+            matches!(status, TransactionStatus::SqueezedOut(s)
+                if s.rejection_reason == RejectionReason::InsufficientFee)
         })
         .await
         .unwrap();
 
     service.stop_and_await().await.unwrap();
 }
+
+#[tokio::test]
+async fn notify_skipped_txs__dependent_tx_squeezed_out_with_dependent_reason() {
+    // Given
+    let mut universe = TestPoolUniverse::default();
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    let (output, unset_input) = universe.create_output_and_input();
+    let tx1 = universe.build_script_transaction(None, Some(vec![output]), 1);
+    let tx1_id = tx1.id(&Default::default());
+    let input = unset_input.into_input(UtxoId::new(tx1_id, 0));
+    let tx2 = universe.build_script_transaction(Some(vec![input]), None, 20);
+    let tx2_id = tx2.id(&Default::default());
+
+    service.shared.insert(tx1).await.unwrap();
+    service
+        .shared
+        .extract_transactions_for_block(Constraints {
+            minimal_gas_price: 0,
+            max_gas: u64::MAX,
+            maximum_txs: u16::MAX,
+            maximum_block_size: u32::MAX,
+        })
+        .unwrap();
+    service.shared.insert(tx2).await.unwrap();
+
+    // When
+    service
+        .shared
+        .notify_skipped_txs(vec![(tx1_id, "Not enough gas".to_string())]);
+
+    // Then
+    universe
+        .await_expected_tx_statuses(vec![tx2_id], |status| {
+            matches!(status, TransactionStatus::SqueezedOut(s)
+                if s.rejection_reason == RejectionReason::DependentTxSqueezedOut)
+        })
+        .await
+        .unwrap();
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn ordered_snapshot__returns_txs_in_requested_order() {
+    // Given
+    let mut universe = TestPoolUniverse::default();
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    let cheap = universe.build_script_transaction(None, None, 10);
+    // The highest tip, but the lowest tip/gas ratio because of the gas limit.
+    let (_, gas_coin) = universe.setup_coin();
+    let heavy = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT.saturating_mul(10))
+        .add_input(gas_coin)
+        .tip(30)
+        .max_fee_limit(10000)
+        .expiration(DEFAULT_EXPIRATION_HEIGHT)
+        .finalize_as_transaction();
+    let middle = universe.build_script_transaction(None, None, 20);
+    let cheap_id = cheap.id(&Default::default());
+    let heavy_id = heavy.id(&Default::default());
+    let middle_id = middle.id(&Default::default());
+
+    service.shared.insert(cheap).await.unwrap();
+    service.shared.insert(heavy).await.unwrap();
+    service.shared.insert(middle).await.unwrap();
+
+    let ids = |order: PendingTransactionsOrder| {
+        let shared = service.shared.clone();
+        async move {
+            shared
+                .ordered_snapshot(order)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|info| info.tx().id())
+                .collect::<Vec<_>>()
+        }
+    };
+
+    // When
+    let by_ratio = ids(PendingTransactionsOrder::Ratio).await;
+    let by_tip = ids(PendingTransactionsOrder::Tip).await;
+    let by_time = ids(PendingTransactionsOrder::Time).await;
+
+    // Then
+    assert_eq!(by_ratio, vec![middle_id, cheap_id, heavy_id]);
+    assert_eq!(by_tip, vec![heavy_id, middle_id, cheap_id]);
+    assert_eq!(by_time, vec![cheap_id, heavy_id, middle_id]);
+
+    service.stop_and_await().await.unwrap();
+}
@@ -14,6 +14,8 @@ use crate::{
     },
     structured_storage::TableWithBlueprint,
     transactional::ReferenceBytesKey,
+    Error as StorageError,
+    Result as StorageResult,
 };
 use fuel_vm_private::fuel_storage::Mappable;
 
@@ -21,6 +23,7 @@ use fuel_vm_private::fuel_storage::Mappable;
 use alloc::{
     boxed::Box,
     collections::BTreeMap,
+    string::String,
     vec::Vec,
 };
 
@@ -57,6 +60,69 @@ where
     }
 }
 
+/// The iterator that examines at most `limit` entries of the underlying iterator.
+/// When more entries remain, it yields [`StorageError::ScanBudgetExceeded`]
+/// with the cursor of the last examined entry and stops.
+pub struct ScanBudgetIter<I, T, F> {
+    iter: I,
+    limit: usize,
+    remaining: usize,
+    last_entry: Option<T>,
+    cursor: F,
+    exceeded: bool,
+}
+
+impl<I, T, F> Iterator for ScanBudgetIter<I, T, F>
+where
+    I: Iterator<Item = StorageResult<T>>,
+    T: Clone,
+    F: Fn(&T) -> String,
+{
+    type Item = StorageResult<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exceeded {
+            return None
+        }
+
+        let item = self.iter.next()?;
+        if self.remaining == 0 {
+            self.exceeded = true;
+            return Some(Err(StorageError::ScanBudgetExceeded {
+                limit: self.limit,
+                cursor: self.last_entry.as_ref().map(&self.cursor),
+            }))
+        }
+        self.remaining = self.remaining.saturating_sub(1);
+
+        if let Ok(entry) = &item {
+            self.last_entry = Some(entry.clone());
+        }
+        Some(item)
+    }
+}
+
+/// The trait limits the number of entries examined by the storage iterator.
+pub trait IntoScanBudgetIter<T>: Iterator<Item = StorageResult<T>> + Sized {
+    /// Converts `Self` iterator into [`ScanBudgetIter`] examining at most `limit` entries.
+    /// The `cursor` encodes the entry from which the scan can be continued.
+    fn with_scan_budget<F>(self, limit: usize, cursor: F) -> ScanBudgetIter<Self, T, F>
+    where
+        F: Fn(&T) -> String,
+    {
+        ScanBudgetIter {
+            iter: self,
+            limit,
+            remaining: limit,
+            last_entry: None,
+            cursor,
+            exceeded: false,
+        }
+    }
+}
+
+impl<I, T> IntoScanBudgetIter<T> for I where I: Iterator<Item = StorageResult<T>> {}
+
 /// A enum for iterating across the database
 #[derive(Copy, Clone, Debug, PartialOrd, Eq, PartialEq)]
 pub enum IterDirection {
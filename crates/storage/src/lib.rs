@@ -21,7 +21,10 @@ use fuel_core_types::services::executor::Error as ExecutorError;
 #[cfg(feature = "alloc")]
 use alloc::{
     boxed::Box,
-    string::ToString,
+    string::{
+        String,
+        ToString,
+    },
 };
 
 pub use fuel_vm_private::{
@@ -74,6 +77,14 @@ pub enum Error {
     /// This error should be created with `not_found` macro.
     #[display(fmt = "resource was not found in table `{_0}` at the: {_1}")]
     NotFound(&'static str, &'static str),
+    /// The iteration examined more entries than allowed by the scan budget.
+    #[display(fmt = "the scan budget of {limit} entries is exceeded")]
+    ScanBudgetExceeded {
+        /// The maximum number of entries allowed to be examined.
+        limit: usize,
+        /// The cursor of the last examined entry, from which the scan can be continued.
+        cursor: Option<String>,
+    },
     // TODO: Do we need this type at all?
     /// Unknown or not expected(by architecture) error.
     #[from]
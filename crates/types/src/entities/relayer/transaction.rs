@@ -167,3 +167,12 @@ pub enum RelayedTransactionStatus {
         failure: String,
     },
 }
+
+impl RelayedTransactionStatus {
+    /// The height of the block that processed the transaction
+    pub fn block_height(&self) -> BlockHeight {
+        match self {
+            RelayedTransactionStatus::Failed { block_height, .. } => *block_height,
+        }
+    }
+}
@@ -355,7 +355,9 @@ pub enum TransactionExecutionStatus {
         /// The total fee paid by the transaction.
         total_fee: u64,
     },
-    /// Transaction was squeezed of the txpool
+    /// Transaction was squeezed of the txpool.
+    /// Stored before the rejection reasons were tracked, decodes with
+    /// [`RejectionReason::Unknown`].
     SqueezedOut {
         /// Why this happened
         reason: String,
@@ -375,6 +377,74 @@ pub enum TransactionExecutionStatus {
         /// The total fee paid by the transaction.
         total_fee: u64,
     },
+    /// Transaction was rejected by the txpool.
+    // Appended after the other variants to keep decoding of the stored statuses.
+    Rejected {
+        /// Why this happened
+        reason: String,
+        /// The structured reason of the rejection
+        rejection_reason: RejectionReason,
+    },
+}
+
+impl TransactionExecutionStatus {
+    /// Returns the structured reason of the rejection,
+    /// if the transaction was rejected by the txpool.
+    pub fn rejection_reason(&self) -> Option<RejectionReason> {
+        match self {
+            TransactionExecutionStatus::SqueezedOut { .. } => {
+                Some(RejectionReason::Unknown)
+            }
+            TransactionExecutionStatus::Rejected {
+                rejection_reason, ..
+            } => Some(*rejection_reason),
+            TransactionExecutionStatus::Submitted { .. }
+            | TransactionExecutionStatus::Success { .. }
+            | TransactionExecutionStatus::Failed { .. } => None,
+        }
+    }
+}
+
+/// The structured reason why the txpool rejected the transaction.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RejectionReason {
+    /// The reason is not known, e.g. the status was produced before
+    /// the reasons were tracked.
+    #[default]
+    Unknown,
+    /// The fee of the transaction doesn't cover the minimal gas price.
+    InsufficientFee,
+    /// The transaction depends on a transaction that was squeezed out.
+    DependentTxSqueezedOut,
+    /// An input of the transaction is spent or used by a more worth transaction.
+    InputSpent,
+    /// The transaction stayed in the txpool longer than its time to live.
+    TtlExpired,
+    /// The txpool has no space for the transaction.
+    PoolFull,
+    /// The transaction failed a validity check identified by the `code`.
+    Validity {
+        /// One of the `RejectionReason::*_CODE` constants.
+        code: u16,
+    },
+}
+
+impl RejectionReason {
+    /// The transaction failed the consensus checks.
+    pub const CONSENSUS_VALIDITY_CODE: u16 = 1;
+    /// The transaction failed the validation of the WASM bytecode.
+    pub const WASM_VALIDITY_CODE: u16 = 2;
+    /// The inputs of the transaction don't match the state.
+    pub const INPUT_VALIDATION_CODE: u16 = 3;
+    /// The transaction uses a blacklisted UTXO, owner, contract or message.
+    pub const BLACKLISTED_CODE: u16 = 4;
+    /// The transaction is a `Mint`.
+    pub const MINT_CODE: u16 = 5;
+    /// The transaction doesn't fit into the dependency graph of the txpool.
+    pub const DEPENDENCY_CODE: u16 = 6;
+    /// The block producer skipped the transaction during the execution.
+    pub const SKIPPED_CODE: u16 = 7;
 }
 
 impl From<TransactionExecutionStatus> for TransactionStatus {
@@ -406,8 +476,12 @@ impl From<TransactionExecutionStatus> for TransactionStatus {
             // TODO: Removed this variant as part of the
             //  https://github.com/FuelLabs/fuel-core/issues/2794
             TransactionExecutionStatus::SqueezedOut { reason } => {
-                TransactionStatus::SqueezedOut(statuses::SqueezedOut { reason }.into())
+                TransactionStatus::squeezed_out(reason, RejectionReason::Unknown)
             }
+            TransactionExecutionStatus::Rejected {
+                reason,
+                rejection_reason,
+            } => TransactionStatus::squeezed_out(reason, rejection_reason),
             TransactionExecutionStatus::Failed {
                 block_height,
                 time,
@@ -476,8 +550,14 @@ impl TransactionStatus {
     }
 
     /// Creates a new `TransactionStatus::SqueezedOut` variant.
-    pub fn squeezed_out(reason: String) -> Self {
-        Self::SqueezedOut(statuses::SqueezedOut { reason }.into())
+    pub fn squeezed_out(reason: String, rejection_reason: RejectionReason) -> Self {
+        Self::SqueezedOut(
+            statuses::SqueezedOut {
+                reason,
+                rejection_reason,
+            }
+            .into(),
+        )
     }
 
     /// Creates a new `TransactionStatus::PreConfirmationSqueezedOut` variant.
@@ -609,12 +689,15 @@ pub mod statuses {
     pub struct SqueezedOut {
         /// The reason why the transaction was squeezed out
         pub reason: String,
+        /// The structured reason why the transaction was squeezed out
+        pub rejection_reason: RejectionReason,
     }
 
     impl Default for SqueezedOut {
         fn default() -> Self {
             Self {
                 reason: "Default reason".to_string(),
+                rejection_reason: RejectionReason::Unknown,
             }
         }
     }
@@ -623,6 +706,10 @@ pub mod statuses {
         fn from(value: &PreConfirmationSqueezedOut) -> Self {
             Self {
                 reason: value.reason.clone(),
+                // The block producer squeezes out the transactions that it skipped.
+                rejection_reason: RejectionReason::Validity {
+                    code: RejectionReason::SKIPPED_CODE,
+                },
             }
         }
     }
@@ -1,7 +1,20 @@
+#![allow(non_snake_case)]
+
 use fuel_core::service::Config;
 use fuel_core_bin::FuelService;
 use fuel_core_client::client::{
-    types::TransactionStatus,
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    types::{
+        asset::{
+            AssetFirstSeen,
+            AssetSupplyChange,
+            ChainAsset,
+        },
+        TransactionStatus,
+    },
     FuelClient,
 };
 use fuel_core_types::{
@@ -11,7 +24,9 @@ use fuel_core_types::{
         RegId,
     },
     fuel_tx::{
+        AssetId,
         Bytes32,
+        ContractId,
         ContractIdExt,
         Input,
         Output,
@@ -20,7 +35,10 @@ use fuel_core_types::{
         UtxoId,
         Witness,
     },
-    fuel_types::canonical::Serialize,
+    fuel_types::{
+        canonical::Serialize,
+        BlockHeight,
+    },
     fuel_vm::{
         Call,
         Contract,
@@ -182,3 +200,290 @@ async fn asset_info_mint_burn() {
     // We should have the minted amount reduced by the burned amount
     assert_eq!(final_supply, (mint_amount - burn_amount) as u128);
 }
+
+/// Deploys the contract that mints the `amount` of its asset with the sub id 0
+/// when called with the jump condition 0, and burns the `amount` otherwise.
+async fn deploy_mint_burn_contract(
+    client: &FuelClient,
+    amount: u32,
+) -> (ContractId, Bytes32) {
+    let reg_len: u8 = 0x10;
+    let reg_amount: u8 = 0x11;
+    let reg_jump_cond: u8 = 0x13;
+    let ops = vec![
+        op::movi(reg_len, 32),
+        op::aloc(reg_len),
+        op::sb(RegId::HP, 0, 0),
+        op::movi(reg_amount, amount),
+        op::jmpf(reg_jump_cond, 0),
+        op::mint(reg_amount, RegId::HP),
+        op::ret(RegId::ONE),
+        op::burn(reg_amount, RegId::HP),
+        op::ret(RegId::ONE),
+    ];
+    let bytecode: Witness = ops.into_iter().collect::<Vec<u8>>().into();
+
+    let salt = Salt::zeroed();
+    let contract = Contract::from(bytecode.as_ref());
+    let root = contract.root();
+    let state_root = Contract::initial_state_root(std::iter::empty());
+    let contract_id = contract.id(&salt, &root, &state_root);
+    let contract_deploy = TransactionBuilder::create(bytecode, salt, vec![])
+        .add_fee_input()
+        .add_output(Output::contract_created(contract_id, state_root))
+        .finalize_as_transaction();
+    let status = client
+        .submit_and_await_commit(&contract_deploy)
+        .await
+        .unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+
+    (contract_id, state_root)
+}
+
+/// Calls the contract with the `jump_cond` and returns the height of the block.
+async fn call_mint_burn_contract(
+    client: &FuelClient,
+    (contract_id, state_root): (ContractId, Bytes32),
+    jump_cond: u16,
+) -> BlockHeight {
+    let script_ops = vec![
+        op::movi(0x13, jump_cond.into()),
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+    let script = TransactionBuilder::script(
+        script_ops.into_iter().collect::<Vec<u8>>(),
+        script_data,
+    )
+    .add_input(Input::contract(
+        UtxoId::new(Bytes32::zeroed(), 0),
+        Bytes32::zeroed(),
+        state_root,
+        TxPointer::default(),
+        contract_id,
+    ))
+    .script_gas_limit(1_000_000)
+    .add_fee_input()
+    .add_output(Output::contract(0, Bytes32::zeroed(), Bytes32::zeroed()))
+    .finalize_as_transaction();
+
+    match client.submit_and_await_commit(&script).await.unwrap() {
+        TransactionStatus::Success { block_height, .. } => block_height,
+        status => panic!("Unexpected status: {status:?}"),
+    }
+}
+
+#[tokio::test]
+async fn asset_supply_history__returns_mint_then_burn_to_zero_in_height_order() {
+    const BURN: u16 = 2;
+    let amount: u32 = 100;
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let contract = deploy_mint_burn_contract(&client, amount).await;
+    let asset_id = contract.0.asset_id(&Bytes32::zeroed());
+
+    // given
+    let minted_at = call_mint_burn_contract(&client, contract, MINT).await;
+    let burned_at = call_mint_burn_contract(&client, contract, BURN).await;
+    let minted_again_at = call_mint_burn_contract(&client, contract, MINT).await;
+
+    // when
+    let mut history = vec![];
+    let mut cursor = None;
+    loop {
+        let page = client
+            .asset_supply_history(
+                &asset_id,
+                PaginationRequest {
+                    cursor,
+                    results: 1,
+                    direction: PageDirection::Forward,
+                },
+            )
+            .await
+            .unwrap();
+        history.extend(page.results);
+        if !page.has_next_page {
+            break
+        }
+        cursor = page.cursor;
+    }
+
+    // then
+    let amount = i128::from(amount);
+    assert_eq!(
+        history,
+        vec![
+            AssetSupplyChange {
+                block_height: minted_at,
+                delta: amount,
+                total_supply: 100,
+            },
+            AssetSupplyChange {
+                block_height: burned_at,
+                delta: -amount,
+                total_supply: 0,
+            },
+            AssetSupplyChange {
+                block_height: minted_again_at,
+                delta: amount,
+                total_supply: 100,
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn asset_first_seen__keeps_the_block_of_the_first_mint() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let contract = deploy_mint_burn_contract(&client, 100).await;
+    let asset_id = contract.0.asset_id(&Bytes32::zeroed());
+    let not_seen = client.asset_first_seen(&asset_id).await.unwrap();
+
+    // given
+    let minted_at = call_mint_burn_contract(&client, contract, MINT).await;
+    let minted_again_at = call_mint_burn_contract(&client, contract, MINT).await;
+
+    // when
+    let first_seen = client.asset_first_seen(&asset_id).await.unwrap();
+
+    // then
+    assert_eq!(not_seen, None);
+    assert!(minted_at < minted_again_at);
+    let block = client.block_by_height(minted_at).await.unwrap().unwrap();
+    assert_eq!(
+        first_seen,
+        Some(AssetFirstSeen {
+            block_height: minted_at,
+            time: block.header.time,
+        })
+    );
+}
+
+const MINT: u16 = 0;
+
+/// Deploys a contract per amount and mints its asset. Returns the minted assets.
+async fn mint_assets(client: &FuelClient, amounts: &[u32]) -> Vec<AssetId> {
+    let mut assets = vec![];
+    for amount in amounts {
+        let contract = deploy_mint_burn_contract(client, *amount).await;
+        call_mint_burn_contract(client, contract, MINT).await;
+        assets.push(contract.0.asset_id(&Bytes32::zeroed()));
+    }
+    assets
+}
+
+/// Returns the assets after the `cursor` one page of one asset at a time.
+async fn page_assets(
+    client: &FuelClient,
+    mut cursor: Option<String>,
+    include_base_asset: bool,
+) -> Vec<ChainAsset> {
+    let mut assets = vec![];
+    loop {
+        let page = client
+            .assets(
+                PaginationRequest {
+                    cursor,
+                    results: 1,
+                    direction: PageDirection::Forward,
+                },
+                include_base_asset,
+            )
+            .await
+            .unwrap();
+        assets.extend(page.results);
+        if !page.has_next_page {
+            break
+        }
+        cursor = page.cursor;
+    }
+    assets
+}
+
+#[tokio::test]
+async fn assets__pages_through_the_minted_assets_in_the_order_of_asset_ids() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // given
+    let mut minted = mint_assets(&client, &[100, 101, 102]).await;
+    minted.sort();
+
+    // when
+    let assets = page_assets(&client, None, false).await;
+
+    // then
+    let asset_ids: Vec<_> = assets.iter().map(|asset| asset.asset_id).collect();
+    assert_eq!(asset_ids, minted);
+    for asset in assets {
+        let detail = asset.detail.expect("The minted asset has the details");
+        assert_eq!(detail.sub_id, Bytes32::zeroed());
+        assert_eq!(
+            detail.contract_id.asset_id(&Bytes32::zeroed()),
+            asset.asset_id
+        );
+        assert!(detail.total_supply > 0);
+    }
+}
+
+#[tokio::test]
+async fn assets__lists_the_base_asset_first_only_with_the_flag() {
+    let config = Config::local_node();
+    let base_asset_id = config.base_asset_id();
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // given
+    let mut minted = mint_assets(&client, &[100, 101]).await;
+    minted.sort();
+
+    // when
+    let with_base = page_assets(&client, None, true).await;
+    let without_base = page_assets(&client, None, false).await;
+
+    // then
+    let with_base_ids: Vec<_> = with_base.iter().map(|asset| asset.asset_id).collect();
+    let mut expected = vec![base_asset_id];
+    expected.extend(minted.iter().copied());
+    assert_eq!(with_base_ids, expected);
+    assert_eq!(with_base[0].detail, None);
+    let without_base_ids: Vec<_> =
+        without_base.iter().map(|asset| asset.asset_id).collect();
+    assert_eq!(without_base_ids, minted);
+}
+
+#[tokio::test]
+async fn assets__cursor_stays_valid_when_assets_are_minted_mid_pagination() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let mut minted = mint_assets(&client, &[100, 101]).await;
+    let first_page = client
+        .assets(
+            PaginationRequest {
+                cursor: None,
+                results: 1,
+                direction: PageDirection::Forward,
+            },
+            false,
+        )
+        .await
+        .unwrap();
+    let first = first_page.results[0].asset_id;
+
+    // given
+    minted.extend(mint_assets(&client, &[102, 103]).await);
+    minted.sort();
+
+    // when
+    let rest = page_assets(&client, first_page.cursor, false).await;
+
+    // then
+    let rest_ids: Vec<_> = rest.iter().map(|asset| asset.asset_id).collect();
+    let expected: Vec<_> = minted.into_iter().filter(|id| *id > first).collect();
+    assert_eq!(rest_ids, expected);
+}
@@ -30,7 +30,9 @@ use fuel_core_types::{
         ContractIdExt,
     },
 };
+use futures::StreamExt;
 use rand::SeedableRng;
+use std::time::Duration;
 use test_helpers::{
     assemble_tx::AssembleAndRunTx,
     default_signing_wallet,
@@ -104,6 +106,229 @@ async fn balance() {
     assert_eq!(balance, 1);
 }
 
+#[tokio::test]
+async fn balance_delta__returns_signed_change_between_blocks() {
+    let wallet = default_signing_wallet();
+    let owner = wallet.owner();
+    let recipient = Address::new([1u8; 32]);
+    let asset_id = AssetId::BASE;
+
+    // given
+    let mut coin_generator = CoinConfigGenerator::new();
+    let state_config = StateConfig {
+        coins: vec![CoinConfig {
+            owner,
+            amount: 450,
+            asset_id,
+            ..coin_generator.generate()
+        }],
+        ..Default::default()
+    };
+    let mut config = Config::local_node_with_state_config(state_config);
+    config.combined_db_config.state_rewind_policy = StateRewindPolicy::RewindFullRange;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client
+        .run_transfer(wallet, vec![(recipient, asset_id, 449)])
+        .await
+        .unwrap();
+    let to_height = client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height;
+
+    // when
+    let owner_delta = client
+        .balance_delta(&owner, Some(&asset_id), 0u32.into(), to_height.into())
+        .await
+        .unwrap();
+    let recipient_delta = client
+        .balance_delta(&recipient, Some(&asset_id), 0u32.into(), to_height.into())
+        .await
+        .unwrap();
+
+    // then
+    assert_eq!(owner_delta, -449);
+    // The recipient had no activity at the `from` height, so its baseline is zero.
+    assert_eq!(recipient_delta, 449);
+}
+
+#[tokio::test]
+async fn balance_delta__fails_when_to_height_is_above_indexed_height() {
+    // given
+    let mut config = Config::local_node();
+    config.combined_db_config.state_rewind_policy = StateRewindPolicy::RewindFullRange;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let latest_height = client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height;
+
+    // when
+    let result = client
+        .balance_delta(
+            &Address::new([1u8; 32]),
+            None,
+            0u32.into(),
+            (latest_height + 10).into(),
+        )
+        .await;
+
+    // then
+    let error = result.expect_err("The height is above the indexed height");
+    assert!(error.to_string().contains("is above the indexed height"));
+}
+
+#[tokio::test]
+async fn balance_updated__transfer_to_the_watched_owner_produces_one_event() {
+    let wallet = default_signing_wallet();
+    let owner = wallet.owner();
+    let recipient = Address::new([1u8; 32]);
+    let asset_id = AssetId::BASE;
+
+    // given
+    let mut coin_generator = CoinConfigGenerator::new();
+    let state_config = StateConfig {
+        coins: vec![CoinConfig {
+            owner,
+            amount: 450,
+            asset_id,
+            ..coin_generator.generate()
+        }],
+        ..Default::default()
+    };
+    let config = Config::local_node_with_state_config(state_config);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let mut stream = client
+        .balance_updated_subscription(&recipient, &asset_id)
+        .await
+        .unwrap();
+
+    // when
+    // Both outputs of the transfer go to the recipient within one block.
+    client
+        .run_transfer(
+            wallet,
+            vec![(recipient, asset_id, 100), (recipient, asset_id, 349)],
+        )
+        .await
+        .unwrap();
+    let transfer_height = client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height;
+    client.produce_blocks(1, None).await.unwrap();
+
+    // then
+    let update = tokio::time::timeout(Duration::from_secs(10), stream.next())
+        .await
+        .expect("The balance update should be streamed")
+        .expect("The stream should be open")
+        .unwrap();
+    assert_eq!(update.owner, recipient);
+    assert_eq!(update.asset_id, asset_id);
+    assert_eq!(update.amount, 449);
+    assert_eq!(u32::from(update.block_height), transfer_height);
+    let next = tokio::time::timeout(Duration::from_secs(1), stream.next()).await;
+    assert!(next.is_err(), "Unexpected balance update: {next:?}");
+}
+
+#[tokio::test]
+async fn balance_updated__fails_above_the_limit_of_subscriptions() {
+    // given
+    let mut config = Config::local_node();
+    config.graphql_config.max_balance_subscriptions = 1;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let owner = Address::new([1u8; 32]);
+    let mut first = client
+        .balance_updated_subscription(&owner, &AssetId::BASE)
+        .await
+        .unwrap();
+    let _ = tokio::time::timeout(Duration::from_millis(500), first.next()).await;
+
+    // when
+    let mut second = client
+        .balance_updated_subscription(&owner, &AssetId::BASE)
+        .await
+        .unwrap();
+    let result = tokio::time::timeout(Duration::from_secs(10), second.next())
+        .await
+        .expect("The error should be streamed")
+        .expect("The stream should return the error");
+
+    // then
+    let error = result.expect_err("The second subscription should be rejected");
+    assert!(
+        error
+            .to_string()
+            .contains("The limit of 1 active balance subscriptions is reached"),
+        "Unexpected error: {error}"
+    );
+}
+
+#[tokio::test]
+async fn owned_coin_value__sums_the_coins_without_the_messages() {
+    // Given
+    let owner = Address::default();
+    let other_asset_id = AssetId::new([1; 32]);
+    let mut coin_generator = CoinConfigGenerator::new();
+    let state_config = StateConfig {
+        coins: vec![
+            (owner, 50, AssetId::BASE),
+            (owner, 100, AssetId::BASE),
+            (owner, 7, other_asset_id),
+        ]
+        .into_iter()
+        .map(|(owner, amount, asset_id)| CoinConfig {
+            owner,
+            amount,
+            asset_id,
+            ..coin_generator.generate()
+        })
+        .collect(),
+        messages: vec![MessageConfig {
+            sender: owner,
+            recipient: owner,
+            nonce: 0u64.into(),
+            amount: 60,
+            data: NON_RETRYABLE.to_vec(),
+            da_height: DaBlockHeight::from(0usize),
+        }],
+        ..Default::default()
+    };
+    let config = Config::local_node_with_state_config(state_config);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let base_value = client
+        .owned_coin_value(&owner, &AssetId::BASE)
+        .await
+        .unwrap();
+    let other_value = client
+        .owned_coin_value(&owner, &other_asset_id)
+        .await
+        .unwrap();
+    let base_balance = client.balance(&owner, Some(&AssetId::BASE)).await.unwrap();
+
+    // Then
+    assert_eq!(base_value, 150);
+    assert_eq!(other_value, 7);
+    assert_eq!(base_balance, 150 + 60);
+}
+
 #[tokio::test]
 async fn balance_messages_only() {
     let owner = Address::default();
@@ -596,3 +821,78 @@ async fn contract_balances_in_the_past() {
         contract_id.asset_id(&sub_asset_id)
     );
 }
+
+#[tokio::test]
+async fn snapshot_view__queries_with_the_token_read_the_pinned_height() {
+    let wallet = default_signing_wallet();
+    let owner = wallet.owner();
+    let asset_id = AssetId::BASE;
+
+    // given
+    let mut coin_generator = CoinConfigGenerator::new();
+    let state_config = StateConfig {
+        coins: vec![CoinConfig {
+            owner,
+            amount: 450,
+            asset_id,
+            ..coin_generator.generate()
+        }],
+        ..Default::default()
+    };
+    let config = Config::local_node_with_state_config(state_config);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let snapshot = client.snapshot_view().await.unwrap();
+    client
+        .run_transfer(wallet, vec![(Address::new([1u8; 32]), asset_id, 449)])
+        .await
+        .unwrap();
+
+    // when
+    let mut pinned_client = client.clone();
+    pinned_client.with_snapshot_token(Some(snapshot.token.clone()));
+    let pinned_balance = pinned_client
+        .balance(&owner, Some(&asset_id))
+        .await
+        .unwrap();
+    let pinned_coins = pinned_client
+        .coins(
+            &owner,
+            Some(&asset_id),
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap()
+        .results;
+    let latest_balance = client.balance(&owner, Some(&asset_id)).await.unwrap();
+
+    // then
+    assert_eq!(pinned_balance, 450);
+    assert_eq!(
+        pinned_coins
+            .iter()
+            .map(|coin| coin.amount)
+            .collect::<Vec<_>>(),
+        vec![450]
+    );
+    assert_eq!(latest_balance, 1);
+}
+
+#[tokio::test]
+async fn snapshot_view__queries_with_the_unknown_token_fail() {
+    // given
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let mut client = FuelClient::from(srv.bound_address);
+    client.with_snapshot_token(Some("unknown".to_string()));
+
+    // when
+    let result = client.balance(&Address::zeroed(), None).await;
+
+    // then
+    let err = result.expect_err("The unknown token should be rejected");
+    assert!(err.to_string().contains("unknown or expired"), "{err}");
+}
@@ -3,6 +3,7 @@ use fuel_core::{
         LastBlockConfig,
         StateConfig,
     },
+    combined_database::CombinedDatabase,
     database::Database,
     service::{
         Config,
@@ -14,7 +15,14 @@ use fuel_core_client::client::{
         PageDirection,
         PaginationRequest,
     },
-    types::TransactionStatus,
+    schema::block::{
+        BlockDataInclusion,
+        BlockIndexedEvent,
+    },
+    types::{
+        TransactionStatus,
+        TransactionType,
+    },
     FuelClient,
 };
 use fuel_core_poa::Trigger;
@@ -32,11 +40,19 @@ use fuel_core_types::{
         block::CompressedBlock,
         consensus::Consensus,
     },
+    fuel_crypto::SecretKey,
     fuel_tx::*,
-    secrecy::ExposeSecret,
+    secrecy::{
+        ExposeSecret,
+        Secret,
+    },
     signer::SignMode,
     tai64::Tai64,
 };
+use futures::{
+    StreamExt,
+    TryStreamExt,
+};
 use itertools::{
     rev,
     Itertools,
@@ -147,6 +163,176 @@ async fn produce_block() {
     };
 }
 
+#[tokio::test]
+async fn block_transaction_ids__returns_ids_of_block_transactions() {
+    // given
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let tx = Transaction::default_test_tx();
+    let status = client.submit_and_await_commit(&tx).await.unwrap();
+    let TransactionStatus::Success { block_height, .. } = status else {
+        panic!("Wrong tx status");
+    };
+
+    // when
+    let tx_ids = client.block_transaction_ids(block_height).await.unwrap();
+    let unknown_tx_ids = client
+        .block_transaction_ids(block_height.succ().unwrap())
+        .await
+        .unwrap();
+
+    // then
+    let block = client.block_by_height(block_height).await.unwrap().unwrap();
+    assert_eq!(tx_ids, Some(block.transactions));
+    assert_eq!(unknown_tx_ids, None);
+}
+
+#[tokio::test]
+async fn block_headers__returns_headers_up_to_the_latest_block() {
+    // given
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let latest_height = client.produce_blocks(3, None).await.unwrap();
+
+    // when
+    let headers = client
+        .block_headers(1u32.into(), 10u32.into())
+        .await
+        .unwrap();
+
+    // then
+    assert_eq!(headers.len(), *latest_height as usize);
+    for (height, header) in (1u32..).zip(&headers) {
+        let block = client
+            .block_by_height(height.into())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(header.header, block.header);
+        assert_eq!(header.consensus, block.consensus);
+    }
+}
+
+#[tokio::test]
+async fn block_headers__fails_above_the_limit() {
+    let mut config = Config::local_node();
+    config.graphql_config.max_block_headers_per_request = 2;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(3, None).await.unwrap();
+
+    // when
+    let within_limit = client.block_headers(1u32.into(), 2u32.into()).await;
+    let above_limit = client.block_headers(1u32.into(), 3u32.into()).await;
+
+    // then
+    assert_eq!(within_limit.unwrap().len(), 2);
+    let error = above_limit.unwrap_err();
+    assert!(error.to_string().contains("above the limit"), "{error}");
+}
+
+/// Produces the `blocks` on the `database` signed by the `producer`
+/// and returns the height of the latest block.
+async fn produce_blocks_signed_by(
+    database: &CombinedDatabase,
+    producer: SecretKey,
+    blocks: u32,
+) -> u32 {
+    let mut config = Config::local_node();
+    config.consensus_signer = SignMode::Key(Secret::new(producer.into()));
+    let srv = FuelService::from_combined_database(database.clone(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let latest_height = client.produce_blocks(blocks, None).await.unwrap();
+    srv.send_stop_signal_and_await_shutdown().await.unwrap();
+    *latest_height
+}
+
+/// Returns the heights of all blocks of the `producer`, one block per page.
+async fn heights_by_producer(client: &FuelClient, producer: &Address) -> Vec<u32> {
+    let mut heights = vec![];
+    let mut cursor = None;
+    loop {
+        let request = PaginationRequest {
+            cursor,
+            results: 1,
+            direction: PageDirection::Forward,
+        };
+        let page = client.blocks_by_producer(producer, request).await.unwrap();
+        heights.extend(page.results.iter().map(|header| header.height));
+        if !page.has_next_page {
+            return heights
+        }
+        cursor = page.cursor;
+    }
+}
+
+#[tokio::test]
+async fn blocks_by_producer__returns_the_blocks_signed_by_each_producer() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let first_producer = SecretKey::random(&mut rng);
+    let second_producer = SecretKey::random(&mut rng);
+    let database = CombinedDatabase::default();
+
+    // Given
+    produce_blocks_signed_by(&database, first_producer, 2).await;
+    produce_blocks_signed_by(&database, second_producer, 1).await;
+    produce_blocks_signed_by(&database, first_producer, 1).await;
+    let srv = FuelService::from_combined_database(database, Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let first =
+        heights_by_producer(&client, &Input::owner(&first_producer.public_key())).await;
+    let second =
+        heights_by_producer(&client, &Input::owner(&second_producer.public_key())).await;
+    let unknown = heights_by_producer(&client, &Address::zeroed()).await;
+
+    // Then
+    assert_eq!(first, vec![1, 2, 4]);
+    assert_eq!(second, vec![3]);
+    assert_eq!(unknown, vec![0]);
+}
+
+#[tokio::test]
+async fn blocks_by_producer__returns_the_headers_of_the_blocks() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let producer = SecretKey::random(&mut rng);
+    let mut config = Config::local_node();
+    config.consensus_signer = SignMode::Key(Secret::new(producer.into()));
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(2, None).await.unwrap();
+
+    // When
+    let headers = client
+        .blocks_by_producer(
+            &Input::owner(&producer.public_key()),
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap()
+        .results;
+
+    // Then
+    assert_eq!(headers.len(), 2);
+    for header in headers {
+        let block = client
+            .block_by_height(header.height.into())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(header, block.header);
+    }
+}
+
 #[tokio::test]
 async fn produce_block_manually() {
     let db = Database::default();
@@ -346,6 +532,107 @@ async fn missing_first_and_last_parameters_returns_an_error() {
     assert!(result.contains("The queries for the whole range is not supported"));
 }
 
+#[tokio::test]
+async fn block_indexed__streams_blocks_in_the_order_of_indexation() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // given
+    let stream = client.block_indexed_subscription().await.unwrap();
+
+    // when
+    client.produce_blocks(3, None).await.unwrap();
+
+    // then
+    let events: Vec<_> = tokio::time::timeout(
+        Duration::from_secs(10),
+        stream.take(3).try_collect::<Vec<_>>(),
+    )
+    .await
+    .expect("The indexed blocks should be streamed")
+    .unwrap();
+    let indexed: Vec<_> = events
+        .into_iter()
+        .map(|event| match event {
+            BlockIndexedEvent::BlockIndexed(block) => (block.height.0, block.tx_count.0),
+            event => panic!("Unexpected event: {event:?}"),
+        })
+        .collect();
+    // Each block only contains the `Mint` transaction.
+    assert_eq!(indexed, vec![(1, 1), (2, 1), (3, 1)]);
+}
+
+#[tokio::test]
+async fn blocks_with_data__payload_matches_point_queries() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // given
+    let include = BlockDataInclusion {
+        transactions: true,
+        receipts: true,
+        statuses: true,
+    };
+    let mut stream = client.blocks_with_data_subscription(include).await.unwrap();
+
+    // when
+    let tx = Transaction::default_test_tx();
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    // then
+    let streamed = tokio::time::timeout(Duration::from_secs(10), stream.next())
+        .await
+        .expect("The block should be streamed")
+        .expect("The stream should be open")
+        .unwrap();
+    let block: fuel_core_client::client::types::Block =
+        streamed.block.try_into().unwrap();
+    let expected_block = client
+        .block_by_height(block.header.height.into())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(block, expected_block);
+    // The block contains the submitted transaction and the `Mint` transaction.
+    assert_eq!(block.transactions.len(), 2);
+
+    let transactions = streamed.transactions.unwrap();
+    let receipts = streamed.receipts.unwrap();
+    let statuses = streamed.statuses.unwrap();
+    assert_eq!(transactions.len(), block.transactions.len());
+    assert_eq!(receipts.len(), block.transactions.len());
+    assert_eq!(statuses.len(), block.transactions.len());
+    for (((tx_id, transaction), receipts), status) in block
+        .transactions
+        .iter()
+        .zip(transactions)
+        .zip(receipts)
+        .zip(statuses)
+    {
+        let transaction: Transaction = transaction.try_into().unwrap();
+        let expected_transaction = client.transaction(tx_id).await.unwrap().unwrap();
+        assert_eq!(
+            TransactionType::from(transaction),
+            expected_transaction.transaction
+        );
+
+        let receipts = receipts
+            .into_iter()
+            .map(Receipt::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let expected_receipts = client.receipts(tx_id).await.unwrap().unwrap();
+        assert_eq!(receipts, expected_receipts);
+
+        let status: TransactionStatus = status.try_into().unwrap();
+        assert_eq!(status, client.transaction_status(tx_id).await.unwrap());
+    }
+}
+
 mod full_block {
     use super::*;
     use cynic::QueryBuilder;
@@ -27,6 +27,11 @@ use fuel_core_types::{
     fuel_tx::TxId,
 };
 use rstest::rstest;
+use test_helpers::{
+    assemble_tx::AssembleAndRunTx,
+    default_signing_wallet,
+    send_graph_ql_query,
+};
 
 async fn setup_service(configs: Vec<CoinConfig>) -> FuelService {
     let state = StateConfig {
@@ -138,6 +143,66 @@ async fn only_asset_id_filtered_coins() {
     assert!(coins.results.into_iter().all(|c| asset_id == c.asset_id));
 }
 
+#[tokio::test]
+async fn coins__asset_id_filter_pages_over_interleaved_assets() {
+    let owner = Address::default();
+    let asset_id = AssetId::new([1u8; 32]);
+
+    // given
+    let mut coin_generator = CoinConfigGenerator::new();
+    let coins: Vec<_> = (1..13usize)
+        .map(|i| CoinConfig {
+            owner,
+            amount: i as Word,
+            asset_id: if i % 3 == 0 {
+                asset_id
+            } else {
+                Default::default()
+            },
+            ..coin_generator.generate()
+        })
+        .collect();
+    let srv = setup_service(coins).await;
+    let client = FuelClient::from(srv.bound_address);
+
+    // when
+    let mut cursor = None;
+    let mut pages = vec![];
+    loop {
+        let page = client
+            .coins(
+                &owner,
+                Some(&asset_id),
+                PaginationRequest {
+                    cursor,
+                    results: 2,
+                    direction: PageDirection::Forward,
+                },
+            )
+            .await
+            .unwrap();
+        pages.push(page.results);
+        if !page.has_next_page {
+            break
+        }
+        cursor = page.cursor;
+    }
+
+    // then
+    assert_eq!(pages.len(), 2);
+    assert!(pages.iter().all(|page| page.len() == 2));
+    let mut amounts: Vec<_> = pages
+        .into_iter()
+        .flatten()
+        .map(|coin| {
+            assert_eq!(coin.asset_id, asset_id);
+            coin.amount
+        })
+        .collect();
+    amounts.sort();
+    assert_eq!(amounts, vec![3, 6, 9, 12]);
+}
+
 #[rstest]
 #[tokio::test]
 async fn get_coins_forwards_backwards(
@@ -175,3 +240,98 @@ async fn get_coins_forwards_backwards(
     assert!(!coins.results.is_empty());
     assert_eq!(coins.results.len(), 10);
 }
+
+fn all_coins() -> PaginationRequest<String> {
+    PaginationRequest {
+        cursor: None,
+        results: 100,
+        direction: PageDirection::Forward,
+    }
+}
+
+#[tokio::test]
+async fn coins__skips_the_spent_coins_flagged_in_the_index() {
+    // given
+    let wallet = default_signing_wallet();
+    let owner = wallet.owner();
+    let asset_id = AssetId::BASE;
+    let mut coin_generator = CoinConfigGenerator::new();
+    let coins: Vec<_> = (0..3)
+        .map(|_| CoinConfig {
+            owner,
+            amount: 1000,
+            asset_id,
+            ..coin_generator.generate()
+        })
+        .collect();
+    let state = StateConfig {
+        coins,
+        ..Default::default()
+    };
+    let mut config = Config::local_node_with_state_config(state);
+    config.graphql_config.mark_spent_owned_coins = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let coins_before = client
+        .coins(&owner, None, all_coins())
+        .await
+        .unwrap()
+        .results;
+
+    // when
+    client
+        .run_transfer(wallet, vec![(Address::new([1u8; 32]), asset_id, 1500)])
+        .await
+        .unwrap();
+
+    // then
+    let listed: Vec<UtxoId> = client
+        .coins(&owner, None, all_coins())
+        .await
+        .unwrap()
+        .results
+        .into_iter()
+        .map(|coin| coin.utxo_id)
+        .collect();
+    let mut spent = vec![];
+    for coin in &coins_before {
+        let unspent = client.coin(&coin.utxo_id).await.unwrap().is_some();
+        if !unspent {
+            spent.push(coin.utxo_id);
+        }
+        assert_eq!(listed.contains(&coin.utxo_id), unspent);
+    }
+    assert!(spent.len() >= 2);
+}
+
+#[tokio::test]
+async fn coins__max_bytes_returns_at_least_one_coin_per_page() {
+    // given
+    let owner = Address::default();
+    let mut coin_generator = CoinConfigGenerator::new();
+    let coins: Vec<_> = (0..3)
+        .map(|_| CoinConfig {
+            owner,
+            ..coin_generator.generate()
+        })
+        .collect();
+    let srv = setup_service(coins).await;
+    let url = format!("http://{}/v1/graphql", srv.bound_address);
+    let query = format!(
+        r#"{{ coins(filter: {{ owner: "{owner:#x}" }}, first: 3, maxBytes: "1") {{
+            edges {{ cursor }} pageInfo {{ hasNextPage }}
+        }} }}"#
+    );
+
+    // when
+    let response = send_graph_ql_query(&url, &query).await;
+
+    // then
+    let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+    let coins = &response["data"]["coins"];
+    assert_eq!(coins["edges"].as_array().unwrap().len(), 1);
+    assert_eq!(
+        coins["pageInfo"]["hasNextPage"],
+        serde_json::Value::Bool(true)
+    );
+}
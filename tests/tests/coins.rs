@@ -1,5 +1,6 @@
 use fuel_core::{
     chain_config::{
+        coin_config_helpers::CoinConfigGenerator,
         CoinConfig,
         MessageConfig,
         StateConfig,
@@ -11,17 +12,24 @@ use fuel_core::{
     },
 };
 use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
     types::CoinType,
     FuelClient,
 };
 use fuel_core_types::fuel_tx::*;
+use futures::TryStreamExt;
 use rand::{
     prelude::StdRng,
     SeedableRng,
 };
+use std::str::FromStr;
 use test_helpers::{
     assemble_tx::AssembleAndRunTx,
     builder::TestContext,
+    send_graph_ql_query,
 };
 
 mod coin {
@@ -799,6 +807,233 @@ async fn coins_to_spend_error_duplicate_asset_query(
     );
 }
 
+#[tokio::test]
+async fn stream_owned_coins__returns_all_coins_in_batches() {
+    // given
+    let owner = Address::from([7; 32]);
+    let mut coin_generator = CoinConfigGenerator::new();
+    let state = StateConfig {
+        coins: (0..25)
+            .map(|i| CoinConfig {
+                owner,
+                amount: 100 + i,
+                asset_id: AssetId::default(),
+                ..coin_generator.generate()
+            })
+            .collect(),
+        ..Default::default()
+    };
+    let config = Config::local_node_with_state_config(state);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let expected_utxo_ids: Vec<_> = client
+        .coins(
+            &owner,
+            None,
+            PaginationRequest {
+                cursor: None,
+                results: 100,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap()
+        .results
+        .into_iter()
+        .map(|coin| coin.utxo_id)
+        .collect();
+
+    // when
+    let batches: Vec<_> = client
+        .stream_owned_coins(&owner, Some(10))
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+
+    // then
+    let batch_sizes: Vec<_> = batches.iter().map(Vec::len).collect();
+    assert_eq!(batch_sizes, vec![10, 10, 5]);
+    let actual_utxo_ids: Vec<_> = batches
+        .into_iter()
+        .flatten()
+        .map(|coin| coin.utxo_id)
+        .collect();
+    assert_eq!(actual_utxo_ids, expected_utxo_ids);
+}
+
+#[tokio::test]
+async fn coin_count_by_owner_asset__returns_number_of_coins_per_asset() {
+    // given
+    let owner = Address::from([8; 32]);
+    let asset_id_a = AssetId::new([1; 32]);
+    let asset_id_b = AssetId::new([2; 32]);
+    let mut coin_generator = CoinConfigGenerator::new();
+    let state = StateConfig {
+        coins: [asset_id_a, asset_id_a, asset_id_a, asset_id_b]
+            .into_iter()
+            .map(|asset_id| CoinConfig {
+                owner,
+                amount: 100,
+                asset_id,
+                ..coin_generator.generate()
+            })
+            .collect(),
+        ..Default::default()
+    };
+    let config = Config::local_node_with_state_config(state);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // when
+    let count_a = client
+        .coin_count_by_owner_asset(&owner, &asset_id_a)
+        .await
+        .unwrap();
+    let count_b = client
+        .coin_count_by_owner_asset(&owner, &asset_id_b)
+        .await
+        .unwrap();
+    let count_unknown = client
+        .coin_count_by_owner_asset(&owner, &AssetId::new([3; 32]))
+        .await
+        .unwrap();
+
+    // then
+    assert_eq!(count_a, 3);
+    assert_eq!(count_b, 1);
+    assert_eq!(count_unknown, 0);
+}
+
+#[tokio::test]
+async fn coins__scan_budget_exceeded_error_allows_to_continue_from_cursor() {
+    // given
+    let owner = Address::from([9; 32]);
+    let asset_id_a = AssetId::new([1; 32]);
+    let asset_id_b = AssetId::new([2; 32]);
+    let mut coin_generator = CoinConfigGenerator::new();
+    // The coin of the `asset_id_b` is the last one in the owner's index.
+    let state = StateConfig {
+        coins: [asset_id_a; 50]
+            .into_iter()
+            .chain([asset_id_b])
+            .map(|asset_id| CoinConfig {
+                owner,
+                amount: 100,
+                asset_id,
+                ..coin_generator.generate()
+            })
+            .collect(),
+        ..Default::default()
+    };
+    let mut config = Config::local_node_with_state_config(state);
+    config.graphql_config.max_scanned_entries = 10;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let url = format!("http://{}/v1/graphql", srv.bound_address);
+
+    // when
+    let mut after = String::new();
+    let mut budget_errors = 0;
+    let coins = loop {
+        let query = format!(
+            r#"{{ coins(filter: {{ owner: "{owner:#x}", assetId: "{asset_id_b:#x}" }}, first: 1{after}) {{ nodes {{ assetId }} }} }}"#
+        );
+        let response = send_graph_ql_query(&url, &query).await;
+        let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+
+        let Some(error) = response["errors"].get(0) else {
+            break response["data"]["coins"]["nodes"].clone()
+        };
+        assert_eq!(error["extensions"]["code"], "SCAN_BUDGET_EXCEEDED");
+        assert_eq!(error["extensions"]["retriable"], true);
+        let cursor = error["extensions"]["cursor"].as_str().unwrap();
+        after = format!(r#", after: "{cursor}""#);
+        budget_errors += 1;
+        assert!(budget_errors < 10, "The scan should progress");
+    };
+
+    // then
+    assert!(budget_errors > 0);
+    let coins = coins.as_array().unwrap();
+    assert_eq!(coins.len(), 1);
+    let asset_id = AssetId::from_str(coins[0]["assetId"].as_str().unwrap()).unwrap();
+    assert_eq!(asset_id, asset_id_b);
+}
+
+#[tokio::test]
+async fn consolidation_suggestion__returns_smallest_dust_coins_up_to_max_inputs() {
+    // given
+    let owner = Address::from([10; 32]);
+    let asset_id = AssetId::new([1; 32]);
+    let mut coin_generator = CoinConfigGenerator::new();
+    let state = StateConfig {
+        coins: [5, 1, 3, 2, 2000]
+            .into_iter()
+            .map(|amount| CoinConfig {
+                owner,
+                amount,
+                asset_id,
+                ..coin_generator.generate()
+            })
+            .collect(),
+        ..Default::default()
+    };
+    let mut config = Config::local_node_with_state_config(state);
+    config.graphql_config.consolidation_dust_threshold = 1000;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // when
+    let suggestion = client
+        .consolidation_suggestion(&owner, &asset_id, None, Some(2))
+        .await
+        .unwrap();
+
+    // then
+    let amounts: Vec<_> = suggestion.coins.iter().map(|coin| coin.amount()).collect();
+    assert_eq!(amounts, vec![1, 2]);
+    assert_eq!(suggestion.total, 3);
+    assert_eq!(suggestion.remaining_dust_coins, 2);
+    assert_eq!(suggestion.remaining_dust, 3 + 5);
+}
+
+#[tokio::test]
+async fn has_spendable_coins__returns_whether_the_owner_has_coins_of_the_asset() {
+    // given
+    let owner = Address::from([11; 32]);
+    let asset_id = AssetId::new([1; 32]);
+    let mut coin_generator = CoinConfigGenerator::new();
+    let state = StateConfig {
+        coins: vec![CoinConfig {
+            owner,
+            amount: 100,
+            asset_id,
+            ..coin_generator.generate()
+        }],
+        ..Default::default()
+    };
+    let config = Config::local_node_with_state_config(state);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // when
+    let with_coins = client.has_spendable_coins(&owner, &asset_id).await.unwrap();
+    let other_asset = client
+        .has_spendable_coins(&owner, &AssetId::new([2; 32]))
+        .await
+        .unwrap();
+    let other_owner = client
+        .has_spendable_coins(&Address::from([12; 32]), &asset_id)
+        .await
+        .unwrap();
+
+    // then
+    assert!(with_coins);
+    assert!(!other_asset);
+    assert!(!other_owner);
+}
+
 trait ToStdErrorString {
     fn to_str_error_string(self) -> String;
 }
@@ -819,3 +1054,511 @@ impl CumulativeAmount for Vec<CoinType> {
         self.iter().map(|coin| coin.amount()).sum()
     }
 }
+
+#[tokio::test]
+async fn coins__include_derived_ownership_returns_coins_of_the_predicate_data_owner() {
+    use fuel_core::fuel_core_graphql_api::indexation::derived_ownership::PredicateDataOwnerMapper;
+    use fuel_core_types::{
+        fuel_asm::{
+            op,
+            RegId,
+        },
+        fuel_crypto::SecretKey,
+    };
+    use std::sync::Arc;
+
+    // given
+    let mut rng = StdRng::seed_from_u64(1234);
+    let public_key = SecretKey::random(&mut rng).public_key();
+    let derived_owner = Input::owner(&public_key);
+    let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+    let root = Input::predicate_owner(&predicate);
+    let mut coin_generator = CoinConfigGenerator::new();
+    let coin = CoinConfig {
+        owner: root,
+        amount: 1000,
+        asset_id: AssetId::BASE,
+        ..coin_generator.generate()
+    };
+    let state = StateConfig {
+        coins: vec![coin.clone()],
+        ..Default::default()
+    };
+    let mut config = Config::local_node_with_state_config(state);
+    config.coin_owner_mapper = Some(Arc::new(PredicateDataOwnerMapper));
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let url = format!("http://{}/v1/graphql", srv.bound_address);
+
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .max_fee_limit(0)
+        .add_input(Input::coin_predicate(
+            coin.utxo_id(),
+            root,
+            coin.amount,
+            coin.asset_id,
+            Default::default(),
+            Default::default(),
+            predicate,
+            public_key.as_ref().to_vec(),
+        ))
+        .add_output(Output::change(root, 0, AssetId::BASE))
+        .finalize_as_transaction();
+    let estimate_predicates = true;
+    client
+        .submit_and_await_commit_opt(&tx, Some(estimate_predicates))
+        .await
+        .unwrap();
+
+    // when
+    let coins_of = |owner: Address, include_derived: bool| {
+        let url = url.clone();
+        async move {
+            let query = format!(
+                r#"{{ coins(filter: {{ owner: "{owner:#x}" }}, first: 10, includeDerivedOwnership: {include_derived}) {{ nodes {{ utxoId }} }} }}"#
+            );
+            let response = send_graph_ql_query(&url, &query).await;
+            let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+            response["data"]["coins"]["nodes"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|node| node["utxoId"].as_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        }
+    };
+    let root_coins = coins_of(root, false).await;
+    let derived_coins = coins_of(derived_owner, true).await;
+    let direct_coins = coins_of(derived_owner, false).await;
+
+    // then
+    assert_eq!(root_coins.len(), 1);
+    assert_eq!(derived_coins, root_coins);
+    assert!(direct_coins.is_empty());
+}
+
+#[tokio::test]
+async fn coins_to_spend__response_is_enough_to_build_valid_transaction() {
+    use fuel_core_types::{
+        blockchain::primitives::DaBlockHeight,
+        fuel_asm::{
+            op,
+            RegId,
+        },
+        fuel_crypto::SecretKey,
+    };
+
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret = SecretKey::random(&mut rng);
+    let owner = Input::owner(&secret.public_key());
+
+    // given
+    let state = StateConfig {
+        coins: vec![CoinConfig {
+            owner,
+            amount: 100,
+            asset_id: AssetId::BASE,
+            ..CoinConfigGenerator::new().generate()
+        }],
+        messages: vec![MessageConfig {
+            sender: Address::from([7; 32]),
+            recipient: owner,
+            nonce: 1.into(),
+            amount: 200,
+            data: vec![],
+            da_height: DaBlockHeight::from(0u64),
+        }],
+        ..Default::default()
+    };
+    let config = Config::local_node_with_state_config(state);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // when
+    let coins = client
+        .coins_to_spend(&owner, vec![(AssetId::BASE, 300, None)], None)
+        .await
+        .unwrap();
+
+    // then
+    let mut builder =
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![]);
+    builder.script_gas_limit(10_000);
+    for coin in coins.into_iter().flatten() {
+        match coin {
+            CoinType::Coin(coin) => {
+                builder.add_unsigned_coin_input(
+                    secret,
+                    coin.utxo_id,
+                    coin.amount,
+                    coin.asset_id,
+                    TxPointer::new(coin.block_created.into(), coin.tx_created_idx),
+                );
+            }
+            CoinType::MessageCoin(message) => {
+                let stored = client.message(&message.nonce).await.unwrap().unwrap();
+                assert_eq!(message.data, stored.data);
+                builder.add_unsigned_message_input(
+                    secret,
+                    message.sender,
+                    message.nonce,
+                    message.amount,
+                    message.data,
+                );
+            }
+            CoinType::Unknown => panic!("Unexpected coin type"),
+        }
+    }
+    builder.add_output(Output::change(owner, 0, AssetId::BASE));
+    let tx = builder.finalize_as_transaction();
+    let status = client.submit_and_await_commit(&tx).await.unwrap();
+    assert!(
+        matches!(
+            status,
+            fuel_core_client::client::types::TransactionStatus::Success { .. }
+        ),
+        "{status:?}"
+    );
+}
+
+#[tokio::test]
+async fn coins__only_predicate_returns_coins_created_once_owner_spent_with_predicate() {
+    use fuel_core_types::fuel_asm::{
+        op,
+        RegId,
+    };
+
+    // given
+    let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+    let root = Input::predicate_owner(&predicate);
+    let mut coin_generator = CoinConfigGenerator::new();
+    let coins: Vec<_> = (0..2)
+        .map(|_| CoinConfig {
+            owner: root,
+            amount: 1000,
+            asset_id: AssetId::BASE,
+            ..coin_generator.generate()
+        })
+        .collect();
+    let state = StateConfig {
+        coins: coins.clone(),
+        ..Default::default()
+    };
+    let config = Config::local_node_with_state_config(state);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let url = format!("http://{}/v1/graphql", srv.bound_address);
+    let predicate_coins_of = |owner: Address| {
+        let url = url.clone();
+        async move {
+            let query = format!(
+                r#"{{ coins(filter: {{ owner: "{owner:#x}" }}, first: 10, onlyPredicate: true) {{ nodes {{ blockCreated }} }} }}"#
+            );
+            let response = send_graph_ql_query(&url, &query).await;
+            let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+            response["data"]["coins"]["nodes"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|node| match &node["blockCreated"] {
+                    serde_json::Value::String(height) => height.parse::<u32>().unwrap(),
+                    height => u32::try_from(height.as_u64().unwrap()).unwrap(),
+                })
+                .collect::<Vec<_>>()
+        }
+    };
+    let coins_before_spending = predicate_coins_of(root).await;
+
+    // when
+    let coin = &coins[0];
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .max_fee_limit(0)
+        .add_input(Input::coin_predicate(
+            coin.utxo_id(),
+            root,
+            coin.amount,
+            coin.asset_id,
+            Default::default(),
+            Default::default(),
+            predicate,
+            vec![],
+        ))
+        .add_output(Output::change(root, 0, AssetId::BASE))
+        .finalize_as_transaction();
+    let estimate_predicates = true;
+    client
+        .submit_and_await_commit_opt(&tx, Some(estimate_predicates))
+        .await
+        .unwrap();
+
+    // then
+    // Only the change created after the spend is flagged, while the genesis coin
+    // received before it is not.
+    assert_eq!(coins_before_spending, Vec::<u32>::new());
+    assert_eq!(predicate_coins_of(root).await, vec![1]);
+    assert_eq!(
+        predicate_coins_of(Address::from([5; 32])).await,
+        Vec::<u32>::new()
+    );
+}
+
+#[tokio::test]
+async fn repair_orphaned_owned_coins__removes_the_coins_missing_on_chain() {
+    use fuel_core::fuel_core_graphql_api::storage::coins::{
+        owner_coin_id_key,
+        OwnedCoinState,
+        OwnedCoins,
+    };
+    use fuel_core_storage::{
+        transactional::ReadTransaction,
+        StorageAsMut,
+    };
+
+    // Given
+    let owner = Address::from([11; 32]);
+    let mut coin_generator = CoinConfigGenerator::new();
+    let coin = CoinConfig {
+        owner,
+        amount: 100,
+        ..coin_generator.generate()
+    };
+    let state = StateConfig {
+        coins: vec![coin.clone()],
+        ..Default::default()
+    };
+    let srv = FuelService::new_node(Config::local_node_with_state_config(state))
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let orphaned = [
+        UtxoId::new([1; 32].into(), 0),
+        UtxoId::new([2; 32].into(), 1),
+    ];
+    let off_chain = srv.shared.database.off_chain();
+    let mut transaction = off_chain.read_transaction();
+    for utxo_id in orphaned.iter() {
+        transaction
+            .storage_as_mut::<OwnedCoins>()
+            .insert(
+                &owner_coin_id_key(&owner, utxo_id),
+                &OwnedCoinState::Unspent,
+            )
+            .unwrap();
+    }
+    off_chain
+        .commit_changes_without_height(transaction.into_changes())
+        .unwrap();
+    // The orphaned coins are listed in the key order of the index.
+    let expected = orphaned.to_vec();
+
+    // When
+    let found = client
+        .find_orphaned_owned_coins(&owner, None)
+        .await
+        .unwrap();
+    let found_limited = client
+        .find_orphaned_owned_coins(&owner, Some(1))
+        .await
+        .unwrap();
+    let repaired_first = client
+        .repair_orphaned_owned_coins(&owner, Some(1))
+        .await
+        .unwrap();
+    let repaired_rest = client
+        .repair_orphaned_owned_coins(&owner, None)
+        .await
+        .unwrap();
+
+    // Then
+    let into_utxo_ids = |coins: Vec<fuel_core_client::client::schema::UtxoId>| {
+        coins.into_iter().map(UtxoId::from).collect::<Vec<_>>()
+    };
+    assert_eq!(into_utxo_ids(found), expected);
+    assert_eq!(into_utxo_ids(found_limited), expected[..1]);
+    assert_eq!(into_utxo_ids(repaired_first.removed), expected[..1]);
+    assert!(repaired_first.has_more);
+    assert_eq!(into_utxo_ids(repaired_rest.removed), expected[1..]);
+    assert!(!repaired_rest.has_more);
+    let above_page_size = client
+        .repair_orphaned_owned_coins(&owner, Some(u32::MAX))
+        .await;
+    assert!(above_page_size
+        .unwrap_err()
+        .to_string()
+        .contains("above the limit"));
+    let found_after_repair = client
+        .find_orphaned_owned_coins(&owner, None)
+        .await
+        .unwrap();
+    assert!(found_after_repair.is_empty());
+    let coins = client
+        .coins(
+            &owner,
+            None,
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(coins.results.len(), 1);
+    assert_eq!(coins.results[0].utxo_id, coin.utxo_id());
+}
+
+#[tokio::test]
+async fn coin_spender__returns_the_transaction_that_spent_the_coin() {
+    use fuel_core_client::client::types::TransactionStatus;
+    use fuel_core_types::fuel_asm::{
+        op,
+        RegId,
+    };
+
+    // Given
+    let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+    let owner = Input::predicate_owner(&predicate);
+    let mut coin_generator = CoinConfigGenerator::new();
+    let coins: Vec<_> = (0..2)
+        .map(|_| CoinConfig {
+            owner,
+            amount: 1000,
+            asset_id: AssetId::BASE,
+            ..coin_generator.generate()
+        })
+        .collect();
+    let state = StateConfig {
+        coins: coins.clone(),
+        ..Default::default()
+    };
+    let srv = FuelService::new_node(Config::local_node_with_state_config(state))
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let spent = &coins[0];
+    let unspent = &coins[1];
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .max_fee_limit(0)
+        .add_input(Input::coin_predicate(
+            spent.utxo_id(),
+            owner,
+            spent.amount,
+            spent.asset_id,
+            Default::default(),
+            Default::default(),
+            predicate,
+            vec![],
+        ))
+        .add_output(Output::change(owner, 0, AssetId::BASE))
+        .finalize_as_transaction();
+    let tx_id = tx.id(&ChainId::default());
+    let status = client
+        .submit_and_await_commit_opt(&tx, Some(true))
+        .await
+        .unwrap();
+    let TransactionStatus::Success { block_height, .. } = status else {
+        panic!("unexpected status: {status:?}");
+    };
+
+    // When
+    let spender = client.coin_spender(&spent.utxo_id()).await.unwrap();
+    let never_spent = client.coin_spender(&unspent.utxo_id()).await.unwrap();
+    let url = format!("http://{}/v1/graphql", srv.bound_address);
+    let query = format!(
+        r#"{{ coin(utxoId: "{:#x}", includeSpent: true) {{ amount spender {{ transactionId blockHeight }} }} }}"#,
+        spent.utxo_id()
+    );
+    let response = send_graph_ql_query(&url, &query).await;
+    let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+
+    // Then
+    let spender = spender.expect("the coin was spent");
+    assert_eq!(spender.transaction_id, tx_id);
+    assert_eq!(spender.block_height, *block_height);
+    assert_eq!(never_spent, None);
+    let coin = &response["data"]["coin"];
+    assert_eq!(coin["amount"], spent.amount.to_string());
+    assert_eq!(coin["spender"]["transactionId"], format!("{tx_id:#x}"));
+    assert_eq!(coin["spender"]["blockHeight"], block_height.to_string());
+}
+
+#[tokio::test]
+async fn spent_coins_at_height__lists_spent_coins_without_messages() {
+    use fuel_core_client::client::types::TransactionStatus;
+    use fuel_core_types::fuel_asm::{
+        op,
+        RegId,
+    };
+
+    // Given
+    let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+    let owner = Input::predicate_owner(&predicate);
+    let mut coin_generator = CoinConfigGenerator::new();
+    let coins: Vec<_> = (0..3)
+        .map(|_| CoinConfig {
+            owner,
+            amount: 1000,
+            asset_id: AssetId::BASE,
+            ..coin_generator.generate()
+        })
+        .collect();
+    let message = MessageConfig {
+        sender: owner,
+        recipient: owner,
+        nonce: 0u64.into(),
+        amount: 1000,
+        data: vec![],
+        da_height: Default::default(),
+    };
+    let state = StateConfig {
+        coins: coins.clone(),
+        messages: vec![message.clone()],
+        ..Default::default()
+    };
+    let srv = FuelService::new_node(Config::local_node_with_state_config(state))
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let spent = [&coins[2], &coins[0]];
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.max_fee_limit(0);
+    for coin in spent {
+        builder.add_input(Input::coin_predicate(
+            coin.utxo_id(),
+            owner,
+            coin.amount,
+            coin.asset_id,
+            Default::default(),
+            Default::default(),
+            predicate.clone(),
+            vec![],
+        ));
+    }
+    builder.add_input(Input::message_coin_predicate(
+        message.sender,
+        message.recipient,
+        message.amount,
+        message.nonce,
+        Default::default(),
+        predicate.clone(),
+        vec![],
+    ));
+    builder.add_output(Output::change(owner, 0, AssetId::BASE));
+    let tx = builder.finalize_as_transaction();
+    let status = client
+        .submit_and_await_commit_opt(&tx, Some(true))
+        .await
+        .unwrap();
+    let TransactionStatus::Success { block_height, .. } = status else {
+        panic!("unexpected status: {status:?}");
+    };
+
+    // When
+    let spent_coins = client.spent_coins_at_height(block_height).await.unwrap();
+    let genesis_spent_coins = client.spent_coins_at_height(0u32.into()).await.unwrap();
+
+    // Then
+    assert_eq!(spent_coins, vec![coins[2].utxo_id(), coins[0].utxo_id()]);
+    assert!(genesis_spent_coins.is_empty());
+}
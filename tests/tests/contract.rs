@@ -30,7 +30,11 @@ use fuel_core_types::{
         *,
     },
 };
-use rand::SeedableRng;
+use rand::{
+    Rng,
+    SeedableRng,
+};
+use test_helpers::mint_contract;
 
 use fuel_core::chain_config::{
     CoinConfig,
@@ -436,3 +440,183 @@ async fn can_get_genesis_contract_salt() {
 
     assert_eq!(ret.salt, Salt::zeroed());
 }
+
+fn transfer_to_contract_tx(
+    rng: &mut rand::rngs::StdRng,
+    contract_id: ContractId,
+    amount: u64,
+) -> Transaction {
+    let script = [
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::addi(0x11, 0x10, ContractId::LEN as _),
+        op::movi(0x12, amount as _),
+        op::tr(0x10, 0x12, 0x11),
+        op::ret(RegId::ONE),
+    ];
+    let mut script_data = contract_id.to_vec();
+    script_data.extend(AssetId::BASE.to_bytes());
+
+    TransactionBuilder::script(script.into_iter().collect(), script_data)
+        .script_gas_limit(1_000_000)
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            u32::MAX as u64,
+            AssetId::BASE,
+            Default::default(),
+        )
+        .add_input(Input::contract(
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_output(Output::contract(1, Default::default(), Default::default()))
+        .finalize_as_transaction()
+}
+
+#[tokio::test]
+async fn contract_balances__index_matches_the_on_chain_state_after_contract_calls() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(SEED);
+    let node = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(node.bound_address);
+
+    // given
+    let (_, contract_id) = mint_contract::deploy(&client, &mut rng).await;
+    let sub_ids = [
+        Bytes32::new([3; 32]),
+        Bytes32::new([1; 32]),
+        Bytes32::new([2; 32]),
+    ];
+    for (i, sub_id) in sub_ids.iter().enumerate() {
+        for amount in [100, 250] {
+            mint_contract::mint(
+                &client,
+                &mut rng,
+                contract_id,
+                *sub_id,
+                amount * (i as u64 + 1),
+            )
+            .await;
+        }
+    }
+    let tx = transfer_to_contract_tx(&mut rng, contract_id, 500);
+    let status = client.submit_and_await_commit(&tx).await.unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+
+    // when
+    let indexed = client
+        .contract_balances(
+            &contract_id,
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap()
+        .results;
+
+    // then
+    let mut assets: Vec<_> = sub_ids
+        .iter()
+        .map(|sub_id| contract_id.asset_id(sub_id))
+        .chain([AssetId::BASE])
+        .collect();
+    assets.sort();
+    let mut on_chain = vec![];
+    for asset_id in assets {
+        let amount = client
+            .contract_balance(&contract_id, Some(&asset_id))
+            .await
+            .unwrap();
+        on_chain.push((asset_id, amount));
+    }
+    let indexed: Vec<_> = indexed
+        .into_iter()
+        .map(|balance| (balance.asset_id, balance.amount))
+        .collect();
+    assert_eq!(indexed, on_chain);
+    assert!(indexed.contains(&(AssetId::BASE, 500)));
+}
+
+#[tokio::test]
+async fn contracts__pages_through_the_contracts_deployed_across_blocks() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(SEED);
+    let node = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(node.bound_address);
+
+    // given
+    let mut deployed = vec![];
+    for _ in 0..3 {
+        deployed.push(mint_contract::deploy(&client, &mut rng).await);
+    }
+    let contract_ids: Vec<_> = deployed.iter().map(|(_, id)| *id).collect();
+
+    // when
+    let first_page = client
+        .contracts(
+            None,
+            None,
+            PaginationRequest {
+                cursor: None,
+                results: 2,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    let second_page = client
+        .contracts(
+            None,
+            None,
+            PaginationRequest {
+                cursor: first_page.cursor.clone(),
+                results: 2,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    let (middle_height, _) = deployed[1];
+    let in_range = client
+        .contracts(
+            Some(middle_height),
+            Some(middle_height),
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+
+    // then
+    assert!(first_page.has_next_page);
+    assert!(!second_page.has_next_page);
+    let paged: Vec<_> = first_page
+        .results
+        .iter()
+        .chain(second_page.results.iter())
+        .map(|contract| contract.contract_id)
+        .collect();
+    assert_eq!(paged, contract_ids);
+    for (contract, (height, _)) in first_page.results.iter().zip(&deployed) {
+        assert_eq!(contract.tx_pointer.block_height(), *height);
+        let on_chain = client
+            .contract(&contract.contract_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(contract.salt, on_chain.salt);
+    }
+    let in_range: Vec<_> = in_range
+        .results
+        .iter()
+        .map(|contract| contract.contract_id)
+        .collect();
+    assert_eq!(in_range, vec![contract_ids[1]]);
+}
@@ -3,6 +3,7 @@ use fuel_core::{
     chain_config::TESTNET_WALLET_SECRETS,
     fuel_core_graphql_api::{
         da_compression::{
+            da_compressed_block_checksum,
             DbTx,
             DecompressDbTx,
         },
@@ -15,6 +16,11 @@ use fuel_core::{
     },
 };
 use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    schema::da_compressed::DaBlockVerificationStatus,
     types::TransactionStatus,
     FuelClient,
 };
@@ -62,6 +68,7 @@ async fn can_fetch_da_compressed_block_from_graphql() {
     config.consensus_signer = SignMode::Key(Secret::new(poa_secret.into()));
     let compression_config = fuel_core_compression::Config {
         temporal_registry_retention: Duration::from_secs(3600),
+        registry_capacity: None,
     };
     config.da_compression = DaCompressionConfig::Enabled(compression_config);
     let chain_id = config
@@ -131,6 +138,155 @@ async fn can_fetch_da_compressed_block_from_graphql() {
     }
 }
 
+#[tokio::test]
+async fn verify_da_block__returns_verified_for_stored_block() {
+    let mut config = Config::local_node();
+    config.da_compression = DaCompressionConfig::Enabled(fuel_core_compression::Config {
+        temporal_registry_retention: Duration::from_secs(3600),
+        registry_capacity: None,
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // given
+    let block_height = client.produce_blocks(1, None).await.unwrap();
+    let block = client
+        .da_compressed_block(block_height)
+        .await
+        .unwrap()
+        .expect("Unable to get compressed block");
+
+    // when
+    let verification = client
+        .verify_da_block(block_height)
+        .await
+        .unwrap()
+        .expect("Unable to verify compressed block");
+
+    // then
+    let checksum = da_compressed_block_checksum(&block);
+    assert_eq!(verification.status, DaBlockVerificationStatus::Verified);
+    assert_eq!(verification.stored_checksum.map(|c| c.0), Some(checksum));
+    assert_eq!(verification.computed_checksum.0, checksum);
+}
+
+#[tokio::test]
+async fn verify_da_block__returns_none_for_unknown_height() {
+    let mut config = Config::local_node();
+    config.da_compression = DaCompressionConfig::Enabled(fuel_core_compression::Config {
+        temporal_registry_retention: Duration::from_secs(3600),
+        registry_capacity: None,
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // when
+    let verification = client.verify_da_block(100u32.into()).await.unwrap();
+
+    // then
+    assert!(verification.is_none());
+}
+
+#[tokio::test]
+async fn da_compressed_block_registrations__returns_none_only_for_unknown_block() {
+    let mut config = Config::local_node();
+    config.da_compression = DaCompressionConfig::Enabled(fuel_core_compression::Config {
+        temporal_registry_retention: Duration::from_secs(3600),
+        registry_capacity: None,
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // given
+    let block_height = client.produce_blocks(1, None).await.unwrap();
+    let request = PaginationRequest {
+        cursor: None,
+        results: 10,
+        direction: PageDirection::Forward,
+    };
+
+    // when
+    let known = client
+        .da_compressed_block_registrations(block_height, request.clone())
+        .await
+        .unwrap();
+    let unknown = client
+        .da_compressed_block_registrations(block_height.succ().unwrap(), request)
+        .await
+        .unwrap();
+
+    // then
+    assert!(known.is_some());
+    assert!(unknown.is_none());
+}
+
+#[tokio::test]
+async fn decompressed_da_block__returns_decoded_block() {
+    let mut config = Config::local_node();
+    config.da_compression = DaCompressionConfig::Enabled(fuel_core_compression::Config {
+        temporal_registry_retention: Duration::from_secs(3600),
+        registry_capacity: None,
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // given
+    let block_height = client.produce_blocks(1, None).await.unwrap();
+
+    // when
+    let block = client
+        .decompressed_da_block(block_height)
+        .await
+        .unwrap()
+        .expect("Unable to get decompressed block");
+
+    // then
+    assert_eq!(block.format_version.0, 0);
+    assert_eq!(block.height.0, u32::from(block_height));
+    // The block contains only the mint transaction.
+    assert_eq!(block.transaction_count.0, 1);
+    let json: serde_json::Value = serde_json::from_str(&block.json).unwrap();
+    assert!(json.is_object());
+}
+
+#[tokio::test]
+async fn da_compression_gaps__returns_heights_without_compressed_block() {
+    let mut config = Config::local_node();
+    config.da_compression = DaCompressionConfig::Enabled(fuel_core_compression::Config {
+        temporal_registry_retention: Duration::from_secs(3600),
+        registry_capacity: None,
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // given
+    let block_height = client.produce_blocks(3, None).await.unwrap();
+    assert_eq!(block_height, 3u32.into());
+
+    // when
+    let gaps = client
+        .da_compression_gaps(1u32.into(), 5u32.into())
+        .await
+        .unwrap();
+
+    // then
+    assert_eq!(gaps, vec![4u32.into(), 5u32.into()]);
+}
+
+#[tokio::test]
+async fn da_compression_gaps__fails_for_too_long_range() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // when
+    let result = client
+        .da_compression_gaps(0u32.into(), u32::MAX.into())
+        .await;
+
+    // then
+    assert!(result.is_err());
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn da_compressed_blocks_are_available_from_non_block_producing_nodes() {
     let mut rng = StdRng::seed_from_u64(line!() as u64);
@@ -142,6 +298,7 @@ async fn da_compressed_blocks_are_available_from_non_block_producing_nodes() {
     let mut config = Config::local_node();
     config.da_compression = DaCompressionConfig::Enabled(fuel_core_compression::Config {
         temporal_registry_retention: Duration::from_secs(3600),
+        registry_capacity: None,
     });
 
     let Nodes {
@@ -196,6 +196,60 @@ async fn latest_gas_price__for_single_block_should_be_starting_gas_price() {
     assert_eq!(expected, actual)
 }
 
+#[tokio::test]
+async fn fees_at_height__splits_the_collected_fees_into_the_base_fee_and_the_tip() {
+    // given
+    let mut node_config = Config::local_node();
+    node_config.gas_price_config.starting_exec_gas_price = 1000;
+    node_config.block_production = Trigger::Never;
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let mut rng = rand::rngs::StdRng::seed_from_u64(2322u64);
+    let tip = 12345;
+    let script = vec![op::ret(RegId::ONE)];
+    let script_bytes = script.iter().flat_map(|op| op.to_bytes()).collect();
+    let mut builder = TransactionBuilder::script(script_bytes, vec![]);
+    let asset_id = *builder.get_params().base_asset_id();
+    let tx: Transaction = builder
+        .max_fee_limit(u32::MAX as u64)
+        .tip(tip)
+        .script_gas_limit(22430)
+        .add_unsigned_coin_input(
+            SecretKey::random(&mut rng),
+            rng.gen(),
+            u32::MAX as u64,
+            asset_id,
+            Default::default(),
+        )
+        .finalize()
+        .into();
+    client.submit(&tx).await.unwrap();
+
+    // when
+    let height = client.produce_blocks(1, None).await.unwrap();
+    let fees = client.fees_at_height(height).await.unwrap().unwrap();
+    let total_fees = client.total_fees_collected().await.unwrap();
+
+    // then
+    assert_eq!(fees.tip, tip);
+    assert!(fees.base_fee > 0);
+    assert_eq!(fees.total, fees.base_fee + fees.tip);
+    assert_eq!(total_fees, fees);
+}
+
+#[tokio::test]
+async fn fees_at_height__returns_none_for_unknown_height() {
+    // given
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // when
+    let fees = client.fees_at_height(100u32.into()).await.unwrap();
+
+    // then
+    assert!(fees.is_none());
+}
+
 #[tokio::test]
 async fn produce_block__raises_gas_price() {
     // given
@@ -8,7 +8,10 @@ use fuel_core::{
     state::rocks_db::DatabaseConfig,
     types::fuel_tx::Transaction,
 };
-use fuel_core_client::client::FuelClient;
+use fuel_core_client::client::{
+    schema::node_info::SubsystemStatus,
+    FuelClient,
+};
 
 #[tokio::test]
 async fn health() {
@@ -21,6 +24,75 @@ async fn health() {
     assert!(health);
 }
 
+#[tokio::test]
+async fn ready__reports_ready_while_offchain_lag_is_within_tolerance() {
+    // Given
+    let mut config = Config::local_node();
+    config.graphql_config.ready_when_offchain_synced = Some(5);
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(3, None).await.unwrap();
+
+    // When
+    let response = reqwest::get(format!("http://{}/v1/ready", srv.bound_address))
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    let body: serde_json::Value =
+        serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(body["ready"], serde_json::Value::Bool(true));
+    assert_eq!(body["on_chain_height"], serde_json::json!(3));
+}
+
+#[tokio::test]
+async fn node_health__reports_the_subsystems_following_the_tip_as_ok() {
+    // Given
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(3, None).await.unwrap();
+
+    // When
+    let health = client.node_health().await.unwrap();
+
+    // Then
+    assert_eq!(health.status, SubsystemStatus::Ok);
+    assert_eq!(health.on_chain.status, SubsystemStatus::Ok);
+    assert_eq!(health.on_chain.height, Some(3.into()));
+    assert_eq!(health.off_chain.status, SubsystemStatus::Ok);
+    assert_eq!(health.txpool.status, SubsystemStatus::Ok);
+    assert_eq!(health.indexation.status, SubsystemStatus::Ok);
+    assert_eq!(health.indexation.dead_letter_height, None);
+}
+
+#[tokio::test]
+async fn health__reports_the_status_of_the_subsystems() {
+    // Given
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(2, None).await.unwrap();
+
+    // When
+    let response = reqwest::get(format!("http://{}/v1/health", srv.bound_address))
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    let body: serde_json::Value =
+        serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(body["up"], serde_json::Value::Bool(true));
+    assert_eq!(body["health"]["status"], serde_json::json!("ok"));
+    assert_eq!(body["health"]["on_chain"]["height"], serde_json::json!(2));
+    assert_eq!(
+        body["health"]["off_chain"]["status"],
+        serde_json::json!("ok")
+    );
+}
+
 #[cfg(feature = "default")]
 #[tokio::test]
 async fn can_restart_node() {
@@ -47,12 +47,16 @@ mod metrics;
 #[cfg(not(feature = "only-p2p"))]
 mod node_info;
 #[cfg(not(feature = "only-p2p"))]
+mod offline_indexer;
+#[cfg(not(feature = "only-p2p"))]
 mod poa;
 #[cfg(not(feature = "only-p2p"))]
 mod recovery;
 #[cfg(not(feature = "only-p2p"))]
 mod regenesis;
 #[cfg(not(feature = "only-p2p"))]
+mod reindex;
+#[cfg(not(feature = "only-p2p"))]
 mod relayer;
 #[cfg(not(feature = "only-p2p"))]
 mod required_fuel_block_height_extension;
@@ -19,6 +19,7 @@ use fuel_core_client::client::{
             RequiredBalance,
         },
         message::MessageStatus,
+        MessageSpentStatus,
         TransactionStatus,
     },
     FuelClient,
@@ -48,6 +49,7 @@ use test_helpers::{
     },
     config_with_fee,
     default_signing_wallet,
+    send_graph_ql_query,
 };
 
 mod relayer;
@@ -179,6 +181,275 @@ async fn messages_by_owner_returns_messages_for_the_given_owner() {
     assert_eq!(result.results.len(), 0);
 }
 
+#[tokio::test]
+async fn messages__max_bytes_ends_the_page_before_the_first_count() {
+    // given
+    let owner = Address::new([1; 32]);
+    let messages = (1..=5u64).map(|nonce| MessageConfig {
+        recipient: owner,
+        nonce: nonce.into(),
+        data: vec![7; 1000],
+        ..Default::default()
+    });
+    let srv = FuelService::new_node(setup_config(messages)).await.unwrap();
+    let url = format!("http://{}/v1/graphql", srv.bound_address);
+    let query = |after: &str| {
+        format!(
+            r#"{{ messages(owner: "{owner:#x}", first: 5, {after} maxBytes: "5000") {{
+                edges {{ cursor }} pageInfo {{ hasNextPage endCursor }}
+            }} }}"#
+        )
+    };
+
+    // when
+    let first_page = send_graph_ql_query(&url, &query("")).await;
+    let first_page: serde_json::Value = serde_json::from_str(&first_page).unwrap();
+    let end_cursor = first_page["data"]["messages"]["pageInfo"]["endCursor"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let second_page =
+        send_graph_ql_query(&url, &query(&format!(r#"after: "{end_cursor}","#))).await;
+    let second_page: serde_json::Value = serde_json::from_str(&second_page).unwrap();
+
+    // then
+    let first_edges = first_page["data"]["messages"]["edges"].as_array().unwrap();
+    let second_edges = second_page["data"]["messages"]["edges"].as_array().unwrap();
+    // Every message with 1000 bytes of data takes more than 2000 bytes of the budget.
+    assert_eq!(first_edges.len(), 2);
+    assert_eq!(
+        first_page["data"]["messages"]["pageInfo"]["hasNextPage"],
+        serde_json::Value::Bool(true)
+    );
+    assert_eq!(second_edges.len(), 2);
+    assert_ne!(first_edges[1]["cursor"], second_edges[0]["cursor"]);
+}
+
+#[tokio::test]
+async fn messages_by_sender__returns_messages_of_the_sender_page_by_page() {
+    // Given
+    let sender = Address::new([1; 32]);
+    let other_sender = Address::new([2; 32]);
+    let messages = (1..=3u64)
+        .map(|nonce| MessageConfig {
+            sender,
+            recipient: Address::new([3; 32]),
+            nonce: nonce.into(),
+            ..Default::default()
+        })
+        .chain(std::iter::once(MessageConfig {
+            sender: other_sender,
+            nonce: 4.into(),
+            ..Default::default()
+        }));
+    let srv = FuelService::new_node(setup_config(messages)).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let first_page = client
+        .messages_by_sender(
+            &sender,
+            PaginationRequest {
+                cursor: None,
+                results: 2,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    let second_page = client
+        .messages_by_sender(
+            &sender,
+            PaginationRequest {
+                cursor: first_page.cursor.clone(),
+                results: 2,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+
+    // Then
+    let nonces = first_page
+        .results
+        .iter()
+        .chain(second_page.results.iter())
+        .map(|message| message.nonce)
+        .collect_vec();
+    assert_eq!(nonces, vec![1.into(), 2.into(), 3.into()]);
+    assert!(first_page.has_next_page);
+    assert!(!second_page.has_next_page);
+    assert!(first_page
+        .results
+        .iter()
+        .all(|message| message.sender == sender));
+}
+
+const MESSAGE_SENDER: Address = Address::new([3; 32]);
+const MESSAGE_AMOUNT: u64 = 1_000;
+
+/// Starts the node with the messages `1..=count` of the `owner`.
+async fn node_with_owned_messages(owner: Address, count: u64) -> FuelService {
+    let messages = (1..=count).map(|nonce| MessageConfig {
+        sender: MESSAGE_SENDER,
+        recipient: owner,
+        nonce: nonce.into(),
+        amount: MESSAGE_AMOUNT,
+        ..Default::default()
+    });
+    FuelService::new_node(setup_config(messages)).await.unwrap()
+}
+
+/// Spends the messages of the `owner` with the `nonces` in one transaction.
+async fn spend_messages(client: &FuelClient, owner: Address, nonces: &[u64]) -> TxId {
+    let inputs = nonces
+        .iter()
+        .map(|nonce| {
+            Input::message_coin_signed(
+                MESSAGE_SENDER,
+                owner,
+                MESSAGE_AMOUNT,
+                (*nonce).into(),
+                Default::default(),
+            )
+        })
+        .collect_vec();
+    let amount = MESSAGE_AMOUNT * nonces.len() as u64;
+    let output = Output::coin(Address::from([2; 32]), amount, Default::default());
+    let tx: Transaction = Transaction::script(
+        1_000_000,
+        vec![],
+        vec![],
+        policies::Policies::new().with_max_fee(0),
+        inputs,
+        vec![output],
+        vec![Vec::new().into()],
+    )
+    .into();
+    client.submit_and_await_commit(&tx).await.unwrap();
+    tx.id(&ChainId::default())
+}
+
+#[tokio::test]
+async fn messages_by_owner__skips_the_spent_messages_by_default() {
+    // Given
+    let owner = Address::from([1; 32]);
+    let srv = node_with_owned_messages(owner, 4).await;
+    let client = FuelClient::from(srv.bound_address);
+    spend_messages(&client, owner, &[2, 3]).await;
+
+    // When
+    let messages = client
+        .messages(
+            Some(&owner),
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+
+    // Then
+    let nonces = messages
+        .results
+        .iter()
+        .map(|message| message.nonce)
+        .collect_vec();
+    assert_eq!(nonces, vec![1.into(), 4.into()]);
+    assert!(messages
+        .results
+        .iter()
+        .all(|message| message.spent_status.is_none()));
+}
+
+#[tokio::test]
+async fn messages_by_owner__include_spent_returns_the_spent_status() {
+    // Given
+    let owner = Address::from([1; 32]);
+    let srv = node_with_owned_messages(owner, 4).await;
+    let client = FuelClient::from(srv.bound_address);
+    let tx_id = spend_messages(&client, owner, &[2, 3]).await;
+    let block_height: u32 = client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height;
+
+    // When
+    let messages = client
+        .messages_including_spent(
+            &owner,
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+
+    // Then
+    let statuses = messages
+        .results
+        .iter()
+        .map(|message| (message.nonce, message.spent_status))
+        .collect_vec();
+    let spent = Some(MessageSpentStatus {
+        block_height,
+        transaction_id: Some(tx_id),
+    });
+    assert_eq!(
+        statuses,
+        vec![
+            (1.into(), None),
+            (2.into(), spent),
+            (3.into(), spent),
+            (4.into(), None),
+        ]
+    );
+    let spent_message = &messages.results[1];
+    assert_eq!(spent_message.sender, MESSAGE_SENDER);
+    assert_eq!(spent_message.amount, MESSAGE_AMOUNT);
+}
+
+#[tokio::test]
+async fn messages_by_owner__include_spent_paginates_across_mixed_entries() {
+    // Given
+    let owner = Address::from([1; 32]);
+    let srv = node_with_owned_messages(owner, 5).await;
+    let client = FuelClient::from(srv.bound_address);
+    spend_messages(&client, owner, &[2, 4]).await;
+
+    // When
+    let mut nonces = vec![];
+    let mut cursor = None;
+    loop {
+        let page = client
+            .messages_including_spent(
+                &owner,
+                PaginationRequest {
+                    cursor,
+                    results: 2,
+                    direction: PageDirection::Forward,
+                },
+            )
+            .await
+            .unwrap();
+        nonces.extend(page.results.iter().map(|message| message.nonce));
+        if !page.has_next_page {
+            break
+        }
+        cursor = page.cursor;
+    }
+
+    // Then
+    assert_eq!(nonces, (1..=5u64).map(Into::into).collect_vec());
+}
+
 #[rstest]
 #[tokio::test]
 async fn messages_empty_results_for_owner_with_no_messages(
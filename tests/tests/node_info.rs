@@ -1,15 +1,47 @@
 #![allow(non_snake_case)]
 
-use fuel_core::service::{
-    Config,
-    FuelService,
+use fuel_core::{
+    chain_config::{
+        coin_config_helpers::CoinConfigGenerator,
+        CoinConfig,
+        StateConfig,
+    },
+    fuel_core_graphql_api::{
+        storage::{
+            coins::OwnedCoinsLayout,
+            da_compression::versioned_block_codec::DaBlockEncoding,
+            Column,
+        },
+        worker_service::DaCompressionConfig,
+    },
+    service::{
+        Config,
+        FuelService,
+    },
 };
 use fuel_core_client::client::{
     types::NodeInfo,
     FuelClient,
 };
 use fuel_core_poa::Trigger;
-use fuel_core_types::fuel_tx::Transaction;
+use fuel_core_storage::kv_store::StorageColumn;
+use fuel_core_types::{
+    fuel_crypto::SecretKey,
+    fuel_tx::{
+        AssetId,
+        Input,
+        Output,
+        Transaction,
+        TransactionBuilder,
+        UniqueIdentifier,
+    },
+    tai64::Tai64,
+};
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+use std::time::Duration;
 
 #[tokio::test]
 async fn node_info() {
@@ -29,6 +61,185 @@ async fn node_info() {
     assert_eq!(max_tx, node_config.txpool.pool_limits.max_txs as u64);
 }
 
+#[tokio::test]
+async fn offchain_columns__returns_every_column_of_the_build() {
+    // Given
+    let mut node_config = Config::local_node();
+    node_config.debug = true;
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let columns = client.offchain_columns().await.unwrap();
+
+    // Then
+    let expected: Vec<_> = enum_iterator::all::<Column>()
+        .map(|column| (column.name(), column.id()))
+        .collect();
+    assert_eq!(columns, expected);
+}
+
+#[tokio::test]
+async fn offchain_columns__fails_when_debug_is_disabled() {
+    // Given
+    let mut node_config = Config::local_node();
+    node_config.debug = false;
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let result = client.offchain_columns().await;
+
+    // Then
+    let error = result.expect_err("Should fail without the debug feature");
+    assert!(error
+        .to_string()
+        .contains("The 'debug' feature is disabled"));
+}
+
+#[tokio::test]
+async fn statistic__returns_the_total_tx_count_after_the_block_is_indexed() {
+    // Given
+    let mut node_config = Config::local_node();
+    node_config.debug = true;
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let tx = Transaction::default_test_tx();
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    // When
+    let statistics = client.statistics().await.unwrap();
+    let tx_count = client.statistic("total_tx_count").await.unwrap();
+
+    // Then
+    assert!(statistics.contains(&"total_tx_count".to_string()));
+    // The script transaction and the mint transaction of the block.
+    assert_eq!(tx_count, Some(2));
+}
+
+#[tokio::test]
+async fn statistic__fails_for_the_unknown_name() {
+    // Given
+    let mut node_config = Config::local_node();
+    node_config.debug = true;
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let result = client.statistic("top_addresses_by_tx_count").await;
+
+    // Then
+    let error = result.expect_err("Should fail for the statistic without `u64` value");
+    assert!(error.to_string().contains("is unknown"));
+}
+
+#[tokio::test]
+async fn offchain_state_hash__is_the_same_for_nodes_at_the_same_height() {
+    // Given
+    let mut node_config = Config::local_node();
+    node_config.debug = true;
+    let first = FuelService::new_node(node_config.clone()).await.unwrap();
+    let second = FuelService::new_node(node_config).await.unwrap();
+    let first_client = FuelClient::from(first.bound_address);
+    let second_client = FuelClient::from(second.bound_address);
+
+    // When
+    let first_hash = first_client.offchain_state_hash().await.unwrap();
+    let second_hash = second_client.offchain_state_hash().await.unwrap();
+
+    // Then
+    assert_eq!(first_hash, second_hash);
+}
+
+#[tokio::test]
+async fn offchain_state_hash__is_the_same_for_nodes_with_different_storage_configs() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret = SecretKey::random(&mut rng);
+    let owner = Input::owner(&secret.public_key());
+    let coin = CoinConfig {
+        owner,
+        amount: 1_000_000,
+        asset_id: AssetId::BASE,
+        ..CoinConfigGenerator::new().generate()
+    };
+    let state = StateConfig {
+        coins: vec![coin.clone()],
+        ..Default::default()
+    };
+
+    // Given
+    let mut first_config = Config::local_node_with_state_config(state);
+    first_config.debug = true;
+    first_config.block_production = Trigger::Never;
+    first_config.da_compression =
+        DaCompressionConfig::Enabled(fuel_core_compression::Config {
+            temporal_registry_retention: Duration::from_secs(3600),
+            registry_capacity: None,
+        });
+    let mut second_config = first_config.clone();
+    second_config.graphql_config.owned_coins_layout = OwnedCoinsLayout::Sharded;
+    second_config.graphql_config.mark_spent_owned_coins = true;
+    second_config.da_compression_encoding = DaBlockEncoding::Zstd { level: 3 };
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(10_000)
+        .add_unsigned_coin_input(
+            secret,
+            coin.utxo_id(),
+            coin.amount,
+            coin.asset_id,
+            Default::default(),
+        )
+        .add_output(Output::change(owner, 0, coin.asset_id))
+        .finalize_as_transaction();
+    let chain_id = first_config
+        .snapshot_reader
+        .chain_config()
+        .consensus_parameters
+        .chain_id();
+    let tx_id = tx.id(&chain_id);
+    let start_timestamp = Tai64::now().0;
+    let first = FuelService::new_node(first_config).await.unwrap();
+    let second = FuelService::new_node(second_config).await.unwrap();
+
+    // When
+    let mut hashes = vec![];
+    for srv in [&first, &second] {
+        let client = FuelClient::from(srv.bound_address);
+        client.submit(&tx).await.unwrap();
+        let height = client
+            .produce_blocks(1, Some(start_timestamp))
+            .await
+            .unwrap();
+        client.await_transaction_commit(&tx_id).await.unwrap();
+        // The blocks are compressed by their own service after the indexation.
+        while client.da_compressed_block(height).await.unwrap().is_none() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        hashes.push(client.offchain_state_hash().await.unwrap());
+    }
+
+    // Then
+    assert_eq!(hashes[0], hashes[1]);
+}
+
+#[tokio::test]
+async fn offchain_state_hash__changes_when_the_block_is_indexed() {
+    // Given
+    let mut node_config = Config::local_node();
+    node_config.debug = true;
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let hash_before = client.offchain_state_hash().await.unwrap();
+
+    // When
+    let tx = Transaction::default_test_tx();
+    client.submit_and_await_commit(&tx).await.unwrap();
+    let hash_after = client.offchain_state_hash().await.unwrap();
+
+    // Then
+    assert_ne!(hash_before, hash_after);
+}
+
 #[tokio::test]
 async fn tx_pool_stats__should_be_updated_when_transaction_is_submitted() {
     // Given
@@ -0,0 +1,223 @@
+#![allow(non_snake_case)]
+
+use fuel_core::{
+    combined_database::CombinedDatabase,
+    service::{
+        offline_indexer::{
+            read_blocks,
+            write_blocks,
+            OfflineIndexer,
+        },
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    FuelClient,
+};
+use fuel_core_storage::transactional::AtomicView;
+use fuel_core_types::{
+    blockchain::SealedBlock,
+    fuel_tx::{
+        Address,
+        AssetId,
+        Output,
+        TransactionBuilder,
+    },
+    fuel_types::BlockHeight,
+    fuel_vm::SecretKey,
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+use std::fs::File;
+
+const TOTAL_BLOCKS: u32 = 100;
+const TRANSFERS: u32 = 10;
+
+fn all_results() -> PaginationRequest<String> {
+    PaginationRequest {
+        cursor: None,
+        results: 100,
+        direction: PageDirection::Forward,
+    }
+}
+
+/// Produces `TOTAL_BLOCKS` blocks, where the first blocks contain transfers to the `owner`.
+async fn produce_blocks(client: &FuelClient, owner: Address, rng: &mut StdRng) {
+    for amount in 1..=TRANSFERS {
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .script_gas_limit(10_000)
+            .add_unsigned_coin_input(
+                SecretKey::random(rng),
+                rng.gen(),
+                amount.into(),
+                AssetId::BASE,
+                Default::default(),
+            )
+            .add_output(Output::coin(owner, amount.into(), AssetId::BASE))
+            .finalize_as_transaction();
+        client.submit_and_await_commit(&tx).await.unwrap();
+    }
+    client
+        .produce_blocks(TOTAL_BLOCKS - TRANSFERS, None)
+        .await
+        .unwrap();
+}
+
+fn export_blocks(node: &FuelService) -> Vec<SealedBlock> {
+    let view = node.shared.database.on_chain().latest_view().unwrap();
+    (1..=TOTAL_BLOCKS)
+        .map(|height| {
+            view.get_sealed_block_by_height(&height.into())
+                .unwrap()
+                .unwrap()
+        })
+        .collect()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn offline_indexer__indexed_database_answers_as_live_node() {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let owner: Address = rng.gen();
+
+    // given
+    let live_node = FuelService::new_node(Config::local_node()).await.unwrap();
+    let live_client = FuelClient::from(live_node.bound_address);
+    produce_blocks(&live_client, owner, &mut rng).await;
+    let blocks = export_blocks(&live_node);
+    let export = tempfile::tempdir().unwrap();
+    let (first, second) = blocks.split_at(40);
+    write_blocks(
+        File::create(export.path().join("0001.blocks")).unwrap(),
+        first,
+    )
+    .unwrap();
+    write_blocks(
+        File::create(export.path().join("0002.blocks")).unwrap(),
+        second,
+    )
+    .unwrap();
+
+    // when
+    let database = CombinedDatabase::default();
+    let mut indexer = OfflineIndexer::new(Config::local_node(), database.clone())
+        .await
+        .unwrap();
+    let report = indexer
+        .index(read_blocks(export.path()).unwrap())
+        .await
+        .unwrap();
+    drop(indexer);
+
+    // then
+    assert_eq!(report.indexed_blocks, u64::from(TOTAL_BLOCKS));
+    let api_node = FuelService::from_combined_database(database, Config::local_node())
+        .await
+        .unwrap();
+    let api_client = FuelClient::from(api_node.bound_address);
+
+    let live_chain = live_client.chain_info().await.unwrap();
+    let api_chain = api_client.chain_info().await.unwrap();
+    assert_eq!(api_chain.latest_block, live_chain.latest_block);
+    for height in [1, TRANSFERS, TOTAL_BLOCKS] {
+        let height = BlockHeight::from(height);
+        assert_eq!(
+            api_client.block_by_height(height).await.unwrap(),
+            live_client.block_by_height(height).await.unwrap()
+        );
+    }
+    assert_eq!(
+        api_client.balance(&owner, None).await.unwrap(),
+        live_client.balance(&owner, None).await.unwrap()
+    );
+    assert_eq!(
+        api_client
+            .coins(&owner, None, all_results())
+            .await
+            .unwrap()
+            .results,
+        live_client
+            .coins(&owner, None, all_results())
+            .await
+            .unwrap()
+            .results
+    );
+    let api_transactions = api_client
+        .transactions_by_owner(&owner, all_results())
+        .await
+        .unwrap()
+        .results;
+    assert_eq!(api_transactions.len(), usize::try_from(TRANSFERS).unwrap());
+    assert_eq!(
+        api_transactions.len(),
+        live_client
+            .transactions_by_owner(&owner, all_results())
+            .await
+            .unwrap()
+            .results
+            .len()
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn offline_indexer__resumes_from_last_indexed_height() {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let owner: Address = rng.gen();
+    let live_node = FuelService::new_node(Config::local_node()).await.unwrap();
+    let live_client = FuelClient::from(live_node.bound_address);
+    produce_blocks(&live_client, owner, &mut rng).await;
+    let blocks = export_blocks(&live_node);
+
+    // given
+    let database = CombinedDatabase::default();
+    let mut indexer = OfflineIndexer::new(Config::local_node(), database.clone())
+        .await
+        .unwrap();
+    indexer
+        .index(blocks[..30].iter().cloned().map(Ok))
+        .await
+        .unwrap();
+    drop(indexer);
+
+    // when
+    let mut indexer = OfflineIndexer::new(Config::local_node(), database)
+        .await
+        .unwrap();
+    let report = indexer.index(blocks.iter().cloned().map(Ok)).await.unwrap();
+
+    // then
+    assert_eq!(report.skipped_blocks, 30);
+    assert_eq!(report.indexed_blocks, u64::from(TOTAL_BLOCKS) - 30);
+    assert_eq!(indexer.indexed_height(), BlockHeight::from(TOTAL_BLOCKS));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn offline_indexer__fails_on_gap_in_blocks() {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let owner: Address = rng.gen();
+    let live_node = FuelService::new_node(Config::local_node()).await.unwrap();
+    let live_client = FuelClient::from(live_node.bound_address);
+    produce_blocks(&live_client, owner, &mut rng).await;
+    let mut blocks = export_blocks(&live_node);
+
+    // given
+    blocks.remove(20);
+    let mut indexer = OfflineIndexer::new(Config::local_node(), Default::default())
+        .await
+        .unwrap();
+
+    // when
+    let result = indexer.index(blocks.into_iter().map(Ok)).await;
+
+    // then
+    let err = result.expect_err("The gap must be detected");
+    assert!(err.to_string().contains("not contiguous"), "{err}");
+    assert_eq!(indexer.indexed_height(), BlockHeight::from(20));
+}
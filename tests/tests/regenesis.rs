@@ -1,10 +1,44 @@
 use clap::Parser;
-use fuel_core::chain_config::{
-    ChainConfig,
-    ConsensusConfig,
-    PoAV2,
-    SnapshotWriter,
-    StateConfig,
+use fuel_core::{
+    chain_config::{
+        ChainConfig,
+        ConsensusConfig,
+        PoAV2,
+        SnapshotMetadata,
+        SnapshotReader,
+        SnapshotVersion,
+        SnapshotWriter,
+        StateConfig,
+        TableEncoding,
+        ZstdCompressionLevel,
+    },
+    combined_database::CombinedDatabase,
+    database::{
+        database_description::off_chain::OffChain,
+        Database,
+    },
+    fuel_core_graphql_api::storage::{
+        balances::{
+            CoinBalances,
+            MessageBalances,
+        },
+        blocks::FuelBlockIdsToHeights,
+        coins::{
+            CoinsToSpendIndex,
+            OwnedCoins,
+        },
+        messages::OwnedMessageIds,
+        transactions::{
+            OwnedTransactions,
+            TransactionStatuses,
+        },
+    },
+    service::genesis::{
+        import_offchain_snapshot,
+        Exporter,
+        OffChainSnapshotImportMode,
+    },
+    ShutdownListener,
 };
 use fuel_core_bin::cli::snapshot;
 use fuel_core_client::client::{
@@ -14,11 +48,27 @@ use fuel_core_client::client::{
     },
     types::{
         message::MessageStatus,
+        RejectionReason,
         TransactionStatus,
+        TransactionType,
     },
 };
+use fuel_core_storage::{
+    iter::{
+        IterableTable,
+        IteratorOverTable,
+    },
+    kv_store::StorageColumn,
+    structured_storage::TableWithBlueprint,
+    tables::FuelBlocks,
+    Mappable,
+    StorageAsMut,
+};
 use fuel_core_types::{
-    blockchain::header::LATEST_STATE_TRANSITION_VERSION,
+    blockchain::{
+        block::CompressedBlock,
+        header::LATEST_STATE_TRANSITION_VERSION,
+    },
     fuel_asm::{
         op,
         GTFArgs,
@@ -28,6 +78,10 @@ use fuel_core_types::{
     fuel_merkle::binary,
     fuel_tx::*,
     fuel_vm::*,
+    services::txpool::{
+        RejectionReason as TxPoolRejectionReason,
+        TransactionExecutionStatus,
+    },
 };
 use itertools::Itertools;
 use rand::{
@@ -47,6 +101,7 @@ use tempfile::{
 use test_helpers::{
     fuel_core_driver::FuelCoreDriver,
     produce_block_with_tx,
+    send_graph_ql_query,
 };
 
 async fn take_snapshot(db_dir: &TempDir, snapshot_dir: &TempDir) -> anyhow::Result<()> {
@@ -64,6 +119,35 @@ async fn take_snapshot(db_dir: &TempDir, snapshot_dir: &TempDir) -> anyhow::Resu
     Ok(())
 }
 
+async fn take_snapshot_with_off_chain_indexes(
+    db_dir: &TempDir,
+    snapshot_dir: &TempDir,
+) -> anyhow::Result<()> {
+    snapshot::exec(snapshot::Command::parse_from([
+        "_IGNORED_",
+        "--db-path",
+        db_dir.path().to_str().unwrap(),
+        "--output-directory",
+        snapshot_dir.path().to_str().unwrap(),
+        "everything",
+        "--include-off-chain-indexes",
+        "encoding",
+        "parquet",
+    ]))
+    .await?;
+    Ok(())
+}
+
+fn off_chain_entries<T>(db: &Database<OffChain>) -> Vec<(T::OwnedKey, T::OwnedValue)>
+where
+    T: Mappable,
+    Database<OffChain>: IterableTable<T>,
+{
+    db.iter_all::<T>(None)
+        .try_collect()
+        .expect("Failed to read the off-chain table")
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_regenesis_old_blocks_are_preserved() -> anyhow::Result<()> {
     const BLOCKS_QUERY: i32 = 50;
@@ -180,6 +264,61 @@ async fn test_regenesis_old_blocks_are_preserved() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn block_headers__returns_headers_before_and_after_regenesis() -> anyhow::Result<()>
+{
+    let mut rng = StdRng::seed_from_u64(1234);
+
+    // given
+    let core =
+        FuelCoreDriver::spawn_feeless(&["--debug", "--poa-instant", "true"]).await?;
+    produce_block_with_tx(&mut rng, &core.client).await;
+    produce_block_with_tx(&mut rng, &core.client).await;
+    let db_dir = core.kill().await;
+    let snapshot_dir = tempdir().expect("Failed to create temp dir");
+    take_snapshot(&db_dir, &snapshot_dir)
+        .await
+        .expect("Failed to take snapshot");
+    let core = FuelCoreDriver::spawn_feeless(&[
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--snapshot",
+        snapshot_dir.path().to_str().unwrap(),
+    ])
+    .await?;
+    produce_block_with_tx(&mut rng, &core.client).await;
+    let blocks = core
+        .client
+        .blocks(PaginationRequest {
+            cursor: None,
+            results: 10,
+            direction: PageDirection::Forward,
+        })
+        .await
+        .expect("Failed to get blocks")
+        .results;
+    // Three blocks before regenesis, the new genesis block and one new block.
+    assert_eq!(blocks.len(), 5);
+
+    // when
+    let headers = core
+        .client
+        .block_headers(1u32.into(), 3u32.into())
+        .await
+        .expect("Failed to get block headers");
+
+    // then
+    assert_eq!(headers.len(), 3);
+    for (header, block) in headers.iter().zip(&blocks[1..4]) {
+        assert_eq!(header.header, block.header);
+        assert_eq!(header.consensus, block.consensus);
+    }
+
+    core.kill().await;
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_regenesis_spent_messages_are_preserved() -> anyhow::Result<()> {
     let mut rng = StdRng::seed_from_u64(1234);
@@ -272,6 +411,279 @@ async fn test_regenesis_spent_messages_are_preserved() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_regenesis_with_off_chain_indexes_matches_recalculated_indexes(
+) -> anyhow::Result<()> {
+    let mut rng = StdRng::seed_from_u64(1234);
+
+    let core =
+        FuelCoreDriver::spawn_feeless(&["--debug", "--poa-instant", "true"]).await?;
+    // Add some blocks
+    produce_block_with_tx(&mut rng, &core.client).await;
+    produce_block_with_tx(&mut rng, &core.client).await;
+    produce_block_with_tx(&mut rng, &core.client).await;
+    // Stop the node, keep the db
+    let db_dir = core.kill().await;
+
+    // ------------------------- The genesis node is stopped -------------------------
+
+    // Take snapshots with and without the off-chain indexes
+    let snapshot_dir = tempdir().expect("Failed to create temp dir");
+    take_snapshot(&db_dir, &snapshot_dir)
+        .await
+        .expect("Failed to take snapshot");
+    let snapshot_with_indexes_dir = tempdir().expect("Failed to create temp dir");
+    take_snapshot_with_off_chain_indexes(&db_dir, &snapshot_with_indexes_dir)
+        .await
+        .expect("Failed to take snapshot with off-chain indexes");
+    let version = |dir: &TempDir| SnapshotMetadata::read(dir.path()).unwrap().version;
+    assert_eq!(version(&snapshot_dir), SnapshotVersion::V1);
+    assert_eq!(version(&snapshot_with_indexes_dir), SnapshotVersion::V2);
+
+    // ------------------------- Start nodes with the regenesis -------------------------
+
+    // The indexes are recalculated from the on-chain state
+    let core = FuelCoreDriver::spawn_feeless(&[
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--snapshot",
+        snapshot_dir.path().to_str().unwrap(),
+    ])
+    .await?;
+    let db = core.node.shared.database.off_chain();
+    let recalculated_owned_coins = off_chain_entries::<OwnedCoins>(db);
+    let recalculated_owned_messages = off_chain_entries::<OwnedMessageIds>(db);
+    let recalculated_coin_balances = off_chain_entries::<CoinBalances>(db);
+    let recalculated_message_balances = off_chain_entries::<MessageBalances>(db);
+    let recalculated_coins_to_spend = off_chain_entries::<CoinsToSpendIndex>(db);
+    core.kill().await;
+    assert!(!recalculated_owned_coins.is_empty());
+
+    // The indexes are imported from the snapshot
+    let core = FuelCoreDriver::spawn_feeless(&[
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--snapshot",
+        snapshot_with_indexes_dir.path().to_str().unwrap(),
+    ])
+    .await?;
+    let db = core.node.shared.database.off_chain();
+    assert_eq!(
+        off_chain_entries::<OwnedCoins>(db),
+        recalculated_owned_coins
+    );
+    assert_eq!(
+        off_chain_entries::<OwnedMessageIds>(db),
+        recalculated_owned_messages
+    );
+    assert_eq!(
+        off_chain_entries::<CoinBalances>(db),
+        recalculated_coin_balances
+    );
+    assert_eq!(
+        off_chain_entries::<MessageBalances>(db),
+        recalculated_message_balances
+    );
+    assert_eq!(
+        off_chain_entries::<CoinsToSpendIndex>(db),
+        recalculated_coins_to_spend
+    );
+
+    core.kill().await;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn export_offchain_snapshot_parallel__is_consistent_while_blocks_are_committed(
+) -> anyhow::Result<()> {
+    let mut rng = StdRng::seed_from_u64(1234);
+
+    let core =
+        FuelCoreDriver::spawn_feeless(&["--debug", "--poa-instant", "true"]).await?;
+    produce_block_with_tx(&mut rng, &core.client).await;
+
+    // The blocks are committed during the whole export
+    let client = core.client.clone();
+    let producer = tokio::spawn(async move {
+        let mut rng = StdRng::seed_from_u64(4321);
+        loop {
+            produce_block_with_tx(&mut rng, &client).await;
+        }
+    });
+
+    for _ in 0..5 {
+        // When
+        let snapshot_dir = tempdir()?;
+        let dir = snapshot_dir.path().to_path_buf();
+        let writer =
+            move || SnapshotWriter::parquet(dir.clone(), ZstdCompressionLevel::Level1);
+        Exporter::new(
+            core.node.shared.database.clone(),
+            ChainConfig::local_testnet(),
+            writer,
+            10,
+            ShutdownListener::spawn(),
+        )
+        .export_offchain_snapshot_parallel(4)
+        .await?;
+
+        // Then
+        let reader = SnapshotReader::open(SnapshotMetadata::read(snapshot_dir.path())?)?;
+        assert_eq!(reader.version(), SnapshotVersion::V2);
+        let statuses_height = reader
+            .read::<TransactionStatuses>()?
+            .into_iter()
+            .flatten_ok()
+            .filter_map_ok(|entry| match entry.value {
+                TransactionExecutionStatus::Success { block_height, .. }
+                | TransactionExecutionStatus::Failed { block_height, .. } => {
+                    Some(block_height)
+                }
+                _ => None,
+            })
+            .fold_ok(None, |max, height| max.max(Some(height)))?;
+        let owned_transactions_height = reader
+            .read::<OwnedTransactions>()?
+            .into_iter()
+            .flatten_ok()
+            .map_ok(|entry| entry.key.block_height)
+            .fold_ok(None, |max, height| max.max(Some(height)))?;
+        assert!(statuses_height.is_some());
+        assert_eq!(statuses_height, owned_transactions_height);
+    }
+
+    producer.abort();
+    core.kill().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn export_offchain_snapshot_parallel__fails_when_off_chain_database_lags(
+) -> anyhow::Result<()> {
+    // Given
+    let mut db = CombinedDatabase::in_memory();
+    for height in 0..=1u32 {
+        db.on_chain_mut()
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&height.into(), &CompressedBlock::default())
+            .unwrap();
+    }
+    db.off_chain_mut()
+        .storage_as_mut::<FuelBlockIdsToHeights>()
+        .insert(&Default::default(), &0u32.into())
+        .unwrap();
+    let snapshot_dir = tempdir()?;
+    let dir = snapshot_dir.path().to_path_buf();
+    let writer =
+        move || SnapshotWriter::parquet(dir.clone(), ZstdCompressionLevel::Level1);
+
+    // When
+    let result = Exporter::new(
+        db,
+        ChainConfig::local_testnet(),
+        writer,
+        10,
+        ShutdownListener::spawn(),
+    )
+    .export_offchain_snapshot_parallel(2)
+    .await;
+
+    // Then
+    let err = result.expect_err("The export should fail");
+    assert!(
+        err.to_string().contains("of the on-chain database"),
+        "{err}"
+    );
+    assert!(SnapshotMetadata::read(snapshot_dir.path()).is_err());
+    Ok(())
+}
+
+async fn take_off_chain_snapshot_with_corrupted_statuses(
+    rng: &mut StdRng,
+) -> anyhow::Result<TempDir> {
+    let core =
+        FuelCoreDriver::spawn_feeless(&["--debug", "--poa-instant", "true"]).await?;
+    produce_block_with_tx(rng, &core.client).await;
+    produce_block_with_tx(rng, &core.client).await;
+    let db_dir = core.kill().await;
+
+    let snapshot_dir = tempdir()?;
+    snapshot::exec(snapshot::Command::parse_from([
+        "_IGNORED_",
+        "--db-path",
+        db_dir.path().to_str().unwrap(),
+        "--output-directory",
+        snapshot_dir.path().to_str().unwrap(),
+        "off-chain",
+        "--threads",
+        "2",
+        "encoding",
+        "parquet",
+    ]))
+    .await?;
+
+    let TableEncoding::Parquet { tables, .. } =
+        SnapshotMetadata::read(snapshot_dir.path())?.table_encoding
+    else {
+        panic!("The off-chain snapshot is always encoded with parquet");
+    };
+    let statuses = &tables[&TransactionStatuses::column().name()];
+    std::fs::write(statuses, b"not a parquet file")?;
+
+    Ok(snapshot_dir)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn import_offchain_snapshot__strict_mode_fails_on_bad_table_without_writes(
+) -> anyhow::Result<()> {
+    // Given
+    let mut rng = StdRng::seed_from_u64(1234);
+    let snapshot_dir = take_off_chain_snapshot_with_corrupted_statuses(&mut rng).await?;
+    let mut db = Database::<OffChain>::in_memory();
+
+    // When
+    let result = import_offchain_snapshot(
+        &mut db,
+        snapshot_dir.path(),
+        OffChainSnapshotImportMode::Strict,
+    );
+
+    // Then
+    assert!(result.is_err());
+    assert!(off_chain_entries::<OwnedTransactions>(&db).is_empty());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn import_offchain_snapshot__lenient_mode_reports_bad_table_and_imports_the_rest(
+) -> anyhow::Result<()> {
+    // Given
+    let mut rng = StdRng::seed_from_u64(1234);
+    let snapshot_dir = take_off_chain_snapshot_with_corrupted_statuses(&mut rng).await?;
+    let mut db = Database::<OffChain>::in_memory();
+
+    // When
+    let report = import_offchain_snapshot(
+        &mut db,
+        snapshot_dir.path(),
+        OffChainSnapshotImportMode::Lenient,
+    )?;
+
+    // Then
+    assert!(!report.is_complete());
+    assert_eq!(report.skipped.len(), 1);
+    assert_eq!(
+        report.skipped[&TransactionStatuses::column().name()].len(),
+        1
+    );
+    assert!(off_chain_entries::<TransactionStatuses>(&db).is_empty());
+    assert!(!off_chain_entries::<OwnedTransactions>(&db).is_empty());
+    assert!(!off_chain_entries::<OwnedCoins>(&db).is_empty());
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_regenesis_processed_transactions_are_preserved() -> anyhow::Result<()> {
     let mut rng = StdRng::seed_from_u64(1234);
@@ -296,12 +708,20 @@ async fn test_regenesis_processed_transactions_are_preserved() -> anyhow::Result
         .finalize_as_transaction();
     core.client.submit_and_await_commit(&tx).await.unwrap();
 
-    let TransactionStatus::SqueezedOut { reason } =
-        core.client.submit_and_await_commit(&tx).await.unwrap()
+    let TransactionStatus::SqueezedOut {
+        reason,
+        rejection_reason,
+    } = core.client.submit_and_await_commit(&tx).await.unwrap()
     else {
         panic!("Expected transaction to be squeezed out")
     };
     assert!(reason.contains("Transaction id was already used"));
+    assert_eq!(
+        rejection_reason,
+        RejectionReason::Validity {
+            code: Some(TxPoolRejectionReason::SKIPPED_CODE)
+        }
+    );
 
     // Stop the node, keep the db
     let db_dir = core.kill().await;
@@ -326,7 +746,7 @@ async fn test_regenesis_processed_transactions_are_preserved() -> anyhow::Result
     ])
     .await?;
 
-    let TransactionStatus::SqueezedOut { reason } =
+    let TransactionStatus::SqueezedOut { reason, .. } =
         core.client.submit_and_await_commit(&tx).await.unwrap()
     else {
         panic!("Expected transaction to be squeezed out")
@@ -340,6 +760,83 @@ async fn test_regenesis_processed_transactions_are_preserved() -> anyhow::Result
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn oldest_retained_block__returns_the_pre_regenesis_data() -> anyhow::Result<()> {
+    use fuel_core_types::fuel_types::canonical::Deserialize;
+
+    let mut rng = StdRng::seed_from_u64(1234);
+    let core =
+        FuelCoreDriver::spawn_feeless(&["--debug", "--poa-instant", "true"]).await?;
+    assert!(core.client.oldest_retained_block().await?.is_none());
+
+    // Given
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .add_unsigned_coin_input(
+            SecretKey::random(&mut rng),
+            rng.gen(),
+            1234,
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::change(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        ))
+        .finalize_as_transaction();
+    let tx_id = tx.id(&ChainId::default());
+    core.client.submit_and_await_commit(&tx).await.unwrap();
+    let db_dir = core.kill().await;
+    let snapshot_dir = tempdir().expect("Failed to create temp dir");
+    take_snapshot(&db_dir, &snapshot_dir)
+        .await
+        .expect("Failed to take first snapshot");
+    let core = FuelCoreDriver::spawn_feeless(&[
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--snapshot",
+        snapshot_dir.path().to_str().unwrap(),
+    ])
+    .await?;
+    let genesis_height = core.client.chain_info().await?.latest_block.header.height;
+    let url = format!("http://{}/v1/graphql", core.node.bound_address);
+    let query = format!(
+        r#"{{ oldTransaction(txId: "{tx_id:#x}") {{
+            rawPayload size transaction {{ rawPayload size }}
+        }} }}"#
+    );
+
+    // When
+    let oldest_block = core.client.oldest_retained_block().await?;
+    let old_transaction = core.client.old_transaction(&tx_id).await?;
+    let unknown_transaction = core.client.old_transaction(&rng.gen()).await?;
+    let raw_old_transaction = send_graph_ql_query(&url, &query).await;
+
+    // Then
+    let oldest_block = oldest_block.expect("The old blocks are retained");
+    assert_eq!(oldest_block.header.height, 0);
+    assert_eq!(oldest_block.regenesis_height, genesis_height);
+    let old_transaction = old_transaction.expect("The old transactions are retained");
+    assert_eq!(old_transaction.id, tx_id);
+    assert_eq!(old_transaction.transaction, TransactionType::Known(tx));
+    assert_eq!(old_transaction.regenesis_height, genesis_height.into());
+    assert!(unknown_transaction.is_none());
+    let raw_old_transaction: serde_json::Value =
+        serde_json::from_str(&raw_old_transaction)?;
+    let raw_old_transaction = &raw_old_transaction["data"]["oldTransaction"];
+    for returned in [raw_old_transaction, &raw_old_transaction["transaction"]] {
+        let raw_payload = returned["rawPayload"].as_str().unwrap();
+        let bytes = hex::decode(raw_payload.trim_start_matches("0x"))?;
+        let decoded = Transaction::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.id(&ChainId::default()), tx_id);
+        assert_eq!(returned["size"].as_str().unwrap(), bytes.len().to_string());
+    }
+
+    core.kill().await;
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_regenesis_message_proofs_are_preserved() -> anyhow::Result<()> {
     let mut rng = StdRng::seed_from_u64(1234);
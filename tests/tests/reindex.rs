@@ -0,0 +1,354 @@
+#![allow(non_snake_case)]
+
+use fuel_core::{
+    combined_database::CombinedDatabase,
+    database::database_description::IndexationKind,
+    fuel_core_graphql_api::storage::Column,
+    service::{
+        reindex::reindex,
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    types::CoinType,
+    FuelClient,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        Address,
+        AssetId,
+        Output,
+        TransactionBuilder,
+        UtxoId,
+    },
+    fuel_vm::SecretKey,
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+use test_helpers::{
+    mint_contract,
+    send_graph_ql_query,
+};
+
+const TRANSFERS: u64 = 10;
+
+/// The sum of the amounts transferred by [`transfer_coins`].
+const TRANSFERRED: u64 = TRANSFERS * (TRANSFERS + 1) / 2;
+
+/// Transfers coins of the amounts from 1 to `TRANSFERS` to the `owner`.
+async fn transfer_coins(client: &FuelClient, owner: Address, rng: &mut StdRng) {
+    for amount in 1..=TRANSFERS {
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .script_gas_limit(10_000)
+            .add_unsigned_coin_input(
+                SecretKey::random(rng),
+                rng.gen(),
+                amount,
+                AssetId::BASE,
+                Default::default(),
+            )
+            .add_output(Output::coin(owner, amount, AssetId::BASE))
+            .finalize_as_transaction();
+        client.submit_and_await_commit(&tx).await.unwrap();
+    }
+}
+
+async fn start_node(database: &CombinedDatabase) -> (FuelService, FuelClient) {
+    let node =
+        FuelService::from_combined_database(database.clone(), Config::local_node())
+            .await
+            .unwrap();
+    let client = FuelClient::from(node.bound_address);
+    (node, client)
+}
+
+/// Returns the amounts of the coins selected to spend all coins of the `owner`.
+async fn coins_to_spend_all(
+    client: &FuelClient,
+    owner: &Address,
+) -> std::io::Result<Vec<u64>> {
+    let coins = client
+        .coins_to_spend(owner, vec![(AssetId::BASE, TRANSFERRED.into(), None)], None)
+        .await?;
+    let mut amounts = coins
+        .into_iter()
+        .flatten()
+        .map(|coin| match coin {
+            CoinType::Coin(coin) => coin.amount,
+            CoinType::MessageCoin(message) => message.amount,
+            CoinType::Unknown => panic!("Unknown coin type"),
+        })
+        .collect::<Vec<_>>();
+    amounts.sort();
+    Ok(amounts)
+}
+
+/// Returns the sorted ids of the coins selected to spend `target` in at most
+/// `max` coins, or the error of the query.
+async fn coins_to_spend_ids(
+    client: &FuelClient,
+    owner: &Address,
+    target: u64,
+    max: u16,
+    excluded: &[UtxoId],
+) -> Result<Vec<UtxoId>, String> {
+    let coins = client
+        .coins_to_spend(
+            owner,
+            vec![(AssetId::BASE, target.into(), Some(max))],
+            Some((excluded.to_vec(), vec![])),
+        )
+        .await
+        .map_err(|error| error.to_string())?;
+    let mut ids = coins
+        .into_iter()
+        .flatten()
+        .map(|coin| match coin {
+            CoinType::Coin(coin) => coin.utxo_id,
+            _ => panic!("Only coins are expected"),
+        })
+        .collect::<Vec<_>>();
+    ids.sort();
+    Ok(ids)
+}
+
+/// Returns whether the node reports the coins to spend of the `owner`
+/// as selected without the coins to spend index.
+async fn used_fallback(node: &FuelService, owner: &Address) -> bool {
+    let url = format!("http://{}/v1/graphql", node.bound_address);
+    let asset_id = AssetId::BASE;
+    let query = format!(
+        r#"{{ coinsToSpend(owner: "{owner:#x}", queryPerAsset: [{{ assetId: "{asset_id:#x}", amount: "1" }}]) {{ __typename }} }}"#
+    );
+    let response = send_graph_ql_query(&url, &query).await;
+    let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+    assert!(response.get("errors").is_none(), "{response}");
+    response["extensions"]["usedFallback"] == true
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reindex__rebuilds_coins_to_spend_while_balances_keep_working() {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let owner: Address = rng.gen();
+    let database = CombinedDatabase::default();
+
+    // given
+    let (node, client) = start_node(&database).await;
+    transfer_coins(&client, owner, &mut rng).await;
+    let balance = client.balance(&owner, None).await.unwrap();
+    let coins = coins_to_spend_all(&client, &owner).await.unwrap();
+    node.send_stop_signal_and_await_shutdown().await.unwrap();
+    assert_eq!(balance, TRANSFERRED);
+    assert_eq!(coins, (1..=TRANSFERS).collect::<Vec<_>>());
+
+    // when
+    database
+        .off_chain()
+        .set_indexation_stale(&[IndexationKind::CoinsToSpend], true)
+        .unwrap();
+    let (node, client) = start_node(&database).await;
+
+    // then
+    assert_eq!(coins_to_spend_all(&client, &owner).await.unwrap(), coins);
+    assert_eq!(client.balance(&owner, None).await.unwrap(), balance);
+    node.send_stop_signal_and_await_shutdown().await.unwrap();
+
+    // when
+    let report = reindex(&database, &AssetId::BASE, &[Column::CoinsToSpend]).unwrap();
+    let (_node, client) = start_node(&database).await;
+
+    // then
+    assert_eq!(report.rebuilt, vec![IndexationKind::CoinsToSpend]);
+    assert_eq!(coins_to_spend_all(&client, &owner).await.unwrap(), coins);
+    assert_eq!(client.balance(&owner, None).await.unwrap(), balance);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn coins_to_spend__fallback_selects_the_same_coins_as_the_index() {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let owner: Address = rng.gen();
+    let database = CombinedDatabase::default();
+    let (_node, client) = start_node(&database).await;
+    transfer_coins(&client, owner, &mut rng).await;
+    let all = coins_to_spend_ids(&client, &owner, TRANSFERRED, u16::MAX, &[])
+        .await
+        .unwrap();
+    let biggest = coins_to_spend_ids(&client, &owner, TRANSFERS, 1, &[])
+        .await
+        .unwrap();
+
+    // The queries don't leave a room for the random dust coins,
+    // so the selection is deterministic.
+    let queries: Vec<(u64, u16, Vec<UtxoId>)> = vec![
+        (TRANSFERRED, u16::MAX, vec![]),
+        (TRANSFERS + TRANSFERS / 2, 2, vec![]),
+        (TRANSFERS + TRANSFERS / 2, 2, biggest.clone()),
+        (TRANSFERRED - TRANSFERS, u16::MAX, biggest),
+        (TRANSFERRED, 2, vec![]),
+        (TRANSFERRED, u16::MAX, all[..1].to_vec()),
+    ];
+
+    // given
+    let mut indexed = Vec::with_capacity(queries.len());
+    for (target, max, excluded) in &queries {
+        indexed.push(coins_to_spend_ids(&client, &owner, *target, *max, excluded).await);
+    }
+
+    // when
+    database
+        .off_chain()
+        .set_indexation_stale(&[IndexationKind::CoinsToSpend], true)
+        .unwrap();
+    let mut fallback = Vec::with_capacity(queries.len());
+    for (target, max, excluded) in &queries {
+        fallback.push(coins_to_spend_ids(&client, &owner, *target, *max, excluded).await);
+    }
+
+    // then
+    assert_eq!(fallback, indexed);
+    assert!(indexed[0].is_ok());
+    assert!(indexed[4].is_err());
+    assert!(indexed[5].is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn coins_to_spend__uses_the_index_again_once_it_is_rebuilt() {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let owner: Address = rng.gen();
+    let database = CombinedDatabase::default();
+    let (node, client) = start_node(&database).await;
+    transfer_coins(&client, owner, &mut rng).await;
+    assert!(!used_fallback(&node, &owner).await);
+
+    // given
+    database
+        .off_chain()
+        .set_indexation_stale(&[IndexationKind::CoinsToSpend], true)
+        .unwrap();
+    assert!(used_fallback(&node, &owner).await);
+
+    // when
+    database
+        .off_chain()
+        .set_indexation_stale(&[IndexationKind::CoinsToSpend], false)
+        .unwrap();
+
+    // then
+    assert!(!used_fallback(&node, &owner).await);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reindex__rebuilds_all_columns_of_the_derivation() {
+    // given
+    let database = CombinedDatabase::default();
+    let (node, _) = start_node(&database).await;
+    node.send_stop_signal_and_await_shutdown().await.unwrap();
+
+    // when
+    let result = reindex(&database, &AssetId::BASE, &[Column::MessageBalances]);
+
+    // then
+    let report = result.unwrap();
+    assert_eq!(report.rebuilt, vec![IndexationKind::Balances]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reindex__backfills_owned_coin_counts_of_the_database_without_them() {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let owner: Address = rng.gen();
+    let database = CombinedDatabase::default();
+    let (node, client) = start_node(&database).await;
+    transfer_coins(&client, owner, &mut rng).await;
+    node.send_stop_signal_and_await_shutdown().await.unwrap();
+
+    // given
+    // The database created before the counts were maintained.
+    database
+        .off_chain()
+        .set_indexation_available(&[IndexationKind::OwnedCoinCounts], false)
+        .unwrap();
+    let (node, client) = start_node(&database).await;
+    let count_before_backfill = client
+        .coin_count_by_owner_asset(&owner, &AssetId::BASE)
+        .await;
+    node.send_stop_signal_and_await_shutdown().await.unwrap();
+
+    // when
+    let report =
+        reindex(&database, &AssetId::BASE, &[Column::OwnedCoinCountByAsset]).unwrap();
+    let (_node, client) = start_node(&database).await;
+
+    // then
+    assert!(count_before_backfill.is_err());
+    assert_eq!(report.rebuilt, vec![IndexationKind::OwnedCoinCounts]);
+    let count = client
+        .coin_count_by_owner_asset(&owner, &AssetId::BASE)
+        .await
+        .unwrap();
+    assert_eq!(count, TRANSFERS);
+}
+
+#[test]
+fn reindex__fails_for_columns_without_derivation() {
+    // given
+    let database = CombinedDatabase::default();
+
+    // when
+    let result = reindex(&database, &AssetId::BASE, &[Column::OwnedCoins]);
+
+    // then
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reindex__replays_the_deployed_contracts_from_the_blocks() {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let database = CombinedDatabase::default();
+    let request = PaginationRequest {
+        cursor: None,
+        results: 10,
+        direction: PageDirection::Forward,
+    };
+
+    // given
+    let (node, client) = start_node(&database).await;
+    for _ in 0..3 {
+        mint_contract::deploy(&client, &mut rng).await;
+    }
+    let contracts: Vec<_> = client
+        .contracts(None, None, request.clone())
+        .await
+        .unwrap()
+        .results
+        .into_iter()
+        .map(|contract| (contract.contract_id, contract.tx_pointer))
+        .collect();
+    node.send_stop_signal_and_await_shutdown().await.unwrap();
+    assert_eq!(contracts.len(), 3);
+
+    // when
+    let report = reindex(&database, &AssetId::BASE, &[Column::ContractsByBlock]).unwrap();
+    let (_node, client) = start_node(&database).await;
+
+    // then
+    assert!(report.rebuilt.is_empty());
+    assert_eq!(report.rebuilt_block_columns, vec![Column::ContractsByBlock]);
+    assert_eq!(report.removed_entries, 3);
+    let reindexed: Vec<_> = client
+        .contracts(None, None, request)
+        .await
+        .unwrap()
+        .results
+        .into_iter()
+        .map(|contract| (contract.contract_id, contract.tx_pointer))
+        .collect();
+    assert_eq!(reindexed, contracts);
+}
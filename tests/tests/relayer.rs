@@ -46,6 +46,7 @@ use fuel_core_types::{
     fuel_crypto::*,
     fuel_tx::*,
     fuel_types::{
+        canonical::Serialize,
         BlockHeight,
         Nonce,
     },
@@ -63,6 +64,7 @@ use hyper::{
 };
 use rand::{
     prelude::StdRng,
+    Rng,
     SeedableRng,
 };
 use serde_json::json;
@@ -284,6 +286,86 @@ async fn can_find_failed_relayed_tx() {
     assert_eq!(expected, actual);
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn relayed_transactions_by_owner__returns_failed_forced_tx_of_owner() {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let mut config = Config::local_node();
+    config.relayer = Some(relayer::Config::default());
+    let relayer_config = config.relayer.as_mut().expect("Expected relayer config");
+    let eth_node = MockMiddleware::default();
+    let contract_address = relayer_config.eth_v2_listening_contracts[0];
+
+    // given
+    let secret_key: SecretKey = SecretKey::random(&mut rng);
+    let owner = Input::owner(&secret_key.public_key());
+    // The coin doesn't exist, so the forced transaction fails.
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(10)
+        .add_unsigned_coin_input(
+            secret_key,
+            rng.gen(),
+            100,
+            AssetId::BASE,
+            Default::default(),
+        )
+        .finalize_as_transaction();
+    let log = make_transaction_event(
+        Nonce::from(1u64),
+        5,
+        contract_address,
+        10_000_000,
+        tx.to_bytes(),
+    );
+    let relayed_tx_id: Bytes32 = log.to_tx().id().into();
+    eth_node.update_data(|data| data.logs_batch = vec![vec![log]]);
+    eth_node.update_data(|data| data.best_block.number = Some(200.into()));
+    let eth_node = Arc::new(eth_node);
+    let eth_node_handle = spawn_eth_node(eth_node).await;
+
+    relayer_config.relayer = Some(vec![format!("http://{}", eth_node_handle.address)
+        .as_str()
+        .try_into()
+        .unwrap()]);
+    let srv = FuelService::from_database(Database::in_memory(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    srv.await_relayer_synced().await.unwrap();
+
+    // when
+    srv.shared
+        .poa_adapter
+        .manually_produce_blocks(
+            None,
+            Mode::Blocks {
+                number_of_blocks: 1,
+            },
+        )
+        .await
+        .unwrap();
+
+    // then
+    let relayed_txs = client
+        .relayed_transactions_by_owner(
+            &owner,
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(relayed_txs.results.len(), 1);
+    let relayed_tx = &relayed_txs.results[0];
+    assert_eq!(relayed_tx.id, relayed_tx_id);
+    let ClientRelayedTransactionStatus::Failed { block_height, .. } = &relayed_tx.status;
+    assert_eq!(*block_height, BlockHeight::from(1));
+
+    srv.send_stop_signal_and_await_shutdown().await.unwrap();
+    eth_node_handle.shutdown.send(()).unwrap();
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn can_restart_node_with_relayer_data() {
     let mut rng = StdRng::seed_from_u64(1234);
@@ -392,6 +474,26 @@ fn make_message_event(
     log
 }
 
+fn make_transaction_event(
+    nonce: Nonce,
+    block_number: u64,
+    contract_address: Bytes20,
+    max_gas: u64,
+    serialized_tx: Vec<u8>,
+) -> Log {
+    let transaction = fuel_core_relayer::bridge::TransactionFilter {
+        nonce: U256::from_big_endian(nonce.as_ref()),
+        max_gas,
+        canonically_serialized_tx: serialized_tx.into(),
+    };
+    let mut log = transaction.into_log();
+    log.address =
+        fuel_core_relayer::test_helpers::convert_to_address(contract_address.as_slice());
+    log.block_number = Some(block_number.into());
+    log.log_index = Some(0.into());
+    log
+}
+
 async fn spawn_eth_node(eth_node: Arc<MockMiddleware>) -> EthNodeHandle {
     // Construct our SocketAddr to listen on...
     let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, 0));
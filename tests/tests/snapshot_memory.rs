@@ -0,0 +1,162 @@
+//! The snapshot of a large database is exported with bounded memory.
+//!
+//! The test is compiled into its own binary, so the global allocator
+//! counts only the allocations of the export.
+
+#![allow(non_snake_case)]
+
+use fuel_core::{
+    chain_config::{
+        ChainConfig,
+        SnapshotMetadata,
+        SnapshotReader,
+        SnapshotWriter,
+        ZstdCompressionLevel,
+        MAX_GROUP_SIZE,
+    },
+    combined_database::CombinedDatabase,
+    service::genesis::Exporter,
+    state::{
+        historical_rocksdb::StateRewindPolicy,
+        rocks_db::DatabaseConfig,
+    },
+    ShutdownListener,
+};
+use fuel_core_storage::{
+    tables::{
+        Coins,
+        FuelBlocks,
+    },
+    transactional::WriteTransaction,
+    StorageAsMut,
+};
+use fuel_core_types::{
+    blockchain::block::CompressedBlock,
+    entities::coins::coin::{
+        CompressedCoin,
+        CompressedCoinV1,
+    },
+    fuel_tx::{
+        Address,
+        AssetId,
+        UtxoId,
+    },
+};
+use std::{
+    alloc::{
+        GlobalAlloc,
+        Layout,
+        System,
+    },
+    sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    },
+};
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Tracks the peak of the memory allocated by the process.
+struct PeakAllocator;
+
+unsafe impl GlobalAlloc for PeakAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let allocated = ALLOCATED
+                .fetch_add(layout.size(), Ordering::Relaxed)
+                .saturating_add(layout.size());
+            PEAK.fetch_max(allocated, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: PeakAllocator = PeakAllocator;
+
+const COINS_PER_BATCH: u32 = 100_000;
+const BATCHES: u32 = 10;
+const GROUP_BYTES: usize = 1024 * 1024;
+/// The serialized coins take more than 100 MiB.
+const MEMORY_LIMIT: usize = 64 * 1024 * 1024;
+
+fn given_coins(db: &mut CombinedDatabase) {
+    for batch in 0..BATCHES {
+        let mut transaction = db.on_chain_mut().write_transaction();
+        for index in 0..COINS_PER_BATCH {
+            let index = batch * COINS_PER_BATCH + index;
+            let mut tx_id = [0u8; 32];
+            tx_id[..4].copy_from_slice(&index.to_be_bytes());
+            let coin = CompressedCoin::V1(CompressedCoinV1 {
+                owner: Address::from([1; 32]),
+                amount: index.into(),
+                asset_id: AssetId::BASE,
+                tx_pointer: Default::default(),
+            });
+            transaction
+                .storage_as_mut::<Coins>()
+                .insert(&UtxoId::new(tx_id.into(), 0), &coin)
+                .unwrap();
+        }
+        transaction.commit().unwrap();
+    }
+
+    db.on_chain_mut()
+        .storage_as_mut::<FuelBlocks>()
+        .insert(&0u32.into(), &CompressedBlock::default())
+        .unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn write_full_snapshot__keeps_memory_bounded_for_1m_coins() {
+    // given
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut db = CombinedDatabase::open(
+        &temp_dir.path().join("db"),
+        StateRewindPolicy::NoRewind,
+        DatabaseConfig::config_for_tests(),
+    )
+    .unwrap();
+    given_coins(&mut db);
+
+    let snapshot_dir = temp_dir.path().join("snapshot");
+    let dir = snapshot_dir.clone();
+    let writer =
+        move || SnapshotWriter::parquet(dir.clone(), ZstdCompressionLevel::Level1);
+    let exporter = Exporter::new(
+        db,
+        ChainConfig::local_testnet(),
+        writer,
+        MAX_GROUP_SIZE,
+        ShutdownListener {
+            token: Default::default(),
+        },
+    )
+    .with_group_bytes(GROUP_BYTES);
+    let baseline = ALLOCATED.load(Ordering::Relaxed);
+    PEAK.store(baseline, Ordering::Relaxed);
+
+    // when
+    exporter.write_full_snapshot().await.unwrap();
+
+    // then
+    let peak = PEAK.load(Ordering::Relaxed).saturating_sub(baseline);
+    assert!(
+        peak < MEMORY_LIMIT,
+        "The export allocated {peak} bytes at the peak"
+    );
+    let snapshot = SnapshotMetadata::read(&snapshot_dir).unwrap();
+    let reader = SnapshotReader::open(snapshot).unwrap();
+    let mut exported = 0;
+    for group in reader.read::<Coins>().unwrap() {
+        exported += group.unwrap().len();
+    }
+    assert_eq!(exported, (COINS_PER_BATCH * BATCHES) as usize);
+}
@@ -320,6 +320,62 @@ async fn rollback_to__should_work_with_empty_gas_price_database() -> anyhow::Res
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn rollback_to__fails_without_partial_rollback_when_index_diffs_are_pruned(
+) -> anyhow::Result<()> {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let args = [
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--state-rewind-duration",
+        "7d",
+        "--index-diff-retention-blocks",
+        "10",
+    ];
+    let driver = FuelCoreDriver::spawn_feeless(&args).await?;
+
+    // Given
+    const TOTAL_BLOCKS: u32 = 30;
+    for _ in 0..TOTAL_BLOCKS {
+        produce_block_with_tx(&mut rng, &driver.client).await;
+    }
+    let temp_dir = driver.kill().await;
+    let rollback = |target_block_height: &str| {
+        let args = [
+            "_IGNORED_",
+            "--db-path",
+            temp_dir.path().to_str().unwrap(),
+            "--target-block-height",
+            target_block_height,
+        ];
+        let command = fuel_core_bin::cli::rollback::Command::parse_from(args);
+        fuel_core_bin::cli::rollback::exec(command)
+    };
+
+    // When
+    let result = rollback("5").await;
+
+    // Then
+    let err = result.expect_err("The index diffs below the height 20 are pruned");
+    assert!(
+        err.to_string().contains("reverse diffs"),
+        "Unexpected error: {err:?}"
+    );
+    rollback("25").await.expect("Rollback should succeed");
+    let driver = FuelCoreDriver::spawn_feeless_with_directory(temp_dir, &args).await?;
+    let latest_height = driver
+        .node
+        .shared
+        .database
+        .on_chain()
+        .latest_height_from_metadata();
+    assert_eq!(Ok(Some(BlockHeight::new(25))), latest_height);
+
+    driver.kill().await;
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn backup_and_restore__should_work_with_state_rewind() -> anyhow::Result<()> {
     let mut rng = StdRng::seed_from_u64(1234);
@@ -510,6 +566,54 @@ async fn dry_run__correct_utxoid_state_in_past_blocks() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn rollback_to__notifies_subscribers_and_records_the_last_rollback(
+) -> anyhow::Result<()> {
+    let driver = FuelCoreDriver::spawn(&[
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--state-rewind-duration",
+        "7d",
+    ])
+    .await?;
+    let client = &driver.client;
+    client.produce_blocks(3, None).await?;
+    let mut removed_block_ids = vec![];
+    for height in [2u32, 3] {
+        let block = client.block_by_height(height.into()).await?.unwrap();
+        removed_block_ids.push(block.id);
+    }
+    assert_eq!(client.last_rollback().await?, None);
+
+    // Given
+    let mut events = client.chain_rollback_subscription().await?;
+
+    // When
+    let mut shutdown_listener = fuel_core::ShutdownListener {
+        token: Default::default(),
+    };
+    driver
+        .node
+        .shared
+        .database
+        .rollback_to(BlockHeight::new(1), &mut shutdown_listener)?;
+
+    // Then
+    let event = tokio::time::timeout(std::time::Duration::from_secs(10), events.next())
+        .await
+        .expect("The rollback should be streamed")
+        .unwrap()?;
+    assert_eq!(event.from_height, 3);
+    assert_eq!(event.to_height, 1);
+    assert_eq!(event.block_ids, Some(removed_block_ids));
+    let last_rollback = client.last_rollback().await?;
+    assert_eq!(last_rollback, Some(event));
+
+    driver.kill().await;
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn dry_run__correct_contract_state_in_past_blocks() -> anyhow::Result<()> {
     let mut rng = StdRng::seed_from_u64(1234);
@@ -584,3 +688,125 @@ async fn dry_run__correct_contract_state_in_past_blocks() -> anyhow::Result<()>
     driver.kill().await;
     Ok(())
 }
+
+fn returned_counter_value(result: &TransactionExecutionResult) -> u64 {
+    let TransactionExecutionResult::Success { receipts, .. } = result else {
+        panic!("Expected a successful execution");
+    };
+    assert!(receipts.len() > 2);
+    let Receipt::Return { val, .. } = receipts[receipts.len() - 2] else {
+        panic!("Expected a return receipt: {:?}", receipts);
+    };
+    val
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn dry_run_at_height__uses_the_contract_state_after_the_block() -> anyhow::Result<()>
+{
+    let mut rng = StdRng::seed_from_u64(1234);
+    let driver = FuelCoreDriver::spawn_feeless(&[
+        "--debug",
+        "--historical-execution",
+        "--poa-instant",
+        "true",
+        "--state-rewind-duration",
+        "7d",
+    ])
+    .await?;
+
+    // given
+    let (_, contract_id) = counter_contract::deploy(&driver.client, &mut rng).await;
+    let (incr1_height, _) =
+        counter_contract::increment(&driver.client, &mut rng, contract_id).await;
+    let (incr2_height, _) =
+        counter_contract::increment(&driver.client, &mut rng, contract_id).await;
+
+    // when
+    let after_first = driver
+        .client
+        .dry_run_at_height(
+            &[counter_contract::increment_tx(&mut rng, contract_id)],
+            None,
+            None,
+            incr1_height,
+        )
+        .await?;
+    let after_second = driver
+        .client
+        .dry_run_at_height(
+            &[counter_contract::increment_tx(&mut rng, contract_id)],
+            None,
+            None,
+            incr2_height,
+        )
+        .await?;
+
+    // then
+    assert_eq!(after_first.state_height, incr1_height);
+    assert_eq!(returned_counter_value(&after_first.statuses[0].result), 2);
+    assert_eq!(after_second.state_height, incr2_height);
+    assert_eq!(returned_counter_value(&after_second.statuses[0].result), 3);
+
+    driver.kill().await;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn dry_run_at_height__fails_with_the_earliest_available_height_outside_of_the_rewind_window(
+) -> anyhow::Result<()> {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let driver = FuelCoreDriver::spawn_feeless(&[
+        "--debug",
+        "--historical-execution",
+        "--poa-instant",
+        "true",
+        "--state-rewind-duration",
+        "2s",
+    ])
+    .await?;
+
+    // given
+    let (deployed_height, contract_id) =
+        counter_contract::deploy(&driver.client, &mut rng).await;
+    driver.client.produce_blocks(5, None).await?;
+
+    // when
+    let err = driver
+        .client
+        .dry_run_at_height(
+            &[counter_contract::increment_tx(&mut rng, contract_id)],
+            None,
+            None,
+            deployed_height,
+        )
+        .await
+        .expect_err("The state at the deployment height should be pruned")
+        .to_string();
+
+    // then
+    assert!(err.contains("is unavailable"), "{err}");
+    let earliest_height: u32 = err
+        .split("the earliest available height is ")
+        .nth(1)
+        .and_then(|rest| {
+            rest.split(|c: char| !c.is_ascii_digit())
+                .next()
+                .and_then(|height| height.parse().ok())
+        })
+        .expect("The error should contain the earliest available height");
+    assert!(earliest_height > u32::from(deployed_height));
+    let result = driver
+        .client
+        .dry_run_at_height(
+            &[counter_contract::increment_tx(&mut rng, contract_id)],
+            None,
+            None,
+            earliest_height.into(),
+        )
+        .await?;
+    assert_eq!(result.state_height, earliest_height.into());
+    assert_eq!(returned_counter_value(&result.statuses[0].result), 1);
+
+    driver.kill().await;
+    Ok(())
+}
@@ -13,10 +13,17 @@ use fuel_core::{
 use fuel_core_client::client::{
     pagination::{
         PageDirection,
+        PaginatedResult,
         PaginationRequest,
     },
+    schema::tx::{
+        TransactionDirection,
+        TransactionStatusFilter,
+    },
     types::{
+        account::OwnerActivityItem,
         StatusWithTransaction,
+        TransactionResponse,
         TransactionStatus,
     },
     FuelClient,
@@ -55,7 +62,9 @@ use std::{
 use test_helpers::{
     assemble_tx::AssembleAndRunTx,
     config_with_fee,
+    counter_contract,
     default_signing_wallet,
+    send_graph_ql_query,
 };
 
 mod predicates;
@@ -490,6 +499,216 @@ async fn get_transaction_by_id() {
     }
 }
 
+#[tokio::test]
+async fn transaction__raw_payload_decodes_to_the_queried_id_and_matches_the_size() {
+    use fuel_core_types::fuel_types::canonical::Deserialize;
+
+    // given
+    let transaction = Transaction::default_test_tx();
+    let id = transaction.id(&ChainId::default());
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.submit_and_await_commit(&transaction).await.unwrap();
+    let url = format!("http://{}/v1/graphql", srv.bound_address);
+    let query = format!(r#"{{ transaction(id: "{id:#x}") {{ rawPayload size }} }}"#);
+
+    // when
+    let response = send_graph_ql_query(&url, &query).await;
+
+    // then
+    let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+    let returned = &response["data"]["transaction"];
+    let raw_payload = returned["rawPayload"].as_str().unwrap();
+    let bytes = hex::decode(raw_payload.trim_start_matches("0x")).unwrap();
+    let decoded = Transaction::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded.id(&ChainId::default()), id);
+    assert_eq!(returned["size"].as_str().unwrap(), bytes.len().to_string());
+}
+
+#[tokio::test]
+async fn transactions_included__returns_the_inclusion_in_the_order_of_the_ids() {
+    // given
+    let transaction = Transaction::default_test_tx();
+    let included_id = transaction.id(&ChainId::default());
+    let unknown_id = TxId::from([7; 32]);
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.submit_and_await_commit(&transaction).await.unwrap();
+
+    // when
+    let included = client
+        .transactions_included(&[unknown_id, included_id, unknown_id])
+        .await
+        .unwrap();
+
+    // then
+    assert_eq!(included, vec![false, true, false]);
+}
+
+#[tokio::test]
+async fn transaction_inclusion_proof__verifies_against_the_transactions_root() {
+    use fuel_core_client::client::types::TransactionType;
+    use fuel_core_types::{
+        fuel_merkle::binary::verify,
+        fuel_types::canonical::Serialize,
+    };
+
+    // given
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let transaction = Transaction::default_test_tx();
+    let id = transaction.id(&ChainId::default());
+    client.submit_and_await_commit(&transaction).await.unwrap();
+    let TransactionType::Known(executed) = client
+        .transaction(&id)
+        .await
+        .unwrap()
+        .expect("The transaction is included")
+        .transaction
+    else {
+        panic!("The transaction is known")
+    };
+
+    // when
+    let first = client.transaction_inclusion_proof(&id).await.unwrap();
+    let cached = client.transaction_inclusion_proof(&id).await.unwrap();
+
+    // then
+    let header = &first.block_header;
+    let proof_set = first
+        .proof
+        .proof_set
+        .iter()
+        .map(|root| **root)
+        .collect::<Vec<_>>();
+    assert!(verify(
+        &*header.transactions_root,
+        &executed.to_bytes(),
+        &proof_set,
+        first.proof.proof_index,
+        u64::from(header.transactions_count),
+    ));
+    assert!(!verify(
+        &*header.transactions_root,
+        &[0u8; 32],
+        &proof_set,
+        first.proof.proof_index,
+        u64::from(header.transactions_count),
+    ));
+    assert_eq!(first, cached);
+}
+
+#[tokio::test]
+async fn transaction_inclusion_proof__fails_for_the_transaction_not_in_any_block() {
+    // given
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // when
+    let result = client
+        .transaction_inclusion_proof(&TxId::from([7; 32]))
+        .await;
+
+    // then
+    let err = result.expect_err("The transaction is not in any block");
+    assert!(
+        err.to_string().contains("not included in any block"),
+        "{err}"
+    );
+}
+
+#[tokio::test]
+async fn tag_transaction__replaces_the_tag_of_the_transaction() {
+    // given
+    let mut config = Config::local_node();
+    config.debug = true;
+    config.graphql_config.max_transaction_tag_size = 256;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let transaction = Transaction::default_test_tx();
+    let id = transaction.id(&ChainId::default());
+    client.submit_and_await_commit(&transaction).await.unwrap();
+    assert_eq!(client.transaction_tag(&id).await.unwrap(), None);
+    client
+        .tag_transaction(&id, b"request-1".to_vec())
+        .await
+        .unwrap();
+
+    // when
+    client
+        .tag_transaction(&id, b"request-2".to_vec())
+        .await
+        .unwrap();
+
+    // then
+    let tag = client.transaction_tag(&id).await.unwrap();
+    assert_eq!(tag, Some(b"request-2".to_vec()));
+    let status = client.transaction_status(&id).await.unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+}
+
+#[tokio::test]
+async fn tag_transaction__fails_for_the_unknown_transaction_or_the_oversized_tag() {
+    // given
+    let mut config = Config::local_node();
+    config.debug = true;
+    config.graphql_config.max_transaction_tag_size = 4;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let transaction = Transaction::default_test_tx();
+    let id = transaction.id(&ChainId::default());
+    client.submit_and_await_commit(&transaction).await.unwrap();
+
+    // when
+    let unknown = client.tag_transaction(&TxId::from([7; 32]), vec![1]).await;
+    let oversized = client.tag_transaction(&id, vec![1; 5]).await;
+
+    // then
+    let err = unknown.expect_err("The transaction is unknown");
+    assert!(err.to_string().contains("is unknown"), "{err}");
+    let err = oversized.expect_err("The tag exceeds the limit");
+    assert!(err.to_string().contains("exceeds the limit"), "{err}");
+    assert_eq!(client.transaction_tag(&id).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn tag_transaction__fails_without_debug_or_with_default_tag_size() {
+    // given
+    let mut config = Config::local_node();
+    config.graphql_config.max_transaction_tag_size = 256;
+    let without_debug = FuelService::new_node(config).await.unwrap();
+    let mut config = Config::local_node();
+    config.debug = true;
+    let with_default_tag_size = FuelService::new_node(config).await.unwrap();
+    let transaction = Transaction::default_test_tx();
+    let id = transaction.id(&ChainId::default());
+
+    // when
+    let mut errors = vec![];
+    for srv in [&without_debug, &with_default_tag_size] {
+        let client = FuelClient::from(srv.bound_address);
+        client.submit_and_await_commit(&transaction).await.unwrap();
+        let result = client.tag_transaction(&id, vec![1]).await;
+        errors.push(result.expect_err("The tagging is disabled"));
+    }
+
+    // then
+    assert!(
+        errors[0]
+            .to_string()
+            .contains("'debug' feature is disabled"),
+        "{}",
+        errors[0]
+    );
+    assert!(
+        errors[1]
+            .to_string()
+            .contains("tagging of the transactions is disabled"),
+        "{}",
+        errors[1]
+    );
+}
+
 #[tokio::test]
 async fn get_transparent_transaction_by_id() {
     let transaction = Transaction::default_test_tx();
@@ -736,6 +955,30 @@ async fn get_transactions_by_owner_returns_correct_number_of_results(
     assert_eq!(transactions_forward.len(), 5);
 }
 
+#[tokio::test]
+async fn get_transactions_by_owner__rejects_pages_above_the_limit() {
+    // given
+    let mut config = Config::local_node();
+    config.graphql_config.max_page_sizes.transactions_by_owner = 2;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let owner = Address::from([1; 32]);
+    let page = |results| PaginationRequest {
+        cursor: None,
+        results,
+        direction: PageDirection::Forward,
+    };
+
+    // when
+    let within_limit = client.transactions_by_owner(&owner, page(2)).await;
+    let above_limit = client.transactions_by_owner(&owner, page(3)).await;
+
+    // then
+    assert!(within_limit.is_ok());
+    let error = above_limit.unwrap_err().to_string();
+    assert!(error.contains("above the limit of 2 entries"), "{error}");
+}
+
 #[test_case::test_case(PageDirection::Forward; "forward")]
 #[test_case::test_case(PageDirection::Backward; "backward")]
 #[tokio::test]
@@ -869,6 +1112,164 @@ async fn get_transactions_by_owner_supports_cursor(direction: PageDirection) {
     );
 }
 
+#[tokio::test]
+async fn get_transactions_by_owner__backward_returns_newest_first_and_cursor_flips_direction(
+) {
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+
+    let mut context = TestContext::new(100).await;
+    for amount in 1..=5 {
+        let _ = context.transfer(alice, bob, amount).await.unwrap();
+    }
+    let client = context.client;
+    let page = |cursor: Option<String>, results, direction| PaginationRequest {
+        cursor,
+        results,
+        direction,
+    };
+    let transactions = |response: PaginatedResult<TransactionResponse, String>| {
+        response
+            .results
+            .into_iter()
+            .map(|tx| tx.transaction)
+            .collect_vec()
+    };
+    let oldest_first = transactions(
+        client
+            .transactions_by_owner(&bob, page(None, 10, PageDirection::Forward))
+            .await
+            .unwrap(),
+    );
+
+    // given
+    let newest_first = transactions(
+        client
+            .transactions_by_owner(&bob, page(None, 10, PageDirection::Backward))
+            .await
+            .unwrap(),
+    );
+    let forward_three = client
+        .transactions_by_owner(&bob, page(None, 3, PageDirection::Forward))
+        .await
+        .unwrap();
+    let backward_two = client
+        .transactions_by_owner(&bob, page(None, 2, PageDirection::Backward))
+        .await
+        .unwrap();
+
+    // when
+    let before_forward_three = transactions(
+        client
+            .transactions_by_owner(
+                &bob,
+                page(forward_three.cursor.clone(), 10, PageDirection::Backward),
+            )
+            .await
+            .unwrap(),
+    );
+    let after_backward_two = transactions(
+        client
+            .transactions_by_owner(
+                &bob,
+                page(backward_two.cursor.clone(), 10, PageDirection::Forward),
+            )
+            .await
+            .unwrap(),
+    );
+
+    // then
+    assert_eq!(oldest_first.len(), 5);
+    let reversed = oldest_first.iter().rev().cloned().collect_vec();
+    assert_eq!(newest_first, reversed);
+    // The transaction at the cursor is excluded in both directions.
+    assert_eq!(
+        before_forward_three,
+        vec![oldest_first[1].clone(), oldest_first[0].clone()]
+    );
+    assert_eq!(after_backward_two, vec![oldest_first[4].clone()]);
+}
+
+#[tokio::test]
+async fn get_transactions_by_owner__backward_pages_are_not_affected_by_new_transactions()
+{
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+
+    let mut context = TestContext::new(100).await;
+    for amount in 1..=5 {
+        let _ = context.transfer(alice, bob, amount).await.unwrap();
+    }
+    let page = |cursor: Option<String>, results, direction| PaginationRequest {
+        cursor,
+        results,
+        direction,
+    };
+    let transactions = |response: PaginatedResult<TransactionResponse, String>| {
+        response
+            .results
+            .into_iter()
+            .map(|tx| tx.transaction)
+            .collect_vec()
+    };
+
+    // given
+    let newest_two = context
+        .client
+        .transactions_by_owner(&bob, page(None, 2, PageDirection::Backward))
+        .await
+        .unwrap();
+    for amount in 6..=7 {
+        let _ = context.transfer(alice, bob, amount).await.unwrap();
+    }
+
+    // when
+    let older = transactions(
+        context
+            .client
+            .transactions_by_owner(
+                &bob,
+                page(newest_two.cursor.clone(), 10, PageDirection::Backward),
+            )
+            .await
+            .unwrap(),
+    );
+    let newest_after_inserts = transactions(
+        context
+            .client
+            .transactions_by_owner(&bob, page(None, 2, PageDirection::Backward))
+            .await
+            .unwrap(),
+    );
+
+    // then
+    let oldest_first = transactions(
+        context
+            .client
+            .transactions_by_owner(&bob, page(None, 10, PageDirection::Forward))
+            .await
+            .unwrap(),
+    );
+    assert_eq!(oldest_first.len(), 7);
+    // The page continues from the cursor without the new transactions.
+    assert_eq!(
+        transactions(newest_two),
+        vec![oldest_first[4].clone(), oldest_first[3].clone()]
+    );
+    assert_eq!(
+        older,
+        vec![
+            oldest_first[2].clone(),
+            oldest_first[1].clone(),
+            oldest_first[0].clone()
+        ]
+    );
+    assert_eq!(
+        newest_after_inserts,
+        vec![oldest_first[6].clone(), oldest_first[5].clone()]
+    );
+}
+
 #[tokio::test]
 async fn get_transactions_from_manual_blocks() {
     let context = TestContext::new(100).await;
@@ -1039,6 +1440,347 @@ async fn get_owned_transactions() {
     assert_eq!(&charlie_txs, &[tx1, tx2, tx3]);
 }
 
+#[tokio::test]
+async fn filtered_transactions_by_owner__selects_by_status_and_direction() {
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+
+    // Given
+    let mut context = TestContext::new(100).await;
+    let sent = context.transfer(alice, bob, 1).await.unwrap();
+    let received = context.transfer(bob, alice, 2).await.unwrap();
+    let failed_tx: Transaction = Transaction::script(
+        1_000_000,
+        op::rvrt(RegId::ONE).to_bytes().to_vec(),
+        vec![],
+        Policies::new().with_max_fee(0),
+        vec![Input::coin_signed(
+            context.rng.gen(),
+            alice,
+            3,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )],
+        vec![Output::coin(bob, 3, Default::default())],
+        vec![vec![].into()],
+    )
+    .into();
+    context
+        .client
+        .submit_and_await_commit(&failed_tx)
+        .await
+        .unwrap();
+    let failed = failed_tx.id(&ChainId::default());
+
+    // When
+    let client = &context.client;
+    let query = |status_filter: Option<TransactionStatusFilter>,
+                 direction: TransactionDirection| async move {
+        let page_request = PaginationRequest {
+            cursor: None,
+            results: 5,
+            direction: PageDirection::Forward,
+        };
+        client
+            .filtered_transactions_by_owner(
+                &alice,
+                status_filter,
+                direction,
+                page_request,
+            )
+            .await
+            .unwrap()
+            .results
+            .into_iter()
+            .map(|tx| {
+                let tx: Transaction = tx.transaction.try_into().unwrap();
+                tx.id(&ChainId::default())
+            })
+            .collect_vec()
+    };
+    let all = query(None, TransactionDirection::Any).await;
+    let all_sent = query(None, TransactionDirection::Sent).await;
+    let all_received = query(None, TransactionDirection::Received).await;
+    let all_failed = query(
+        Some(TransactionStatusFilter::Failure),
+        TransactionDirection::Any,
+    )
+    .await;
+    let successfully_sent = query(
+        Some(TransactionStatusFilter::Success),
+        TransactionDirection::Sent,
+    )
+    .await;
+    let squeezed_out = query(
+        Some(TransactionStatusFilter::SqueezedOut),
+        TransactionDirection::Any,
+    )
+    .await;
+
+    // Then
+    assert_eq!(all, [sent, received, failed]);
+    assert_eq!(all_sent, [sent, failed]);
+    assert_eq!(all_received, [received]);
+    assert_eq!(all_failed, [failed]);
+    assert_eq!(successfully_sent, [sent]);
+    assert!(squeezed_out.is_empty());
+}
+
+#[tokio::test]
+async fn get_contract_transactions() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // given
+    let (_, contract_id) = counter_contract::deploy(&client, &mut rng).await;
+    let mut calls = vec![];
+    for _ in 0..2 {
+        let tx = counter_contract::increment_tx(&mut rng, contract_id);
+        client.submit_and_await_commit(&tx).await.unwrap();
+        calls.push(tx.id(&ChainId::default()));
+    }
+    let (_, other_contract_id) = counter_contract::deploy(&client, &mut rng).await;
+
+    // when
+    let page_request = PaginationRequest {
+        cursor: None,
+        results: 5,
+        direction: PageDirection::Forward,
+    };
+    let contract_txs = client
+        .transactions_by_contract(&contract_id, page_request.clone())
+        .await
+        .unwrap()
+        .results
+        .into_iter()
+        .map(|tx| {
+            let tx: Transaction = tx.transaction.try_into().unwrap();
+
+            tx.id(&ChainId::default())
+        })
+        .collect_vec();
+    let other_contract_txs = client
+        .transactions_by_contract(&other_contract_id, page_request)
+        .await
+        .unwrap()
+        .results;
+
+    // then
+    // The first transaction deploys the contract, the rest of them call it.
+    assert_eq!(contract_txs.len(), 3);
+    assert_eq!(&contract_txs[1..], &calls);
+    assert_eq!(other_contract_txs.len(), 1);
+}
+
+#[tokio::test]
+async fn get_top_addresses_by_tx_count() {
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+    let charlie = Address::from([3; 32]);
+
+    let mut context = TestContext::new(100).await;
+    context.transfer(alice, charlie, 1).await.unwrap();
+    context.transfer(charlie, bob, 2).await.unwrap();
+    context.transfer(bob, charlie, 3).await.unwrap();
+
+    // when
+    let top_addresses = context.client.top_addresses_by_tx_count(2).await.unwrap();
+
+    // then
+    assert_eq!(top_addresses, vec![(charlie, 3), (bob, 2)]);
+}
+
+#[tokio::test]
+async fn get_account_summary() {
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+    let charlie = Address::from([3; 32]);
+    let unknown = Address::from([4; 32]);
+
+    let mut context = TestContext::new(100).await;
+    context.transfer(alice, charlie, 1).await.unwrap();
+    context.transfer(charlie, bob, 2).await.unwrap();
+    context.transfer(bob, charlie, 3).await.unwrap();
+
+    // when
+    let summary = context
+        .client
+        .account_summary(&charlie)
+        .await
+        .unwrap()
+        .expect("charlie has activity");
+    let unknown_summary = context.client.account_summary(&unknown).await.unwrap();
+
+    // then
+    assert_eq!(summary.owner, charlie);
+    assert_eq!(summary.first_seen_block, 1u32.into());
+    assert_eq!(summary.last_active_block, 3u32.into());
+    assert_eq!(summary.total_transactions, 3);
+    // Charlie received two coins and spent one of them.
+    assert_eq!(summary.coin_count, 1);
+    assert_eq!(unknown_summary, None);
+}
+
+#[tokio::test]
+async fn owner_last_activity__returns_the_height_of_the_last_block_with_the_owner() {
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+    let charlie = Address::from([3; 32]);
+    let unknown = Address::from([4; 32]);
+
+    let mut context = TestContext::new(100).await;
+    context.transfer(alice, charlie, 1).await.unwrap();
+    context.transfer(charlie, bob, 2).await.unwrap();
+
+    // when
+    let charlie_activity = context.client.owner_last_activity(&charlie).await.unwrap();
+    let bob_activity = context.client.owner_last_activity(&bob).await.unwrap();
+    let unknown_activity = context.client.owner_last_activity(&unknown).await.unwrap();
+
+    // then
+    assert_eq!(charlie_activity, Some(2u32.into()));
+    assert_eq!(bob_activity, Some(2u32.into()));
+    assert_eq!(unknown_activity, None);
+}
+
+#[tokio::test]
+async fn prune_owner__removes_off_chain_indexes_of_the_owner() {
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+    let charlie = Address::from([3; 32]);
+
+    let mut config = Config::local_node();
+    config.graphql_config.allow_owner_pruning = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let mut context = TestContext {
+        srv,
+        rng: StdRng::seed_from_u64(100),
+        client,
+    };
+    context.transfer(alice, charlie, 1).await.unwrap();
+    context.transfer(charlie, bob, 2).await.unwrap();
+    context.transfer(bob, charlie, 3).await.unwrap();
+    let client = context.client;
+    let all = || PaginationRequest {
+        cursor: None,
+        results: 10,
+        direction: PageDirection::Forward,
+    };
+    let charlie_coins = client.coins(&charlie, None, all()).await.unwrap();
+
+    // when
+    let pruned = client.prune_owner(&charlie).await.unwrap();
+
+    // then
+    assert_eq!(pruned.owned_coins, charlie_coins.results.len() as u64);
+    assert_eq!(pruned.owned_message_ids, 0);
+    assert_eq!(pruned.owned_transactions, 3);
+    assert_eq!(pruned.owned_coin_counts, 1);
+    assert_eq!(pruned.owner_activities, 1);
+
+    let coins = client.coins(&charlie, None, all()).await.unwrap();
+    assert!(coins.results.is_empty());
+    let transactions = client.transactions_by_owner(&charlie, all()).await.unwrap();
+    assert!(transactions.results.is_empty());
+    assert_eq!(client.account_summary(&charlie).await.unwrap(), None);
+    // The indexes of other owners are untouched.
+    let transactions = client.transactions_by_owner(&bob, all()).await.unwrap();
+    assert_eq!(transactions.results.len(), 2);
+}
+
+#[tokio::test]
+async fn prune_owner__fails_when_owner_pruning_is_disabled() {
+    let alice = Address::from([1; 32]);
+    let charlie = Address::from([3; 32]);
+
+    let mut context = TestContext::new(100).await;
+    context.transfer(alice, charlie, 1).await.unwrap();
+
+    // when
+    let result = context.client.prune_owner(&charlie).await;
+
+    // then
+    let err = result.expect_err("The owner pruning is disabled by default");
+    assert!(err
+        .to_string()
+        .contains("The owner pruning must be enabled to use this endpoint"));
+    let summary = context.client.account_summary(&charlie).await.unwrap();
+    assert!(summary.is_some());
+}
+
+#[tokio::test]
+async fn get_owner_activity_pages_through_coins_and_transactions() {
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+    let charlie = Address::from([3; 32]);
+
+    let mut context = TestContext::new(100).await;
+    let tx1 = context.transfer(alice, charlie, 1).await.unwrap();
+    let tx2 = context.transfer(charlie, bob, 2).await.unwrap();
+    let tx3 = context.transfer(bob, charlie, 3).await.unwrap();
+    let client = context.client;
+    let all_results = PaginationRequest {
+        cursor: None,
+        results: 100,
+        direction: PageDirection::Forward,
+    };
+    let full_feed = client
+        .owner_activity(&charlie, all_results.clone())
+        .await
+        .unwrap()
+        .results;
+
+    // when
+    let mut paged_feed = vec![];
+    let mut cursor = None;
+    loop {
+        let page = client
+            .owner_activity(
+                &charlie,
+                PaginationRequest {
+                    cursor,
+                    results: 2,
+                    direction: PageDirection::Forward,
+                },
+            )
+            .await
+            .unwrap();
+        paged_feed.extend(page.results);
+        if !page.has_next_page {
+            break
+        }
+        cursor = page.cursor;
+    }
+
+    // then
+    assert_eq!(paged_feed, full_feed);
+    let tx_ids = full_feed
+        .iter()
+        .filter_map(|item| match item {
+            OwnerActivityItem::Transaction(tx) => Some(tx.id(&ChainId::default())),
+            _ => None,
+        })
+        .collect_vec();
+    assert_eq!(tx_ids, vec![tx1, tx2, tx3]);
+    let coins = full_feed
+        .iter()
+        .filter_map(|item| match item {
+            OwnerActivityItem::Coin(coin) => Some(coin.clone()),
+            _ => None,
+        })
+        .collect_vec();
+    let owned_coins = client
+        .coins(&charlie, None, all_results)
+        .await
+        .unwrap()
+        .results;
+    assert_eq!(coins.len(), owned_coins.len());
+    assert!(coins.iter().all(|coin| owned_coins.contains(coin)));
+}
+
 #[tokio::test]
 async fn pending_pool_returns_error_after_timeout_for_transaction_that_spends_already_spent_utxo(
 ) {
@@ -1,6 +1,7 @@
 // Tests related to the predicate execution feature
 
 use crate::helpers::TestSetupBuilder;
+use fuel_core_client::client::schema::account::OwnerKind;
 use fuel_core_types::{
     fuel_asm::*,
     fuel_tx::{
@@ -102,6 +103,68 @@ async fn transaction_with_valid_predicate_is_executed() {
     )
 }
 
+#[tokio::test]
+async fn is_known_predicate__classifies_owner_after_first_spend() {
+    let mut rng = StdRng::seed_from_u64(2322);
+
+    let amount = 500;
+    let asset_id = rng.gen();
+    let predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+    let owner = Input::predicate_owner(&predicate);
+    let recipient: Address = rng.gen();
+    let mut predicate_tx =
+        TransactionBuilder::script(Default::default(), Default::default())
+            .add_input(Input::coin_predicate(
+                rng.gen(),
+                owner,
+                amount,
+                asset_id,
+                Default::default(),
+                Default::default(),
+                predicate,
+                vec![],
+            ))
+            .add_output(Output::change(recipient, 0, asset_id))
+            .script_gas_limit(1000)
+            .finalize();
+    let context = TestSetupBuilder::default()
+        .config_coin_inputs_from_transactions(&[&predicate_tx])
+        .finalize()
+        .await;
+    predicate_tx
+        .estimate_predicates(
+            &CheckPredicateParams::from(
+                &context
+                    .srv
+                    .shared
+                    .config
+                    .snapshot_reader
+                    .chain_config()
+                    .consensus_parameters,
+            ),
+            MemoryInstance::new(),
+            &EmptyStorage,
+        )
+        .expect("Predicate check failed");
+
+    // given
+    let kind_before_spend = context.client.is_known_predicate(&owner).await.unwrap();
+    assert_eq!(kind_before_spend, OwnerKind::Unknown);
+
+    // when
+    context
+        .client
+        .submit_and_await_commit(&predicate_tx.into())
+        .await
+        .unwrap();
+
+    // then
+    let owner_kind = context.client.is_known_predicate(&owner).await.unwrap();
+    let recipient_kind = context.client.is_known_predicate(&recipient).await.unwrap();
+    assert_eq!(owner_kind, OwnerKind::Predicate);
+    assert_eq!(recipient_kind, OwnerKind::Unknown);
+}
+
 #[tokio::test]
 async fn transaction_with_invalid_predicate_is_rejected() {
     let mut rng = StdRng::seed_from_u64(2322);
@@ -179,3 +242,66 @@ async fn transaction_with_predicates_that_exhaust_gas_limit_are_rejected() {
         "got unexpected error {err}"
     )
 }
+
+#[tokio::test]
+async fn estimate_predicates_breakdown__reports_both_predicates_when_one_fails() {
+    let mut rng = StdRng::seed_from_u64(2322);
+
+    // given
+    let amount = 500;
+    let asset_id = rng.gen();
+    let failing_predicate = op::ret(RegId::ZERO).to_bytes().to_vec();
+    let succeeding_predicate = op::ret(RegId::ONE).to_bytes().to_vec();
+    let predicate_tx = TransactionBuilder::script(Default::default(), Default::default())
+        .add_input(Input::coin_predicate(
+            rng.gen(),
+            Input::predicate_owner(&failing_predicate),
+            amount,
+            asset_id,
+            Default::default(),
+            Default::default(),
+            failing_predicate,
+            vec![],
+        ))
+        .add_input(Input::coin_predicate(
+            rng.gen(),
+            Input::predicate_owner(&succeeding_predicate),
+            amount,
+            asset_id,
+            Default::default(),
+            Default::default(),
+            succeeding_predicate,
+            vec![],
+        ))
+        .add_output(Output::change(rng.gen(), 0, asset_id))
+        .script_gas_limit(1000)
+        .finalize();
+    let context = TestSetupBuilder::default()
+        .config_coin_inputs_from_transactions(&[&predicate_tx])
+        .finalize()
+        .await;
+    let mut tx: Transaction = predicate_tx.into();
+
+    // when
+    let predicates = context
+        .client
+        .estimate_predicates_breakdown(&mut tx)
+        .await
+        .unwrap();
+
+    // then
+    assert_eq!(predicates.len(), 2);
+    let failed = &predicates[0];
+    assert_eq!(failed.input_index, 0);
+    assert!(!failed.success);
+    assert_eq!(failed.gas_used, None);
+    assert!(failed.failure_reason.is_some());
+    let succeeded = &predicates[1];
+    assert_eq!(succeeded.input_index, 1);
+    assert!(succeeded.success);
+    assert_eq!(succeeded.failure_reason, None);
+    let inputs = tx.as_script().unwrap().inputs();
+    assert_eq!(inputs[0].predicate_gas_used(), Some(0));
+    assert_eq!(inputs[1].predicate_gas_used(), succeeded.gas_used);
+    assert!(succeeded.gas_used.unwrap() > 0);
+}
@@ -4,6 +4,13 @@ use crate::helpers::{
     TestContext,
     TestSetupBuilder,
 };
+use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    schema::tx::PendingTransactionsSort,
+};
 use fuel_core_poa::Trigger;
 use fuel_core_types::{
     fuel_asm::*,
@@ -72,3 +79,154 @@ async fn txs_max_script_gas_limit() {
         transactions.len() + 1 // coinbase
     )
 }
+
+#[tokio::test]
+async fn pending_transactions__returns_txs_in_requested_order() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    // The transaction with the highest tip has the lowest tip/gas ratio.
+    let tips_and_gas_limits = [(10, 10_000), (30, 1_000_000), (20, 10_000)];
+    let transactions = tips_and_gas_limits
+        .into_iter()
+        .map(|(tip, gas_limit)| {
+            TransactionBuilder::script(
+                op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+                vec![],
+            )
+            .script_gas_limit(gas_limit)
+            .tip(tip)
+            .max_fee_limit(tip)
+            .add_unsigned_coin_input(
+                SecretKey::random(&mut rng),
+                rng.gen(),
+                1000,
+                Default::default(),
+                Default::default(),
+            )
+            .finalize()
+        })
+        .collect_vec();
+    test_builder.config_coin_inputs_from_transactions(&transactions.iter().collect_vec());
+    test_builder.trigger = Trigger::Never;
+    let TestContext { client, srv, .. } = test_builder.finalize().await;
+
+    let ids = transactions
+        .iter()
+        .map(|tx| tx.id(&ChainId::default()))
+        .collect_vec();
+    let (cheap, heavy, middle) = (ids[0], ids[1], ids[2]);
+    for tx in transactions.iter() {
+        srv.shared
+            .txpool_shared_state
+            .insert(tx.clone().into())
+            .await
+            .unwrap();
+    }
+    let page = |cursor, results| PaginationRequest {
+        cursor,
+        results,
+        direction: PageDirection::Forward,
+    };
+
+    // when
+    let mut orders = vec![];
+    for sort in [
+        PendingTransactionsSort::Ratio,
+        PendingTransactionsSort::Tip,
+        PendingTransactionsSort::Time,
+    ] {
+        let pending = client
+            .pending_transactions(sort, page(None, 10))
+            .await
+            .unwrap();
+        orders.push(pending.results.iter().map(|tx| tx.id).collect_vec());
+    }
+
+    // then
+    assert_eq!(orders[0], vec![middle, cheap, heavy]);
+    assert_eq!(orders[1], vec![heavy, middle, cheap]);
+    assert_eq!(orders[2], vec![cheap, heavy, middle]);
+}
+
+#[tokio::test]
+async fn pending_transactions__next_pages_are_served_from_the_first_page_snapshot() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    let transactions = (1..=4)
+        .map(|tip| {
+            TransactionBuilder::script(
+                op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+                vec![],
+            )
+            .script_gas_limit(10_000)
+            .tip(tip)
+            .max_fee_limit(tip)
+            .add_unsigned_coin_input(
+                SecretKey::random(&mut rng),
+                rng.gen(),
+                1000,
+                Default::default(),
+                Default::default(),
+            )
+            .finalize()
+        })
+        .collect_vec();
+    test_builder.config_coin_inputs_from_transactions(&transactions.iter().collect_vec());
+    test_builder.trigger = Trigger::Never;
+    let TestContext { client, srv, .. } = test_builder.finalize().await;
+
+    let ids = transactions
+        .iter()
+        .map(|tx| tx.id(&ChainId::default()))
+        .collect_vec();
+    for tx in transactions.iter().take(3) {
+        srv.shared
+            .txpool_shared_state
+            .insert(tx.clone().into())
+            .await
+            .unwrap();
+    }
+    let page = |cursor, results| PaginationRequest {
+        cursor,
+        results,
+        direction: PageDirection::Forward,
+    };
+    let first_page = client
+        .pending_transactions(PendingTransactionsSort::Tip, page(None, 1))
+        .await
+        .unwrap();
+
+    // given
+    srv.shared
+        .txpool_shared_state
+        .insert(transactions[3].clone().into())
+        .await
+        .unwrap();
+
+    // when
+    let next_page = client
+        .pending_transactions(
+            PendingTransactionsSort::Tip,
+            page(first_page.cursor.clone(), 10),
+        )
+        .await
+        .unwrap();
+    let new_first_page = client
+        .pending_transactions(PendingTransactionsSort::Tip, page(None, 1))
+        .await
+        .unwrap();
+
+    // then
+    assert_eq!(first_page.results[0].id, ids[2]);
+    assert!(first_page.has_next_page);
+    let next_ids = next_page.results.iter().map(|tx| tx.id).collect_vec();
+    assert_eq!(next_ids, vec![ids[1], ids[0]]);
+    assert!(!next_page.has_next_page);
+    // The new pagination takes the new snapshot with the late transaction.
+    assert_eq!(new_first_page.results[0].id, ids[3]);
+
+    let other_sort = client
+        .pending_transactions(PendingTransactionsSort::Time, page(first_page.cursor, 10))
+        .await;
+    assert!(other_sort.is_err());
+}
@@ -1,5 +1,6 @@
 use fuel_core_client::client::types::TransactionStatus;
 use fuel_core_types::{
+    fuel_crypto::Hasher,
     fuel_tx::{
         policies::Policies,
         AssetId,
@@ -320,6 +321,88 @@ async fn upgrade_to_a_partially_uploaded_state_transition_fails() {
     );
 }
 
+#[tokio::test]
+async fn consensus_parameters_by_version__returns_the_versions_before_and_after_upgrade()
+{
+    let privileged_address = Input::predicate_owner(predicate());
+    let amount = 1_000;
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.utxo_validation = false;
+    test_builder.privileged_address = privileged_address;
+    let TestContext {
+        client,
+        srv: _drop,
+        mut rng,
+        ..
+    } = test_builder.finalize().await;
+
+    // Given
+    let old_parameters = client.chain_info().await.unwrap().consensus_parameters;
+    let mut new_parameters = old_parameters.clone();
+    let new_block_gas_limit = old_parameters.block_gas_limit().saturating_add(1);
+    new_parameters.set_block_gas_limit(new_block_gas_limit);
+    let serialized_parameters = postcard::to_allocvec(&new_parameters).unwrap();
+    let upgrade = Transaction::upgrade(
+        UpgradePurpose::ConsensusParameters {
+            witness_index: 0,
+            checksum: Hasher::hash(&serialized_parameters),
+        },
+        Policies::new().with_max_fee(amount),
+        vec![Input::coin_predicate(
+            rng.gen(),
+            privileged_address,
+            amount,
+            AssetId::BASE,
+            Default::default(),
+            Default::default(),
+            predicate(),
+            vec![],
+        )],
+        vec![],
+        vec![serialized_parameters.into()],
+    );
+    let tx = upgrade.into();
+    let estimate_predicates = true;
+    let result = client
+        .submit_and_await_commit_opt(&tx, Some(estimate_predicates))
+        .await;
+    let TransactionStatus::Success { block_height, .. } =
+        result.expect("We should be able to upgrade the consensus parameters")
+    else {
+        unreachable!()
+    };
+    let old_version = client
+        .block_by_height(block_height)
+        .await
+        .unwrap()
+        .unwrap()
+        .header
+        .consensus_parameters_version;
+    let new_version = old_version.checked_add(1).unwrap();
+
+    // When
+    let versions = client.consensus_parameters_versions().await.unwrap();
+    let old_version = i32::try_from(old_version).unwrap();
+    let new_version = i32::try_from(new_version).unwrap();
+    let fetched_old_parameters = client
+        .consensus_parameters_by_version(old_version)
+        .await
+        .unwrap();
+    let fetched_new_parameters = client
+        .consensus_parameters_by_version(new_version)
+        .await
+        .unwrap()
+        .expect("The upgraded version should be indexed");
+
+    // Then
+    assert_eq!(versions, vec![old_version, new_version]);
+    assert_eq!(fetched_old_parameters, Some(old_parameters));
+    assert_eq!(
+        fetched_new_parameters.block_gas_limit(),
+        new_block_gas_limit
+    );
+}
+
 fn valid_transaction(rng: &mut StdRng, amount: u64) -> Transaction {
     Transaction::script(
         10_000,